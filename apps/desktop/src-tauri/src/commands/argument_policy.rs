@@ -0,0 +1,58 @@
+//! Argument policy commands
+//!
+//! IPC commands for feature set argument policies: fixed values, defaults,
+//! allowed values and forbidden properties for the tools a set grants.
+
+use mcpmux_core::{ArgumentPolicy, ToolArgumentPolicy};
+use mcpmux_storage::ArgumentPolicyRepository;
+use tauri::State;
+use tracing::info;
+
+use crate::state::AppState;
+
+/// List the argument policies of a feature set
+#[tauri::command]
+pub async fn list_argument_policies(
+    feature_set_id: String,
+    state: State<'_, AppState>,
+) -> Result<Vec<ToolArgumentPolicy>, String> {
+    ArgumentPolicyRepository::new(state.database())
+        .list_for_feature_sets(&[feature_set_id])
+        .await
+        .map_err(|e| format!("Failed to list argument policies: {}", e))
+}
+
+/// Set a feature set's argument policy for a tool (`None` or an empty policy removes it)
+#[tauri::command]
+pub async fn set_argument_policy(
+    feature_set_id: String,
+    feature_id: String,
+    policy: Option<ArgumentPolicy>,
+    state: State<'_, AppState>,
+) -> Result<(), String> {
+    let repo = ArgumentPolicyRepository::new(state.database());
+
+    match policy.filter(|p| !p.is_empty()) {
+        Some(policy) => {
+            policy.validate().map_err(|e| e.to_string())?;
+            info!(
+                "[ArgumentPolicy] Setting policy for tool {} in feature set {}",
+                feature_id, feature_set_id
+            );
+            repo.set(&ToolArgumentPolicy {
+                feature_set_id,
+                feature_id,
+                policy,
+            })
+            .await
+        }
+        None => {
+            info!(
+                "[ArgumentPolicy] Removing policy for tool {} in feature set {}",
+                feature_id, feature_set_id
+            );
+            repo.delete(&feature_set_id, &feature_id).await
+        }
+    }
+    .map_err(|e| format!("Failed to save argument policy: {}", e))
+}
@@ -3,44 +3,65 @@
 //! IPC commands for generating MCP configuration files for clients.
 
 use mcpmux_core::{
-    ConfigExporter, ConfigFormat, ResolvedServer, ResolvedTransport, TransportConfig,
+    backup_config_file, write_config_file, ConfigExporter, ConfigFormat, InstalledServer,
+    ResolvedServer, ResolvedTransport, TransportConfig,
 };
+use mcpmux_gateway::pool::transport::load_env_file;
+use mcpmux_gateway::pool::transport::resolution::build_transport_config;
+use mcpmux_gateway::ResolvedTransport as GatewayTransport;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
-use std::path::PathBuf;
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
 use tauri::State;
+use uuid::Uuid;
 
 use crate::state::AppState;
 
 /// Request for exporting configuration
 #[derive(Debug, Deserialize)]
 pub struct ExportConfigRequest {
-    /// Client type: "cursor", "vscode", "claude"
+    /// Client type: "cursor", "vscode", "claude", "windsurf", "zed", "codex",
+    /// "gemini", "claude-code", "vscode-mcp"
     pub client_type: String,
     /// Space ID to export config for (use "default" for default space)
     pub space_id: String,
     /// Whether to mask credentials
     #[serde(default)]
     pub mask_credentials: bool,
+    /// Config file to merge into; previews become a dry run against it
+    #[serde(default)]
+    pub path: Option<String>,
 }
 
 /// Response for config export
 #[derive(Debug, Serialize)]
 pub struct ExportConfigResponse {
-    /// Generated JSON config
+    /// Generated config (TOML for Codex CLI, JSON otherwise)
     pub content: String,
     /// Default file path for this format
     pub default_path: Option<String>,
     /// File name suggestion
     pub suggested_filename: String,
+    /// Unified diff against the target file (dry runs only)
+    pub diff: Option<String>,
+    /// Whether writing would change the target file
+    pub changed: bool,
+    /// Fingerprint of the target file, `None` if it does not exist
+    pub base_fingerprint: Option<String>,
 }
 
 /// Get the config format from client type
-fn get_format(client_type: &str) -> Result<ConfigFormat, String> {
+pub(crate) fn get_format(client_type: &str) -> Result<ConfigFormat, String> {
     match client_type.to_lowercase().as_str() {
         "cursor" => Ok(ConfigFormat::Cursor),
         "vscode" | "vscode-continue" | "continue" => Ok(ConfigFormat::VsCodeContinue),
         "claude" | "claude-desktop" => Ok(ConfigFormat::ClaudeDesktop),
+        "windsurf" => Ok(ConfigFormat::Windsurf),
+        "zed" => Ok(ConfigFormat::Zed),
+        "codex" | "codex-cli" => Ok(ConfigFormat::CodexCli),
+        "gemini" | "gemini-cli" => Ok(ConfigFormat::GeminiCli),
+        "claude-code" => Ok(ConfigFormat::ClaudeCode),
+        "vscode-mcp" => Ok(ConfigFormat::VsCode),
         _ => Err(format!("Unknown client type: {}", client_type)),
     }
 }
@@ -61,6 +82,9 @@ async fn get_space_id(state: &AppState, space_id: &str) -> Result<String, String
 }
 
 /// Build resolved servers from installed servers (using cached definitions)
+///
+/// Servers resolve the way the gateway launches them: space secrets,
+/// config-file variables, working directory and `.env` file included.
 async fn build_resolved_servers(
     state: &AppState,
     space_id: &str,
@@ -72,95 +96,106 @@ async fn build_resolved_servers(
         .list_enabled(space_id)
         .await
         .map_err(|e| e.to_string())?;
+    let space_uuid = Uuid::parse_str(space_id).map_err(|e| format!("Invalid space_id: {}", e))?;
+    let secrets = state
+        .space_secret_service
+        .values(&space_uuid)
+        .await
+        .map_err(|e| e.to_string())?;
 
     let mut resolved = Vec::new();
 
     for inst in installed {
         // Use cached definition (offline-first)
-        if let Some(entry) = inst.get_definition() {
-            // Build resolved transport from registry transport + input values
-            let transport = match &entry.transport {
-                TransportConfig::Stdio {
-                    command, args, env, ..
-                } => {
-                    // Resolve placeholders in command
-                    let resolved_command = resolve_placeholders(command, &inst.input_values);
-
-                    // Resolve placeholders in args
-                    let mut resolved_args: Vec<String> = args
-                        .iter()
-                        .map(|arg| resolve_placeholders(arg, &inst.input_values))
-                        .collect();
-
-                    // Append user's extra args
-                    resolved_args.extend(inst.args_append.clone());
-
-                    // Build env from registry + input values + env_overrides
-                    let mut resolved_env = HashMap::new();
-
-                    // 1. Start with registry env
-                    for (k, v) in env {
-                        resolved_env.insert(k.clone(), resolve_placeholders(v, &inst.input_values));
-                    }
-
-                    // 2. Add input values (for api_key type servers)
-                    if !mask_credentials {
-                        resolved_env.extend(inst.input_values.clone());
-                    } else {
-                        for k in inst.input_values.keys() {
-                            resolved_env.insert(k.clone(), "***MASKED***".to_string());
-                        }
-                    }
-
-                    // 3. Apply user's env overrides
-                    resolved_env.extend(inst.env_overrides.clone());
-
-                    ResolvedTransport::Stdio {
-                        command: resolved_command,
-                        args: resolved_args,
-                        env: resolved_env,
-                    }
+        let Some(entry) = inst.get_definition() else {
+            continue;
+        };
+        let transport =
+            build_transport_config(&entry.transport, &inst, &secrets, Some(state.data_dir()))
+                .map_err(|e| format!("Server '{}': {}", inst.server_id, e))?;
+        let mut credentials = credential_keys(&entry.transport, &inst);
+
+        let transport = match transport {
+            GatewayTransport::Stdio {
+                command,
+                args,
+                mut env,
+                cwd,
+                env_file,
+            } => {
+                // Not every client reads .env files: inline it under env,
+                // which wins as it does when the gateway launches the server
+                if let Some(path) = env_file {
+                    let mut vars = load_env_file(&path)
+                        .map_err(|e| format!("Server '{}': {}", inst.server_id, e))?;
+                    credentials.extend(vars.keys().cloned());
+                    vars.extend(env);
+                    env = vars;
                 }
-                TransportConfig::Http { url, headers, .. } => {
-                    let resolved_url = resolve_placeholders(url, &inst.input_values);
-
-                    // Resolve headers from registry
-                    let mut resolved_headers: HashMap<String, String> = headers
-                        .iter()
-                        .map(|(k, v)| (k.clone(), resolve_placeholders(v, &inst.input_values)))
-                        .collect();
-
-                    // Add user's extra headers
-                    resolved_headers.extend(inst.extra_headers.clone());
-
-                    ResolvedTransport::Http {
-                        url: resolved_url,
-                        headers: resolved_headers,
-                    }
+                if mask_credentials {
+                    mask(&mut env, &credentials);
                 }
-            };
-
-            resolved.push(ResolvedServer {
-                server_id: inst.server_id.clone(),
-                transport,
-            });
-        }
+                ResolvedTransport::Stdio {
+                    command,
+                    args,
+                    env,
+                    cwd: cwd.map(|path| path.to_string_lossy().into_owned()),
+                }
+            }
+            GatewayTransport::Http { url, mut headers } => {
+                if mask_credentials {
+                    mask(&mut headers, &credentials);
+                }
+                ResolvedTransport::Http { url, headers }
+            }
+        };
+
+        resolved.push(ResolvedServer {
+            server_id: inst.server_id.clone(),
+            transport,
+        });
     }
 
     Ok(resolved)
 }
 
-/// Resolve ${input:xxx} placeholders in a string
-fn resolve_placeholders(template: &str, input_values: &HashMap<String, String>) -> String {
-    let mut result = template.to_string();
-    for (key, value) in input_values {
-        let placeholder = format!("${{input:{}}}", key);
-        result = result.replace(&placeholder, value);
+/// Env vars and headers that may carry credentials: input values, and
+/// entries whose template substitutes anything
+fn credential_keys(transport: &TransportConfig, inst: &InstalledServer) -> HashSet<String> {
+    let templates = match transport {
+        TransportConfig::Stdio { env, .. } => env.iter().chain(&inst.env_overrides),
+        TransportConfig::Http { headers, .. } => headers.iter().chain(&inst.extra_headers),
+    };
+    templates
+        .filter(|(_, template)| template.contains("${"))
+        .map(|(key, _)| key.clone())
+        .chain(inst.input_values.keys().cloned())
+        .collect()
+}
+
+fn mask(values: &mut HashMap<String, String>, keys: &HashSet<String>) {
+    for (key, value) in values.iter_mut() {
+        if keys.contains(key) {
+            *value = "***MASKED***".to_string();
+        }
     }
-    result
 }
 
-/// Preview config export (returns JSON string)
+/// Target file for a write: the given path, or the client's default location
+fn target_path(format: ConfigFormat, path: Option<String>) -> Result<PathBuf, String> {
+    match path {
+        Some(p) => Ok(PathBuf::from(p)),
+        None => format
+            .default_path()
+            .ok_or_else(|| "No default config path for this client".to_string()),
+    }
+}
+
+/// Preview config export (returns the file content).
+///
+/// With `request.path` set this is a dry run of [`export_config_to_file`]:
+/// the content is the file as it would be written, with a diff against the
+/// current file and its fingerprint to pass back when writing.
 #[tauri::command]
 pub async fn preview_config_export(
     request: ExportConfigRequest,
@@ -174,81 +209,144 @@ pub async fn preview_config_export(
 
     // Create exporter and generate config
     let exporter = ConfigExporter::new();
-    let content = exporter
-        .export_json(format, &servers)
-        .map_err(|e| e.to_string())?;
-
     let default_path = format
         .default_path()
         .map(|p| p.to_string_lossy().to_string());
+    let suggested_filename = format.file_name().to_string();
+
+    if let Some(path) = request.path {
+        let plan = exporter
+            .plan_write(format, Path::new(&path), &servers)
+            .map_err(|e| e.to_string())?;
+        return Ok(ExportConfigResponse {
+            content: plan.content,
+            default_path,
+            suggested_filename,
+            diff: Some(plan.diff),
+            changed: plan.changed,
+            base_fingerprint: plan.base_fingerprint,
+        });
+    }
 
-    let suggested_filename = match format {
-        ConfigFormat::Cursor => "mcp.json".to_string(),
-        ConfigFormat::VsCodeContinue => "continue-mcp.json".to_string(),
-        ConfigFormat::ClaudeDesktop => "claude_desktop_config.json".to_string(),
-    };
+    let content = exporter
+        .export(format, &servers)
+        .map_err(|e| e.to_string())?;
 
     Ok(ExportConfigResponse {
         content,
         default_path,
         suggested_filename,
+        diff: None,
+        changed: true,
+        base_fingerprint: None,
     })
 }
 
-/// Export config to file
+/// Export config to file.
+///
+/// Only the space's server entries are written; other servers, settings and
+/// comments in an existing file are kept, and the file is backed up first.
+/// `path` defaults to the client's default location. Overwriting an existing
+/// file requires the `base_fingerprint` from the preview as
+/// `expected_fingerprint`; the write is refused if the file was edited since.
 #[tauri::command]
 pub async fn export_config_to_file(
     request: ExportConfigRequest,
-    path: String,
+    path: Option<String>,
+    expected_fingerprint: Option<String>,
     state: State<'_, AppState>,
 ) -> Result<String, String> {
     let space_id = get_space_id(&state, &request.space_id).await?;
     let format = get_format(&request.client_type)?;
+    let path = target_path(format, path.or(request.path))?;
 
     // Build resolved servers (with actual credentials for file export)
     let servers = build_resolved_servers(&state, &space_id, false).await?;
 
-    // Create exporter and generate config
-    let exporter = ConfigExporter::new();
-    let content = exporter
-        .export_json(format, &servers)
+    let plan = ConfigExporter::new()
+        .plan_write(format, &path, &servers)
         .map_err(|e| e.to_string())?;
-
-    // Write to file
-    let path = PathBuf::from(&path);
-    if let Some(parent) = path.parent() {
-        std::fs::create_dir_all(parent).map_err(|e| e.to_string())?;
+    if plan.base_fingerprint.is_some() && expected_fingerprint.is_none() {
+        return Err(format!(
+            "{} already exists; preview the export before overwriting it",
+            path.display()
+        ));
+    }
+    if expected_fingerprint.is_some() && expected_fingerprint != plan.base_fingerprint {
+        return Err(format!(
+            "{} was modified since the export was previewed; preview again before writing",
+            path.display()
+        ));
+    }
+    if plan.changed {
+        write_config_file(&plan).map_err(|e| e.to_string())?;
     }
-    std::fs::write(&path, &content).map_err(|e| e.to_string())?;
 
     Ok(path.to_string_lossy().to_string())
 }
 
-/// Get default config paths for all clients
+/// Merge config into an existing client file.
+///
+/// Only the space's server entries are written; other servers, settings and
+/// comments in the file are kept, and the file is backed up first. `path`
+/// defaults to the client's default location. Returns the written content
+/// with the diff that was applied.
 #[tauri::command]
-pub async fn get_config_paths() -> Result<HashMap<String, Option<String>>, String> {
-    let mut paths = HashMap::new();
+pub async fn merge_config_into_file(
+    request: ExportConfigRequest,
+    path: Option<String>,
+    state: State<'_, AppState>,
+) -> Result<ExportConfigResponse, String> {
+    let space_id = get_space_id(&state, &request.space_id).await?;
+    let format = get_format(&request.client_type)?;
+    let path = target_path(format, path.or(request.path))?;
 
-    paths.insert(
-        "cursor".to_string(),
-        ConfigFormat::Cursor
-            .default_path()
-            .map(|p| p.to_string_lossy().to_string()),
-    );
-    paths.insert(
-        "vscode".to_string(),
-        ConfigFormat::VsCodeContinue
-            .default_path()
-            .map(|p| p.to_string_lossy().to_string()),
-    );
-    paths.insert(
-        "claude".to_string(),
-        ConfigFormat::ClaudeDesktop
-            .default_path()
-            .map(|p| p.to_string_lossy().to_string()),
-    );
+    // Build resolved servers (with actual credentials for file export)
+    let servers = build_resolved_servers(&state, &space_id, false).await?;
+
+    let plan = ConfigExporter::new()
+        .plan_write(format, &path, &servers)
+        .map_err(|e| e.to_string())?;
+    if plan.changed {
+        write_config_file(&plan).map_err(|e| e.to_string())?;
+    }
+
+    Ok(ExportConfigResponse {
+        default_path: Some(path.to_string_lossy().to_string()),
+        suggested_filename: format.file_name().to_string(),
+        diff: Some(plan.diff),
+        changed: plan.changed,
+        base_fingerprint: plan.base_fingerprint,
+        content: plan.content,
+    })
+}
 
-    Ok(paths)
+/// Get default config paths for all clients
+#[tauri::command]
+pub async fn get_config_paths() -> Result<HashMap<String, Option<String>>, String> {
+    let formats = [
+        ("cursor", ConfigFormat::Cursor),
+        ("vscode", ConfigFormat::VsCodeContinue),
+        ("claude", ConfigFormat::ClaudeDesktop),
+        ("windsurf", ConfigFormat::Windsurf),
+        ("zed", ConfigFormat::Zed),
+        ("codex", ConfigFormat::CodexCli),
+        ("gemini", ConfigFormat::GeminiCli),
+        ("claude-code", ConfigFormat::ClaudeCode),
+        ("vscode-mcp", ConfigFormat::VsCode),
+    ];
+
+    Ok(formats
+        .into_iter()
+        .map(|(client, format)| {
+            (
+                client.to_string(),
+                format
+                    .default_path()
+                    .map(|p| p.to_string_lossy().to_string()),
+            )
+        })
+        .collect())
 }
 
 /// Check if config file exists at default location
@@ -269,8 +367,7 @@ pub async fn backup_existing_config(client_type: String) -> Result<Option<String
 
     match format.default_path() {
         Some(path) if path.exists() => {
-            let backup_path = path.with_extension("json.bak");
-            std::fs::copy(&path, &backup_path).map_err(|e| e.to_string())?;
+            let backup_path = backup_config_file(&path).map_err(|e| e.to_string())?;
             Ok(Some(backup_path.to_string_lossy().to_string()))
         }
         _ => Ok(None),
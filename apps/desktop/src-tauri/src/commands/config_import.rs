@@ -0,0 +1,288 @@
+//! Config import commands
+//!
+//! IPC commands for importing MCP servers from other clients' config files
+//! (Cursor, VS Code, Continue, Claude Desktop) into a space.
+
+use mcpmux_core::application::ServerAppService;
+use mcpmux_core::{
+    parse_client_config, plan_gateway_rewrite, write_config_file, GatewayEndpoint, ImportCandidate,
+    ImportClient, ServerDefinition, ServerSource, TransportConfig,
+};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use tauri::State;
+use tokio::sync::RwLock;
+use tracing::{info, warn};
+
+use crate::commands::gateway::GatewayAppState;
+use crate::state::AppState;
+
+/// A server found in a client config
+#[derive(Debug, Serialize)]
+pub struct ImportableServer {
+    /// Key of the server in the client config
+    pub name: String,
+    /// Server ID it will be installed as
+    pub server_id: String,
+    pub server_name: String,
+    /// Command line or URL, for display
+    pub target: String,
+    /// Recognised as a registry server
+    pub registry_match: bool,
+    /// Inputs that hold secrets found as literals in the config
+    pub secret_inputs: Vec<String>,
+    /// Already installed in the space (will be skipped)
+    pub already_installed: bool,
+}
+
+/// Servers found in one client config file
+#[derive(Debug, Serialize)]
+pub struct ClientConfigScan {
+    pub client: ImportClient,
+    pub client_name: String,
+    pub path: String,
+    pub servers: Vec<ImportableServer>,
+    /// Set when the file exists but could not be read
+    pub error: Option<String>,
+}
+
+/// Request for importing servers from a client config
+#[derive(Debug, Deserialize)]
+pub struct ImportServersRequest {
+    pub client: ImportClient,
+    pub path: String,
+    pub space_id: String,
+    /// Names (config keys) of the servers to import
+    pub names: Vec<String>,
+    /// When set, rewrite the client config to use the gateway at this URL
+    #[serde(default)]
+    pub gateway_url: Option<String>,
+}
+
+/// Result of an import
+#[derive(Debug, Default, Serialize)]
+pub struct ImportServersResponse {
+    /// Server IDs installed
+    pub imported: Vec<String>,
+    /// Servers not installed, with the reason
+    pub skipped: Vec<String>,
+    /// Backup of the client config, if it was rewritten
+    pub backup_path: Option<String>,
+}
+
+/// Registry definitions imported entries are matched against
+pub(crate) async fn registry_definitions(state: &AppState) -> Vec<ServerDefinition> {
+    if let Err(e) = state.server_discovery.refresh_if_needed().await {
+        warn!("[ConfigImport] Registry refresh failed: {}", e);
+    }
+    state
+        .server_discovery
+        .list()
+        .await
+        .into_iter()
+        .filter(|d| !matches!(d.source, ServerSource::UserSpace { .. }))
+        .collect()
+}
+
+/// Read and parse a client config
+async fn read_candidates(
+    client: ImportClient,
+    space_id: &str,
+    path: &Path,
+    registry: &[ServerDefinition],
+) -> Result<Vec<ImportCandidate>, String> {
+    let content = tokio::fs::read_to_string(path)
+        .await
+        .map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
+    parse_client_config(&content, client, space_id, path, registry).map_err(|e| e.to_string())
+}
+
+/// How `client` should reach the gateway: over HTTP, or through the stdio
+/// bridge for clients that only launch local servers
+async fn gateway_endpoint(
+    client: ImportClient,
+    gateway_url: &str,
+    gateway_state: &GatewayAppState,
+) -> Result<GatewayEndpoint, String> {
+    let http = GatewayEndpoint::Http {
+        url: format!("{}/mcp", gateway_url.trim_end_matches('/')),
+    };
+    if client.supports(&http) {
+        return Ok(http);
+    }
+
+    let (Some(socket_path), Some(gw_state)) = (
+        gateway_state.socket_path.as_ref(),
+        gateway_state.gateway_state.as_ref(),
+    ) else {
+        return Err(format!(
+            "{} can only launch local servers; enable the gateway socket to point it at McpMux",
+            client.display_name()
+        ));
+    };
+    let gw_state = gw_state.read().await;
+    let repo = gw_state
+        .inbound_client_repository()
+        .ok_or("Database not available")?;
+    let client_id = crate::stdio_bridge::register_bridge_client(repo, client.as_str())
+        .await
+        .map_err(|e| format!("Failed to register bridge client: {}", e))?;
+    let bridge_command = std::env::current_exe()
+        .map_err(|e| format!("Failed to locate the McpMux executable: {}", e))?;
+
+    Ok(GatewayEndpoint::UnixSocket {
+        path: socket_path.into(),
+        bridge_command: bridge_command.to_string_lossy().into_owned(),
+        client_id,
+    })
+}
+
+fn describe_target(definition: &ServerDefinition) -> String {
+    match &definition.transport {
+        TransportConfig::Stdio { command, args, .. } => std::iter::once(command.as_str())
+            .chain(args.iter().map(String::as_str))
+            .collect::<Vec<_>>()
+            .join(" "),
+        TransportConfig::Http { url, .. } => url.clone(),
+    }
+}
+
+/// Find MCP servers in the config files of installed clients
+#[tauri::command]
+pub async fn scan_client_configs(
+    space_id: String,
+    gateway_url: Option<String>,
+    state: State<'_, AppState>,
+) -> Result<Vec<ClientConfigScan>, String> {
+    let registry = registry_definitions(&state).await;
+    let installed = state
+        .installed_server_repository
+        .list_for_space(&space_id)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    let mut scans = Vec::new();
+    for client in ImportClient::ALL {
+        for path in client.default_paths() {
+            if !path.exists() {
+                continue;
+            }
+            let mut scan = ClientConfigScan {
+                client,
+                client_name: client.display_name().to_string(),
+                path: path.to_string_lossy().to_string(),
+                servers: Vec::new(),
+                error: None,
+            };
+            match read_candidates(client, &space_id, &path, &registry).await {
+                Ok(candidates) => {
+                    scan.servers = candidates
+                        .into_iter()
+                        .filter(|c| gateway_url.as_deref().is_none_or(|url| !c.points_at(url)))
+                        .map(|c| ImportableServer {
+                            already_installed: installed
+                                .iter()
+                                .any(|i| i.server_id == c.definition.id),
+                            server_id: c.definition.id.clone(),
+                            server_name: c.definition.name.clone(),
+                            target: describe_target(&c.definition),
+                            registry_match: c.registry_match,
+                            secret_inputs: c.secret_inputs,
+                            name: c.name,
+                        })
+                        .collect();
+                }
+                Err(e) => scan.error = Some(e),
+            }
+            scans.push(scan);
+        }
+    }
+
+    Ok(scans)
+}
+
+/// Install servers from a client config into a space.
+///
+/// Imported servers start disabled, like registry installs. With a gateway
+/// URL, the client config is backed up and rewritten so the imported servers
+/// are replaced by a single entry for the gateway; comments and other
+/// settings in it are kept.
+#[tauri::command]
+pub async fn import_client_servers(
+    request: ImportServersRequest,
+    state: State<'_, AppState>,
+    app_service: State<'_, Arc<RwLock<Option<ServerAppService>>>>,
+    gateway_state: State<'_, Arc<RwLock<GatewayAppState>>>,
+) -> Result<ImportServersResponse, String> {
+    let service_lock = app_service.read().await;
+    let service = service_lock
+        .as_ref()
+        .ok_or("ServerAppService not initialized")?;
+    let space_uuid = uuid::Uuid::parse_str(&request.space_id).map_err(|e| e.to_string())?;
+
+    let path = PathBuf::from(&request.path);
+    // Work out how the client will reach the gateway before installing anything
+    let endpoint = match &request.gateway_url {
+        Some(url) => {
+            Some(gateway_endpoint(request.client, url, &*gateway_state.read().await).await?)
+        }
+        None => None,
+    };
+    let registry = registry_definitions(&state).await;
+    let candidates = read_candidates(request.client, &request.space_id, &path, &registry).await?;
+
+    let mut response = ImportServersResponse::default();
+    // Entries the gateway now serves, to drop from the client config
+    let mut routed = Vec::new();
+    for candidate in candidates {
+        if !request.names.contains(&candidate.name) {
+            continue;
+        }
+        let name = candidate.name.clone();
+        let server_id = candidate.definition.id.clone();
+        match service.import(space_uuid, candidate, request.client).await {
+            Ok(_) => {
+                info!(
+                    "[ConfigImport] Imported '{}' from {} as {}",
+                    name,
+                    request.client.display_name(),
+                    server_id
+                );
+                response.imported.push(server_id);
+                routed.push(name);
+            }
+            Err(e) => {
+                if state
+                    .installed_server_repository
+                    .get_by_server_id(&request.space_id, &server_id)
+                    .await
+                    .ok()
+                    .flatten()
+                    .is_some()
+                {
+                    routed.push(name.clone());
+                }
+                response.skipped.push(format!("{}: {}", name, e));
+            }
+        }
+    }
+
+    if let Some(endpoint) = &endpoint {
+        if !routed.is_empty() {
+            let plan = plan_gateway_rewrite(&path, request.client, &routed, endpoint)
+                .map_err(|e| e.to_string())?;
+            if plan.changed {
+                let backup_path = write_config_file(&plan).map_err(|e| e.to_string())?;
+                info!(
+                    "[ConfigImport] Pointed {} at the gateway (backup: {:?})",
+                    path.display(),
+                    backup_path
+                );
+                response.backup_path = backup_path.map(|p| p.to_string_lossy().to_string());
+            }
+        }
+    }
+
+    Ok(response)
+}
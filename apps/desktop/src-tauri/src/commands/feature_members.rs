@@ -6,8 +6,8 @@ use mcpmux_core::{FeatureSetMember, MemberMode};
 use std::sync::Arc;
 use tauri::State;
 use tokio::sync::RwLock;
-use uuid::Uuid as StdUuid;
 
+use crate::commands::feature_set::{affected_spaces, feature_member_id};
 use crate::commands::gateway::GatewayAppState;
 use crate::state::AppState;
 
@@ -28,6 +28,14 @@ pub async fn add_feature_to_set(
         _ => return Err("Invalid mode. Use 'include' or 'exclude'".to_string()),
     };
 
+    let feature_set = app_state
+        .feature_set_repository
+        .get(&feature_set_id)
+        .await
+        .map_err(|e| e.to_string())?
+        .ok_or("Feature set not found")?;
+    let feature_id = feature_member_id(app_state, &feature_set, feature_id).await?;
+
     app_state
         .feature_set_repository
         .add_feature_member(&feature_set_id, &feature_id, mode)
@@ -39,18 +47,13 @@ pub async fn add_feature_to_set(
     if let Some(ref gw) = gw_state.gateway_state {
         let gw = gw.read().await;
 
-        // Get feature set to access space_id
-        if let Ok(Some(fs)) = app_state.feature_set_repository.get(&feature_set_id).await {
-            if let Some(space_id_str) = fs.space_id {
-                if let Ok(space_uuid) = StdUuid::parse_str(&space_id_str) {
-                    gw.emit_domain_event(mcpmux_core::DomainEvent::FeatureSetMembersChanged {
-                        space_id: space_uuid,
-                        feature_set_id: feature_set_id.clone(),
-                        added_count: 1,
-                        removed_count: 0,
-                    });
-                }
-            }
+        for space_uuid in affected_spaces(app_state, &feature_set).await {
+            gw.emit_domain_event(mcpmux_core::DomainEvent::FeatureSetMembersChanged {
+                space_id: space_uuid,
+                feature_set_id: feature_set_id.clone(),
+                added_count: 1,
+                removed_count: 0,
+            });
         }
     }
 
@@ -67,6 +70,14 @@ pub async fn remove_feature_from_set(
 ) -> Result<(), String> {
     let app_state = &*state;
 
+    let feature_set = app_state
+        .feature_set_repository
+        .get(&feature_set_id)
+        .await
+        .map_err(|e| e.to_string())?
+        .ok_or("Feature set not found")?;
+    let feature_id = feature_member_id(app_state, &feature_set, feature_id).await?;
+
     app_state
         .feature_set_repository
         .remove_feature_member(&feature_set_id, &feature_id)
@@ -78,18 +89,13 @@ pub async fn remove_feature_from_set(
     if let Some(ref gw) = gw_state.gateway_state {
         let gw = gw.read().await;
 
-        // Get feature set to access space_id
-        if let Ok(Some(fs)) = app_state.feature_set_repository.get(&feature_set_id).await {
-            if let Some(space_id_str) = fs.space_id {
-                if let Ok(space_uuid) = StdUuid::parse_str(&space_id_str) {
-                    gw.emit_domain_event(mcpmux_core::DomainEvent::FeatureSetMembersChanged {
-                        space_id: space_uuid,
-                        feature_set_id: feature_set_id.clone(),
-                        added_count: 0,
-                        removed_count: 1,
-                    });
-                }
-            }
+        for space_uuid in affected_spaces(app_state, &feature_set).await {
+            gw.emit_domain_event(mcpmux_core::DomainEvent::FeatureSetMembersChanged {
+                space_id: space_uuid,
+                feature_set_id: feature_set_id.clone(),
+                added_count: 0,
+                removed_count: 1,
+            });
         }
     }
 
@@ -0,0 +1,203 @@
+//! Feature override commands
+//!
+//! IPC commands for exposing tools and prompts under a different name, title
+//! or description, for a whole space or for one feature set.
+
+use std::sync::Arc;
+
+use mcpmux_core::{DomainEvent, FeatureOverride, FeatureType};
+use mcpmux_storage::FeatureOverrideRepository;
+use serde::Serialize;
+use tauri::State;
+use tokio::sync::RwLock;
+use tracing::info;
+use uuid::Uuid as StdUuid;
+
+use crate::commands::gateway::GatewayAppState;
+use crate::state::AppState;
+
+/// A feature override, flagged when its feature changed since it was saved
+#[derive(Debug, Serialize)]
+pub struct FeatureOverrideResponse {
+    #[serde(flatten)]
+    pub feature_override: FeatureOverride,
+    /// The feature's definition changed (or the feature is gone); review the override
+    pub stale: bool,
+}
+
+/// List a space's feature overrides
+#[tauri::command]
+pub async fn list_feature_overrides(
+    space_id: String,
+    state: State<'_, AppState>,
+) -> Result<Vec<FeatureOverrideResponse>, String> {
+    let overrides = FeatureOverrideRepository::new(state.database())
+        .list_for_space(&space_id)
+        .await
+        .map_err(|e| format!("Failed to list feature overrides: {}", e))?;
+    let features = state
+        .server_feature_repository_core
+        .list_for_space(&space_id)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    Ok(overrides
+        .into_iter()
+        .map(|feature_override| {
+            let stale = features
+                .iter()
+                .find(|f| feature_override.targets(f))
+                .is_none_or(|f| feature_override.is_stale(f));
+            FeatureOverrideResponse {
+                feature_override,
+                stale,
+            }
+        })
+        .collect())
+}
+
+/// Set a feature's exposed name, title and description.
+///
+/// Empty values clear a field; clearing all three removes the override.
+/// Saving records the feature's current definition, clearing the stale flag.
+#[tauri::command]
+#[allow(clippy::too_many_arguments)]
+pub async fn set_feature_override(
+    space_id: String,
+    feature_id: String,
+    feature_set_id: Option<String>,
+    name: Option<String>,
+    title: Option<String>,
+    description: Option<String>,
+    state: State<'_, AppState>,
+    gateway_state: State<'_, Arc<RwLock<GatewayAppState>>>,
+) -> Result<(), String> {
+    let feature_uuid =
+        StdUuid::parse_str(&feature_id).map_err(|e| format!("Invalid feature ID: {e}"))?;
+    let feature = state
+        .server_feature_repository_core
+        .get(&feature_uuid)
+        .await
+        .map_err(|e| e.to_string())?
+        .filter(|f| f.space_id == space_id)
+        .ok_or_else(|| format!("Feature not found: {feature_id}"))?;
+
+    let non_empty = |value: Option<String>| {
+        value
+            .map(|v| v.trim().to_string())
+            .filter(|v| !v.is_empty())
+    };
+    let mut feature_override = FeatureOverride::for_feature(&feature, feature_set_id);
+    feature_override.name = non_empty(name);
+    feature_override.title = non_empty(title);
+    feature_override.description = non_empty(description);
+
+    let repo = FeatureOverrideRepository::new(state.database());
+    if feature_override.is_empty() {
+        info!(
+            "[FeatureOverride] Removing override for {}/{}",
+            feature.server_id, feature.feature_name
+        );
+        repo.delete(
+            &space_id,
+            feature_override.feature_set_id.as_deref(),
+            &feature.server_id,
+            &feature.feature_type,
+            &feature.feature_name,
+        )
+        .await
+        .map_err(|e| format!("Failed to remove feature override: {}", e))?;
+    } else {
+        feature_override.validate().map_err(|e| e.to_string())?;
+        if let Some(name) = &feature_override.name {
+            ensure_name_is_free(&state, &repo, &feature_override, name).await?;
+        }
+        info!(
+            "[FeatureOverride] Setting override for {}/{} (feature set: {:?})",
+            feature.server_id, feature.feature_name, feature_override.feature_set_id
+        );
+        repo.set(&feature_override)
+            .await
+            .map_err(|e| format!("Failed to save feature override: {}", e))?;
+    }
+
+    // Tell connected clients to refetch the server's tools or prompts
+    let gw_state = gateway_state.read().await;
+    if let (Some(gw), Ok(space_uuid)) = (&gw_state.gateway_state, StdUuid::parse_str(&space_id)) {
+        let server_id = feature.server_id.clone();
+        let event = match feature.feature_type {
+            FeatureType::Prompt => DomainEvent::PromptsChanged {
+                space_id: space_uuid,
+                server_id,
+            },
+            _ => DomainEvent::ToolsChanged {
+                space_id: space_uuid,
+                server_id,
+            },
+        };
+        gw.read().await.emit_domain_event(event);
+    }
+
+    Ok(())
+}
+
+/// Names route calls back to one feature, so they must be unique in the space
+/// and must not look like any server's `prefix_name`, which would shadow that
+/// server's features, including ones it only exposes later.
+async fn ensure_name_is_free(
+    state: &AppState,
+    repo: &FeatureOverrideRepository,
+    feature_override: &FeatureOverride,
+    name: &str,
+) -> Result<(), String> {
+    let overrides = repo
+        .list_for_space(&feature_override.space_id)
+        .await
+        .map_err(|e| e.to_string())?;
+    let taken_by_override = overrides.iter().any(|o| {
+        o.name.as_deref() == Some(name)
+            && (o.feature_set_id != feature_override.feature_set_id
+                || o.server_id != feature_override.server_id
+                || o.feature_type != feature_override.feature_type
+                || o.feature_name != feature_override.feature_name)
+    });
+    if taken_by_override {
+        return Err(format!("The name '{}' is already used in this space", name));
+    }
+
+    // Prefixes a server's features can be exposed under: its ID and its alias
+    let servers = state
+        .installed_server_repository
+        .list_for_space(&feature_override.space_id)
+        .await
+        .map_err(|e| e.to_string())?;
+    for server in servers {
+        let mut prefixes = vec![server.server_id.clone(), server.server_id.replace('/', ".")];
+        if let Some(alias) = state
+            .server_discovery
+            .get(&server.server_id)
+            .await
+            .and_then(|d| d.alias)
+        {
+            prefixes.push(alias);
+        }
+        // Re-using the feature's own qualified name is harmless
+        if server.server_id == feature_override.server_id
+            && prefixes
+                .iter()
+                .any(|p| name == format!("{}_{}", p, feature_override.feature_name))
+        {
+            continue;
+        }
+        if let Some(prefix) = prefixes.iter().find(|p| {
+            name.strip_prefix(p.as_str())
+                .is_some_and(|rest| rest.starts_with('_'))
+        }) {
+            return Err(format!(
+                "The name '{}' uses the prefix '{}_' of server {}; choose another name",
+                name, prefix, server.server_id
+            ));
+        }
+    }
+    Ok(())
+}
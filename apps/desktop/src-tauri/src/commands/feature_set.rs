@@ -3,8 +3,9 @@
 //! IPC commands for managing feature sets (permission bundles).
 
 use chrono::Utc;
-use mcpmux_core::{FeatureSet, FeatureSetMember, MemberMode, MemberType};
+use mcpmux_core::{FeatureRule, FeatureSet, FeatureSetMember, MemberMode, MemberType};
 use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
 use std::sync::Arc;
 use tauri::State;
 use tokio::sync::RwLock;
@@ -48,6 +49,7 @@ pub struct FeatureSetResponse {
     pub server_id: Option<String>,
     pub is_builtin: bool,
     pub is_deleted: bool,
+    pub read_only: bool,
     pub members: Vec<FeatureSetMemberResponse>,
 }
 
@@ -64,6 +66,7 @@ impl From<FeatureSet> for FeatureSetResponse {
             server_id: fs.server_id,
             is_builtin: fs.is_builtin,
             is_deleted: fs.is_deleted,
+            read_only: fs.read_only,
             members,
         }
     }
@@ -73,9 +76,12 @@ impl From<FeatureSet> for FeatureSetResponse {
 #[derive(Debug, Deserialize)]
 pub struct CreateFeatureSetInput {
     pub name: String,
-    pub space_id: String,
+    /// None creates a global feature set, usable in every space
+    pub space_id: Option<String>,
     pub description: Option<String>,
     pub icon: Option<String>,
+    #[serde(default)]
+    pub read_only: bool,
 }
 
 /// Input for updating a feature set
@@ -84,16 +90,112 @@ pub struct UpdateFeatureSetInput {
     pub name: Option<String>,
     pub description: Option<String>,
     pub icon: Option<String>,
+    pub read_only: Option<bool>,
 }
 
 /// Input for adding a member to a feature set
 #[derive(Debug, Deserialize)]
 pub struct AddMemberInput {
-    pub member_type: String, // "feature" or "feature_set"
+    pub member_type: String, // "feature", "feature_set" or "rule"
     pub member_id: String,
     pub mode: Option<String>, // "include" or "exclude", defaults to "include"
 }
 
+/// Spaces a feature set applies in: its own, or every space for a global set
+pub(crate) async fn affected_spaces(state: &AppState, feature_set: &FeatureSet) -> Vec<StdUuid> {
+    match &feature_set.space_id {
+        Some(space_id) => StdUuid::parse_str(space_id).into_iter().collect(),
+        None => state
+            .space_service
+            .list()
+            .await
+            .unwrap_or_default()
+            .into_iter()
+            .map(|space| space.id)
+            .collect(),
+    }
+}
+
+/// Whether `member_id` is `feature_set_id` or nests it at any depth, so
+/// adding it as a member would make a cycle
+async fn would_cycle(
+    state: &AppState,
+    feature_set_id: &str,
+    member_id: &str,
+) -> Result<bool, String> {
+    let mut visited = HashSet::new();
+    let mut pending = vec![member_id.to_string()];
+    while let Some(id) = pending.pop() {
+        if id == feature_set_id {
+            return Ok(true);
+        }
+        if !visited.insert(id.clone()) {
+            continue;
+        }
+        let Some(nested) = state
+            .feature_set_repository
+            .get_with_members(&id)
+            .await
+            .map_err(|e| e.to_string())?
+        else {
+            continue;
+        };
+        pending.extend(
+            nested
+                .members
+                .into_iter()
+                .filter(|m| m.member_type == MemberType::FeatureSet)
+                .map(|m| m.member_id),
+        );
+    }
+    Ok(false)
+}
+
+/// Notify MCP clients that a feature set changed (if gateway running)
+async fn notify_feature_set_modified(
+    state: &AppState,
+    gateway_state: &RwLock<GatewayAppState>,
+    feature_set: &FeatureSet,
+) {
+    let gw_state = gateway_state.read().await;
+    let Some(ref grant_service) = gw_state.grant_service else {
+        return;
+    };
+    for space_id in affected_spaces(state, feature_set).await {
+        if let Err(e) = grant_service
+            .notify_feature_set_modified(&space_id.to_string(), &feature_set.id)
+            .await
+        {
+            warn!("[FeatureSet] Failed to emit notifications: {}", e);
+        }
+    }
+}
+
+/// The member ID a feature is stored under in `feature_set`.
+///
+/// Global sets store the feature's portable key instead of its ID, since
+/// feature IDs differ per space. IDs that are not UUIDs are taken to be
+/// portable keys already.
+pub(crate) async fn feature_member_id(
+    state: &AppState,
+    feature_set: &FeatureSet,
+    feature_id: String,
+) -> Result<String, String> {
+    if !feature_set.is_global() {
+        return Ok(feature_id);
+    }
+    let Ok(uuid) = StdUuid::parse_str(&feature_id) else {
+        return Ok(feature_id);
+    };
+    state
+        .server_feature_repository_core
+        .get(&uuid)
+        .await
+        .map_err(|e| e.to_string())?
+        .map(|feature| feature.portable_key())
+        .ok_or_else(|| format!("Feature not found: {}", feature_id))
+}
+
 /// List all feature sets.
 #[tauri::command]
 pub async fn list_feature_sets(
@@ -108,18 +210,25 @@ pub async fn list_feature_sets(
     Ok(feature_sets.into_iter().map(Into::into).collect())
 }
 
-/// List feature sets for a specific space.
+/// List feature sets for a specific space, followed by the global sets.
 /// Filters out server-all feature sets for servers that are not enabled.
 #[tauri::command]
 pub async fn list_feature_sets_by_space(
     space_id: String,
     state: State<'_, AppState>,
 ) -> Result<Vec<FeatureSetResponse>, String> {
-    let feature_sets = state
+    let mut feature_sets = state
         .feature_set_repository
         .list_by_space(&space_id)
         .await
         .map_err(|e| e.to_string())?;
+    feature_sets.extend(
+        state
+            .feature_set_repository
+            .list_global()
+            .await
+            .map_err(|e| e.to_string())?,
+    );
 
     // Get enabled server IDs to filter server-all feature sets
     let installed_servers = state
@@ -153,6 +262,20 @@ pub async fn list_feature_sets_by_space(
     Ok(filtered)
 }
 
+/// List global feature sets.
+#[tauri::command]
+pub async fn list_global_feature_sets(
+    state: State<'_, AppState>,
+) -> Result<Vec<FeatureSetResponse>, String> {
+    let feature_sets = state
+        .feature_set_repository
+        .list_global()
+        .await
+        .map_err(|e| e.to_string())?;
+
+    Ok(feature_sets.into_iter().map(Into::into).collect())
+}
+
 /// Get a feature set by ID (without members).
 #[tauri::command]
 pub async fn get_feature_set(
@@ -183,14 +306,18 @@ pub async fn get_feature_set_with_members(
     Ok(feature_set.map(Into::into))
 }
 
-/// Create a new custom feature set.
+/// Create a new custom feature set, global when no space is given.
 #[tauri::command]
 pub async fn create_feature_set(
     input: CreateFeatureSetInput,
     state: State<'_, AppState>,
     gateway_state: State<'_, Arc<RwLock<GatewayAppState>>>,
 ) -> Result<FeatureSetResponse, String> {
-    let mut feature_set = FeatureSet::new_custom(&input.name, &input.space_id);
+    let mut feature_set = match &input.space_id {
+        Some(space_id) => FeatureSet::new_custom(&input.name, space_id),
+        None => FeatureSet::new_global(&input.name),
+    }
+    .with_read_only(input.read_only);
 
     if let Some(desc) = input.description {
         feature_set = feature_set.with_description(desc);
@@ -211,8 +338,7 @@ pub async fn create_feature_set(
     if let Some(ref gw) = gw_state.gateway_state {
         let gw = gw.read().await;
 
-        // Parse space_id as Uuid
-        if let Ok(space_uuid) = StdUuid::parse_str(&input.space_id) {
+        for space_uuid in affected_spaces(&state, &feature_set).await {
             gw.emit_domain_event(mcpmux_core::DomainEvent::FeatureSetCreated {
                 space_id: space_uuid,
                 feature_set_id: feature_set.id.clone(),
@@ -250,15 +376,13 @@ pub async fn delete_feature_set(
     if let Some(ref gw) = gw_state.gateway_state {
         let gw = gw.read().await;
 
-        // Only emit if we found the feature set and it has a space_id
+        // Only emit if we found the feature set
         if let Some(fs) = feature_set {
-            if let Some(space_id_str) = fs.space_id {
-                if let Ok(space_uuid) = StdUuid::parse_str(&space_id_str) {
-                    gw.emit_domain_event(mcpmux_core::DomainEvent::FeatureSetDeleted {
-                        space_id: space_uuid,
-                        feature_set_id: id,
-                    });
-                }
+            for space_uuid in affected_spaces(&state, &fs).await {
+                gw.emit_domain_event(mcpmux_core::DomainEvent::FeatureSetDeleted {
+                    space_id: space_uuid,
+                    feature_set_id: id.clone(),
+                });
             }
         }
     }
@@ -298,7 +422,7 @@ pub async fn ensure_server_all_feature_set(
     Ok(feature_set.into())
 }
 
-/// Update a feature set (name, description, icon).
+/// Update a feature set (name, description, icon, read-only).
 #[tauri::command]
 pub async fn update_feature_set(
     id: String,
@@ -326,6 +450,9 @@ pub async fn update_feature_set(
     if let Some(icon) = input.icon {
         feature_set.icon = Some(icon);
     }
+    if let Some(read_only) = input.read_only {
+        feature_set.read_only = read_only;
+    }
     feature_set.updated_at = Utc::now();
 
     state
@@ -334,17 +461,7 @@ pub async fn update_feature_set(
         .await
         .map_err(|e| e.to_string())?;
 
-    // Notify MCP clients that feature set changed (if gateway running)
-    let space_id = feature_set.space_id.as_deref().unwrap_or("default");
-    let gw_state = gateway_state.read().await;
-    if let Some(ref grant_service) = gw_state.grant_service {
-        if let Err(e) = grant_service
-            .notify_feature_set_modified(space_id, &id)
-            .await
-        {
-            warn!("[FeatureSet] Failed to emit notifications: {}", e);
-        }
-    }
+    notify_feature_set_modified(&state, &gateway_state, &feature_set).await;
 
     Ok(feature_set.into())
 }
@@ -375,9 +492,20 @@ pub async fn add_feature_set_member(
 
     let member_type = match input.member_type.as_str() {
         "feature_set" => MemberType::FeatureSet,
+        "rule" => MemberType::Rule,
         _ => MemberType::Feature,
     };
 
+    // Rules are stored as written, so reject expressions that would never match
+    let member_id = match member_type {
+        MemberType::Rule => FeatureRule::parse(&input.member_id)
+            .map_err(|e| format!("Invalid rule: {}", e))?
+            .as_str()
+            .to_string(),
+        MemberType::Feature => feature_member_id(&state, &feature_set, input.member_id).await?,
+        MemberType::FeatureSet => input.member_id,
+    };
+
     let mode = input
         .mode
         .as_deref()
@@ -388,19 +516,24 @@ pub async fn add_feature_set_member(
     if feature_set
         .members
         .iter()
-        .any(|m| m.member_type == member_type && m.member_id == input.member_id)
+        .any(|m| m.member_type == member_type && m.member_id == member_id)
     {
         return Err("Member already exists in this feature set".to_string());
     }
 
     // Check for recursive reference (featureset including itself)
-    if member_type == MemberType::FeatureSet && input.member_id == feature_set_id {
+    if member_type == MemberType::FeatureSet && member_id == feature_set_id {
         return Err("Cannot add a feature set to itself".to_string());
     }
+    if member_type == MemberType::FeatureSet
+        && would_cycle(&state, &feature_set_id, &member_id).await?
+    {
+        return Err("Cannot add a feature set that already includes this one".to_string());
+    }
 
     // Prevent including "all" or "default" type feature sets in other feature sets
     if member_type == MemberType::FeatureSet {
-        if let Ok(Some(target_fs)) = state.feature_set_repository.get(&input.member_id).await {
+        if let Ok(Some(target_fs)) = state.feature_set_repository.get(&member_id).await {
             let target_type = target_fs.feature_set_type.as_str();
             if target_type == "all" || target_type == "default" {
                 return Err(format!(
@@ -408,6 +541,11 @@ pub async fn add_feature_set_member(
                     target_type
                 ));
             }
+            if feature_set.is_global() && !target_fs.is_global() {
+                return Err(
+                    "Global feature sets can only include other global feature sets".to_string(),
+                );
+            }
         }
     }
 
@@ -415,7 +553,7 @@ pub async fn add_feature_set_member(
         id: uuid::Uuid::new_v4().to_string(),
         feature_set_id: feature_set_id.clone(),
         member_type,
-        member_id: input.member_id,
+        member_id,
         mode,
     };
 
@@ -428,17 +566,7 @@ pub async fn add_feature_set_member(
         .await
         .map_err(|e| e.to_string())?;
 
-    // Notify MCP clients that feature set changed (if gateway running)
-    let space_id = feature_set.space_id.as_deref().unwrap_or("default");
-    let gw_state = gateway_state.read().await;
-    if let Some(ref grant_service) = gw_state.grant_service {
-        if let Err(e) = grant_service
-            .notify_feature_set_modified(space_id, &feature_set_id)
-            .await
-        {
-            warn!("[FeatureSet] Failed to emit notifications: {}", e);
-        }
-    }
+    notify_feature_set_modified(&state, &gateway_state, &feature_set).await;
 
     Ok(feature_set.into())
 }
@@ -471,17 +599,7 @@ pub async fn remove_feature_set_member(
         .await
         .map_err(|e| e.to_string())?;
 
-    // Notify MCP clients that feature set changed (if gateway running)
-    let space_id = feature_set.space_id.as_deref().unwrap_or("default");
-    let gw_state = gateway_state.read().await;
-    if let Some(ref grant_service) = gw_state.grant_service {
-        if let Err(e) = grant_service
-            .notify_feature_set_modified(space_id, &feature_set_id)
-            .await
-        {
-            warn!("[FeatureSet] Failed to emit notifications: {}", e);
-        }
-    }
+    notify_feature_set_modified(&state, &gateway_state, &feature_set).await;
 
     Ok(feature_set.into())
 }
@@ -521,11 +639,20 @@ pub async fn set_feature_set_members(
             if m.member_type == "feature_set" && m.member_id == feature_set_id {
                 return false;
             }
+            // Skip rules that do not parse
+            if m.member_type == "rule" && FeatureRule::parse(&m.member_id).is_err() {
+                warn!(
+                    "[FeatureSet] Skipping invalid rule member '{}'",
+                    m.member_id
+                );
+                return false;
+            }
             true
         })
         .map(|input| {
             let member_type = match input.member_type.as_str() {
                 "feature_set" => MemberType::FeatureSet,
+                "rule" => MemberType::Rule,
                 _ => MemberType::Feature,
             };
             let mode = input
@@ -544,6 +671,49 @@ pub async fn set_feature_set_members(
         })
         .collect();
 
+    // Global sets store portable feature keys and may only nest global sets
+    let new_members = if feature_set.is_global() {
+        let mut portable = Vec::with_capacity(new_members.len());
+        for mut member in new_members {
+            match member.member_type {
+                MemberType::Feature => {
+                    member.member_id =
+                        feature_member_id(&state, &feature_set, member.member_id).await?;
+                }
+                MemberType::FeatureSet => {
+                    let target = state
+                        .feature_set_repository
+                        .get(&member.member_id)
+                        .await
+                        .map_err(|e| e.to_string())?;
+                    if !target.is_some_and(|fs| fs.is_global()) {
+                        warn!(
+                            "[FeatureSet] Skipping non-global feature set member '{}'",
+                            member.member_id
+                        );
+                        continue;
+                    }
+                }
+                MemberType::Rule => {}
+            }
+            portable.push(member);
+        }
+        portable
+    } else {
+        new_members
+    };
+
+    for member in &new_members {
+        if member.member_type == MemberType::FeatureSet
+            && would_cycle(&state, &feature_set_id, &member.member_id).await?
+        {
+            return Err(format!(
+                "Feature set '{}' already includes this one",
+                member.member_id
+            ));
+        }
+    }
+
     feature_set.members = new_members;
     feature_set.updated_at = Utc::now();
 
@@ -553,17 +723,7 @@ pub async fn set_feature_set_members(
         .await
         .map_err(|e| e.to_string())?;
 
-    // Notify MCP clients that feature set changed (if gateway running)
-    let space_id = feature_set.space_id.as_deref().unwrap_or("default");
-    let gw_state = gateway_state.read().await;
-    if let Some(ref grant_service) = gw_state.grant_service {
-        if let Err(e) = grant_service
-            .notify_feature_set_modified(space_id, &feature_set_id)
-            .await
-        {
-            warn!("[FeatureSet] Failed to emit notifications: {}", e);
-        }
-    }
+    notify_feature_set_modified(&state, &gateway_state, &feature_set).await;
 
     Ok(feature_set.into())
 }
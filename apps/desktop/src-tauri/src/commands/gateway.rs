@@ -23,6 +23,8 @@ pub struct GatewayStatus {
     pub running: bool,
     /// Gateway URL if running
     pub url: Option<String>,
+    /// Unix domain socket path if the gateway is serving on one
+    pub socket_path: Option<String>,
     /// Number of active client sessions
     pub active_sessions: usize,
     /// Number of connected backend servers
@@ -44,6 +46,10 @@ pub struct GatewayAppState {
     pub running: bool,
     /// Gateway URL
     pub url: Option<String>,
+    /// Unix domain socket path (when serving on a socket)
+    pub socket_path: Option<String>,
+    /// Whether the TCP listener is enabled
+    pub tcp_enabled: bool,
     /// Gateway task handle
     pub handle: Option<tokio::task::JoinHandle<anyhow::Result<()>>>,
     /// Gateway state reference for accessing backends
@@ -56,6 +62,8 @@ pub struct GatewayAppState {
     pub event_emitter: Option<Arc<mcpmux_gateway::EventEmitter>>,
     /// Grant service for centralized grant management with auto-notifications
     pub grant_service: Option<Arc<mcpmux_gateway::GrantService>>,
+    /// Tool approval service for held tool calls
+    pub tool_approval_service: Option<Arc<mcpmux_gateway::ToolApprovalService>>,
 }
 
 /// Start domain event bridge from Gateway to Tauri
@@ -122,6 +130,17 @@ fn map_domain_event_to_ui(event: &DomainEvent) -> (&'static str, serde_json::Val
                 "name": name,
             }),
         ),
+        DomainEvent::SpaceReadOnlyChanged {
+            space_id,
+            read_only,
+        } => (
+            "space-changed",
+            serde_json::json!({
+                "action": "read_only_changed",
+                "space_id": space_id,
+                "read_only": read_only,
+            }),
+        ),
         DomainEvent::SpaceDeleted { space_id } => (
             "space-changed",
             serde_json::json!({
@@ -260,6 +279,45 @@ fn map_domain_event_to_ui(event: &DomainEvent) -> (&'static str, serde_json::Val
             }),
         ),
 
+        DomainEvent::FeatureDefinitionChanged {
+            space_id,
+            server_id,
+            feature_id,
+            feature_type,
+            feature_name,
+            old_definition,
+            new_definition,
+        } => (
+            "feature-definition-changed",
+            serde_json::json!({
+                "action": "changed",
+                "space_id": space_id,
+                "server_id": server_id,
+                "feature_id": feature_id,
+                "feature_type": feature_type,
+                "feature_name": feature_name,
+                "old_definition": old_definition,
+                "new_definition": new_definition,
+            }),
+        ),
+        DomainEvent::FeatureDefinitionApproved {
+            space_id,
+            server_id,
+            feature_id,
+            feature_type,
+            feature_name,
+        } => (
+            "feature-definition-changed",
+            serde_json::json!({
+                "action": "approved",
+                "space_id": space_id,
+                "server_id": server_id,
+                "feature_id": feature_id,
+                "feature_type": feature_type,
+                "feature_name": feature_name,
+            }),
+        ),
+
         // Feature set events
         DomainEvent::FeatureSetCreated {
             space_id,
@@ -404,6 +462,48 @@ fn map_domain_event_to_ui(event: &DomainEvent) -> (&'static str, serde_json::Val
             }),
         ),
 
+        // Tool approval events
+        DomainEvent::ToolApprovalRequested {
+            request_id,
+            space_id,
+            client_id,
+            server_id,
+            tool_name,
+            arguments,
+        } => (
+            "tool-approval",
+            serde_json::json!({
+                "action": "requested",
+                "request_id": request_id,
+                "space_id": space_id,
+                "client_id": client_id,
+                "server_id": server_id,
+                "tool_name": tool_name,
+                "arguments": arguments,
+            }),
+        ),
+        DomainEvent::ToolApprovalResolved {
+            request_id,
+            space_id,
+            client_id,
+            server_id,
+            tool_name,
+            approved,
+            decided_by,
+        } => (
+            "tool-approval",
+            serde_json::json!({
+                "action": "resolved",
+                "request_id": request_id,
+                "space_id": space_id,
+                "client_id": client_id,
+                "server_id": server_id,
+                "tool_name": tool_name,
+                "approved": approved,
+                "decided_by": decided_by,
+            }),
+        ),
+
         // Gateway events
         DomainEvent::GatewayStarted { url, port } => (
             "gateway-changed",
@@ -494,7 +594,8 @@ fn create_gateway_dependencies(
         .with_log_manager(app_state.server_log_manager.clone())
         .with_database(app_state.database())
         .with_state_dir(app_state.data_dir().to_path_buf())
-        .with_settings_repo(app_state.settings_repository.clone());
+        .with_settings_repo(app_state.settings_repository.clone())
+        .with_space_secret_repo(app_state.space_secret_repository.clone());
 
     if let Some(secret) = jwt_secret {
         builder = builder.with_jwt_secret(secret);
@@ -503,6 +604,80 @@ fn create_gateway_dependencies(
     builder.build().map_err(|e: String| e)
 }
 
+/// Build the gateway listener config from persisted settings
+///
+/// TCP binds `gateway.bind_host` (non-loopback requires TLS); a Unix domain
+/// socket is added when `gateway.unix_socket_path` is set (Unix platforms only).
+pub async fn build_gateway_config(
+    settings_repo: Arc<dyn mcpmux_core::AppSettingsRepository>,
+    data_dir: &std::path::Path,
+    port: u16,
+) -> mcpmux_gateway::GatewayConfig {
+    let settings = mcpmux_core::AppSettingsService::new(settings_repo);
+
+    let unix_socket = if cfg!(unix) {
+        match settings.get_gateway_unix_socket_path().await {
+            Some(path) => Some(
+                mcpmux_gateway::UnixSocketConfig::new(path)
+                    .with_mode(settings.get_gateway_unix_socket_mode().await),
+            ),
+            None => None,
+        }
+    } else {
+        None
+    };
+
+    // Never leave the gateway without a listener
+    let tcp_enabled = settings.get_gateway_tcp_enabled().await || unix_socket.is_none();
+
+    let host = settings.get_gateway_bind_host().await; // Bind address must be IP
+    let public_url = settings.get_gateway_public_url().await;
+
+    let tls = if settings.get_gateway_tls_enabled().await {
+        let tls = match settings.get_gateway_tls_cert_paths().await {
+            Some((cert_path, key_path)) => {
+                mcpmux_gateway::TlsConfig::from_files(cert_path, key_path)
+            }
+            None => {
+                // Self-signed certificate covering the bind address and public host
+                let hostnames =
+                    mcpmux_gateway::server::self_signed_hostnames(&host, public_url.as_deref());
+                mcpmux_gateway::TlsConfig::self_signed(data_dir.join("tls"), hostnames)
+            }
+        };
+        let client_auth = settings.get_gateway_tls_client_auth().await;
+        let client_auth =
+            mcpmux_gateway::ClientAuthMode::parse(&client_auth).unwrap_or_else(|| {
+                warn!(
+                    "[Gateway] Unknown TLS client auth mode '{}', using off",
+                    client_auth
+                );
+                mcpmux_gateway::ClientAuthMode::Off
+            });
+        Some(
+            tls.with_client_auth(
+                client_auth,
+                settings
+                    .get_gateway_tls_client_ca_path()
+                    .await
+                    .map(std::path::PathBuf::from),
+            ),
+        )
+    } else {
+        None
+    };
+
+    mcpmux_gateway::GatewayConfig {
+        host,
+        port,
+        tcp_enabled,
+        unix_socket,
+        tls,
+        public_url,
+        enable_cors: true,
+    }
+}
+
 /// Get gateway status, optionally scoped to a specific space
 #[tauri::command]
 pub async fn get_gateway_status(
@@ -542,6 +717,7 @@ pub async fn get_gateway_status(
     Ok(GatewayStatus {
         running: state.running,
         url: state.url.clone(),
+        socket_path: state.socket_path.clone(),
         active_sessions,
         connected_backends,
     })
@@ -568,19 +744,24 @@ pub async fn start_gateway(
         .await
         .map_err(|e| e.to_string())?;
 
-    let url = format!("http://localhost:{}", final_port);
+    // Create gateway config (TCP and/or Unix socket, TLS from settings)
+    let config = build_gateway_config(
+        app_state.settings_repository.clone(),
+        app_state.data_dir(),
+        final_port,
+    )
+    .await;
+    let url = config.base_url();
 
     info!("Starting gateway on {}", url);
 
     // Create dependencies using DI builder pattern
     let dependencies = create_gateway_dependencies(&app_state, app_handle.clone())?;
-
-    // Create gateway config
-    let config = mcpmux_gateway::GatewayConfig {
-        host: "127.0.0.1".to_string(), // Bind address must be IP
-        port: final_port,
-        enable_cors: true,
-    };
+    let socket_path = config
+        .unix_socket
+        .as_ref()
+        .map(|s| s.path.to_string_lossy().to_string());
+    let tcp_enabled = config.tcp_enabled;
 
     // Create self-contained gateway server with DI
     // Gateway will auto-initialize all services and auto-connect enabled servers
@@ -595,6 +776,7 @@ pub async fn start_gateway(
     info!("[Gateway] Getting grant_service from server...");
     let grant_service = server.grant_service();
     info!("[Gateway] Got grant_service: {:p}", &*grant_service);
+    let tool_approval_service = server.tool_approval_service();
 
     // Start domain event bridge (clean architecture)
     start_domain_event_bridge(&app_handle, gw_state.clone());
@@ -605,11 +787,14 @@ pub async fn start_gateway(
     info!("[Gateway] Setting state fields...");
     state.running = true;
     state.url = Some(url.clone());
+    state.socket_path = socket_path;
+    state.tcp_enabled = tcp_enabled;
     state.handle = Some(handle);
     state.gateway_state = Some(gw_state);
     state.pool_service = Some(pool_service);
     state.feature_service = Some(feature_service);
     state.event_emitter = Some(event_emitter);
+    state.tool_approval_service = Some(tool_approval_service);
     info!(
         "[Gateway] About to set grant_service: {:p}",
         &*grant_service
@@ -648,6 +833,7 @@ pub async fn stop_gateway(
 
     state.running = false;
     state.url = None;
+    state.socket_path = None;
 
     Ok(())
 }
@@ -668,6 +854,7 @@ pub async fn restart_gateway(
         }
         state.running = false;
         state.url = None;
+        state.socket_path = None;
     }
 
     // Start with new config
@@ -687,6 +874,36 @@ pub async fn generate_gateway_config(
     // Use branding constant for MCP config key
     let config_key = mcpmux_core::branding::MCP_CONFIG_KEY;
 
+    // Socket-only gateway: clients launch the McpMux executable as a stdio
+    // server that relays to the socket, in the client's own config format
+    if let (false, Some(socket_path)) = (state.tcp_enabled, state.socket_path.as_ref()) {
+        let format = super::config_export::get_format(&client_type)
+            .unwrap_or(mcpmux_core::ConfigFormat::Cursor);
+
+        let gw_state = state
+            .gateway_state
+            .as_ref()
+            .ok_or("Gateway is not running")?;
+        let gw_state = gw_state.read().await;
+        let repo = gw_state
+            .inbound_client_repository()
+            .ok_or("Database not available")?;
+        let client_id = crate::stdio_bridge::register_bridge_client(repo, &client_type)
+            .await
+            .map_err(|e| format!("Failed to register bridge client: {}", e))?;
+
+        let bridge_command = std::env::current_exe()
+            .map_err(|e| format!("Failed to locate the McpMux executable: {}", e))?;
+        let endpoint = mcpmux_core::GatewayEndpoint::UnixSocket {
+            path: socket_path.into(),
+            bridge_command: bridge_command.to_string_lossy().into_owned(),
+            client_id,
+        };
+        return mcpmux_core::ConfigExporter::new()
+            .export_gateway(format, config_key, &endpoint)
+            .map_err(|e| e.to_string());
+    }
+
     let config = match client_type.as_str() {
         "cursor" => {
             serde_json::json!({
@@ -786,14 +1003,22 @@ pub async fn connect_server(
     drop(state); // Release lock before async work
 
     // Build transport config from cached definition + input values
+    let secrets = app_state
+        .space_secret_service
+        .values(&space_uuid)
+        .await
+        .map_err(|e| e.to_string())?;
     let transport = mcpmux_gateway::pool::transport::resolution::build_transport_config(
         &server_definition.transport,
         &installed,
+        &secrets,
         Some(app_state.data_dir()),
-    );
+    )
+    .map_err(|e| e.to_string())?;
 
     // Connect using pool service (manual connect from API)
-    let ctx = ConnectionContext::new(space_uuid, server_id.clone(), transport);
+    let ctx = ConnectionContext::new(space_uuid, server_id.clone(), transport)
+        .with_concurrency_limit(installed.concurrency_limit.clone());
     let result = pool_service.connect_server(&ctx).await;
 
     match result {
@@ -982,6 +1207,15 @@ pub async fn connect_all_enabled_servers(
         .await
         .map_err(|e: anyhow::Error| e.to_string())?;
 
+    // Track results; servers whose transport can't be built count as failed
+    let mut result = BulkConnectResult {
+        connected: 0,
+        reused: 0,
+        failed: 0,
+        oauth_required: 0,
+        errors: vec![],
+    };
+
     // Build list of servers to connect
     let mut servers_to_connect: Vec<(
         InstalledServerInfo,
@@ -999,6 +1233,11 @@ pub async fn connect_all_enabled_servers(
             .list_enabled(&space_id_str)
             .await
             .map_err(|e| e.to_string())?;
+        let secrets = app_state
+            .space_secret_service
+            .values(&space.id)
+            .await
+            .map_err(|e| e.to_string())?;
 
         for installed in installed_servers {
             // Use cached definition from InstalledServer (offline-first approach)
@@ -1044,13 +1283,25 @@ pub async fn connect_all_enabled_servers(
                 server_id: installed.server_id.clone(),
                 requires_oauth,
                 has_credentials,
+                concurrency_limit: installed.concurrency_limit.clone(),
             };
 
-            let transport = mcpmux_gateway::pool::transport::resolution::build_transport_config(
-                &server_definition.transport,
-                &installed,
-                Some(app_state.data_dir()),
-            );
+            let transport =
+                match mcpmux_gateway::pool::transport::resolution::build_transport_config(
+                    &server_definition.transport,
+                    &installed,
+                    &secrets,
+                    Some(app_state.data_dir()),
+                ) {
+                    Ok(transport) => transport,
+                    Err(e) => {
+                        result.failed += 1;
+                        result
+                            .errors
+                            .push(format!("{}: {}", installed.server_id, e));
+                        continue;
+                    }
+                };
 
             servers_to_connect.push((server_info, transport, server_definition, installed));
         }
@@ -1062,20 +1313,13 @@ pub async fn connect_all_enabled_servers(
         spaces.len()
     );
 
-    // Connect servers one by one and track results
-    let mut result = BulkConnectResult {
-        connected: 0,
-        reused: 0,
-        failed: 0,
-        oauth_required: 0,
-        errors: vec![],
-    };
-
+    // Connect servers one by one
     for (server_info, transport, server_definition, installed) in servers_to_connect {
         let space_uuid = server_info.space_id;
         let server_id = server_info.server_id.clone();
 
-        let ctx = ConnectionContext::new(space_uuid, server_id.clone(), transport);
+        let ctx = ConnectionContext::new(space_uuid, server_id.clone(), transport)
+            .with_concurrency_limit(server_info.concurrency_limit.clone());
         match pool_service.connect_server(&ctx).await {
             ConnectionResult::Connected { reused, features } => {
                 if reused {
@@ -1131,6 +1375,18 @@ pub async fn get_pool_stats(
         total_space_server_mappings: stats.connecting_instances
             + stats.failed_instances
             + stats.oauth_pending_instances,
+        queued_requests: stats.queued_requests,
+        request_queues: stats
+            .request_queues
+            .into_iter()
+            .map(|q| RequestQueueResponse {
+                space_id: q.space_id.to_string(),
+                server_id: q.server_id,
+                max_concurrent_requests: q.max_concurrent_requests,
+                active_requests: q.active_requests,
+                queued_requests: q.queued_requests,
+            })
+            .collect(),
     })
 }
 
@@ -1168,4 +1424,17 @@ pub struct PoolStatsResponse {
     pub total_instances: usize,
     pub connected_instances: usize,
     pub total_space_server_mappings: usize,
+    /// Requests waiting for a slot on servers with a concurrency limit
+    pub queued_requests: usize,
+    pub request_queues: Vec<RequestQueueResponse>,
+}
+
+/// Request queue of a server with a concurrency limit
+#[derive(Debug, Serialize)]
+pub struct RequestQueueResponse {
+    pub space_id: String,
+    pub server_id: String,
+    pub max_concurrent_requests: u32,
+    pub active_requests: usize,
+    pub queued_requests: usize,
 }
@@ -3,36 +3,52 @@
 //! This module contains all commands that can be invoked from the frontend.
 //! Commands are organized by feature area.
 
+pub mod argument_policy;
 pub mod client;
 pub mod client_custom_features;
 pub mod client_install;
 pub mod config_export;
+pub mod config_import;
 pub mod credential;
 pub mod feature_members;
+pub mod feature_override;
 pub mod feature_set;
 pub mod gateway;
 pub mod logs;
 pub mod oauth;
+pub mod rate_limit;
 pub mod server;
 pub mod server_discovery;
 pub mod server_feature;
 pub mod server_manager;
 pub mod settings;
 pub mod space;
+pub mod space_bundle;
+pub mod space_secret;
+pub mod tool_approval;
+pub mod workspace_manifest;
 
 // Re-export commands for convenience
+pub use argument_policy::*;
 pub use client::*;
 pub use client_custom_features::*;
 pub use client_install::*;
 pub use config_export::*;
+pub use config_import::*;
 pub use feature_members::*;
+pub use feature_override::*;
 pub use feature_set::*;
 pub use gateway::*;
 pub use logs::*;
 pub use oauth::*;
+pub use rate_limit::*;
 pub use server::*;
 pub use server_discovery::*;
 pub use server_feature::*;
 pub use server_manager::*;
 pub use settings::*;
 pub use space::*;
+pub use space_bundle::*;
+pub use space_secret::*;
+pub use tool_approval::*;
+pub use workspace_manifest::*;
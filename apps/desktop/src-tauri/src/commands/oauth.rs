@@ -27,7 +27,8 @@
 use std::collections::HashMap;
 use std::sync::Arc;
 
-use mcpmux_core::branding;
+use mcpmux_core::{branding, FeatureRule, ServerFeature};
+use mcpmux_storage::{ClientGrant, GrantLimits};
 use serde::{Deserialize, Serialize};
 use tauri::{Emitter, Manager, State};
 use tokio::sync::RwLock;
@@ -686,6 +687,7 @@ pub async fn get_oauth_clients(
                 metadata_cache_ttl: client.metadata_cache_ttl,
                 connection_mode: client.connection_mode,
                 locked_space_id: client.locked_space_id,
+                meta_tools: client.meta_tools,
                 last_seen: client.last_seen,
                 created_at: client.created_at,
                 has_active_tokens: false, // TODO: Check if client has active tokens
@@ -761,6 +763,7 @@ pub struct OAuthClientInfo {
     // MCP client preferences
     pub connection_mode: String,
     pub locked_space_id: Option<String>,
+    pub meta_tools: bool,
     pub last_seen: Option<String>,
     pub created_at: String,
     pub has_active_tokens: bool,
@@ -772,6 +775,9 @@ pub struct UpdateClientSettingsRequest {
     pub client_alias: Option<String>,
     pub connection_mode: Option<String>,
     pub locked_space_id: Option<String>,
+    /// tools/list returns only search_tools and invoke_tool
+    #[serde(default)]
+    pub meta_tools: Option<bool>,
 }
 
 /// Update an OAuth client's settings (direct service access)
@@ -799,6 +805,7 @@ pub async fn update_oauth_client(
         settings.client_alias,
         settings.connection_mode,
         settings.locked_space_id.map(Some),
+        settings.meta_tools,
     )
     .await
     .map_err(|e| format!("Failed to update client: {}", e))?;
@@ -835,6 +842,7 @@ pub async fn update_oauth_client(
         metadata_cache_ttl: updated_client.metadata_cache_ttl,
         connection_mode: updated_client.connection_mode,
         locked_space_id: updated_client.locked_space_id,
+        meta_tools: updated_client.meta_tools,
         last_seen: updated_client.last_seen,
         created_at: updated_client.created_at,
         has_active_tokens: false,
@@ -884,7 +892,32 @@ pub async fn get_oauth_client_grants(
     Ok(grants)
 }
 
+/// Get an OAuth client's explicit grants in a space with their expiry and call limits
+#[tauri::command]
+pub async fn get_oauth_client_grant_details(
+    gateway_state: State<'_, Arc<RwLock<GatewayAppState>>>,
+    client_id: String,
+    space_id: String,
+) -> Result<Vec<ClientGrant>, String> {
+    let app_state = gateway_state.read().await;
+    let Some(ref gw_state) = app_state.gateway_state else {
+        return Err("Gateway not running".to_string());
+    };
+
+    let state = gw_state.read().await;
+    let Some(repo) = state.inbound_client_repository() else {
+        return Err("Database not available".to_string());
+    };
+
+    repo.list_grants_for_space(&client_id, &space_id)
+        .await
+        .map_err(|e| format!("Failed to get grants: {}", e))
+}
+
 /// Grant a feature set to an OAuth client in a specific space
+///
+/// `expires_at` (RFC 3339) and `max_calls` optionally limit the grant; it is
+/// revoked automatically when either is reached.
 #[tauri::command]
 pub async fn grant_oauth_client_feature_set(
     app_handle: tauri::AppHandle,
@@ -892,6 +925,8 @@ pub async fn grant_oauth_client_feature_set(
     client_id: String,
     space_id: String,
     feature_set_id: String,
+    expires_at: Option<String>,
+    max_calls: Option<u32>,
 ) -> Result<(), String> {
     info!("[OAuth] grant_oauth_client_feature_set called: client_id={}, space_id={}, feature_set_id={}", 
         client_id, space_id, feature_set_id);
@@ -918,9 +953,21 @@ pub async fn grant_oauth_client_feature_set(
         return Err("Gateway not running".to_string());
     };
 
+    let limits = GrantLimits {
+        expires_at: expires_at
+            .map(|at| {
+                chrono::DateTime::parse_from_rfc3339(&at)
+                    .map(|dt| dt.with_timezone(&chrono::Utc))
+                    .map_err(|e| format!("Invalid expiry time '{}': {}", at, e))
+            })
+            .transpose()?,
+        max_calls,
+    };
+
     // Single call handles: DB update + validation + automatic notifications (DRY!)
+    // Always upsert so re-granting without limits clears any previous ones.
     grant_service
-        .grant_feature_set(&client_id, &space_id, &feature_set_id)
+        .grant_feature_set_with_limits(&client_id, &space_id, &feature_set_id, &limits)
         .await
         .map_err(|e| format!("Failed to grant feature set: {}", e))?;
 
@@ -974,6 +1021,17 @@ pub async fn revoke_oauth_client_feature_set(
     Ok(())
 }
 
+/// A rule member reachable from a client's grants, with what it currently matches
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ResolvedRule {
+    pub feature_set_id: String,
+    pub expression: String,
+    /// "include" or "exclude"
+    pub mode: String,
+    /// Qualified names of the space's available features the rule matches
+    pub matches: Vec<String>,
+}
+
 /// Resolved client features response
 #[derive(Debug, Serialize, Deserialize)]
 pub struct ResolvedClientFeatures {
@@ -982,6 +1040,9 @@ pub struct ResolvedClientFeatures {
     pub tools: Vec<serde_json::Value>,
     pub prompts: Vec<serde_json::Value>,
     pub resources: Vec<serde_json::Value>,
+    /// Rule members in the granted feature sets (preview of dynamic membership)
+    #[serde(default)]
+    pub rules: Vec<ResolvedRule>,
 }
 
 /// Get resolved features for an OAuth client in a specific space
@@ -1071,39 +1132,56 @@ pub async fn get_oauth_client_resolved_features(
         resources.len()
     );
 
-    // Convert to response format
-    let tools_response: Vec<_> = tools
-        .iter()
-        .map(|f| {
-            serde_json::json!({
-                "name": f.feature_name,
-                "description": f.description,
-                "server_id": f.server_id,
+    // Preview rule members against the space's current features
+    let rule_members = feature_service
+        .get_rules_for_grants(&feature_set_ids)
+        .await
+        .unwrap_or_default();
+    let rules: Vec<(FeatureRule, ResolvedRule)> = if rule_members.is_empty() {
+        Vec::new()
+    } else {
+        let space_features = feature_service
+            .get_all_features_for_space(&space_id, None)
+            .await
+            .unwrap_or_default();
+        rule_members
+            .into_iter()
+            .filter_map(|member| {
+                let rule = FeatureRule::parse(&member.member_id).ok()?;
+                let matches = space_features
+                    .iter()
+                    .filter(|f| rule.matches(f))
+                    .map(|f| f.qualified_name())
+                    .collect();
+                let preview = ResolvedRule {
+                    feature_set_id: member.feature_set_id,
+                    expression: member.member_id,
+                    mode: member.mode.as_str().to_string(),
+                    matches,
+                };
+                Some((rule, preview))
             })
-        })
-        .collect();
+            .collect()
+    };
 
-    let prompts_response: Vec<_> = prompts
-        .iter()
-        .map(|f| {
-            serde_json::json!({
-                "name": f.feature_name,
-                "description": f.description,
-                "server_id": f.server_id,
-            })
+    // Convert to response format; `matched_rules` lists the include rules admitting each feature
+    let to_response = |f: &ServerFeature| {
+        let matched_rules: Vec<&str> = rules
+            .iter()
+            .filter(|(rule, preview)| preview.mode == "include" && rule.matches(f))
+            .map(|(rule, _)| rule.as_str())
+            .collect();
+        serde_json::json!({
+            "name": f.feature_name,
+            "description": f.description,
+            "server_id": f.server_id,
+            "matched_rules": matched_rules,
         })
-        .collect();
+    };
 
-    let resources_response: Vec<_> = resources
-        .iter()
-        .map(|f| {
-            serde_json::json!({
-                "name": f.feature_name,
-                "description": f.description,
-                "server_id": f.server_id,
-            })
-        })
-        .collect();
+    let tools_response: Vec<_> = tools.iter().map(to_response).collect();
+    let prompts_response: Vec<_> = prompts.iter().map(to_response).collect();
+    let resources_response: Vec<_> = resources.iter().map(to_response).collect();
 
     Ok(ResolvedClientFeatures {
         space_id,
@@ -1111,9 +1189,184 @@ pub async fn get_oauth_client_resolved_features(
         tools: tools_response,
         prompts: prompts_response,
         resources: resources_response,
+        rules: rules.into_iter().map(|(_, preview)| preview).collect(),
+    })
+}
+
+/// A tool as seen through a client's tool budget
+#[derive(Debug, Serialize, Deserialize)]
+pub struct BudgetedTool {
+    /// Qualified tool name (prefix_tool_name)
+    pub name: String,
+    /// Portable feature key (what pins refer to)
+    pub key: String,
+    pub server_id: String,
+    /// Estimated context tokens for the tool definition
+    pub estimated_tokens: usize,
+    /// Calls recorded in this space
+    pub call_count: u64,
+    pub pinned: bool,
+    /// Whether the tool is returned by tools/list under the budget
+    pub exposed: bool,
+}
+
+/// Tool budget for an OAuth client in a space, with estimated token cost
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ClientToolBudgetInfo {
+    pub max_tools: Option<u32>,
+    pub pinned_tools: Vec<String>,
+    pub server_priority: Vec<String>,
+    pub total_tools: usize,
+    pub exposed_tools: usize,
+    pub total_estimated_tokens: usize,
+    pub exposed_estimated_tokens: usize,
+    pub tools: Vec<BudgetedTool>,
+}
+
+/// Get an OAuth client's tool budget and the estimated token cost of its tools
+///
+/// Token costs are estimated from the stored tool definitions (`raw_json`).
+#[tauri::command]
+pub async fn get_oauth_client_tool_budget(
+    gateway_state: State<'_, Arc<RwLock<GatewayAppState>>>,
+    app_state: State<'_, crate::AppState>,
+    client_id: String,
+    space_id: String,
+) -> Result<ClientToolBudgetInfo, String> {
+    let gw_app_state = gateway_state.read().await;
+
+    let Some(ref gw_state) = gw_app_state.gateway_state else {
+        return Err("Gateway not running".to_string());
+    };
+    let Some(ref feature_service) = gw_app_state.feature_service else {
+        return Err("Feature service not available".to_string());
+    };
+
+    let state = gw_state.read().await;
+    let (Some(repo), Some(usage_repo)) = (
+        state.inbound_client_repository(),
+        state.tool_usage_repository(),
+    ) else {
+        return Err("Database not available".to_string());
+    };
+
+    let budget = repo
+        .get_tool_budget(&client_id)
+        .await
+        .map_err(|e| format!("Failed to get tool budget: {}", e))?
+        .unwrap_or_default();
+    let usage = usage_repo
+        .get_usage_for_space(&space_id)
+        .await
+        .map_err(|e| format!("Failed to get tool usage: {}", e))?;
+
+    // Effective grants: explicit grants + default feature set (same as MCP handlers)
+    let mut feature_set_ids = repo
+        .get_grants_for_space(&client_id, &space_id)
+        .await
+        .map_err(|e| format!("Failed to get grants: {}", e))?;
+    if let Ok(Some(default_fs)) = app_state
+        .feature_set_repository
+        .get_default_for_space(&space_id)
+        .await
+    {
+        if !feature_set_ids.contains(&default_fs.id) {
+            feature_set_ids.push(default_fs.id);
+        }
+    }
+    drop(state);
+
+    let tools = feature_service
+        .get_tools_for_grants(&space_id, &feature_set_ids)
+        .await
+        .unwrap_or_default();
+    let exposed: std::collections::HashSet<uuid::Uuid> =
+        mcpmux_gateway::services::select_tools(tools.clone(), &budget, &usage)
+            .iter()
+            .map(|f| f.id)
+            .collect();
+
+    let tools: Vec<BudgetedTool> = tools
+        .iter()
+        .map(|f| {
+            let key = f.portable_key();
+            BudgetedTool {
+                pinned: budget.pinned_tools.contains(&key),
+                name: f.qualified_name(),
+                key,
+                server_id: f.server_id.clone(),
+                estimated_tokens: mcpmux_gateway::services::estimate_tool_tokens(f),
+                call_count: usage
+                    .get(&(f.server_id.clone(), f.feature_name.clone()))
+                    .map(|u| u.call_count)
+                    .unwrap_or(0),
+                exposed: exposed.contains(&f.id),
+            }
+        })
+        .collect();
+
+    let exposed_tools: Vec<&BudgetedTool> = tools.iter().filter(|t| t.exposed).collect();
+    Ok(ClientToolBudgetInfo {
+        max_tools: budget.max_tools,
+        pinned_tools: budget.pinned_tools,
+        server_priority: budget.server_priority,
+        total_tools: tools.len(),
+        exposed_tools: exposed_tools.len(),
+        total_estimated_tokens: tools.iter().map(|t| t.estimated_tokens).sum(),
+        exposed_estimated_tokens: exposed_tools.iter().map(|t| t.estimated_tokens).sum(),
+        tools,
     })
 }
 
+/// Update an OAuth client's tool budget
+///
+/// `pinned_tools` are portable feature keys; `server_priority` are server IDs.
+#[tauri::command]
+pub async fn update_oauth_client_tool_budget(
+    gateway_state: State<'_, Arc<RwLock<GatewayAppState>>>,
+    client_id: String,
+    max_tools: Option<u32>,
+    pinned_tools: Vec<String>,
+    server_priority: Vec<String>,
+) -> Result<(), String> {
+    if max_tools == Some(0) {
+        return Err("Maximum tool count must be positive".to_string());
+    }
+
+    let app_state = gateway_state.read().await;
+    let Some(ref gw_state) = app_state.gateway_state else {
+        return Err("Gateway not running".to_string());
+    };
+
+    let state = gw_state.read().await;
+    let Some(repo) = state.inbound_client_repository() else {
+        return Err("Database not available".to_string());
+    };
+
+    repo.set_tool_budget(
+        &client_id,
+        &mcpmux_storage::ClientToolBudget {
+            max_tools,
+            pinned_tools,
+            server_priority,
+        },
+    )
+    .await
+    .map_err(|e| format!("Failed to update tool budget: {}", e))?;
+
+    info!(
+        "[OAuth] Updated tool budget for client {}: max={:?}",
+        client_id, max_tools
+    );
+
+    // Emit domain event (applies on the client's next tools/list)
+    state.emit_domain_event(mcpmux_core::DomainEvent::ClientUpdated {
+        client_id: client_id.clone(),
+    });
+
+    Ok(())
+}
+
 /// Delete an OAuth client (direct service access)
 #[tauri::command]
 pub async fn delete_oauth_client(
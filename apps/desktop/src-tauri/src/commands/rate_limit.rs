@@ -0,0 +1,104 @@
+//! Rate limit commands
+//!
+//! IPC commands for per-client rate limits and daily quotas on tool calls,
+//! with their current usage.
+
+use chrono::Utc;
+use mcpmux_core::ClientRateLimit;
+use mcpmux_storage::RateLimitRepository;
+use serde::Serialize;
+use tauri::State;
+use tracing::info;
+
+use crate::state::AppState;
+
+/// A rate limit with its usage as of now
+#[derive(Debug, Serialize)]
+pub struct ClientRateLimitStatus {
+    #[serde(flatten)]
+    pub limit: ClientRateLimit,
+    /// Calls left in the token bucket (None without a per-minute limit)
+    pub available_calls: Option<u32>,
+    /// Calls left today (None without a daily quota)
+    pub quota_remaining: Option<u32>,
+}
+
+/// List a client's rate limits with their current usage
+#[tauri::command]
+pub async fn list_client_rate_limits(
+    client_id: String,
+    state: State<'_, AppState>,
+) -> Result<Vec<ClientRateLimitStatus>, String> {
+    let limits = RateLimitRepository::new(state.database())
+        .list_for_client(&client_id)
+        .await
+        .map_err(|e| format!("Failed to list rate limits: {}", e))?;
+
+    let now = Utc::now();
+    Ok(limits
+        .into_iter()
+        .map(|limit| ClientRateLimitStatus {
+            available_calls: limit.available_calls(now),
+            quota_remaining: limit.quota_remaining(now),
+            limit,
+        })
+        .collect())
+}
+
+/// Set a client's rate limit for a server, or for one tool when `tool_name` is given
+#[tauri::command]
+pub async fn set_client_rate_limit(
+    client_id: String,
+    server_id: String,
+    tool_name: Option<String>,
+    per_minute: Option<u32>,
+    burst: Option<u32>,
+    daily_quota: Option<u32>,
+    state: State<'_, AppState>,
+) -> Result<(), String> {
+    let limit = ClientRateLimit {
+        client_id,
+        server_id,
+        tool_name: tool_name.filter(|t| !t.is_empty()),
+        per_minute,
+        burst,
+        daily_quota,
+        usage: None,
+    };
+    limit.validate().map_err(|e| e.to_string())?;
+
+    info!(
+        "[RateLimit] Setting limit for client {} on {}: {:?}/min, burst {:?}, {:?}/day",
+        limit.client_id,
+        limit.target(),
+        limit.per_minute,
+        limit.burst,
+        limit.daily_quota
+    );
+    RateLimitRepository::new(state.database())
+        .set(&limit)
+        .await
+        .map_err(|e| format!("Failed to save rate limit: {}", e))
+}
+
+/// Remove a client's rate limit for a server or tool
+#[tauri::command]
+pub async fn delete_client_rate_limit(
+    client_id: String,
+    server_id: String,
+    tool_name: Option<String>,
+    state: State<'_, AppState>,
+) -> Result<(), String> {
+    info!(
+        "[RateLimit] Removing limit for client {} on {} {:?}",
+        client_id, server_id, tool_name
+    );
+    RateLimitRepository::new(state.database())
+        .delete(
+            &client_id,
+            &server_id,
+            tool_name.as_deref().filter(|t| !t.is_empty()),
+        )
+        .await
+        .map_err(|e| format!("Failed to remove rate limit: {}", e))
+}
@@ -2,7 +2,7 @@
 
 use crate::AppState;
 use mcpmux_core::application::ServerAppService;
-use mcpmux_core::domain::InstalledServer;
+use mcpmux_core::domain::{ConcurrencyLimit, InstalledServer};
 use std::collections::HashMap;
 use std::sync::Arc;
 use tauri::State;
@@ -127,6 +127,91 @@ pub async fn set_server_oauth_connected(
         .map_err(|e| e.to_string())
 }
 
+/// Launch a server with the read-only arguments and environment it declares
+#[tauri::command]
+pub async fn set_server_read_only(
+    app_service: State<'_, Arc<RwLock<Option<ServerAppService>>>>,
+    id: String,
+    space_id: String,
+    read_only: bool,
+) -> Result<InstalledServer, String> {
+    let service_lock = app_service.read().await;
+    let service = service_lock
+        .as_ref()
+        .ok_or("ServerAppService not initialized")?;
+
+    let space_uuid = uuid::Uuid::parse_str(&space_id).map_err(|e| e.to_string())?;
+
+    service
+        .set_read_only(space_uuid, &id, read_only)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Limit how many requests are in flight to a server (None removes the limit)
+#[tauri::command]
+pub async fn set_server_concurrency_limit(
+    app_service: State<'_, Arc<RwLock<Option<ServerAppService>>>>,
+    id: String,
+    space_id: String,
+    concurrency_limit: Option<ConcurrencyLimit>,
+) -> Result<InstalledServer, String> {
+    let service_lock = app_service.read().await;
+    let service = service_lock
+        .as_ref()
+        .ok_or("ServerAppService not initialized")?;
+
+    let space_uuid = uuid::Uuid::parse_str(&space_id).map_err(|e| e.to_string())?;
+
+    service
+        .set_concurrency_limit(space_uuid, &id, concurrency_limit)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Send tool calls without checking arguments against the server's schemas
+#[tauri::command]
+pub async fn set_server_skip_argument_validation(
+    app_service: State<'_, Arc<RwLock<Option<ServerAppService>>>>,
+    id: String,
+    space_id: String,
+    skip: bool,
+) -> Result<InstalledServer, String> {
+    let service_lock = app_service.read().await;
+    let service = service_lock
+        .as_ref()
+        .ok_or("ServerAppService not initialized")?;
+
+    let space_uuid = uuid::Uuid::parse_str(&space_id).map_err(|e| e.to_string())?;
+
+    service
+        .set_skip_argument_validation(space_uuid, &id, skip)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Set the working directory and .env file a stdio server launches with
+#[tauri::command]
+pub async fn set_server_launch_overrides(
+    app_service: State<'_, Arc<RwLock<Option<ServerAppService>>>>,
+    id: String,
+    space_id: String,
+    cwd: Option<String>,
+    env_file: Option<String>,
+) -> Result<InstalledServer, String> {
+    let service_lock = app_service.read().await;
+    let service = service_lock
+        .as_ref()
+        .ok_or("ServerAppService not initialized")?;
+
+    let space_uuid = uuid::Uuid::parse_str(&space_id).map_err(|e| e.to_string())?;
+
+    service
+        .set_launch_overrides(space_uuid, &id, cwd, env_file)
+        .await
+        .map_err(|e| e.to_string())
+}
+
 #[tauri::command]
 pub async fn save_server_inputs(
     app_service: State<'_, Arc<RwLock<Option<ServerAppService>>>>,
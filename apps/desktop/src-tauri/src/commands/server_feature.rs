@@ -2,10 +2,18 @@
 //!
 //! IPC commands for querying discovered MCP features (tools, prompts, resources).
 
+use std::sync::Arc;
+
+use mcpmux_core::{
+    FeatureDefinitionVersion, ServerFeatureRepository as CoreServerFeatureRepository,
+};
 use mcpmux_storage::{FeatureType, ServerFeature, ServerFeatureRepository};
 use serde::Serialize;
 use tauri::State;
+use tokio::sync::RwLock;
+use tracing::info;
 
+use super::gateway::GatewayAppState;
 use crate::state::AppState;
 
 /// Response for server feature listing
@@ -22,6 +30,8 @@ pub struct ServerFeatureResponse {
     pub discovered_at: String,
     pub last_seen_at: String,
     pub is_available: bool,
+    /// Changed definition awaiting approval (feature is hidden from clients)
+    pub pending_definition: Option<serde_json::Value>,
 }
 
 impl From<ServerFeature> for ServerFeatureResponse {
@@ -38,6 +48,7 @@ impl From<ServerFeature> for ServerFeatureResponse {
             discovered_at: f.discovered_at.to_rfc3339(),
             last_seen_at: f.last_seen_at.to_rfc3339(),
             is_available: f.is_available,
+            pending_definition: f.pending_raw_json,
         }
     }
 }
@@ -130,6 +141,57 @@ pub async fn get_server_feature(
     Ok(feature.map(Into::into))
 }
 
+/// Approve a feature's changed definition, lifting its quarantine.
+///
+/// Goes through the gateway when it is running so connected clients are
+/// notified that the feature is available again.
+#[tauri::command]
+pub async fn approve_server_feature_definition(
+    id: String,
+    state: State<'_, AppState>,
+    gateway_state: State<'_, Arc<RwLock<GatewayAppState>>>,
+) -> Result<ServerFeatureResponse, String> {
+    let feature_id = uuid::Uuid::parse_str(&id).map_err(|e| format!("Invalid feature ID: {e}"))?;
+
+    let feature_service = gateway_state.read().await.feature_service.clone();
+    let approved = match feature_service {
+        Some(service) => service
+            .approve_pending_definition(&feature_id)
+            .await
+            .map_err(|e| format!("Failed to approve definition: {e}"))?,
+        None => state
+            .server_feature_repository_core
+            .approve_pending_definition(&feature_id)
+            .await
+            .map_err(|e| format!("Failed to approve definition: {e}"))?,
+    };
+    let approved = approved.ok_or_else(|| format!("Feature not found: {id}"))?;
+
+    info!(
+        "[ServerFeature] Approved definition of {} '{}' on {}",
+        approved.feature_type.as_str(),
+        approved.feature_name,
+        approved.server_id
+    );
+
+    Ok(ServerFeature::from(approved).into())
+}
+
+/// List the definition history of a feature (oldest first).
+#[tauri::command]
+pub async fn list_server_feature_versions(
+    id: String,
+    state: State<'_, AppState>,
+) -> Result<Vec<FeatureDefinitionVersion>, String> {
+    let feature_id = uuid::Uuid::parse_str(&id).map_err(|e| format!("Invalid feature ID: {e}"))?;
+
+    state
+        .server_feature_repository_core
+        .list_definition_versions(&feature_id)
+        .await
+        .map_err(|e| e.to_string())
+}
+
 /// Seed server features for E2E testing.
 ///
 /// Accepts an array of feature definitions and upserts them into the database.
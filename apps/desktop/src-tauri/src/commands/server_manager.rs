@@ -9,7 +9,8 @@
 use crate::AppState;
 use mcpmux_gateway::pool::transport::resolution::build_transport_config; // Import from gateway
 use mcpmux_gateway::{
-    ConnectionContext, ConnectionResult, ConnectionStatus, ServerKey, ServerManager,
+    ConnectionContext, ConnectionResult, ConnectionStatus, ResolvedTransport, ServerKey,
+    ServerManager,
 };
 use serde::Serialize;
 use std::collections::HashMap;
@@ -39,6 +40,23 @@ pub struct ServerManagerState {
 // Event bridge moved to unified gateway event system in commands/gateway.rs
 // See: start_gateway_event_bridge() which handles all backend→frontend events
 
+/// Build the transport config of an installed server, resolving the space
+/// secrets its input values reference
+async fn resolve_transport(
+    app_state: &AppState,
+    definition: &mcpmux_core::ServerDefinition,
+    installed: &mcpmux_core::InstalledServer,
+) -> anyhow::Result<ResolvedTransport> {
+    let space_id = Uuid::parse_str(&installed.space_id)?;
+    let secrets = app_state.space_secret_service.values(&space_id).await?;
+    build_transport_config(
+        &definition.transport,
+        installed,
+        &secrets,
+        Some(app_state.data_dir()),
+    )
+}
+
 /// Get all server statuses for a space
 #[tauri::command]
 pub async fn get_server_statuses(
@@ -131,15 +149,18 @@ pub async fn enable_server_v2(
     manager.set_connecting(&key).await;
 
     // Build transport config
-    let transport = build_transport_config(
-        &server_definition.transport,
-        &installed,
-        Some(app_state.data_dir()),
-    );
+    let transport = match resolve_transport(&app_state, &server_definition, &installed).await {
+        Ok(transport) => transport,
+        Err(e) => {
+            manager.set_error(&key, e.to_string()).await;
+            return Err(e.to_string());
+        }
+    };
 
     // Attempt connection with auto_reconnect=true to avoid starting OAuth flow
     // If OAuth is needed, we just set AuthRequired and let user click Connect
-    let ctx = ConnectionContext::auto(space_uuid, server_id.clone(), transport);
+    let ctx = ConnectionContext::auto(space_uuid, server_id.clone(), transport)
+        .with_concurrency_limit(installed.concurrency_limit.clone());
     let result = pool_service.connect_server(&ctx).await;
 
     match result {
@@ -320,12 +341,15 @@ pub async fn start_auth_v2(
     manager.set_connecting(&key).await;
 
     // Build transport config and attempt connection (manual connect from user clicking Connect button)
-    let transport = build_transport_config(
-        &server_definition.transport,
-        &installed,
-        Some(app_state.data_dir()),
-    );
-    let ctx = ConnectionContext::new(space_uuid, server_id.clone(), transport);
+    let transport = match resolve_transport(&app_state, &server_definition, &installed).await {
+        Ok(transport) => transport,
+        Err(e) => {
+            manager.set_error(&key, e.to_string()).await;
+            return Err(e.to_string());
+        }
+    };
+    let ctx = ConnectionContext::new(space_uuid, server_id.clone(), transport)
+        .with_concurrency_limit(installed.concurrency_limit.clone());
     let result = pool_service.connect_server(&ctx).await;
 
     match result {
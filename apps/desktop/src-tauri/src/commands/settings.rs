@@ -1,5 +1,6 @@
 //! Settings commands for auto-start and system tray behavior
 
+use mcpmux_core::{AppSettingsService, ClientCompatProfile};
 use serde::{Deserialize, Serialize};
 use tauri::State;
 use tauri_plugin_autostart::AutoLaunchManager;
@@ -122,6 +123,248 @@ pub async fn update_startup_settings(
     Ok(())
 }
 
+/// Gateway listener settings (TCP and/or Unix domain socket)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GatewayListenerSettings {
+    /// Whether the gateway serves on its loopback TCP port
+    pub tcp_enabled: bool,
+    /// Unix domain socket path (None = no socket listener)
+    pub unix_socket_path: Option<String>,
+    /// Socket permission bits as an octal string (e.g. "600", "660")
+    pub unix_socket_mode: String,
+}
+
+/// Get gateway listener settings
+#[tauri::command]
+pub async fn get_gateway_listener_settings(
+    app_state: State<'_, AppState>,
+) -> Result<GatewayListenerSettings, String> {
+    let settings = AppSettingsService::new(app_state.settings_repository.clone());
+
+    Ok(GatewayListenerSettings {
+        tcp_enabled: settings.get_gateway_tcp_enabled().await,
+        unix_socket_path: settings.get_gateway_unix_socket_path().await,
+        unix_socket_mode: format!("{:o}", settings.get_gateway_unix_socket_mode().await),
+    })
+}
+
+/// Update gateway listener settings (applied on next gateway start)
+#[tauri::command]
+pub async fn update_gateway_listener_settings(
+    settings: GatewayListenerSettings,
+    app_state: State<'_, AppState>,
+) -> Result<(), String> {
+    info!(
+        "[Settings] Updating gateway listener settings: {:?}",
+        settings
+    );
+
+    let unix_socket_path = settings
+        .unix_socket_path
+        .as_deref()
+        .map(str::trim)
+        .filter(|p| !p.is_empty());
+
+    if unix_socket_path.is_some() && !cfg!(unix) {
+        return Err("Unix domain sockets are not supported on this platform".to_string());
+    }
+    if !settings.tcp_enabled && unix_socket_path.is_none() {
+        return Err("TCP can only be disabled when a Unix socket path is set".to_string());
+    }
+    if let Some(path) = unix_socket_path {
+        if !std::path::Path::new(path).is_absolute() {
+            return Err(format!("Socket path must be absolute: {}", path));
+        }
+    }
+
+    let mode = u32::from_str_radix(settings.unix_socket_mode.trim(), 8)
+        .ok()
+        .filter(|m| *m <= 0o777)
+        .ok_or_else(|| format!("Invalid socket mode: {}", settings.unix_socket_mode))?;
+
+    let service = AppSettingsService::new(app_state.settings_repository.clone());
+    service
+        .set_gateway_unix_socket_path(unix_socket_path)
+        .await
+        .map_err(|e| format!("Failed to save socket path: {}", e))?;
+    service
+        .set_gateway_unix_socket_mode(mode)
+        .await
+        .map_err(|e| format!("Failed to save socket mode: {}", e))?;
+    service
+        .set_gateway_tcp_enabled(settings.tcp_enabled)
+        .await
+        .map_err(|e| format!("Failed to save tcp_enabled setting: {}", e))?;
+
+    Ok(())
+}
+
+/// Gateway network exposure settings (bind address, TLS, public URL)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GatewayTlsSettings {
+    /// TCP bind address (e.g. "127.0.0.1", "0.0.0.0")
+    pub bind_host: String,
+    /// Externally reachable base URL used in OAuth metadata
+    pub public_url: Option<String>,
+    /// Whether the gateway terminates TLS
+    pub tls_enabled: bool,
+    /// PEM certificate chain path (None = self-signed)
+    pub cert_path: Option<String>,
+    /// PEM private key path (None = self-signed)
+    pub key_path: Option<String>,
+    /// Client certificate verification: "off", "optional" or "required"
+    pub client_auth: String,
+    /// PEM bundle of CAs trusted for client certificates
+    pub client_ca_path: Option<String>,
+}
+
+/// Get gateway network exposure settings
+#[tauri::command]
+pub async fn get_gateway_tls_settings(
+    app_state: State<'_, AppState>,
+) -> Result<GatewayTlsSettings, String> {
+    let settings = AppSettingsService::new(app_state.settings_repository.clone());
+    let cert_paths = settings.get_gateway_tls_cert_paths().await;
+
+    Ok(GatewayTlsSettings {
+        bind_host: settings.get_gateway_bind_host().await,
+        public_url: settings.get_gateway_public_url().await,
+        tls_enabled: settings.get_gateway_tls_enabled().await,
+        cert_path: cert_paths.as_ref().map(|(cert, _)| cert.clone()),
+        key_path: cert_paths.map(|(_, key)| key),
+        client_auth: settings.get_gateway_tls_client_auth().await,
+        client_ca_path: settings.get_gateway_tls_client_ca_path().await,
+    })
+}
+
+/// Update gateway network exposure settings (applied on next gateway start)
+#[tauri::command]
+pub async fn update_gateway_tls_settings(
+    settings: GatewayTlsSettings,
+    app_state: State<'_, AppState>,
+) -> Result<(), String> {
+    info!("[Settings] Updating gateway TLS settings: {:?}", settings);
+
+    fn non_empty(value: &Option<String>) -> Option<&str> {
+        value.as_deref().map(str::trim).filter(|v| !v.is_empty())
+    }
+
+    let bind_host: std::net::IpAddr = settings
+        .bind_host
+        .trim()
+        .parse()
+        .map_err(|_| format!("Bind address must be an IP address: {}", settings.bind_host))?;
+    if !bind_host.is_loopback() && !settings.tls_enabled {
+        return Err("TLS must be enabled to expose the gateway beyond localhost".to_string());
+    }
+
+    let public_url = non_empty(&settings.public_url);
+    if let Some(url) = public_url {
+        let parsed = url::Url::parse(url).map_err(|e| format!("Invalid public URL: {}", e))?;
+        if !matches!(parsed.scheme(), "http" | "https") {
+            return Err(format!("Public URL must be http(s): {}", url));
+        }
+    }
+
+    let cert_paths = match (
+        non_empty(&settings.cert_path),
+        non_empty(&settings.key_path),
+    ) {
+        (Some(cert), Some(key)) => Some((cert, key)),
+        (None, None) => None,
+        _ => return Err("Certificate and key paths must be set together".to_string()),
+    };
+
+    let client_auth = mcpmux_gateway::ClientAuthMode::parse(settings.client_auth.trim())
+        .ok_or_else(|| format!("Invalid client auth mode: {}", settings.client_auth))?;
+    let client_ca_path = non_empty(&settings.client_ca_path);
+    if client_auth != mcpmux_gateway::ClientAuthMode::Off && client_ca_path.is_none() {
+        return Err("Client certificate verification requires a CA bundle".to_string());
+    }
+
+    let service = AppSettingsService::new(app_state.settings_repository.clone());
+    service
+        .set_gateway_bind_host(Some(&bind_host.to_string()))
+        .await
+        .map_err(|e| format!("Failed to save bind address: {}", e))?;
+    service
+        .set_gateway_public_url(public_url)
+        .await
+        .map_err(|e| format!("Failed to save public URL: {}", e))?;
+    service
+        .set_gateway_tls_enabled(settings.tls_enabled)
+        .await
+        .map_err(|e| format!("Failed to save tls_enabled setting: {}", e))?;
+    service
+        .set_gateway_tls_cert_paths(cert_paths)
+        .await
+        .map_err(|e| format!("Failed to save certificate paths: {}", e))?;
+    service
+        .set_gateway_tls_client_auth(client_auth.as_str())
+        .await
+        .map_err(|e| format!("Failed to save client auth mode: {}", e))?;
+    service
+        .set_gateway_tls_client_ca_path(client_ca_path)
+        .await
+        .map_err(|e| format!("Failed to save client CA path: {}", e))?;
+
+    Ok(())
+}
+
+/// Client compatibility profiles (user-defined and built-in)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ClientCompatSettings {
+    /// User-defined profiles (checked first; replace built-ins by name)
+    pub profiles: Vec<ClientCompatProfile>,
+    /// Profiles shipped with the gateway
+    pub builtin_profiles: Vec<ClientCompatProfile>,
+}
+
+/// Get client compatibility profiles
+#[tauri::command]
+pub async fn get_client_compat_profiles(
+    app_state: State<'_, AppState>,
+) -> Result<ClientCompatSettings, String> {
+    let settings = AppSettingsService::new(app_state.settings_repository.clone());
+
+    Ok(ClientCompatSettings {
+        profiles: settings.get_client_compat_profiles().await,
+        builtin_profiles: ClientCompatProfile::builtin(),
+    })
+}
+
+/// Update user-defined client compatibility profiles (applied on next tools/list)
+#[tauri::command]
+pub async fn update_client_compat_profiles(
+    profiles: Vec<ClientCompatProfile>,
+    app_state: State<'_, AppState>,
+) -> Result<(), String> {
+    info!(
+        "[Settings] Updating {} client compatibility profile(s)",
+        profiles.len()
+    );
+
+    for profile in &profiles {
+        if profile.name.trim().is_empty() {
+            return Err("Profile name is required".to_string());
+        }
+        if profile.max_tool_name_length == Some(0) {
+            return Err(format!(
+                "Profile '{}': maximum tool name length must be positive",
+                profile.name
+            ));
+        }
+    }
+
+    AppSettingsService::new(app_state.settings_repository.clone())
+        .set_client_compat_profiles(&profiles)
+        .await
+        .map_err(|e| format!("Failed to save compatibility profiles: {}", e))
+}
+
 /// Check if app should start hidden (for auto-launch with --hidden flag)
 pub fn should_start_hidden() -> bool {
     let args: Vec<String> = std::env::args().collect();
@@ -2,8 +2,12 @@
 //!
 //! IPC commands for managing spaces (isolated environments).
 
-use mcpmux_core::{ConnectionMode, Space};
+use chrono::{DateTime, Utc};
+use mcpmux_core::{
+    ClonedSpace, ConnectionMode, Space, SpaceCloneOptions, SpaceTemplate, TemplateInput,
+};
 use serde::Serialize;
+use std::collections::HashMap;
 use std::sync::Arc;
 use tauri::{AppHandle, Emitter, State};
 use tokio::sync::RwLock;
@@ -156,6 +160,189 @@ pub async fn delete_space(
     Ok(())
 }
 
+/// A saved template, without its server definitions
+#[derive(Debug, Serialize)]
+pub struct SpaceTemplateSummary {
+    pub id: Uuid,
+    pub name: String,
+    pub description: Option<String>,
+    pub created_at: DateTime<Utc>,
+    /// Display names of the template's servers
+    pub servers: Vec<String>,
+    /// Inputs to fill in when creating a space from the template
+    pub inputs: Vec<TemplateInput>,
+    /// Space secrets to set in spaces created from the template
+    pub secrets: Vec<String>,
+}
+
+impl From<SpaceTemplate> for SpaceTemplateSummary {
+    fn from(template: SpaceTemplate) -> Self {
+        let inputs = template.inputs();
+        let secrets = template.secrets();
+        Self {
+            id: template.id,
+            name: template.name,
+            description: template.description,
+            created_at: template.created_at,
+            servers: template
+                .space
+                .servers
+                .iter()
+                .map(|s| s.display_name().to_string())
+                .collect(),
+            inputs,
+            secrets,
+        }
+    }
+}
+
+/// Clone a space, including its config file.
+#[tauri::command]
+pub async fn clone_space(
+    id: String,
+    options: SpaceCloneOptions,
+    app: AppHandle,
+    state: State<'_, AppState>,
+    gateway_state: State<'_, Arc<RwLock<GatewayAppState>>>,
+) -> Result<ClonedSpace, String> {
+    let uuid = Uuid::parse_str(&id).map_err(|e| e.to_string())?;
+
+    let cloned = state
+        .space_service
+        .clone_space(&uuid, &options)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    // Servers defined in the config file come along with the file
+    let source_path = state.space_config_path(&id);
+    let config_path = state.space_config_path(&cloned.space.id.to_string());
+    if source_path.exists() {
+        std::fs::copy(&source_path, &config_path)
+            .map_err(|e| format!("Failed to copy config file: {}", e))?;
+    } else {
+        std::fs::write(&config_path, DEFAULT_SPACE_CONFIG)
+            .map_err(|e| format!("Failed to create config file: {}", e))?;
+    }
+
+    announce_space(&cloned.space, &app, &state, &gateway_state).await;
+
+    info!(
+        "[clone_space] Cloned space '{}' into '{}'",
+        id, cloned.space.name
+    );
+
+    Ok(cloned)
+}
+
+/// List saved space templates.
+#[tauri::command]
+pub async fn list_space_templates(
+    state: State<'_, AppState>,
+) -> Result<Vec<SpaceTemplateSummary>, String> {
+    let templates = state
+        .space_service
+        .list_templates()
+        .await
+        .map_err(|e| e.to_string())?;
+    Ok(templates.into_iter().map(Into::into).collect())
+}
+
+/// Save a space as a template.
+#[tauri::command]
+pub async fn save_space_template(
+    space_id: String,
+    name: String,
+    description: Option<String>,
+    state: State<'_, AppState>,
+) -> Result<SpaceTemplateSummary, String> {
+    let uuid = Uuid::parse_str(&space_id).map_err(|e| e.to_string())?;
+
+    let template = state
+        .space_service
+        .save_template(&uuid, name, description)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    info!(
+        "[save_space_template] Saved space '{}' as template '{}'",
+        space_id, template.name
+    );
+
+    Ok(template.into())
+}
+
+/// Delete a saved space template.
+#[tauri::command]
+pub async fn delete_space_template(id: String, state: State<'_, AppState>) -> Result<(), String> {
+    let uuid = Uuid::parse_str(&id).map_err(|e| e.to_string())?;
+    state
+        .space_service
+        .delete_template(&uuid)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Create a space from a template.
+///
+/// `inputs` maps server ID to input ID to value, for the inputs the template
+/// has no value for.
+#[tauri::command]
+pub async fn create_space_from_template(
+    template_id: String,
+    name: String,
+    icon: Option<String>,
+    inputs: HashMap<String, HashMap<String, String>>,
+    app: AppHandle,
+    state: State<'_, AppState>,
+    gateway_state: State<'_, Arc<RwLock<GatewayAppState>>>,
+) -> Result<ClonedSpace, String> {
+    let uuid = Uuid::parse_str(&template_id).map_err(|e| e.to_string())?;
+
+    let created = state
+        .space_service
+        .create_from_template(&uuid, &name, icon, &inputs)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    let config_path = state.space_config_path(&created.space.id.to_string());
+    if !config_path.exists() {
+        std::fs::write(&config_path, DEFAULT_SPACE_CONFIG)
+            .map_err(|e| format!("Failed to create config file: {}", e))?;
+    }
+
+    announce_space(&created.space, &app, &state, &gateway_state).await;
+
+    info!(
+        "[create_space_from_template] Created space '{}' from template '{}'",
+        created.space.name, template_id
+    );
+
+    Ok(created)
+}
+
+/// Emit `SpaceCreated` if the gateway is running and show the space in the tray
+async fn announce_space(
+    space: &Space,
+    app: &AppHandle,
+    state: &AppState,
+    gateway_state: &RwLock<GatewayAppState>,
+) {
+    let gw_state = gateway_state.read().await;
+    if let Some(ref gw) = gw_state.gateway_state {
+        let gw = gw.read().await;
+        gw.emit_domain_event(mcpmux_core::DomainEvent::SpaceCreated {
+            space_id: space.id,
+            name: space.name.clone(),
+            icon: space.icon.clone(),
+        });
+    }
+    drop(gw_state);
+
+    if let Err(e) = tray::update_tray_spaces(app, state).await {
+        warn!("Failed to update tray menu: {}", e);
+    }
+}
+
 /// Get the active (default) space.
 #[tauri::command]
 pub async fn get_active_space(state: State<'_, AppState>) -> Result<Option<Space>, String> {
@@ -272,6 +459,41 @@ pub async fn set_active_space<R: tauri::Runtime>(
     Ok(())
 }
 
+/// Turn read-only mode on or off for a space.
+///
+/// In read-only mode clients only see tools marked read-only, whatever their grants.
+#[tauri::command]
+pub async fn set_space_read_only(
+    id: String,
+    read_only: bool,
+    state: State<'_, AppState>,
+    gateway_state: State<'_, Arc<RwLock<GatewayAppState>>>,
+) -> Result<Space, String> {
+    let uuid = Uuid::parse_str(&id).map_err(|e| e.to_string())?;
+    let space = state
+        .space_service
+        .set_read_only(&uuid, read_only)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    // Emit domain event if gateway is running (refreshes connected clients)
+    let gw_state = gateway_state.read().await;
+    if let Some(ref gw) = gw_state.gateway_state {
+        let gw = gw.read().await;
+        gw.emit_domain_event(mcpmux_core::DomainEvent::SpaceReadOnlyChanged {
+            space_id: space.id,
+            read_only,
+        });
+    }
+
+    info!(
+        "[set_space_read_only] Space '{}' read-only: {}",
+        space.name, read_only
+    );
+
+    Ok(space)
+}
+
 /// Open space configuration file in external editor
 #[tauri::command]
 pub async fn open_space_config_file(
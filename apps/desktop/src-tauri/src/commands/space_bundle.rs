@@ -0,0 +1,136 @@
+//! Space bundle commands
+//!
+//! IPC commands for exporting spaces into a passphrase-encrypted file and
+//! importing them on another machine.
+
+use mcpmux_core::{BundleImportOptions, BundleImportReport};
+use mcpmux_storage::{open_space_bundle, seal_space_bundle};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use tauri::{AppHandle, State};
+use tokio::sync::RwLock;
+use tracing::{info, warn};
+use uuid::Uuid;
+
+use crate::commands::gateway::GatewayAppState;
+use crate::state::AppState;
+use crate::tray;
+
+/// Request for exporting spaces into a bundle file
+#[derive(Debug, Deserialize)]
+pub struct ExportSpaceBundleRequest {
+    pub space_ids: Vec<String>,
+    pub passphrase: String,
+    /// Include OAuth refresh tokens so OAuth servers stay connected
+    #[serde(default)]
+    pub include_oauth_tokens: bool,
+    /// File to write the bundle to
+    pub path: String,
+}
+
+/// Result of a bundle export
+#[derive(Debug, Serialize)]
+pub struct ExportSpaceBundleResponse {
+    pub path: String,
+    pub spaces: usize,
+    pub servers: usize,
+}
+
+/// Request for importing a bundle file
+#[derive(Debug, Deserialize)]
+pub struct ImportSpaceBundleRequest {
+    pub path: String,
+    pub passphrase: String,
+    #[serde(flatten)]
+    pub options: BundleImportOptions,
+}
+
+/// Export spaces into a passphrase-encrypted bundle file.
+#[tauri::command]
+pub async fn export_space_bundle(
+    request: ExportSpaceBundleRequest,
+    state: State<'_, AppState>,
+) -> Result<ExportSpaceBundleResponse, String> {
+    let space_ids = request
+        .space_ids
+        .iter()
+        .map(|id| Uuid::parse_str(id).map_err(|e| e.to_string()))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let bundle = state
+        .space_bundle_service
+        .export(&space_ids, request.include_oauth_tokens)
+        .await
+        .map_err(|e| e.to_string())?;
+    let sealed = seal_space_bundle(&bundle, &request.passphrase).map_err(|e| e.to_string())?;
+    std::fs::write(&request.path, sealed)
+        .map_err(|e| format!("Failed to write {}: {}", request.path, e))?;
+
+    let servers = bundle.spaces.iter().map(|s| s.servers.len()).sum();
+    info!(
+        "[export_space_bundle] Exported {} spaces ({} servers) to {}",
+        bundle.spaces.len(),
+        servers,
+        request.path
+    );
+
+    Ok(ExportSpaceBundleResponse {
+        path: request.path,
+        spaces: bundle.spaces.len(),
+        servers,
+    })
+}
+
+/// Import a bundle file written by [`export_space_bundle`].
+#[tauri::command]
+pub async fn import_space_bundle(
+    request: ImportSpaceBundleRequest,
+    app: AppHandle,
+    state: State<'_, AppState>,
+    gateway_state: State<'_, Arc<RwLock<GatewayAppState>>>,
+) -> Result<BundleImportReport, String> {
+    let sealed = std::fs::read_to_string(&request.path)
+        .map_err(|e| format!("Failed to read {}: {}", request.path, e))?;
+    let bundle = open_space_bundle(&sealed, &request.passphrase).map_err(|e| e.to_string())?;
+
+    let report = state
+        .space_bundle_service
+        .import(bundle, &request.options)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    let mut created = Vec::new();
+    for imported in report.spaces.iter().filter(|s| s.created) {
+        if let Ok(Some(space)) = state.space_service.get(&imported.space_id).await {
+            created.push(space);
+        }
+    }
+
+    // Emit domain events for new spaces if gateway is running
+    let gw_state = gateway_state.read().await;
+    if let Some(ref gw) = gw_state.gateway_state {
+        let gw = gw.read().await;
+        for space in &created {
+            gw.emit_domain_event(mcpmux_core::DomainEvent::SpaceCreated {
+                space_id: space.id,
+                name: space.name.clone(),
+                icon: space.icon.clone(),
+            });
+        }
+    }
+    drop(gw_state);
+
+    if !created.is_empty() {
+        if let Err(e) = tray::update_tray_spaces(&app, &state).await {
+            warn!("Failed to update tray menu: {}", e);
+        }
+    }
+
+    info!(
+        "[import_space_bundle] Imported {} spaces from {}",
+        report.spaces.len(),
+        request.path
+    );
+
+    Ok(report)
+}
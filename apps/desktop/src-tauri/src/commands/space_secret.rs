@@ -0,0 +1,112 @@
+//! Space secret commands
+//!
+//! IPC commands for the named secrets a space's servers reference in their
+//! input values as `${secret:name}`.
+
+use mcpmux_core::SpaceSecretInfo;
+use serde::Serialize;
+use std::sync::Arc;
+use tauri::State;
+use tokio::sync::RwLock;
+use tracing::{info, warn};
+use uuid::Uuid;
+
+use crate::commands::gateway::GatewayAppState;
+use crate::commands::server_manager::{retry_connection, ServerManagerState};
+use crate::state::AppState;
+
+/// Result of saving a secret
+#[derive(Debug, Serialize)]
+pub struct SetSpaceSecretResponse {
+    /// Server IDs reconnected to pick up the new value
+    pub reconnected: Vec<String>,
+    /// Servers that failed to reconnect, as "server_id: error"
+    pub errors: Vec<String>,
+}
+
+/// List a space's secrets (names and the servers using them, not values).
+#[tauri::command]
+pub async fn list_space_secrets(
+    space_id: String,
+    state: State<'_, AppState>,
+) -> Result<Vec<SpaceSecretInfo>, String> {
+    let uuid = Uuid::parse_str(&space_id).map_err(|e| e.to_string())?;
+    state
+        .space_secret_service
+        .list(&uuid)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Create or rotate a secret, then reconnect the enabled servers using it.
+#[tauri::command]
+pub async fn set_space_secret(
+    space_id: String,
+    name: String,
+    value: String,
+    app_state: State<'_, AppState>,
+    server_manager_state: State<'_, Arc<RwLock<ServerManagerState>>>,
+    gateway_state: State<'_, Arc<RwLock<GatewayAppState>>>,
+) -> Result<SetSpaceSecretResponse, String> {
+    let uuid = Uuid::parse_str(&space_id).map_err(|e| e.to_string())?;
+
+    let dependents = app_state
+        .space_secret_service
+        .set(&uuid, &name, &value)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    let mut response = SetSpaceSecretResponse {
+        reconnected: Vec::new(),
+        errors: Vec::new(),
+    };
+
+    // Servers only pick up the new value on connect
+    let gateway_running = server_manager_state.read().await.manager.is_some();
+    if gateway_running {
+        for server in dependents.into_iter().filter(|s| s.enabled) {
+            match retry_connection(
+                space_id.clone(),
+                server.server_id.clone(),
+                server_manager_state.clone(),
+                gateway_state.clone(),
+                app_state.clone(),
+            )
+            .await
+            {
+                Ok(()) => response.reconnected.push(server.server_id),
+                Err(e) => {
+                    warn!(
+                        "[set_space_secret] Failed to reconnect {}: {}",
+                        server.server_id, e
+                    );
+                    response.errors.push(format!("{}: {}", server.server_id, e));
+                }
+            }
+        }
+    }
+
+    info!(
+        "[set_space_secret] Saved secret '{}' in space {} ({} servers reconnected)",
+        name,
+        space_id,
+        response.reconnected.len()
+    );
+
+    Ok(response)
+}
+
+/// Delete a secret no server uses.
+#[tauri::command]
+pub async fn delete_space_secret(
+    space_id: String,
+    name: String,
+    state: State<'_, AppState>,
+) -> Result<(), String> {
+    let uuid = Uuid::parse_str(&space_id).map_err(|e| e.to_string())?;
+    state
+        .space_secret_service
+        .delete(&uuid, &name)
+        .await
+        .map_err(|e| e.to_string())
+}
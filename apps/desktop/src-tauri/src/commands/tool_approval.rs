@@ -0,0 +1,149 @@
+//! Tool approval commands
+//!
+//! IPC commands for approval policies (allow / ask / deny per tool or
+//! feature set) and for deciding on tool calls the gateway is holding.
+
+use std::sync::Arc;
+
+use mcpmux_core::{AppSettingsService, ApprovalPolicy, ApprovalTarget, ToolApprovalRule};
+use mcpmux_gateway::ToolApprovalRequest;
+use mcpmux_storage::ToolApprovalRepository;
+use tauri::State;
+use tokio::sync::RwLock;
+use tracing::info;
+
+use super::gateway::GatewayAppState;
+use crate::state::AppState;
+
+fn parse_target(target_type: &str) -> Result<ApprovalTarget, String> {
+    ApprovalTarget::parse(target_type).ok_or_else(|| {
+        format!(
+            "Invalid target type '{}': expected 'tool' or 'feature_set'",
+            target_type
+        )
+    })
+}
+
+fn parse_policy(policy: &str) -> Result<ApprovalPolicy, String> {
+    ApprovalPolicy::parse(policy).ok_or_else(|| {
+        format!(
+            "Invalid policy '{}': expected 'allow', 'ask' or 'deny'",
+            policy
+        )
+    })
+}
+
+/// List approval rules in a space
+#[tauri::command]
+pub async fn list_tool_approval_policies(
+    space_id: String,
+    state: State<'_, AppState>,
+) -> Result<Vec<ToolApprovalRule>, String> {
+    ToolApprovalRepository::new(state.database())
+        .list_for_space(&space_id)
+        .await
+        .map_err(|e| format!("Failed to list approval policies: {}", e))
+}
+
+/// Set the approval policy for a tool or feature set (`None` removes the rule)
+#[tauri::command]
+pub async fn set_tool_approval_policy(
+    space_id: String,
+    target_type: String,
+    target: String,
+    policy: Option<String>,
+    state: State<'_, AppState>,
+) -> Result<(), String> {
+    let target_type = parse_target(&target_type)?;
+    let repo = ToolApprovalRepository::new(state.database());
+
+    match policy {
+        Some(policy) => {
+            let policy = parse_policy(&policy)?;
+            info!(
+                "[ToolApproval] Setting {} {} to {} in space {}",
+                target_type.as_str(),
+                target,
+                policy.as_str(),
+                space_id
+            );
+            repo.set(&ToolApprovalRule {
+                space_id,
+                target_type,
+                target,
+                policy,
+            })
+            .await
+        }
+        None => {
+            info!(
+                "[ToolApproval] Removing rule for {} {} in space {}",
+                target_type.as_str(),
+                target,
+                space_id
+            );
+            repo.delete(&space_id, target_type, &target).await
+        }
+    }
+    .map_err(|e| format!("Failed to save approval policy: {}", e))
+}
+
+/// Get the policy for destructive tools without a rule
+#[tauri::command]
+pub async fn get_destructive_tool_policy(state: State<'_, AppState>) -> Result<String, String> {
+    Ok(AppSettingsService::new(state.settings_repository.clone())
+        .get_destructive_tool_policy()
+        .await
+        .as_str()
+        .to_string())
+}
+
+/// Set the policy for destructive tools without a rule
+#[tauri::command]
+pub async fn set_destructive_tool_policy(
+    policy: String,
+    state: State<'_, AppState>,
+) -> Result<(), String> {
+    let policy = parse_policy(&policy)?;
+    AppSettingsService::new(state.settings_repository.clone())
+        .set_destructive_tool_policy(policy)
+        .await
+        .map_err(|e| format!("Failed to save destructive tool policy: {}", e))
+}
+
+/// List tool calls waiting for approval
+#[tauri::command]
+pub async fn list_pending_tool_approvals(
+    gateway_state: State<'_, Arc<RwLock<GatewayAppState>>>,
+) -> Result<Vec<ToolApprovalRequest>, String> {
+    Ok(gateway_state
+        .read()
+        .await
+        .tool_approval_service
+        .as_ref()
+        .map(|service| service.list_pending())
+        .unwrap_or_default())
+}
+
+/// Approve or reject a held tool call
+#[tauri::command]
+pub async fn resolve_tool_approval(
+    request_id: String,
+    approved: bool,
+    remember: bool,
+    gateway_state: State<'_, Arc<RwLock<GatewayAppState>>>,
+) -> Result<(), String> {
+    let request_id =
+        uuid::Uuid::parse_str(&request_id).map_err(|e| format!("Invalid request ID: {e}"))?;
+
+    let service = gateway_state
+        .read()
+        .await
+        .tool_approval_service
+        .clone()
+        .ok_or_else(|| "Gateway not running".to_string())?;
+
+    service
+        .resolve(request_id, approved, remember)
+        .map_err(|e| e.to_string())
+}
@@ -0,0 +1,87 @@
+//! Workspace manifest commands
+//!
+//! IPC commands for previewing and applying a declarative workspace
+//! manifest (spaces, servers, feature sets, client grants and settings).
+
+use mcpmux_core::{DomainEvent, ManifestPlan, WorkspaceManifest};
+use std::path::Path;
+use std::sync::Arc;
+use tauri::{AppHandle, State};
+use tokio::sync::RwLock;
+use tracing::{info, warn};
+
+use crate::commands::config_import::registry_definitions;
+use crate::commands::gateway::GatewayAppState;
+use crate::state::AppState;
+use crate::tray;
+
+async fn read_manifest(path: &str) -> Result<WorkspaceManifest, String> {
+    let content = tokio::fs::read_to_string(path)
+        .await
+        .map_err(|e| format!("Failed to read {}: {}", path, e))?;
+    WorkspaceManifest::parse(&content).map_err(|e| format!("{:#}", e))
+}
+
+/// Show the changes applying a manifest would make.
+#[tauri::command]
+pub async fn plan_workspace_manifest(
+    path: String,
+    state: State<'_, AppState>,
+) -> Result<ManifestPlan, String> {
+    let manifest = read_manifest(&path).await?;
+    let registry = registry_definitions(&state).await;
+
+    state
+        .workspace_manifest_service
+        .plan(&manifest, Path::new(&path), &registry)
+        .await
+        .map_err(|e| format!("{:#}", e))
+}
+
+/// Converge spaces, servers, feature sets, grants and settings onto a manifest.
+#[tauri::command]
+pub async fn apply_workspace_manifest(
+    path: String,
+    app: AppHandle,
+    state: State<'_, AppState>,
+    gateway_state: State<'_, Arc<RwLock<GatewayAppState>>>,
+) -> Result<ManifestPlan, String> {
+    let manifest = read_manifest(&path).await?;
+    let registry = registry_definitions(&state).await;
+
+    let plan = state
+        .workspace_manifest_service
+        .apply(&manifest, Path::new(&path), &registry)
+        .await
+        .map_err(|e| format!("{:#}", e))?;
+
+    // Emit domain events if gateway is running
+    let gw_state = gateway_state.read().await;
+    if let Some(ref gw) = gw_state.gateway_state {
+        let gw = gw.read().await;
+        for event in &plan.events {
+            gw.emit_domain_event(event.clone());
+        }
+    }
+    drop(gw_state);
+
+    let spaces_changed = plan.events.iter().any(|e| {
+        matches!(
+            e,
+            DomainEvent::SpaceCreated { .. } | DomainEvent::SpaceUpdated { .. }
+        )
+    });
+    if spaces_changed {
+        if let Err(e) = tray::update_tray_spaces(&app, &state).await {
+            warn!("Failed to update tray menu: {}", e);
+        }
+    }
+
+    info!(
+        "[apply_workspace_manifest] Applied {} changes from {}",
+        plan.changes.len(),
+        path
+    );
+
+    Ok(plan)
+}
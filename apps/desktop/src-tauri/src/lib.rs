@@ -11,6 +11,7 @@ use tracing::{debug, error, info, warn};
 mod commands;
 mod services;
 mod state;
+mod stdio_bridge;
 mod tray;
 
 // Re-export deep link handler
@@ -211,6 +212,18 @@ async fn open_logs_folder() -> Result<(), String> {
 
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
+    // Bridge mode owns stdout, so it runs before logging and the window are set up
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    if let Some(bridge_args) = stdio_bridge::parse_args(&args) {
+        let result =
+            bridge_args.and_then(|bridge_args| stdio_bridge::run(bridge_args, &get_app_data_dir()));
+        if let Err(e) = result {
+            eprintln!("{} stdio bridge: {:#}", branding::DISPLAY_NAME, e);
+            std::process::exit(1);
+        }
+        return;
+    }
+
     // Keep the guard alive for the entire program - dropping it stops file logging
     let _log_guard = init_tracing();
 
@@ -314,6 +327,7 @@ pub fn run() {
             let server_log_manager = app_state.server_log_manager.clone();
             let port_service = app_state.gateway_port_service.clone();
             let settings_repo = app_state.settings_repository.clone();
+            let space_secret_repo = app_state.space_secret_repository.clone();
 
             // Auto-start gateway on app launch
             let gw_state_clone = gateway_state.clone();
@@ -335,7 +349,14 @@ pub fn run() {
                     }
                 };
 
-                let url = format!("http://localhost:{}", final_port);
+                // Create gateway config (TCP and/or Unix socket, TLS from settings)
+                let config = crate::commands::gateway::build_gateway_config(
+                    settings_repo.clone(),
+                    &app_data_dir,
+                    final_port,
+                )
+                .await;
+                let url = config.base_url();
                 info!("Auto-starting gateway on {}", url);
 
                 // Load JWT signing secret (DPAPI on Windows, keychain elsewhere)
@@ -367,7 +388,8 @@ pub fn run() {
                     .with_log_manager(server_log_manager)
                     .with_database(db_for_gateway)
                     .with_state_dir(app_data_dir.clone())
-                    .with_settings_repo(settings_repo);
+                    .with_settings_repo(settings_repo.clone())
+                    .with_space_secret_repo(space_secret_repo);
 
                 if let Some(secret) = jwt_secret {
                     deps_builder = deps_builder.with_jwt_secret(secret);
@@ -381,12 +403,11 @@ pub fn run() {
                     }
                 };
 
-                // Create gateway config
-                let config = mcpmux_gateway::GatewayConfig {
-                    host: "127.0.0.1".to_string(),  // Bind address must be IP
-                    port: final_port,
-                    enable_cors: true,
-                };
+                let socket_path = config
+                    .unix_socket
+                    .as_ref()
+                    .map(|s| s.path.to_string_lossy().to_string());
+                let tcp_enabled = config.tcp_enabled;
 
                 // Create self-contained gateway server with DI
                 // Gateway auto-initializes all services and auto-connects enabled servers
@@ -399,6 +420,7 @@ pub fn run() {
                 let server_manager_arc = server.server_manager();
                 let event_emitter = server.event_emitter();
                 let grant_service = server.grant_service();
+                let tool_approval_service = server.tool_approval_service();
 
                 // Start domain event bridge
                 crate::commands::gateway::start_domain_event_bridge(&app_handle_for_sm, gw_inner_state.clone());
@@ -494,12 +516,15 @@ pub fn run() {
                 let mut state = gw_state_clone.write().await;
                 state.running = true;
                 state.url = Some(url.clone());
+                state.socket_path = socket_path;
+                state.tcp_enabled = tcp_enabled;
                 state.handle = Some(handle);
                 state.gateway_state = Some(gw_inner_state);
                 state.pool_service = Some(pool_service);
                 state.feature_service = Some(feature_service);
                 state.event_emitter = Some(event_emitter);
                 state.grant_service = Some(grant_service);
+                state.tool_approval_service = Some(tool_approval_service);
 
                 info!(
                     "Gateway auto-started successfully on {} - GrantService initialized: {}",
@@ -531,12 +556,13 @@ pub fn run() {
                         default_space_id,
                         Some(move |space_id: &str, result: &mcpmux_core::application::SyncResult| {
                             // Emit event to refresh UI
-                            if result.has_changes() {
+                            if result.has_changes() || !result.problems.is_empty() {
                                 if let Err(e) = app_handle_for_watcher.emit("space-servers-updated", serde_json::json!({
                                     "space_id": space_id,
                                     "added": result.added,
                                     "updated": result.updated,
                                     "removed": result.removed,
+                                    "problems": result.problems,
                                 })) {
                                     warn!("[FileWatcher] Failed to emit event: {}", e);
                                 }
@@ -734,8 +760,19 @@ pub fn run() {
             commands::get_space,
             commands::create_space,
             commands::delete_space,
+            commands::export_space_bundle,
+            commands::import_space_bundle,
+            commands::clone_space,
+            commands::list_space_templates,
+            commands::save_space_template,
+            commands::delete_space_template,
+            commands::create_space_from_template,
+            commands::list_space_secrets,
+            commands::set_space_secret,
+            commands::delete_space_secret,
             commands::get_active_space,
             commands::set_active_space,
+            commands::set_space_read_only,
             commands::open_space_config_file,
             commands::read_space_config,
             commands::save_space_config,
@@ -756,9 +793,14 @@ pub fn run() {
             commands::set_server_enabled,
             commands::set_server_oauth_connected,
             commands::save_server_inputs,
+            commands::set_server_read_only,
+            commands::set_server_concurrency_limit,
+            commands::set_server_skip_argument_validation,
+            commands::set_server_launch_overrides,
             // FeatureSet commands
             commands::list_feature_sets,
             commands::list_feature_sets_by_space,
+            commands::list_global_feature_sets,
             commands::get_feature_set,
             commands::get_feature_set_with_members,
             commands::create_feature_set,
@@ -780,7 +822,26 @@ pub fn run() {
             commands::list_server_features_by_server,
             commands::list_server_features_by_type,
             commands::get_server_feature,
+            commands::approve_server_feature_definition,
+            commands::list_server_feature_versions,
+            // Tool approval commands
+            commands::list_tool_approval_policies,
+            commands::set_tool_approval_policy,
+            commands::get_destructive_tool_policy,
+            commands::set_destructive_tool_policy,
+            commands::list_pending_tool_approvals,
+            commands::resolve_tool_approval,
             commands::seed_server_features,
+            // Feature override commands
+            commands::list_feature_overrides,
+            commands::set_feature_override,
+            // Argument policy commands
+            commands::list_argument_policies,
+            commands::set_argument_policy,
+            // Rate limit commands
+            commands::list_client_rate_limits,
+            commands::set_client_rate_limit,
+            commands::delete_client_rate_limit,
             // Client commands
             commands::list_clients,
             commands::get_client,
@@ -796,9 +857,16 @@ pub fn run() {
             // Config export commands
             commands::preview_config_export,
             commands::export_config_to_file,
+            commands::merge_config_into_file,
             commands::get_config_paths,
             commands::check_config_exists,
             commands::backup_existing_config,
+            // Config import commands
+            commands::scan_client_configs,
+            commands::import_client_servers,
+            // Workspace manifest commands
+            commands::plan_workspace_manifest,
+            commands::apply_workspace_manifest,
             // Client install commands (one-click IDE setup)
             commands::add_to_vscode,
             commands::add_to_cursor,
@@ -822,9 +890,12 @@ pub fn run() {
             commands::update_oauth_client,
             commands::delete_oauth_client,
             commands::get_oauth_client_grants,
+            commands::get_oauth_client_grant_details,
             commands::grant_oauth_client_feature_set,
             commands::revoke_oauth_client_feature_set,
             commands::get_oauth_client_resolved_features,
+            commands::get_oauth_client_tool_budget,
+            commands::update_oauth_client_tool_budget,
             commands::open_url,
             // Server Manager commands (event-driven v2)
             commands::get_server_statuses,
@@ -847,6 +918,13 @@ pub fn run() {
             // Startup settings commands
             commands::get_startup_settings,
             commands::update_startup_settings,
+            // Gateway listener settings
+            commands::get_gateway_listener_settings,
+            commands::update_gateway_listener_settings,
+            commands::get_gateway_tls_settings,
+            commands::update_gateway_tls_settings,
+            commands::get_client_compat_profiles,
+            commands::update_client_compat_profiles,
         ])
         .run(tauri::generate_context!())
         .expect("error while running McpMux application");
@@ -137,7 +137,11 @@ impl SpaceFileWatcher {
 
                         match sync_service.sync_from_file(space_id, &path).await {
                             Ok(result) => {
-                                if result.has_changes() {
+                                for problem in &result.problems {
+                                    warn!("Unresolved variable in {:?}: {}", path, problem);
+                                }
+
+                                if result.has_changes() || !result.problems.is_empty() {
                                     info!(
                                         "Sync complete: {} added, {} updated, {} removed",
                                         result.added.len(),
@@ -7,13 +7,15 @@ use mcpmux_core::{
     AppSettingsRepository, AppSettingsService, ClientService, CredentialRepository,
     FeatureSetRepository, GatewayPortService, InboundMcpClientRepository,
     InstalledServerRepository, LogConfig, OutboundOAuthRepository, ServerDiscoveryService,
-    ServerFeatureRepository as CoreServerFeatureRepository, ServerLogManager, SpaceRepository,
-    SpaceService,
+    ServerFeatureRepository as CoreServerFeatureRepository, ServerLogManager, SpaceBundleService,
+    SpaceRepository, SpaceSecretRepository, SpaceSecretService, SpaceService, TransactionManager,
+    WorkspaceManifestService,
 };
 use mcpmux_storage::{
     Database, FieldEncryptor, SqliteAppSettingsRepository, SqliteCredentialRepository,
     SqliteFeatureSetRepository, SqliteInboundMcpClientRepository, SqliteInstalledServerRepository,
     SqliteOutboundOAuthRepository, SqliteServerFeatureRepository, SqliteSpaceRepository,
+    SqliteSpaceSecretRepository, SqliteTransactionManager,
 };
 use std::path::PathBuf;
 use std::sync::Arc;
@@ -34,6 +36,10 @@ pub struct AppState {
     pub space_service: SpaceService,
     /// Service for managing clients (auto-grants, etc.)
     pub client_service: ClientService,
+    /// Service for exporting/importing spaces between machines
+    pub space_bundle_service: Arc<SpaceBundleService>,
+    /// Service for planning and applying workspace manifests
+    pub workspace_manifest_service: WorkspaceManifestService,
     /// Server discovery service for loading servers from API/bundled/user spaces
     pub server_discovery: Arc<ServerDiscoveryService>,
     /// Server log manager for file-based logging
@@ -42,6 +48,10 @@ pub struct AppState {
     pub installed_server_repository: Arc<dyn InstalledServerRepository>,
     /// Credential repository (with encryption)
     pub credential_repository: Arc<dyn CredentialRepository>,
+    /// Space secret repository (with encryption)
+    pub space_secret_repository: Arc<dyn SpaceSecretRepository>,
+    /// Service for managing space secrets
+    pub space_secret_service: SpaceSecretService,
     /// Backend OAuth repository (our DCR with remote MCP servers)
     pub backend_oauth_repository: Arc<dyn OutboundOAuthRepository>,
     /// FeatureSet repository for permission bundles
@@ -94,6 +104,10 @@ impl AppState {
             SqliteCredentialRepository::new(db.clone(), encryptor.clone()),
         );
 
+        let space_secret_repository: Arc<dyn SpaceSecretRepository> = Arc::new(
+            SqliteSpaceSecretRepository::new(db.clone(), encryptor.clone()),
+        );
+
         let backend_oauth_repository: Arc<dyn OutboundOAuthRepository> =
             Arc::new(SqliteOutboundOAuthRepository::new(db.clone()));
 
@@ -113,17 +127,55 @@ impl AppState {
         let settings_service = Arc::new(AppSettingsService::new(settings_repository.clone()));
         let gateway_port_service = Arc::new(GatewayPortService::new(settings_repository.clone()));
 
+        // Units of work run on their own connections to the database file
+        let transactions: Arc<dyn TransactionManager> = Arc::new(SqliteTransactionManager::new(
+            db_path.clone(),
+            encryptor.clone(),
+        ));
+
         // Create services
+        let space_bundle_service = Arc::new(
+            SpaceBundleService::new(
+                space_repository.clone(),
+                installed_server_repository.clone(),
+                server_feature_repository_core.clone(),
+                feature_set_repository.clone(),
+                credential_repository.clone(),
+                backend_oauth_repository.clone(),
+                space_secret_repository.clone(),
+            )
+            .with_transactions(transactions.clone()),
+        );
+        let workspace_manifest_service = WorkspaceManifestService::new(
+            space_repository.clone(),
+            installed_server_repository.clone(),
+            server_feature_repository_core.clone(),
+            feature_set_repository.clone(),
+            client_repository.clone(),
+            credential_repository.clone(),
+            space_secret_repository.clone(),
+            settings_repository.clone(),
+        )
+        .with_transactions(transactions);
         let space_service = SpaceService::with_feature_set_repository(
             space_repository,
             feature_set_repository.clone(),
+        )
+        .with_cloning(
+            space_bundle_service.clone(),
+            client_repository.clone(),
+            data_dir.join("templates"),
         );
         let client_service =
             ClientService::new(client_repository.clone(), feature_set_repository.clone());
+        let space_secret_service = SpaceSecretService::new(
+            space_secret_repository.clone(),
+            installed_server_repository.clone(),
+        );
 
         // Create server discovery service
         // Spaces directory is relative to app data_dir (single source of truth)
-        let spaces_dir = data_dir.join("spaces");
+        let spaces_dir = mcpmux_core::get_spaces_dir(&data_dir);
         std::fs::create_dir_all(&spaces_dir)?;
         info!("Using spaces directory: {:?}", spaces_dir);
 
@@ -155,10 +207,14 @@ impl AppState {
             gateway_port_service,
             space_service,
             client_service,
+            space_bundle_service,
+            workspace_manifest_service,
             server_discovery,
             server_log_manager,
             installed_server_repository,
             credential_repository,
+            space_secret_repository,
+            space_secret_service,
             backend_oauth_repository,
             feature_set_repository,
             client_repository,
@@ -0,0 +1,164 @@
+//! Stdio bridge mode
+//!
+//! `mcpmux --stdio-bridge --client-id <id> (--socket <path> | --url <url>)`
+//! relays a stdio-only MCP client to the running gateway. It is handled
+//! before Tauri starts: stdout carries JSON-RPC, so nothing else may write to
+//! it, and the single-instance plugin would hand the launch to the open window.
+
+use anyhow::{anyhow, bail, Result};
+use mcpmux_core::STDIO_BRIDGE_FLAG;
+use mcpmux_gateway::BridgeTarget;
+use mcpmux_storage::{InboundClient, InboundClientRepository, RegistrationType};
+use std::path::{Path, PathBuf};
+
+/// Lifetime of the access token minted for a bridge process
+const BRIDGE_TOKEN_TTL_SECS: i64 = 30 * 24 * 60 * 60;
+
+/// Arguments of a bridge launch
+#[derive(Debug, PartialEq, Eq)]
+pub struct BridgeArgs {
+    client_id: String,
+    target: BridgeTarget,
+}
+
+/// Parse bridge arguments (without the program name).
+///
+/// Returns `None` when the process was not launched as a bridge.
+pub fn parse_args(args: &[String]) -> Option<Result<BridgeArgs>> {
+    if args.first().map(String::as_str) != Some(STDIO_BRIDGE_FLAG) {
+        return None;
+    }
+
+    let parse = || -> Result<BridgeArgs> {
+        let mut client_id = None;
+        let mut target = None;
+        let mut rest = args[1..].iter();
+        while let Some(arg) = rest.next() {
+            let mut value = || {
+                rest.next()
+                    .cloned()
+                    .ok_or_else(|| anyhow!("{} requires a value", arg))
+            };
+            match arg.as_str() {
+                "--client-id" => client_id = Some(value()?),
+                "--socket" => target = Some(BridgeTarget::UnixSocket(PathBuf::from(value()?))),
+                "--url" => target = Some(BridgeTarget::Url(value()?)),
+                other => bail!("Unknown bridge argument: {}", other),
+            }
+        }
+        Ok(BridgeArgs {
+            client_id: client_id.ok_or_else(|| anyhow!("--client-id is required"))?,
+            target: target.ok_or_else(|| anyhow!("--socket or --url is required"))?,
+        })
+    };
+    Some(parse())
+}
+
+/// Run the bridge until the client or the gateway closes
+pub fn run(args: BridgeArgs, data_dir: &Path) -> Result<()> {
+    // Same-user access: the token is signed with the gateway's own secret
+    let secret = mcpmux_storage::create_jwt_secret_provider(data_dir)?.get_or_create_secret()?;
+    let token = mcpmux_gateway::auth::create_access_token(
+        &args.client_id,
+        None,
+        BRIDGE_TOKEN_TTL_SECS,
+        &secret[..],
+    );
+
+    tokio::runtime::Builder::new_multi_thread()
+        .enable_all()
+        .build()?
+        .block_on(mcpmux_gateway::run_stdio_bridge(&args.target, Some(token)))
+}
+
+/// Client ID a bridge for `client_type` authenticates as
+fn bridge_client_id(client_type: &str) -> String {
+    format!("mcpmux-bridge-{}", client_type)
+}
+
+/// Register the pre-approved inbound client that bridges for `client_type`
+/// authenticate as. Existing registrations (and their grants) are kept.
+pub async fn register_bridge_client(
+    repo: &InboundClientRepository,
+    client_type: &str,
+) -> Result<String> {
+    let client_id = bridge_client_id(client_type);
+    if repo.get_client(&client_id).await?.is_some() {
+        return Ok(client_id);
+    }
+
+    let now = chrono::Utc::now().to_rfc3339();
+    repo.save_client(&InboundClient {
+        client_id: client_id.clone(),
+        registration_type: RegistrationType::Preregistered,
+        client_name: format!("{} (stdio bridge)", client_type),
+        client_alias: None,
+        redirect_uris: Vec::new(),
+        grant_types: Vec::new(),
+        response_types: Vec::new(),
+        token_endpoint_auth_method: "none".to_string(),
+        scope: None,
+        approved: true,
+        logo_uri: None,
+        client_uri: None,
+        software_id: None,
+        software_version: None,
+        metadata_url: None,
+        metadata_cached_at: None,
+        metadata_cache_ttl: None,
+        connection_mode: "follow_active".to_string(),
+        locked_space_id: None,
+        meta_tools: false,
+        last_seen: None,
+        created_at: now.clone(),
+        updated_at: now,
+    })
+    .await?;
+
+    Ok(client_id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(args: &[&str]) -> Vec<String> {
+        args.iter().map(|a| a.to_string()).collect()
+    }
+
+    #[test]
+    fn test_parse_args_ignores_normal_launch() {
+        assert!(parse_args(&args(&["--hidden"])).is_none());
+        assert!(parse_args(&[]).is_none());
+    }
+
+    #[test]
+    fn test_parse_args_socket() {
+        let parsed = parse_args(&args(&[
+            STDIO_BRIDGE_FLAG,
+            "--client-id",
+            "mcpmux-bridge-cursor",
+            "--socket",
+            "/tmp/mcpmux.sock",
+        ]))
+        .unwrap()
+        .unwrap();
+        assert_eq!(
+            parsed,
+            BridgeArgs {
+                client_id: "mcpmux-bridge-cursor".to_string(),
+                target: BridgeTarget::UnixSocket(PathBuf::from("/tmp/mcpmux.sock")),
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_args_requires_target() {
+        assert!(parse_args(&args(&[STDIO_BRIDGE_FLAG, "--client-id", "x"]))
+            .unwrap()
+            .is_err());
+        assert!(parse_args(&args(&[STDIO_BRIDGE_FLAG, "--socket"]))
+            .unwrap()
+            .is_err());
+    }
+}
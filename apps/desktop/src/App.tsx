@@ -30,6 +30,7 @@ import {
 import { ThemeProvider } from '@/components/ThemeProvider';
 import { OAuthConsentModal } from '@/components/OAuthConsentModal';
 import { ServerInstallModal } from '@/components/ServerInstallModal';
+import { ToolApprovalModal } from '@/components/ToolApprovalModal';
 import { SpaceSwitcher } from '@/components/SpaceSwitcher';
 import { ConnectIDEs } from '@/components/ConnectIDEs';
 import { useDataSync } from '@/hooks/useDataSync';
@@ -354,6 +355,8 @@ function App() {
       <OAuthConsentModal />
       {/* Server install modal - shown when install deep link is received */}
       <ServerInstallModal />
+      {/* Tool approval modal - shown when the gateway holds a tool call for approval */}
+      <ToolApprovalModal />
     </ThemeProvider>
   );
 }
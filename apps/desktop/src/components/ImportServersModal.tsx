@@ -0,0 +1,218 @@
+import { useEffect, useState } from 'react';
+import { X, Loader2, Download, KeyRound } from 'lucide-react';
+import {
+  scanClientConfigs,
+  importClientServers,
+  type ClientConfigScan,
+} from '@/lib/api/configImport';
+
+interface ImportServersModalProps {
+  spaceId: string;
+  spaceName: string;
+  /** Running gateway URL; required to point clients at McpMux */
+  gatewayUrl: string | null;
+  onClose: () => void;
+  onImported: () => void;
+}
+
+export function ImportServersModal({
+  spaceId,
+  spaceName,
+  gatewayUrl,
+  onClose,
+  onImported,
+}: ImportServersModalProps) {
+  const [scans, setScans] = useState<ClientConfigScan[]>([]);
+  const [isLoading, setIsLoading] = useState(true);
+  const [error, setError] = useState<string | null>(null);
+  // Selected server names, keyed by config path
+  const [selected, setSelected] = useState<Record<string, string[]>>({});
+  const [rewrite, setRewrite] = useState<Record<string, boolean>>({});
+  const [importingPath, setImportingPath] = useState<string | null>(null);
+  const [results, setResults] = useState<Record<string, string>>({});
+
+  useEffect(() => {
+    const load = async () => {
+      try {
+        setIsLoading(true);
+        const found = await scanClientConfigs(spaceId, gatewayUrl);
+        setScans(found);
+        setSelected(
+          Object.fromEntries(
+            found.map((scan) => [
+              scan.path,
+              scan.servers.filter((s) => !s.already_installed).map((s) => s.name),
+            ])
+          )
+        );
+      } catch (e) {
+        setError(String(e));
+      } finally {
+        setIsLoading(false);
+      }
+    };
+    load();
+  }, [spaceId, gatewayUrl]);
+
+  const toggle = (path: string, name: string) => {
+    setSelected((prev) => {
+      const current = prev[path] ?? [];
+      return {
+        ...prev,
+        [path]: current.includes(name) ? current.filter((n) => n !== name) : [...current, name],
+      };
+    });
+  };
+
+  const handleImport = async (scan: ClientConfigScan) => {
+    const names = selected[scan.path] ?? [];
+    if (names.length === 0) return;
+    setImportingPath(scan.path);
+    try {
+      const result = await importClientServers(
+        scan.client,
+        scan.path,
+        spaceId,
+        names,
+        rewrite[scan.path] ? gatewayUrl : null
+      );
+      const parts = [`Imported ${result.imported.length} server(s)`];
+      if (result.skipped.length > 0) parts.push(`skipped: ${result.skipped.join('; ')}`);
+      if (result.backup_path) parts.push(`config rewritten (backup: ${result.backup_path})`);
+      setResults((prev) => ({ ...prev, [scan.path]: parts.join(' — ') }));
+      onImported();
+    } catch (e) {
+      setResults((prev) => ({ ...prev, [scan.path]: `Import failed: ${e}` }));
+    } finally {
+      setImportingPath(null);
+    }
+  };
+
+  return (
+    <div className="fixed inset-0 bg-black/60 backdrop-blur-sm flex items-center justify-center z-50 p-4">
+      <div className="bg-[rgb(var(--surface))] w-full max-w-3xl max-h-[80vh] rounded-xl shadow-2xl flex flex-col border border-[rgb(var(--border))]">
+        {/* Header */}
+        <div className="flex items-center justify-between p-4 border-b border-[rgb(var(--border))]">
+          <div>
+            <h3 className="text-lg font-semibold">Import from Clients</h3>
+            <p className="text-sm text-[rgb(var(--muted))]">
+              Install servers configured in Cursor, VS Code, Continue or Claude Desktop into: {spaceName}
+            </p>
+          </div>
+          <button
+            onClick={onClose}
+            className="p-2 hover:bg-[rgb(var(--surface-hover))] rounded-lg transition-colors"
+          >
+            <X className="h-5 w-5 text-[rgb(var(--muted))]" />
+          </button>
+        </div>
+
+        <div className="flex-1 overflow-y-auto p-4 space-y-4">
+          {isLoading ? (
+            <div className="flex items-center justify-center py-12 text-[rgb(var(--muted))]">
+              <Loader2 className="h-5 w-5 animate-spin mr-2" />
+              Looking for client configs...
+            </div>
+          ) : error ? (
+            <div className="text-sm text-[rgb(var(--error))]">{error}</div>
+          ) : scans.length === 0 ? (
+            <div className="text-center py-12 text-sm text-[rgb(var(--muted))]">
+              No client config files found.
+            </div>
+          ) : (
+            scans.map((scan) => (
+              <div
+                key={scan.path}
+                className="border border-[rgb(var(--border-subtle))] rounded-lg p-3 space-y-2"
+              >
+                <div>
+                  <div className="font-medium">{scan.client_name}</div>
+                  <div className="text-xs text-[rgb(var(--muted))] font-mono truncate">{scan.path}</div>
+                </div>
+
+                {scan.error ? (
+                  <div className="text-sm text-[rgb(var(--error))]">{scan.error}</div>
+                ) : scan.servers.length === 0 ? (
+                  <div className="text-sm text-[rgb(var(--muted))]">No servers to import.</div>
+                ) : (
+                  <>
+                    {scan.servers.map((server) => (
+                      <label
+                        key={server.name}
+                        className={`flex items-start gap-2 text-sm ${server.already_installed ? 'opacity-60' : ''}`}
+                      >
+                        <input
+                          type="checkbox"
+                          className="mt-1"
+                          disabled={server.already_installed}
+                          checked={(selected[scan.path] ?? []).includes(server.name)}
+                          onChange={() => toggle(scan.path, server.name)}
+                        />
+                        <div className="min-w-0">
+                          <div className="flex items-center gap-2">
+                            <span className="font-medium">{server.server_name}</span>
+                            {server.registry_match && (
+                              <span className="px-1.5 py-0.5 rounded text-xs bg-blue-100 text-blue-800 dark:bg-blue-900 dark:text-blue-200">
+                                Registry
+                              </span>
+                            )}
+                            {server.secret_inputs.length > 0 && (
+                              <span
+                                className="flex items-center gap-1 text-xs text-[rgb(var(--muted))]"
+                                title={server.secret_inputs.join(', ')}
+                              >
+                                <KeyRound className="h-3 w-3" />
+                                {server.secret_inputs.length} secret(s) stored encrypted
+                              </span>
+                            )}
+                            {server.already_installed && (
+                              <span className="text-xs text-[rgb(var(--muted))]">Already installed</span>
+                            )}
+                          </div>
+                          <div className="text-xs text-[rgb(var(--muted))] font-mono truncate">{server.target}</div>
+                        </div>
+                      </label>
+                    ))}
+
+                    <div className="flex items-center justify-between pt-2">
+                      <label
+                        className={`flex items-center gap-2 text-xs ${gatewayUrl ? '' : 'opacity-60'}`}
+                        title={gatewayUrl ? undefined : 'Start the gateway to point clients at McpMux'}
+                      >
+                        <input
+                          type="checkbox"
+                          disabled={!gatewayUrl}
+                          checked={rewrite[scan.path] ?? false}
+                          onChange={(e) =>
+                            setRewrite((prev) => ({ ...prev, [scan.path]: e.target.checked }))
+                          }
+                        />
+                        Replace imported servers in {scan.client_name} with McpMux (a backup is kept)
+                      </label>
+                      <button
+                        onClick={() => handleImport(scan)}
+                        disabled={importingPath !== null || (selected[scan.path] ?? []).length === 0}
+                        className="flex items-center gap-2 px-3 py-1.5 text-sm bg-[rgb(var(--primary))] text-[rgb(var(--primary-foreground))] rounded-lg hover:bg-[rgb(var(--primary-hover))] transition-colors disabled:opacity-50"
+                      >
+                        {importingPath === scan.path ? (
+                          <Loader2 className="h-4 w-4 animate-spin" />
+                        ) : (
+                          <Download className="h-4 w-4" />
+                        )}
+                        Import
+                      </button>
+                    </div>
+                  </>
+                )}
+
+                {results[scan.path] && (
+                  <div className="text-xs text-[rgb(var(--muted))]">{results[scan.path]}</div>
+                )}
+              </div>
+            ))
+          )}
+        </div>
+      </div>
+    </div>
+  );
+}
@@ -23,6 +23,7 @@ function extractDefinition(server: ServerViewModel): ServerDefinition {
     env_overrides: _j,
     args_append: _k,
     extra_headers: _l,
+    read_only: _m,
     ...definition
   } = server;
   return definition;
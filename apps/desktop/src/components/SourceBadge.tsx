@@ -52,6 +52,16 @@ export function SourceBadge({ source, className = '' }: SourceBadgeProps) {
         </span>
       );
 
+    case 'imported':
+      return (
+        <span
+          className={`inline-flex items-center px-2 py-0.5 rounded text-xs font-medium bg-purple-100 text-purple-800 dark:bg-purple-900 dark:text-purple-200 ${className}`}
+          title={`Imported from ${source.client}`}
+        >
+          Imported
+        </span>
+      );
+
     default:
       return null;
   }
@@ -69,6 +79,7 @@ export function getUninstallLabel(source: InstallationSource | undefined): strin
     case 'user_config':
       return 'Remove from Config';
     case 'manual_entry':
+    case 'imported':
       return 'Remove';
     case 'registry':
     default:
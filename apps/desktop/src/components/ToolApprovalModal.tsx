@@ -0,0 +1,160 @@
+/**
+ * Tool Approval Modal
+ *
+ * Displays when the gateway holds a tool call under an "ask" approval policy.
+ *
+ * ## Flow
+ * 1. Gateway emits a `tool-approval` event (requested / resolved)
+ * 2. Reload pending approvals from the backend and show the oldest
+ * 3. On approve/reject, call resolve_tool_approval; the gateway releases the call
+ */
+
+import { useState, useEffect, useCallback } from 'react';
+import { listen } from '@tauri-apps/api/event';
+import { Check, X, AlertCircle, ShieldAlert } from 'lucide-react';
+import { Button, Card, CardHeader, CardTitle, CardDescription, CardContent } from '@mcpmux/ui';
+import {
+  listPendingToolApprovals,
+  resolveToolApproval,
+  type ToolApprovalRequest,
+} from '@/lib/api/toolApproval';
+
+export function ToolApprovalModal() {
+  const [pending, setPending] = useState<ToolApprovalRequest[]>([]);
+  const [remember, setRemember] = useState(false);
+  const [isProcessing, setIsProcessing] = useState(false);
+  const [processError, setProcessError] = useState<string | null>(null);
+
+  const refresh = useCallback(async () => {
+    try {
+      setPending(await listPendingToolApprovals());
+    } catch (err) {
+      console.error('[ToolApproval] Failed to load pending approvals:', err);
+    }
+  }, []);
+
+  useEffect(() => {
+    refresh();
+    // Both new requests and decisions made elsewhere (client, timeout) change the queue
+    const unlisten = listen('tool-approval', () => {
+      refresh();
+    });
+
+    return () => {
+      unlisten.then((fn) => fn());
+    };
+  }, [refresh]);
+
+  const current = pending[0];
+
+  // Reset per-request state when the shown request changes
+  useEffect(() => {
+    setRemember(false);
+    setProcessError(null);
+  }, [current?.request_id]);
+
+  const handleDecision = async (approved: boolean) => {
+    if (!current) return;
+
+    setIsProcessing(true);
+    setProcessError(null);
+
+    try {
+      await resolveToolApproval(current.request_id, approved, approved && remember);
+      setPending((queue) => queue.filter((r) => r.request_id !== current.request_id));
+    } catch (err) {
+      console.error('[ToolApproval] Failed to resolve approval:', err);
+      setProcessError(String(err));
+      refresh();
+    } finally {
+      setIsProcessing(false);
+    }
+  };
+
+  if (!current) return null;
+
+  const argumentsText = JSON.stringify(current.arguments ?? {}, null, 2);
+
+  return (
+    <div className="fixed inset-0 z-50 flex items-center justify-center bg-black/50 backdrop-blur-sm">
+      <Card className="animate-in fade-in zoom-in mx-4 w-full max-w-lg shadow-xl duration-200">
+        <CardHeader>
+          <div className="flex items-center gap-3">
+            <div className="rounded-full bg-amber-500/10 p-2">
+              <ShieldAlert className="h-6 w-6 text-amber-500" />
+            </div>
+            <div>
+              <CardTitle>Approve Tool Call</CardTitle>
+              <CardDescription>
+                A client is waiting for your decision
+                {pending.length > 1 ? ` (${pending.length - 1} more queued)` : ''}
+              </CardDescription>
+            </div>
+          </div>
+        </CardHeader>
+        <CardContent className="space-y-4">
+          <div className="bg-surface-hover rounded-lg border border-[rgb(var(--border))] p-4">
+            <div className="flex items-center gap-2">
+              <span className="font-mono text-sm font-medium">{current.tool_name}</span>
+              {current.destructive && (
+                <span className="rounded-full border border-red-500/20 bg-red-500/10 px-2 py-0.5 text-xs text-red-500">
+                  destructive
+                </span>
+              )}
+            </div>
+            <div className="mt-1 break-all text-xs text-[rgb(var(--muted))]">
+              Server {current.server_id} · Client{' '}
+              {current.client_id.length > 40
+                ? `${current.client_id.substring(0, 40)}...`
+                : current.client_id}
+            </div>
+          </div>
+
+          <div>
+            <div className="mb-2 text-sm font-medium">Arguments</div>
+            <pre className="max-h-48 overflow-auto rounded-lg border border-[rgb(var(--border))] bg-[rgb(var(--surface))] p-3 text-xs">
+              {argumentsText}
+            </pre>
+          </div>
+
+          <label className="flex cursor-pointer items-center gap-2 text-sm">
+            <input
+              type="checkbox"
+              checked={remember}
+              onChange={(e) => setRemember(e.target.checked)}
+            />
+            Don't ask again for this tool in this session
+          </label>
+
+          {processError && (
+            <div className="flex items-center gap-2 rounded-lg bg-red-500/10 p-3 text-sm text-red-500">
+              <AlertCircle className="h-4 w-4 flex-shrink-0" />
+              <span>{processError}</span>
+            </div>
+          )}
+
+          <div className="flex gap-3 pt-2">
+            <Button
+              variant="secondary"
+              className="flex-1"
+              onClick={() => handleDecision(false)}
+              disabled={isProcessing}
+            >
+              <X className="mr-2 h-4 w-4" />
+              Reject
+            </Button>
+            <Button
+              variant="primary"
+              className="flex-1"
+              onClick={() => handleDecision(true)}
+              disabled={isProcessing}
+            >
+              <Check className="mr-2 h-4 w-4" />
+              Approve
+            </Button>
+          </div>
+        </CardContent>
+      </Card>
+    </div>
+  );
+}
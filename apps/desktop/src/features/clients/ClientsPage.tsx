@@ -42,9 +42,15 @@ import type { FeatureSet } from '@/lib/api/featureSets';
 import { listFeatureSetsBySpace } from '@/lib/api/featureSets';
 import { 
   getOAuthClientGrants, 
+  getOAuthClientGrantDetails,
   grantOAuthClientFeatureSet, 
   revokeOAuthClientFeatureSet,
-  getOAuthClientResolvedFeatures 
+  getOAuthClientResolvedFeatures,
+  getOAuthClientToolBudget,
+  updateOAuthClientToolBudget,
+  type ClientGrant,
+  type ClientToolBudgetInfo,
+  type ResolvedClientFeatures
 } from '@/lib/api/oauthClients';
 import {
   addFeatureToSet,
@@ -53,8 +59,17 @@ import {
   type FeatureSetMember
 } from '@/lib/api/featureMembers';
 import { listServerFeatures } from '@/lib/api/serverFeatures';
+import {
+  listClientRateLimits,
+  setClientRateLimit,
+  deleteClientRateLimit,
+  type ClientRateLimit,
+} from '@/lib/api/rateLimits';
 import { invoke } from '@tauri-apps/api/core';
 
+// Many clients degrade beyond this many tools
+const TOOL_COUNT_WARNING_THRESHOLD = 40;
+
 // Connection mode options
 const CONNECTION_MODES = [
   {
@@ -133,14 +148,28 @@ export default function ClientsPage() {
   const [availableFeatureSets, setAvailableFeatureSets] = useState<FeatureSet[]>([]);
   const [grantedFeatureSetIds, setGrantedFeatureSetIds] = useState<string[]>([]);
   const [isLoadingGrants, setIsLoadingGrants] = useState(false);
+  const [grantDetails, setGrantDetails] = useState<ClientGrant[]>([]);
+  // Limits applied to new grants ('' = none)
+  const [grantHours, setGrantHours] = useState('');
+  const [grantMaxCalls, setGrantMaxCalls] = useState('');
   
   // Resolved features state
-  const [resolvedFeatures, setResolvedFeatures] = useState<{
-    tools: Array<{ name: string; description?: string; server_id: string }>;
-    prompts: Array<{ name: string; description?: string; server_id: string }>;
-    resources: Array<{ name: string; description?: string; server_id: string }>;
-  } | null>(null);
+  const [resolvedFeatures, setResolvedFeatures] = useState<
+    Pick<ResolvedClientFeatures, 'tools' | 'prompts' | 'resources' | 'rules'> | null
+  >(null);
   const [isLoadingResolvedFeatures, setIsLoadingResolvedFeatures] = useState(false);
+  const [toolBudget, setToolBudget] = useState<ClientToolBudgetInfo | null>(null);
+  const [editMaxTools, setEditMaxTools] = useState('');
+  const [editMetaTools, setEditMetaTools] = useState(false);
+  const [editServerPriority, setEditServerPriority] = useState('');
+  const [rateLimits, setRateLimits] = useState<ClientRateLimit[]>([]);
+  const [newRateLimit, setNewRateLimit] = useState({
+    serverId: '',
+    toolName: '',
+    perMinute: '',
+    burst: '',
+    dailyQuota: '',
+  });
   
   // Individual features management
   const [availableFeatures, setAvailableFeatures] = useState<Array<{
@@ -212,12 +241,14 @@ export default function ClientsPage() {
     
     setIsLoadingGrants(true);
     try {
-      const [featureSets, grants] = await Promise.all([
+      const [featureSets, grants, details] = await Promise.all([
         listFeatureSetsBySpace(activeSpace.id),
         getOAuthClientGrants(clientId, activeSpace.id),
+        getOAuthClientGrantDetails(clientId, activeSpace.id).catch(() => [] as ClientGrant[]),
       ]);
       setAvailableFeatureSets(featureSets);
       setGrantedFeatureSetIds(grants);
+      setGrantDetails(details);
     } catch (e) {
       console.warn('Failed to load grants:', e);
     } finally {
@@ -240,7 +271,18 @@ export default function ClientsPage() {
         tools: resolved.tools,
         prompts: resolved.prompts,
         resources: resolved.resources,
+        rules: resolved.rules ?? [],
       });
+
+      try {
+        const budget = await getOAuthClientToolBudget(clientId, resolveSpaceId);
+        setToolBudget(budget);
+        setEditMaxTools(budget.max_tools?.toString() ?? '');
+        setEditServerPriority(budget.server_priority.join(', '));
+      } catch (e) {
+        console.warn('Failed to load tool budget:', e);
+        setToolBudget(null);
+      }
     } catch (e) {
       console.warn('Failed to load resolved features:', e);
       setResolvedFeatures(null);
@@ -249,6 +291,84 @@ export default function ClientsPage() {
     }
   };
 
+  const handleSaveToolBudget = async () => {
+    if (!selectedClient || !toolBudget) return;
+
+    const trimmed = editMaxTools.trim();
+    const maxTools = trimmed === '' ? null : Number.parseInt(trimmed, 10);
+    if (maxTools !== null && (Number.isNaN(maxTools) || maxTools <= 0)) {
+      showError('Invalid tool limit', 'Enter a positive number or leave empty for no limit');
+      return;
+    }
+
+    try {
+      await updateOAuthClientToolBudget(
+        selectedClient.client_id,
+        maxTools,
+        toolBudget.pinned_tools,
+        editServerPriority
+          .split(',')
+          .map((id) => id.trim())
+          .filter(Boolean)
+      );
+      await loadResolvedFeatures(selectedClient.client_id);
+      success('Tool limit saved', 'Applies the next time the client lists tools');
+    } catch (e) {
+      showError('Failed to save tool limit', String(e));
+    }
+  };
+
+  const loadRateLimits = async (clientId: string) => {
+    try {
+      setRateLimits(await listClientRateLimits(clientId));
+    } catch (e) {
+      console.warn('Failed to load rate limits:', e);
+      setRateLimits([]);
+    }
+  };
+
+  const handleAddRateLimit = async () => {
+    if (!selectedClient || !newRateLimit.serverId) return;
+
+    const parse = (value: string) => {
+      const trimmed = value.trim();
+      return trimmed === '' ? undefined : Number.parseInt(trimmed, 10);
+    };
+    const values = {
+      perMinute: parse(newRateLimit.perMinute),
+      burst: parse(newRateLimit.burst),
+      dailyQuota: parse(newRateLimit.dailyQuota),
+    };
+    if (Object.values(values).some(v => v !== undefined && (Number.isNaN(v) || v <= 0))) {
+      showError('Invalid rate limit', 'Limits must be positive numbers');
+      return;
+    }
+
+    try {
+      await setClientRateLimit(
+        selectedClient.client_id,
+        newRateLimit.serverId,
+        newRateLimit.toolName || null,
+        values
+      );
+      setNewRateLimit({ serverId: '', toolName: '', perMinute: '', burst: '', dailyQuota: '' });
+      await loadRateLimits(selectedClient.client_id);
+      success('Rate limit saved', 'Applies to the next tool call');
+    } catch (e) {
+      showError('Failed to save rate limit', String(e));
+    }
+  };
+
+  const handleDeleteRateLimit = async (limit: ClientRateLimit) => {
+    if (!selectedClient) return;
+    try {
+      await deleteClientRateLimit(selectedClient.client_id, limit.server_id, limit.tool_name);
+      await loadRateLimits(selectedClient.client_id);
+    } catch (e) {
+      showError('Failed to remove rate limit', String(e));
+    }
+  };
+
   const refreshOAuthClients = async () => {
     setIsRefreshingOAuth(true);
     try {
@@ -275,6 +395,7 @@ export default function ClientsPage() {
     loadAvailableFeatures();
     loadClientCustomFeatureSet(selectedClient);
     loadResolvedFeatures(selectedClient.client_id);
+    loadRateLimits(selectedClient.client_id);
   }, [activeSpace?.id, selectedClient?.client_id]);
 
   useEffect(() => {
@@ -305,6 +426,7 @@ export default function ClientsPage() {
     setEditAlias(client.client_alias || '');
     setEditMode(client.connection_mode);
     setEditLockedSpaceId(client.locked_space_id || '');
+    setEditMetaTools(client.meta_tools);
     
     // Reset collapsible states
     setExpandedSections({
@@ -338,10 +460,19 @@ export default function ClientsPage() {
         setGrantedFeatureSetIds(prev => prev.filter(id => id !== featureSetId));
         success('Permission revoked', `"${fsName}" removed from client`);
       } else {
-        await grantOAuthClientFeatureSet(selectedClient.client_id, activeSpace.id, featureSetId);
+        const hours = Number.parseFloat(grantHours);
+        const maxCalls = Number.parseInt(grantMaxCalls, 10);
+        const limits = {
+          expiresAt: hours > 0 ? new Date(Date.now() + hours * 3_600_000).toISOString() : undefined,
+          maxCalls: maxCalls > 0 ? maxCalls : undefined,
+        };
+        await grantOAuthClientFeatureSet(selectedClient.client_id, activeSpace.id, featureSetId, limits);
         setGrantedFeatureSetIds(prev => [...prev, featureSetId]);
         success('Permission granted', `"${fsName}" added to client`);
       }
+      getOAuthClientGrantDetails(selectedClient.client_id, activeSpace.id)
+        .then(setGrantDetails)
+        .catch(() => setGrantDetails([]));
       loadResolvedFeatures(selectedClient.client_id);
     } catch (e) {
       const msg = e instanceof Error ? e.message : String(e);
@@ -359,6 +490,7 @@ export default function ClientsPage() {
         client_alias: editAlias || undefined,
         connection_mode: editMode as 'follow_active' | 'locked' | 'ask_on_change',
         locked_space_id: undefined,
+        meta_tools: editMetaTools,
       };
       
       if (editMode === 'locked' && editLockedSpaceId) {
@@ -809,6 +941,16 @@ export default function ClientsPage() {
                       </div>
                     )}
 
+                    {/* Meta-tool mode */}
+                    <label className="flex items-center gap-2 text-xs text-[rgb(var(--muted))]">
+                      <input
+                        type="checkbox"
+                        checked={editMetaTools}
+                        onChange={(e) => setEditMetaTools(e.target.checked)}
+                      />
+                      Meta-tool mode: list only search_tools and invoke_tool
+                    </label>
+
                     {/* Save Button */}
                     <Button
                       onClick={handleSaveConfig}
@@ -900,11 +1042,34 @@ export default function ClientsPage() {
                             <div className="text-xs font-medium text-[rgb(var(--muted))] mb-2">
                               Feature Sets
                             </div>
+                            {/* Limits for new grants */}
+                            <div className="flex items-center gap-2 text-[10px] text-[rgb(var(--muted))]" data-testid="client-grant-limits">
+                              <span>New grants:</span>
+                              <select
+                                value={grantHours}
+                                onChange={(e) => setGrantHours(e.target.value)}
+                                className="px-1.5 py-1 rounded border border-[rgb(var(--border))] bg-transparent"
+                              >
+                                <option value="">No expiry</option>
+                                <option value="1">Expire in 1 hour</option>
+                                <option value="4">Expire in 4 hours</option>
+                                <option value="24">Expire in 1 day</option>
+                              </select>
+                              <input
+                                type="number"
+                                min={1}
+                                value={grantMaxCalls}
+                                onChange={(e) => setGrantMaxCalls(e.target.value)}
+                                placeholder="Max calls"
+                                className="w-20 px-1.5 py-1 rounded border border-[rgb(var(--border))] bg-transparent"
+                              />
+                            </div>
                             {availableFeatureSets
                               .filter(fs => !fs.name.endsWith(' - Custom'))
                               .slice(0, 5)
                               .map((fs) => {
                                 const isGranted = grantedFeatureSetIds.includes(fs.id);
+                                const grant = grantDetails.find(g => g.feature_set_id === fs.id);
                                 const isDefault = fs.feature_set_type === 'default';
                                 const isDisabled = isDefault;
                                 
@@ -948,6 +1113,14 @@ export default function ClientsPage() {
                                           {fs.description}
                                         </div>
                                       )}
+                                      {isGranted && grant && (grant.expires_at || grant.max_calls !== null) && (
+                                        <div className="text-[10px] text-amber-600 dark:text-amber-400 mt-0.5">
+                                          {[
+                                            grant.expires_at && `until ${new Date(grant.expires_at).toLocaleString()}`,
+                                            grant.max_calls !== null && `${grant.call_count}/${grant.max_calls} calls`,
+                                          ].filter(Boolean).join(' · ')}
+                                        </div>
+                                      )}
                                     </div>
                                   </button>
                                 );
@@ -1123,6 +1296,171 @@ export default function ClientsPage() {
                       </div>
                     ) : (
                       <div className="space-y-2">
+                        {/* Tool budget */}
+                        {toolBudget && toolBudget.total_tools > 0 && (
+                          <div className={`p-2 rounded-lg border text-xs space-y-2 ${
+                            toolBudget.exposed_tools > TOOL_COUNT_WARNING_THRESHOLD
+                              ? 'border-amber-300 dark:border-amber-700 bg-amber-50 dark:bg-amber-900/20'
+                              : 'border-[rgb(var(--border))]'
+                          }`}>
+                            <div className="flex items-start gap-2">
+                              {toolBudget.exposed_tools > TOOL_COUNT_WARNING_THRESHOLD && (
+                                <AlertCircle className="h-4 w-4 text-amber-600 dark:text-amber-400 flex-shrink-0" />
+                              )}
+                              <div>
+                                <div className="font-medium">
+                                  {toolBudget.exposed_tools} of {toolBudget.total_tools} tools exposed
+                                  {' '}(~{toolBudget.exposed_estimated_tokens.toLocaleString()} tokens)
+                                </div>
+                                {toolBudget.exposed_tools > TOOL_COUNT_WARNING_THRESHOLD && (
+                                  <div className="text-[10px] text-amber-700 dark:text-amber-300 mt-0.5">
+                                    Many clients degrade with more than {TOOL_COUNT_WARNING_THRESHOLD} tools.
+                                    Set a limit to keep the most used tools.
+                                  </div>
+                                )}
+                              </div>
+                            </div>
+                            <div className="flex items-center gap-2">
+                              <label className="text-[10px] text-[rgb(var(--muted))]" htmlFor="client-max-tools">
+                                Max tools
+                              </label>
+                              <input
+                                id="client-max-tools"
+                                type="number"
+                                min={1}
+                                value={editMaxTools}
+                                onChange={(e) => setEditMaxTools(e.target.value)}
+                                placeholder="No limit"
+                                className="w-24 px-2 py-1 text-xs rounded border border-[rgb(var(--border))] bg-transparent"
+                              />
+                              <Button size="sm" variant="secondary" onClick={handleSaveToolBudget}>
+                                Save
+                              </Button>
+                            </div>
+                            <div className="flex items-center gap-2">
+                              <label className="text-[10px] text-[rgb(var(--muted))]" htmlFor="client-server-priority">
+                                Server priority
+                              </label>
+                              <input
+                                id="client-server-priority"
+                                type="text"
+                                value={editServerPriority}
+                                onChange={(e) => setEditServerPriority(e.target.value)}
+                                placeholder="server IDs, highest first"
+                                className="flex-1 px-2 py-1 text-xs rounded border border-[rgb(var(--border))] bg-transparent"
+                              />
+                            </div>
+                          </div>
+                        )}
+
+                        {/* Rate limits and daily quotas */}
+                        <div className="p-2 rounded-lg border border-[rgb(var(--border))] text-xs space-y-2" data-testid="client-rate-limits">
+                          <div className="font-medium">Rate limits</div>
+                          {rateLimits.length === 0 && (
+                            <div className="text-[10px] text-[rgb(var(--muted))]">
+                              No limits. Calls to every tool are unthrottled.
+                            </div>
+                          )}
+                          {rateLimits.map((limit) => (
+                            <div key={`${limit.server_id}/${limit.tool_name ?? ''}`} className="flex items-center gap-2">
+                              <div className="min-w-0 flex-1">
+                                <code className="block truncate">
+                                  {limit.tool_name ? `${limit.server_id}/${limit.tool_name}` : `${limit.server_id} (all tools)`}
+                                </code>
+                                <div className="text-[10px] text-[rgb(var(--muted))]">
+                                  {[
+                                    limit.per_minute !== null &&
+                                      `${limit.available_calls ?? 0}/${limit.burst ?? limit.per_minute} available, ${limit.per_minute}/min`,
+                                    limit.daily_quota !== null &&
+                                      `${limit.quota_remaining ?? 0}/${limit.daily_quota} left today`,
+                                  ].filter(Boolean).join(' · ')}
+                                </div>
+                              </div>
+                              <button
+                                onClick={() => handleDeleteRateLimit(limit)}
+                                className="p-1 rounded hover:bg-[rgb(var(--surface-hover))] text-[rgb(var(--muted))]"
+                                title="Remove limit"
+                              >
+                                <X className="h-3 w-3" />
+                              </button>
+                            </div>
+                          ))}
+                          <div className="flex flex-wrap items-center gap-1.5">
+                            <select
+                              value={newRateLimit.serverId}
+                              onChange={(e) => setNewRateLimit(prev => ({ ...prev, serverId: e.target.value, toolName: '' }))}
+                              className="px-1.5 py-1 text-[10px] rounded border border-[rgb(var(--border))] bg-transparent"
+                            >
+                              <option value="">Server…</option>
+                              {[...new Set(availableFeatures.map(f => f.server_id))].map(serverId => (
+                                <option key={serverId} value={serverId}>{serverId}</option>
+                              ))}
+                            </select>
+                            <select
+                              value={newRateLimit.toolName}
+                              onChange={(e) => setNewRateLimit(prev => ({ ...prev, toolName: e.target.value }))}
+                              disabled={!newRateLimit.serverId}
+                              className="px-1.5 py-1 text-[10px] rounded border border-[rgb(var(--border))] bg-transparent"
+                            >
+                              <option value="">All tools</option>
+                              {availableFeatures
+                                .filter(f => f.feature_type === 'tool' && f.server_id === newRateLimit.serverId)
+                                .map(f => (
+                                  <option key={f.id} value={f.feature_name}>{f.feature_name}</option>
+                                ))}
+                            </select>
+                            {([
+                              ['perMinute', 'Per min'],
+                              ['burst', 'Burst'],
+                              ['dailyQuota', 'Per day'],
+                            ] as const).map(([field, placeholder]) => (
+                              <input
+                                key={field}
+                                type="number"
+                                min={1}
+                                value={newRateLimit[field]}
+                                onChange={(e) => setNewRateLimit(prev => ({ ...prev, [field]: e.target.value }))}
+                                placeholder={placeholder}
+                                className="w-16 px-1.5 py-1 text-[10px] rounded border border-[rgb(var(--border))] bg-transparent"
+                              />
+                            ))}
+                            <Button
+                              size="sm"
+                              variant="secondary"
+                              onClick={handleAddRateLimit}
+                              disabled={!newRateLimit.serverId || (!newRateLimit.perMinute && !newRateLimit.dailyQuota)}
+                            >
+                              Set
+                            </Button>
+                          </div>
+                        </div>
+
+                        {/* Rule members and what they match right now */}
+                        {resolvedFeatures.rules.length > 0 && (
+                          <div className="p-2 rounded-lg border border-[rgb(var(--border))] text-xs space-y-1" data-testid="client-resolved-rules">
+                            <div className="font-medium">Rules</div>
+                            {resolvedFeatures.rules.map((rule) => (
+                              <div key={`${rule.feature_set_id}:${rule.mode}:${rule.expression}`} className="flex items-start gap-2">
+                                <span className={`text-[10px] px-1.5 py-0.5 rounded font-medium ${
+                                  rule.mode === 'include'
+                                    ? 'bg-green-100 dark:bg-green-900/30 text-green-700 dark:text-green-300'
+                                    : 'bg-red-100 dark:bg-red-900/30 text-red-700 dark:text-red-300'
+                                }`}>
+                                  {rule.mode}
+                                </span>
+                                <div className="min-w-0">
+                                  <code className="block truncate">{rule.expression}</code>
+                                  <div className="text-[10px] text-[rgb(var(--muted))] line-clamp-2">
+                                    {rule.matches.length === 0
+                                      ? 'Matches nothing yet'
+                                      : `${rule.matches.length} match${rule.matches.length !== 1 ? 'es' : ''}: ${rule.matches.join(', ')}`}
+                                  </div>
+                                </div>
+                              </div>
+                            ))}
+                          </div>
+                        )}
+
                         {/* Tools */}
                         {resolvedFeatures.tools.length > 0 && (
                           <div className="border border-blue-200 dark:border-blue-800 rounded-lg overflow-hidden">
@@ -1155,6 +1493,11 @@ export default function ClientsPage() {
                                     <div className="font-medium text-xs text-blue-900 dark:text-blue-100 truncate">
                                       {tool.name}
                                     </div>
+                                    {tool.matched_rules?.length > 0 && (
+                                      <div className="text-[10px] text-blue-600 dark:text-blue-400 truncate">
+                                        via {tool.matched_rules.join(', ')}
+                                      </div>
+                                    )}
                                     {tool.description && (
                                       <div className="text-[10px] text-blue-700 dark:text-blue-300 mt-0.5 line-clamp-2">
                                         {tool.description}
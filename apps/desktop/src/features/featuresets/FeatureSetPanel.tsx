@@ -19,12 +19,35 @@ import {
   Star,
   Shield,
   Save,
+  Filter,
+  Plus,
 } from 'lucide-react';
 import { Button, useToast, ToastContainer } from '@mcpmux/ui';
-import type { FeatureSet, AddMemberInput } from '@/lib/api/featureSets';
-import { setFeatureSetMembers } from '@/lib/api/featureSets';
+import type { FeatureSet, FeatureSetMember, AddMemberInput, MemberMode } from '@/lib/api/featureSets';
+import {
+  addFeatureSetMember,
+  removeFeatureSetMember,
+  setFeatureSetMembers,
+  updateFeatureSet,
+} from '@/lib/api/featureSets';
 import type { ServerFeature } from '@/lib/api/serverFeatures';
 import { listServerFeatures } from '@/lib/api/serverFeatures';
+import {
+  listToolApprovalPolicies,
+  setToolApprovalPolicy,
+  type ApprovalPolicy,
+} from '@/lib/api/toolApproval';
+import {
+  listArgumentPolicies,
+  setArgumentPolicy,
+  type ArgumentPolicy,
+  type ToolArgumentPolicy,
+} from '@/lib/api/argumentPolicies';
+import {
+  listFeatureOverrides,
+  setFeatureOverride,
+  type FeatureOverride,
+} from '@/lib/api/featureOverrides';
 
 interface FeatureSetPanelProps {
   featureSet: FeatureSet;
@@ -34,6 +57,9 @@ interface FeatureSetPanelProps {
   onUpdate?: () => void;
 }
 
+/** Key a global feature set stores a feature under, since feature IDs differ per space */
+const portableKey = (f: ServerFeature) => `${f.server_id}:${f.feature_type}:${f.feature_name}`;
+
 interface ServerGroup {
   serverId: string;
   features: ServerFeature[];
@@ -43,12 +69,32 @@ interface ServerGroup {
 export function FeatureSetPanel({ featureSet, spaceId, onClose, onDelete, onUpdate }: FeatureSetPanelProps) {
   const [allFeatures, setAllFeatures] = useState<ServerFeature[]>([]);
   const [selectedFeatureIds, setSelectedFeatureIds] = useState<Set<string>>(new Set());
+  // Global set members naming features this space doesn't have; kept on save
+  const [otherSpaceKeys, setOtherSpaceKeys] = useState<string[]>([]);
   const [searchQuery, setSearchQuery] = useState('');
   const [isLoading, setIsLoading] = useState(true);
   const [isSaving, setIsSaving] = useState(false);
   const [error, setError] = useState<string | null>(null);
   const [expandedServers, setExpandedServers] = useState<Set<string>>(new Set());
   const { toasts, success, error: showError, dismiss } = useToast();
+  // Approval policy for tools in this set ('' = no rule)
+  const [approvalPolicy, setApprovalPolicy] = useState<ApprovalPolicy | ''>('');
+  const [readOnly, setReadOnly] = useState(featureSet.read_only ?? false);
+  // Rule members, matched against current features whenever grants resolve
+  const [rules, setRules] = useState<FeatureSetMember[]>(
+    () => featureSet.members?.filter((m) => m.member_type === 'rule') ?? []
+  );
+  const [newRule, setNewRule] = useState('');
+  const [newRuleMode, setNewRuleMode] = useState<MemberMode>('include');
+  // Argument policies, edited as JSON one tool at a time
+  const [argumentPolicies, setArgumentPolicies] = useState<ToolArgumentPolicy[]>([]);
+  const [policyFeatureId, setPolicyFeatureId] = useState('');
+  const [policyJson, setPolicyJson] = useState('');
+  // Name/title/description overrides for the space and this set
+  const [overrides, setOverrides] = useState<FeatureOverride[]>([]);
+  const [overrideFeatureId, setOverrideFeatureId] = useState('');
+  const [overrideForSet, setOverrideForSet] = useState(false);
+  const [overrideValues, setOverrideValues] = useState({ name: '', title: '', description: '' });
 
   // Collapsible sections - only one expanded at a time, features by default
   const [expandedSections, setExpandedSections] = useState({
@@ -62,6 +108,7 @@ export function FeatureSetPanel({ featureSet, spaceId, onClose, onDelete, onUpda
   const isCustom = featureSet.feature_set_type === 'custom';
   const isAll = featureSet.feature_set_type === 'all';
   const isServerAll = featureSet.feature_set_type === 'server-all';
+  const isGlobal = featureSet.space_id === null;
   
   // For special feature sets, compute actual member count
   const getActualMemberCount = () => {
@@ -100,6 +147,7 @@ export function FeatureSetPanel({ featureSet, spaceId, onClose, onDelete, onUpda
         
         // Initialize selected features from current members
         const currentIds = new Set<string>();
+        const unmatchedKeys: string[] = [];
         
         // For special feature sets, compute selection dynamically
         if (featureSet.feature_set_type === 'all') {
@@ -112,6 +160,19 @@ export function FeatureSetPanel({ featureSet, spaceId, onClose, onDelete, onUpda
               currentIds.add(f.id);
             }
           });
+        } else if (isGlobal) {
+          // Global sets store portable keys; match them to this space's features
+          const idsByKey = new Map(features.map((f) => [portableKey(f), f.id]));
+          featureSet.members?.forEach((m) => {
+            if (m.member_type === 'feature' && m.mode === 'include') {
+              const id = idsByKey.get(m.member_id);
+              if (id) {
+                currentIds.add(id);
+              } else {
+                unmatchedKeys.push(m.member_id);
+              }
+            }
+          });
         } else {
           // For configurable sets (default/custom), use members array
           featureSet.members?.forEach((m) => {
@@ -122,6 +183,7 @@ export function FeatureSetPanel({ featureSet, spaceId, onClose, onDelete, onUpda
         }
         
         setSelectedFeatureIds(currentIds);
+        setOtherSpaceKeys(unmatchedKeys);
         
         // Start with all servers collapsed
         setExpandedServers(new Set());
@@ -133,7 +195,7 @@ export function FeatureSetPanel({ featureSet, spaceId, onClose, onDelete, onUpda
     };
     
     loadFeatures();
-  }, [spaceId, featureSet]);
+  }, [spaceId, featureSet, isGlobal]);
 
   // Group features by server
   const serverGroups: ServerGroup[] = allFeatures.reduce((acc, feature) => {
@@ -205,18 +267,166 @@ export function FeatureSetPanel({ featureSet, spaceId, onClose, onDelete, onUpda
     });
   };
 
+  // Load this set's approval policy
+  useEffect(() => {
+    listToolApprovalPolicies(spaceId)
+      .then((rules) => {
+        const rule = rules.find(
+          (r) => r.target_type === 'feature_set' && r.target === featureSet.id
+        );
+        setApprovalPolicy(rule?.policy ?? '');
+      })
+      .catch((e) => console.error('Failed to load approval policy:', e));
+  }, [spaceId, featureSet.id]);
+
+  const handleApprovalPolicyChange = async (policy: ApprovalPolicy | '') => {
+    const previous = approvalPolicy;
+    setApprovalPolicy(policy);
+    try {
+      await setToolApprovalPolicy(spaceId, 'feature_set', featureSet.id, policy || null);
+      success('Approval policy saved', policy ? `Tools in "${featureSet.name}": ${policy}` : 'Rule removed');
+    } catch (e) {
+      setApprovalPolicy(previous);
+      showError('Failed to save approval policy', e instanceof Error ? e.message : String(e));
+    }
+  };
+
+  // Load this set's argument policies
+  useEffect(() => {
+    listArgumentPolicies(featureSet.id)
+      .then(setArgumentPolicies)
+      .catch((e) => console.error('Failed to load argument policies:', e));
+  }, [featureSet.id]);
+
+  const handlePolicyToolChange = (featureId: string) => {
+    setPolicyFeatureId(featureId);
+    const existing = argumentPolicies.find((p) => p.feature_id === featureId);
+    const policy: ArgumentPolicy = {
+      fixed: existing?.fixed ?? {},
+      defaults: existing?.defaults ?? {},
+      allowed_values: existing?.allowed_values ?? {},
+      forbidden: existing?.forbidden ?? [],
+    };
+    setPolicyJson(featureId ? JSON.stringify(policy, null, 2) : '');
+  };
+
+  const handleSaveArgumentPolicy = async (remove: boolean) => {
+    if (!policyFeatureId) return;
+    try {
+      const policy = remove ? null : (JSON.parse(policyJson) as ArgumentPolicy);
+      await setArgumentPolicy(featureSet.id, policyFeatureId, policy);
+      setArgumentPolicies(await listArgumentPolicies(featureSet.id));
+      if (remove) handlePolicyToolChange('');
+      success(remove ? 'Argument policy removed' : 'Argument policy saved');
+    } catch (e) {
+      showError('Failed to save argument policy', e instanceof Error ? e.message : String(e));
+    }
+  };
+
+  // Load the space's feature overrides
+  useEffect(() => {
+    listFeatureOverrides(spaceId)
+      .then(setOverrides)
+      .catch((e) => console.error('Failed to load feature overrides:', e));
+  }, [spaceId]);
+
+  const findOverride = (featureId: string, forSet: boolean) => {
+    const feature = allFeatures.find((f) => f.id === featureId);
+    if (!feature) return undefined;
+    return overrides.find(
+      (o) =>
+        o.server_id === feature.server_id &&
+        o.feature_type === feature.feature_type &&
+        o.feature_name === feature.feature_name &&
+        o.feature_set_id === (forSet ? featureSet.id : null)
+    );
+  };
+
+  const selectOverride = (featureId: string, forSet: boolean) => {
+    setOverrideFeatureId(featureId);
+    setOverrideForSet(forSet);
+    const existing = findOverride(featureId, forSet);
+    setOverrideValues({
+      name: existing?.name ?? '',
+      title: existing?.title ?? '',
+      description: existing?.description ?? '',
+    });
+  };
+
+  const handleSaveOverride = async () => {
+    if (!overrideFeatureId) return;
+    try {
+      await setFeatureOverride(
+        spaceId,
+        overrideFeatureId,
+        overrideForSet ? featureSet.id : null,
+        overrideValues
+      );
+      setOverrides(await listFeatureOverrides(spaceId));
+      success('Override saved', 'Connected clients will refresh their tool list');
+    } catch (e) {
+      showError('Failed to save override', e instanceof Error ? e.message : String(e));
+    }
+  };
+
+  const handleReadOnlyChange = async (value: boolean) => {
+    setReadOnly(value);
+    try {
+      await updateFeatureSet(featureSet.id, { read_only: value });
+      success('Feature set updated', value ? 'Only read-only tools are granted' : 'All selected tools are granted');
+      onUpdate?.();
+    } catch (e) {
+      setReadOnly(!value);
+      showError('Failed to update feature set', e instanceof Error ? e.message : String(e));
+    }
+  };
+
+  const handleAddRule = async () => {
+    const expression = newRule.trim();
+    if (!expression) return;
+    try {
+      const updated = await addFeatureSetMember(featureSet.id, {
+        member_type: 'rule',
+        member_id: expression,
+        mode: newRuleMode,
+      });
+      setRules(updated.members.filter((m) => m.member_type === 'rule'));
+      setNewRule('');
+      success('Rule added', expression);
+      onUpdate?.();
+    } catch (e) {
+      showError('Failed to add rule', e instanceof Error ? e.message : String(e));
+    }
+  };
+
+  const handleRemoveRule = async (rule: FeatureSetMember) => {
+    try {
+      const updated = await removeFeatureSetMember(featureSet.id, rule.id);
+      setRules(updated.members.filter((m) => m.member_type === 'rule'));
+      onUpdate?.();
+    } catch (e) {
+      showError('Failed to remove rule', e instanceof Error ? e.message : String(e));
+    }
+  };
+
   const handleSave = async () => {
     setIsSaving(true);
     setError(null);
     try {
       // Update members
-      const members: AddMemberInput[] = Array.from(selectedFeatureIds).map((id) => ({
+      const members: AddMemberInput[] = [...selectedFeatureIds, ...otherSpaceKeys].map((id) => ({
         member_type: 'feature' as const,
         member_id: id,
         mode: 'include' as const,
       }));
-      
-      await setFeatureSetMembers(featureSet.id, members);
+      // Keep rule members; they are edited separately
+      const ruleMembers: AddMemberInput[] = rules.map((r) => ({
+        member_type: 'rule' as const,
+        member_id: r.member_id,
+        mode: r.mode,
+      }));
+
+      await setFeatureSetMembers(featureSet.id, [...members, ...ruleMembers]);
 
       success('Changes saved', `"${featureSet.name}" has been updated with ${members.length} feature${members.length !== 1 ? 's' : ''}`);
       onUpdate?.();
@@ -364,6 +574,162 @@ export function FeatureSetPanel({ featureSet, spaceId, onClose, onDelete, onUpda
                   </p>
                 </div>
                 
+                <div>
+                  <label className="block text-xs font-medium mb-1.5 text-[rgb(var(--muted))]">
+                    Tool Approval
+                  </label>
+                  <select
+                    value={approvalPolicy}
+                    onChange={(e) => handleApprovalPolicyChange(e.target.value as ApprovalPolicy | '')}
+                    className="w-full px-3 py-1.5 text-sm border border-[rgb(var(--border))] rounded-lg bg-[rgb(var(--surface))] text-[rgb(var(--foreground))]"
+                    data-testid="feature-set-approval-policy"
+                  >
+                    <option value="">No rule (destructive-tool default)</option>
+                    <option value="allow">Allow</option>
+                    <option value="ask">Ask before each call</option>
+                    <option value="deny">Deny</option>
+                  </select>
+                  <p className="text-xs text-[rgb(var(--muted))] mt-1">
+                    Applies to every tool in this set. When a tool is in several sets, the strictest policy wins.
+                  </p>
+                </div>
+
+                {isConfigurable && (
+                  <div data-testid="feature-set-argument-policies">
+                    <label className="block text-xs font-medium mb-1.5 text-[rgb(var(--muted))]">
+                      Argument Policies
+                    </label>
+                    <select
+                      value={policyFeatureId}
+                      onChange={(e) => handlePolicyToolChange(e.target.value)}
+                      className="w-full px-3 py-1.5 text-sm border border-[rgb(var(--border))] rounded-lg bg-[rgb(var(--surface))] text-[rgb(var(--foreground))]"
+                    >
+                      <option value="">Select a tool...</option>
+                      {allFeatures
+                        .filter((f) => f.feature_type === 'tool' && selectedFeatureIds.has(f.id))
+                        .map((f) => (
+                          <option key={f.id} value={f.id}>
+                            {f.server_id}: {f.display_name ?? f.feature_name}
+                            {argumentPolicies.some((p) => p.feature_id === f.id) ? ' (policy)' : ''}
+                          </option>
+                        ))}
+                    </select>
+                    {policyFeatureId && (
+                      <div className="mt-2 space-y-2">
+                        <textarea
+                          value={policyJson}
+                          onChange={(e) => setPolicyJson(e.target.value)}
+                          rows={8}
+                          spellCheck={false}
+                          className="w-full px-3 py-2 text-xs font-mono rounded-lg border border-[rgb(var(--border))] bg-[rgb(var(--background))] focus:outline-none focus:ring-2 focus:ring-primary-500"
+                          data-testid="feature-set-argument-policy-json"
+                        />
+                        <div className="flex justify-end gap-2">
+                          <Button variant="ghost" size="sm" onClick={() => handleSaveArgumentPolicy(true)}>
+                            Remove
+                          </Button>
+                          <Button variant="primary" size="sm" onClick={() => handleSaveArgumentPolicy(false)}>
+                            <Save className="h-3.5 w-3.5 mr-1" />
+                            Save Policy
+                          </Button>
+                        </div>
+                      </div>
+                    )}
+                    <p className="text-xs text-[rgb(var(--muted))] mt-1">
+                      Pin (<code>fixed</code>), default, restrict (<code>allowed_values</code>) or hide (<code>forbidden</code>) a tool's arguments for clients granted this set.
+                    </p>
+                  </div>
+                )}
+
+                {isConfigurable && (
+                  <div data-testid="feature-set-overrides">
+                    <label className="block text-xs font-medium mb-1.5 text-[rgb(var(--muted))]">
+                      Names &amp; Descriptions
+                    </label>
+                    <select
+                      value={overrideFeatureId}
+                      onChange={(e) => selectOverride(e.target.value, overrideForSet)}
+                      className="w-full px-3 py-1.5 text-sm border border-[rgb(var(--border))] rounded-lg bg-[rgb(var(--surface))] text-[rgb(var(--foreground))]"
+                    >
+                      <option value="">Select a tool or prompt...</option>
+                      {allFeatures
+                        .filter((f) => f.feature_type !== 'resource' && selectedFeatureIds.has(f.id))
+                        .map((f) => {
+                          const existing = findOverride(f.id, false) ?? findOverride(f.id, true);
+                          return (
+                            <option key={f.id} value={f.id}>
+                              {f.server_id}: {f.feature_name}
+                              {existing ? (existing.stale ? ' (override, changed since)' : ' (override)') : ''}
+                            </option>
+                          );
+                        })}
+                    </select>
+                    {overrideFeatureId && (
+                      <div className="mt-2 space-y-2">
+                        <label className="flex items-center gap-2 text-xs">
+                          <input
+                            type="checkbox"
+                            checked={overrideForSet}
+                            onChange={(e) => selectOverride(overrideFeatureId, e.target.checked)}
+                          />
+                          Only for clients granted this feature set
+                        </label>
+                        {findOverride(overrideFeatureId, overrideForSet)?.stale && (
+                          <p className="text-xs text-yellow-700 dark:text-yellow-300">
+                            The server changed this feature since the override was saved. Review and save again.
+                          </p>
+                        )}
+                        {(['name', 'title'] as const).map((field) => (
+                          <input
+                            key={field}
+                            type="text"
+                            value={overrideValues[field]}
+                            onChange={(e) => setOverrideValues({ ...overrideValues, [field]: e.target.value })}
+                            placeholder={field === 'name' ? 'Exposed name (e.g. find_repos)' : 'Title'}
+                            className="w-full px-3 py-1.5 text-sm rounded-lg border border-[rgb(var(--border))] bg-[rgb(var(--background))] focus:outline-none focus:ring-2 focus:ring-primary-500"
+                            data-testid={`feature-override-${field}`}
+                          />
+                        ))}
+                        <textarea
+                          value={overrideValues.description}
+                          onChange={(e) => setOverrideValues({ ...overrideValues, description: e.target.value })}
+                          rows={3}
+                          placeholder="Description"
+                          className="w-full px-3 py-2 text-sm rounded-lg border border-[rgb(var(--border))] bg-[rgb(var(--background))] focus:outline-none focus:ring-2 focus:ring-primary-500"
+                          data-testid="feature-override-description"
+                        />
+                        <div className="flex justify-end">
+                          <Button variant="primary" size="sm" onClick={handleSaveOverride}>
+                            <Save className="h-3.5 w-3.5 mr-1" />
+                            Save Override
+                          </Button>
+                        </div>
+                      </div>
+                    )}
+                    <p className="text-xs text-[rgb(var(--muted))] mt-1">
+                      Rename a tool or rewrite its description as clients see it. Leave all fields empty to remove the override.
+                    </p>
+                  </div>
+                )}
+
+                {isCustom && (
+                  <label className="flex cursor-pointer items-start gap-2">
+                    <input
+                      type="checkbox"
+                      className="mt-0.5"
+                      checked={readOnly}
+                      onChange={(e) => handleReadOnlyChange(e.target.checked)}
+                      data-testid="feature-set-read-only"
+                    />
+                    <span>
+                      <span className="block text-sm font-medium">Read-only</span>
+                      <span className="block text-xs text-[rgb(var(--muted))]">
+                        Only grant tools the server marks as read-only. Prompts and resources are unaffected.
+                      </span>
+                    </span>
+                  </label>
+                )}
+
                 {isDefault && (
                   <div className="p-3 bg-yellow-50 dark:bg-yellow-900/10 border border-yellow-200 dark:border-yellow-800 rounded-lg">
                     <div className="flex gap-2">
@@ -434,6 +800,65 @@ export function FeatureSetPanel({ featureSet, spaceId, onClose, onDelete, onUpda
 
             {expandedSections.features && (
               <div className="border-t-2 border-[rgb(var(--border))] bg-white dark:bg-[rgb(var(--background))] flex flex-col h-[500px]">
+                {/* Rule members */}
+                {isConfigurable && (
+                  <div className="p-3 border-b border-[rgb(var(--border))] bg-[rgb(var(--surface))] space-y-2" data-testid="feature-set-rules">
+                    <div className="flex items-center gap-2 text-xs font-medium text-[rgb(var(--muted))]">
+                      <Filter className="h-3.5 w-3.5" />
+                      Rules
+                    </div>
+                    {rules.map((rule) => (
+                      <div key={rule.id} className="flex items-center gap-2 text-sm">
+                        <span className={`text-[10px] px-1.5 py-0.5 rounded font-medium ${
+                          rule.mode === 'include'
+                            ? 'bg-green-100 dark:bg-green-900/30 text-green-700 dark:text-green-300'
+                            : 'bg-red-100 dark:bg-red-900/30 text-red-700 dark:text-red-300'
+                        }`}>
+                          {rule.mode}
+                        </span>
+                        <code className="flex-1 truncate text-xs">{rule.member_id}</code>
+                        <button
+                          onClick={() => handleRemoveRule(rule)}
+                          className="p-1 rounded hover:bg-[rgb(var(--surface-hover))]"
+                          title="Remove rule"
+                        >
+                          <X className="h-3.5 w-3.5" />
+                        </button>
+                      </div>
+                    ))}
+                    <div className="flex items-center gap-2">
+                      <select
+                        value={newRuleMode}
+                        onChange={(e) => setNewRuleMode(e.target.value as MemberMode)}
+                        className="px-2 py-1.5 text-xs border border-[rgb(var(--border))] rounded-lg bg-[rgb(var(--background))]"
+                      >
+                        <option value="include">Include</option>
+                        <option value="exclude">Exclude</option>
+                      </select>
+                      <input
+                        type="text"
+                        value={newRule}
+                        onChange={(e) => setNewRule(e.target.value)}
+                        onKeyDown={(e) => e.key === 'Enter' && handleAddRule()}
+                        placeholder="server:github type:tool annotation:readOnlyHint=true"
+                        className="flex-1 px-3 py-1.5 text-xs font-mono rounded-lg border border-[rgb(var(--border))] bg-[rgb(var(--background))] focus:outline-none focus:ring-2 focus:ring-primary-500"
+                        data-testid="feature-set-rule-input"
+                      />
+                      <button
+                        onClick={handleAddRule}
+                        disabled={!newRule.trim()}
+                        className="p-1.5 rounded-lg hover:bg-[rgb(var(--surface-hover))] disabled:opacity-50"
+                        title="Add rule"
+                      >
+                        <Plus className="h-4 w-4" />
+                      </button>
+                    </div>
+                    <p className="text-[11px] text-[rgb(var(--muted))]">
+                      Match by <code>name:</code>, <code>server:</code> (glob, or /regex/), <code>type:</code> or <code>annotation:</code>. Rules also pick up tools added later.
+                    </p>
+                  </div>
+                )}
+
                 {/* Search Bar inside panel */}
                 <div className="p-3 border-b border-[rgb(var(--border))] bg-[rgb(var(--surface))]">
                   <div className="relative">
@@ -77,6 +77,7 @@ export function FeatureSetsPage() {
   const [createName, setCreateName] = useState('');
   const [createDescription, setCreateDescription] = useState('');
   const [createIcon, setCreateIcon] = useState('');
+  const [createGlobal, setCreateGlobal] = useState(false);
   
   // Panel state
   const [selectedFeatureSet, setSelectedFeatureSet] = useState<FeatureSet | null>(null);
@@ -114,7 +115,7 @@ export function FeatureSetsPage() {
     try {
       const input: CreateFeatureSetInput = {
         name: createName.trim(),
-        space_id: viewSpace.id,
+        space_id: createGlobal ? null : viewSpace.id,
         description: createDescription.trim() || undefined,
         icon: createIcon.trim() || undefined,
       };
@@ -123,6 +124,7 @@ export function FeatureSetsPage() {
       setCreateName('');
       setCreateDescription('');
       setCreateIcon('');
+      setCreateGlobal(false);
       setShowCreateModal(false);
       
       success('Feature set created', `"${newFs.name}" has been created successfully`);
@@ -317,6 +319,15 @@ export function FeatureSetsPage() {
                           }`}>
                             {getFeatureSetTypeName(fs.feature_set_type)}
                           </span>
+                          {fs.space_id === null && (
+                            <span
+                              className="ml-1.5 inline-flex items-center gap-1 px-2.5 py-0.5 rounded-full text-xs font-medium bg-green-100 dark:bg-green-900/30 text-green-700 dark:text-green-300"
+                              title="Applies in every space"
+                            >
+                              <Globe className="h-3 w-3" />
+                              Global
+                            </span>
+                          )}
                         </div>
                       </div>
 
@@ -426,6 +437,22 @@ export function FeatureSetsPage() {
                   maxLength={2}
                 />
               </div>
+
+              <label className="flex items-start gap-2 text-sm">
+                <input
+                  type="checkbox"
+                  className="mt-1"
+                  checked={createGlobal}
+                  onChange={(e) => setCreateGlobal(e.target.checked)}
+                  data-testid="featureset-create-global"
+                />
+                <span>
+                  Global
+                  <span className="block text-xs text-[rgb(var(--muted))]">
+                    Usable in every space, e.g. an org-wide policy like excluding delete_* tools
+                  </span>
+                </span>
+              </label>
               
               <div className="flex gap-3 pt-2">
                 <Button variant="ghost" onClick={() => setShowCreateModal(false)}>
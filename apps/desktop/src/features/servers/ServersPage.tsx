@@ -19,11 +19,18 @@ import {
   Clock,
   FileJson,
   FolderOpen,
+  Download,
 } from 'lucide-react';
 import { ServerActionMenu } from './ServerActionMenu';
-import type { ServerViewModel, ServerDefinition, InstalledServerState, InputDefinition } from '../../types/registry';
+import type {
+  ServerViewModel,
+  ServerDefinition,
+  InstalledServerState,
+  InputDefinition,
+  ConcurrencyLimit,
+} from '../../types/registry';
 import type { ServerFeature } from '@/lib/api/serverFeatures';
-import { listServerFeaturesByServer } from '@/lib/api/serverFeatures';
+import { approveServerFeatureDefinition, listServerFeaturesByServer } from '@/lib/api/serverFeatures';
 import type { ConnectionStatus, ServerStatusResponse } from '@/lib/api/serverManager';
 import { getServerStatuses as fetchServerStatuses } from '@/lib/api/serverManager';
 import { useViewSpace } from '@/stores';
@@ -33,6 +40,7 @@ import type { GatewayChangedPayload, ServerChangedPayload } from '@/hooks/useDom
 import type { FeaturesUpdatedEvent } from '@/lib/api/serverManager';
 import { ServerLogViewer } from '@/components/ServerLogViewer';
 import { ConfigEditorModal } from '@/components/ConfigEditorModal';
+import { ImportServersModal } from '@/components/ImportServersModal';
 import { ServerDefinitionModal } from '@/components/ServerDefinitionModal';
 import { SourceBadge } from '@/components/SourceBadge';
 
@@ -71,7 +79,12 @@ function mergeDefinitionsWithStates(
       installation_source: state?.source, // Track how server was installed
       env_overrides: state?.env_overrides ?? {},
       args_append: state?.args_append ?? [],
+      cwd_override: state?.cwd_override ?? null,
+      env_file_override: state?.env_file_override ?? null,
       extra_headers: state?.extra_headers ?? {},
+      read_only: state?.read_only ?? false,
+      concurrency_limit: state?.concurrency_limit ?? null,
+      skip_argument_validation: state?.skip_argument_validation ?? false,
     } as ServerViewModel;
   });
 }
@@ -105,7 +118,12 @@ function createOfflineServerViewModel(state: InstalledServerState): ServerViewMo
         installation_source: state.source,
         env_overrides: state.env_overrides ?? {},
         args_append: state.args_append ?? [],
+        cwd_override: state.cwd_override ?? null,
+        env_file_override: state.env_file_override ?? null,
         extra_headers: state.extra_headers ?? {},
+        read_only: state.read_only ?? false,
+        concurrency_limit: state.concurrency_limit ?? null,
+        skip_argument_validation: state.skip_argument_validation ?? false,
       } as ServerViewModel;
     } catch (e) {
       console.warn('[ServersPage] Failed to parse cached_definition, using minimal fallback:', e);
@@ -141,7 +159,12 @@ function createOfflineServerViewModel(state: InstalledServerState): ServerViewMo
     installation_source: state.source,
     env_overrides: state.env_overrides ?? {},
     args_append: state.args_append ?? [],
+    cwd_override: state.cwd_override ?? null,
+    env_file_override: state.env_file_override ?? null,
     extra_headers: state.extra_headers ?? {},
+    read_only: state.read_only ?? false,
+    concurrency_limit: state.concurrency_limit ?? null,
+    skip_argument_validation: state.skip_argument_validation ?? false,
   } as ServerViewModel;
 }
 
@@ -156,8 +179,50 @@ interface ConfigModalState {
   envOverrides: Record<string, string>;
   /** Additional arguments to append (stdio only) */
   argsAppend: string[];
+  /** Working directory override (stdio only, empty = definition's) */
+  cwd?: string;
+  /** .env file override (stdio only, empty = definition's) */
+  envFile?: string;
   /** Extra HTTP headers (http only) */
   extraHeaders: Record<string, string>;
+  /** Launch in the server's declared read-only mode */
+  readOnly?: boolean;
+  /** Send tool calls without checking arguments against input schemas */
+  skipArgumentValidation?: boolean;
+  /** Concurrency limit inputs (empty max = unlimited) */
+  concurrency?: ConcurrencyInputs;
+}
+
+interface ConcurrencyInputs {
+  maxConcurrent: string;
+  maxQueued: string;
+  timeoutSecs: string;
+}
+
+function concurrencyInputs(limit: ConcurrencyLimit | null | undefined): ConcurrencyInputs {
+  return {
+    maxConcurrent: limit ? String(limit.max_concurrent_requests) : '',
+    maxQueued: String(limit?.max_queued_requests ?? 32),
+    timeoutSecs: String(limit?.queue_timeout_secs ?? 30),
+  };
+}
+
+/** Parse concurrency inputs; null means unlimited */
+function parseConcurrencyInputs(inputs: ConcurrencyInputs): ConcurrencyLimit | null {
+  if (inputs.maxConcurrent.trim() === '') return null;
+  const limit = {
+    max_concurrent_requests: Number.parseInt(inputs.maxConcurrent, 10),
+    max_queued_requests: Number.parseInt(inputs.maxQueued, 10),
+    queue_timeout_secs: Number.parseInt(inputs.timeoutSecs, 10),
+  };
+  if (
+    !(limit.max_concurrent_requests > 0) ||
+    !(limit.max_queued_requests >= 0) ||
+    !(limit.queue_timeout_secs > 0)
+  ) {
+    throw new Error('Concurrency limits must be positive numbers');
+  }
+  return limit;
 }
 
 export function ServersPage() {
@@ -190,6 +255,9 @@ export function ServersPage() {
   
   // Config editor state
   const [editConfigSpace, setEditConfigSpace] = useState<{ id: string; name: string } | null>(null);
+
+  // Import from clients modal state
+  const [importSpace, setImportSpace] = useState<{ id: string; name: string } | null>(null);
   
   const viewSpace = useViewSpace();
   
@@ -397,6 +465,17 @@ export function ServersPage() {
     }
   };
 
+  // Approve a changed feature definition (lifts the quarantine)
+  const handleApproveDefinition = async (feature: ServerFeature) => {
+    try {
+      await approveServerFeatureDefinition(feature.id);
+      showToast(`Approved new definition of ${feature.feature_name}`, 'success');
+      await loadFeaturesForServer(feature.server_id);
+    } catch (e) {
+      showToast(String(e), 'error');
+    }
+  };
+
   // Toggle server expansion
   const toggleExpanded = (serverId: string) => {
     setExpandedServers(prev => {
@@ -535,7 +614,12 @@ export function ServersPage() {
         enableOnSave: true, // This is from Enable flow
         envOverrides: { ...(server.env_overrides ?? {}) },
         argsAppend: [...(server.args_append ?? [])],
+        cwd: server.cwd_override ?? '',
+        envFile: server.env_file_override ?? '',
         extraHeaders: { ...(server.extra_headers ?? {}) },
+        readOnly: server.read_only ?? false,
+        concurrency: concurrencyInputs(server.concurrency_limit),
+        skipArgumentValidation: server.skip_argument_validation ?? false,
       });
       return;
     }
@@ -606,7 +690,12 @@ export function ServersPage() {
       enableOnSave: false, // Just configure, don't enable
       envOverrides: { ...(server.env_overrides ?? {}) },
       argsAppend: [...(server.args_append ?? [])],
+      cwd: server.cwd_override ?? '',
+      envFile: server.env_file_override ?? '',
       extraHeaders: { ...(server.extra_headers ?? {}) },
+      readOnly: server.read_only ?? false,
+      concurrency: concurrencyInputs(server.concurrency_limit),
+      skipArgumentValidation: server.skip_argument_validation ?? false,
     });
   };
 
@@ -619,7 +708,16 @@ export function ServersPage() {
     
     setActionLoading(`config-${serverId}`);
     try {
-      const { saveServerInputs } = await import('@/lib/api/registry');
+      const {
+        saveServerInputs,
+        setServerReadOnly,
+        setServerConcurrencyLimit,
+        setServerSkipArgumentValidation,
+        setServerLaunchOverrides,
+      } = await import('@/lib/api/registry');
+      const concurrencyLimit = configModal.concurrency
+        ? parseConcurrencyInputs(configModal.concurrency)
+        : (server.concurrency_limit ?? null);
 
       // Save input values with env overrides, args, and headers.
       // Always send the values (even if empty) so that clearing them works.
@@ -633,6 +731,26 @@ export function ServersPage() {
         configModal.argsAppend,
         configModal.extraHeaders,
       );
+      if (
+        server.capabilities?.read_only_mode &&
+        (configModal.readOnly ?? false) !== (server.read_only ?? false)
+      ) {
+        await setServerReadOnly(serverId, viewSpace?.id ?? '', configModal.readOnly ?? false);
+      }
+      if (JSON.stringify(concurrencyLimit) !== JSON.stringify(server.concurrency_limit ?? null)) {
+        await setServerConcurrencyLimit(serverId, viewSpace?.id ?? '', concurrencyLimit);
+      }
+      if ((configModal.skipArgumentValidation ?? false) !== (server.skip_argument_validation ?? false)) {
+        await setServerSkipArgumentValidation(serverId, viewSpace?.id ?? '', configModal.skipArgumentValidation ?? false);
+      }
+      const cwd = configModal.cwd?.trim() || null;
+      const envFile = configModal.envFile?.trim() || null;
+      if (
+        server.transport.type === 'stdio' &&
+        (cwd !== (server.cwd_override ?? null) || envFile !== (server.env_file_override ?? null))
+      ) {
+        await setServerLaunchOverrides(serverId, viewSpace?.id ?? '', cwd, envFile);
+      }
 
       setConfigModal({ open: false, server: null, inputValues: {}, envOverrides: {}, argsAppend: [], extraHeaders: {} });
       
@@ -844,13 +962,22 @@ export function ServersPage() {
           </p>
         </div>
         {viewSpace && (
-          <button
-            onClick={() => setEditConfigSpace({ id: viewSpace.id, name: viewSpace.name })}
-            className="flex items-center gap-2 px-4 py-2 text-sm border border-[rgb(var(--border))] rounded-lg hover:bg-[rgb(var(--surface-hover))] transition-colors"
-          >
-            <FileJson className="h-4 w-4" />
-            Add Custom Server
-          </button>
+          <div className="flex items-center gap-2">
+            <button
+              onClick={() => setImportSpace({ id: viewSpace.id, name: viewSpace.name })}
+              className="flex items-center gap-2 px-4 py-2 text-sm border border-[rgb(var(--border))] rounded-lg hover:bg-[rgb(var(--surface-hover))] transition-colors"
+            >
+              <Download className="h-4 w-4" />
+              Import from Clients
+            </button>
+            <button
+              onClick={() => setEditConfigSpace({ id: viewSpace.id, name: viewSpace.name })}
+              className="flex items-center gap-2 px-4 py-2 text-sm border border-[rgb(var(--border))] rounded-lg hover:bg-[rgb(var(--surface-hover))] transition-colors"
+            >
+              <FileJson className="h-4 w-4" />
+              Add Custom Server
+            </button>
+          </div>
         )}
       </div>
 
@@ -1183,6 +1310,42 @@ export function ServersPage() {
                       </div>
                     ) : (
                       <div className="p-4 space-y-4">
+                        {/* Changed definitions awaiting approval */}
+                        {features.some(f => f.pending_definition) && (
+                          <div className="p-3 rounded-lg border border-[rgb(var(--warning))]/40 bg-[rgb(var(--warning))]/10 space-y-2">
+                            <h4 className="text-sm font-medium">
+                              Changed definitions awaiting approval
+                            </h4>
+                            <p className="text-xs text-[rgb(var(--muted))]">
+                              This server changed these definitions. They are hidden from clients until you approve them.
+                            </p>
+                            {features.filter(f => f.pending_definition).map(feature => (
+                              <div
+                                key={feature.id}
+                                className="p-2 bg-[rgb(var(--card))] rounded border border-[rgb(var(--border-subtle))] text-xs space-y-1"
+                              >
+                                <div className="flex items-center justify-between gap-2">
+                                  <span className="font-medium">
+                                    {feature.feature_type}: {feature.feature_name}
+                                  </span>
+                                  <button
+                                    onClick={() => handleApproveDefinition(feature)}
+                                    className="px-2 py-0.5 rounded bg-[rgb(var(--surface-hover))] hover:bg-[rgb(var(--surface-active))] transition-colors"
+                                  >
+                                    Approve
+                                  </button>
+                                </div>
+                                <div className="text-[rgb(var(--muted))]">
+                                  Before: {feature.description || '(no description)'}
+                                </div>
+                                <div>
+                                  After: {String(feature.pending_definition?.description ?? '(no description)')}
+                                </div>
+                              </div>
+                            ))}
+                          </div>
+                        )}
+
                         {/* Tools */}
                         {features.filter(f => f.feature_type === 'tool').length > 0 && (
                           <div>
@@ -1435,6 +1598,81 @@ export function ServersPage() {
                 );
               })}
 
+              {/* Read-only launch mode (servers that declare one) */}
+              {configModal.server.capabilities?.read_only_mode && (
+                <label className="flex cursor-pointer items-start gap-2">
+                  <input
+                    type="checkbox"
+                    className="mt-1"
+                    checked={configModal.readOnly ?? false}
+                    onChange={(e) => setConfigModal({ ...configModal, readOnly: e.target.checked })}
+                    data-testid="config-read-only"
+                  />
+                  <span>
+                    <span className="block text-sm font-medium text-[rgb(var(--foreground))]">
+                      Launch in read-only mode
+                    </span>
+                    <span className="block text-xs text-[rgb(var(--muted))]">
+                      Start the server with the read-only arguments and environment it declares
+                    </span>
+                  </span>
+                </label>
+              )}
+
+              {/* Argument validation against tool input schemas */}
+              <label className="flex cursor-pointer items-start gap-2">
+                <input
+                  type="checkbox"
+                  className="mt-1"
+                  checked={configModal.skipArgumentValidation ?? false}
+                  onChange={(e) => setConfigModal({ ...configModal, skipArgumentValidation: e.target.checked })}
+                  data-testid="config-skip-argument-validation"
+                />
+                <span>
+                  <span className="block text-sm font-medium text-[rgb(var(--foreground))]">
+                    Skip argument validation
+                  </span>
+                  <span className="block text-xs text-[rgb(var(--muted))]">
+                    Send tool calls without checking them against the tool's input schema. Use when the server's schemas are wrong.
+                  </span>
+                </span>
+              </label>
+
+              {/* Concurrency limit and request queue */}
+              {configModal.concurrency && (
+                <div>
+                  <label className="block text-sm font-medium text-[rgb(var(--foreground))] mb-1">
+                    Concurrency Limit
+                  </label>
+                  <p className="text-xs text-[rgb(var(--muted))] mb-2">
+                    Requests sent to the server at once. Extra requests wait in a queue; leave empty for no limit.
+                  </p>
+                  <div className="flex items-center gap-2">
+                    {([
+                      ['maxConcurrent', 'Max concurrent', 'Unlimited'],
+                      ['maxQueued', 'Queue size', '32'],
+                      ['timeoutSecs', 'Queue timeout (s)', '30'],
+                    ] as const).map(([field, title, placeholder]) => (
+                      <input
+                        key={field}
+                        type="number"
+                        min={field === 'maxQueued' ? 0 : 1}
+                        title={title}
+                        placeholder={placeholder}
+                        value={configModal.concurrency![field]}
+                        disabled={field !== 'maxConcurrent' && configModal.concurrency!.maxConcurrent.trim() === ''}
+                        onChange={(e) => setConfigModal({
+                          ...configModal,
+                          concurrency: { ...configModal.concurrency!, [field]: e.target.value },
+                        })}
+                        className="input w-28 text-sm"
+                        data-testid={`config-concurrency-${field}`}
+                      />
+                    ))}
+                  </div>
+                </div>
+              )}
+
               {/* Additional Arguments (stdio only) */}
               {configModal.server.transport.type === 'stdio' && (
                 <div>
@@ -1468,6 +1706,36 @@ export function ServersPage() {
                 </div>
               )}
 
+              {/* Working Directory and .env File (stdio only) */}
+              {configModal.server.transport.type === 'stdio' && (
+                <div>
+                  <label className="block text-sm font-medium text-[rgb(var(--foreground))] mb-1">
+                    Working Directory
+                  </label>
+                  <p className="text-xs text-[rgb(var(--muted))] mb-2">
+                    Folder to launch the server from, and a .env file to load (leave empty to use the server's own)
+                  </p>
+                  <div className="space-y-2">
+                    <input
+                      type="text"
+                      value={configModal.cwd ?? ''}
+                      onChange={(e) => setConfigModal({ ...configModal, cwd: e.target.value })}
+                      placeholder={configModal.server.transport.cwd || '/path/to/project'}
+                      className="input w-full font-mono text-sm"
+                      data-testid="config-cwd"
+                    />
+                    <input
+                      type="text"
+                      value={configModal.envFile ?? ''}
+                      onChange={(e) => setConfigModal({ ...configModal, envFile: e.target.value })}
+                      placeholder={configModal.server.transport.env_file || '/path/to/project/.env'}
+                      className="input w-full font-mono text-sm"
+                      data-testid="config-env-file"
+                    />
+                  </div>
+                </div>
+              )}
+
               {/* Environment Variable Overrides */}
               <div>
                 <label className="block text-sm font-medium text-[rgb(var(--foreground))] mb-1">
@@ -1687,6 +1955,17 @@ export function ServersPage() {
           }}
         />
       )}
+
+      {/* Import from Clients Modal */}
+      {importSpace && (
+        <ImportServersModal
+          spaceId={importSpace.id}
+          spaceName={importSpace.name}
+          gatewayUrl={gatewayRunning ? gatewayUrl : null}
+          onClose={() => setImportSpace(null)}
+          onImported={() => loadData()}
+        />
+      )}
     </div>
   );
 }
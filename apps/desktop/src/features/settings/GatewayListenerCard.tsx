@@ -0,0 +1,143 @@
+import { useEffect, useState } from 'react';
+import {
+  Card,
+  CardHeader,
+  CardTitle,
+  CardDescription,
+  CardContent,
+  Button,
+  Switch,
+} from '@mcpmux/ui';
+import { Cable, Loader2, Save } from 'lucide-react';
+import {
+  getGatewayListenerSettings,
+  updateGatewayListenerSettings,
+  type GatewayListenerSettings,
+} from '@/lib/api/gateway';
+
+const inputClass =
+  'w-full px-3 py-2 rounded-lg border border-[rgb(var(--border))] bg-[rgb(var(--surface))] text-sm font-mono focus:outline-none focus:ring-2 focus:ring-primary-500';
+
+export function GatewayListenerCard() {
+  const [settings, setSettings] = useState<GatewayListenerSettings | null>(null);
+  const [isSaving, setIsSaving] = useState(false);
+  const [saved, setSaved] = useState(false);
+  const [error, setError] = useState<string | null>(null);
+
+  useEffect(() => {
+    getGatewayListenerSettings()
+      .then(setSettings)
+      .catch((e) => setError(String(e)));
+  }, []);
+
+  const update = (patch: Partial<GatewayListenerSettings>) => {
+    setSettings((current) => (current ? { ...current, ...patch } : current));
+    setSaved(false);
+  };
+
+  const handleSave = async () => {
+    if (!settings) return;
+    setIsSaving(true);
+    setError(null);
+    try {
+      await updateGatewayListenerSettings({
+        ...settings,
+        unixSocketPath: settings.unixSocketPath?.trim() || null,
+      });
+      setSaved(true);
+    } catch (e) {
+      setError(String(e));
+    } finally {
+      setIsSaving(false);
+    }
+  };
+
+  return (
+    <Card data-testid="settings-gateway-listener-section">
+      <CardHeader>
+        <CardTitle className="flex items-center gap-2">
+          <Cable className="h-5 w-5" />
+          Gateway Listener
+        </CardTitle>
+        <CardDescription>
+          Serve the gateway on a Unix domain socket so access is limited by file permissions.
+          Clients reach the socket through the McpMux stdio bridge.
+        </CardDescription>
+      </CardHeader>
+      <CardContent>
+        {!settings ? (
+          error ? (
+            <div className="text-sm text-[rgb(var(--error))]">{error}</div>
+          ) : (
+            <div className="flex items-center gap-2 text-sm text-[rgb(var(--muted))]">
+              <Loader2 className="h-4 w-4 animate-spin" />
+              Loading…
+            </div>
+          )
+        ) : (
+          <div className="space-y-4">
+            <div className="flex items-center justify-between gap-4">
+              <div className="flex-1 min-w-0">
+                <label className="text-sm font-medium">TCP</label>
+                <p className="text-xs text-[rgb(var(--muted))] mt-1">
+                  Serve on the gateway port. Can only be turned off when a socket is set.
+                </p>
+              </div>
+              <Switch
+                checked={settings.tcpEnabled}
+                onCheckedChange={(checked) => update({ tcpEnabled: checked })}
+                disabled={isSaving}
+                data-testid="gateway-tcp-switch"
+              />
+            </div>
+
+            <div className="space-y-1">
+              <label className="block text-sm font-medium">Socket path</label>
+              <input
+                type="text"
+                value={settings.unixSocketPath ?? ''}
+                onChange={(e) => update({ unixSocketPath: e.target.value })}
+                placeholder="/run/user/1000/mcpmux.sock"
+                className={inputClass}
+                data-testid="gateway-socket-path-input"
+              />
+            </div>
+
+            <div className="space-y-1">
+              <label className="block text-sm font-medium">Socket permissions</label>
+              <input
+                type="text"
+                value={settings.unixSocketMode}
+                onChange={(e) => update({ unixSocketMode: e.target.value })}
+                placeholder="600"
+                className={`${inputClass} max-w-[8rem]`}
+                data-testid="gateway-socket-mode-input"
+              />
+            </div>
+
+            {error && <div className="text-sm text-[rgb(var(--error))]">{error}</div>}
+            {saved && (
+              <p className="text-sm text-[rgb(var(--muted))]">
+                Saved. Restart the gateway to apply.
+              </p>
+            )}
+
+            <Button
+              size="sm"
+              onClick={handleSave}
+              disabled={isSaving}
+              data-testid="gateway-listener-save-btn"
+            >
+              {isSaving ? (
+                <Loader2 className="h-4 w-4 mr-2 animate-spin" />
+              ) : (
+                <Save className="h-4 w-4 mr-2" />
+              )}
+              Save
+            </Button>
+          </div>
+        )}
+      </CardContent>
+    </Card>
+  );
+}
@@ -0,0 +1,199 @@
+import { useEffect, useState } from 'react';
+import {
+  Card,
+  CardHeader,
+  CardTitle,
+  CardDescription,
+  CardContent,
+  Button,
+  Switch,
+} from '@mcpmux/ui';
+import { Loader2, Lock, Save } from 'lucide-react';
+import {
+  getGatewayTlsSettings,
+  updateGatewayTlsSettings,
+  type GatewayTlsSettings,
+} from '@/lib/api/gateway';
+
+const inputClass =
+  'w-full px-3 py-2 rounded-lg border border-[rgb(var(--border))] bg-[rgb(var(--surface))] text-sm font-mono focus:outline-none focus:ring-2 focus:ring-primary-500';
+
+/** Empty inputs are stored as "not set" */
+const orNull = (value: string | null) => value?.trim() || null;
+
+export function GatewayTlsCard() {
+  const [settings, setSettings] = useState<GatewayTlsSettings | null>(null);
+  const [isSaving, setIsSaving] = useState(false);
+  const [saved, setSaved] = useState(false);
+  const [error, setError] = useState<string | null>(null);
+
+  useEffect(() => {
+    getGatewayTlsSettings()
+      .then(setSettings)
+      .catch((e) => setError(String(e)));
+  }, []);
+
+  const update = (patch: Partial<GatewayTlsSettings>) => {
+    setSettings((current) => (current ? { ...current, ...patch } : current));
+    setSaved(false);
+  };
+
+  const handleSave = async () => {
+    if (!settings) return;
+    setIsSaving(true);
+    setError(null);
+    try {
+      await updateGatewayTlsSettings({
+        ...settings,
+        bindHost: settings.bindHost.trim(),
+        publicUrl: orNull(settings.publicUrl),
+        certPath: orNull(settings.certPath),
+        keyPath: orNull(settings.keyPath),
+        clientCaPath: orNull(settings.clientCaPath),
+      });
+      setSaved(true);
+    } catch (e) {
+      setError(String(e));
+    } finally {
+      setIsSaving(false);
+    }
+  };
+
+  return (
+    <Card data-testid="settings-gateway-tls-section">
+      <CardHeader>
+        <CardTitle className="flex items-center gap-2">
+          <Lock className="h-5 w-5" />
+          Network Exposure
+        </CardTitle>
+        <CardDescription>
+          Expose the gateway beyond this machine. Non-loopback addresses require TLS.
+        </CardDescription>
+      </CardHeader>
+      <CardContent>
+        {!settings ? (
+          error ? (
+            <div className="text-sm text-[rgb(var(--error))]">{error}</div>
+          ) : (
+            <div className="flex items-center gap-2 text-sm text-[rgb(var(--muted))]">
+              <Loader2 className="h-4 w-4 animate-spin" />
+              Loading…
+            </div>
+          )
+        ) : (
+          <div className="space-y-4">
+            <div className="space-y-1">
+              <label className="block text-sm font-medium">Bind address</label>
+              <input
+                type="text"
+                value={settings.bindHost}
+                onChange={(e) => update({ bindHost: e.target.value })}
+                placeholder="127.0.0.1"
+                className={inputClass}
+                data-testid="gateway-bind-host-input"
+              />
+            </div>
+
+            <div className="space-y-1">
+              <label className="block text-sm font-medium">Public URL</label>
+              <input
+                type="text"
+                value={settings.publicUrl ?? ''}
+                onChange={(e) => update({ publicUrl: e.target.value })}
+                placeholder="https://mux.example.lan:45818"
+                className={inputClass}
+                data-testid="gateway-public-url-input"
+              />
+            </div>
+
+            <div className="flex items-center justify-between gap-4">
+              <div className="flex-1 min-w-0">
+                <label className="text-sm font-medium">TLS</label>
+                <p className="text-xs text-[rgb(var(--muted))] mt-1">
+                  Leave the certificate and key empty to use a generated self-signed certificate.
+                </p>
+              </div>
+              <Switch
+                checked={settings.tlsEnabled}
+                onCheckedChange={(checked) => update({ tlsEnabled: checked })}
+                disabled={isSaving}
+                data-testid="gateway-tls-switch"
+              />
+            </div>
+
+            {settings.tlsEnabled && (
+              <>
+                <div className="grid grid-cols-2 gap-2">
+                  <input
+                    type="text"
+                    value={settings.certPath ?? ''}
+                    onChange={(e) => update({ certPath: e.target.value })}
+                    placeholder="Certificate (PEM)"
+                    className={inputClass}
+                    data-testid="gateway-tls-cert-input"
+                  />
+                  <input
+                    type="text"
+                    value={settings.keyPath ?? ''}
+                    onChange={(e) => update({ keyPath: e.target.value })}
+                    placeholder="Private key (PEM)"
+                    className={inputClass}
+                    data-testid="gateway-tls-key-input"
+                  />
+                </div>
+
+                <div className="flex items-center justify-between gap-4">
+                  <label className="text-sm font-medium">Client certificates</label>
+                  <select
+                    value={settings.clientAuth}
+                    onChange={(e) =>
+                      update({ clientAuth: e.target.value as GatewayTlsSettings['clientAuth'] })
+                    }
+                    className="px-3 py-1.5 text-sm border border-[rgb(var(--border))] rounded-lg bg-[rgb(var(--surface))] text-[rgb(var(--foreground))]"
+                    data-testid="gateway-tls-client-auth-select"
+                  >
+                    <option value="off">Off</option>
+                    <option value="optional">Optional</option>
+                    <option value="required">Required</option>
+                  </select>
+                </div>
+
+                {settings.clientAuth !== 'off' && (
+                  <input
+                    type="text"
+                    value={settings.clientCaPath ?? ''}
+                    onChange={(e) => update({ clientCaPath: e.target.value })}
+                    placeholder="Client CA bundle (PEM)"
+                    className={inputClass}
+                    data-testid="gateway-tls-client-ca-input"
+                  />
+                )}
+              </>
+            )}
+
+            {error && <div className="text-sm text-[rgb(var(--error))]">{error}</div>}
+            {saved && (
+              <p className="text-sm text-[rgb(var(--muted))]">
+                Saved. Restart the gateway to apply.
+              </p>
+            )}
+
+            <Button
+              size="sm"
+              onClick={handleSave}
+              disabled={isSaving}
+              data-testid="gateway-tls-save-btn"
+            >
+              {isSaving ? (
+                <Loader2 className="h-4 w-4 mr-2 animate-spin" />
+              ) : (
+                <Save className="h-4 w-4 mr-2" />
+              )}
+              Save
+            </Button>
+          </div>
+        )}
+      </CardContent>
+    </Card>
+  );
+}
@@ -23,9 +23,18 @@ import {
   XCircle,
   Trash2,
   BarChart3,
+  ShieldAlert,
 } from 'lucide-react';
 import { useAppStore, useTheme, useAnalyticsEnabled } from '@/stores';
+import {
+  getDestructiveToolPolicy,
+  setDestructiveToolPolicy,
+  type ApprovalPolicy,
+} from '@/lib/api/toolApproval';
 import { UpdateChecker } from './UpdateChecker';
+import { GatewayListenerCard } from './GatewayListenerCard';
+import { GatewayTlsCard } from './GatewayTlsCard';
+import { WorkspaceManifestCard } from './WorkspaceManifestCard';
 
 interface StartupSettings {
   autoLaunch: boolean;
@@ -55,6 +64,10 @@ export function SettingsPage() {
   const [logRetentionDays, setLogRetentionDays] = useState<number>(30);
   const [savingRetention, setSavingRetention] = useState(false);
 
+  // Destructive tool approval policy state
+  const [destructivePolicy, setDestructivePolicyState] = useState<ApprovalPolicy>('allow');
+  const [savingDestructivePolicy, setSavingDestructivePolicy] = useState(false);
+
   // Load logs path on mount
   useEffect(() => {
     const loadLogsPath = async () => {
@@ -81,6 +94,13 @@ export function SettingsPage() {
     loadRetention();
   }, []);
 
+  // Load destructive tool policy on mount
+  useEffect(() => {
+    getDestructiveToolPolicy()
+      .then(setDestructivePolicyState)
+      .catch((err) => console.error('Failed to load destructive tool policy:', err));
+  }, []);
+
   // Load startup settings on mount
   useEffect(() => {
     const loadStartupSettings = async () => {
@@ -146,6 +166,22 @@ export function SettingsPage() {
     }
   };
 
+  const handleDestructivePolicyChange = async (policy: ApprovalPolicy) => {
+    const oldPolicy = destructivePolicy;
+    setDestructivePolicyState(policy);
+    setSavingDestructivePolicy(true);
+    try {
+      await setDestructiveToolPolicy(policy);
+      success('Settings saved', `Destructive tools set to "${policy}"`);
+    } catch (err) {
+      const errorMessage = err instanceof Error ? err.message : String(err);
+      error('Failed to save setting', errorMessage);
+      setDestructivePolicyState(oldPolicy);
+    } finally {
+      setSavingDestructivePolicy(false);
+    }
+  };
+
   const handleOpenLogs = async () => {
     setOpeningLogs(true);
     try {
@@ -305,6 +341,42 @@ export function SettingsPage() {
         </CardContent>
       </Card>
 
+      {/* Tool Approval Section */}
+      <Card data-testid="settings-tool-approval-section">
+        <CardHeader>
+          <CardTitle className="flex items-center gap-2">
+            <ShieldAlert className="h-5 w-5" />
+            Tool Approval
+          </CardTitle>
+          <CardDescription>
+            Decide what happens when a client calls a tool that may be destructive: any tool
+            its server doesn't mark as read-only or non-destructive. Per-tool and
+            per-feature-set policies take precedence.
+          </CardDescription>
+        </CardHeader>
+        <CardContent>
+          <div className="flex items-center justify-between gap-4">
+            <div className="flex-1 min-w-0">
+              <label className="text-sm font-medium">Destructive Tools</label>
+              <p className="text-xs text-[rgb(var(--muted))] mt-1">
+                "Ask" holds the call until you approve it here or in the client.
+              </p>
+            </div>
+            <select
+              value={destructivePolicy}
+              onChange={(e) => handleDestructivePolicyChange(e.target.value as ApprovalPolicy)}
+              disabled={savingDestructivePolicy}
+              className="px-3 py-1.5 text-sm border border-[rgb(var(--border))] rounded-lg bg-[rgb(var(--surface))] text-[rgb(var(--foreground))]"
+              data-testid="destructive-tool-policy-select"
+            >
+              <option value="allow">Allow</option>
+              <option value="ask">Ask</option>
+              <option value="deny">Deny</option>
+            </select>
+          </div>
+        </CardContent>
+      </Card>
+
       {/* Analytics Section */}
       <Card data-testid="settings-analytics-section">
         <CardHeader>
@@ -337,6 +409,12 @@ export function SettingsPage() {
         </CardContent>
       </Card>
 
+      <GatewayListenerCard />
+
+      <GatewayTlsCard />
+
+      <WorkspaceManifestCard />
+
       {/* Logs Section */}
       <Card>
         <CardHeader>
@@ -0,0 +1,149 @@
+import { useState } from 'react';
+import { open } from '@tauri-apps/plugin-dialog';
+import {
+  Card,
+  CardHeader,
+  CardTitle,
+  CardDescription,
+  CardContent,
+  Button,
+} from '@mcpmux/ui';
+import { FileCode, FolderOpen, Loader2, Play } from 'lucide-react';
+import {
+  planWorkspaceManifest,
+  applyWorkspaceManifest,
+  type ManifestPlan,
+  type PlannedChange,
+} from '@/lib/api/workspaceManifest';
+
+const SYMBOLS: Record<PlannedChange['action'], string> = {
+  create: '+',
+  update: '~',
+  delete: '-',
+};
+
+const LABELS: Record<PlannedChange['resource'], string> = {
+  setting: 'setting',
+  space: 'space',
+  server: 'server',
+  feature_set: 'feature set',
+  client_grant: 'grant',
+};
+
+function describe(change: PlannedChange): string {
+  let line = `${SYMBOLS[change.action]} ${LABELS[change.resource]} ${change.name}`;
+  if (change.space) line += ` (${change.space})`;
+  if (change.details.length > 0) line += `: ${change.details.join(', ')}`;
+  return line;
+}
+
+export function WorkspaceManifestCard() {
+  const [path, setPath] = useState<string | null>(null);
+  const [plan, setPlan] = useState<ManifestPlan | null>(null);
+  const [applied, setApplied] = useState(false);
+  const [isWorking, setIsWorking] = useState(false);
+  const [error, setError] = useState<string | null>(null);
+
+  const run = async (manifestPath: string, apply: boolean) => {
+    setIsWorking(true);
+    setError(null);
+    try {
+      const result = apply
+        ? await applyWorkspaceManifest(manifestPath)
+        : await planWorkspaceManifest(manifestPath);
+      setPlan(result);
+      setApplied(apply);
+    } catch (e) {
+      setPlan(null);
+      setError(String(e));
+    } finally {
+      setIsWorking(false);
+    }
+  };
+
+  const handleChoose = async () => {
+    const selected = await open({
+      multiple: false,
+      filters: [{ name: 'Workspace manifest', extensions: ['json'] }],
+    });
+    if (!selected || Array.isArray(selected)) return;
+    setPath(selected);
+    await run(selected, false);
+  };
+
+  return (
+    <Card data-testid="settings-workspace-manifest-section">
+      <CardHeader>
+        <CardTitle className="flex items-center gap-2">
+          <FileCode className="h-5 w-5" />
+          Workspace Manifest
+        </CardTitle>
+        <CardDescription>
+          Manage spaces, servers, feature sets and client grants from a version-controlled file.
+        </CardDescription>
+      </CardHeader>
+      <CardContent>
+        <div className="space-y-4">
+          {path && (
+            <p className="text-sm text-[rgb(var(--muted))] font-mono bg-surface-secondary rounded px-2 py-1">
+              {path}
+            </p>
+          )}
+          <div className="flex items-center gap-2">
+            <Button
+              variant="secondary"
+              size="sm"
+              onClick={handleChoose}
+              disabled={isWorking}
+              data-testid="workspace-manifest-choose-btn"
+            >
+              <FolderOpen className="h-4 w-4 mr-2" />
+              Choose Manifest…
+            </Button>
+            {path && (
+              <Button
+                size="sm"
+                onClick={() => run(path, true)}
+                disabled={isWorking || !plan || plan.changes.length === 0 || applied}
+                data-testid="workspace-manifest-apply-btn"
+              >
+                {isWorking ? (
+                  <Loader2 className="h-4 w-4 mr-2 animate-spin" />
+                ) : (
+                  <Play className="h-4 w-4 mr-2" />
+                )}
+                Apply
+              </Button>
+            )}
+          </div>
+
+          {error && <div className="text-sm text-[rgb(var(--error))]">{error}</div>}
+          {plan && (
+            <div className="space-y-2">
+              <p className="text-sm font-medium">
+                {plan.changes.length === 0
+                  ? 'Everything matches the manifest.'
+                  : applied
+                    ? `Applied ${plan.changes.length} change(s)`
+                    : `${plan.changes.length} change(s) to apply`}
+              </p>
+              {plan.changes.length > 0 && (
+                <pre
+                  className="text-xs font-mono bg-surface-secondary rounded px-2 py-1 whitespace-pre-wrap"
+                  data-testid="workspace-manifest-plan"
+                >
+                  {plan.changes.map(describe).join('\n')}
+                </pre>
+              )}
+              {plan.warnings.map((warning) => (
+                <p key={warning} className="text-xs text-[rgb(var(--warning))]">
+                  {warning}
+                </p>
+              ))}
+            </div>
+          )}
+        </div>
+      </CardContent>
+    </Card>
+  );
+}
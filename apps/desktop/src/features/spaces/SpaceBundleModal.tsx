@@ -0,0 +1,231 @@
+import { useState } from 'react';
+import { X, Loader2, Upload, Download } from 'lucide-react';
+import { open, save } from '@tauri-apps/plugin-dialog';
+import type { Space } from '@/lib/api/spaces';
+import {
+  exportSpaceBundle,
+  importSpaceBundle,
+  type ServerConflict,
+} from '@/lib/api/spaceBundle';
+
+interface SpaceBundleModalProps {
+  spaces: Space[];
+  onClose: () => void;
+  /** Called after an import so the space list can be reloaded */
+  onImported: () => void;
+}
+
+type Mode = 'export' | 'import';
+
+export function SpaceBundleModal({ spaces, onClose, onImported }: SpaceBundleModalProps) {
+  const [mode, setMode] = useState<Mode>('export');
+  const [passphrase, setPassphrase] = useState('');
+  const [selectedIds, setSelectedIds] = useState<string[]>(spaces.map((s) => s.id));
+  const [includeTokens, setIncludeTokens] = useState(false);
+  const [targetSpaceId, setTargetSpaceId] = useState<string>('');
+  const [onConflict, setOnConflict] = useState<ServerConflict>('skip');
+  const [isWorking, setIsWorking] = useState(false);
+  const [result, setResult] = useState<string | null>(null);
+  const [error, setError] = useState<string | null>(null);
+
+  const toggle = (id: string) =>
+    setSelectedIds((prev) => (prev.includes(id) ? prev.filter((i) => i !== id) : [...prev, id]));
+
+  const handleExport = async () => {
+    const path = await save({
+      defaultPath: 'mcpmux-spaces.mcpmux',
+      filters: [{ name: 'McpMux bundle', extensions: ['mcpmux'] }],
+    });
+    if (!path) return;
+    setIsWorking(true);
+    setError(null);
+    setResult(null);
+    try {
+      const res = await exportSpaceBundle(selectedIds, passphrase, includeTokens, path);
+      setResult(`Exported ${res.spaces} space(s) with ${res.servers} server(s) to ${res.path}`);
+    } catch (e) {
+      setError(String(e));
+    } finally {
+      setIsWorking(false);
+    }
+  };
+
+  const handleImport = async () => {
+    const path = await open({
+      multiple: false,
+      filters: [{ name: 'McpMux bundle', extensions: ['mcpmux'] }],
+    });
+    if (!path || Array.isArray(path)) return;
+    setIsWorking(true);
+    setError(null);
+    setResult(null);
+    try {
+      const report = await importSpaceBundle(path, passphrase, targetSpaceId || null, onConflict);
+      setResult(
+        report.spaces
+          .map((s) => {
+            const parts = [`${s.name}: ${s.servers.length} server(s)`];
+            if (s.renamed.length > 0)
+              parts.push(`renamed ${s.renamed.map((r) => `${r.from} → ${r.to}`).join(', ')}`);
+            if (s.skipped.length > 0) parts.push(`skipped ${s.skipped.join(', ')}`);
+            if (s.oauth_tokens > 0) parts.push(`${s.oauth_tokens} OAuth connection(s) restored`);
+            if (s.missing_secrets.length > 0)
+              parts.push(`set secrets ${s.missing_secrets.join(', ')}`);
+            return parts.join(' — ');
+          })
+          .join('\n')
+      );
+      onImported();
+    } catch (e) {
+      setError(String(e));
+    } finally {
+      setIsWorking(false);
+    }
+  };
+
+  const canRun =
+    !isWorking && passphrase.length >= 8 && (mode === 'import' || selectedIds.length > 0);
+
+  return (
+    <div className="fixed inset-0 bg-black/60 backdrop-blur-sm flex items-center justify-center z-50 p-4">
+      <div
+        className="bg-[rgb(var(--surface))] w-full max-w-lg max-h-[80vh] rounded-xl shadow-2xl flex flex-col border border-[rgb(var(--border))]"
+        data-testid="space-bundle-modal"
+      >
+        {/* Header */}
+        <div className="flex items-center justify-between p-4 border-b border-[rgb(var(--border))]">
+          <div>
+            <h3 className="text-lg font-semibold">Move Spaces</h3>
+            <p className="text-sm text-[rgb(var(--muted))]">
+              Transfer spaces to another machine in a passphrase-encrypted file
+            </p>
+          </div>
+          <button
+            onClick={onClose}
+            className="p-2 hover:bg-[rgb(var(--surface-hover))] rounded-lg transition-colors"
+          >
+            <X className="h-5 w-5 text-[rgb(var(--muted))]" />
+          </button>
+        </div>
+
+        <div className="flex-1 overflow-y-auto p-4 space-y-4">
+          <div className="flex gap-2">
+            {(['export', 'import'] as Mode[]).map((m) => (
+              <button
+                key={m}
+                onClick={() => {
+                  setMode(m);
+                  setResult(null);
+                  setError(null);
+                }}
+                className={`flex-1 px-3 py-1.5 text-sm rounded-lg border transition-colors ${
+                  mode === m
+                    ? 'border-primary-500 bg-primary-50 dark:bg-primary-900/20'
+                    : 'border-[rgb(var(--border))] hover:bg-[rgb(var(--surface-hover))]'
+                }`}
+              >
+                {m === 'export' ? 'Export' : 'Import'}
+              </button>
+            ))}
+          </div>
+
+          {mode === 'export' ? (
+            <>
+              <div className="space-y-1">
+                {spaces.map((space) => (
+                  <label key={space.id} className="flex items-center gap-2 text-sm">
+                    <input
+                      type="checkbox"
+                      checked={selectedIds.includes(space.id)}
+                      onChange={() => toggle(space.id)}
+                    />
+                    <span>{space.icon || '🌐'}</span>
+                    {space.name}
+                  </label>
+                ))}
+              </div>
+              <label className="flex items-start gap-2 text-sm">
+                <input
+                  type="checkbox"
+                  className="mt-1"
+                  checked={includeTokens}
+                  onChange={(e) => setIncludeTokens(e.target.checked)}
+                />
+                <span>
+                  Include OAuth refresh tokens
+                  <span className="block text-xs text-[rgb(var(--muted))]">
+                    Keeps OAuth servers connected; otherwise they need to be authorized again
+                  </span>
+                </span>
+              </label>
+            </>
+          ) : (
+            <>
+              <div>
+                <label className="block text-sm font-medium mb-1.5">Import into</label>
+                <select
+                  value={targetSpaceId}
+                  onChange={(e) => setTargetSpaceId(e.target.value)}
+                  className="w-full px-3 py-2 rounded-lg border border-[rgb(var(--border))] bg-[rgb(var(--surface))] text-sm"
+                >
+                  <option value="">New spaces</option>
+                  {spaces.map((space) => (
+                    <option key={space.id} value={space.id}>
+                      {space.name}
+                    </option>
+                  ))}
+                </select>
+              </div>
+              <div>
+                <label className="block text-sm font-medium mb-1.5">
+                  When a server is already installed
+                </label>
+                <select
+                  value={onConflict}
+                  onChange={(e) => setOnConflict(e.target.value as ServerConflict)}
+                  className="w-full px-3 py-2 rounded-lg border border-[rgb(var(--border))] bg-[rgb(var(--surface))] text-sm"
+                >
+                  <option value="skip">Keep the existing server</option>
+                  <option value="replace">Replace it</option>
+                  <option value="rename">Install the bundled one under a new ID</option>
+                </select>
+              </div>
+            </>
+          )}
+
+          <div>
+            <label className="block text-sm font-medium mb-1.5">Passphrase</label>
+            <input
+              type="password"
+              value={passphrase}
+              onChange={(e) => setPassphrase(e.target.value)}
+              placeholder="At least 8 characters"
+              className="w-full px-3 py-2 rounded-lg border border-[rgb(var(--border))] bg-[rgb(var(--surface))] focus:outline-none focus:ring-2 focus:ring-primary-500"
+              data-testid="space-bundle-passphrase"
+            />
+          </div>
+
+          {error && <div className="text-sm text-[rgb(var(--error))]">{error}</div>}
+          {result && <div className="text-sm whitespace-pre-line">{result}</div>}
+        </div>
+
+        <div className="flex justify-end p-4 border-t border-[rgb(var(--border))]">
+          <button
+            onClick={mode === 'export' ? handleExport : handleImport}
+            disabled={!canRun}
+            className="flex items-center gap-2 px-3 py-1.5 text-sm bg-[rgb(var(--primary))] text-[rgb(var(--primary-foreground))] rounded-lg hover:bg-[rgb(var(--primary-hover))] transition-colors disabled:opacity-50"
+          >
+            {isWorking ? (
+              <Loader2 className="h-4 w-4 animate-spin" />
+            ) : mode === 'export' ? (
+              <Upload className="h-4 w-4" />
+            ) : (
+              <Download className="h-4 w-4" />
+            )}
+            {mode === 'export' ? 'Export…' : 'Import…'}
+          </button>
+        </div>
+      </div>
+    </div>
+  );
+}
@@ -0,0 +1,168 @@
+import { useEffect, useState } from 'react';
+import { X, Loader2, KeyRound, Trash2 } from 'lucide-react';
+import type { Space } from '@/lib/api/spaces';
+import {
+  deleteSpaceSecret,
+  listSpaceSecrets,
+  setSpaceSecret,
+  type SpaceSecret,
+} from '@/lib/api/spaceSecrets';
+
+interface SpaceSecretsModalProps {
+  space: Space;
+  onClose: () => void;
+}
+
+const inputClass =
+  'w-full px-3 py-2 rounded-lg border border-[rgb(var(--border))] bg-[rgb(var(--surface))] text-sm focus:outline-none focus:ring-2 focus:ring-primary-500';
+
+export function SpaceSecretsModal({ space, onClose }: SpaceSecretsModalProps) {
+  const [secrets, setSecrets] = useState<SpaceSecret[]>([]);
+  const [name, setName] = useState('');
+  const [value, setValue] = useState('');
+  const [isWorking, setIsWorking] = useState(false);
+  const [result, setResult] = useState<string | null>(null);
+  const [error, setError] = useState<string | null>(null);
+
+  const load = async () => {
+    try {
+      setSecrets(await listSpaceSecrets(space.id));
+    } catch (e) {
+      setError(String(e));
+    }
+  };
+
+  useEffect(() => {
+    load();
+  }, [space.id]);
+
+  const handleSave = async () => {
+    setIsWorking(true);
+    setError(null);
+    setResult(null);
+    try {
+      const res = await setSpaceSecret(space.id, name.trim(), value);
+      const parts = [`Saved ${name.trim()}`];
+      if (res.reconnected.length > 0) parts.push(`reconnected ${res.reconnected.join(', ')}`);
+      setResult(parts.join(' — '));
+      if (res.errors.length > 0) setError(res.errors.join('\n'));
+      setName('');
+      setValue('');
+      await load();
+    } catch (e) {
+      setError(String(e));
+    } finally {
+      setIsWorking(false);
+    }
+  };
+
+  const handleDelete = async (secretName: string) => {
+    setError(null);
+    setResult(null);
+    try {
+      await deleteSpaceSecret(space.id, secretName);
+      await load();
+    } catch (e) {
+      setError(String(e));
+    }
+  };
+
+  return (
+    <div className="fixed inset-0 bg-black/60 backdrop-blur-sm flex items-center justify-center z-50 p-4">
+      <div
+        className="bg-[rgb(var(--surface))] w-full max-w-lg max-h-[80vh] rounded-xl shadow-2xl flex flex-col border border-[rgb(var(--border))]"
+        data-testid="space-secrets-modal"
+      >
+        {/* Header */}
+        <div className="flex items-center justify-between p-4 border-b border-[rgb(var(--border))]">
+          <div>
+            <h3 className="text-lg font-semibold">Secrets — {space.name}</h3>
+            <p className="text-sm text-[rgb(var(--muted))]">
+              Use a secret in any server input as{' '}
+              <code className="text-xs">{'${secret:name}'}</code>
+            </p>
+          </div>
+          <button
+            onClick={onClose}
+            className="p-2 hover:bg-[rgb(var(--surface-hover))] rounded-lg transition-colors"
+          >
+            <X className="h-5 w-5 text-[rgb(var(--muted))]" />
+          </button>
+        </div>
+
+        <div className="flex-1 overflow-y-auto p-4 space-y-4">
+          {secrets.length === 0 ? (
+            <p className="text-sm text-[rgb(var(--muted))]">No secrets in this space yet.</p>
+          ) : (
+            <div className="space-y-2">
+              {secrets.map((secret) => (
+                <div
+                  key={secret.name}
+                  className="flex items-center justify-between gap-2 p-2 rounded-lg border border-[rgb(var(--border-subtle))]"
+                >
+                  <div className="min-w-0">
+                    <button
+                      onClick={() => setName(secret.name)}
+                      className="font-mono text-sm hover:underline"
+                      title="Rotate"
+                    >
+                      {secret.name}
+                    </button>
+                    <p className="text-xs text-[rgb(var(--muted))] truncate">
+                      {secret.used_by.length > 0
+                        ? `Used by ${secret.used_by.join(', ')}`
+                        : 'Not used'}
+                    </p>
+                  </div>
+                  <button
+                    onClick={() => handleDelete(secret.name)}
+                    disabled={secret.used_by.length > 0}
+                    className="p-1.5 text-[rgb(var(--muted))] hover:text-red-500 rounded-lg transition-colors disabled:opacity-50 disabled:cursor-not-allowed"
+                    title={secret.used_by.length > 0 ? 'In use' : 'Delete Secret'}
+                  >
+                    <Trash2 className="h-4 w-4" />
+                  </button>
+                </div>
+              ))}
+            </div>
+          )}
+
+          <div className="space-y-2">
+            <label className="block text-sm font-medium">Add or rotate</label>
+            <input
+              type="text"
+              value={name}
+              onChange={(e) => setName(e.target.value)}
+              placeholder="github_token"
+              className={inputClass}
+              data-testid="space-secret-name"
+            />
+            <input
+              type="password"
+              value={value}
+              onChange={(e) => setValue(e.target.value)}
+              placeholder="Value"
+              className={inputClass}
+              data-testid="space-secret-value"
+            />
+          </div>
+
+          {error && <div className="text-sm text-[rgb(var(--error))] whitespace-pre-line">{error}</div>}
+          {result && <div className="text-sm">{result}</div>}
+        </div>
+
+        <div className="flex justify-end p-4 border-t border-[rgb(var(--border))]">
+          <button
+            onClick={handleSave}
+            disabled={isWorking || !name.trim() || !value}
+            className="flex items-center gap-2 px-3 py-1.5 text-sm bg-[rgb(var(--primary))] text-[rgb(var(--primary-foreground))] rounded-lg hover:bg-[rgb(var(--primary-hover))] transition-colors disabled:opacity-50"
+            data-testid="space-secret-save"
+          >
+            {isWorking ? <Loader2 className="h-4 w-4 animate-spin" /> : <KeyRound className="h-4 w-4" />}
+            Save Secret
+          </button>
+        </div>
+      </div>
+    </div>
+  );
+}
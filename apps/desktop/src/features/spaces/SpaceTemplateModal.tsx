@@ -0,0 +1,342 @@
+import { useEffect, useState } from 'react';
+import { X, Loader2, Copy, Save, Trash2 } from 'lucide-react';
+import {
+  cloneSpace,
+  createSpaceFromTemplate,
+  deleteSpaceTemplate,
+  listSpaceTemplates,
+  saveSpaceTemplate,
+  type ClonedSpace,
+  type Space,
+  type SpaceTemplate,
+} from '@/lib/api/spaces';
+
+interface SpaceTemplateModalProps {
+  spaces: Space[];
+  onClose: () => void;
+  /** Called after a space is created so the space list can be reloaded */
+  onCreated: () => void;
+}
+
+type Mode = 'clone' | 'save' | 'create';
+
+const MODE_LABELS: Record<Mode, string> = {
+  clone: 'Clone',
+  save: 'Save Template',
+  create: 'From Template',
+};
+
+const inputClass =
+  'w-full px-3 py-2 rounded-lg border border-[rgb(var(--border))] bg-[rgb(var(--surface))] text-sm focus:outline-none focus:ring-2 focus:ring-primary-500';
+
+function describe(created: ClonedSpace): string {
+  const parts = [`Created ${created.space.name} with ${created.servers.length} server(s)`];
+  if (created.feature_sets > 0) parts.push(`${created.feature_sets} feature set(s)`);
+  if (created.grants > 0) parts.push(`${created.grants} client grant(s)`);
+  const summary = parts.join(', ');
+  return created.missing_secrets.length > 0
+    ? `${summary}\nSet these space secrets before starting its servers: ${created.missing_secrets.join(', ')}`
+    : summary;
+}
+
+export function SpaceTemplateModal({ spaces, onClose, onCreated }: SpaceTemplateModalProps) {
+  const [mode, setMode] = useState<Mode>('clone');
+  const [sourceId, setSourceId] = useState(spaces[0]?.id ?? '');
+  const [name, setName] = useState('');
+  const [description, setDescription] = useState('');
+  const [includeSecrets, setIncludeSecrets] = useState(false);
+  const [includeGrants, setIncludeGrants] = useState(true);
+  const [templates, setTemplates] = useState<SpaceTemplate[]>([]);
+  const [templateId, setTemplateId] = useState('');
+  // server ID -> input ID -> value
+  const [inputs, setInputs] = useState<Record<string, Record<string, string>>>({});
+  const [isWorking, setIsWorking] = useState(false);
+  const [result, setResult] = useState<string | null>(null);
+  const [error, setError] = useState<string | null>(null);
+
+  const loadTemplates = async () => {
+    try {
+      const list = await listSpaceTemplates();
+      setTemplates(list);
+      setTemplateId((current) =>
+        list.some((t) => t.id === current) ? current : (list[0]?.id ?? '')
+      );
+    } catch (e) {
+      setError(String(e));
+    }
+  };
+
+  useEffect(() => {
+    loadTemplates();
+  }, []);
+
+  const template = templates.find((t) => t.id === templateId);
+
+  const setInput = (serverId: string, inputId: string, value: string) =>
+    setInputs((prev) => ({ ...prev, [serverId]: { ...prev[serverId], [inputId]: value } }));
+
+  const run = async (action: () => Promise<string>) => {
+    setIsWorking(true);
+    setError(null);
+    setResult(null);
+    try {
+      setResult(await action());
+    } catch (e) {
+      setError(String(e));
+    } finally {
+      setIsWorking(false);
+    }
+  };
+
+  const handleClone = () =>
+    run(async () => {
+      const created = await cloneSpace(sourceId, {
+        name: name.trim(),
+        include_secrets: includeSecrets,
+        include_grants: includeGrants,
+      });
+      onCreated();
+      return describe(created);
+    });
+
+  const handleSave = () =>
+    run(async () => {
+      const saved = await saveSpaceTemplate(sourceId, name.trim(), description.trim() || undefined);
+      await loadTemplates();
+      return `Saved template ${saved.name} with ${saved.servers.length} server(s)`;
+    });
+
+  const handleCreate = () =>
+    run(async () => {
+      const created = await createSpaceFromTemplate(templateId, name.trim(), undefined, inputs);
+      setInputs({});
+      onCreated();
+      return describe(created);
+    });
+
+  const handleDeleteTemplate = () =>
+    run(async () => {
+      await deleteSpaceTemplate(templateId);
+      await loadTemplates();
+      return 'Template deleted';
+    });
+
+  const missingRequired =
+    mode === 'create' &&
+    (template?.inputs ?? []).some(
+      (i) => i.input.required && !inputs[i.server_id]?.[i.input.id]?.trim()
+    );
+  const canRun =
+    !isWorking &&
+    name.trim().length > 0 &&
+    (mode === 'create' ? !!templateId && !missingRequired : !!sourceId);
+
+  return (
+    <div className="fixed inset-0 bg-black/60 backdrop-blur-sm flex items-center justify-center z-50 p-4">
+      <div
+        className="bg-[rgb(var(--surface))] w-full max-w-lg max-h-[80vh] rounded-xl shadow-2xl flex flex-col border border-[rgb(var(--border))]"
+        data-testid="space-template-modal"
+      >
+        {/* Header */}
+        <div className="flex items-center justify-between p-4 border-b border-[rgb(var(--border))]">
+          <div>
+            <h3 className="text-lg font-semibold">Clone & Templates</h3>
+            <p className="text-sm text-[rgb(var(--muted))]">
+              Start a new space from an existing one or a saved template
+            </p>
+          </div>
+          <button
+            onClick={onClose}
+            className="p-2 hover:bg-[rgb(var(--surface-hover))] rounded-lg transition-colors"
+          >
+            <X className="h-5 w-5 text-[rgb(var(--muted))]" />
+          </button>
+        </div>
+
+        <div className="flex-1 overflow-y-auto p-4 space-y-4">
+          <div className="flex gap-2">
+            {(Object.keys(MODE_LABELS) as Mode[]).map((m) => (
+              <button
+                key={m}
+                onClick={() => {
+                  setMode(m);
+                  setResult(null);
+                  setError(null);
+                }}
+                className={`flex-1 px-3 py-1.5 text-sm rounded-lg border transition-colors ${
+                  mode === m
+                    ? 'border-primary-500 bg-primary-50 dark:bg-primary-900/20'
+                    : 'border-[rgb(var(--border))] hover:bg-[rgb(var(--surface-hover))]'
+                }`}
+              >
+                {MODE_LABELS[m]}
+              </button>
+            ))}
+          </div>
+
+          {mode === 'create' ? (
+            templates.length === 0 ? (
+              <p className="text-sm text-[rgb(var(--muted))]">
+                No templates yet. Save a space as a template first.
+              </p>
+            ) : (
+              <div>
+                <label className="block text-sm font-medium mb-1.5">Template</label>
+                <div className="flex gap-2">
+                  <select
+                    value={templateId}
+                    onChange={(e) => {
+                      setTemplateId(e.target.value);
+                      setInputs({});
+                    }}
+                    className={inputClass}
+                  >
+                    {templates.map((t) => (
+                      <option key={t.id} value={t.id}>
+                        {t.name}
+                      </option>
+                    ))}
+                  </select>
+                  <button
+                    onClick={handleDeleteTemplate}
+                    disabled={isWorking || !templateId}
+                    className="p-2 text-[rgb(var(--muted))] hover:text-red-500 rounded-lg transition-colors disabled:opacity-50"
+                    title="Delete Template"
+                  >
+                    <Trash2 className="h-4 w-4" />
+                  </button>
+                </div>
+                {template && (
+                  <p className="text-xs text-[rgb(var(--muted))] mt-1">
+                    {template.description ? `${template.description} — ` : ''}
+                    {template.servers.join(', ') || 'No servers'}
+                  </p>
+                )}
+              </div>
+            )
+          ) : (
+            <div>
+              <label className="block text-sm font-medium mb-1.5">Space</label>
+              <select
+                value={sourceId}
+                onChange={(e) => setSourceId(e.target.value)}
+                className={inputClass}
+              >
+                {spaces.map((space) => (
+                  <option key={space.id} value={space.id}>
+                    {space.name}
+                  </option>
+                ))}
+              </select>
+            </div>
+          )}
+
+          <div>
+            <label className="block text-sm font-medium mb-1.5">
+              {mode === 'save' ? 'Template name' : 'New space name'}
+            </label>
+            <input
+              type="text"
+              value={name}
+              onChange={(e) => setName(e.target.value)}
+              className={inputClass}
+              data-testid="space-template-name"
+            />
+          </div>
+
+          {mode === 'save' && (
+            <div>
+              <label className="block text-sm font-medium mb-1.5">Description</label>
+              <input
+                type="text"
+                value={description}
+                onChange={(e) => setDescription(e.target.value)}
+                className={inputClass}
+              />
+              <p className="text-xs text-[rgb(var(--muted))] mt-1">
+                Secrets aren't saved; they're asked for when a space is created from the template
+              </p>
+            </div>
+          )}
+
+          {mode === 'clone' && (
+            <>
+              <label className="flex items-start gap-2 text-sm">
+                <input
+                  type="checkbox"
+                  className="mt-1"
+                  checked={includeSecrets}
+                  onChange={(e) => setIncludeSecrets(e.target.checked)}
+                />
+                <span>
+                  Copy secrets
+                  <span className="block text-xs text-[rgb(var(--muted))]">
+                    API keys, other secret inputs and space secrets; otherwise they need to be entered again. OAuth servers always need connecting again
+                  </span>
+                </span>
+              </label>
+              <label className="flex items-start gap-2 text-sm">
+                <input
+                  type="checkbox"
+                  className="mt-1"
+                  checked={includeGrants}
+                  onChange={(e) => setIncludeGrants(e.target.checked)}
+                />
+                <span>
+                  Copy client grants
+                  <span className="block text-xs text-[rgb(var(--muted))]">
+                    Clients get the same feature sets in the new space
+                  </span>
+                </span>
+              </label>
+            </>
+          )}
+
+          {mode === 'create' &&
+            template?.inputs.map((i) => (
+              <div key={`${i.server_id}/${i.input.id}`}>
+                <label className="block text-sm font-medium mb-1.5">
+                  {i.server_name}: {i.input.label}
+                  {i.input.required && <span className="text-red-500"> *</span>}
+                </label>
+                <input
+                  type={i.input.secret ? 'password' : 'text'}
+                  value={inputs[i.server_id]?.[i.input.id] ?? ''}
+                  onChange={(e) => setInput(i.server_id, i.input.id, e.target.value)}
+                  placeholder={i.input.placeholder}
+                  className={inputClass}
+                />
+              </div>
+            ))}
+
+          {mode === 'create' && template && template.secrets.length > 0 && (
+            <div className="text-xs text-[rgb(var(--muted))]">
+              Space secrets to set after creating the space: {template.secrets.join(', ')}
+            </div>
+          )}
+
+          {error && <div className="text-sm text-[rgb(var(--error))]">{error}</div>}
+          {result && <div className="text-sm whitespace-pre-line">{result}</div>}
+        </div>
+
+        <div className="flex justify-end p-4 border-t border-[rgb(var(--border))]">
+          <button
+            onClick={mode === 'clone' ? handleClone : mode === 'save' ? handleSave : handleCreate}
+            disabled={!canRun}
+            className="flex items-center gap-2 px-3 py-1.5 text-sm bg-[rgb(var(--primary))] text-[rgb(var(--primary-foreground))] rounded-lg hover:bg-[rgb(var(--primary-hover))] transition-colors disabled:opacity-50"
+            data-testid="space-template-run"
+          >
+            {isWorking ? (
+              <Loader2 className="h-4 w-4 animate-spin" />
+            ) : mode === 'save' ? (
+              <Save className="h-4 w-4" />
+            ) : (
+              <Copy className="h-4 w-4" />
+            )}
+            {mode === 'save' ? 'Save Template' : 'Create Space'}
+          </button>
+        </div>
+      </div>
+    </div>
+  );
+}
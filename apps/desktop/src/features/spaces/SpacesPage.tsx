@@ -7,6 +7,10 @@ import {
   Search,
   Layout,
   AlertCircle,
+  Eye,
+  ArrowLeftRight,
+  Copy,
+  KeyRound,
 } from 'lucide-react';
 import {
   Card,
@@ -23,7 +27,17 @@ import {
   useSpaces,
   useIsLoading,
 } from '@/stores';
-import { createSpace, deleteSpace, setActiveSpace as setActiveSpaceAPI } from '@/lib/api/spaces';
+import {
+  createSpace,
+  deleteSpace,
+  listSpaces,
+  setActiveSpace as setActiveSpaceAPI,
+  setSpaceReadOnly,
+  type Space,
+} from '@/lib/api/spaces';
+import { SpaceBundleModal } from './SpaceBundleModal';
+import { SpaceTemplateModal } from './SpaceTemplateModal';
+import { SpaceSecretsModal } from './SpaceSecretsModal';
 
 export function SpacesPage() {
   const spaces = useSpaces();
@@ -34,6 +48,8 @@ export function SpacesPage() {
   const addSpace = useAppStore((state) => state.addSpace);
   const removeSpace = useAppStore((state) => state.removeSpace);
   const setActiveSpaceInStore = useAppStore((state) => state.setActiveSpace);
+  const updateSpace = useAppStore((state) => state.updateSpace);
+  const setSpaces = useAppStore((state) => state.setSpaces);
 
   // Local state
   const [searchQuery, setSearchQuery] = useState('');
@@ -47,6 +63,18 @@ export function SpacesPage() {
   const [newSpaceIcon, setNewSpaceIcon] = useState('🌐');
   const [isCreating, setIsCreating] = useState(false);
 
+  const [showBundleModal, setShowBundleModal] = useState(false);
+  const [showTemplateModal, setShowTemplateModal] = useState(false);
+  const [secretsSpace, setSecretsSpace] = useState<Space | null>(null);
+
+  const reloadSpaces = async () => {
+    try {
+      setSpaces(await listSpaces());
+    } catch (e) {
+      showError('Failed to reload spaces', e instanceof Error ? e.message : String(e));
+    }
+  };
+
   const handleCreate = async () => {
     if (!newSpaceName.trim()) return;
     
@@ -104,6 +132,27 @@ export function SpacesPage() {
     }
   };
 
+  const handleToggleReadOnly = async (id: string, readOnly: boolean) => {
+    setIsActionLoading(id);
+    setError(null);
+    try {
+      const space = await setSpaceReadOnly(id, readOnly);
+      updateSpace(id, { read_only: space.read_only });
+      success(
+        readOnly ? 'Read-only mode on' : 'Read-only mode off',
+        readOnly
+          ? `Clients in "${space.name}" only see read-only tools`
+          : `Clients in "${space.name}" see all granted tools`
+      );
+    } catch (e) {
+      const msg = e instanceof Error ? e.message : String(e);
+      setError(msg);
+      showError('Failed to change read-only mode', msg);
+    } finally {
+      setIsActionLoading(null);
+    }
+  };
+
   // Filter spaces
   const filteredSpaces = spaces.filter(space => {
     if (!searchQuery) return true;
@@ -128,10 +177,20 @@ export function SpacesPage() {
                 Manage isolated environments with their own credentials and server configurations
               </p>
             </div>
-            <Button variant="primary" size="md" onClick={() => setShowCreateModal(true)} data-testid="create-space-btn">
-              <Plus className="h-4 w-4 mr-2" />
-              Create Space
-            </Button>
+            <div className="flex gap-2">
+              <Button variant="secondary" size="md" onClick={() => setShowTemplateModal(true)} data-testid="space-template-btn">
+                <Copy className="h-4 w-4 mr-2" />
+                Clone / Templates
+              </Button>
+              <Button variant="secondary" size="md" onClick={() => setShowBundleModal(true)} data-testid="space-bundle-btn">
+                <ArrowLeftRight className="h-4 w-4 mr-2" />
+                Export / Import
+              </Button>
+              <Button variant="primary" size="md" onClick={() => setShowCreateModal(true)} data-testid="create-space-btn">
+                <Plus className="h-4 w-4 mr-2" />
+                Create Space
+              </Button>
+            </div>
           </div>
 
           {/* Search Bar */}
@@ -220,6 +279,19 @@ export function SpacesPage() {
                               <Check className="h-3 w-3" /> Active
                             </span>
                           )}
+                          {space.read_only && (
+                            <span className="inline-flex items-center gap-1 px-2 py-0.5 rounded-full text-xs font-medium bg-blue-100 dark:bg-blue-900/30 text-blue-700 dark:text-blue-400">
+                              <Eye className="h-3 w-3" /> Read-only
+                            </span>
+                          )}
+                          <button
+                            onClick={() => setSecretsSpace(space)}
+                            className="p-1.5 text-[rgb(var(--muted))] hover:text-[rgb(var(--foreground))] hover:bg-[rgb(var(--surface-hover))] rounded-lg transition-colors"
+                            title="Secrets"
+                            data-testid={`space-secrets-${space.id}`}
+                          >
+                            <KeyRound className="h-4 w-4" />
+                          </button>
                           {!space.is_default && (
                              <button
                                onClick={() => handleDelete(space.id)}
@@ -235,7 +307,20 @@ export function SpacesPage() {
                       </div>
 
                       {/* Footer Actions */}
-                      <div className="flex items-center justify-end pt-3 border-t border-[rgb(var(--border-subtle))]">
+                      <div className="flex items-center justify-between pt-3 border-t border-[rgb(var(--border-subtle))]">
+                         <label
+                           className="flex cursor-pointer items-center gap-1.5 text-xs text-[rgb(var(--muted))]"
+                           title="Only expose tools the server marks as read-only"
+                         >
+                           <input
+                             type="checkbox"
+                             checked={space.read_only ?? false}
+                             onChange={(e) => handleToggleReadOnly(space.id, e.target.checked)}
+                             disabled={isProcessing}
+                             data-testid={`space-read-only-${space.id}`}
+                           />
+                           Read-only
+                         </label>
                          {!isActive ? (
                            <Button
                              size="sm"
@@ -264,6 +349,26 @@ export function SpacesPage() {
         </div>
       </div>
 
+      {showBundleModal && (
+        <SpaceBundleModal
+          spaces={spaces}
+          onClose={() => setShowBundleModal(false)}
+          onImported={reloadSpaces}
+        />
+      )}
+
+      {showTemplateModal && (
+        <SpaceTemplateModal
+          spaces={spaces}
+          onClose={() => setShowTemplateModal(false)}
+          onCreated={reloadSpaces}
+        />
+      )}
+
+      {secretsSpace && (
+        <SpaceSecretsModal space={secretsSpace} onClose={() => setSecretsSpace(null)} />
+      )}
+
       {/* Create Modal */}
       {showCreateModal && (
         <div className="fixed inset-0 bg-black/50 flex items-center justify-center z-50" data-testid="create-space-modal-overlay">
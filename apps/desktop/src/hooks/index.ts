@@ -27,6 +27,7 @@ export type {
   ClientChangedPayload,
   GrantsChangedPayload,
   GatewayChangedPayload,
+  ToolApprovalPayload,
   MCPNotificationPayload,
 } from './useDomainEvents';
 
@@ -58,6 +58,7 @@ export type DomainEventChannel =
   | 'client-changed'
   | 'grants-changed'
   | 'gateway-changed'
+  | 'tool-approval'
   | 'mcp-notification';
 
 /** Base event payload */
@@ -153,6 +154,19 @@ export interface GatewayChangedPayload extends DomainEventPayload {
   port?: number;
 }
 
+/** Tool approval event payloads */
+export interface ToolApprovalPayload extends DomainEventPayload {
+  action: 'requested' | 'resolved';
+  request_id: string;
+  space_id: string;
+  client_id: string;
+  server_id: string;
+  tool_name: string;
+  arguments?: unknown;
+  approved?: boolean;
+  decided_by?: string;
+}
+
 /** MCP notification payload */
 export interface MCPNotificationPayload extends DomainEventPayload {
   type: 'tools_changed' | 'prompts_changed' | 'resources_changed';
@@ -171,6 +185,7 @@ export interface PayloadTypeMap {
   'client-changed': ClientChangedPayload;
   'grants-changed': GrantsChangedPayload;
   'gateway-changed': GatewayChangedPayload;
+  'tool-approval': ToolApprovalPayload;
   'mcp-notification': MCPNotificationPayload;
 }
 
@@ -200,6 +215,7 @@ const ALL_CHANNELS: DomainEventChannel[] = [
   'client-changed',
   'grants-changed',
   'gateway-changed',
+  'tool-approval',
   'mcp-notification',
 ];
 
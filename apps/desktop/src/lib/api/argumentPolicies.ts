@@ -0,0 +1,41 @@
+import { invoke } from '@tauri-apps/api/core';
+
+/**
+ * Constraints a feature set puts on a tool's arguments.
+ */
+export interface ArgumentPolicy {
+  /** Values always sent; hidden from the client */
+  fixed: Record<string, unknown>;
+  /** Values sent when the client omits the property */
+  defaults: Record<string, unknown>;
+  /** The only values a property may take */
+  allowed_values: Record<string, unknown[]>;
+  /** Properties the client may not send; hidden from the client */
+  forbidden: string[];
+}
+
+/**
+ * A feature set's argument policy for one tool (server feature ID).
+ */
+export interface ToolArgumentPolicy extends ArgumentPolicy {
+  feature_set_id: string;
+  feature_id: string;
+}
+
+/**
+ * List the argument policies of a feature set.
+ */
+export async function listArgumentPolicies(featureSetId: string): Promise<ToolArgumentPolicy[]> {
+  return invoke('list_argument_policies', { featureSetId });
+}
+
+/**
+ * Set a feature set's argument policy for a tool (null removes it).
+ */
+export async function setArgumentPolicy(
+  featureSetId: string,
+  featureId: string,
+  policy: ArgumentPolicy | null
+): Promise<void> {
+  return invoke('set_argument_policy', { featureSetId, featureId, policy });
+}
@@ -0,0 +1,66 @@
+import { invoke } from '@tauri-apps/api/core';
+
+export type ImportClient = 'cursor' | 'vs_code' | 'vs_code_continue' | 'claude_desktop';
+
+/** A server found in a client config */
+export interface ImportableServer {
+  /** Key of the server in the client config */
+  name: string;
+  /** Server ID it will be installed as */
+  server_id: string;
+  server_name: string;
+  /** Command line or URL */
+  target: string;
+  /** Recognised as a registry server */
+  registry_match: boolean;
+  /** Inputs holding secrets found as literals (stored encrypted on import) */
+  secret_inputs: string[];
+  /** Already installed in the space (skipped on import) */
+  already_installed: boolean;
+}
+
+/** Servers found in one client config file */
+export interface ClientConfigScan {
+  client: ImportClient;
+  client_name: string;
+  path: string;
+  servers: ImportableServer[];
+  /** Set when the file exists but could not be read */
+  error: string | null;
+}
+
+export interface ImportServersResult {
+  /** Server IDs installed */
+  imported: string[];
+  /** Servers not installed, with the reason */
+  skipped: string[];
+  /** Backup of the client config, if it was rewritten */
+  backup_path: string | null;
+}
+
+/**
+ * Find MCP servers in the config files of installed clients.
+ * Entries that already point at the gateway are left out.
+ */
+export async function scanClientConfigs(
+  spaceId: string,
+  gatewayUrl: string | null
+): Promise<ClientConfigScan[]> {
+  return invoke('scan_client_configs', { spaceId, gatewayUrl });
+}
+
+/**
+ * Install servers from a client config into a space.
+ * With a gateway URL, the client config is backed up and rewritten to use the gateway.
+ */
+export async function importClientServers(
+  client: ImportClient,
+  path: string,
+  spaceId: string,
+  names: string[],
+  gatewayUrl: string | null
+): Promise<ImportServersResult> {
+  return invoke('import_client_servers', {
+    request: { client, path, space_id: spaceId, names, gateway_url: gatewayUrl },
+  });
+}
@@ -0,0 +1,47 @@
+import { invoke } from '@tauri-apps/api/core';
+
+/**
+ * Exposed name, title and description for a tool or prompt.
+ */
+export interface FeatureOverride {
+  space_id: string;
+  /** Feature set the override is limited to (null = whole space) */
+  feature_set_id: string | null;
+  server_id: string;
+  feature_type: 'tool' | 'prompt';
+  feature_name: string;
+  /** Replaces the qualified name (prefix_name) */
+  name: string | null;
+  title: string | null;
+  description: string | null;
+  definition_hash: string | null;
+  updated_at: string;
+  /** The feature changed since the override was saved */
+  stale: boolean;
+}
+
+/**
+ * List a space's feature overrides.
+ */
+export async function listFeatureOverrides(spaceId: string): Promise<FeatureOverride[]> {
+  return invoke('list_feature_overrides', { spaceId });
+}
+
+/**
+ * Set a feature's overrides (empty values clear fields; all empty removes the override).
+ */
+export async function setFeatureOverride(
+  spaceId: string,
+  featureId: string,
+  featureSetId: string | null,
+  values: { name?: string; title?: string; description?: string }
+): Promise<void> {
+  return invoke('set_feature_override', {
+    spaceId,
+    featureId,
+    featureSetId,
+    name: values.name ?? null,
+    title: values.title ?? null,
+    description: values.description ?? null,
+  });
+}
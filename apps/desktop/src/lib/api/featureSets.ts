@@ -7,8 +7,11 @@ export type FeatureSetType = 'all' | 'default' | 'server-all' | 'custom';
 
 /**
  * Member type in a feature set.
+ *
+ * A `rule` member's `member_id` is an expression matched against the space's
+ * current features, e.g. `server:github type:tool annotation:readOnlyHint=true`.
  */
-export type MemberType = 'feature' | 'feature_set';
+export type MemberType = 'feature' | 'feature_set' | 'rule';
 
 /**
  * Mode for including/excluding members.
@@ -39,6 +42,8 @@ export interface FeatureSet {
   server_id: string | null;
   is_builtin: boolean;
   is_deleted: boolean;
+  /** Only admit tools the server marks read-only */
+  read_only: boolean;
   members: FeatureSetMember[];
 }
 
@@ -47,9 +52,11 @@ export interface FeatureSet {
  */
 export interface CreateFeatureSetInput {
   name: string;
-  space_id: string;
+  /** null creates a global feature set, usable in every space */
+  space_id: string | null;
   description?: string;
   icon?: string;
+  read_only?: boolean;
 }
 
 /**
@@ -59,6 +66,7 @@ export interface UpdateFeatureSetInput {
   name?: string;
   description?: string;
   icon?: string;
+  read_only?: boolean;
 }
 
 /**
@@ -84,6 +92,13 @@ export async function listFeatureSetsBySpace(spaceId: string): Promise<FeatureSe
   return invoke('list_feature_sets_by_space', { spaceId });
 }
 
+/**
+ * List global feature sets (usable in every space).
+ */
+export async function listGlobalFeatureSets(): Promise<FeatureSet[]> {
+  return invoke('list_global_feature_sets');
+}
+
 /**
  * Get a feature set by ID.
  */
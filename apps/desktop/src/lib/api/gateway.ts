@@ -6,6 +6,7 @@ import { invoke } from '@tauri-apps/api/core';
 export interface GatewayStatus {
   running: boolean;
   url: string | null;
+  socket_path: string | null;
   active_sessions: number;
   connected_backends: number;
 }
@@ -43,6 +44,94 @@ export async function restartGateway(): Promise<string> {
   return invoke('restart_gateway');
 }
 
+/**
+ * Gateway listener settings (TCP and/or Unix domain socket).
+ */
+export interface GatewayListenerSettings {
+  tcpEnabled: boolean;
+  unixSocketPath: string | null;
+  /** Octal permission bits, e.g. "600" */
+  unixSocketMode: string;
+}
+
+/**
+ * Get gateway listener settings.
+ */
+export async function getGatewayListenerSettings(): Promise<GatewayListenerSettings> {
+  return invoke('get_gateway_listener_settings');
+}
+
+/**
+ * Update gateway listener settings (applied on next gateway restart).
+ */
+export async function updateGatewayListenerSettings(
+  settings: GatewayListenerSettings
+): Promise<void> {
+  return invoke('update_gateway_listener_settings', { settings });
+}
+
+/**
+ * Gateway network exposure settings (bind address, TLS, public URL).
+ */
+export interface GatewayTlsSettings {
+  bindHost: string;
+  publicUrl: string | null;
+  tlsEnabled: boolean;
+  /** PEM certificate chain; null with keyPath null = self-signed */
+  certPath: string | null;
+  keyPath: string | null;
+  clientAuth: 'off' | 'optional' | 'required';
+  clientCaPath: string | null;
+}
+
+/**
+ * Get gateway network exposure settings.
+ */
+export async function getGatewayTlsSettings(): Promise<GatewayTlsSettings> {
+  return invoke('get_gateway_tls_settings');
+}
+
+/**
+ * Update gateway network exposure settings (applied on next gateway restart).
+ */
+export async function updateGatewayTlsSettings(settings: GatewayTlsSettings): Promise<void> {
+  return invoke('update_gateway_tls_settings', { settings });
+}
+
+/**
+ * Per-client tool listing restrictions, matched by software_id / client_name.
+ */
+export interface ClientCompatProfile {
+  name: string;
+  software_ids: string[];
+  /** Case-insensitive substrings of client_name */
+  client_name_patterns: string[];
+  max_tool_name_length: number | null;
+  restrict_tool_name_charset: boolean;
+  inline_schema_refs: boolean;
+  strip_schema_keywords: string[];
+  max_description_length: number | null;
+}
+
+export interface ClientCompatSettings {
+  profiles: ClientCompatProfile[];
+  builtinProfiles: ClientCompatProfile[];
+}
+
+/**
+ * Get client compatibility profiles (user-defined and built-in).
+ */
+export async function getClientCompatProfiles(): Promise<ClientCompatSettings> {
+  return invoke('get_client_compat_profiles');
+}
+
+/**
+ * Replace user-defined client compatibility profiles.
+ */
+export async function updateClientCompatProfiles(profiles: ClientCompatProfile[]): Promise<void> {
+  return invoke('update_client_compat_profiles', { profiles });
+}
+
 /**
  * Export config for a client.
  */
@@ -129,6 +218,8 @@ export interface OAuthClient {
   // MCP client preferences
   connection_mode: string;
   locked_space_id: string | null;
+  /** tools/list returns only the search_tools and invoke_tool meta-tools */
+  meta_tools: boolean;
   last_seen: string | null;
   created_at: string;
   has_active_tokens: boolean;
@@ -141,6 +232,7 @@ export interface UpdateClientRequest {
   client_alias?: string;
   connection_mode?: 'follow_active' | 'locked' | 'ask_on_change';
   locked_space_id?: string | null;
+  meta_tools?: boolean;
 }
 
 /**
@@ -193,6 +285,20 @@ export interface PoolStats {
   total_instances: number;
   connected_instances: number;
   total_space_server_mappings: number;
+  /** Requests waiting for a slot on servers with a concurrency limit */
+  queued_requests: number;
+  request_queues: RequestQueueStats[];
+}
+
+/**
+ * Request queue of a server with a concurrency limit.
+ */
+export interface RequestQueueStats {
+  space_id: string;
+  server_id: string;
+  max_concurrent_requests: number;
+  active_requests: number;
+  queued_requests: number;
 }
 
 /**
@@ -8,3 +8,11 @@ export * from './clientInstall';
 export * from './clients';
 export * from './gateway';
 export * from './serverManager';
+export * from './toolApproval';
+export * from './rateLimits';
+export * from './argumentPolicies';
+export * from './featureOverrides';
+export * from './configImport';
+export * from './spaceBundle';
+export * from './workspaceManifest';
+export * from './spaceSecrets';
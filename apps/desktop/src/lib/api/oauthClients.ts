@@ -16,15 +16,55 @@ export async function getOAuthClientGrants(
   return invoke('get_oauth_client_grants', { clientId, spaceId });
 }
 
+/**
+ * An explicit grant with its optional limits
+ */
+export interface ClientGrant {
+  client_id: string;
+  space_id: string;
+  feature_set_id: string;
+  /** RFC 3339 time after which the grant is revoked */
+  expires_at: string | null;
+  /** Tool calls after which the grant is revoked */
+  max_calls: number | null;
+  call_count: number;
+}
+
+/**
+ * Optional limits for a new grant
+ */
+export interface GrantLimits {
+  expiresAt?: string;
+  maxCalls?: number;
+}
+
+/**
+ * Get an OAuth client's explicit grants in a space, with expiry and call limits.
+ */
+export async function getOAuthClientGrantDetails(
+  clientId: string,
+  spaceId: string
+): Promise<ClientGrant[]> {
+  return invoke('get_oauth_client_grant_details', { clientId, spaceId });
+}
+
 /**
  * Grant a feature set to an OAuth client in a specific space.
+ * With limits, the grant is revoked automatically at `expiresAt` or after `maxCalls` tool calls.
  */
 export async function grantOAuthClientFeatureSet(
   clientId: string,
   spaceId: string,
-  featureSetId: string
+  featureSetId: string,
+  limits?: GrantLimits
 ): Promise<void> {
-  return invoke('grant_oauth_client_feature_set', { clientId, spaceId, featureSetId });
+  return invoke('grant_oauth_client_feature_set', {
+    clientId,
+    spaceId,
+    featureSetId,
+    expiresAt: limits?.expiresAt ?? null,
+    maxCalls: limits?.maxCalls ?? null,
+  });
 }
 
 /**
@@ -44,9 +84,33 @@ export async function revokeOAuthClientFeatureSet(
 export interface ResolvedClientFeatures {
   space_id: string;
   feature_set_ids: string[];
-  tools: Array<{ name: string; description?: string; server_id: string }>;
-  prompts: Array<{ name: string; description?: string; server_id: string }>;
-  resources: Array<{ name: string; description?: string; server_id: string }>;
+  tools: ResolvedFeature[];
+  prompts: ResolvedFeature[];
+  resources: ResolvedFeature[];
+  /** Rule members in the granted feature sets and what they currently match */
+  rules: ResolvedRule[];
+}
+
+/**
+ * A feature a client can access
+ */
+export interface ResolvedFeature {
+  name: string;
+  description?: string;
+  server_id: string;
+  /** Include rules that admit this feature */
+  matched_rules: string[];
+}
+
+/**
+ * A rule member reachable from a client's grants
+ */
+export interface ResolvedRule {
+  feature_set_id: string;
+  expression: string;
+  mode: 'include' | 'exclude';
+  /** Qualified names of the space's available features the rule matches */
+  matches: string[];
 }
 
 /**
@@ -67,3 +131,60 @@ export async function getOAuthClientResolvedFeatures(
   return invoke('get_oauth_client_resolved_features', { clientId, spaceId });
 }
 
+
+/**
+ * A tool as seen through a client's tool budget
+ */
+export interface BudgetedTool {
+  name: string;
+  /** Portable feature key; pins refer to tools by this key */
+  key: string;
+  server_id: string;
+  estimated_tokens: number;
+  call_count: number;
+  pinned: boolean;
+  exposed: boolean;
+}
+
+/**
+ * Tool budget for a client in a space, with estimated token cost of tool schemas
+ */
+export interface ClientToolBudgetInfo {
+  max_tools: number | null;
+  pinned_tools: string[];
+  /** Server IDs whose tools are kept after pinned tools, in order */
+  server_priority: string[];
+  total_tools: number;
+  exposed_tools: number;
+  total_estimated_tokens: number;
+  exposed_estimated_tokens: number;
+  tools: BudgetedTool[];
+}
+
+/**
+ * Get an OAuth client's tool budget and estimated token cost in a specific space.
+ */
+export async function getOAuthClientToolBudget(
+  clientId: string,
+  spaceId: string
+): Promise<ClientToolBudgetInfo> {
+  return invoke('get_oauth_client_tool_budget', { clientId, spaceId });
+}
+
+/**
+ * Update an OAuth client's tool budget (null maxTools = unlimited).
+ * Pinned tools are portable feature keys; server priority lists server IDs.
+ */
+export async function updateOAuthClientToolBudget(
+  clientId: string,
+  maxTools: number | null,
+  pinnedTools: string[],
+  serverPriority: string[]
+): Promise<void> {
+  return invoke('update_oauth_client_tool_budget', {
+    clientId,
+    maxTools,
+    pinnedTools,
+    serverPriority,
+  });
+}
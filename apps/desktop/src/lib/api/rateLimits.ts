@@ -0,0 +1,67 @@
+import { invoke } from '@tauri-apps/api/core';
+
+/**
+ * A client's rate limit and/or daily quota on a server or tool, with usage as of now.
+ */
+export interface ClientRateLimit {
+  client_id: string;
+  server_id: string;
+  /** Backend tool name; null limits every tool on the server */
+  tool_name: string | null;
+  /** Calls per minute (token bucket refill rate) */
+  per_minute: number | null;
+  /** Calls allowed in a burst (defaults to per_minute) */
+  burst: number | null;
+  /** Calls per UTC day */
+  daily_quota: number | null;
+  /** Calls left in the bucket right now */
+  available_calls: number | null;
+  /** Calls left today */
+  quota_remaining: number | null;
+}
+
+/**
+ * Limit values for a new or updated rate limit.
+ */
+export interface RateLimitValues {
+  perMinute?: number;
+  burst?: number;
+  dailyQuota?: number;
+}
+
+/**
+ * List a client's rate limits with their current usage.
+ */
+export async function listClientRateLimits(clientId: string): Promise<ClientRateLimit[]> {
+  return invoke('list_client_rate_limits', { clientId });
+}
+
+/**
+ * Set a client's rate limit for a server, or for one tool when toolName is given.
+ */
+export async function setClientRateLimit(
+  clientId: string,
+  serverId: string,
+  toolName: string | null,
+  values: RateLimitValues
+): Promise<void> {
+  return invoke('set_client_rate_limit', {
+    clientId,
+    serverId,
+    toolName,
+    perMinute: values.perMinute ?? null,
+    burst: values.burst ?? null,
+    dailyQuota: values.dailyQuota ?? null,
+  });
+}
+
+/**
+ * Remove a client's rate limit for a server or tool.
+ */
+export async function deleteClientRateLimit(
+  clientId: string,
+  serverId: string,
+  toolName: string | null
+): Promise<void> {
+  return invoke('delete_client_rate_limit', { clientId, serverId, toolName });
+}
@@ -3,7 +3,14 @@
  */
 
 import { invoke } from '@tauri-apps/api/core';
-import type { RegistryCategory, ServerDefinition, InstalledServerState, UiConfig, HomeConfig } from '../../types/registry';
+import type {
+  RegistryCategory,
+  ServerDefinition,
+  InstalledServerState,
+  UiConfig,
+  HomeConfig,
+  ConcurrencyLimit,
+} from '../../types/registry';
 
 /** Discover all servers (definitions from all sources) */
 export async function discoverServers(): Promise<ServerDefinition[]> {
@@ -80,6 +87,43 @@ export async function setServerOAuthConnected(
   return invoke<void>('set_server_oauth_connected', { id, connected, spaceId });
 }
 
+/** Launch a server with the read-only args/env its definition declares */
+export async function setServerReadOnly(
+  id: string,
+  spaceId: string,
+  readOnly: boolean
+): Promise<void> {
+  return invoke<void>('set_server_read_only', { id, spaceId, readOnly });
+}
+
+/** Limit in-flight requests to a server (null removes the limit) */
+export async function setServerConcurrencyLimit(
+  id: string,
+  spaceId: string,
+  concurrencyLimit: ConcurrencyLimit | null
+): Promise<void> {
+  return invoke<void>('set_server_concurrency_limit', { id, spaceId, concurrencyLimit });
+}
+
+/** Send tool calls without checking arguments against the server's schemas */
+export async function setServerSkipArgumentValidation(
+  id: string,
+  spaceId: string,
+  skip: boolean
+): Promise<void> {
+  return invoke<void>('set_server_skip_argument_validation', { id, spaceId, skip });
+}
+
+/** Set the working directory and .env file a stdio server launches with (null uses the definition's) */
+export async function setServerLaunchOverrides(
+  id: string,
+  spaceId: string,
+  cwd: string | null,
+  envFile: string | null
+): Promise<void> {
+  return invoke<void>('set_server_launch_overrides', { id, spaceId, cwd, envFile });
+}
+
 /** Save input values for a server */
 export async function saveServerInputs(
   id: string,
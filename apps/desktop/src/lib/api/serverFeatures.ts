@@ -20,6 +20,20 @@ export interface ServerFeature {
   discovered_at: string;
   last_seen_at: string;
   is_available: boolean;
+  /** Changed definition awaiting approval; the feature is hidden from clients until approved */
+  pending_definition: Record<string, unknown> | null;
+}
+
+/**
+ * A definition a server reported for a feature.
+ */
+export interface FeatureDefinitionVersion {
+  feature_id: string;
+  definition_hash: string;
+  raw_json: Record<string, unknown> | null;
+  approved: boolean;
+  recorded_at: string;
+  approved_at: string | null;
 }
 
 /**
@@ -56,3 +70,17 @@ export async function listServerFeaturesByType(
 export async function getServerFeature(id: string): Promise<ServerFeature | null> {
   return invoke('get_server_feature', { id });
 }
+
+/**
+ * Approve a feature's changed definition (lifts the quarantine).
+ */
+export async function approveServerFeatureDefinition(id: string): Promise<ServerFeature> {
+  return invoke('approve_server_feature_definition', { id });
+}
+
+/**
+ * List the definition history of a feature (oldest first).
+ */
+export async function listServerFeatureVersions(id: string): Promise<FeatureDefinitionVersion[]> {
+  return invoke('list_server_feature_versions', { id });
+}
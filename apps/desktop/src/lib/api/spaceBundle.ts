@@ -0,0 +1,75 @@
+import { invoke } from '@tauri-apps/api/core';
+
+/** What to do with a bundled server already installed in the target space */
+export type ServerConflict = 'skip' | 'replace' | 'rename';
+
+export interface ExportSpaceBundleResult {
+  path: string;
+  spaces: number;
+  servers: number;
+}
+
+/** Outcome of importing one bundled space */
+export interface ImportedSpace {
+  space_id: string;
+  name: string;
+  /** Whether the space was created by the import */
+  created: boolean;
+  /** Server IDs installed */
+  servers: string[];
+  renamed: { from: string; to: string }[];
+  /** Server IDs not installed because they were already present */
+  skipped: string[];
+  /** Number of custom feature sets created */
+  feature_sets: number;
+  /** Number of OAuth refresh tokens restored */
+  oauth_tokens: number;
+  /** Number of space secrets created */
+  secrets: number;
+  /** Space secrets the servers reference that the space doesn't have */
+  missing_secrets: string[];
+}
+
+export interface BundleImportReport {
+  spaces: ImportedSpace[];
+}
+
+/**
+ * Export spaces, with their servers, inputs and feature sets, into a
+ * passphrase-encrypted file for moving to another machine.
+ */
+export async function exportSpaceBundle(
+  spaceIds: string[],
+  passphrase: string,
+  includeOauthTokens: boolean,
+  path: string
+): Promise<ExportSpaceBundleResult> {
+  return invoke('export_space_bundle', {
+    request: {
+      space_ids: spaceIds,
+      passphrase,
+      include_oauth_tokens: includeOauthTokens,
+      path,
+    },
+  });
+}
+
+/**
+ * Import a bundle file. Each bundled space becomes a new space unless
+ * `targetSpaceId` is given.
+ */
+export async function importSpaceBundle(
+  path: string,
+  passphrase: string,
+  targetSpaceId: string | null,
+  onConflict: ServerConflict
+): Promise<BundleImportReport> {
+  return invoke('import_space_bundle', {
+    request: {
+      path,
+      passphrase,
+      target_space_id: targetSpaceId,
+      on_conflict: onConflict,
+    },
+  });
+}
@@ -0,0 +1,45 @@
+import { invoke } from '@tauri-apps/api/core';
+
+/**
+ * A named secret shared by a space's servers. Input values reference it as
+ * `${secret:name}`. The value never leaves the backend.
+ */
+export interface SpaceSecret {
+  name: string;
+  created_at: string;
+  updated_at: string;
+  /** Server IDs whose input values reference the secret */
+  used_by: string[];
+}
+
+export interface SetSpaceSecretResult {
+  /** Server IDs reconnected to pick up the new value */
+  reconnected: string[];
+  /** Servers that failed to reconnect, as "server_id: error" */
+  errors: string[];
+}
+
+/**
+ * List a space's secrets.
+ */
+export async function listSpaceSecrets(spaceId: string): Promise<SpaceSecret[]> {
+  return invoke('list_space_secrets', { spaceId });
+}
+
+/**
+ * Create or rotate a secret. Enabled servers using it are reconnected.
+ */
+export async function setSpaceSecret(
+  spaceId: string,
+  name: string,
+  value: string
+): Promise<SetSpaceSecretResult> {
+  return invoke('set_space_secret', { spaceId, name, value });
+}
+
+/**
+ * Delete a secret. Fails while a server still references it.
+ */
+export async function deleteSpaceSecret(spaceId: string, name: string): Promise<void> {
+  return invoke('delete_space_secret', { spaceId, name });
+}
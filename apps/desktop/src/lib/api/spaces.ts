@@ -1,4 +1,5 @@
 import { invoke } from '@tauri-apps/api/core';
+import type { InputDefinition } from '../../types/registry';
 
 /**
  * A Space represents an isolated environment with its own credentials and server configs.
@@ -10,6 +11,8 @@ export interface Space {
   description: string | null;
   is_default: boolean;
   sort_order: number;
+  /** Only expose tools marked read-only, whatever the grants */
+  read_only: boolean;
   created_at: string;
   updated_at: string;
 }
@@ -56,6 +59,13 @@ export async function setActiveSpace(id: string): Promise<void> {
   return invoke('set_active_space', { id });
 }
 
+/**
+ * Turn read-only mode on or off for a space.
+ */
+export async function setSpaceReadOnly(id: string, readOnly: boolean): Promise<Space> {
+  return invoke('set_space_read_only', { id, readOnly });
+}
+
 /**
  * Read space configuration JSON file.
  */
@@ -84,3 +94,92 @@ export async function removeServerFromConfig(spaceId: string, serverId: string):
 export async function openSpaceConfigFile(spaceId: string): Promise<void> {
   return invoke('open_space_config_file', { spaceId });
 }
+
+/** Options for cloning a space */
+export interface SpaceCloneOptions {
+  /** Name of the new space */
+  name: string;
+  /** Copy secret inputs (OAuth servers always need connecting again) */
+  include_secrets?: boolean;
+  /** Grant clients the copied feature sets they had in the source space */
+  include_grants?: boolean;
+}
+
+/** A space created by cloning or from a template */
+export interface ClonedSpace {
+  space: Space;
+  /** Server IDs installed */
+  servers: string[];
+  /** Number of custom feature sets created */
+  feature_sets: number;
+  /** Number of client grants copied */
+  grants: number;
+  /** Space secrets the servers reference that still need setting */
+  missing_secrets: string[];
+}
+
+/** An input to fill in when creating a space from a template */
+export interface TemplateInput {
+  server_id: string;
+  server_name: string;
+  input: InputDefinition;
+}
+
+/** A saved space template */
+export interface SpaceTemplate {
+  id: string;
+  name: string;
+  description: string | null;
+  created_at: string;
+  /** Display names of the template's servers */
+  servers: string[];
+  /** Inputs to fill in when creating a space from the template */
+  inputs: TemplateInput[];
+  /** Space secrets to set in spaces created from the template */
+  secrets: string[];
+}
+
+/**
+ * Clone a space: its servers, feature sets, config file and optionally
+ * secrets and client grants.
+ */
+export async function cloneSpace(id: string, options: SpaceCloneOptions): Promise<ClonedSpace> {
+  return invoke('clone_space', { id, options });
+}
+
+/**
+ * List saved space templates.
+ */
+export async function listSpaceTemplates(): Promise<SpaceTemplate[]> {
+  return invoke('list_space_templates');
+}
+
+/**
+ * Save a space as a template (without its secrets).
+ */
+export async function saveSpaceTemplate(
+  spaceId: string,
+  name: string,
+  description?: string
+): Promise<SpaceTemplate> {
+  return invoke('save_space_template', { spaceId, name, description });
+}
+
+/**
+ * Delete a saved space template.
+ */
+export async function deleteSpaceTemplate(id: string): Promise<void> {
+  return invoke('delete_space_template', { id });
+}
+
+/**
+ * Create a space from a template. `inputs` maps server ID to input ID to value.
+ */
+export async function createSpaceFromTemplate(
+  templateId: string,
+  name: string,
+  icon: string | undefined,
+  inputs: Record<string, Record<string, string>>
+): Promise<ClonedSpace> {
+  return invoke('create_space_from_template', { templateId, name, icon, inputs });
+}
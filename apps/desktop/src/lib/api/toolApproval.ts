@@ -0,0 +1,89 @@
+import { invoke } from '@tauri-apps/api/core';
+
+/**
+ * What happens when a client calls a tool.
+ */
+export type ApprovalPolicy = 'allow' | 'ask' | 'deny';
+
+/**
+ * What an approval rule applies to: a tool (server feature ID) or a feature set.
+ */
+export type ApprovalTarget = 'tool' | 'feature_set';
+
+/**
+ * An approval policy for a tool or feature set in a space.
+ */
+export interface ToolApprovalRule {
+  space_id: string;
+  target_type: ApprovalTarget;
+  target: string;
+  policy: ApprovalPolicy;
+}
+
+/**
+ * A tool call held by the gateway until a user decides.
+ */
+export interface ToolApprovalRequest {
+  request_id: string;
+  space_id: string;
+  client_id: string;
+  session_id: string | null;
+  server_id: string;
+  feature_id: string;
+  tool_name: string;
+  arguments: unknown;
+  /** Whether the server annotates the tool as destructive */
+  destructive: boolean;
+  requested_at: string;
+}
+
+/**
+ * List approval rules in a space.
+ */
+export async function listToolApprovalPolicies(spaceId: string): Promise<ToolApprovalRule[]> {
+  return invoke('list_tool_approval_policies', { spaceId });
+}
+
+/**
+ * Set the approval policy for a tool or feature set (null removes the rule).
+ */
+export async function setToolApprovalPolicy(
+  spaceId: string,
+  targetType: ApprovalTarget,
+  target: string,
+  policy: ApprovalPolicy | null
+): Promise<void> {
+  return invoke('set_tool_approval_policy', { spaceId, targetType, target, policy });
+}
+
+/**
+ * Get the policy for tools annotated as destructive that have no rule.
+ */
+export async function getDestructiveToolPolicy(): Promise<ApprovalPolicy> {
+  return invoke('get_destructive_tool_policy');
+}
+
+/**
+ * Set the policy for tools annotated as destructive that have no rule.
+ */
+export async function setDestructiveToolPolicy(policy: ApprovalPolicy): Promise<void> {
+  return invoke('set_destructive_tool_policy', { policy });
+}
+
+/**
+ * List tool calls waiting for approval (oldest first).
+ */
+export async function listPendingToolApprovals(): Promise<ToolApprovalRequest[]> {
+  return invoke('list_pending_tool_approvals');
+}
+
+/**
+ * Approve or reject a held tool call.
+ */
+export async function resolveToolApproval(
+  requestId: string,
+  approved: boolean,
+  remember: boolean
+): Promise<void> {
+  return invoke('resolve_tool_approval', { requestId, approved, remember });
+}
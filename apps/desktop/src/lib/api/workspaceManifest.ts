@@ -0,0 +1,37 @@
+import { invoke } from '@tauri-apps/api/core';
+
+export type ChangeAction = 'create' | 'update' | 'delete';
+
+export type ManifestResource = 'setting' | 'space' | 'server' | 'feature_set' | 'client_grant';
+
+/** One change needed to converge on a manifest */
+export interface PlannedChange {
+  action: ChangeAction;
+  resource: ManifestResource;
+  /** Space the resource belongs to */
+  space: string | null;
+  name: string;
+  /** What changes (attribute names, never secret values) */
+  details: string[];
+}
+
+export interface ManifestPlan {
+  changes: PlannedChange[];
+  /** Declarations that can't take effect yet */
+  warnings: string[];
+}
+
+/**
+ * Preview the changes applying a workspace manifest would make.
+ */
+export async function planWorkspaceManifest(path: string): Promise<ManifestPlan> {
+  return invoke('plan_workspace_manifest', { path });
+}
+
+/**
+ * Converge spaces, servers, feature sets, client grants and settings onto a
+ * workspace manifest. Returns the changes made.
+ */
+export async function applyWorkspaceManifest(path: string): Promise<ManifestPlan> {
+  return invoke('apply_workspace_manifest', { path });
+}
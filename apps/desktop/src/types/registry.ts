@@ -33,14 +33,23 @@ export interface TransportMetadata {
 
 /** Transport configuration */
 export type TransportConfig =
-  | { type: 'stdio'; command: string; args: string[]; env: Record<string, string>; metadata: TransportMetadata }
+  | {
+      type: 'stdio';
+      command: string;
+      args: string[];
+      env: Record<string, string>;
+      cwd?: string;
+      env_file?: string;
+      metadata: TransportMetadata;
+    }
   | { type: 'http'; url: string; headers: Record<string, string>; metadata: TransportMetadata };
 
 /** Server source */
 export type ServerSource =
   | { type: 'UserSpace'; space_id: string; file_path: string }
   | { type: 'Bundled' }
-  | { type: 'Registry'; url: string; name: string };
+  | { type: 'Registry'; url: string; name: string }
+  | { type: 'Imported'; client: string };
 
 /** Publisher info */
 export interface PublisherInfo {
@@ -85,7 +94,17 @@ export type AuthConfig =
 export type InstallationSource =
   | { type: 'registry' }
   | { type: 'user_config'; file_path: string }
-  | { type: 'manual_entry' };
+  | { type: 'manual_entry' }
+  | { type: 'imported'; client: string };
+
+/** Limit on a server's in-flight requests (excess requests wait in a FIFO queue) */
+export interface ConcurrencyLimit {
+  max_concurrent_requests: number;
+  /** Requests allowed to wait for a slot */
+  max_queued_requests: number;
+  /** Seconds a request waits before failing */
+  queue_timeout_secs: number;
+}
 
 /** Installed server state from database */
 export interface InstalledServerState {
@@ -98,7 +117,12 @@ export interface InstalledServerState {
   enabled: boolean;
   env_overrides: Record<string, string>;
   args_append: string[];
+  cwd_override: string | null; // Working directory (stdio only)
+  env_file_override: string | null; // .env file loaded into the environment (stdio only)
   extra_headers: Record<string, string>;
+  read_only: boolean; // Launch with the server's declared read-only args/env
+  concurrency_limit: ConcurrencyLimit | null; // null = unlimited
+  skip_argument_validation: boolean; // Don't check tool arguments against input schemas
   oauth_connected: boolean;
   source: InstallationSource; // How this server was installed
   created_at: string;
@@ -121,8 +145,18 @@ export interface ServerViewModel extends ServerDefinition {
   env_overrides?: Record<string, string>;
   /** Extra arguments to append to command (stdio only) */
   args_append?: string[];
+  /** Working directory override (stdio only) */
+  cwd_override?: string | null;
+  /** .env file override (stdio only) */
+  env_file_override?: string | null;
   /** Extra HTTP headers (http only) */
   extra_headers?: Record<string, string>;
+  /** Launch with the server's declared read-only args/env */
+  read_only?: boolean;
+  /** Limit on in-flight requests (null = unlimited) */
+  concurrency_limit?: ConcurrencyLimit | null;
+  /** Don't check tool arguments against input schemas */
+  skip_argument_validation?: boolean;
 }
 
 /** Registry category */
@@ -209,6 +243,10 @@ export interface Capabilities {
   resources?: boolean;
   prompts?: boolean;
   read_only_mode?: boolean;
+  /** Extra stdio arguments that put the server in read-only mode */
+  read_only_args?: string[];
+  /** Extra environment that puts the server in read-only mode */
+  read_only_env?: Record<string, string>;
 }
 
 /** Sponsorship information */
@@ -8,11 +8,14 @@ use std::sync::Arc;
 use tracing::{info, warn};
 use uuid::Uuid;
 
-use crate::domain::{DomainEvent, InstallationSource, InstalledServer, ServerDefinition};
+use crate::domain::{
+    ConcurrencyLimit, DomainEvent, InstallationSource, InstalledServer, ServerDefinition,
+};
 use crate::event_bus::EventSender;
 use crate::repository::{
     CredentialRepository, FeatureSetRepository, InstalledServerRepository, ServerFeatureRepository,
 };
+use crate::service::{ImportCandidate, ImportClient};
 
 /// Application service for server installation and management
 pub struct ServerAppService {
@@ -64,13 +67,47 @@ impl ServerAppService {
         server_id: &str,
         definition: &ServerDefinition,
         input_values: HashMap<String, String>,
+    ) -> Result<InstalledServer> {
+        let server =
+            InstalledServer::new(space_id.to_string(), server_id).with_inputs(input_values);
+        self.install_server(space_id, server, definition).await
+    }
+
+    /// Install a server imported from another client's config
+    ///
+    /// Emits: `ServerInstalled`
+    pub async fn import(
+        &self,
+        space_id: Uuid,
+        candidate: ImportCandidate,
+        client: ImportClient,
+    ) -> Result<InstalledServer> {
+        let mut server = InstalledServer::new(space_id.to_string(), &candidate.definition.id)
+            .with_inputs(candidate.input_values)
+            .with_source(InstallationSource::Imported {
+                client: client.as_str().to_string(),
+            });
+        server.env_overrides = candidate.env_overrides;
+        server.args_append = candidate.args_append;
+        server.extra_headers = candidate.extra_headers;
+
+        self.install_server(space_id, server, &candidate.definition)
+            .await
+    }
+
+    async fn install_server(
+        &self,
+        space_id: Uuid,
+        server: InstalledServer,
+        definition: &ServerDefinition,
     ) -> Result<InstalledServer> {
         let space_id_str = space_id.to_string();
+        let server_id = server.server_id.clone();
 
         // Check if already installed
         if self
             .server_repo
-            .get_by_server_id(&space_id_str, server_id)
+            .get_by_server_id(&space_id_str, &server_id)
             .await?
             .is_some()
         {
@@ -79,21 +116,18 @@ impl ServerAppService {
 
         // Create installation (disabled by default, user must enable)
         // Cache the definition for offline use
-        let server = InstalledServer::new(&space_id_str, server_id)
-            .with_inputs(input_values)
-            .with_definition(definition)
-            .with_enabled(false);
+        let server = server.with_definition(definition).with_enabled(false);
 
         self.server_repo.install(&server).await?;
 
         // Create server-all feature set
         if let Some(ref fs_repo) = self.feature_set_repo {
             if let Err(e) = fs_repo
-                .ensure_server_all(&space_id_str, server_id, &definition.name)
+                .ensure_server_all(&space_id_str, &server_id, &definition.name)
                 .await
             {
                 tracing::warn!(
-                    server_id = server_id,
+                    server_id = %server_id,
                     error = %e,
                     "Failed to create server-all feature set"
                 );
@@ -102,14 +136,14 @@ impl ServerAppService {
 
         info!(
             space_id = %space_id,
-            server_id = server_id,
+            server_id = %server_id,
             "[ServerAppService] Installed server"
         );
 
         // Emit event
         self.event_sender.emit(DomainEvent::ServerInstalled {
             space_id,
-            server_id: server_id.to_string(),
+            server_id,
             server_name: definition.name.clone(),
         });
 
@@ -344,6 +378,166 @@ impl ServerAppService {
         Ok(())
     }
 
+    /// Limit how many requests are in flight to a server (`None` removes the limit)
+    ///
+    /// Takes effect on the next connect. Emits: `ServerConfigUpdated`
+    pub async fn set_concurrency_limit(
+        &self,
+        space_id: Uuid,
+        server_id: &str,
+        concurrency_limit: Option<ConcurrencyLimit>,
+    ) -> Result<InstalledServer> {
+        if let Some(limit) = &concurrency_limit {
+            limit.validate()?;
+        }
+
+        let space_id_str = space_id.to_string();
+
+        let mut server = self
+            .server_repo
+            .get_by_server_id(&space_id_str, server_id)
+            .await?
+            .ok_or_else(|| anyhow!("Server not installed"))?;
+
+        server.concurrency_limit = concurrency_limit;
+        server.updated_at = chrono::Utc::now();
+        self.server_repo.update(&server).await?;
+
+        info!(
+            space_id = %space_id,
+            server_id = server_id,
+            concurrency_limit = ?server.concurrency_limit,
+            "[ServerAppService] Updated concurrency limit"
+        );
+
+        self.event_sender.emit(DomainEvent::ServerConfigUpdated {
+            space_id,
+            server_id: server_id.to_string(),
+        });
+
+        Ok(server)
+    }
+
+    /// Launch a server in (or out of) the read-only mode its definition declares
+    ///
+    /// Takes effect on the next connect. Emits: `ServerConfigUpdated`
+    pub async fn set_read_only(
+        &self,
+        space_id: Uuid,
+        server_id: &str,
+        read_only: bool,
+    ) -> Result<InstalledServer> {
+        let space_id_str = space_id.to_string();
+
+        let mut server = self
+            .server_repo
+            .get_by_server_id(&space_id_str, server_id)
+            .await?
+            .ok_or_else(|| anyhow!("Server not installed"))?;
+
+        if read_only
+            && !server
+                .get_definition()
+                .is_some_and(|d| d.supports_read_only_mode())
+        {
+            return Err(anyhow!("Server does not declare a read-only mode"));
+        }
+
+        server.read_only = read_only;
+        server.updated_at = chrono::Utc::now();
+        self.server_repo.update(&server).await?;
+
+        info!(
+            space_id = %space_id,
+            server_id = server_id,
+            read_only = read_only,
+            "[ServerAppService] Updated read-only launch mode"
+        );
+
+        self.event_sender.emit(DomainEvent::ServerConfigUpdated {
+            space_id,
+            server_id: server_id.to_string(),
+        });
+
+        Ok(server)
+    }
+
+    /// Set the working directory and `.env` file a stdio server launches with
+    /// (`None` falls back to the definition's)
+    ///
+    /// Takes effect on the next connect. Emits: `ServerConfigUpdated`
+    pub async fn set_launch_overrides(
+        &self,
+        space_id: Uuid,
+        server_id: &str,
+        cwd: Option<String>,
+        env_file: Option<String>,
+    ) -> Result<InstalledServer> {
+        let space_id_str = space_id.to_string();
+
+        let mut server = self
+            .server_repo
+            .get_by_server_id(&space_id_str, server_id)
+            .await?
+            .ok_or_else(|| anyhow!("Server not installed"))?;
+
+        server.cwd_override = cwd.filter(|path| !path.trim().is_empty());
+        server.env_file_override = env_file.filter(|path| !path.trim().is_empty());
+        server.updated_at = chrono::Utc::now();
+        self.server_repo.update(&server).await?;
+
+        info!(
+            space_id = %space_id,
+            server_id = server_id,
+            cwd = ?server.cwd_override,
+            env_file = ?server.env_file_override,
+            "[ServerAppService] Updated launch overrides"
+        );
+
+        self.event_sender.emit(DomainEvent::ServerConfigUpdated {
+            space_id,
+            server_id: server_id.to_string(),
+        });
+
+        Ok(server)
+    }
+
+    /// Turn off (or back on) checking tool arguments against input schemas
+    ///
+    /// Takes effect on the next tool call. Emits: `ServerConfigUpdated`
+    pub async fn set_skip_argument_validation(
+        &self,
+        space_id: Uuid,
+        server_id: &str,
+        skip: bool,
+    ) -> Result<InstalledServer> {
+        let space_id_str = space_id.to_string();
+
+        let mut server = self
+            .server_repo
+            .get_by_server_id(&space_id_str, server_id)
+            .await?
+            .ok_or_else(|| anyhow!("Server not installed"))?;
+
+        server.skip_argument_validation = skip;
+        server.updated_at = chrono::Utc::now();
+        self.server_repo.update(&server).await?;
+
+        info!(
+            space_id = %space_id,
+            server_id = server_id,
+            skip_argument_validation = skip,
+            "[ServerAppService] Updated argument validation"
+        );
+
+        self.event_sender.emit(DomainEvent::ServerConfigUpdated {
+            space_id,
+            server_id: server_id.to_string(),
+        });
+
+        Ok(server)
+    }
+
     /// Update OAuth connected status
     pub async fn set_oauth_connected(
         &self,
@@ -8,7 +8,7 @@ use std::path::Path;
 use std::sync::Arc;
 
 use anyhow::{Context, Result};
-use tracing::{debug, info};
+use tracing::{debug, info, warn};
 
 use crate::domain::config::UserSpaceConfig;
 use crate::domain::{InstallationSource, InstalledServer};
@@ -23,6 +23,9 @@ pub struct SyncResult {
     pub updated: Vec<String>,
     /// Server IDs that were removed
     pub removed: Vec<String>,
+    /// Variables that can't be resolved, as `server: field: problem` lines.
+    /// The servers are still synced but will fail to connect.
+    pub problems: Vec<String>,
 }
 
 impl SyncResult {
@@ -60,7 +63,8 @@ impl UserSpaceSyncService {
     /// * `file_path` - Path to the user space JSON config file
     ///
     /// # Returns
-    /// A `SyncResult` with lists of added, updated, and removed server IDs
+    /// A `SyncResult` with lists of added, updated, and removed server IDs,
+    /// plus any variables in the file that can't be resolved
     pub async fn sync_from_file(&self, space_id: &str, file_path: &Path) -> Result<SyncResult> {
         info!("Syncing servers from file: {:?}", file_path);
 
@@ -72,6 +76,11 @@ impl UserSpaceSyncService {
         let config: UserSpaceConfig = serde_json::from_str(&content)
             .with_context(|| format!("Failed to parse config file: {:?}", file_path))?;
 
+        let problems = config.unresolved_variables(space_id, file_path);
+        for problem in &problems {
+            warn!("Unresolved variable in {:?}: {}", file_path, problem);
+        }
+
         // 2. Convert to ServerDefinitions
         let definitions = config.to_server_definitions(space_id, file_path.to_path_buf());
         let file_server_ids: HashSet<String> = definitions.iter().map(|d| d.id.clone()).collect();
@@ -102,7 +111,10 @@ impl UserSpaceSyncService {
             existing_ids
         );
 
-        let mut result = SyncResult::default();
+        let mut result = SyncResult {
+            problems,
+            ..Default::default()
+        };
 
         // 4. Add/Update servers from file
         for definition in definitions {
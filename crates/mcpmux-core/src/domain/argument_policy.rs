@@ -0,0 +1,295 @@
+//! Tool argument policies
+//!
+//! A feature set can constrain the arguments of a tool it grants: fixed
+//! values (hidden from the client and always sent), defaults, allowed-value
+//! enums and forbidden properties. The advertised `inputSchema` is rewritten
+//! to match and calls are checked again before dispatch.
+
+use std::collections::BTreeMap;
+
+use anyhow::{bail, Result};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+/// A tool call whose arguments break an [`ArgumentPolicy`]
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error("Invalid arguments for {tool}: {reason}")]
+pub struct ArgumentPolicyViolation {
+    pub tool: String,
+    pub reason: String,
+}
+
+/// Constraints on a tool's arguments
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ArgumentPolicy {
+    /// Values always sent; removed from the advertised schema
+    #[serde(default)]
+    pub fixed: Map<String, Value>,
+    /// Values sent when the client omits the property
+    #[serde(default)]
+    pub defaults: Map<String, Value>,
+    /// The only values a property may take (advertised as `enum`)
+    #[serde(default)]
+    pub allowed_values: BTreeMap<String, Vec<Value>>,
+    /// Properties the client may not send; removed from the advertised schema
+    #[serde(default)]
+    pub forbidden: Vec<String>,
+}
+
+/// An argument policy a feature set applies to one tool
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ToolArgumentPolicy {
+    pub feature_set_id: String,
+    /// Server feature ID of the tool
+    pub feature_id: String,
+    #[serde(flatten)]
+    pub policy: ArgumentPolicy,
+}
+
+impl ArgumentPolicy {
+    pub fn is_empty(&self) -> bool {
+        self.fixed.is_empty()
+            && self.defaults.is_empty()
+            && self.allowed_values.is_empty()
+            && self.forbidden.is_empty()
+    }
+
+    /// Check the policy is consistent
+    pub fn validate(&self) -> Result<()> {
+        for name in &self.forbidden {
+            if self.fixed.contains_key(name) || self.defaults.contains_key(name) {
+                bail!("Argument '{}' cannot be both forbidden and set", name);
+            }
+        }
+        for (name, allowed) in &self.allowed_values {
+            if allowed.is_empty() {
+                bail!("Argument '{}' needs at least one allowed value", name);
+            }
+            for value in [self.fixed.get(name), self.defaults.get(name)]
+                .into_iter()
+                .flatten()
+            {
+                if !allowed.contains(value) {
+                    bail!("Value {} for '{}' is not an allowed value", value, name);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Combine with another feature set's policy for the same tool.
+    ///
+    /// Both apply: forbidden properties add up and allowed values narrow.
+    /// For fixed values and defaults set by both, `self` wins.
+    pub fn merge(&mut self, other: &ArgumentPolicy) {
+        for (name, value) in &other.fixed {
+            self.fixed
+                .entry(name.clone())
+                .or_insert_with(|| value.clone());
+        }
+        for (name, value) in &other.defaults {
+            self.defaults
+                .entry(name.clone())
+                .or_insert_with(|| value.clone());
+        }
+        for (name, allowed) in &other.allowed_values {
+            self.allowed_values
+                .entry(name.clone())
+                .and_modify(|mine| mine.retain(|v| allowed.contains(v)))
+                .or_insert_with(|| allowed.clone());
+        }
+        for name in &other.forbidden {
+            if !self.forbidden.contains(name) {
+                self.forbidden.push(name.clone());
+            }
+        }
+    }
+
+    /// Rewrite a tool's `inputSchema` as the client should see it
+    pub fn apply_to_schema(&self, schema: &mut Value) {
+        let hidden =
+            |name: &str| self.fixed.contains_key(name) || self.forbidden.iter().any(|f| f == name);
+
+        if let Some(required) = schema.get_mut("required").and_then(Value::as_array_mut) {
+            required.retain(|r| {
+                r.as_str()
+                    .is_none_or(|name| !hidden(name) && !self.defaults.contains_key(name))
+            });
+        }
+
+        let Some(properties) = schema.get_mut("properties").and_then(Value::as_object_mut) else {
+            return;
+        };
+        properties.retain(|name, _| !hidden(name));
+        for (name, value) in &self.defaults {
+            if let Some(property) = properties.get_mut(name).and_then(Value::as_object_mut) {
+                property.insert("default".to_string(), value.clone());
+            }
+        }
+        for (name, allowed) in &self.allowed_values {
+            if let Some(property) = properties.get_mut(name).and_then(Value::as_object_mut) {
+                property.insert("enum".to_string(), Value::Array(allowed.clone()));
+            }
+        }
+    }
+
+    /// Check a call's arguments and inject fixed values and defaults
+    pub fn apply_to_arguments(
+        &self,
+        tool: &str,
+        arguments: &mut Value,
+    ) -> std::result::Result<(), ArgumentPolicyViolation> {
+        let violation = |reason: String| ArgumentPolicyViolation {
+            tool: tool.to_string(),
+            reason,
+        };
+
+        if arguments.is_null() {
+            *arguments = Value::Object(Map::new());
+        }
+        let Some(args) = arguments.as_object_mut() else {
+            return Err(violation("arguments must be an object".to_string()));
+        };
+
+        for name in &self.forbidden {
+            if args.contains_key(name) {
+                return Err(violation(format!("argument '{}' is not allowed", name)));
+            }
+        }
+        for (name, value) in &self.fixed {
+            match args.get(name) {
+                Some(given) if given != value => {
+                    return Err(violation(format!(
+                        "argument '{}' is fixed to {}",
+                        name, value
+                    )));
+                }
+                _ => {
+                    args.insert(name.clone(), value.clone());
+                }
+            }
+        }
+        for (name, value) in &self.defaults {
+            args.entry(name.clone()).or_insert_with(|| value.clone());
+        }
+        for (name, allowed) in &self.allowed_values {
+            if let Some(given) = args.get(name) {
+                if !allowed.contains(given) {
+                    return Err(violation(format!(
+                        "argument '{}' must be one of {}",
+                        name,
+                        Value::Array(allowed.clone())
+                    )));
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn policy() -> ArgumentPolicy {
+        serde_json::from_value(json!({
+            "fixed": { "owner": "acme" },
+            "defaults": { "state": "open" },
+            "allowed_values": { "state": ["open", "closed"] },
+            "forbidden": ["assignees"]
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_apply_to_schema() {
+        let mut schema = json!({
+            "type": "object",
+            "properties": {
+                "owner": { "type": "string" },
+                "repo": { "type": "string" },
+                "state": { "type": "string" },
+                "assignees": { "type": "array" }
+            },
+            "required": ["owner", "repo", "state"]
+        });
+        policy().apply_to_schema(&mut schema);
+
+        assert_eq!(
+            schema,
+            json!({
+                "type": "object",
+                "properties": {
+                    "repo": { "type": "string" },
+                    "state": { "type": "string", "default": "open", "enum": ["open", "closed"] }
+                },
+                "required": ["repo"]
+            })
+        );
+    }
+
+    #[test]
+    fn test_apply_to_arguments() {
+        let policy = policy();
+
+        let mut args = json!({ "repo": "widgets" });
+        policy
+            .apply_to_arguments("github.create_issue", &mut args)
+            .unwrap();
+        assert_eq!(
+            args,
+            json!({ "repo": "widgets", "owner": "acme", "state": "open" })
+        );
+
+        let mut null_args = Value::Null;
+        policy
+            .apply_to_arguments("github.create_issue", &mut null_args)
+            .unwrap();
+        assert_eq!(null_args["owner"], "acme");
+
+        for bad in [
+            json!({ "owner": "evil" }),
+            json!({ "assignees": ["bob"] }),
+            json!({ "state": "deleted" }),
+        ] {
+            let mut args = bad;
+            let err = policy
+                .apply_to_arguments("github.create_issue", &mut args)
+                .unwrap_err();
+            assert!(err.to_string().contains("github.create_issue"));
+        }
+    }
+
+    #[test]
+    fn test_merge_narrows() {
+        let mut merged = policy();
+        merged.merge(
+            &serde_json::from_value(json!({
+                "fixed": { "owner": "other", "repo": "widgets" },
+                "allowed_values": { "state": ["closed"] },
+                "forbidden": ["labels"]
+            }))
+            .unwrap(),
+        );
+
+        assert_eq!(merged.fixed["owner"], "acme");
+        assert_eq!(merged.fixed["repo"], "widgets");
+        assert_eq!(merged.allowed_values["state"], vec![json!("closed")]);
+        assert_eq!(merged.forbidden, vec!["assignees", "labels"]);
+    }
+
+    #[test]
+    fn test_validate() {
+        assert!(policy().validate().is_ok());
+        assert!(ArgumentPolicy::default().is_empty());
+
+        let mut both = policy();
+        both.forbidden.push("owner".to_string());
+        assert!(both.validate().is_err());
+
+        let mut outside = policy();
+        outside.defaults.insert("state".to_string(), json!("draft"));
+        assert!(outside.validate().is_err());
+    }
+}
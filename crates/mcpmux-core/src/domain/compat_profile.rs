@@ -0,0 +1,130 @@
+//! Client compatibility profiles
+//!
+//! MCP clients differ in what they accept from `tools/list`: some cap tool
+//! names at 64 characters of `[a-zA-Z0-9_-]`, some reject JSON Schema keywords
+//! such as `$ref` or `oneOf`, some choke on long descriptions. A profile
+//! describes those restrictions and is matched against the inbound client's
+//! `software_id` / `client_name`.
+
+use serde::{Deserialize, Serialize};
+
+/// Tool listing restrictions applied for a matching client
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[serde(default)]
+pub struct ClientCompatProfile {
+    /// Profile name (user profiles replace built-ins with the same name)
+    pub name: String,
+
+    /// Exact `software_id` values this profile applies to
+    pub software_ids: Vec<String>,
+
+    /// Case-insensitive substrings matched against `client_name`
+    pub client_name_patterns: Vec<String>,
+
+    /// Maximum tool name length (names are shortened reversibly)
+    pub max_tool_name_length: Option<usize>,
+
+    /// Restrict tool names to `[a-zA-Z0-9_-]`
+    pub restrict_tool_name_charset: bool,
+
+    /// Inline local `$ref`s and drop `$defs`/`definitions`
+    pub inline_schema_refs: bool,
+
+    /// JSON Schema keywords removed from input/output schemas (e.g. "format", "oneOf")
+    pub strip_schema_keywords: Vec<String>,
+
+    /// Maximum tool description length in characters
+    pub max_description_length: Option<usize>,
+}
+
+impl ClientCompatProfile {
+    /// Whether this profile applies to a client with the given identity
+    pub fn matches(&self, software_id: Option<&str>, client_name: &str) -> bool {
+        if let Some(software_id) = software_id {
+            if self.software_ids.iter().any(|id| id == software_id) {
+                return true;
+            }
+        }
+
+        let client_name = client_name.to_lowercase();
+        self.client_name_patterns
+            .iter()
+            .filter(|p| !p.is_empty())
+            .any(|p| client_name.contains(&p.to_lowercase()))
+    }
+
+    /// Whether tool names need rewriting under this profile
+    pub fn rewrites_names(&self) -> bool {
+        self.restrict_tool_name_charset || self.max_tool_name_length.is_some()
+    }
+
+    /// Built-in profiles for clients with known restrictions
+    pub fn builtin() -> Vec<ClientCompatProfile> {
+        vec![ClientCompatProfile {
+            // OpenAI function names must match ^[a-zA-Z0-9_-]{1,64}$
+            name: "openai".to_string(),
+            software_ids: vec![],
+            client_name_patterns: vec!["openai".to_string(), "chatgpt".to_string()],
+            max_tool_name_length: Some(64),
+            restrict_tool_name_charset: true,
+            ..Default::default()
+        }]
+    }
+
+    /// Find the profile for a client.
+    ///
+    /// User profiles are checked first; built-ins that a user profile
+    /// overrides by name are skipped.
+    pub fn resolve(
+        user_profiles: &[ClientCompatProfile],
+        software_id: Option<&str>,
+        client_name: &str,
+    ) -> Option<ClientCompatProfile> {
+        let builtin = Self::builtin();
+        user_profiles
+            .iter()
+            .chain(
+                builtin
+                    .iter()
+                    .filter(|b| !user_profiles.iter().any(|u| u.name == b.name)),
+            )
+            .find(|p| p.matches(software_id, client_name))
+            .cloned()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_matches_software_id_and_name() {
+        let profile = ClientCompatProfile {
+            name: "cursor".to_string(),
+            software_ids: vec!["com.cursor.app".to_string()],
+            client_name_patterns: vec!["Cursor".to_string()],
+            ..Default::default()
+        };
+
+        assert!(profile.matches(Some("com.cursor.app"), "Something"));
+        assert!(profile.matches(None, "cursor (vscode)"));
+        assert!(!profile.matches(Some("com.other.app"), "Claude Desktop"));
+    }
+
+    #[test]
+    fn test_resolve_user_overrides_builtin() {
+        assert_eq!(
+            ClientCompatProfile::resolve(&[], None, "ChatGPT").map(|p| p.name),
+            Some("openai".to_string())
+        );
+
+        // A user profile named like a built-in replaces it entirely
+        let user = vec![ClientCompatProfile {
+            name: "openai".to_string(),
+            client_name_patterns: vec!["my-agent".to_string()],
+            ..Default::default()
+        }];
+        assert!(ClientCompatProfile::resolve(&user, None, "ChatGPT").is_none());
+        assert!(ClientCompatProfile::resolve(&user, None, "My-Agent").is_some());
+    }
+}
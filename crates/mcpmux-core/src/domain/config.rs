@@ -1,3 +1,4 @@
+use crate::domain::interpolation::VariableContext;
 use crate::domain::server::{
     AuthConfig, HostingType, InputDefinition, PublisherInfo, ServerDefinition, ServerSource,
     TransportConfig, TransportMetadata,
@@ -29,6 +30,11 @@ pub struct UserServerEntry {
     pub command: Option<String>,
     pub args: Option<Vec<String>>,
     pub env: Option<HashMap<String, String>>,
+    /// Directory to launch the server from (relative to the config file)
+    pub cwd: Option<String>,
+    /// `.env` file loaded into the server's environment (relative to the config file)
+    #[serde(alias = "envFile")]
+    pub env_file: Option<String>,
 
     // --- HTTP Transport (URL-based) ---
     pub url: Option<String>,
@@ -63,6 +69,29 @@ impl UserSpaceConfig {
             .map(|(id, entry)| entry.to_server_definition(id, space_id, file_path.clone()))
             .collect()
     }
+
+    /// Variables (see [`VariableContext`]) that can't currently be resolved,
+    /// as `server: field: problem` lines sorted by server
+    pub fn unresolved_variables(&self, space_id: &str, file_path: &std::path::Path) -> Vec<String> {
+        let ctx = VariableContext::new(space_id).with_config_file(file_path);
+        let mut problems: Vec<String> = self
+            .servers
+            .iter()
+            .flat_map(|(id, entry)| {
+                entry
+                    .templates()
+                    .into_iter()
+                    .flat_map(|(field, template)| {
+                        ctx.unresolved(template)
+                            .into_iter()
+                            .map(move |e| format!("{}: {}: {}", id, field, e))
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect();
+        problems.sort();
+        problems
+    }
 }
 
 impl UserServerEntry {
@@ -137,6 +166,33 @@ impl UserServerEntry {
         }
     }
 
+    /// Every field that can hold variables, labelled for error messages
+    fn templates(&self) -> Vec<(String, &str)> {
+        let mut templates = Vec::new();
+        if let Some(command) = &self.command {
+            templates.push(("command".to_string(), command.as_str()));
+        }
+        for (i, arg) in self.args.iter().flatten().enumerate() {
+            templates.push((format!("args[{}]", i), arg.as_str()));
+        }
+        for (key, value) in self.env.iter().flatten() {
+            templates.push((format!("env {}", key), value.as_str()));
+        }
+        if let Some(cwd) = &self.cwd {
+            templates.push(("cwd".to_string(), cwd.as_str()));
+        }
+        if let Some(env_file) = &self.env_file {
+            templates.push(("env_file".to_string(), env_file.as_str()));
+        }
+        if let Some(url) = &self.url {
+            templates.push(("url".to_string(), url.as_str()));
+        }
+        for (key, value) in self.headers.iter().flatten() {
+            templates.push((format!("header {}", key), value.as_str()));
+        }
+        templates
+    }
+
     /// Normalize a server ID for prefix compatibility
     /// Removes spaces and special characters, converts to lowercase
     /// IMPORTANT: No underscores - underscore is reserved as delimiter in qualified names (prefix_toolname)
@@ -185,6 +241,8 @@ impl UserServerEntry {
                 command: cmd.clone(),
                 args: self.args.clone().unwrap_or_default(),
                 env: self.env.clone().unwrap_or_default(),
+                cwd: self.cwd.clone(),
+                env_file: self.env_file.clone(),
                 metadata: TransportMetadata::default(),
             }
         } else {
@@ -193,6 +251,8 @@ impl UserServerEntry {
                 command: String::new(),
                 args: vec![],
                 env: HashMap::new(),
+                cwd: None,
+                env_file: None,
                 metadata: TransportMetadata::default(),
             }
         };
@@ -293,6 +353,8 @@ mod tests {
                 "GITHUB_TOKEN".to_string(),
                 "${input:GITHUB_TOKEN}".to_string(),
             )])),
+            cwd: None,
+            env_file: None,
             url: None,
             headers: None,
             name: None,
@@ -318,6 +380,8 @@ mod tests {
             command: Some("${input:BINARY_PATH}".to_string()),
             args: None,
             env: None,
+            cwd: None,
+            env_file: None,
             url: None,
             headers: None,
             name: None,
@@ -344,6 +408,8 @@ mod tests {
                 "${input:GITHUB_TOKEN}".to_string(),
             ]),
             env: None,
+            cwd: None,
+            env_file: None,
             url: None,
             headers: None,
             name: None,
@@ -372,6 +438,8 @@ mod tests {
                 "API_KEY".to_string(),
                 "${input:API_KEY}".to_string(),
             )])),
+            cwd: None,
+            env_file: None,
             url: None,
             headers: None,
             name: None,
@@ -404,6 +472,8 @@ mod tests {
                 ("TOKEN".to_string(), "${input:TOKEN}".to_string()),
                 ("BACKUP_TOKEN".to_string(), "${input:TOKEN}".to_string()),
             ])),
+            cwd: None,
+            env_file: None,
             url: None,
             headers: None,
             name: None,
@@ -430,6 +500,8 @@ mod tests {
                 "API_KEY".to_string(),
                 "${input:API_KEY}".to_string(),
             )])),
+            cwd: None,
+            env_file: None,
             url: None,
             headers: None,
             name: None,
@@ -542,6 +614,8 @@ mod tests {
             command: None,
             args: None,
             env: None,
+            cwd: None,
+            env_file: None,
             url: Some("https://api.example.com/mcp".to_string()),
             headers: Some(HashMap::from([(
                 "Authorization".to_string(),
@@ -578,6 +652,8 @@ mod tests {
                 "NODE_ENV".to_string(),
                 "production".to_string(),
             )])),
+            cwd: None,
+            env_file: None,
             url: None,
             headers: None,
             name: None,
@@ -611,6 +687,8 @@ mod tests {
                 "API_KEY".to_string(),
                 "${input:API_KEY}".to_string(),
             )])),
+            cwd: None,
+            env_file: None,
             url: None,
             headers: None,
             name: None,
@@ -636,6 +714,8 @@ mod tests {
                 "TOKEN".to_string(),
                 "${input:TOKEN}".to_string(),
             )])),
+            cwd: None,
+            env_file: None,
             url: None,
             headers: None,
             name: None,
@@ -698,6 +778,8 @@ mod tests {
                 "LOG_LEVEL".to_string(),
                 "${input:LOG_LEVEL}".to_string(),
             )])),
+            cwd: None,
+            env_file: None,
             url: None,
             headers: None,
             name: None,
@@ -742,6 +824,8 @@ mod tests {
                 "API_KEY".to_string(),
                 "${input:API_KEY}".to_string(),
             )])),
+            cwd: None,
+            env_file: None,
             url: None,
             headers: None,
             name: None,
@@ -780,4 +864,34 @@ mod tests {
         assert_eq!(deserialized.id, "PORT");
         assert_eq!(deserialized.default, Some("8080".to_string()));
     }
+
+    #[test]
+    fn test_unresolved_variables_are_reported_per_field() {
+        let config: UserSpaceConfig = serde_json::from_str(
+            r#"{
+                "mcpServers": {
+                    "github": {
+                        "command": "npx",
+                        "args": ["${workspaceFolder}/server.js"],
+                        "env": {
+                            "TOKEN": "${env:MCPMUX_CONFIG_TEST_UNSET}",
+                            "LEVEL": "${env:MCPMUX_CONFIG_TEST_UNSET:-info}",
+                            "KEY": "${input:KEY}"
+                        }
+                    }
+                }
+            }"#,
+        )
+        .unwrap();
+
+        let problems = config.unresolved_variables("space-1", &PathBuf::from("/tmp/space.json"));
+        assert_eq!(
+            problems,
+            vec![
+                "github: env TOKEN: ${env:MCPMUX_CONFIG_TEST_UNSET}: environment variable \
+                 MCPMUX_CONFIG_TEST_UNSET is not set"
+                    .to_string()
+            ]
+        );
+    }
 }
@@ -17,7 +17,7 @@ use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
-use super::ServerFeature;
+use super::{FeatureType, ServerFeature};
 
 // ============================================================================
 // CACHED FEATURES (moved from gateway to core for event payloads)
@@ -183,6 +183,9 @@ pub enum DomainEvent {
     /// A space was deleted
     SpaceDeleted { space_id: Uuid },
 
+    /// A space's read-only switch was toggled
+    SpaceReadOnlyChanged { space_id: Uuid, read_only: bool },
+
     /// Active space changed
     SpaceActivated {
         #[serde(skip_serializing_if = "Option::is_none")]
@@ -254,6 +257,31 @@ pub enum DomainEvent {
         removed: Vec<String>,
     },
 
+    /// A server changed a feature's definition; the feature is quarantined
+    /// until the user approves the new definition
+    FeatureDefinitionChanged {
+        space_id: Uuid,
+        server_id: String,
+        feature_id: Uuid,
+        feature_type: FeatureType,
+        feature_name: String,
+        /// Approved definition (still stored, not served while quarantined)
+        #[serde(skip_serializing_if = "Option::is_none")]
+        old_definition: Option<serde_json::Value>,
+        /// Definition now reported by the server
+        #[serde(skip_serializing_if = "Option::is_none")]
+        new_definition: Option<serde_json::Value>,
+    },
+
+    /// The user approved a changed feature definition
+    FeatureDefinitionApproved {
+        space_id: Uuid,
+        server_id: String,
+        feature_id: Uuid,
+        feature_type: FeatureType,
+        feature_name: String,
+    },
+
     // ════════════════════════════════════════════════════════════════════════
     // FEATURE SETS
     // ════════════════════════════════════════════════════════════════════════
@@ -336,6 +364,32 @@ pub enum DomainEvent {
         feature_set_ids: Vec<String>,
     },
 
+    // ════════════════════════════════════════════════════════════════════════
+    // TOOL CALL APPROVAL
+    // ════════════════════════════════════════════════════════════════════════
+    /// A tool call is held until a user approves or rejects it
+    ToolApprovalRequested {
+        request_id: Uuid,
+        space_id: Uuid,
+        client_id: String,
+        server_id: String,
+        /// Qualified tool name as called by the client
+        tool_name: String,
+        arguments: serde_json::Value,
+    },
+
+    /// A held tool call was approved, rejected or timed out
+    ToolApprovalResolved {
+        request_id: Uuid,
+        space_id: Uuid,
+        client_id: String,
+        server_id: String,
+        tool_name: String,
+        approved: bool,
+        /// Who decided ("desktop", "client", "timeout")
+        decided_by: String,
+    },
+
     // ════════════════════════════════════════════════════════════════════════
     // GATEWAY
     // ════════════════════════════════════════════════════════════════════════
@@ -368,6 +422,7 @@ impl DomainEvent {
         match self {
             Self::SpaceCreated { .. } => "space_created",
             Self::SpaceUpdated { .. } => "space_updated",
+            Self::SpaceReadOnlyChanged { .. } => "space_read_only_changed",
             Self::SpaceDeleted { .. } => "space_deleted",
             Self::SpaceActivated { .. } => "space_activated",
             Self::ServerInstalled { .. } => "server_installed",
@@ -378,6 +433,8 @@ impl DomainEvent {
             Self::ServerStatusChanged { .. } => "server_status_changed",
             Self::ServerAuthProgress { .. } => "server_auth_progress",
             Self::ServerFeaturesRefreshed { .. } => "server_features_refreshed",
+            Self::FeatureDefinitionChanged { .. } => "feature_definition_changed",
+            Self::FeatureDefinitionApproved { .. } => "feature_definition_approved",
             Self::FeatureSetCreated { .. } => "feature_set_created",
             Self::FeatureSetUpdated { .. } => "feature_set_updated",
             Self::FeatureSetDeleted { .. } => "feature_set_deleted",
@@ -390,6 +447,8 @@ impl DomainEvent {
             Self::GrantIssued { .. } => "grant_issued",
             Self::GrantRevoked { .. } => "grant_revoked",
             Self::ClientGrantsUpdated { .. } => "client_grants_updated",
+            Self::ToolApprovalRequested { .. } => "tool_approval_requested",
+            Self::ToolApprovalResolved { .. } => "tool_approval_resolved",
             Self::GatewayStarted { .. } => "gateway_started",
             Self::GatewayStopped => "gateway_stopped",
             Self::ToolsChanged { .. } => "tools_changed",
@@ -412,12 +471,16 @@ impl DomainEvent {
             }
             // Feature refresh directly affects capabilities
             Self::ServerFeaturesRefreshed { .. } => true,
+            // Quarantine hides a feature; approval brings it back
+            Self::FeatureDefinitionChanged { .. } | Self::FeatureDefinitionApproved { .. } => true,
             // Grant changes affect what client can access
             Self::GrantIssued { .. }
             | Self::GrantRevoked { .. }
             | Self::ClientGrantsUpdated { .. } => true,
             // Feature set member changes affect granted capabilities
             Self::FeatureSetMembersChanged { .. } => true,
+            // Read-only mode hides tools not marked read-only
+            Self::SpaceReadOnlyChanged { .. } => true,
             // Backend server notifications
             Self::ToolsChanged { .. }
             | Self::PromptsChanged { .. }
@@ -432,6 +495,7 @@ impl DomainEvent {
         match self {
             Self::SpaceCreated { space_id, .. }
             | Self::SpaceUpdated { space_id, .. }
+            | Self::SpaceReadOnlyChanged { space_id, .. }
             | Self::SpaceDeleted { space_id }
             | Self::ServerInstalled { space_id, .. }
             | Self::ServerUninstalled { space_id, .. }
@@ -441,6 +505,8 @@ impl DomainEvent {
             | Self::ServerStatusChanged { space_id, .. }
             | Self::ServerAuthProgress { space_id, .. }
             | Self::ServerFeaturesRefreshed { space_id, .. }
+            | Self::FeatureDefinitionChanged { space_id, .. }
+            | Self::FeatureDefinitionApproved { space_id, .. }
             | Self::FeatureSetCreated { space_id, .. }
             | Self::FeatureSetUpdated { space_id, .. }
             | Self::FeatureSetDeleted { space_id, .. }
@@ -448,6 +514,8 @@ impl DomainEvent {
             | Self::GrantIssued { space_id, .. }
             | Self::GrantRevoked { space_id, .. }
             | Self::ClientGrantsUpdated { space_id, .. }
+            | Self::ToolApprovalRequested { space_id, .. }
+            | Self::ToolApprovalResolved { space_id, .. }
             | Self::ToolsChanged { space_id, .. }
             | Self::PromptsChanged { space_id, .. }
             | Self::ResourcesChanged { space_id, .. } => Some(*space_id),
@@ -475,6 +543,10 @@ impl DomainEvent {
             | Self::ServerStatusChanged { server_id, .. }
             | Self::ServerAuthProgress { server_id, .. }
             | Self::ServerFeaturesRefreshed { server_id, .. }
+            | Self::FeatureDefinitionChanged { server_id, .. }
+            | Self::FeatureDefinitionApproved { server_id, .. }
+            | Self::ToolApprovalRequested { server_id, .. }
+            | Self::ToolApprovalResolved { server_id, .. }
             | Self::ToolsChanged { server_id, .. }
             | Self::PromptsChanged { server_id, .. }
             | Self::ResourcesChanged { server_id, .. } => Some(server_id),
@@ -491,7 +563,9 @@ impl DomainEvent {
             | Self::ClientTokenIssued { client_id, .. }
             | Self::GrantIssued { client_id, .. }
             | Self::GrantRevoked { client_id, .. }
-            | Self::ClientGrantsUpdated { client_id, .. } => Some(client_id),
+            | Self::ClientGrantsUpdated { client_id, .. }
+            | Self::ToolApprovalRequested { client_id, .. }
+            | Self::ToolApprovalResolved { client_id, .. } => Some(client_id),
             _ => None,
         }
     }
@@ -0,0 +1,229 @@
+//! Feature overrides
+//!
+//! A space (or one feature set in it) can expose a tool or prompt under a
+//! different name, title or description than the backend reports. Overrides
+//! are keyed by server and feature name so they survive rediscovery; the
+//! definition hash recorded when an override is saved flags it as stale once
+//! the backend changes the feature.
+
+use anyhow::{bail, Result};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use super::{FeatureType, ServerFeature};
+
+/// Longest exposed name accepted (most clients cap tool names at 64)
+const MAX_NAME_LEN: usize = 64;
+
+/// Exposed name, title and description for one feature
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FeatureOverride {
+    pub space_id: String,
+
+    /// Feature set the override is limited to (`None` = the whole space)
+    #[serde(default)]
+    pub feature_set_id: Option<String>,
+
+    pub server_id: String,
+    pub feature_type: FeatureType,
+    pub feature_name: String,
+
+    /// Name exposed to clients in place of the qualified name
+    #[serde(default)]
+    pub name: Option<String>,
+
+    #[serde(default)]
+    pub title: Option<String>,
+
+    #[serde(default)]
+    pub description: Option<String>,
+
+    /// Definition hash of the feature when the override was saved
+    #[serde(default)]
+    pub definition_hash: Option<String>,
+
+    #[serde(default = "Utc::now")]
+    pub updated_at: DateTime<Utc>,
+}
+
+impl FeatureOverride {
+    /// Empty override for a feature, recording its current definition
+    pub fn for_feature(feature: &ServerFeature, feature_set_id: Option<String>) -> Self {
+        Self {
+            space_id: feature.space_id.clone(),
+            feature_set_id,
+            server_id: feature.server_id.clone(),
+            feature_type: feature.feature_type.clone(),
+            feature_name: feature.feature_name.clone(),
+            name: None,
+            title: None,
+            description: None,
+            definition_hash: feature.definition_hash.clone(),
+            updated_at: Utc::now(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.name.is_none() && self.title.is_none() && self.description.is_none()
+    }
+
+    /// Check the override can be applied
+    pub fn validate(&self) -> Result<()> {
+        if self.feature_type == FeatureType::Resource {
+            bail!("Resources cannot be overridden");
+        }
+        if let Some(name) = &self.name {
+            if name.is_empty() || name.len() > MAX_NAME_LEN {
+                bail!("Name must be 1-{} characters", MAX_NAME_LEN);
+            }
+            if !name
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+            {
+                bail!("Name may only contain letters, digits, '_' and '-'");
+            }
+        }
+        Ok(())
+    }
+
+    /// Whether this override targets the feature
+    pub fn targets(&self, feature: &ServerFeature) -> bool {
+        self.server_id == feature.server_id
+            && self.feature_type == feature.feature_type
+            && self.feature_name == feature.feature_name
+    }
+
+    /// Whether the feature changed since the override was saved
+    pub fn is_stale(&self, feature: &ServerFeature) -> bool {
+        feature.pending_definition.is_some()
+            || (self.definition_hash.is_some() && self.definition_hash != feature.definition_hash)
+    }
+
+    /// Rewrite the feature (and its raw JSON) as clients should see it
+    pub fn apply(&self, feature: &mut ServerFeature) {
+        if let Some(name) = &self.name {
+            feature.exposed_name = Some(name.clone());
+        }
+        if let Some(title) = &self.title {
+            feature.display_name = Some(title.clone());
+        }
+        if let Some(description) = &self.description {
+            feature.description = Some(description.clone());
+        }
+
+        let Some(json) = feature.raw_json.as_mut().and_then(|j| j.as_object_mut()) else {
+            return;
+        };
+        if let Some(title) = &self.title {
+            json.insert("title".to_string(), title.clone().into());
+        }
+        if let Some(description) = &self.description {
+            json.insert("description".to_string(), description.clone().into());
+        }
+    }
+}
+
+/// Apply a space's overrides to features granted through `feature_set_ids`.
+///
+/// Space-wide overrides apply first; an override for a granted feature set
+/// then wins field by field (the first granted set's override, in grant order).
+pub fn apply_feature_overrides(
+    overrides: &[FeatureOverride],
+    feature_set_ids: &[String],
+    features: &mut [ServerFeature],
+) {
+    if overrides.is_empty() {
+        return;
+    }
+    for feature in features.iter_mut() {
+        if let Some(space_wide) = overrides
+            .iter()
+            .find(|o| o.feature_set_id.is_none() && o.targets(feature))
+        {
+            space_wide.apply(feature);
+        }
+        let for_set = feature_set_ids.iter().find_map(|set_id| {
+            overrides
+                .iter()
+                .find(|o| o.feature_set_id.as_ref() == Some(set_id) && o.targets(feature))
+        });
+        if let Some(for_set) = for_set {
+            for_set.apply(feature);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn tool() -> ServerFeature {
+        let mut tool = ServerFeature::tool("space_1", "github", "search_repositories")
+            .with_description("Search for GitHub repositories (long backend text)")
+            .with_raw_json(json!({
+                "name": "search_repositories",
+                "description": "Search for GitHub repositories (long backend text)",
+                "inputSchema": { "type": "object" }
+            }));
+        tool.definition_hash = Some("abc".to_string());
+        tool
+    }
+
+    #[test]
+    fn test_apply_renames_and_rewrites_description() {
+        let mut feature = tool();
+        let mut ov = FeatureOverride::for_feature(&feature, None);
+        ov.name = Some("find_repos".to_string());
+        ov.description = Some("Find repositories".to_string());
+        ov.apply(&mut feature);
+
+        assert_eq!(feature.qualified_name(), "find_repos");
+        assert_eq!(
+            feature.qualified_name_with_server_id(),
+            "github_search_repositories"
+        );
+        assert_eq!(feature.description.as_deref(), Some("Find repositories"));
+        assert_eq!(
+            feature.raw_json.unwrap()["description"],
+            "Find repositories"
+        );
+    }
+
+    #[test]
+    fn test_feature_set_override_wins() {
+        let feature = tool();
+        let mut space_wide = FeatureOverride::for_feature(&feature, None);
+        space_wide.name = Some("find_repos".to_string());
+        space_wide.title = Some("Find repositories".to_string());
+        let mut for_set = FeatureOverride::for_feature(&feature, Some("fs_1".to_string()));
+        for_set.name = Some("repo_search".to_string());
+        let overrides = vec![space_wide, for_set];
+
+        let mut granted = vec![feature.clone()];
+        apply_feature_overrides(&overrides, &["fs_1".to_string()], &mut granted);
+        assert_eq!(granted[0].qualified_name(), "repo_search");
+        assert_eq!(
+            granted[0].display_name.as_deref(),
+            Some("Find repositories")
+        );
+
+        let mut other = vec![feature];
+        apply_feature_overrides(&overrides, &["fs_2".to_string()], &mut other);
+        assert_eq!(other[0].qualified_name(), "find_repos");
+    }
+
+    #[test]
+    fn test_validate_and_stale() {
+        let mut feature = tool();
+        let mut ov = FeatureOverride::for_feature(&feature, None);
+        ov.name = Some("find repos".to_string());
+        assert!(ov.validate().is_err());
+        ov.name = Some("find_repos".to_string());
+        assert!(ov.validate().is_ok());
+
+        assert!(!ov.is_stale(&feature));
+        feature.definition_hash = Some("def".to_string());
+        assert!(ov.is_stale(&feature));
+    }
+}
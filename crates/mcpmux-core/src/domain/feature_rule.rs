@@ -0,0 +1,199 @@
+//! Rule members - feature set membership matched against discovered features
+//!
+//! A rule member is stored as an expression in `FeatureSetMember::member_id`
+//! and evaluated whenever grants are resolved, so features a server adds
+//! later are picked up without editing the set. An expression is a
+//! whitespace-separated list of conditions that must all hold:
+//!
+//! - `name:<glob>` or `name:/<regex>/` - feature name, with or without its
+//!   server prefix (e.g. `name:github_*`, `name:/^(get|list)_/`)
+//! - `server:<glob>` or `server:/<regex>/` - server ID
+//! - `type:tool|prompt|resource`
+//! - `annotation:<hint>` or `annotation:<hint>=<value>` - tool annotation
+//!   (e.g. `annotation:readOnlyHint=true`)
+
+use anyhow::{anyhow, bail, Result};
+use regex::Regex;
+
+use super::{FeatureType, ServerFeature};
+
+/// How a rule condition matches text
+#[derive(Debug, Clone)]
+enum TextMatcher {
+    Glob(glob::Pattern),
+    Regex(Regex),
+}
+
+impl TextMatcher {
+    fn parse(pattern: &str) -> Result<Self> {
+        match pattern.strip_prefix('/').and_then(|p| p.strip_suffix('/')) {
+            Some(re) => Regex::new(re)
+                .map(Self::Regex)
+                .map_err(|e| anyhow!("Invalid regex '{}': {}", re, e)),
+            None => glob::Pattern::new(pattern)
+                .map(Self::Glob)
+                .map_err(|e| anyhow!("Invalid glob '{}': {}", pattern, e)),
+        }
+    }
+
+    fn matches(&self, text: &str) -> bool {
+        match self {
+            Self::Glob(pattern) => pattern.matches(text),
+            Self::Regex(re) => re.is_match(text),
+        }
+    }
+}
+
+/// A single condition of a rule
+#[derive(Debug, Clone)]
+enum RuleCondition {
+    Name(TextMatcher),
+    Server(TextMatcher),
+    Type(FeatureType),
+    /// Annotation value; `true` when written without `=<value>`
+    Annotation(String, serde_json::Value),
+}
+
+impl RuleCondition {
+    fn parse(term: &str) -> Result<Self> {
+        let (field, value) = term
+            .split_once(':')
+            .ok_or_else(|| anyhow!("Expected <field>:<pattern>, got '{}'", term))?;
+        if value.is_empty() {
+            bail!("Missing pattern for '{}'", field);
+        }
+        match field {
+            "name" => Ok(Self::Name(TextMatcher::parse(value)?)),
+            "server" => Ok(Self::Server(TextMatcher::parse(value)?)),
+            "type" => FeatureType::parse(value)
+                .map(Self::Type)
+                .ok_or_else(|| anyhow!("Unknown feature type '{}'", value)),
+            "annotation" => {
+                let (hint, expected) = match value.split_once('=') {
+                    Some((hint, raw)) => (
+                        hint,
+                        serde_json::from_str(raw)
+                            .unwrap_or_else(|_| serde_json::Value::String(raw.to_string())),
+                    ),
+                    None => (value, serde_json::Value::Bool(true)),
+                };
+                Ok(Self::Annotation(hint.to_string(), expected))
+            }
+            other => bail!(
+                "Unknown rule field '{}' (expected name, server, type or annotation)",
+                other
+            ),
+        }
+    }
+
+    fn matches(&self, feature: &ServerFeature) -> bool {
+        match self {
+            Self::Name(m) => {
+                m.matches(&feature.feature_name)
+                    || m.matches(&feature.qualified_name())
+                    || m.matches(&feature.qualified_name_with_server_id())
+            }
+            Self::Server(m) => m.matches(&feature.server_id),
+            Self::Type(t) => feature.feature_type == *t,
+            Self::Annotation(hint, expected) => {
+                feature
+                    .raw_json
+                    .as_ref()
+                    .and_then(|raw| raw.get("annotations"))
+                    .and_then(|annotations| annotations.get(hint))
+                    == Some(expected)
+            }
+        }
+    }
+}
+
+/// A parsed rule member expression
+#[derive(Debug, Clone)]
+pub struct FeatureRule {
+    expression: String,
+    conditions: Vec<RuleCondition>,
+}
+
+impl FeatureRule {
+    /// Parse a rule expression, e.g. `server:github type:tool annotation:readOnlyHint=true`
+    pub fn parse(expression: &str) -> Result<Self> {
+        let conditions = expression
+            .split_whitespace()
+            .map(RuleCondition::parse)
+            .collect::<Result<Vec<_>>>()?;
+        if conditions.is_empty() {
+            bail!("Rule expression is empty");
+        }
+        Ok(Self {
+            expression: expression.trim().to_string(),
+            conditions,
+        })
+    }
+
+    /// The expression this rule was parsed from
+    pub fn as_str(&self) -> &str {
+        &self.expression
+    }
+
+    /// Whether every condition holds for the feature
+    pub fn matches(&self, feature: &ServerFeature) -> bool {
+        self.conditions.iter().all(|c| c.matches(feature))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn read_only_tool(server_id: &str, name: &str) -> ServerFeature {
+        ServerFeature::tool("space-1", server_id, name).with_raw_json(serde_json::json!({
+            "name": name,
+            "annotations": { "readOnlyHint": true }
+        }))
+    }
+
+    #[test]
+    fn test_name_glob_matches_with_and_without_prefix() {
+        let tool = ServerFeature::tool("space-1", "github", "create_issue");
+        assert!(FeatureRule::parse("name:create_*").unwrap().matches(&tool));
+        assert!(FeatureRule::parse("name:github_*").unwrap().matches(&tool));
+        assert!(!FeatureRule::parse("name:delete_*").unwrap().matches(&tool));
+    }
+
+    #[test]
+    fn test_regex_and_combined_conditions() {
+        let rule =
+            FeatureRule::parse("server:/^git(hub|lab)$/ type:tool name:/^(get|list)_/").unwrap();
+        assert!(rule.matches(&ServerFeature::tool("space-1", "gitlab", "list_repos")));
+        assert!(!rule.matches(&ServerFeature::tool("space-1", "gitlab", "delete_repo")));
+        assert!(!rule.matches(&ServerFeature::prompt("space-1", "github", "list_prs")));
+        assert!(!rule.matches(&ServerFeature::tool("space-1", "jira", "list_issues")));
+    }
+
+    #[test]
+    fn test_annotation_conditions() {
+        let read = read_only_tool("fs", "read_file");
+        let write = ServerFeature::tool("space-1", "fs", "write_file");
+
+        let rule = FeatureRule::parse("annotation:readOnlyHint=true").unwrap();
+        assert!(rule.matches(&read));
+        assert!(!rule.matches(&write));
+
+        // A bare hint means `=true`
+        assert!(FeatureRule::parse("annotation:readOnlyHint")
+            .unwrap()
+            .matches(&read));
+        assert!(!FeatureRule::parse("annotation:readOnlyHint=false")
+            .unwrap()
+            .matches(&read));
+    }
+
+    #[test]
+    fn test_invalid_expressions() {
+        assert!(FeatureRule::parse("").is_err());
+        assert!(FeatureRule::parse("name").is_err());
+        assert!(FeatureRule::parse("owner:me").is_err());
+        assert!(FeatureRule::parse("type:widget").is_err());
+        assert!(FeatureRule::parse("name:/(unclosed/").is_err());
+    }
+}
@@ -6,6 +6,11 @@
 //! - Default: Features auto-granted to all clients in the space
 //! - ServerAll: All features from a specific server
 //! - Custom: User-defined composition of features and other featuresets
+//!
+//! Custom featuresets may also be global (no space). A global set applies in
+//! every space it is nested into or granted in; its feature members are stored
+//! as [`ServerFeature::portable_key`](super::ServerFeature::portable_key)s
+//! because feature IDs differ per space.
 
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
@@ -83,6 +88,8 @@ pub enum MemberType {
     FeatureSet,
     /// A specific feature (tool, prompt, or resource)
     Feature,
+    /// Every feature matching a rule expression (see [`FeatureRule`](super::FeatureRule))
+    Rule,
 }
 
 impl MemberType {
@@ -90,6 +97,7 @@ impl MemberType {
         match self {
             Self::FeatureSet => "feature_set",
             Self::Feature => "feature",
+            Self::Rule => "rule",
         }
     }
 
@@ -97,6 +105,7 @@ impl MemberType {
         match s {
             "feature_set" => Some(Self::FeatureSet),
             "feature" => Some(Self::Feature),
+            "rule" => Some(Self::Rule),
             _ => None,
         }
     }
@@ -111,7 +120,8 @@ pub struct FeatureSetMember {
     pub feature_set_id: String,
     /// Type of member
     pub member_type: MemberType,
-    /// ID of the member (feature ID for Feature, featureset ID for FeatureSet)
+    /// ID of the member (feature ID for Feature, featureset ID for FeatureSet,
+    /// rule expression for Rule)
     pub member_id: String,
     /// Include or exclude
     pub mode: MemberMode,
@@ -150,6 +160,17 @@ impl FeatureSetMember {
             mode: MemberMode::Include,
         }
     }
+
+    /// Create a new rule member (see [`FeatureRule`](super::FeatureRule) for the syntax)
+    pub fn rule(feature_set_id: &str, expression: &str, mode: MemberMode) -> Self {
+        Self {
+            id: Uuid::new_v4().to_string(),
+            feature_set_id: feature_set_id.to_string(),
+            member_type: MemberType::Rule,
+            member_id: expression.trim().to_string(),
+            mode,
+        }
+    }
 }
 
 /// FeatureSet defines a bundle of permissions using explicit feature selection.
@@ -174,7 +195,7 @@ pub struct FeatureSet {
     /// Icon (emoji or URL)
     pub icon: Option<String>,
 
-    /// The space this featureset belongs to (None = global/builtin)
+    /// The space this featureset belongs to (None = global)
     pub space_id: Option<String>,
 
     /// The type of featureset
@@ -191,6 +212,10 @@ pub struct FeatureSet {
     #[serde(default)]
     pub is_deleted: bool,
 
+    /// Only admit read-only features (tools marked `readOnlyHint`)
+    #[serde(default)]
+    pub read_only: bool,
+
     /// Creation timestamp
     pub created_at: DateTime<Utc>,
 
@@ -216,12 +241,26 @@ impl FeatureSet {
             server_id: None,
             is_builtin: false,
             is_deleted: false,
+            read_only: false,
             created_at: now,
             updated_at: now,
             members: vec![],
         }
     }
 
+    /// Create a new global custom FeatureSet, usable in every space
+    pub fn new_global(name: impl Into<String>) -> Self {
+        Self {
+            space_id: None,
+            ..Self::new_custom(name, "")
+        }
+    }
+
+    /// Whether this featureset is global rather than owned by a space
+    pub fn is_global(&self) -> bool {
+        self.space_id.is_none()
+    }
+
     /// Create the "All Features" featureset for a space
     pub fn new_all(space_id: impl Into<String>) -> Self {
         let space_id = space_id.into();
@@ -238,6 +277,7 @@ impl FeatureSet {
             server_id: None,
             is_builtin: true,
             is_deleted: false,
+            read_only: false,
             created_at: now,
             updated_at: now,
             members: vec![],
@@ -260,6 +300,7 @@ impl FeatureSet {
             server_id: None,
             is_builtin: true,
             is_deleted: false,
+            read_only: false,
             created_at: now,
             updated_at: now,
             members: vec![],
@@ -286,6 +327,7 @@ impl FeatureSet {
             server_id: Some(server_id),
             is_builtin: true,
             is_deleted: false,
+            read_only: false,
             created_at: now,
             updated_at: now,
             members: vec![],
@@ -304,6 +346,12 @@ impl FeatureSet {
         self
     }
 
+    /// Only admit read-only features
+    pub fn with_read_only(mut self, read_only: bool) -> Self {
+        self.read_only = read_only;
+        self
+    }
+
     /// Check if this featureset is the "All" type for a space
     pub fn is_all_type(&self) -> bool {
         self.feature_set_type == FeatureSetType::All
@@ -430,6 +478,7 @@ mod tests {
             Some(MemberType::FeatureSet)
         );
         assert_eq!(MemberType::parse("feature"), Some(MemberType::Feature));
+        assert_eq!(MemberType::parse("rule"), Some(MemberType::Rule));
         assert_eq!(MemberType::parse("invalid"), None);
     }
 
@@ -437,6 +486,7 @@ mod tests {
     fn test_member_type_as_str() {
         assert_eq!(MemberType::FeatureSet.as_str(), "feature_set");
         assert_eq!(MemberType::Feature.as_str(), "feature");
+        assert_eq!(MemberType::Rule.as_str(), "rule");
     }
 
     // Member construction tests
@@ -482,4 +532,13 @@ mod tests {
         assert_eq!(fs.description, Some("Tools for testing".to_string()));
         assert_eq!(fs.space_id, Some("space".to_string()));
     }
+
+    #[test]
+    fn test_new_global() {
+        let fs = FeatureSet::new_global("No deletes");
+        assert!(fs.is_global());
+        assert_eq!(fs.feature_set_type, FeatureSetType::Custom);
+        assert!(!fs.is_builtin);
+        assert!(!FeatureSet::new_custom("Test", "space").is_global());
+    }
 }
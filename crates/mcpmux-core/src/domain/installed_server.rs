@@ -1,12 +1,13 @@
 //! InstalledServer entity - per-space server installation
 
+use anyhow::{bail, Result};
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::path::PathBuf;
 use uuid::Uuid;
 
-use super::ServerDefinition;
+use super::{referenced_secrets, ServerDefinition};
 
 /// Tracks how a server was installed (for sync/cleanup decisions)
 #[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
@@ -22,6 +23,60 @@ pub enum InstallationSource {
     },
     /// Manually entered via "Add Server" UI (not from any file)
     ManualEntry,
+    /// Imported from another client's MCP config (Cursor, VS Code, ...)
+    Imported {
+        /// Client the server was imported from
+        client: String,
+    },
+}
+
+/// Limit on a server's in-flight requests
+///
+/// Tool calls, prompt fetches and resource reads beyond
+/// `max_concurrent_requests` wait in a FIFO queue of up to
+/// `max_queued_requests`, and fail after `queue_timeout_secs`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ConcurrencyLimit {
+    /// Requests sent to the server at once
+    pub max_concurrent_requests: u32,
+
+    /// Requests allowed to wait for a slot (further requests fail immediately)
+    #[serde(default = "ConcurrencyLimit::default_max_queued_requests")]
+    pub max_queued_requests: u32,
+
+    /// How long a request waits for a slot before failing
+    #[serde(default = "ConcurrencyLimit::default_queue_timeout_secs")]
+    pub queue_timeout_secs: u64,
+}
+
+impl ConcurrencyLimit {
+    fn default_max_queued_requests() -> u32 {
+        32
+    }
+
+    fn default_queue_timeout_secs() -> u64 {
+        30
+    }
+
+    /// Limit to `max_concurrent_requests` with the default queue settings
+    pub fn new(max_concurrent_requests: u32) -> Self {
+        Self {
+            max_concurrent_requests,
+            max_queued_requests: Self::default_max_queued_requests(),
+            queue_timeout_secs: Self::default_queue_timeout_secs(),
+        }
+    }
+
+    /// Check the limit values are usable
+    pub fn validate(&self) -> Result<()> {
+        if self.max_concurrent_requests == 0 {
+            bail!("Max concurrent requests must be greater than zero");
+        }
+        if self.queue_timeout_secs == 0 {
+            bail!("Queue timeout must be greater than zero");
+        }
+        Ok(())
+    }
 }
 
 /// Installed server - represents a server installation in a space
@@ -70,10 +125,31 @@ pub struct InstalledServer {
     #[serde(default)]
     pub args_append: Vec<String>,
 
+    /// Working directory for stdio transports, replacing the definition's
+    #[serde(default)]
+    pub cwd_override: Option<String>,
+
+    /// `.env` file loaded for stdio transports, replacing the definition's
+    #[serde(default)]
+    pub env_file_override: Option<String>,
+
     /// Extra HTTP headers for HTTP transports (e.g., custom auth headers)
     #[serde(default)]
     pub extra_headers: HashMap<String, String>,
 
+    /// Launch with the read-only arguments and environment the server declares
+    #[serde(default)]
+    pub read_only: bool,
+
+    /// Limit on in-flight requests to the server (`None` = unlimited)
+    #[serde(default)]
+    pub concurrency_limit: Option<ConcurrencyLimit>,
+
+    /// Send tool calls without checking arguments against the tool's input
+    /// schema (for servers whose schemas are known to be wrong)
+    #[serde(default)]
+    pub skip_argument_validation: bool,
+
     /// Whether OAuth authentication has been completed
     pub oauth_connected: bool,
 
@@ -104,7 +180,12 @@ impl InstalledServer {
             enabled: false, // Disabled by default - user must explicitly enable
             env_overrides: HashMap::new(),
             args_append: Vec::new(),
+            cwd_override: None,
+            env_file_override: None,
             extra_headers: HashMap::new(),
+            read_only: false,
+            concurrency_limit: None,
+            skip_argument_validation: false,
             oauth_connected: false,
             source: InstallationSource::default(),
             created_at: now,
@@ -178,6 +259,13 @@ impl InstalledServer {
             _ => None,
         }
     }
+
+    /// Whether any input value references the space secret `name`
+    pub fn references_secret(&self, name: &str) -> bool {
+        self.input_values
+            .values()
+            .any(|value| referenced_secrets(value).any(|n| n == name))
+    }
 }
 
 #[cfg(test)]
@@ -450,4 +538,19 @@ mod tests {
         assert_eq!(deserialized.args_append.len(), 100);
         assert_eq!(deserialized.args_append[99], "--arg-99");
     }
+
+    #[test]
+    fn test_concurrency_limit_defaults() {
+        let limit: ConcurrencyLimit =
+            serde_json::from_str(r#"{"max_concurrent_requests": 1}"#).expect("deserialize");
+        assert_eq!(limit, ConcurrencyLimit::new(1));
+        assert_eq!(limit.max_queued_requests, 32);
+        assert_eq!(limit.queue_timeout_secs, 30);
+        assert!(limit.validate().is_ok());
+
+        assert!(ConcurrencyLimit::new(0).validate().is_err());
+        assert!(InstalledServer::new("space_default", "test-server")
+            .concurrency_limit
+            .is_none());
+    }
 }
@@ -0,0 +1,211 @@
+//! Variable interpolation for user space configs
+//!
+//! Servers defined in a space's JSON file can use these variables in their
+//! command, args, env, URL and headers, alongside `${input:NAME}`:
+//!
+//! - `${env:VAR}` - environment variable of the McpMux process
+//! - `${file:path}` - file contents without the trailing newline; relative
+//!   paths resolve against the config file's folder, `~/` against home
+//! - `${workspaceFolder}` - folder containing the config file
+//! - `${userHome}` - the user's home directory
+//! - `${space:id}` - ID of the space
+//!
+//! `env` and `file` take a default for when the variable is unset or empty
+//! (or the file is missing): `${env:LOG_LEVEL:-info}`.
+//!
+//! Anything else, including `${input:NAME}`, is left as is.
+
+use lazy_static::lazy_static;
+use regex::{Captures, Regex};
+use std::path::{Path, PathBuf};
+
+lazy_static! {
+    static ref VARIABLE_REGEX: Regex =
+        Regex::new(r"\$\{(env|file|space|workspaceFolder|userHome)(?::([^}]*?))?(?::-([^}]*))?\}")
+            .unwrap();
+}
+
+/// A variable that could not be resolved
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error("{variable}: {reason}")]
+pub struct UnresolvedVariable {
+    /// The variable as written, e.g. `${env:GITHUB_TOKEN}`
+    pub variable: String,
+    pub reason: String,
+}
+
+/// What variables resolve against
+#[derive(Debug, Clone)]
+pub struct VariableContext {
+    space_id: String,
+    config_file: Option<PathBuf>,
+}
+
+impl VariableContext {
+    pub fn new(space_id: impl Into<String>) -> Self {
+        Self {
+            space_id: space_id.into(),
+            config_file: None,
+        }
+    }
+
+    /// The config file the server is defined in
+    pub fn with_config_file(mut self, path: impl Into<PathBuf>) -> Self {
+        self.config_file = Some(path.into());
+        self
+    }
+
+    /// Replace every variable in `template`, failing on the first that
+    /// can't be resolved
+    pub fn interpolate(&self, template: &str) -> Result<String, UnresolvedVariable> {
+        let mut error = None;
+        let result =
+            VARIABLE_REGEX.replace_all(template, |caps: &Captures| match self.resolve(caps) {
+                Ok(value) => value,
+                Err(e) => {
+                    error.get_or_insert(e);
+                    String::new()
+                }
+            });
+        match error {
+            Some(e) => Err(e),
+            None => Ok(result.into_owned()),
+        }
+    }
+
+    /// Every variable in `template` that can't be resolved
+    pub fn unresolved(&self, template: &str) -> Vec<UnresolvedVariable> {
+        VARIABLE_REGEX
+            .captures_iter(template)
+            .filter_map(|caps| self.resolve(&caps).err())
+            .collect()
+    }
+
+    /// Resolve a path the way `${file:...}` does: `~/` against home and
+    /// relative paths against the config file's folder
+    pub fn resolve_path(&self, path: &str) -> PathBuf {
+        self.file_path(path).unwrap_or_else(|_| PathBuf::from(path))
+    }
+
+    fn resolve(&self, caps: &Captures) -> Result<String, UnresolvedVariable> {
+        let argument = caps.get(2).map(|m| m.as_str());
+        let default = caps.get(3).map(|m| m.as_str());
+        let fail = |reason: String| UnresolvedVariable {
+            variable: caps[0].to_string(),
+            reason,
+        };
+
+        match (&caps[1], argument) {
+            ("env", Some(name)) => match std::env::var(name) {
+                Ok(value) if !value.is_empty() => Ok(value),
+                _ => default
+                    .map(str::to_string)
+                    .ok_or_else(|| fail(format!("environment variable {} is not set", name))),
+            },
+            ("file", Some(path)) => {
+                let path = self.file_path(path).map_err(fail)?;
+                match std::fs::read_to_string(&path) {
+                    Ok(content) => Ok(content.trim_end_matches(['\r', '\n']).to_string()),
+                    Err(_) if default.is_some() => Ok(default.unwrap_or_default().to_string()),
+                    Err(e) => Err(fail(format!("failed to read {}: {}", path.display(), e))),
+                }
+            }
+            ("space", Some("id")) => Ok(self.space_id.clone()),
+            ("space", Some(other)) => Err(fail(format!("unknown space variable '{}'", other))),
+            ("workspaceFolder", None) => self
+                .config_dir()
+                .map(|dir| dir.to_string_lossy().into_owned())
+                .ok_or_else(|| fail("the server is not defined in a config file".to_string())),
+            ("userHome", None) => dirs::home_dir()
+                .map(|dir| dir.to_string_lossy().into_owned())
+                .ok_or_else(|| fail("no home directory".to_string())),
+            _ => Err(fail("malformed variable".to_string())),
+        }
+    }
+
+    fn config_dir(&self) -> Option<&Path> {
+        self.config_file.as_deref().and_then(Path::parent)
+    }
+
+    fn file_path(&self, path: &str) -> Result<PathBuf, String> {
+        if let Some(rest) = path.strip_prefix("~/") {
+            return dirs::home_dir()
+                .map(|home| home.join(rest))
+                .ok_or_else(|| "no home directory".to_string());
+        }
+        let path = Path::new(path);
+        if path.is_absolute() {
+            return Ok(path.to_path_buf());
+        }
+        self.config_dir()
+            .map(|dir| dir.join(path))
+            .ok_or_else(|| "relative path outside a config file".to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_env_with_default() {
+        std::env::set_var("MCPMUX_INTERPOLATION_TEST", "from-env");
+        let ctx = VariableContext::new("space-1");
+
+        assert_eq!(
+            ctx.interpolate("--level=${env:MCPMUX_INTERPOLATION_TEST}")
+                .unwrap(),
+            "--level=from-env"
+        );
+        assert_eq!(
+            ctx.interpolate("${env:MCPMUX_INTERPOLATION_UNSET:-info}")
+                .unwrap(),
+            "info"
+        );
+
+        let err = ctx
+            .interpolate("${env:MCPMUX_INTERPOLATION_UNSET}")
+            .unwrap_err();
+        assert_eq!(err.variable, "${env:MCPMUX_INTERPOLATION_UNSET}");
+        assert!(err.reason.contains("not set"));
+    }
+
+    #[test]
+    fn test_file_relative_to_config() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("token.txt"), "abc123\n").unwrap();
+        let ctx = VariableContext::new("space-1").with_config_file(dir.path().join("space.json"));
+
+        assert_eq!(ctx.interpolate("${file:token.txt}").unwrap(), "abc123");
+        assert_eq!(
+            ctx.interpolate("${file:missing.txt:-none}").unwrap(),
+            "none"
+        );
+        assert!(ctx.interpolate("${file:missing.txt}").is_err());
+        assert_eq!(
+            ctx.interpolate("${workspaceFolder}/data").unwrap(),
+            format!("{}/data", dir.path().display())
+        );
+    }
+
+    #[test]
+    fn test_space_and_untouched_placeholders() {
+        let ctx = VariableContext::new("space-1");
+
+        assert_eq!(
+            ctx.interpolate("${space:id}/${input:TOKEN}/${HOME}")
+                .unwrap(),
+            "space-1/${input:TOKEN}/${HOME}"
+        );
+        assert!(ctx.interpolate("${space:name}").is_err());
+        assert!(ctx.interpolate("${workspaceFolder}").is_err());
+    }
+
+    #[test]
+    fn test_unresolved_reports_every_variable() {
+        let ctx = VariableContext::new("space-1");
+        let unresolved = ctx
+            .unresolved("${env:MCPMUX_INTERPOLATION_UNSET_A} ${env:MCPMUX_INTERPOLATION_UNSET_B}");
+        assert_eq!(unresolved.len(), 2);
+    }
+}
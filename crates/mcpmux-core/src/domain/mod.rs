@@ -5,29 +5,45 @@
 //! - Value Objects (ConnectionStatus, FeatureType, etc.)
 //! - Domain Events (DomainEvent enum for event-driven architecture)
 
+mod argument_policy;
 mod client;
+mod compat_profile;
 pub mod config;
 mod credential;
 mod event;
+mod feature_override;
+mod feature_rule;
 mod feature_set;
 mod installed_server;
+mod interpolation;
 mod outbound_oauth_registration;
+mod rate_limit;
 mod server;
 mod server_feature;
 mod server_log;
 mod space;
+mod space_secret;
+mod tool_approval;
 
 // Export event types first (ConnectionStatus is defined here)
 pub use event::{ConnectionStatus, DiscoveredCapabilities, DomainEvent, DomainEventEnvelope};
 
 // Export entities (installed_server re-exports ConnectionStatus from event)
+pub use argument_policy::{ArgumentPolicy, ArgumentPolicyViolation, ToolArgumentPolicy};
 pub use client::*;
+pub use compat_profile::ClientCompatProfile;
 pub use config::*;
 pub use credential::*;
+pub use feature_override::{apply_feature_overrides, FeatureOverride};
+pub use feature_rule::FeatureRule;
 pub use feature_set::*;
-pub use installed_server::{InstallationSource, InstalledServer};
+pub use installed_server::{ConcurrencyLimit, InstallationSource, InstalledServer};
+pub use interpolation::{UnresolvedVariable, VariableContext};
 pub use outbound_oauth_registration::*;
+pub use rate_limit::{ClientRateLimit, RateLimitExceeded, RateLimitUsage};
 pub use server::*;
 pub use server_feature::*;
 pub use server_log::*;
 pub use space::*;
+pub use space_secret::{interpolate_secrets, referenced_secrets, SpaceSecret};
+pub use tool_approval::{ApprovalPolicy, ApprovalTarget, ToolApprovalRule};
@@ -0,0 +1,252 @@
+//! Tool call rate limits and daily quotas
+//!
+//! A limit applies to one inbound client's calls to a server, or to a single
+//! tool on that server. `per_minute` refills a token bucket holding up to
+//! `burst` calls; `daily_quota` caps calls per UTC day. Usage counters are
+//! kept with the limit so they survive restarts.
+
+use anyhow::{bail, Result};
+use chrono::{DateTime, Duration, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+
+/// A tool call refused by a rate limit or quota
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error("Rate limit exceeded for {target}: {reason}. Retry after {retry_after_secs}s")]
+pub struct RateLimitExceeded {
+    /// `server_id` or `server_id/tool_name`
+    pub target: String,
+    pub reason: String,
+    pub retry_after_secs: u64,
+}
+
+/// Persisted counters for a [`ClientRateLimit`]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RateLimitUsage {
+    /// Calls left in the token bucket at `updated_at`
+    pub tokens: f64,
+    pub updated_at: DateTime<Utc>,
+    /// UTC day `quota_used` counts towards
+    pub quota_day: NaiveDate,
+    pub quota_used: u32,
+}
+
+/// A rate limit and/or daily quota on a client's calls to a server or tool
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ClientRateLimit {
+    pub client_id: String,
+    pub server_id: String,
+    /// Backend tool name; `None` limits every tool on the server
+    pub tool_name: Option<String>,
+    /// Calls per minute (token bucket refill rate)
+    pub per_minute: Option<u32>,
+    /// Calls allowed in a burst (defaults to `per_minute`)
+    pub burst: Option<u32>,
+    /// Calls per UTC day
+    pub daily_quota: Option<u32>,
+    /// Counters as of the last call (`None` before the first call)
+    #[serde(default)]
+    pub usage: Option<RateLimitUsage>,
+}
+
+impl ClientRateLimit {
+    /// Check the limit values are usable
+    pub fn validate(&self) -> Result<()> {
+        if self.per_minute.is_none() && self.daily_quota.is_none() {
+            bail!("A rate limit needs calls per minute, a daily quota, or both");
+        }
+        if self.burst.is_some() && self.per_minute.is_none() {
+            bail!("Burst requires calls per minute");
+        }
+        if [self.per_minute, self.burst, self.daily_quota].contains(&Some(0)) {
+            bail!("Limits must be greater than zero");
+        }
+        Ok(())
+    }
+
+    /// `server_id` or `server_id/tool_name`, for messages
+    pub fn target(&self) -> String {
+        match &self.tool_name {
+            Some(tool) => format!("{}/{}", self.server_id, tool),
+            None => self.server_id.clone(),
+        }
+    }
+
+    /// Whether the limit covers a call to `tool_name` on `server_id`
+    pub fn applies_to(&self, server_id: &str, tool_name: &str) -> bool {
+        self.server_id == server_id && self.tool_name.as_deref().is_none_or(|t| t == tool_name)
+    }
+
+    fn capacity(&self) -> Option<f64> {
+        self.burst.or(self.per_minute).map(f64::from)
+    }
+
+    /// Counters as of `now`: the bucket refilled and the quota reset on a new day
+    pub fn usage_at(&self, now: DateTime<Utc>) -> RateLimitUsage {
+        let today = now.date_naive();
+        let Some(usage) = &self.usage else {
+            return RateLimitUsage {
+                tokens: self.capacity().unwrap_or(0.0),
+                updated_at: now,
+                quota_day: today,
+                quota_used: 0,
+            };
+        };
+
+        let tokens = match (self.capacity(), self.per_minute) {
+            (Some(capacity), Some(per_minute)) => {
+                let elapsed = (now - usage.updated_at).num_milliseconds().max(0) as f64 / 1000.0;
+                (usage.tokens + elapsed * f64::from(per_minute) / 60.0).min(capacity)
+            }
+            _ => 0.0,
+        };
+        RateLimitUsage {
+            tokens,
+            updated_at: now,
+            quota_day: today,
+            quota_used: if usage.quota_day == today {
+                usage.quota_used
+            } else {
+                0
+            },
+        }
+    }
+
+    /// Calls left in the token bucket at `now` (`None` without a per-minute limit)
+    pub fn available_calls(&self, now: DateTime<Utc>) -> Option<u32> {
+        self.per_minute
+            .map(|_| self.usage_at(now).tokens.floor() as u32)
+    }
+
+    /// Calls left in today's quota at `now` (`None` without a quota)
+    pub fn quota_remaining(&self, now: DateTime<Utc>) -> Option<u32> {
+        self.daily_quota
+            .map(|quota| quota.saturating_sub(self.usage_at(now).quota_used))
+    }
+
+    /// Take one call from the limit.
+    ///
+    /// Returns the updated counters to persist, or why the call is refused
+    /// and when it can be retried.
+    pub fn try_acquire(
+        &self,
+        now: DateTime<Utc>,
+    ) -> std::result::Result<RateLimitUsage, RateLimitExceeded> {
+        let mut usage = self.usage_at(now);
+
+        if let Some(quota) = self.daily_quota {
+            if usage.quota_used >= quota {
+                let midnight = (usage.quota_day + Duration::days(1))
+                    .and_hms_opt(0, 0, 0)
+                    .map(|t| t.and_utc())
+                    .unwrap_or(now);
+                return Err(RateLimitExceeded {
+                    target: self.target(),
+                    reason: format!("daily quota of {} calls used", quota),
+                    retry_after_secs: (midnight - now).num_seconds().max(1) as u64,
+                });
+            }
+        }
+
+        if let Some(per_minute) = self.per_minute {
+            if usage.tokens < 1.0 {
+                let wait = (1.0 - usage.tokens) * 60.0 / f64::from(per_minute);
+                return Err(RateLimitExceeded {
+                    target: self.target(),
+                    reason: format!("limit of {} calls per minute reached", per_minute),
+                    retry_after_secs: wait.ceil().max(1.0) as u64,
+                });
+            }
+            usage.tokens -= 1.0;
+        }
+
+        if self.daily_quota.is_some() {
+            usage.quota_used += 1;
+        }
+        Ok(usage)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn limit(
+        per_minute: Option<u32>,
+        burst: Option<u32>,
+        daily_quota: Option<u32>,
+    ) -> ClientRateLimit {
+        ClientRateLimit {
+            client_id: "client".to_string(),
+            server_id: "github".to_string(),
+            tool_name: None,
+            per_minute,
+            burst,
+            daily_quota,
+            usage: None,
+        }
+    }
+
+    fn at(s: &str) -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339(s).unwrap().with_timezone(&Utc)
+    }
+
+    #[test]
+    fn test_token_bucket_burst_and_refill() {
+        let mut limit = limit(Some(6), Some(2), None);
+        let now = at("2026-01-01T12:00:00Z");
+
+        // Burst of two, then refused until a token refills (10s at 6/min)
+        limit.usage = Some(limit.try_acquire(now).unwrap());
+        limit.usage = Some(limit.try_acquire(now).unwrap());
+        let err = limit.try_acquire(now).unwrap_err();
+        assert_eq!(err.retry_after_secs, 10);
+        assert!(err.to_string().contains("github"));
+
+        let later = now + Duration::seconds(10);
+        assert_eq!(limit.available_calls(later), Some(1));
+        assert!(limit.try_acquire(later).is_ok());
+
+        // The bucket never holds more than the burst
+        assert_eq!(limit.available_calls(now + Duration::hours(1)), Some(2));
+    }
+
+    #[test]
+    fn test_daily_quota_resets_at_utc_midnight() {
+        let mut limit = limit(None, None, Some(2));
+        let now = at("2026-01-01T23:00:00Z");
+
+        limit.usage = Some(limit.try_acquire(now).unwrap());
+        limit.usage = Some(limit.try_acquire(now).unwrap());
+        assert_eq!(limit.quota_remaining(now), Some(0));
+        let err = limit.try_acquire(now).unwrap_err();
+        assert_eq!(err.retry_after_secs, 3600);
+
+        let tomorrow = at("2026-01-02T00:00:01Z");
+        assert_eq!(limit.quota_remaining(tomorrow), Some(2));
+        assert!(limit.try_acquire(tomorrow).is_ok());
+    }
+
+    #[test]
+    fn test_applies_to_server_or_tool() {
+        let server = limit(Some(1), None, None);
+        assert!(server.applies_to("github", "create_issue"));
+        assert!(!server.applies_to("gitlab", "create_issue"));
+
+        let tool = ClientRateLimit {
+            tool_name: Some("create_issue".to_string()),
+            ..server
+        };
+        assert!(tool.applies_to("github", "create_issue"));
+        assert!(!tool.applies_to("github", "list_issues"));
+        assert_eq!(tool.target(), "github/create_issue");
+    }
+
+    #[test]
+    fn test_validate() {
+        assert!(limit(Some(10), Some(20), Some(100)).validate().is_ok());
+        assert!(limit(None, None, Some(100)).validate().is_ok());
+        assert!(limit(None, None, None).validate().is_err());
+        assert!(limit(None, Some(5), Some(100)).validate().is_err());
+        assert!(limit(Some(0), None, None).validate().is_err());
+    }
+}
@@ -73,6 +73,11 @@ impl ServerDefinition {
     pub fn requires_oauth(&self) -> bool {
         matches!(self.auth, Some(AuthConfig::Oauth))
     }
+
+    /// Whether the server declares a read-only launch mode
+    pub fn supports_read_only_mode(&self) -> bool {
+        self.capabilities.as_ref().is_some_and(|c| c.read_only_mode)
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
@@ -88,6 +93,8 @@ pub enum ServerSource {
     Bundled,
     /// Loaded from a remote or custom registry (API, NPM, etc.)
     Registry { url: String, name: String },
+    /// Read from another client's config file (`ImportClient::as_str`)
+    Imported { client: String },
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -106,6 +113,12 @@ pub enum TransportConfig {
         args: Vec<String>,
         #[serde(default)]
         env: HashMap<String, String>,
+        /// Directory to launch the server from
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        cwd: Option<String>,
+        /// `.env` file loaded into the server's environment; `env` wins on conflicts
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        env_file: Option<String>,
         #[serde(default)]
         metadata: TransportMetadata,
     },
@@ -231,6 +244,12 @@ pub struct Capabilities {
     pub prompts: bool,
     #[serde(default)]
     pub read_only_mode: bool,
+    /// Extra stdio arguments that put the server in read-only mode
+    #[serde(default)]
+    pub read_only_args: Vec<String>,
+    /// Extra environment that puts the server in read-only mode
+    #[serde(default)]
+    pub read_only_env: HashMap<String, String>,
 }
 
 /// Sponsorship information for commercial listings
@@ -27,6 +27,10 @@ pub struct Space {
     /// Sort order for display
     pub sort_order: i32,
 
+    /// Only expose read-only features to clients, whatever their grants
+    #[serde(default)]
+    pub read_only: bool,
+
     /// Creation timestamp
     pub created_at: DateTime<Utc>,
 
@@ -45,6 +49,7 @@ impl Space {
             description: None,
             is_default: false,
             sort_order: 0,
+            read_only: false,
             created_at: now,
             updated_at: now,
         }
@@ -0,0 +1,136 @@
+//! Space secrets - named secrets shared by a space's servers
+//!
+//! A token used by several servers is stored once per space and referenced
+//! from their input values as `${secret:github_token}`. Rotating the secret
+//! updates every server that references it.
+
+use anyhow::{bail, Result};
+use chrono::{DateTime, Utc};
+use lazy_static::lazy_static;
+use regex::{Captures, Regex};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use uuid::Uuid;
+
+use super::UnresolvedVariable;
+
+lazy_static! {
+    static ref SECRET_REGEX: Regex = Regex::new(r"\$\{secret:([^}]*)\}").unwrap();
+}
+
+/// Longest secret name accepted
+const MAX_NAME_LEN: usize = 64;
+
+/// A named secret in a space
+///
+/// The `value` is plaintext at the domain level; the storage layer encrypts it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SpaceSecret {
+    pub space_id: Uuid,
+    pub name: String,
+    #[serde(skip_serializing)]
+    pub value: String,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl SpaceSecret {
+    pub fn new(space_id: Uuid, name: impl Into<String>, value: impl Into<String>) -> Self {
+        let now = Utc::now();
+        Self {
+            space_id,
+            name: name.into(),
+            value: value.into(),
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    /// Check a secret name: letters, digits, `_`, `-` and `.`
+    pub fn validate_name(name: &str) -> Result<()> {
+        if name.is_empty() {
+            bail!("Secret name is empty");
+        }
+        if name.len() > MAX_NAME_LEN {
+            bail!("Secret name is longer than {} characters", MAX_NAME_LEN);
+        }
+        if !name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | '.'))
+        {
+            bail!(
+                "Secret name '{}' may only contain letters, digits, '_', '-' and '.'",
+                name
+            );
+        }
+        Ok(())
+    }
+}
+
+/// Names of the secrets `template` references
+pub fn referenced_secrets(template: &str) -> impl Iterator<Item = &str> {
+    SECRET_REGEX
+        .captures_iter(template)
+        .filter_map(|caps| caps.get(1).map(|m| m.as_str()))
+}
+
+/// Replace every `${secret:name}` in `template` with the secret's value,
+/// failing on the first that isn't set
+pub fn interpolate_secrets(
+    template: &str,
+    secrets: &HashMap<String, String>,
+) -> Result<String, UnresolvedVariable> {
+    let mut error = None;
+    let result =
+        SECRET_REGEX.replace_all(template, |caps: &Captures| match secrets.get(&caps[1]) {
+            Some(value) => value.clone(),
+            None => {
+                error.get_or_insert(UnresolvedVariable {
+                    variable: caps[0].to_string(),
+                    reason: format!("secret '{}' is not set in this space", &caps[1]),
+                });
+                String::new()
+            }
+        });
+    match error {
+        Some(e) => Err(e),
+        None => Ok(result.into_owned()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_interpolate_secrets() {
+        let secrets = HashMap::from([("github_token".to_string(), "ghp_abc".to_string())]);
+
+        assert_eq!(
+            interpolate_secrets("Bearer ${secret:github_token}", &secrets).unwrap(),
+            "Bearer ghp_abc"
+        );
+        assert_eq!(
+            interpolate_secrets("${input:TOKEN}", &secrets).unwrap(),
+            "${input:TOKEN}"
+        );
+
+        let err = interpolate_secrets("${secret:missing}", &secrets).unwrap_err();
+        assert_eq!(err.variable, "${secret:missing}");
+        assert!(err.reason.contains("not set"));
+    }
+
+    #[test]
+    fn test_referenced_secrets() {
+        let names: Vec<&str> = referenced_secrets("${secret:a}:${secret:b.c}").collect();
+        assert_eq!(names, vec!["a", "b.c"]);
+    }
+
+    #[test]
+    fn test_validate_name() {
+        assert!(SpaceSecret::validate_name("github_token").is_ok());
+        assert!(SpaceSecret::validate_name("").is_err());
+        assert!(SpaceSecret::validate_name("has space").is_err());
+        assert!(SpaceSecret::validate_name("a}b").is_err());
+    }
+}
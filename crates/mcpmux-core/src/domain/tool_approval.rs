@@ -0,0 +1,144 @@
+//! Tool call approval policies
+//!
+//! A policy decides whether a tool call runs immediately (`allow`), is held
+//! until a human approves it (`ask`), or is refused (`deny`). Policies are set
+//! per tool or per feature set within a space; tools the server annotates as
+//! destructive fall back to a configurable default.
+
+use serde::{Deserialize, Serialize};
+
+/// What happens when a client calls a tool
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize, Default,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum ApprovalPolicy {
+    /// Run the call immediately
+    #[default]
+    Allow,
+    /// Hold the call until a user approves it
+    Ask,
+    /// Refuse the call
+    Deny,
+}
+
+impl ApprovalPolicy {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Allow => "allow",
+            Self::Ask => "ask",
+            Self::Deny => "deny",
+        }
+    }
+
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.to_lowercase().as_str() {
+            "allow" => Some(Self::Allow),
+            "ask" => Some(Self::Ask),
+            "deny" => Some(Self::Deny),
+            _ => None,
+        }
+    }
+
+    /// Effective policy for a tool.
+    ///
+    /// A tool rule wins outright. Otherwise the most restrictive rule among
+    /// the feature sets containing the tool applies. Without any rule,
+    /// destructive tools get `destructive_default` and everything else is allowed.
+    pub fn resolve(
+        tool_rule: Option<ApprovalPolicy>,
+        feature_set_rules: impl IntoIterator<Item = ApprovalPolicy>,
+        is_destructive: bool,
+        destructive_default: ApprovalPolicy,
+    ) -> ApprovalPolicy {
+        if let Some(policy) = tool_rule {
+            return policy;
+        }
+        if let Some(policy) = feature_set_rules.into_iter().max() {
+            return policy;
+        }
+        if is_destructive {
+            destructive_default
+        } else {
+            ApprovalPolicy::Allow
+        }
+    }
+}
+
+/// What a [`ToolApprovalRule`] applies to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ApprovalTarget {
+    /// A single tool (target is the server feature ID)
+    Tool,
+    /// Every tool in a feature set (target is the feature set ID)
+    FeatureSet,
+}
+
+impl ApprovalTarget {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Tool => "tool",
+            Self::FeatureSet => "feature_set",
+        }
+    }
+
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "tool" => Some(Self::Tool),
+            "feature_set" => Some(Self::FeatureSet),
+            _ => None,
+        }
+    }
+}
+
+/// An approval policy for a tool or feature set in a space
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ToolApprovalRule {
+    pub space_id: String,
+    pub target_type: ApprovalTarget,
+    pub target: String,
+    pub policy: ApprovalPolicy,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ApprovalPolicy::*;
+
+    #[test]
+    fn test_resolve_precedence() {
+        // Tool rule beats feature set rules
+        assert_eq!(
+            ApprovalPolicy::resolve(Some(Allow), [Deny], true, Ask),
+            Allow
+        );
+
+        // Most restrictive feature set rule wins
+        assert_eq!(
+            ApprovalPolicy::resolve(None, [Allow, Ask], false, Allow),
+            Ask
+        );
+        assert_eq!(
+            ApprovalPolicy::resolve(None, [Ask, Deny], false, Allow),
+            Deny
+        );
+
+        // Annotation default only applies without rules
+        assert_eq!(ApprovalPolicy::resolve(None, [], true, Ask), Ask);
+        assert_eq!(ApprovalPolicy::resolve(None, [], false, Ask), Allow);
+        assert_eq!(ApprovalPolicy::resolve(None, [Allow], true, Ask), Allow);
+    }
+
+    #[test]
+    fn test_parse_round_trip() {
+        for policy in [Allow, Ask, Deny] {
+            assert_eq!(ApprovalPolicy::parse(policy.as_str()), Some(policy));
+        }
+        assert_eq!(ApprovalPolicy::parse("maybe"), None);
+        assert_eq!(
+            ApprovalTarget::parse(ApprovalTarget::FeatureSet.as_str()),
+            Some(ApprovalTarget::FeatureSet)
+        );
+    }
+}
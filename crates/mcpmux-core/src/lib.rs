@@ -36,6 +36,11 @@ pub use event_bus::{
 
 use std::path::{Path, PathBuf};
 
+/// Get the directory holding space configuration files (relative to the app data directory)
+pub fn get_spaces_dir(data_dir: &Path) -> PathBuf {
+    data_dir.join("spaces")
+}
+
 /// Get the path to a space's configuration file (relative to a base spaces directory)
 pub fn get_space_config_path(spaces_dir: &Path, space_id: &str) -> PathBuf {
     spaces_dir.join(format!("{}.json", space_id))
@@ -3,12 +3,14 @@
 //! These traits define the interface for data storage without specifying
 //! the implementation (SQLite, in-memory, etc.)
 
+use std::sync::Arc;
+
 use async_trait::async_trait;
 use uuid::Uuid;
 
 use crate::domain::{
-    Client, Credential, CredentialType, FeatureSet, FeatureSetMember, InstalledServer, MemberMode,
-    OutboundOAuthRegistration, ServerFeature, Space,
+    Client, Credential, CredentialType, FeatureDefinitionVersion, FeatureSet, FeatureSetMember,
+    InstalledServer, MemberMode, OutboundOAuthRegistration, ServerFeature, Space, SpaceSecret,
 };
 
 /// Result type for repository operations
@@ -131,6 +133,17 @@ pub trait ServerFeatureRepository: Send + Sync {
 
     /// Delete all features for a server
     async fn delete_for_server(&self, space_id: &str, server_id: &str) -> RepoResult<()>;
+
+    /// Approve a feature's pending definition (promotes it and lifts the quarantine)
+    ///
+    /// Returns the updated feature, or None if the feature doesn't exist.
+    async fn approve_pending_definition(&self, id: &Uuid) -> RepoResult<Option<ServerFeature>>;
+
+    /// Definition history of a feature (oldest first)
+    async fn list_definition_versions(
+        &self,
+        id: &Uuid,
+    ) -> RepoResult<Vec<FeatureDefinitionVersion>>;
 }
 
 /// FeatureSet repository trait
@@ -142,6 +155,9 @@ pub trait FeatureSetRepository: Send + Sync {
     /// Get feature sets for a specific space
     async fn list_by_space(&self, space_id: &str) -> RepoResult<Vec<FeatureSet>>;
 
+    /// Get global feature sets (no space), with members loaded
+    async fn list_global(&self) -> RepoResult<Vec<FeatureSet>>;
+
     /// Get a feature set by ID
     async fn get(&self, id: &str) -> RepoResult<Option<FeatureSet>>;
 
@@ -307,6 +323,24 @@ pub trait CredentialRepository: Send + Sync {
     async fn list_for_space(&self, space_id: &Uuid) -> RepoResult<Vec<Credential>>;
 }
 
+/// Space secret repository trait (local-only, never synced)
+///
+/// One row per (space, name); only the value is encrypted.
+#[async_trait]
+pub trait SpaceSecretRepository: Send + Sync {
+    /// List all secrets of a space, sorted by name
+    async fn list_for_space(&self, space_id: &Uuid) -> RepoResult<Vec<SpaceSecret>>;
+
+    /// Get a secret by name
+    async fn get(&self, space_id: &Uuid, name: &str) -> RepoResult<Option<SpaceSecret>>;
+
+    /// Save a secret (upsert by space_id + name)
+    async fn save(&self, secret: &SpaceSecret) -> RepoResult<()>;
+
+    /// Delete a secret. Returns true if it existed
+    async fn delete(&self, space_id: &Uuid, name: &str) -> RepoResult<bool>;
+}
+
 /// Outbound OAuth Client repository (OUTBOUND)
 /// Stores McpMux's OAuth client registrations WITH backend MCP servers
 /// (McpMux acting as OAuth client connecting TO backends)
@@ -357,3 +391,73 @@ pub trait AppSettingsRepository: Send + Sync {
     /// Get all settings with a given prefix (e.g., "gateway." returns all gateway settings)
     async fn list_by_prefix(&self, prefix: &str) -> RepoResult<Vec<(String, String)>>;
 }
+
+/// Repositories bound to one open transaction
+///
+/// Writes made through these are committed or rolled back together; writes
+/// made through any other repository are not part of the transaction.
+#[derive(Clone)]
+pub struct TransactionRepositories {
+    pub spaces: Arc<dyn SpaceRepository>,
+    pub installed_servers: Arc<dyn InstalledServerRepository>,
+    pub server_features: Arc<dyn ServerFeatureRepository>,
+    pub feature_sets: Arc<dyn FeatureSetRepository>,
+    pub clients: Arc<dyn InboundMcpClientRepository>,
+    pub credentials: Arc<dyn CredentialRepository>,
+    pub space_secrets: Arc<dyn SpaceSecretRepository>,
+    pub oauth: Arc<dyn OutboundOAuthRepository>,
+    pub settings: Arc<dyn AppSettingsRepository>,
+}
+
+/// An open transaction
+#[async_trait]
+pub trait Transaction: Send + Sync {
+    /// Repositories whose writes belong to this transaction
+    fn repositories(&self) -> &TransactionRepositories;
+
+    /// Commit the transaction
+    async fn commit(self: Box<Self>) -> RepoResult<()>;
+
+    /// Roll back the transaction
+    async fn rollback(self: Box<Self>) -> RepoResult<()>;
+}
+
+/// Transaction boundary spanning several repositories
+///
+/// Services that write through more than one repository use this to make
+/// a multi-step operation all-or-nothing. Each transaction runs on its own
+/// connection, so writes made elsewhere while it is open are neither part
+/// of it nor discarded when it rolls back. Implementations serialize units
+/// of work: `begin` waits until any other open transaction has finished.
+#[async_trait]
+pub trait TransactionManager: Send + Sync {
+    /// Start a transaction
+    async fn begin(&self) -> RepoResult<Box<dyn Transaction>>;
+}
+
+/// Run `work` inside a transaction, rolling back if it fails.
+///
+/// `work` gets the repositories bound to the transaction and must make its
+/// writes through them.
+pub async fn in_transaction<T, F, Fut>(
+    transactions: &dyn TransactionManager,
+    work: F,
+) -> RepoResult<T>
+where
+    F: FnOnce(TransactionRepositories) -> Fut,
+    Fut: std::future::Future<Output = RepoResult<T>>,
+{
+    let transaction = transactions.begin().await?;
+    match work(transaction.repositories().clone()).await {
+        Ok(value) => {
+            transaction.commit().await?;
+            Ok(value)
+        }
+        Err(e) => {
+            if let Err(rollback_err) = transaction.rollback().await {
+                tracing::warn!("Failed to roll back transaction: {}", rollback_err);
+            }
+            Err(e)
+        }
+    }
+}
@@ -8,7 +8,7 @@ use serde::{de::DeserializeOwned, Serialize};
 use std::sync::Arc;
 use tracing::{info, warn};
 
-use crate::AppSettingsRepository;
+use crate::{AppSettingsRepository, ApprovalPolicy, ClientCompatProfile};
 
 // =============================================================================
 // Setting Keys (centralized constants)
@@ -22,6 +22,30 @@ pub mod keys {
         pub const PORT: &str = "gateway.port";
         /// Auto-start gateway on app launch (bool)
         pub const AUTO_START: &str = "gateway.auto_start";
+        /// Serve the gateway on TCP host/port (bool)
+        pub const TCP_ENABLED: &str = "gateway.tcp_enabled";
+        /// Unix domain socket path to serve the gateway on (string, unset = disabled)
+        pub const UNIX_SOCKET_PATH: &str = "gateway.unix_socket_path";
+        /// Permission bits for the Unix domain socket (octal string, e.g. "600")
+        pub const UNIX_SOCKET_MODE: &str = "gateway.unix_socket_mode";
+        /// TCP bind address (string, default "127.0.0.1")
+        pub const BIND_HOST: &str = "gateway.bind_host";
+        /// Externally reachable base URL used in OAuth metadata (string)
+        pub const PUBLIC_URL: &str = "gateway.public_url";
+        /// Terminate TLS on the TCP listener (bool)
+        pub const TLS_ENABLED: &str = "gateway.tls_enabled";
+        /// PEM certificate chain path (string, unset = self-signed)
+        pub const TLS_CERT_PATH: &str = "gateway.tls_cert_path";
+        /// PEM private key path (string, unset = self-signed)
+        pub const TLS_KEY_PATH: &str = "gateway.tls_key_path";
+        /// Client certificate verification ("off", "optional", "required")
+        pub const TLS_CLIENT_AUTH: &str = "gateway.tls_client_auth";
+        /// PEM bundle of CAs trusted for client certificates (string)
+        pub const TLS_CLIENT_CA_PATH: &str = "gateway.tls_client_ca_path";
+        /// User-defined client compatibility profiles (JSON array)
+        pub const CLIENT_COMPAT_PROFILES: &str = "gateway.client_compat_profiles";
+        /// Approval policy for possibly destructive tools ("allow", "ask", "deny")
+        pub const DESTRUCTIVE_TOOL_POLICY: &str = "gateway.destructive_tool_policy";
     }
 
     /// OAuth callback settings namespace
@@ -143,6 +167,19 @@ impl AppSettingsService {
         self.repository.delete(key).await
     }
 
+    /// Get a non-empty string setting value.
+    async fn get_non_empty(&self, key: &str) -> Option<String> {
+        self.get_string(key).await.filter(|v| !v.is_empty())
+    }
+
+    /// Set a string setting value, deleting the key when `None`.
+    async fn set_optional(&self, key: &str, value: Option<&str>) -> anyhow::Result<()> {
+        match value {
+            Some(value) => self.repository.set(key, value).await,
+            None => self.repository.delete(key).await,
+        }
+    }
+
     // =========================================================================
     // Gateway settings
     // =========================================================================
@@ -190,6 +227,193 @@ impl AppSettingsService {
             .await
     }
 
+    /// Default permission bits for the gateway Unix socket (owner read/write only)
+    pub const DEFAULT_UNIX_SOCKET_MODE: u32 = 0o600;
+
+    /// Get whether the gateway serves on TCP (default: true).
+    pub async fn get_gateway_tcp_enabled(&self) -> bool {
+        self.get_string(keys::gateway::TCP_ENABLED)
+            .await
+            .map(|v| v == "true")
+            .unwrap_or(true)
+    }
+
+    /// Set whether the gateway serves on TCP.
+    pub async fn set_gateway_tcp_enabled(&self, enabled: bool) -> anyhow::Result<()> {
+        info!("[Settings] Setting gateway tcp_enabled to {}", enabled);
+        self.repository
+            .set(
+                keys::gateway::TCP_ENABLED,
+                if enabled { "true" } else { "false" },
+            )
+            .await
+    }
+
+    /// Get the gateway Unix socket path.
+    ///
+    /// Returns `None` if not set (no Unix socket listener).
+    pub async fn get_gateway_unix_socket_path(&self) -> Option<String> {
+        self.get_non_empty(keys::gateway::UNIX_SOCKET_PATH).await
+    }
+
+    /// Set the gateway Unix socket path (`None` disables the socket listener).
+    pub async fn set_gateway_unix_socket_path(&self, path: Option<&str>) -> anyhow::Result<()> {
+        info!("[Settings] Setting gateway unix socket to {:?}", path);
+        self.set_optional(keys::gateway::UNIX_SOCKET_PATH, path)
+            .await
+    }
+
+    /// Get the gateway Unix socket permission bits (default: 0o600).
+    pub async fn get_gateway_unix_socket_mode(&self) -> u32 {
+        self.get_string(keys::gateway::UNIX_SOCKET_MODE)
+            .await
+            .and_then(|v| u32::from_str_radix(&v, 8).ok())
+            .unwrap_or(Self::DEFAULT_UNIX_SOCKET_MODE)
+    }
+
+    /// Set the gateway Unix socket permission bits (stored as octal).
+    pub async fn set_gateway_unix_socket_mode(&self, mode: u32) -> anyhow::Result<()> {
+        info!("[Settings] Setting gateway unix socket mode to {:o}", mode);
+        self.repository
+            .set(keys::gateway::UNIX_SOCKET_MODE, &format!("{:o}", mode))
+            .await
+    }
+
+    /// Default TCP bind address (loopback only)
+    pub const DEFAULT_GATEWAY_BIND_HOST: &'static str = "127.0.0.1";
+
+    /// Get the gateway TCP bind address (default: 127.0.0.1).
+    pub async fn get_gateway_bind_host(&self) -> String {
+        self.get_non_empty(keys::gateway::BIND_HOST)
+            .await
+            .unwrap_or_else(|| Self::DEFAULT_GATEWAY_BIND_HOST.to_string())
+    }
+
+    /// Set the gateway TCP bind address (`None` reverts to loopback).
+    pub async fn set_gateway_bind_host(&self, host: Option<&str>) -> anyhow::Result<()> {
+        info!("[Settings] Setting gateway bind host to {:?}", host);
+        self.set_optional(keys::gateway::BIND_HOST, host).await
+    }
+
+    /// Get the gateway's externally reachable base URL, if configured.
+    pub async fn get_gateway_public_url(&self) -> Option<String> {
+        self.get_non_empty(keys::gateway::PUBLIC_URL).await
+    }
+
+    /// Set the gateway's externally reachable base URL (`None` = localhost).
+    pub async fn set_gateway_public_url(&self, url: Option<&str>) -> anyhow::Result<()> {
+        info!("[Settings] Setting gateway public URL to {:?}", url);
+        self.set_optional(keys::gateway::PUBLIC_URL, url).await
+    }
+
+    /// Get whether the gateway terminates TLS (default: false).
+    pub async fn get_gateway_tls_enabled(&self) -> bool {
+        self.get_string(keys::gateway::TLS_ENABLED)
+            .await
+            .map(|v| v == "true")
+            .unwrap_or(false)
+    }
+
+    /// Set whether the gateway terminates TLS.
+    pub async fn set_gateway_tls_enabled(&self, enabled: bool) -> anyhow::Result<()> {
+        info!("[Settings] Setting gateway tls_enabled to {}", enabled);
+        self.repository
+            .set(
+                keys::gateway::TLS_ENABLED,
+                if enabled { "true" } else { "false" },
+            )
+            .await
+    }
+
+    /// Get the user-provided TLS certificate and key paths.
+    ///
+    /// Returns `None` unless both are set (caller falls back to self-signed).
+    pub async fn get_gateway_tls_cert_paths(&self) -> Option<(String, String)> {
+        let cert = self.get_non_empty(keys::gateway::TLS_CERT_PATH).await?;
+        let key = self.get_non_empty(keys::gateway::TLS_KEY_PATH).await?;
+        Some((cert, key))
+    }
+
+    /// Set the TLS certificate and key paths (`None` = self-signed).
+    pub async fn set_gateway_tls_cert_paths(
+        &self,
+        paths: Option<(&str, &str)>,
+    ) -> anyhow::Result<()> {
+        info!(
+            "[Settings] Setting gateway TLS certificate paths to {:?}",
+            paths
+        );
+        self.set_optional(keys::gateway::TLS_CERT_PATH, paths.map(|p| p.0))
+            .await?;
+        self.set_optional(keys::gateway::TLS_KEY_PATH, paths.map(|p| p.1))
+            .await
+    }
+
+    /// Get the client certificate verification mode (default: "off").
+    pub async fn get_gateway_tls_client_auth(&self) -> String {
+        self.get_non_empty(keys::gateway::TLS_CLIENT_AUTH)
+            .await
+            .unwrap_or_else(|| "off".to_string())
+    }
+
+    /// Set the client certificate verification mode.
+    pub async fn set_gateway_tls_client_auth(&self, mode: &str) -> anyhow::Result<()> {
+        info!("[Settings] Setting gateway TLS client auth to {}", mode);
+        self.repository
+            .set(keys::gateway::TLS_CLIENT_AUTH, mode)
+            .await
+    }
+
+    /// Get the client CA bundle path, if configured.
+    pub async fn get_gateway_tls_client_ca_path(&self) -> Option<String> {
+        self.get_non_empty(keys::gateway::TLS_CLIENT_CA_PATH).await
+    }
+
+    /// Set the client CA bundle path.
+    pub async fn set_gateway_tls_client_ca_path(&self, path: Option<&str>) -> anyhow::Result<()> {
+        info!("[Settings] Setting gateway TLS client CA to {:?}", path);
+        self.set_optional(keys::gateway::TLS_CLIENT_CA_PATH, path)
+            .await
+    }
+
+    /// Get user-defined client compatibility profiles (built-ins are not included).
+    pub async fn get_client_compat_profiles(&self) -> Vec<ClientCompatProfile> {
+        self.get_or_default(keys::gateway::CLIENT_COMPAT_PROFILES, Vec::new())
+            .await
+    }
+
+    /// Set user-defined client compatibility profiles.
+    pub async fn set_client_compat_profiles(
+        &self,
+        profiles: &[ClientCompatProfile],
+    ) -> anyhow::Result<()> {
+        info!(
+            "[Settings] Setting {} client compatibility profile(s)",
+            profiles.len()
+        );
+        self.set_typed(keys::gateway::CLIENT_COMPAT_PROFILES, &profiles)
+            .await
+    }
+
+    /// Get the approval policy for destructive tools without a rule (default: allow).
+    pub async fn get_destructive_tool_policy(&self) -> ApprovalPolicy {
+        self.get_or_default(
+            keys::gateway::DESTRUCTIVE_TOOL_POLICY,
+            ApprovalPolicy::Allow,
+        )
+        .await
+    }
+
+    /// Set the approval policy for destructive tools without a rule.
+    pub async fn set_destructive_tool_policy(&self, policy: ApprovalPolicy) -> anyhow::Result<()> {
+        info!(
+            "[Settings] Setting destructive tool policy to {}",
+            policy.as_str()
+        );
+        self.set_typed(keys::gateway::DESTRUCTIVE_TOOL_POLICY, &policy)
+            .await
+    }
+
     // =========================================================================
     // OAuth settings
     // =========================================================================
@@ -371,6 +595,120 @@ mod tests {
         assert!(service.get_gateway_auto_start().await);
     }
 
+    #[tokio::test]
+    async fn test_gateway_unix_socket() {
+        let repo = Arc::new(InMemorySettingsRepository::new());
+        let service = AppSettingsService::new(repo);
+
+        // Defaults: TCP on, no socket, owner-only mode
+        assert!(service.get_gateway_tcp_enabled().await);
+        assert_eq!(service.get_gateway_unix_socket_path().await, None);
+        assert_eq!(service.get_gateway_unix_socket_mode().await, 0o600);
+
+        service
+            .set_gateway_unix_socket_path(Some("/run/user/1000/mcpmux.sock"))
+            .await
+            .unwrap();
+        service.set_gateway_unix_socket_mode(0o660).await.unwrap();
+        service.set_gateway_tcp_enabled(false).await.unwrap();
+
+        assert_eq!(
+            service.get_gateway_unix_socket_path().await.as_deref(),
+            Some("/run/user/1000/mcpmux.sock")
+        );
+        assert_eq!(service.get_gateway_unix_socket_mode().await, 0o660);
+        assert!(!service.get_gateway_tcp_enabled().await);
+
+        service.set_gateway_unix_socket_path(None).await.unwrap();
+        assert_eq!(service.get_gateway_unix_socket_path().await, None);
+    }
+
+    #[tokio::test]
+    async fn test_gateway_tls_settings() {
+        let repo = Arc::new(InMemorySettingsRepository::new());
+        let service = AppSettingsService::new(repo);
+
+        // Defaults: loopback, plain HTTP, no public URL
+        assert_eq!(service.get_gateway_bind_host().await, "127.0.0.1");
+        assert!(!service.get_gateway_tls_enabled().await);
+        assert_eq!(service.get_gateway_public_url().await, None);
+        assert_eq!(service.get_gateway_tls_cert_paths().await, None);
+        assert_eq!(service.get_gateway_tls_client_auth().await, "off");
+
+        service
+            .set_gateway_bind_host(Some("0.0.0.0"))
+            .await
+            .unwrap();
+        service.set_gateway_tls_enabled(true).await.unwrap();
+        service
+            .set_gateway_public_url(Some("https://mux.lan:45818"))
+            .await
+            .unwrap();
+        service
+            .set_gateway_tls_cert_paths(Some(("/etc/mux/cert.pem", "/etc/mux/key.pem")))
+            .await
+            .unwrap();
+
+        assert_eq!(service.get_gateway_bind_host().await, "0.0.0.0");
+        assert!(service.get_gateway_tls_enabled().await);
+        assert_eq!(
+            service.get_gateway_public_url().await.as_deref(),
+            Some("https://mux.lan:45818")
+        );
+        assert_eq!(
+            service.get_gateway_tls_cert_paths().await,
+            Some((
+                "/etc/mux/cert.pem".to_string(),
+                "/etc/mux/key.pem".to_string()
+            ))
+        );
+
+        // Clearing reverts to defaults
+        service.set_gateway_bind_host(None).await.unwrap();
+        service.set_gateway_tls_cert_paths(None).await.unwrap();
+        assert_eq!(service.get_gateway_bind_host().await, "127.0.0.1");
+        assert_eq!(service.get_gateway_tls_cert_paths().await, None);
+    }
+
+    #[tokio::test]
+    async fn test_client_compat_profiles() {
+        let repo = Arc::new(InMemorySettingsRepository::new());
+        let service = AppSettingsService::new(repo);
+
+        assert!(service.get_client_compat_profiles().await.is_empty());
+
+        let profiles = vec![ClientCompatProfile {
+            name: "strict".to_string(),
+            client_name_patterns: vec!["agent".to_string()],
+            max_tool_name_length: Some(48),
+            strip_schema_keywords: vec!["format".to_string()],
+            ..Default::default()
+        }];
+        service.set_client_compat_profiles(&profiles).await.unwrap();
+
+        assert_eq!(service.get_client_compat_profiles().await, profiles);
+    }
+
+    #[tokio::test]
+    async fn test_destructive_tool_policy() {
+        let repo = Arc::new(InMemorySettingsRepository::new());
+        let service = AppSettingsService::new(repo);
+
+        assert_eq!(
+            service.get_destructive_tool_policy().await,
+            ApprovalPolicy::Allow
+        );
+
+        service
+            .set_destructive_tool_policy(ApprovalPolicy::Ask)
+            .await
+            .unwrap();
+        assert_eq!(
+            service.get_destructive_tool_policy().await,
+            ApprovalPolicy::Ask
+        );
+    }
+
     #[tokio::test]
     async fn test_theme() {
         let repo = Arc::new(InMemorySettingsRepository::new());
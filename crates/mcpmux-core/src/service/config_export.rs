@@ -4,6 +4,12 @@
 //! - Cursor (mcp.json)
 //! - VS Code Continue (.continuerc / settings.json)
 //! - Claude Desktop (claude_desktop_config.json)
+//! - Windsurf (mcp_config.json)
+//! - Zed (settings.json `context_servers`)
+//! - Codex CLI (config.toml `mcp_servers`)
+//! - Gemini CLI (settings.json)
+//! - Claude Code (.claude.json / project .mcp.json)
+//! - VS Code native (mcp.json `servers`)
 //!
 //! This module works with:
 //! - `RegistryServer` - Server definition from registry (transport config, inputs)
@@ -11,9 +17,15 @@
 
 use crate::domain::InstalledServer;
 use crate::registry::{RegistryServer, TransportConfig};
+use anyhow::{anyhow, bail, Context, Result};
 use serde::{Deserialize, Serialize};
+use serde_json::{json, Map, Value};
+use sha2::{Digest, Sha256};
+use similar::TextDiff;
 use std::collections::HashMap;
-use std::path::PathBuf;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use toml_edit::{DocumentMut, Item};
 use uuid::Uuid;
 
 /// Client configuration format
@@ -25,12 +37,55 @@ pub enum ConfigFormat {
     VsCodeContinue,
     /// Claude Desktop format
     ClaudeDesktop,
+    /// Windsurf format
+    Windsurf,
+    /// Zed `context_servers` (inside settings.json)
+    Zed,
+    /// Codex CLI `mcp_servers` (TOML)
+    CodexCli,
+    /// Gemini CLI format
+    GeminiCli,
+    /// Claude Code format (user `.claude.json` or project `.mcp.json`)
+    ClaudeCode,
+    /// VS Code native `mcp.json`
+    VsCode,
 }
 
 impl ConfigFormat {
     /// Get file extension for this format
     pub fn file_extension(&self) -> &'static str {
-        ".json"
+        match self {
+            ConfigFormat::CodexCli => ".toml",
+            _ => ".json",
+        }
+    }
+
+    /// Suggested file name when saving this format
+    pub fn file_name(&self) -> &'static str {
+        match self {
+            ConfigFormat::Cursor | ConfigFormat::VsCode => "mcp.json",
+            ConfigFormat::VsCodeContinue => "continue-mcp.json",
+            ConfigFormat::ClaudeDesktop => "claude_desktop_config.json",
+            ConfigFormat::Windsurf => "mcp_config.json",
+            ConfigFormat::Zed | ConfigFormat::GeminiCli => "settings.json",
+            ConfigFormat::CodexCli => "config.toml",
+            ConfigFormat::ClaudeCode => ".mcp.json",
+        }
+    }
+
+    /// Path of the server map inside the config file
+    pub fn servers_key(&self) -> &'static [&'static str] {
+        match self {
+            ConfigFormat::VsCodeContinue => &["experimental", "modelContextProtocol", "servers"],
+            ConfigFormat::Zed => &["context_servers"],
+            ConfigFormat::CodexCli => &["mcp_servers"],
+            ConfigFormat::VsCode => &["servers"],
+            ConfigFormat::Cursor
+            | ConfigFormat::ClaudeDesktop
+            | ConfigFormat::Windsurf
+            | ConfigFormat::GeminiCli
+            | ConfigFormat::ClaudeCode => &["mcpServers"],
+        }
     }
 
     /// Get default config file path for this format
@@ -64,6 +119,38 @@ impl ConfigFormat {
                     dirs::config_dir().map(|c| c.join("Claude").join("claude_desktop_config.json"))
                 }
             }
+            ConfigFormat::Windsurf => {
+                // ~/.codeium/windsurf/mcp_config.json
+                dirs::home_dir()
+                    .map(|h| h.join(".codeium").join("windsurf").join("mcp_config.json"))
+            }
+            ConfigFormat::Zed => {
+                // %APPDATA%\Zed\settings.json on Windows, ~/.config/zed/settings.json elsewhere
+                #[cfg(target_os = "windows")]
+                {
+                    dirs::config_dir().map(|c| c.join("Zed").join("settings.json"))
+                }
+                #[cfg(not(target_os = "windows"))]
+                {
+                    dirs::home_dir().map(|h| h.join(".config").join("zed").join("settings.json"))
+                }
+            }
+            ConfigFormat::CodexCli => {
+                // ~/.codex/config.toml
+                dirs::home_dir().map(|h| h.join(".codex").join("config.toml"))
+            }
+            ConfigFormat::GeminiCli => {
+                // ~/.gemini/settings.json
+                dirs::home_dir().map(|h| h.join(".gemini").join("settings.json"))
+            }
+            ConfigFormat::ClaudeCode => {
+                // User scope; project scope uses .mcp.json in the project root
+                dirs::home_dir().map(|h| h.join(".claude.json"))
+            }
+            ConfigFormat::VsCode => {
+                // User-level mcp.json in the VS Code profile
+                dirs::config_dir().map(|c| c.join("Code").join("User").join("mcp.json"))
+            }
         }
     }
 }
@@ -149,6 +236,135 @@ pub enum ClaudeServerConfig {
     },
 }
 
+/// Windsurf MCP configuration format
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WindsurfConfig {
+    #[serde(rename = "mcpServers")]
+    pub mcp_servers: HashMap<String, WindsurfServerConfig>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum WindsurfServerConfig {
+    Stdio {
+        command: String,
+        args: Vec<String>,
+        #[serde(skip_serializing_if = "HashMap::is_empty")]
+        env: HashMap<String, String>,
+    },
+    Http {
+        #[serde(rename = "serverUrl")]
+        server_url: String,
+        #[serde(skip_serializing_if = "HashMap::is_empty")]
+        headers: HashMap<String, String>,
+    },
+}
+
+/// Zed `context_servers` format
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ZedConfig {
+    pub context_servers: HashMap<String, ZedServerConfig>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ZedServerConfig {
+    Stdio {
+        /// Always "custom" for servers not installed as Zed extensions
+        source: String,
+        command: String,
+        args: Vec<String>,
+        #[serde(skip_serializing_if = "HashMap::is_empty")]
+        env: HashMap<String, String>,
+    },
+    Http {
+        url: String,
+        #[serde(skip_serializing_if = "HashMap::is_empty")]
+        headers: HashMap<String, String>,
+    },
+}
+
+/// Codex CLI configuration format (TOML)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CodexConfig {
+    pub mcp_servers: HashMap<String, CodexServerConfig>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum CodexServerConfig {
+    Stdio {
+        command: String,
+        args: Vec<String>,
+        #[serde(skip_serializing_if = "HashMap::is_empty")]
+        env: HashMap<String, String>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        cwd: Option<String>,
+    },
+    Http {
+        url: String,
+        #[serde(skip_serializing_if = "HashMap::is_empty")]
+        http_headers: HashMap<String, String>,
+    },
+}
+
+/// Gemini CLI configuration format
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GeminiConfig {
+    #[serde(rename = "mcpServers")]
+    pub mcp_servers: HashMap<String, GeminiServerConfig>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum GeminiServerConfig {
+    Stdio {
+        command: String,
+        args: Vec<String>,
+        #[serde(skip_serializing_if = "HashMap::is_empty")]
+        env: HashMap<String, String>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        cwd: Option<String>,
+    },
+    Http {
+        /// Streamable HTTP endpoint (`url` is SSE-only in Gemini CLI)
+        #[serde(rename = "httpUrl")]
+        http_url: String,
+        #[serde(skip_serializing_if = "HashMap::is_empty")]
+        headers: HashMap<String, String>,
+    },
+}
+
+/// Claude Code configuration format
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ClaudeCodeConfig {
+    #[serde(rename = "mcpServers")]
+    pub mcp_servers: HashMap<String, TypedServerConfig>,
+}
+
+/// VS Code native mcp.json format
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VsCodeConfig {
+    pub servers: HashMap<String, TypedServerConfig>,
+}
+
+/// Server config with an explicit `type` (Claude Code, VS Code)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "lowercase")]
+pub enum TypedServerConfig {
+    Stdio {
+        command: String,
+        args: Vec<String>,
+        #[serde(skip_serializing_if = "HashMap::is_empty")]
+        env: HashMap<String, String>,
+    },
+    Http {
+        url: String,
+        #[serde(skip_serializing_if = "HashMap::is_empty")]
+        headers: HashMap<String, String>,
+    },
+}
+
 /// Type alias for credential resolver function
 pub type CredentialResolver =
     Box<dyn Fn(&str, &Uuid) -> Option<HashMap<String, String>> + Send + Sync>;
@@ -179,6 +395,8 @@ pub enum ResolvedTransport {
         command: String,
         args: Vec<String>,
         env: HashMap<String, String>,
+        /// Directory to launch the process from
+        cwd: Option<String>,
     },
     Http {
         url: String,
@@ -186,6 +404,56 @@ pub enum ResolvedTransport {
     },
 }
 
+/// Command-line flag that runs the McpMux executable as a stdio bridge
+pub const STDIO_BRIDGE_FLAG: &str = "--stdio-bridge";
+
+/// How an exported client config reaches the gateway
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GatewayEndpoint {
+    /// Streamable HTTP endpoint URL
+    Http { url: String },
+    /// Unix domain socket. Clients can't speak HTTP over a socket, so they
+    /// launch `bridge_command` as a stdio server that relays to the socket,
+    /// authenticating as the pre-registered `client_id`.
+    UnixSocket {
+        path: PathBuf,
+        bridge_command: String,
+        client_id: String,
+    },
+}
+
+impl GatewayEndpoint {
+    /// The gateway as a server entry named `name`
+    pub fn to_server(&self, name: &str) -> ResolvedServer {
+        let transport = match self {
+            GatewayEndpoint::Http { url } => ResolvedTransport::Http {
+                url: url.clone(),
+                headers: HashMap::new(),
+            },
+            GatewayEndpoint::UnixSocket {
+                path,
+                bridge_command,
+                client_id,
+            } => ResolvedTransport::Stdio {
+                command: bridge_command.clone(),
+                args: vec![
+                    STDIO_BRIDGE_FLAG.to_string(),
+                    "--client-id".to_string(),
+                    client_id.clone(),
+                    "--socket".to_string(),
+                    path.to_string_lossy().into_owned(),
+                ],
+                env: HashMap::new(),
+                cwd: None,
+            },
+        };
+        ResolvedServer {
+            server_id: name.to_string(),
+            transport,
+        }
+    }
+}
+
 impl ConfigExporter {
     /// Create a new config exporter
     pub fn new() -> Self {
@@ -254,6 +522,7 @@ impl ConfigExporter {
                     command: resolved_command,
                     args: resolved_args,
                     env: resolved_env,
+                    cwd: None,
                 }
             }
             TransportConfig::Http { url, headers, .. } => {
@@ -287,7 +556,9 @@ impl ConfigExporter {
 
         for server in servers {
             let server_config = match &server.transport {
-                ResolvedTransport::Stdio { command, args, env } => CursorServerConfig::Stdio {
+                ResolvedTransport::Stdio {
+                    command, args, env, ..
+                } => CursorServerConfig::Stdio {
                     command: command.clone(),
                     args: args.clone(),
                     env: env.clone(),
@@ -309,7 +580,9 @@ impl ConfigExporter {
 
         for server in servers {
             let transport = match &server.transport {
-                ResolvedTransport::Stdio { command, args, env } => ContinueTransport::Stdio {
+                ResolvedTransport::Stdio {
+                    command, args, env, ..
+                } => ContinueTransport::Stdio {
                     command: command.clone(),
                     args: args.clone(),
                     env: env.clone(),
@@ -335,7 +608,9 @@ impl ConfigExporter {
 
         for server in servers {
             let server_config = match &server.transport {
-                ResolvedTransport::Stdio { command, args, env } => ClaudeServerConfig::Stdio {
+                ResolvedTransport::Stdio {
+                    command, args, env, ..
+                } => ClaudeServerConfig::Stdio {
                     command: command.clone(),
                     args: args.clone(),
                     env: env.clone(),
@@ -353,28 +628,272 @@ impl ConfigExporter {
         ClaudeDesktopConfig { mcp_servers }
     }
 
-    /// Export config to JSON string
-    pub fn export_json(
+    /// Generate Windsurf format config
+    pub fn to_windsurf(&self, servers: &[ResolvedServer]) -> WindsurfConfig {
+        let mut mcp_servers = HashMap::new();
+
+        for server in servers {
+            let server_config = match &server.transport {
+                ResolvedTransport::Stdio {
+                    command, args, env, ..
+                } => WindsurfServerConfig::Stdio {
+                    command: command.clone(),
+                    args: args.clone(),
+                    env: env.clone(),
+                },
+                ResolvedTransport::Http { url, headers } => WindsurfServerConfig::Http {
+                    server_url: url.clone(),
+                    headers: headers.clone(),
+                },
+            };
+
+            mcp_servers.insert(server.server_id.clone(), server_config);
+        }
+
+        WindsurfConfig { mcp_servers }
+    }
+
+    /// Generate Zed format config
+    pub fn to_zed(&self, servers: &[ResolvedServer]) -> ZedConfig {
+        let mut context_servers = HashMap::new();
+
+        for server in servers {
+            let server_config = match &server.transport {
+                ResolvedTransport::Stdio {
+                    command, args, env, ..
+                } => ZedServerConfig::Stdio {
+                    source: "custom".to_string(),
+                    command: command.clone(),
+                    args: args.clone(),
+                    env: env.clone(),
+                },
+                ResolvedTransport::Http { url, headers } => ZedServerConfig::Http {
+                    url: url.clone(),
+                    headers: headers.clone(),
+                },
+            };
+
+            context_servers.insert(server.server_id.clone(), server_config);
+        }
+
+        ZedConfig { context_servers }
+    }
+
+    /// Generate Codex CLI format config
+    pub fn to_codex(&self, servers: &[ResolvedServer]) -> CodexConfig {
+        let mut mcp_servers = HashMap::new();
+
+        for server in servers {
+            let server_config = match &server.transport {
+                ResolvedTransport::Stdio {
+                    command,
+                    args,
+                    env,
+                    cwd,
+                } => CodexServerConfig::Stdio {
+                    command: command.clone(),
+                    args: args.clone(),
+                    env: env.clone(),
+                    cwd: cwd.clone(),
+                },
+                ResolvedTransport::Http { url, headers } => CodexServerConfig::Http {
+                    url: url.clone(),
+                    http_headers: headers.clone(),
+                },
+            };
+
+            mcp_servers.insert(server.server_id.clone(), server_config);
+        }
+
+        CodexConfig { mcp_servers }
+    }
+
+    /// Generate Gemini CLI format config
+    pub fn to_gemini(&self, servers: &[ResolvedServer]) -> GeminiConfig {
+        let mut mcp_servers = HashMap::new();
+
+        for server in servers {
+            let server_config = match &server.transport {
+                ResolvedTransport::Stdio {
+                    command,
+                    args,
+                    env,
+                    cwd,
+                } => GeminiServerConfig::Stdio {
+                    command: command.clone(),
+                    args: args.clone(),
+                    env: env.clone(),
+                    cwd: cwd.clone(),
+                },
+                ResolvedTransport::Http { url, headers } => GeminiServerConfig::Http {
+                    http_url: url.clone(),
+                    headers: headers.clone(),
+                },
+            };
+
+            mcp_servers.insert(server.server_id.clone(), server_config);
+        }
+
+        GeminiConfig { mcp_servers }
+    }
+
+    /// Generate Claude Code format config
+    pub fn to_claude_code(&self, servers: &[ResolvedServer]) -> ClaudeCodeConfig {
+        ClaudeCodeConfig {
+            mcp_servers: Self::typed_servers(servers),
+        }
+    }
+
+    /// Generate VS Code native format config
+    pub fn to_vscode(&self, servers: &[ResolvedServer]) -> VsCodeConfig {
+        VsCodeConfig {
+            servers: Self::typed_servers(servers),
+        }
+    }
+
+    fn typed_servers(servers: &[ResolvedServer]) -> HashMap<String, TypedServerConfig> {
+        servers
+            .iter()
+            .map(|server| {
+                let server_config = match &server.transport {
+                    ResolvedTransport::Stdio {
+                        command, args, env, ..
+                    } => TypedServerConfig::Stdio {
+                        command: command.clone(),
+                        args: args.clone(),
+                        env: env.clone(),
+                    },
+                    ResolvedTransport::Http { url, headers } => TypedServerConfig::Http {
+                        url: url.clone(),
+                        headers: headers.clone(),
+                    },
+                };
+                (server.server_id.clone(), server_config)
+            })
+            .collect()
+    }
+
+    /// Generate the config for a format as a JSON value
+    fn to_value(&self, format: ConfigFormat, servers: &[ResolvedServer]) -> Result<Value> {
+        let value = match format {
+            ConfigFormat::Cursor => serde_json::to_value(self.to_cursor(servers)),
+            ConfigFormat::VsCodeContinue => serde_json::to_value(self.to_continue(servers)),
+            ConfigFormat::ClaudeDesktop => serde_json::to_value(self.to_claude_desktop(servers)),
+            ConfigFormat::Windsurf => serde_json::to_value(self.to_windsurf(servers)),
+            ConfigFormat::Zed => serde_json::to_value(self.to_zed(servers)),
+            ConfigFormat::CodexCli => serde_json::to_value(self.to_codex(servers)),
+            ConfigFormat::GeminiCli => serde_json::to_value(self.to_gemini(servers)),
+            ConfigFormat::ClaudeCode => serde_json::to_value(self.to_claude_code(servers)),
+            ConfigFormat::VsCode => serde_json::to_value(self.to_vscode(servers)),
+        }?;
+        Ok(value)
+    }
+
+    /// Export config in the format's file syntax (TOML for Codex CLI, JSON otherwise)
+    pub fn export(&self, format: ConfigFormat, servers: &[ResolvedServer]) -> Result<String> {
+        let value = self.to_value(format, servers)?;
+        match format {
+            ConfigFormat::CodexCli => Ok(toml::to_string_pretty(&value)?),
+            _ => Ok(serde_json::to_string_pretty(&value)?),
+        }
+    }
+
+    /// Export a config whose only server is the gateway, as `name`
+    pub fn export_gateway(
         &self,
         format: ConfigFormat,
+        name: &str,
+        endpoint: &GatewayEndpoint,
+    ) -> Result<String> {
+        self.export(format, &[endpoint.to_server(name)])
+    }
+
+    /// Merge exported servers into an existing config file's content.
+    ///
+    /// Only the exported servers' entries are replaced or added; other
+    /// servers, unrelated settings, comments and formatting are kept.
+    /// `existing` is JSON/JSONC, or TOML for Codex CLI.
+    pub fn merge_into(
+        &self,
+        format: ConfigFormat,
+        existing: &str,
         servers: &[ResolvedServer],
-    ) -> Result<String, serde_json::Error> {
+    ) -> Result<String> {
+        let exported = self.to_value(format, servers)?;
+        let key = format.servers_key();
+        let exported_servers = key
+            .iter()
+            .try_fold(&exported, |v, k| v.get(k))
+            .and_then(|v| v.as_object())
+            .cloned()
+            .unwrap_or_default();
+
         match format {
-            ConfigFormat::Cursor => {
-                let config = self.to_cursor(servers);
-                serde_json::to_string_pretty(&config)
-            }
-            ConfigFormat::VsCodeContinue => {
-                let config = self.to_continue(servers);
-                serde_json::to_string_pretty(&config)
-            }
-            ConfigFormat::ClaudeDesktop => {
-                let config = self.to_claude_desktop(servers);
-                serde_json::to_string_pretty(&config)
-            }
+            ConfigFormat::CodexCli => merge_toml(existing, key, exported_servers, &[]),
+            _ => merge_jsonc(existing, key, exported_servers),
         }
     }
 
+    /// Plan writing the export into the config file at `path`.
+    ///
+    /// Nothing is written; the plan holds the merged content, a unified diff
+    /// against the current file and the fingerprint of the file it was made
+    /// from, for [`write_config_file`].
+    pub fn plan_write(
+        &self,
+        format: ConfigFormat,
+        path: &Path,
+        servers: &[ResolvedServer],
+    ) -> Result<ConfigWritePlan> {
+        let existing = read_existing(path)?;
+        let content = match &existing {
+            Some(existing) => self.merge_into(format, existing, servers)?,
+            None => self.export(format, servers)?,
+        };
+        Ok(write_plan(path, existing, content))
+    }
+
+    /// Plan pointing an existing config file at the gateway.
+    ///
+    /// The `replaced` entries are dropped from the server map at `key` and
+    /// the gateway is added as `name`; like [`Self::plan_write`], everything
+    /// else in the file is kept.
+    pub fn plan_gateway_write(
+        &self,
+        format: ConfigFormat,
+        path: &Path,
+        key: &[&str],
+        name: &str,
+        endpoint: &GatewayEndpoint,
+        replaced: &[String],
+    ) -> Result<ConfigWritePlan> {
+        let existing =
+            read_existing(path)?.ok_or_else(|| anyhow!("{} does not exist", path.display()))?;
+        let exported = self.to_value(format, &[endpoint.to_server(name)])?;
+        let gateway = format
+            .servers_key()
+            .iter()
+            .try_fold(&exported, |v, k| v.get(k))
+            .and_then(|v| v.as_object())
+            .cloned()
+            .unwrap_or_default();
+        let replaced: Vec<String> = replaced
+            .iter()
+            .filter(|r| r.as_str() != name)
+            .cloned()
+            .collect();
+
+        let content = match format {
+            ConfigFormat::CodexCli => merge_toml(&existing, key, gateway, &replaced)?,
+            _ => merge_jsonc(
+                &remove_jsonc_members(&existing, key, &replaced)?,
+                key,
+                gateway,
+            )?,
+        };
+        Ok(write_plan(path, Some(existing), content))
+    }
+
     /// Resolve multiple servers from registry and installed data
     pub fn resolve_servers(
         &self,
@@ -395,6 +914,542 @@ impl ConfigExporter {
     }
 }
 
+/// A write of exported servers into a client config file, not yet applied
+#[derive(Debug, Clone, Serialize)]
+pub struct ConfigWritePlan {
+    pub path: PathBuf,
+    /// File content after the write
+    pub content: String,
+    /// Unified diff from the current file to `content`
+    pub diff: String,
+    /// Whether the write changes the file
+    pub changed: bool,
+    /// Fingerprint of the file the plan was made from (`None` if it did not exist)
+    pub base_fingerprint: Option<String>,
+}
+
+/// Fingerprint of config file content, used to detect concurrent edits
+pub fn config_fingerprint(content: &str) -> String {
+    format!("{:x}", Sha256::digest(content.as_bytes()))
+}
+
+/// Plan replacing `existing` (the file at `path`, if any) with `content`
+fn write_plan(path: &Path, existing: Option<String>, content: String) -> ConfigWritePlan {
+    let original = existing.as_deref().unwrap_or_default();
+    let diff = TextDiff::from_lines(original, &content)
+        .unified_diff()
+        .header(
+            &format!("{} (current)", path.display()),
+            &format!("{} (McpMux)", path.display()),
+        )
+        .to_string();
+
+    ConfigWritePlan {
+        path: path.to_path_buf(),
+        changed: original != content,
+        diff,
+        base_fingerprint: existing.as_deref().map(config_fingerprint),
+        content,
+    }
+}
+
+fn read_existing(path: &Path) -> Result<Option<String>> {
+    match std::fs::read_to_string(path) {
+        Ok(content) => Ok(Some(content)),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+        Err(e) => Err(anyhow!("Failed to read {}: {}", path.display(), e)),
+    }
+}
+
+/// Copy a config file to `<name>.bak` next to it
+pub fn backup_config_file(path: &Path) -> Result<PathBuf> {
+    let mut backup_name = path.file_name().unwrap_or_default().to_os_string();
+    backup_name.push(".bak");
+    let backup_path = path.with_file_name(backup_name);
+    std::fs::copy(path, &backup_path)
+        .with_context(|| format!("Failed to back up {}", path.display()))?;
+    Ok(backup_path)
+}
+
+/// Apply a [`ConfigWritePlan`].
+///
+/// Fails without writing if the file changed since the plan was made. An
+/// existing file is backed up first; the new content is written to a
+/// temporary file and renamed into place. The file keeps its permissions; a
+/// new one is readable by the user only, since it may hold secrets. Returns
+/// the backup path.
+pub fn write_config_file(plan: &ConfigWritePlan) -> Result<Option<PathBuf>> {
+    let current = read_existing(&plan.path)?;
+    if current.as_deref().map(config_fingerprint) != plan.base_fingerprint {
+        bail!(
+            "{} was modified since the export was previewed; preview again before writing",
+            plan.path.display()
+        );
+    }
+
+    let permissions = match current {
+        Some(_) => Some(std::fs::metadata(&plan.path)?.permissions()),
+        None => None,
+    };
+    let backup_path = match current {
+        Some(_) => Some(backup_config_file(&plan.path)?),
+        None => {
+            if let Some(parent) = plan.path.parent() {
+                std::fs::create_dir_all(parent)?;
+            }
+            None
+        }
+    };
+
+    let mut tmp_name = plan.path.file_name().unwrap_or_default().to_os_string();
+    tmp_name.push(".mcpmux.tmp");
+    let tmp_path = plan.path.with_file_name(tmp_name);
+    write_private(&tmp_path, &plan.content, permissions)
+        .with_context(|| format!("Failed to write {}", tmp_path.display()))?;
+    std::fs::rename(&tmp_path, &plan.path)
+        .with_context(|| format!("Failed to write {}", plan.path.display()))?;
+
+    Ok(backup_path)
+}
+
+/// Write `content` to a fresh file with `permissions`, or readable by the
+/// user only without them
+fn write_private(
+    path: &Path,
+    content: &str,
+    permissions: Option<std::fs::Permissions>,
+) -> std::io::Result<()> {
+    // A leftover temporary file would keep its old permissions
+    match std::fs::remove_file(path) {
+        Err(e) if e.kind() != std::io::ErrorKind::NotFound => return Err(e),
+        _ => {}
+    }
+    let mut options = std::fs::OpenOptions::new();
+    options.write(true).create_new(true);
+    #[cfg(unix)]
+    std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+    let mut file = options.open(path)?;
+    file.write_all(content.as_bytes())?;
+    if let Some(permissions) = permissions {
+        file.set_permissions(permissions)?;
+    }
+    file.sync_all()
+}
+
+/// Upsert `servers` into the TOML table at `key` and drop the `removed`
+/// entries, keeping the rest of the document
+fn merge_toml(
+    existing: &str,
+    key: &[&str],
+    servers: Map<String, Value>,
+    removed: &[String],
+) -> Result<String> {
+    let mut doc: DocumentMut = existing.parse()?;
+    let mut table = doc.as_table_mut();
+    for k in key {
+        table = table
+            .entry(k)
+            .or_insert_with(|| {
+                let mut table = toml_edit::Table::new();
+                table.set_implicit(true);
+                Item::Table(table)
+            })
+            .as_table_mut()
+            .ok_or_else(|| anyhow!("'{}' is not a table", k))?;
+    }
+
+    for id in removed {
+        table.remove(id);
+    }
+    for (id, server) in servers {
+        let fields = server
+            .as_object()
+            .ok_or_else(|| anyhow!("Server '{}' is not a table", id))?;
+        // Update an existing entry in place so its header comments survive
+        if !table.get(&id).is_some_and(Item::is_table) {
+            table.insert(&id, Item::Table(toml_edit::Table::new()));
+        }
+        let entry = table[id.as_str()].as_table_mut().expect("entry is a table");
+        entry.clear();
+        for (field, value) in fields {
+            if let Some(value) = toml_value(value) {
+                entry.insert(field, Item::Value(value));
+            }
+        }
+    }
+
+    Ok(doc.to_string())
+}
+
+fn toml_value(value: &Value) -> Option<toml_edit::Value> {
+    Some(match value {
+        Value::Null => return None,
+        Value::Bool(b) => (*b).into(),
+        Value::Number(n) => match n.as_i64() {
+            Some(i) => i.into(),
+            None => n.as_f64()?.into(),
+        },
+        Value::String(s) => s.as_str().into(),
+        Value::Array(items) => {
+            toml_edit::Value::Array(items.iter().filter_map(toml_value).collect())
+        }
+        Value::Object(fields) => {
+            let mut table = toml_edit::InlineTable::new();
+            for (k, v) in fields {
+                if let Some(v) = toml_value(v) {
+                    table.insert(k, v);
+                }
+            }
+            toml_edit::Value::InlineTable(table)
+        }
+    })
+}
+
+/// A member of a JSONC object, as byte offsets into the source text
+struct JsoncMember {
+    key: String,
+    key_start: usize,
+    value_start: usize,
+    value_end: usize,
+}
+
+/// A JSONC object, as byte offsets into the source text
+struct JsoncObject {
+    open: usize,
+    close: usize,
+    members: Vec<JsoncMember>,
+}
+
+/// Upsert `servers` into the object at `key` by splicing the source text, so
+/// comments and formatting elsewhere in the file are untouched.
+fn merge_jsonc(existing: &str, key: &[&str], servers: Map<String, Value>) -> Result<String> {
+    let bytes = existing.as_bytes();
+    let start = skip_trivia(bytes, 0);
+    if start == bytes.len() {
+        let mut value = Value::Object(servers);
+        for k in key.iter().rev() {
+            value = json!({ *k: value });
+        }
+        return Ok(serde_json::to_string_pretty(&value)? + "\n");
+    }
+    if bytes[start] != b'{' {
+        bail!("Config file is not a JSON object");
+    }
+
+    let mut object = parse_jsonc_object(existing, start)?;
+    for (depth, k) in key.iter().enumerate() {
+        match object.members.iter().find(|m| m.key == *k) {
+            Some(m) if bytes[m.value_start] == b'{' => {
+                object = parse_jsonc_object(existing, m.value_start)?;
+            }
+            Some(_) => bail!("'{}' is not an object", k),
+            None => {
+                // Create the rest of the path around the servers
+                let mut value = Value::Object(servers);
+                for k in key[depth + 1..].iter().rev() {
+                    value = json!({ *k: value });
+                }
+                let edit = jsonc_insertion(existing, &object, &[(k.to_string(), value)])?;
+                return Ok(apply_edits(existing, vec![edit]));
+            }
+        }
+    }
+
+    let mut edits = Vec::new();
+    let mut added = Vec::new();
+    for (id, value) in servers {
+        match object.members.iter().find(|m| m.key == id) {
+            Some(m) => {
+                let indent = line_indent(existing, m.key_start);
+                let unit = indent_unit(existing, &object);
+                edits.push((
+                    m.value_start,
+                    m.value_end,
+                    render_json(&value, indent, &unit)?,
+                ));
+            }
+            None => added.push((id, value)),
+        }
+    }
+    if !added.is_empty() {
+        edits.push(jsonc_insertion(existing, &object, &added)?);
+    }
+    Ok(apply_edits(existing, edits))
+}
+
+/// Drop the `names` members from the object at `key` by splicing the source
+/// text, taking each member's line and separating comma with it
+fn remove_jsonc_members(existing: &str, key: &[&str], names: &[String]) -> Result<String> {
+    let bytes = existing.as_bytes();
+    let start = skip_trivia(bytes, 0);
+    if names.is_empty() || start == bytes.len() || bytes[start] != b'{' {
+        return Ok(existing.to_string());
+    }
+    let mut object = parse_jsonc_object(existing, start)?;
+    for k in key {
+        match object.members.iter().find(|m| m.key == *k) {
+            Some(m) if bytes[m.value_start] == b'{' => {
+                object = parse_jsonc_object(existing, m.value_start)?;
+            }
+            _ => return Ok(existing.to_string()),
+        }
+    }
+
+    let members = &object.members;
+    let removed = |m: &JsoncMember| names.contains(&m.key);
+    // Trailing run of removed members: their separator is the comma before them
+    let kept_prefix = members
+        .iter()
+        .rposition(|m| !removed(m))
+        .map_or(0, |i| i + 1);
+    let mut edits = Vec::new();
+    for (i, member) in members.iter().enumerate() {
+        if !removed(member) || i >= kept_prefix {
+            continue;
+        }
+        let line_start = existing[..member.key_start]
+            .rfind('\n')
+            .map_or(0, |p| p + 1);
+        let starts_line = existing[line_start..member.key_start].trim().is_empty();
+        let mut end = member.value_end;
+        while end < bytes.len() && bytes[end].is_ascii_whitespace() && bytes[end] != b'\n' {
+            end += 1;
+        }
+        if bytes.get(end) == Some(&b',') {
+            end += 1;
+        }
+        let line_end = existing[end..]
+            .find('\n')
+            .map_or(existing.len(), |p| end + p + 1);
+        let rest = existing[end..line_end].trim();
+        if starts_line && (rest.is_empty() || rest.starts_with("//")) {
+            // The member's lines, with any comment trailing its comma
+            edits.push((line_start, line_end, String::new()));
+        } else {
+            let next = skip_trivia(bytes, end);
+            edits.push((member.key_start, next, String::new()));
+        }
+    }
+    if kept_prefix < members.len() {
+        let last = &members[members.len() - 1];
+        let mut end = last.value_end;
+        let after = skip_trivia(bytes, end);
+        if bytes.get(after) == Some(&b',') {
+            end = after + 1;
+        }
+        match kept_prefix.checked_sub(1).map(|i| &members[i]) {
+            Some(kept) => edits.push((kept.value_end, end, String::new())),
+            None => edits.push((object.open + 1, object.close, String::new())),
+        }
+    }
+    Ok(apply_edits(existing, edits))
+}
+
+/// Apply non-overlapping `(start, end, replacement)` edits
+fn apply_edits(text: &str, mut edits: Vec<(usize, usize, String)>) -> String {
+    let mut out = text.to_string();
+    edits.sort_by_key(|(start, end, _)| std::cmp::Reverse((*start, *end)));
+    for (start, end, replacement) in edits {
+        out.replace_range(start..end, &replacement);
+    }
+    out
+}
+
+/// Edit adding members at the end of an object
+fn jsonc_insertion(
+    text: &str,
+    object: &JsoncObject,
+    members: &[(String, Value)],
+) -> Result<(usize, usize, String)> {
+    let outer_indent = line_indent(text, object.open);
+    let unit = indent_unit(text, object);
+    let (indent, start, end) = match (object.members.first(), object.members.last()) {
+        // Members on their own lines: match their indentation
+        (Some(first), Some(last)) if text[object.open..first.key_start].contains('\n') => (
+            line_indent(text, first.key_start).to_string(),
+            last.value_end,
+            last.value_end,
+        ),
+        (Some(_), Some(last)) => (
+            format!("{}{}", outer_indent, unit),
+            last.value_end,
+            last.value_end,
+        ),
+        _ => {
+            // Replace a blank body; keep one holding comments
+            let inner = &text[object.open + 1..object.close];
+            let end = if inner.trim().is_empty() {
+                object.close
+            } else {
+                object.open + 1
+            };
+            (format!("{}{}", outer_indent, unit), object.open + 1, end)
+        }
+    };
+
+    let mut insertion = String::new();
+    for (i, (key, value)) in members.iter().enumerate() {
+        if i > 0 || !object.members.is_empty() {
+            insertion.push(',');
+        }
+        insertion.push_str(&format!(
+            "\n{}{}: {}",
+            indent,
+            serde_json::to_string(key)?,
+            render_json(value, &indent, &unit)?
+        ));
+    }
+    if object.members.is_empty() {
+        insertion.push('\n');
+        insertion.push_str(outer_indent);
+    }
+    Ok((start, end, insertion))
+}
+
+/// Pretty-print a value whose first line starts at `indent`, nesting by `unit`
+fn render_json(value: &Value, indent: &str, unit: &str) -> Result<String> {
+    let mut out = Vec::new();
+    let formatter = serde_json::ser::PrettyFormatter::with_indent(unit.as_bytes());
+    value.serialize(&mut serde_json::Serializer::with_formatter(
+        &mut out, formatter,
+    ))?;
+    Ok(String::from_utf8(out)?.replace('\n', &format!("\n{}", indent)))
+}
+
+/// Indentation step used in the file, taken from the object's members
+/// (two spaces if it cannot be told)
+fn indent_unit(text: &str, object: &JsoncObject) -> String {
+    let outer = line_indent(text, object.open);
+    object
+        .members
+        .first()
+        .filter(|first| text[object.open..first.key_start].contains('\n'))
+        .and_then(|first| line_indent(text, first.key_start).strip_prefix(outer))
+        .filter(|unit| !unit.is_empty())
+        .unwrap_or("  ")
+        .to_string()
+}
+
+/// Leading whitespace of the line containing `pos`
+fn line_indent(text: &str, pos: usize) -> &str {
+    let line_start = text[..pos].rfind('\n').map_or(0, |i| i + 1);
+    let line = &text[line_start..pos];
+    &line[..line.len() - line.trim_start().len()]
+}
+
+/// Skip whitespace and comments
+fn skip_trivia(bytes: &[u8], mut i: usize) -> usize {
+    loop {
+        while i < bytes.len() && bytes[i].is_ascii_whitespace() {
+            i += 1;
+        }
+        if bytes[i..].starts_with(b"//") {
+            while i < bytes.len() && bytes[i] != b'\n' {
+                i += 1;
+            }
+        } else if bytes[i..].starts_with(b"/*") {
+            i = bytes[i + 2..]
+                .windows(2)
+                .position(|w| w == b"*/")
+                .map_or(bytes.len(), |p| i + 2 + p + 2);
+        } else {
+            return i;
+        }
+    }
+}
+
+/// End of the string starting at `i`
+fn scan_jsonc_string(bytes: &[u8], mut i: usize) -> Result<usize> {
+    i += 1;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'\\' => i += 2,
+            b'"' => return Ok(i + 1),
+            _ => i += 1,
+        }
+    }
+    bail!("Unterminated string in config file")
+}
+
+/// End of the value starting at `i`
+fn skip_jsonc_value(bytes: &[u8], mut i: usize) -> Result<usize> {
+    match bytes.get(i) {
+        Some(b'"') => scan_jsonc_string(bytes, i),
+        Some(b'{') | Some(b'[') => {
+            let mut depth = 0usize;
+            loop {
+                i = skip_trivia(bytes, i);
+                match bytes.get(i) {
+                    Some(b'"') => i = scan_jsonc_string(bytes, i)?,
+                    Some(b'{') | Some(b'[') => {
+                        depth += 1;
+                        i += 1;
+                    }
+                    Some(b'}') | Some(b']') => {
+                        depth -= 1;
+                        i += 1;
+                        if depth == 0 {
+                            return Ok(i);
+                        }
+                    }
+                    Some(_) => i += 1,
+                    None => bail!("Unterminated object or array in config file"),
+                }
+            }
+        }
+        Some(_) => {
+            let end = bytes[i..]
+                .iter()
+                .position(|b| b",}]/".contains(b) || b.is_ascii_whitespace())
+                .map_or(bytes.len(), |p| i + p);
+            if end == i {
+                bail!("Expected a value at byte {} of config file", i);
+            }
+            Ok(end)
+        }
+        None => bail!("Unexpected end of config file"),
+    }
+}
+
+/// Parse the members of the object whose `{` is at `open`
+fn parse_jsonc_object(text: &str, open: usize) -> Result<JsoncObject> {
+    let bytes = text.as_bytes();
+    let mut members = Vec::new();
+    let mut i = skip_trivia(bytes, open + 1);
+    loop {
+        match bytes.get(i) {
+            Some(b'}') => {
+                return Ok(JsoncObject {
+                    open,
+                    close: i,
+                    members,
+                })
+            }
+            Some(b'"') => {
+                let key_end = scan_jsonc_string(bytes, i)?;
+                let key: String = serde_json::from_str(&text[i..key_end])?;
+                let colon = skip_trivia(bytes, key_end);
+                if bytes.get(colon) != Some(&b':') {
+                    bail!("Expected ':' after key {} in config file", key);
+                }
+                let value_start = skip_trivia(bytes, colon + 1);
+                let value_end = skip_jsonc_value(bytes, value_start)?;
+                members.push(JsoncMember {
+                    key,
+                    key_start: i,
+                    value_start,
+                    value_end,
+                });
+                i = skip_trivia(bytes, value_end);
+                if bytes.get(i) == Some(&b',') {
+                    i = skip_trivia(bytes, i + 1);
+                }
+            }
+            _ => bail!("Expected a key at byte {} of config file", i),
+        }
+    }
+}
+
 /// Export result
 #[derive(Debug, Clone)]
 pub struct ExportResult {
@@ -417,6 +1472,7 @@ mod tests {
                 command: command.to_string(),
                 args: args.into_iter().map(String::from).collect(),
                 env: HashMap::new(),
+                cwd: None,
             },
         }
     }
@@ -437,6 +1493,40 @@ mod tests {
             .contains_key("io.github.github/github-mcp-server"));
     }
 
+    #[test]
+    fn test_gateway_socket_export_uses_stdio_bridge() {
+        let endpoint = GatewayEndpoint::UnixSocket {
+            path: PathBuf::from("/run/user/1000/mcpmux.sock"),
+            bridge_command: "/usr/bin/mcpmux".to_string(),
+            client_id: "mcpmux-bridge-vscode-mcp".to_string(),
+        };
+        let bridge_args = json!([
+            STDIO_BRIDGE_FLAG,
+            "--client-id",
+            "mcpmux-bridge-vscode-mcp",
+            "--socket",
+            "/run/user/1000/mcpmux.sock"
+        ]);
+
+        let content = ConfigExporter::new()
+            .export_gateway(ConfigFormat::VsCode, "mcpmux", &endpoint)
+            .unwrap();
+        let value: Value = serde_json::from_str(&content).unwrap();
+        assert_eq!(
+            value["servers"]["mcpmux"],
+            json!({ "type": "stdio", "command": "/usr/bin/mcpmux", "args": bridge_args })
+        );
+
+        let content = ConfigExporter::new()
+            .export_gateway(ConfigFormat::ClaudeDesktop, "mcpmux", &endpoint)
+            .unwrap();
+        let value: Value = serde_json::from_str(&content).unwrap();
+        assert_eq!(
+            value["mcpServers"]["mcpmux"],
+            json!({ "command": "/usr/bin/mcpmux", "args": bridge_args })
+        );
+    }
+
     #[test]
     fn test_continue_config() {
         let servers = vec![create_test_resolved_server(
@@ -472,6 +1562,244 @@ mod tests {
             .contains_key("io.github.modelcontextprotocol/memory"));
     }
 
+    fn create_test_http_server(id: &str, url: &str) -> ResolvedServer {
+        ResolvedServer {
+            server_id: id.to_string(),
+            transport: ResolvedTransport::Http {
+                url: url.to_string(),
+                headers: HashMap::from([("Authorization".to_string(), "Bearer t".to_string())]),
+            },
+        }
+    }
+
+    #[test]
+    fn test_new_formats_http_keys() {
+        let servers = vec![create_test_http_server("remote", "https://example.com/mcp")];
+        let exporter = ConfigExporter::new();
+
+        let cases = [
+            (ConfigFormat::Windsurf, "/mcpServers/remote/serverUrl"),
+            (ConfigFormat::Zed, "/context_servers/remote/url"),
+            (ConfigFormat::GeminiCli, "/mcpServers/remote/httpUrl"),
+            (ConfigFormat::ClaudeCode, "/mcpServers/remote/url"),
+            (ConfigFormat::VsCode, "/servers/remote/url"),
+        ];
+        for (format, pointer) in cases {
+            let value: Value =
+                serde_json::from_str(&exporter.export(format, &servers).unwrap()).unwrap();
+            assert_eq!(
+                value.pointer(pointer),
+                Some(&Value::from("https://example.com/mcp")),
+                "{:?}",
+                format
+            );
+        }
+
+        let value: Value =
+            serde_json::from_str(&exporter.export(ConfigFormat::VsCode, &servers).unwrap())
+                .unwrap();
+        assert_eq!(
+            value.pointer("/servers/remote/type"),
+            Some(&Value::from("http"))
+        );
+    }
+
+    #[test]
+    fn test_codex_toml_export() {
+        let servers = vec![
+            create_test_resolved_server("memory", "npx", vec!["-y", "server-memory"]),
+            create_test_http_server("remote", "https://example.com/mcp"),
+        ];
+
+        let content = ConfigExporter::new()
+            .export(ConfigFormat::CodexCli, &servers)
+            .unwrap();
+        let table: toml::Table = toml::from_str(&content).unwrap();
+
+        let memory = &table["mcp_servers"]["memory"];
+        assert_eq!(memory["command"].as_str(), Some("npx"));
+        assert!(memory.get("cwd").is_none());
+        let remote = &table["mcp_servers"]["remote"];
+        assert_eq!(
+            remote["http_headers"]["Authorization"].as_str(),
+            Some("Bearer t")
+        );
+    }
+
+    #[test]
+    fn test_working_directory_exported_where_supported() {
+        let mut server = create_test_resolved_server("local", "node", vec!["index.js"]);
+        if let ResolvedTransport::Stdio { cwd, .. } = &mut server.transport {
+            *cwd = Some("/srv/checkout".to_string());
+        }
+        let exporter = ConfigExporter::new();
+
+        let gemini = serde_json::to_value(exporter.to_gemini(&[server])).unwrap();
+        assert_eq!(gemini["mcpServers"]["local"]["cwd"], "/srv/checkout");
+    }
+
+    #[test]
+    fn test_merge_into_keeps_unrelated_keys_and_comments() {
+        let existing = r#"{
+    // editor settings
+    "theme": "dark",
+    "context_servers": {
+        "other": { "source": "custom", "command": "other", "args": [] },
+    }
+}
+"#;
+        let servers = vec![create_test_resolved_server("memory", "npx", vec![])];
+
+        let merged = ConfigExporter::new()
+            .merge_into(ConfigFormat::Zed, existing, &servers)
+            .unwrap();
+
+        assert!(merged.starts_with("{\n    // editor settings\n    \"theme\": \"dark\","));
+        assert!(
+            merged.contains(r#""other": { "source": "custom", "command": "other", "args": [] },"#)
+        );
+        assert!(merged.contains("\n        \"memory\": {\n            \"source\": \"custom\","));
+    }
+
+    #[test]
+    fn test_merge_into_replaces_existing_entry_only() {
+        let existing = r#"{
+  "mcpServers": {
+    "memory": { "command": "old" }, // keep this note
+    "other": { "command": "other" }
+  }
+}"#;
+        let servers = vec![create_test_resolved_server("memory", "npx", vec![])];
+
+        let merged = ConfigExporter::new()
+            .merge_into(ConfigFormat::Cursor, existing, &servers)
+            .unwrap();
+
+        assert!(!merged.contains("\"old\""));
+        assert!(merged.contains("}, // keep this note\n    \"other\": { \"command\": \"other\" }"));
+    }
+
+    #[test]
+    fn test_merge_into_creates_missing_path() {
+        let existing = "{\n  \"models\": []\n}\n";
+        let servers = vec![create_test_resolved_server("memory", "npx", vec![])];
+
+        let merged = ConfigExporter::new()
+            .merge_into(ConfigFormat::VsCodeContinue, existing, &servers)
+            .unwrap();
+        let value: Value = serde_json::from_str(&merged).unwrap();
+
+        assert_eq!(value["models"], json!([]));
+        assert_eq!(
+            value["experimental"]["modelContextProtocol"]["servers"]["memory"]["transport"]
+                ["command"],
+            "npx"
+        );
+    }
+
+    #[test]
+    fn test_merge_into_codex_toml() {
+        let existing = "# Codex settings\nmodel = \"o3\"\n\n# my server\n[mcp_servers.other]\ncommand = \"other\"\nargs = []\n";
+        let servers = vec![create_test_resolved_server("memory", "npx", vec![])];
+
+        let merged = ConfigExporter::new()
+            .merge_into(ConfigFormat::CodexCli, existing, &servers)
+            .unwrap();
+
+        assert!(merged.starts_with(existing));
+        let table: toml::Table = toml::from_str(&merged).unwrap();
+        assert_eq!(table["model"].as_str(), Some("o3"));
+        assert_eq!(
+            table["mcp_servers"]["memory"]["command"].as_str(),
+            Some("npx")
+        );
+    }
+
+    #[test]
+    fn test_remove_jsonc_members() {
+        let key = ["mcpServers"];
+        let existing =
+            "{\n  \"mcpServers\": {\n    \"a\": {},\n    \"b\": {},\n    \"c\": {}\n  }\n}\n";
+
+        let removed = remove_jsonc_members(existing, &key, &["b".to_string()]).unwrap();
+        assert_eq!(
+            removed,
+            "{\n  \"mcpServers\": {\n    \"a\": {},\n    \"c\": {}\n  }\n}\n"
+        );
+        let removed = remove_jsonc_members(existing, &key, &["c".to_string()]).unwrap();
+        assert_eq!(
+            removed,
+            "{\n  \"mcpServers\": {\n    \"a\": {},\n    \"b\": {}\n  }\n}\n"
+        );
+
+        let inline = r#"{ "mcpServers": { "a": 1, "b": 2, "c": 3 } }"#;
+        let removed =
+            remove_jsonc_members(inline, &key, &["a".to_string(), "c".to_string()]).unwrap();
+        assert_eq!(removed, r#"{ "mcpServers": { "b": 2 } }"#);
+        let removed = remove_jsonc_members(
+            inline,
+            &key,
+            &["a".to_string(), "b".to_string(), "c".to_string()],
+        )
+        .unwrap();
+        assert_eq!(removed, r#"{ "mcpServers": {} }"#);
+    }
+
+    #[test]
+    fn test_write_detects_concurrent_edit() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("settings.json");
+        std::fs::write(&path, "{ \"theme\": \"dark\" }").unwrap();
+        let servers = vec![create_test_resolved_server("memory", "npx", vec![])];
+        let exporter = ConfigExporter::new();
+
+        let plan = exporter
+            .plan_write(ConfigFormat::GeminiCli, &path, &servers)
+            .unwrap();
+        assert!(plan.changed);
+        assert!(plan.diff.contains("+  \"mcpServers\": {"));
+
+        std::fs::write(&path, "{ \"theme\": \"light\" }").unwrap();
+        assert!(write_config_file(&plan).is_err());
+
+        let plan = exporter
+            .plan_write(ConfigFormat::GeminiCli, &path, &servers)
+            .unwrap();
+        let backup = write_config_file(&plan).unwrap().unwrap();
+        assert_eq!(
+            std::fs::read_to_string(backup).unwrap(),
+            "{ \"theme\": \"light\" }"
+        );
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), plan.content);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_write_keeps_file_permissions() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let dir = tempfile::tempdir().unwrap();
+        let servers = vec![create_test_resolved_server("memory", "npx", vec![])];
+        let exporter = ConfigExporter::new();
+        let mode = |path: &Path| std::fs::metadata(path).unwrap().permissions().mode() & 0o777;
+
+        let existing = dir.path().join("settings.json");
+        std::fs::write(&existing, "{}").unwrap();
+        std::fs::set_permissions(&existing, std::fs::Permissions::from_mode(0o640)).unwrap();
+        let plan = exporter
+            .plan_write(ConfigFormat::GeminiCli, &existing, &servers)
+            .unwrap();
+        write_config_file(&plan).unwrap();
+        assert_eq!(mode(&existing), 0o640);
+
+        let created = dir.path().join("new").join("settings.json");
+        let plan = exporter
+            .plan_write(ConfigFormat::GeminiCli, &created, &servers)
+            .unwrap();
+        write_config_file(&plan).unwrap();
+        assert_eq!(mode(&created), 0o600);
+    }
+
     #[test]
     fn test_resolve_placeholders() {
         let template = "https://api.example.com/${input:api_key}/v1";
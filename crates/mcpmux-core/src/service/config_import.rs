@@ -0,0 +1,916 @@
+//! Config import engine for reading other clients' MCP configuration files.
+//!
+//! Reads the servers configured in:
+//! - Cursor (mcp.json)
+//! - VS Code (mcp.json, or the `mcp` block in settings.json)
+//! - VS Code Continue (config.json)
+//! - Claude Desktop (claude_desktop_config.json)
+//!
+//! Entries are parsed into `UserServerEntry`/`ServerDefinition`. Servers that
+//! match a registry entry are imported as that registry server; literal
+//! secrets in `env`/`headers` (or in the overrides of a registry match) are
+//! lifted into input values (stored encrypted) and replaced by
+//! `${input:...}` placeholders.
+
+use super::{ConfigExporter, ConfigFormat, ConfigWritePlan, GatewayEndpoint};
+use crate::domain::{
+    InputDefinition, ServerDefinition, ServerSource, TransportConfig, UserServerEntry,
+    UserServerMetadata,
+};
+use anyhow::{anyhow, bail, Context, Result};
+use lazy_static::lazy_static;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use tracing::warn;
+
+lazy_static! {
+    static ref VSCODE_INPUT_REGEX: Regex = Regex::new(r"\$\{input:([^}]+)\}").unwrap();
+    static ref PLACEHOLDER_REGEX: Regex = Regex::new(r"\$\{input:([A-Z_][A-Z0-9_]*)\}").unwrap();
+}
+
+/// Where each client keeps its server map (JSON pointers, tried in order)
+const SERVER_CONTAINERS: &[&str] = &[
+    "/mcpServers",
+    "/servers",
+    "/mcp/servers",
+    "/experimental/modelContextProtocol/servers",
+];
+
+/// Key/header name parts that mark a value as a secret
+const SECRET_KEY_PARTS: &[&str] = &[
+    "TOKEN",
+    "SECRET",
+    "PASSWORD",
+    "PASSWD",
+    "PAT",
+    "KEY",
+    "APIKEY",
+    "AUTH",
+    "AUTHORIZATION",
+    "CREDENTIAL",
+    "CREDENTIALS",
+    "COOKIE",
+];
+
+/// Well-known token prefixes, caught even under innocuous key names
+const SECRET_VALUE_PREFIXES: &[&str] = &[
+    "ghp_",
+    "gho_",
+    "github_pat_",
+    "glpat-",
+    "sk-",
+    "xoxb-",
+    "xoxp-",
+    "AKIA",
+];
+
+/// Name of the entry pointing a client at the gateway
+const GATEWAY_ENTRY_NAME: &str = "mcpmux";
+
+/// Client whose configuration can be imported
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ImportClient {
+    Cursor,
+    VsCode,
+    VsCodeContinue,
+    ClaudeDesktop,
+}
+
+impl ImportClient {
+    /// All importable clients
+    pub const ALL: [ImportClient; 4] = [
+        ImportClient::Cursor,
+        ImportClient::VsCode,
+        ImportClient::VsCodeContinue,
+        ImportClient::ClaudeDesktop,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ImportClient::Cursor => "cursor",
+            ImportClient::VsCode => "vscode",
+            ImportClient::VsCodeContinue => "continue",
+            ImportClient::ClaudeDesktop => "claude_desktop",
+        }
+    }
+
+    pub fn parse(s: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|c| c.as_str() == s)
+    }
+
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            ImportClient::Cursor => "Cursor",
+            ImportClient::VsCode => "VS Code",
+            ImportClient::VsCodeContinue => "Continue",
+            ImportClient::ClaudeDesktop => "Claude Desktop",
+        }
+    }
+
+    /// Config files this client may keep servers in
+    pub fn default_paths(&self) -> Vec<PathBuf> {
+        match self {
+            ImportClient::Cursor => ConfigFormat::Cursor.default_path().into_iter().collect(),
+            ImportClient::VsCodeContinue => ConfigFormat::VsCodeContinue
+                .default_path()
+                .into_iter()
+                .collect(),
+            ImportClient::ClaudeDesktop => ConfigFormat::ClaudeDesktop
+                .default_path()
+                .into_iter()
+                .collect(),
+            ImportClient::VsCode => ConfigFormat::VsCode
+                .default_path()
+                .map(|mcp| vec![mcp.with_file_name("settings.json"), mcp])
+                .unwrap_or_default(),
+        }
+    }
+
+    /// Export format whose server entries this client reads
+    pub fn config_format(&self) -> ConfigFormat {
+        match self {
+            ImportClient::Cursor => ConfigFormat::Cursor,
+            ImportClient::VsCode => ConfigFormat::VsCode,
+            ImportClient::VsCodeContinue => ConfigFormat::VsCodeContinue,
+            ImportClient::ClaudeDesktop => ConfigFormat::ClaudeDesktop,
+        }
+    }
+
+    /// Whether the client can reach the gateway at `endpoint`
+    pub fn supports(&self, endpoint: &GatewayEndpoint) -> bool {
+        // Claude Desktop only launches stdio servers
+        !matches!(
+            (self, endpoint),
+            (ImportClient::ClaudeDesktop, GatewayEndpoint::Http { .. })
+        )
+    }
+}
+
+/// A server read from a client config, ready to install
+#[derive(Debug, Clone)]
+pub struct ImportCandidate {
+    /// Key of the server in the client config
+    pub name: String,
+    /// Registry definition when recognised, otherwise built from the entry
+    pub definition: ServerDefinition,
+    /// Values for the definition's inputs (secrets included)
+    pub input_values: HashMap<String, String>,
+    /// Env vars the registry definition doesn't set (registry matches only)
+    pub env_overrides: HashMap<String, String>,
+    /// Args beyond the registry definition's (registry matches only)
+    pub args_append: Vec<String>,
+    /// Headers the registry definition doesn't set (registry matches only)
+    pub extra_headers: HashMap<String, String>,
+    /// Whether the entry was recognised as a registry server
+    pub registry_match: bool,
+    /// Inputs holding secrets that were literals in the client config
+    pub secret_inputs: Vec<String>,
+}
+
+impl ImportCandidate {
+    /// Whether the entry is the gateway itself (already pointed at McpMux)
+    pub fn points_at(&self, gateway_url: &str) -> bool {
+        if self.name.eq_ignore_ascii_case(GATEWAY_ENTRY_NAME) {
+            return true;
+        }
+        match &self.definition.transport {
+            TransportConfig::Http { url, .. } => url.starts_with(gateway_url.trim_end_matches('/')),
+            TransportConfig::Stdio { .. } => false,
+        }
+    }
+}
+
+/// Parse a client config into import candidates.
+///
+/// Accepts JSON with comments and trailing commas (VS Code settings.json).
+/// Entries that don't parse are skipped with a warning.
+pub fn parse_client_config(
+    content: &str,
+    client: ImportClient,
+    space_id: &str,
+    path: &Path,
+    registry: &[ServerDefinition],
+) -> Result<Vec<ImportCandidate>> {
+    let root: Value = serde_json::from_str(&strip_jsonc(content))
+        .with_context(|| format!("Invalid JSON in {}", path.display()))?;
+    // VS Code declares prompted inputs next to its servers
+    let vscode_inputs = ["/inputs", "/mcp/inputs"]
+        .iter()
+        .find_map(|p| root.pointer(p).and_then(|i| i.as_array()))
+        .cloned()
+        .unwrap_or_default();
+
+    let mut candidates = Vec::new();
+    for (name, raw) in server_entries(&root) {
+        let raw = raw
+            .get("transport")
+            .filter(|t| t.is_object())
+            .unwrap_or(&raw);
+        let mut entry: UserServerEntry = match serde_json::from_value(raw.clone()) {
+            Ok(entry) => entry,
+            Err(e) => {
+                warn!(
+                    "[ConfigImport] Skipping '{}' in {}: {}",
+                    name,
+                    path.display(),
+                    e
+                );
+                continue;
+            }
+        };
+        if entry.command.is_none() && entry.url.is_none() {
+            warn!(
+                "[ConfigImport] Skipping '{}' in {}: no command or url",
+                name,
+                path.display()
+            );
+            continue;
+        }
+        convert_vscode_inputs(&mut entry, &vscode_inputs);
+
+        let candidate = match match_registry(&entry, registry) {
+            Some(definition) => from_registry(name, &entry, definition),
+            None => {
+                let (input_values, secret_inputs) = lift_secrets(&mut entry);
+                let mut definition =
+                    entry.to_server_definition(&name, space_id, path.to_path_buf());
+                definition.source = ServerSource::Imported {
+                    client: client.as_str().to_string(),
+                };
+                ImportCandidate {
+                    definition,
+                    name,
+                    input_values,
+                    env_overrides: HashMap::new(),
+                    args_append: Vec::new(),
+                    extra_headers: HashMap::new(),
+                    registry_match: false,
+                    secret_inputs,
+                }
+            }
+        };
+        candidates.push(candidate);
+    }
+
+    Ok(candidates)
+}
+
+/// Plan rewriting the client config at `path` so the imported servers are
+/// replaced by a single entry for the gateway, for [`super::write_config_file`].
+/// Other settings, servers and comments are kept.
+pub fn plan_gateway_rewrite(
+    path: &Path,
+    client: ImportClient,
+    imported: &[String],
+    endpoint: &GatewayEndpoint,
+) -> Result<ConfigWritePlan> {
+    if !client.supports(endpoint) {
+        bail!(
+            "{} can only launch local servers; enable the gateway socket to point it at McpMux",
+            client.display_name()
+        );
+    }
+    let content = std::fs::read_to_string(path)
+        .with_context(|| format!("Failed to read {}", path.display()))?;
+    let root: Value = serde_json::from_str(&strip_jsonc(&content))
+        .with_context(|| format!("Invalid JSON in {}", path.display()))?;
+    let pointer = SERVER_CONTAINERS
+        .iter()
+        .find(|p| root.pointer(p).is_some_and(|v| v.is_object()))
+        .ok_or_else(|| anyhow!("No MCP server list found in config"))?;
+    let key: Vec<&str> = pointer.trim_start_matches('/').split('/').collect();
+
+    ConfigExporter::new().plan_gateway_write(
+        client.config_format(),
+        path,
+        &key,
+        GATEWAY_ENTRY_NAME,
+        endpoint,
+        imported,
+    )
+}
+
+/// Server entries by name, from the first server list found
+fn server_entries(root: &Value) -> Vec<(String, Value)> {
+    let Some(container) = SERVER_CONTAINERS.iter().find_map(|p| root.pointer(p)) else {
+        return Vec::new();
+    };
+    match container {
+        Value::Object(map) => map.iter().map(|(k, v)| (k.clone(), v.clone())).collect(),
+        // Older Continue configs keep a list of servers
+        Value::Array(list) => list
+            .iter()
+            .enumerate()
+            .map(|(i, v)| {
+                let name = v
+                    .get("name")
+                    .and_then(|n| n.as_str())
+                    .map(String::from)
+                    .unwrap_or_else(|| format!("server-{}", i + 1));
+                (name, v.clone())
+            })
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+/// Rename VS Code `${input:id}` references to McpMux input IDs, carrying over
+/// the prompt description and whether the input is a password.
+fn convert_vscode_inputs(entry: &mut UserServerEntry, vscode_inputs: &[Value]) {
+    let mut inputs = Vec::new();
+    let mut rename = |value: &mut String| {
+        if !VSCODE_INPUT_REGEX.is_match(value) {
+            return;
+        }
+        *value = VSCODE_INPUT_REGEX
+            .replace_all(value, |caps: &regex::Captures| {
+                let id = input_id(&caps[1]);
+                if !inputs.iter().any(|i: &InputDefinition| i.id == id) {
+                    let declared = vscode_inputs
+                        .iter()
+                        .find(|i| i.get("id").and_then(|v| v.as_str()) == Some(&caps[1]));
+                    let secret = declared
+                        .and_then(|i| i.get("password"))
+                        .and_then(|p| p.as_bool())
+                        .unwrap_or(true);
+                    let description = declared
+                        .and_then(|i| i.get("description"))
+                        .and_then(|d| d.as_str())
+                        .map(String::from);
+                    inputs.push(input_definition(&id, description, secret));
+                }
+                format!("${{input:{}}}", id)
+            })
+            .into_owned();
+    };
+
+    entry.command.iter_mut().for_each(&mut rename);
+    entry.url.iter_mut().for_each(&mut rename);
+    entry.args.iter_mut().flatten().for_each(&mut rename);
+    entry
+        .env
+        .iter_mut()
+        .flat_map(|e| e.values_mut())
+        .for_each(&mut rename);
+    entry
+        .headers
+        .iter_mut()
+        .flat_map(|h| h.values_mut())
+        .for_each(&mut rename);
+    add_inputs(entry, inputs);
+}
+
+/// Replace literal secrets in env/headers with placeholders.
+///
+/// Returns the lifted values by input ID, and the IDs.
+fn lift_secrets(entry: &mut UserServerEntry) -> (HashMap<String, String>, Vec<String>) {
+    let mut values = HashMap::new();
+    let mut inputs = Vec::new();
+    for map in entry.env.iter_mut().chain(entry.headers.iter_mut()) {
+        lift_map_secrets(map, &[], &mut values, &mut inputs);
+    }
+
+    let ids = inputs.iter().map(|i| i.id.clone()).collect();
+    add_inputs(entry, inputs);
+    (values, ids)
+}
+
+/// Replace literal secrets in `map` with `${input:...}` placeholders, adding
+/// their values to `values` and their definitions to `inputs`. IDs in
+/// `reserved` or already in `values` are not reused.
+fn lift_map_secrets(
+    map: &mut HashMap<String, String>,
+    reserved: &[String],
+    values: &mut HashMap<String, String>,
+    inputs: &mut Vec<InputDefinition>,
+) {
+    for (key, value) in map.iter_mut() {
+        if value.contains("${") || !is_secret(key, value) {
+            continue;
+        }
+        let base = input_id(key);
+        let mut id = base.clone();
+        let mut n = 1;
+        while reserved.contains(&id) || values.contains_key(&id) {
+            n += 1;
+            id = format!("{}_{}", base, n);
+        }
+        let (prefix, secret) = match value.split_once(' ') {
+            Some((scheme, token)) if scheme.eq_ignore_ascii_case("bearer") => {
+                (format!("{} ", scheme), token.to_string())
+            }
+            _ => (String::new(), value.clone()),
+        };
+        *value = format!("{}${{input:{}}}", prefix, id);
+        values.insert(id.clone(), secret);
+        inputs.push(input_definition(
+            &id,
+            Some(format!("Imported {}", key)),
+            true,
+        ));
+    }
+}
+
+/// Whether an env var or header holds a secret, judged by its name or a
+/// well-known token prefix
+pub(crate) fn is_secret(key: &str, value: &str) -> bool {
+    if value.is_empty() {
+        return false;
+    }
+    let key_is_secret = key
+        .to_ascii_uppercase()
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .any(|part| SECRET_KEY_PARTS.contains(&part));
+    key_is_secret || SECRET_VALUE_PREFIXES.iter().any(|p| value.starts_with(p))
+}
+
+/// Recognise an entry as a registry server: same command and package, or same URL
+fn match_registry<'a>(
+    entry: &UserServerEntry,
+    registry: &'a [ServerDefinition],
+) -> Option<&'a ServerDefinition> {
+    registry.iter().find(|def| match &def.transport {
+        TransportConfig::Stdio { command, args, .. } => {
+            entry.command.as_deref() == Some(command.as_str())
+                && package(entry.args.as_deref().unwrap_or_default()).is_some_and(|p| {
+                    package(args).is_some_and(|r| p == r && !PLACEHOLDER_REGEX.is_match(r))
+                })
+        }
+        TransportConfig::Http { url, .. } => entry
+            .url
+            .as_deref()
+            .is_some_and(|u| u.trim_end_matches('/') == url.trim_end_matches('/')),
+    })
+}
+
+/// The package a launcher runs (first positional arg, without `@version`)
+fn package(args: &[String]) -> Option<&str> {
+    let arg = args.iter().find(|a| !a.starts_with('-'))?;
+    Some(match arg.rfind('@') {
+        Some(i) if i > 0 => &arg[..i],
+        _ => arg,
+    })
+}
+
+/// Import an entry as a registry server, filling its inputs from the entry's
+/// values and keeping what the registry doesn't cover as overrides
+fn from_registry(
+    name: String,
+    entry: &UserServerEntry,
+    definition: &ServerDefinition,
+) -> ImportCandidate {
+    let mut input_values = HashMap::new();
+    let mut env_overrides = HashMap::new();
+    let mut args_append = Vec::new();
+    let mut extra_headers = HashMap::new();
+
+    match &definition.transport {
+        TransportConfig::Stdio { args, env, .. } => {
+            let entry_args = entry.args.clone().unwrap_or_default();
+            for (i, arg) in entry_args.iter().enumerate() {
+                match args.get(i) {
+                    Some(template) => fill_input(template, arg, &mut input_values),
+                    None => args_append.push(arg.clone()),
+                }
+            }
+            for (key, value) in entry.env.iter().flatten() {
+                match env.get(key) {
+                    Some(template) => fill_input(template, value, &mut input_values),
+                    None => {
+                        env_overrides.insert(key.clone(), value.clone());
+                    }
+                }
+            }
+        }
+        TransportConfig::Http { headers, .. } => {
+            for (key, value) in entry.headers.iter().flatten() {
+                match headers.get(key) {
+                    Some(template) => fill_input(template, value, &mut input_values),
+                    None => {
+                        extra_headers.insert(key.clone(), value.clone());
+                    }
+                }
+            }
+        }
+    }
+
+    let declared = &definition.transport.metadata().inputs;
+    let mut secret_inputs: Vec<String> = declared
+        .iter()
+        .filter(|i| i.secret && input_values.contains_key(&i.id))
+        .map(|i| i.id.clone())
+        .collect();
+
+    // Secrets the definition has no input for are kept as inputs too, so
+    // they are stored encrypted rather than as plain overrides
+    let reserved: Vec<String> = declared.iter().map(|i| i.id.clone()).collect();
+    let mut lifted = Vec::new();
+    for map in [&mut env_overrides, &mut extra_headers] {
+        lift_map_secrets(map, &reserved, &mut input_values, &mut lifted);
+    }
+    secret_inputs.extend(lifted.into_iter().map(|i| i.id));
+
+    ImportCandidate {
+        name,
+        definition: definition.clone(),
+        input_values,
+        env_overrides,
+        args_append,
+        extra_headers,
+        registry_match: true,
+        secret_inputs,
+    }
+}
+
+/// If `template` is a single placeholder (with optional fixed text around it)
+/// that `value` fits, record the value for that input
+fn fill_input(template: &str, value: &str, input_values: &mut HashMap<String, String>) {
+    let mut matches = PLACEHOLDER_REGEX.captures_iter(template);
+    let (Some(caps), None) = (matches.next(), matches.next()) else {
+        return;
+    };
+    let whole = caps.get(0).unwrap();
+    let (prefix, suffix) = (&template[..whole.start()], &template[whole.end()..]);
+    if value.contains("${") || value.len() < prefix.len() + suffix.len() {
+        return;
+    }
+    if let Some(inner) = value
+        .strip_prefix(prefix)
+        .and_then(|v| v.strip_suffix(suffix))
+    {
+        input_values.insert(caps[1].to_string(), inner.to_string());
+    }
+}
+
+/// McpMux input ID for a key or VS Code input id (`github-token` → `GITHUB_TOKEN`)
+fn input_id(key: &str) -> String {
+    let id: String = key
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() {
+                c.to_ascii_uppercase()
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    if id.starts_with(|c: char| c.is_ascii_digit()) {
+        format!("_{}", id)
+    } else {
+        id
+    }
+}
+
+fn input_definition(id: &str, description: Option<String>, secret: bool) -> InputDefinition {
+    InputDefinition {
+        id: id.to_string(),
+        label: id.to_string(),
+        r#type: if secret { "password" } else { "text" }.to_string(),
+        required: true,
+        secret,
+        description,
+        default: None,
+        placeholder: None,
+        obtain_url: None,
+        obtain_instructions: None,
+    }
+}
+
+/// Declare inputs explicitly so header placeholders get definitions too
+fn add_inputs(entry: &mut UserServerEntry, inputs: Vec<InputDefinition>) {
+    if inputs.is_empty() {
+        return;
+    }
+    let metadata = entry.metadata.get_or_insert(UserServerMetadata {
+        inputs: None,
+        publisher: None,
+    });
+    let declared = metadata.inputs.get_or_insert_with(Vec::new);
+    for input in inputs {
+        if !declared.iter().any(|i| i.id == input.id) {
+            declared.push(input);
+        }
+    }
+}
+
+/// Strip `//` and `/* */` comments and trailing commas (JSONC → JSON)
+fn strip_jsonc(input: &str) -> String {
+    let chars: Vec<char> = input.chars().collect();
+    let mut out = String::with_capacity(input.len());
+    let mut in_string = false;
+    let mut i = 0;
+
+    while i < chars.len() {
+        let c = chars[i];
+        if in_string {
+            out.push(c);
+            if c == '\\' {
+                if let Some(&next) = chars.get(i + 1) {
+                    out.push(next);
+                    i += 1;
+                }
+            } else if c == '"' {
+                in_string = false;
+            }
+            i += 1;
+            continue;
+        }
+
+        match (c, chars.get(i + 1)) {
+            ('"', _) => {
+                in_string = true;
+                out.push(c);
+            }
+            ('/', Some('/')) => {
+                while i < chars.len() && chars[i] != '\n' {
+                    i += 1;
+                }
+                continue;
+            }
+            ('/', Some('*')) => {
+                i += 2;
+                while i < chars.len() && !(chars[i] == '*' && chars.get(i + 1) == Some(&'/')) {
+                    i += 1;
+                }
+                i += 2;
+                continue;
+            }
+            (',', _) => {
+                let next = chars[i + 1..].iter().find(|c| !c.is_whitespace());
+                if !matches!(next, Some('}') | Some(']')) {
+                    out.push(c);
+                }
+            }
+            _ => out.push(c),
+        }
+        i += 1;
+    }
+
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn path() -> PathBuf {
+        PathBuf::from("/home/me/.cursor/mcp.json")
+    }
+
+    fn github_registry() -> ServerDefinition {
+        let mut def = UserServerEntry {
+            command: Some("npx".to_string()),
+            args: Some(vec![
+                "-y".to_string(),
+                "@modelcontextprotocol/server-github".to_string(),
+            ]),
+            env: Some(HashMap::from([(
+                "GITHUB_PERSONAL_ACCESS_TOKEN".to_string(),
+                "${input:GITHUB_PERSONAL_ACCESS_TOKEN}".to_string(),
+            )])),
+            cwd: None,
+            env_file: None,
+            url: None,
+            headers: None,
+            name: Some("GitHub".to_string()),
+            description: None,
+            icon: None,
+            alias: None,
+            auth: None,
+            metadata: None,
+        }
+        .to_server_definition("github", "space_1", path());
+        def.source = ServerSource::Bundled;
+        def
+    }
+
+    #[test]
+    fn test_parse_cursor_config_lifts_secrets() {
+        let content = r#"{
+            "mcpServers": {
+                "linear": {
+                    "url": "https://mcp.linear.app/mcp",
+                    "headers": { "Authorization": "Bearer lin_api_123" }
+                },
+                "notes": {
+                    "command": "node",
+                    "args": ["notes.js"],
+                    "env": { "NOTES_API_KEY": "abc", "LOG_LEVEL": "debug" }
+                }
+            }
+        }"#;
+
+        let candidates =
+            parse_client_config(content, ImportClient::Cursor, "space_1", &path(), &[]).unwrap();
+        assert_eq!(candidates.len(), 2);
+
+        let linear = candidates.iter().find(|c| c.name == "linear").unwrap();
+        assert_eq!(linear.input_values["AUTHORIZATION"], "lin_api_123");
+        match &linear.definition.transport {
+            TransportConfig::Http {
+                headers, metadata, ..
+            } => {
+                assert_eq!(headers["Authorization"], "Bearer ${input:AUTHORIZATION}");
+                assert!(metadata
+                    .inputs
+                    .iter()
+                    .any(|i| i.id == "AUTHORIZATION" && i.secret));
+            }
+            _ => panic!("expected http transport"),
+        }
+
+        let notes = candidates.iter().find(|c| c.name == "notes").unwrap();
+        assert_eq!(notes.secret_inputs, vec!["NOTES_API_KEY".to_string()]);
+        match &notes.definition.transport {
+            TransportConfig::Stdio { env, .. } => {
+                assert_eq!(env["NOTES_API_KEY"], "${input:NOTES_API_KEY}");
+                assert_eq!(env["LOG_LEVEL"], "debug");
+            }
+            _ => panic!("expected stdio transport"),
+        }
+    }
+
+    #[test]
+    fn test_parse_vscode_settings_with_inputs_and_comments() {
+        let content = r#"{
+            // Editor settings
+            "editor.fontSize": 14,
+            "mcp": {
+                "inputs": [
+                    { "type": "promptString", "id": "search-key", "description": "Search API key", "password": true }
+                ],
+                /* Servers */
+                "servers": {
+                    "search": {
+                        "type": "stdio",
+                        "command": "uvx",
+                        "args": ["search-mcp", "--key", "${input:search-key}"],
+                    },
+                },
+            },
+        }"#;
+
+        let candidates =
+            parse_client_config(content, ImportClient::Cursor, "space_1", &path(), &[]).unwrap();
+        assert_eq!(candidates.len(), 1);
+        match &candidates[0].definition.transport {
+            TransportConfig::Stdio { args, metadata, .. } => {
+                assert_eq!(args[2], "${input:SEARCH_KEY}");
+                let input = metadata
+                    .inputs
+                    .iter()
+                    .find(|i| i.id == "SEARCH_KEY")
+                    .unwrap();
+                assert!(input.secret);
+                assert_eq!(input.description.as_deref(), Some("Search API key"));
+            }
+            _ => panic!("expected stdio transport"),
+        }
+    }
+
+    #[test]
+    fn test_registry_match_fills_inputs() {
+        let content = r#"{
+            "mcpServers": {
+                "gh": {
+                    "command": "npx",
+                    "args": ["-y", "@modelcontextprotocol/server-github@latest"],
+                    "env": { "GITHUB_PERSONAL_ACCESS_TOKEN": "ghp_secret", "DEBUG": "1" }
+                }
+            }
+        }"#;
+
+        let registry = vec![github_registry()];
+        let candidates =
+            parse_client_config(content, ImportClient::Cursor, "space_1", &path(), &registry)
+                .unwrap();
+        let gh = &candidates[0];
+        assert!(gh.registry_match);
+        assert_eq!(gh.definition.id, "github");
+        assert_eq!(
+            gh.input_values["GITHUB_PERSONAL_ACCESS_TOKEN"],
+            "ghp_secret"
+        );
+        assert_eq!(gh.env_overrides["DEBUG"], "1");
+        assert_eq!(
+            gh.secret_inputs,
+            vec!["GITHUB_PERSONAL_ACCESS_TOKEN".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_plan_gateway_rewrite_keeps_comments() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("mcp.json");
+        let content = r#"{
+  // my servers
+  "theme": "dark",
+  "mcpServers": {
+    "gh": { "command": "npx", "args": [] },
+    "kept": { "command": "node", "args": [] } // local
+  }
+}
+"#;
+        std::fs::write(&path, content).unwrap();
+
+        let endpoint = GatewayEndpoint::Http {
+            url: "http://localhost:45818/mcp".to_string(),
+        };
+        let plan =
+            plan_gateway_rewrite(&path, ImportClient::Cursor, &["gh".to_string()], &endpoint)
+                .unwrap();
+        assert!(plan.content.contains("// my servers"));
+        assert!(plan.content.contains("} // local"));
+        let root: Value = serde_json::from_str(&strip_jsonc(&plan.content)).unwrap();
+        assert_eq!(root["theme"], "dark");
+        assert!(root["mcpServers"].get("gh").is_none());
+        assert!(root["mcpServers"].get("kept").is_some());
+        assert_eq!(
+            root["mcpServers"]["mcpmux"]["url"],
+            "http://localhost:45818/mcp"
+        );
+    }
+
+    #[test]
+    fn test_claude_desktop_rewrite_needs_stdio_bridge() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("claude_desktop_config.json");
+        std::fs::write(
+            &path,
+            r#"{ "mcpServers": { "gh": { "command": "npx", "args": [] } } }"#,
+        )
+        .unwrap();
+        let imported = ["gh".to_string()];
+
+        let http = GatewayEndpoint::Http {
+            url: "http://localhost:45818/mcp".to_string(),
+        };
+        assert!(
+            plan_gateway_rewrite(&path, ImportClient::ClaudeDesktop, &imported, &http).is_err()
+        );
+
+        let bridge = GatewayEndpoint::UnixSocket {
+            path: PathBuf::from("/tmp/mcpmux.sock"),
+            bridge_command: "/usr/bin/mcpmux".to_string(),
+            client_id: "client_1".to_string(),
+        };
+        let plan =
+            plan_gateway_rewrite(&path, ImportClient::ClaudeDesktop, &imported, &bridge).unwrap();
+        let root: Value = serde_json::from_str(&plan.content).unwrap();
+        assert!(root["mcpServers"].get("gh").is_none());
+        assert_eq!(root["mcpServers"]["mcpmux"]["command"], "/usr/bin/mcpmux");
+    }
+
+    #[test]
+    fn test_registry_match_lifts_secret_overrides() {
+        let content = r#"{
+            "mcpServers": {
+                "gh": {
+                    "command": "npx",
+                    "args": ["-y", "@modelcontextprotocol/server-github"],
+                    "env": {
+                        "GITHUB_PERSONAL_ACCESS_TOKEN": "${input:GH}",
+                        "SENTRY_AUTH_TOKEN": "sntrys_123",
+                        "DEBUG": "1"
+                    }
+                }
+            }
+        }"#;
+
+        let registry = vec![github_registry()];
+        let candidates =
+            parse_client_config(content, ImportClient::Cursor, "space_1", &path(), &registry)
+                .unwrap();
+        let gh = &candidates[0];
+        assert_eq!(gh.env_overrides["DEBUG"], "1");
+        assert_eq!(
+            gh.env_overrides["SENTRY_AUTH_TOKEN"],
+            "${input:SENTRY_AUTH_TOKEN}"
+        );
+        assert_eq!(gh.input_values["SENTRY_AUTH_TOKEN"], "sntrys_123");
+        assert_eq!(gh.secret_inputs, vec!["SENTRY_AUTH_TOKEN".to_string()]);
+    }
+
+    #[test]
+    fn test_unmatched_entries_are_marked_imported() {
+        let content = r#"{ "mcpServers": { "notes": { "command": "node", "args": [] } } }"#;
+        let candidates =
+            parse_client_config(content, ImportClient::Cursor, "space_1", &path(), &[]).unwrap();
+        assert!(matches!(
+            &candidates[0].definition.source,
+            ServerSource::Imported { client } if client == "cursor"
+        ));
+    }
+
+    #[test]
+    fn test_gateway_entry_is_recognised() {
+        let content = r#"{ "servers": { "McpMux": { "type": "http", "url": "http://localhost:45818/mcp" } } }"#;
+        let candidates =
+            parse_client_config(content, ImportClient::Cursor, "space_1", &path(), &[]).unwrap();
+        assert!(candidates[0].points_at("http://localhost:45818"));
+    }
+}
@@ -7,18 +7,24 @@ mod cimd_fetcher;
 mod client_install;
 mod client_service;
 mod config_export;
+mod config_import;
 pub mod gateway_port_service;
 mod permission_service;
 mod registry_api_client;
 mod server_discovery;
 mod server_log_manager;
+mod space_bundle;
+mod space_secret_service;
 mod space_service;
+mod space_template;
+mod workspace_manifest;
 
 pub use app_settings_service::{keys, AppSettingsService};
 pub use cimd_fetcher::*;
 pub use client_install::{cursor_deep_link, vscode_deep_link};
 pub use client_service::*;
 pub use config_export::*;
+pub use config_import::*;
 pub use gateway_port_service::{
     allocate_dynamic_port, is_port_available, GatewayPortService, PortAllocationError,
     PortResolution, DEFAULT_GATEWAY_PORT,
@@ -27,4 +33,8 @@ pub use permission_service::*;
 pub use registry_api_client::*;
 pub use server_discovery::*;
 pub use server_log_manager::*;
+pub use space_bundle::*;
+pub use space_secret_service::*;
 pub use space_service::*;
+pub use space_template::*;
+pub use workspace_manifest::*;
@@ -3,14 +3,16 @@
 //! This service computes which features a client can access based on their
 //! granted feature sets and the feature set composition rules.
 
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 
 use anyhow::Result;
 use tracing::{debug, warn};
 use uuid::Uuid;
 
-use crate::domain::{FeatureSet, FeatureSetType, MemberMode, MemberType, ServerFeature};
+use crate::domain::{
+    FeatureRule, FeatureSet, FeatureSetType, MemberMode, MemberType, ServerFeature,
+};
 use crate::repository::{
     FeatureSetRepository, InboundMcpClientRepository, ServerFeatureRepository,
 };
@@ -54,6 +56,38 @@ impl ResolvedPermissions {
     }
 }
 
+/// State carried through one permission resolution
+#[derive(Default)]
+struct Resolution {
+    /// Feature sets on the current grant's path, to stop circular composition
+    visited: HashSet<String>,
+    /// The space's features, loaded on first use by rule and global members
+    features: Option<SpaceFeatures>,
+}
+
+/// A space's features, indexed for global set members
+struct SpaceFeatures {
+    all: Vec<ServerFeature>,
+    /// Feature IDs by [`ServerFeature::portable_key`]
+    by_portable_key: HashMap<String, Vec<String>>,
+}
+
+impl SpaceFeatures {
+    fn new(all: Vec<ServerFeature>) -> Self {
+        let mut by_portable_key: HashMap<String, Vec<String>> = HashMap::new();
+        for feature in &all {
+            by_portable_key
+                .entry(feature.portable_key())
+                .or_default()
+                .push(feature.id.to_string());
+        }
+        Self {
+            all,
+            by_portable_key,
+        }
+    }
+}
+
 /// Service for resolving permissions
 pub struct PermissionService {
     client_repository: Arc<dyn InboundMcpClientRepository>,
@@ -99,8 +133,10 @@ impl PermissionService {
         }
 
         // Resolve each feature set
+        let mut resolution = Resolution::default();
         for fs_id in &granted_ids {
-            self.resolve_feature_set(fs_id, space_id, &mut result, &mut HashSet::new())
+            resolution.visited.clear();
+            self.resolve_feature_set(fs_id, space_id, &mut result, &mut resolution)
                 .await?;
         }
 
@@ -122,18 +158,18 @@ impl PermissionService {
         feature_set_id: &'a str,
         space_id: &'a str,
         result: &'a mut ResolvedPermissions,
-        visited: &'a mut HashSet<String>,
+        resolution: &'a mut Resolution,
     ) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<()>> + Send + 'a>> {
         Box::pin(async move {
             // Prevent infinite recursion
-            if visited.contains(feature_set_id) {
+            if resolution.visited.contains(feature_set_id) {
                 warn!(
                     feature_set_id = %feature_set_id,
                     "Circular reference detected in feature set composition"
                 );
                 return Ok(());
             }
-            visited.insert(feature_set_id.to_string());
+            resolution.visited.insert(feature_set_id.to_string());
 
             // Get the feature set with members
             let feature_set = match self
@@ -160,7 +196,7 @@ impl PermissionService {
                 }
                 FeatureSetType::Default => {
                     // Resolve members of the Default set
-                    self.resolve_members(&feature_set, space_id, result, visited)
+                    self.resolve_members(&feature_set, space_id, result, resolution)
                         .await?;
                 }
                 FeatureSetType::ServerAll => {
@@ -176,7 +212,7 @@ impl PermissionService {
                 }
                 FeatureSetType::Custom => {
                     // Resolve members recursively
-                    self.resolve_members(&feature_set, space_id, result, visited)
+                    self.resolve_members(&feature_set, space_id, result, resolution)
                         .await?;
                 }
             }
@@ -191,30 +227,37 @@ impl PermissionService {
         feature_set: &'a FeatureSet,
         space_id: &'a str,
         result: &'a mut ResolvedPermissions,
-        visited: &'a mut HashSet<String>,
+        resolution: &'a mut Resolution,
     ) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<()>> + Send + 'a>> {
         Box::pin(async move {
             for member in &feature_set.members {
-                match member.mode {
-                    MemberMode::Include => {
-                        match member.member_type {
-                            MemberType::FeatureSet => {
-                                // Recursively resolve nested feature set
-                                self.resolve_feature_set(
-                                    &member.member_id,
-                                    space_id,
-                                    result,
-                                    visited,
-                                )
-                                .await?;
-                            }
-                            MemberType::Feature => {
-                                // Add individual feature
-                                result.allowed_feature_ids.insert(member.member_id.clone());
-                            }
-                        }
+                match (member.mode, member.member_type) {
+                    (mode, MemberType::Rule) => {
+                        // Match the rule against the space's current features
+                        self.resolve_rule(mode, &member.member_id, space_id, result, resolution)
+                            .await?;
+                    }
+                    (mode, MemberType::Feature) if feature_set.is_global() => {
+                        // Global sets name features by server and feature name
+                        self.resolve_portable_feature(
+                            mode,
+                            &member.member_id,
+                            space_id,
+                            result,
+                            resolution,
+                        )
+                        .await?;
+                    }
+                    (MemberMode::Include, MemberType::FeatureSet) => {
+                        // Recursively resolve nested feature set
+                        self.resolve_feature_set(&member.member_id, space_id, result, resolution)
+                            .await?;
+                    }
+                    (MemberMode::Include, MemberType::Feature) => {
+                        // Add individual feature
+                        result.allowed_feature_ids.insert(member.member_id.clone());
                     }
-                    MemberMode::Exclude => {
+                    (MemberMode::Exclude, _) => {
                         // For exclusions, remove from allowed set
                         result.allowed_feature_ids.remove(&member.member_id);
                     }
@@ -224,6 +267,78 @@ impl PermissionService {
         })
     }
 
+    /// The space's features, loaded once per resolution
+    async fn space_features<'r>(
+        &self,
+        space_id: &str,
+        resolution: &'r mut Resolution,
+    ) -> Result<&'r SpaceFeatures> {
+        let features = match resolution.features.take() {
+            Some(features) => features,
+            None => SpaceFeatures::new(
+                self.server_feature_repository
+                    .list_for_space(space_id)
+                    .await?,
+            ),
+        };
+        Ok(resolution.features.insert(features))
+    }
+
+    /// Apply a rule member against the features currently known in the space
+    async fn resolve_rule(
+        &self,
+        mode: MemberMode,
+        expression: &str,
+        space_id: &str,
+        result: &mut ResolvedPermissions,
+        resolution: &mut Resolution,
+    ) -> Result<()> {
+        let rule = match FeatureRule::parse(expression) {
+            Ok(rule) => rule,
+            Err(e) => {
+                warn!(rule = %expression, error = %e, "Skipping invalid rule member");
+                return Ok(());
+            }
+        };
+        let features = self.space_features(space_id, resolution).await?;
+        for feature in features.all.iter().filter(|f| rule.matches(f)) {
+            let id = feature.id.to_string();
+            match mode {
+                MemberMode::Include => {
+                    result.allowed_feature_ids.insert(id);
+                }
+                MemberMode::Exclude => {
+                    result.allowed_feature_ids.remove(&id);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Apply a global set's feature member to the matching feature in the space
+    async fn resolve_portable_feature(
+        &self,
+        mode: MemberMode,
+        portable_key: &str,
+        space_id: &str,
+        result: &mut ResolvedPermissions,
+        resolution: &mut Resolution,
+    ) -> Result<()> {
+        let features = self.space_features(space_id, resolution).await?;
+        let ids = features.by_portable_key.get(portable_key);
+        for id in ids.into_iter().flatten().cloned() {
+            match mode {
+                MemberMode::Include => {
+                    result.allowed_feature_ids.insert(id);
+                }
+                MemberMode::Exclude => {
+                    result.allowed_feature_ids.remove(&id);
+                }
+            }
+        }
+        Ok(())
+    }
+
     /// Get all allowed features for a client in a space
     pub async fn get_allowed_features(
         &self,
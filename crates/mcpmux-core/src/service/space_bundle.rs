@@ -0,0 +1,770 @@
+//! Space bundles - moving spaces between machines
+//!
+//! A [`SpaceBundle`] holds everything needed to recreate spaces elsewhere:
+//! installed servers with their input values, space secrets, discovered
+//! features, feature sets with their members and, optionally, OAuth refresh
+//! tokens. Bundles are
+//! plaintext here; the storage layer seals them with a passphrase, since the
+//! machine-local master key can't travel with them.
+//!
+//! Importing remaps every ID, so a bundle can be imported more than once and
+//! into spaces that already have servers.
+
+use anyhow::{anyhow, bail, Result};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeSet, HashMap, HashSet};
+use std::sync::Arc;
+use tracing::{info, warn};
+use uuid::Uuid;
+
+use crate::domain::{
+    referenced_secrets, Credential, CredentialType, FeatureSet, FeatureSetMember, FeatureSetType,
+    FeatureType, InstalledServer, MemberType, OutboundOAuthRegistration, ServerFeature, Space,
+    SpaceSecret,
+};
+use crate::repository::{
+    in_transaction, CredentialRepository, FeatureSetRepository, InstalledServerRepository,
+    OutboundOAuthRepository, ServerFeatureRepository, SpaceRepository, SpaceSecretRepository,
+    TransactionManager, TransactionRepositories,
+};
+use crate::service::config_import::is_secret;
+
+/// Current bundle format version
+pub const SPACE_BUNDLE_VERSION: u32 = 1;
+
+/// Spaces exported for import on another machine
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SpaceBundle {
+    pub version: u32,
+    pub exported_at: DateTime<Utc>,
+    pub spaces: Vec<BundledSpace>,
+}
+
+/// One space and everything in it
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BundledSpace {
+    pub space: Space,
+    /// Installed servers, with decrypted input values
+    pub servers: Vec<InstalledServer>,
+    /// Discovered features (referenced by feature set members)
+    #[serde(default)]
+    pub features: Vec<ServerFeature>,
+    /// Feature sets with their members
+    #[serde(default)]
+    pub feature_sets: Vec<FeatureSet>,
+    /// OAuth registrations with refresh tokens (only when requested)
+    #[serde(default)]
+    pub oauth: Vec<BundledOAuth>,
+    /// Space secrets referenced as `${secret:name}`
+    #[serde(default)]
+    pub secrets: Vec<BundledSecret>,
+}
+
+impl BundledSpace {
+    /// Drop the values of secret inputs, secret-looking env overrides and
+    /// headers, space secrets and any OAuth tokens. Space secrets keep their
+    /// names so they show up in [`Self::missing_secrets`].
+    pub fn strip_secrets(&mut self) {
+        let literal_secret =
+            |key: &String, value: &mut String| !value.contains("${") && is_secret(key, value);
+        for server in &mut self.servers {
+            server.env_overrides.retain(|k, v| !literal_secret(k, v));
+            server.extra_headers.retain(|k, v| !literal_secret(k, v));
+            server.oauth_connected = false;
+            let Some(definition) = server.get_definition() else {
+                continue;
+            };
+            for input in &definition.transport.metadata().inputs {
+                if input.secret {
+                    server.input_values.remove(&input.id);
+                }
+            }
+        }
+        for secret in &mut self.secrets {
+            secret.value.clear();
+        }
+        self.oauth.clear();
+    }
+
+    /// Names of the space secrets the servers reference, plus any bundled
+    pub fn secret_names(&self) -> BTreeSet<String> {
+        let mut names: BTreeSet<String> = self.secrets.iter().map(|s| s.name.clone()).collect();
+        for server in &self.servers {
+            let values = server
+                .input_values
+                .values()
+                .chain(server.env_overrides.values())
+                .chain(server.extra_headers.values());
+            for value in values {
+                names.extend(referenced_secrets(value).map(str::to_string));
+            }
+        }
+        names
+    }
+
+    /// Space secrets the bundle has no value for; they need setting in the
+    /// space before the servers that reference them can start
+    pub fn missing_secrets(&self) -> Vec<String> {
+        let present: HashSet<&str> = self
+            .secrets
+            .iter()
+            .filter(|s| !s.value.is_empty())
+            .map(|s| s.name.as_str())
+            .collect();
+        self.secret_names()
+            .into_iter()
+            .filter(|name| !present.contains(name.as_str()))
+            .collect()
+    }
+}
+
+/// A space secret and its value (empty once stripped)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BundledSecret {
+    pub name: String,
+    #[serde(default)]
+    pub value: String,
+}
+
+/// An OAuth registration and the refresh token issued under it
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BundledOAuth {
+    pub registration: OutboundOAuthRegistration,
+    pub refresh_token: String,
+    pub refresh_token_expires_at: Option<DateTime<Utc>>,
+}
+
+/// What to do with a bundled server whose ID is already installed in the
+/// target space
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ServerConflict {
+    /// Keep the existing server and drop the bundled one
+    #[default]
+    Skip,
+    /// Uninstall the existing server and install the bundled one
+    Replace,
+    /// Install the bundled server under a new server ID
+    Rename,
+}
+
+/// Options for importing a bundle
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct BundleImportOptions {
+    /// Import every bundled space into this existing space instead of
+    /// creating new spaces
+    #[serde(default)]
+    pub target_space_id: Option<Uuid>,
+    #[serde(default)]
+    pub on_conflict: ServerConflict,
+}
+
+/// A server installed under a different ID to avoid a conflict
+#[derive(Debug, Clone, Serialize)]
+pub struct RenamedServer {
+    pub from: String,
+    pub to: String,
+}
+
+/// Outcome of importing one bundled space
+#[derive(Debug, Clone, Serialize)]
+pub struct ImportedSpace {
+    pub space_id: Uuid,
+    pub name: String,
+    /// Whether the space was created by the import
+    pub created: bool,
+    /// Server IDs installed
+    pub servers: Vec<String>,
+    pub renamed: Vec<RenamedServer>,
+    /// Server IDs not installed because they were already present
+    pub skipped: Vec<String>,
+    /// Number of custom feature sets created
+    pub feature_sets: usize,
+    /// Number of OAuth refresh tokens restored
+    pub oauth_tokens: usize,
+    /// Number of space secrets created
+    pub secrets: usize,
+    /// Space secrets referenced by the servers that the space doesn't have
+    pub missing_secrets: Vec<String>,
+    /// Bundled feature set ID -> local feature set ID
+    #[serde(skip)]
+    pub(crate) feature_set_ids: HashMap<String, String>,
+}
+
+/// Outcome of importing a bundle
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct BundleImportReport {
+    pub spaces: Vec<ImportedSpace>,
+}
+
+/// Exports spaces into bundles and imports them back
+pub struct SpaceBundleService {
+    space_repo: Arc<dyn SpaceRepository>,
+    installed_server_repo: Arc<dyn InstalledServerRepository>,
+    server_feature_repo: Arc<dyn ServerFeatureRepository>,
+    feature_set_repo: Arc<dyn FeatureSetRepository>,
+    credential_repo: Arc<dyn CredentialRepository>,
+    oauth_repo: Arc<dyn OutboundOAuthRepository>,
+    space_secret_repo: Arc<dyn SpaceSecretRepository>,
+    transactions: Option<Arc<dyn TransactionManager>>,
+}
+
+impl SpaceBundleService {
+    pub fn new(
+        space_repo: Arc<dyn SpaceRepository>,
+        installed_server_repo: Arc<dyn InstalledServerRepository>,
+        server_feature_repo: Arc<dyn ServerFeatureRepository>,
+        feature_set_repo: Arc<dyn FeatureSetRepository>,
+        credential_repo: Arc<dyn CredentialRepository>,
+        oauth_repo: Arc<dyn OutboundOAuthRepository>,
+        space_secret_repo: Arc<dyn SpaceSecretRepository>,
+    ) -> Self {
+        Self {
+            space_repo,
+            installed_server_repo,
+            server_feature_repo,
+            feature_set_repo,
+            credential_repo,
+            oauth_repo,
+            space_secret_repo,
+            transactions: None,
+        }
+    }
+
+    /// Run imports inside a transaction so a failure leaves nothing behind
+    pub fn with_transactions(mut self, transactions: Arc<dyn TransactionManager>) -> Self {
+        self.transactions = Some(transactions);
+        self
+    }
+
+    /// The transaction manager imports run under, if any
+    pub(crate) fn transactions(&self) -> Option<&dyn TransactionManager> {
+        self.transactions.as_deref()
+    }
+
+    /// A copy of this service that reads and writes through a transaction
+    pub(crate) fn bound_to(&self, repos: &TransactionRepositories) -> Self {
+        Self {
+            space_repo: repos.spaces.clone(),
+            installed_server_repo: repos.installed_servers.clone(),
+            server_feature_repo: repos.server_features.clone(),
+            feature_set_repo: repos.feature_sets.clone(),
+            credential_repo: repos.credentials.clone(),
+            oauth_repo: repos.oauth.clone(),
+            space_secret_repo: repos.space_secrets.clone(),
+            transactions: None,
+        }
+    }
+
+    /// Bundle the given spaces.
+    ///
+    /// OAuth refresh tokens are only included with `include_oauth_tokens`;
+    /// without them OAuth servers need to be authorized again after import.
+    pub async fn export(
+        &self,
+        space_ids: &[Uuid],
+        include_oauth_tokens: bool,
+    ) -> Result<SpaceBundle> {
+        let mut spaces = Vec::new();
+        for space_id in space_ids {
+            let space = self
+                .space_repo
+                .get(space_id)
+                .await?
+                .ok_or_else(|| anyhow!("Space not found: {}", space_id))?;
+            let id = space_id.to_string();
+
+            let servers = self.installed_server_repo.list_for_space(&id).await?;
+            let features = self.server_feature_repo.list_for_space(&id).await?;
+
+            let mut feature_sets = Vec::new();
+            for feature_set in self.feature_set_repo.list_by_space(&id).await? {
+                if feature_set.is_deleted {
+                    continue;
+                }
+                if let Some(with_members) = self
+                    .feature_set_repo
+                    .get_with_members(&feature_set.id)
+                    .await?
+                {
+                    feature_sets.push(with_members);
+                }
+            }
+
+            let mut oauth = Vec::new();
+            if include_oauth_tokens {
+                for registration in self.oauth_repo.list_for_space(space_id).await? {
+                    let token = self
+                        .credential_repo
+                        .get(
+                            space_id,
+                            &registration.server_id,
+                            &CredentialType::RefreshToken,
+                        )
+                        .await?;
+                    if let Some(token) = token {
+                        oauth.push(BundledOAuth {
+                            registration,
+                            refresh_token: token.value,
+                            refresh_token_expires_at: token.expires_at,
+                        });
+                    }
+                }
+            }
+
+            let secrets = self
+                .space_secret_repo
+                .list_for_space(space_id)
+                .await?
+                .into_iter()
+                .map(|secret| BundledSecret {
+                    name: secret.name,
+                    value: secret.value,
+                })
+                .collect();
+
+            spaces.push(BundledSpace {
+                space,
+                servers,
+                features,
+                feature_sets,
+                oauth,
+                secrets,
+            });
+        }
+
+        Ok(SpaceBundle {
+            version: SPACE_BUNDLE_VERSION,
+            exported_at: Utc::now(),
+            spaces,
+        })
+    }
+
+    /// Import a bundle under fresh IDs.
+    ///
+    /// Each bundled space becomes a new space unless
+    /// `options.target_space_id` is set. Secrets are written through the
+    /// repositories, so they end up encrypted under the local master key.
+    /// With a transaction manager the whole bundle is imported or nothing is.
+    pub async fn import(
+        &self,
+        bundle: SpaceBundle,
+        options: &BundleImportOptions,
+    ) -> Result<BundleImportReport> {
+        if bundle.version > SPACE_BUNDLE_VERSION {
+            bail!(
+                "Bundle version {} is newer than this version of McpMux supports ({})",
+                bundle.version,
+                SPACE_BUNDLE_VERSION
+            );
+        }
+
+        match &self.transactions {
+            Some(transactions) => {
+                in_transaction(transactions.as_ref(), |repos| async move {
+                    self.bound_to(&repos).import_bundle(bundle, options).await
+                })
+                .await
+            }
+            None => self.import_bundle(bundle, options).await,
+        }
+    }
+
+    /// Import every space in a bundle through this service's repositories
+    pub(crate) async fn import_bundle(
+        &self,
+        bundle: SpaceBundle,
+        options: &BundleImportOptions,
+    ) -> Result<BundleImportReport> {
+        let mut report = BundleImportReport::default();
+        for bundled in bundle.spaces {
+            report
+                .spaces
+                .push(self.import_space(bundled, options).await?);
+        }
+        Ok(report)
+    }
+
+    async fn import_space(
+        &self,
+        bundled: BundledSpace,
+        options: &BundleImportOptions,
+    ) -> Result<ImportedSpace> {
+        let (space, created) = match options.target_space_id {
+            Some(id) => (
+                self.space_repo
+                    .get(&id)
+                    .await?
+                    .ok_or_else(|| anyhow!("Space not found: {}", id))?,
+                false,
+            ),
+            None => {
+                let now = Utc::now();
+                let space = Space {
+                    id: Uuid::new_v4(),
+                    is_default: false,
+                    created_at: now,
+                    updated_at: now,
+                    ..bundled.space.clone()
+                };
+                self.space_repo.create(&space).await?;
+                (space, true)
+            }
+        };
+        let space_id = space.id.to_string();
+        self.feature_set_repo
+            .ensure_builtin_for_space(&space_id)
+            .await?;
+
+        let mut imported = ImportedSpace {
+            space_id: space.id,
+            name: space.name.clone(),
+            created,
+            servers: Vec::new(),
+            renamed: Vec::new(),
+            skipped: Vec::new(),
+            feature_sets: 0,
+            oauth_tokens: 0,
+            secrets: 0,
+            missing_secrets: Vec::new(),
+            feature_set_ids: HashMap::new(),
+        };
+
+        // Space secrets; ones the space already has are kept
+        let secret_names = bundled.secret_names();
+        let mut present: HashSet<String> = self
+            .space_secret_repo
+            .list_for_space(&space.id)
+            .await?
+            .into_iter()
+            .map(|s| s.name)
+            .collect();
+        for secret in &bundled.secrets {
+            if secret.value.is_empty() || present.contains(&secret.name) {
+                continue;
+            }
+            SpaceSecret::validate_name(&secret.name)?;
+            self.space_secret_repo
+                .save(&SpaceSecret::new(space.id, &secret.name, &secret.value))
+                .await?;
+            present.insert(secret.name.clone());
+            imported.secrets += 1;
+        }
+        imported.missing_secrets = secret_names
+            .into_iter()
+            .filter(|name| !present.contains(name))
+            .collect();
+
+        // Servers: bundled server ID -> installed server
+        let existing = self.installed_server_repo.list_for_space(&space_id).await?;
+        let mut taken: HashSet<String> = existing.iter().map(|s| s.server_id.clone()).collect();
+        let mut servers: HashMap<String, InstalledServer> = HashMap::new();
+        for server in bundled.servers {
+            let mut server_id = server.server_id.clone();
+            if let Some(current) = existing.iter().find(|s| s.server_id == server_id) {
+                match options.on_conflict {
+                    ServerConflict::Skip => {
+                        imported.skipped.push(server_id);
+                        continue;
+                    }
+                    ServerConflict::Replace => {
+                        self.installed_server_repo.uninstall(&current.id).await?;
+                        self.server_feature_repo
+                            .delete_for_server(&space_id, &server_id)
+                            .await?;
+                        // The old server's tokens and client registration
+                        // don't belong to the replacement
+                        self.credential_repo
+                            .delete_all(&space.id, &server_id)
+                            .await?;
+                        self.oauth_repo.delete(&space.id, &server_id).await?;
+                    }
+                    ServerConflict::Rename => {
+                        server_id = unused_server_id(&server_id, &taken);
+                        imported.renamed.push(RenamedServer {
+                            from: server.server_id.clone(),
+                            to: server_id.clone(),
+                        });
+                    }
+                }
+            }
+            taken.insert(server_id.clone());
+
+            let now = Utc::now();
+            let mut installed = InstalledServer {
+                id: Uuid::new_v4(),
+                space_id: space_id.clone(),
+                server_id: server_id.clone(),
+                oauth_connected: false,
+                created_at: now,
+                updated_at: now,
+                ..server.clone()
+            };
+            if server_id != server.server_id {
+                installed.cached_definition = installed
+                    .cached_definition
+                    .as_deref()
+                    .map(|definition| rename_definition(definition, &server_id));
+            }
+            self.installed_server_repo.install(&installed).await?;
+
+            let server_name = installed.server_name.clone().unwrap_or(server_id.clone());
+            self.feature_set_repo
+                .ensure_server_all(&space_id, &server_id, &server_name)
+                .await?;
+
+            imported.servers.push(server_id);
+            servers.insert(server.server_id, installed);
+        }
+
+        // Features: bundled feature ID -> local feature ID
+        let features: Vec<ServerFeature> = bundled
+            .features
+            .iter()
+            .filter_map(|feature| {
+                let server = servers.get(&feature.server_id)?;
+                Some(ServerFeature {
+                    id: Uuid::new_v4(),
+                    space_id: space_id.clone(),
+                    server_id: server.server_id.clone(),
+                    ..feature.clone()
+                })
+            })
+            .collect();
+        self.server_feature_repo.upsert_many(&features).await?;
+
+        let mut local_features: HashMap<(String, FeatureType, String), String> = HashMap::new();
+        for server in servers.values() {
+            for feature in self
+                .server_feature_repo
+                .list_for_server(&space_id, &server.server_id)
+                .await?
+            {
+                local_features.insert(
+                    (
+                        feature.server_id,
+                        feature.feature_type,
+                        feature.feature_name,
+                    ),
+                    feature.id.to_string(),
+                );
+            }
+        }
+        let feature_ids: HashMap<String, String> = bundled
+            .features
+            .iter()
+            .filter_map(|feature| {
+                let server = servers.get(&feature.server_id)?;
+                let key = (
+                    server.server_id.clone(),
+                    feature.feature_type.clone(),
+                    feature.feature_name.clone(),
+                );
+                Some((feature.id.to_string(), local_features.get(&key)?.clone()))
+            })
+            .collect();
+
+        // Feature sets: bundled ID -> local ID. Builtin sets map onto the
+        // space's own; custom sets get new IDs; global sets present on this
+        // machine keep theirs.
+        let mut feature_set_ids: HashMap<String, String> = self
+            .feature_set_repo
+            .list_global()
+            .await?
+            .into_iter()
+            .map(|fs| (fs.id.clone(), fs.id))
+            .collect();
+        for feature_set in &bundled.feature_sets {
+            let local = match feature_set.feature_set_type {
+                FeatureSetType::All => self.feature_set_repo.get_all_for_space(&space_id).await?,
+                FeatureSetType::Default => {
+                    self.feature_set_repo
+                        .get_default_for_space(&space_id)
+                        .await?
+                }
+                FeatureSetType::ServerAll => {
+                    match feature_set
+                        .server_id
+                        .as_ref()
+                        .and_then(|id| servers.get(id))
+                    {
+                        Some(server) => {
+                            self.feature_set_repo
+                                .get_server_all(&space_id, &server.server_id)
+                                .await?
+                        }
+                        None => None,
+                    }
+                }
+                FeatureSetType::Custom => {
+                    feature_set_ids.insert(feature_set.id.clone(), Uuid::new_v4().to_string());
+                    continue;
+                }
+            };
+            if let Some(local) = local {
+                feature_set_ids.insert(feature_set.id.clone(), local.id);
+            }
+        }
+
+        imported.feature_set_ids = feature_set_ids.clone();
+        for feature_set in bundled.feature_sets {
+            let Some(local_id) = feature_set_ids.get(&feature_set.id).cloned() else {
+                continue;
+            };
+            let members: Vec<FeatureSetMember> = feature_set
+                .members
+                .iter()
+                .filter_map(|member| {
+                    let member_id = match member.member_type {
+                        MemberType::FeatureSet => feature_set_ids.get(&member.member_id)?.clone(),
+                        MemberType::Feature => feature_ids.get(&member.member_id)?.clone(),
+                        MemberType::Rule => member.member_id.clone(),
+                    };
+                    Some(FeatureSetMember {
+                        id: Uuid::new_v4().to_string(),
+                        feature_set_id: local_id.clone(),
+                        member_type: member.member_type,
+                        member_id,
+                        mode: member.mode,
+                    })
+                })
+                .collect();
+
+            if feature_set.feature_set_type == FeatureSetType::Custom {
+                let now = Utc::now();
+                self.feature_set_repo
+                    .create(&FeatureSet {
+                        id: local_id,
+                        space_id: Some(space_id.clone()),
+                        server_id: None,
+                        created_at: now,
+                        updated_at: now,
+                        members,
+                        ..feature_set
+                    })
+                    .await?;
+                imported.feature_sets += 1;
+            } else if !members.is_empty() {
+                // Add the bundled members to the space's builtin set
+                let Some(mut local) = self.feature_set_repo.get_with_members(&local_id).await?
+                else {
+                    continue;
+                };
+                for member in members {
+                    let present = local.members.iter().any(|m| {
+                        m.member_type == member.member_type && m.member_id == member.member_id
+                    });
+                    if !present {
+                        local.members.push(member);
+                    }
+                }
+                local.updated_at = Utc::now();
+                self.feature_set_repo.update(&local).await?;
+            }
+        }
+
+        // OAuth registrations and refresh tokens
+        for oauth in bundled.oauth {
+            let Some(server) = servers.get(&oauth.registration.server_id) else {
+                continue;
+            };
+            let now = Utc::now();
+            self.oauth_repo
+                .save(&OutboundOAuthRegistration {
+                    id: Uuid::new_v4(),
+                    space_id: space.id,
+                    server_id: server.server_id.clone(),
+                    created_at: now,
+                    updated_at: now,
+                    ..oauth.registration
+                })
+                .await?;
+            self.credential_repo
+                .save(&Credential::refresh_token(
+                    space.id,
+                    server.server_id.clone(),
+                    oauth.refresh_token,
+                    oauth.refresh_token_expires_at,
+                ))
+                .await?;
+            if let Err(e) = self
+                .installed_server_repo
+                .set_oauth_connected(&server.id, true)
+                .await
+            {
+                warn!(
+                    server_id = %server.server_id,
+                    error = %e,
+                    "[SpaceBundle] Failed to mark imported server as connected"
+                );
+            }
+            imported.oauth_tokens += 1;
+        }
+
+        if !imported.missing_secrets.is_empty() {
+            warn!(
+                space_id = %space.id,
+                "[SpaceBundle] Imported space '{}' needs secrets set: {}",
+                space.name,
+                imported.missing_secrets.join(", ")
+            );
+        }
+        info!(
+            space_id = %space.id,
+            servers = imported.servers.len(),
+            skipped = imported.skipped.len(),
+            "[SpaceBundle] Imported space '{}'",
+            space.name
+        );
+        Ok(imported)
+    }
+}
+
+/// First of `<id>-imported`, `<id>-imported-2`, ... not in `taken`
+fn unused_server_id(server_id: &str, taken: &HashSet<String>) -> String {
+    let base = format!("{}-imported", server_id);
+    let mut candidate = base.clone();
+    let mut n = 2;
+    while taken.contains(&candidate) {
+        candidate = format!("{}-{}", base, n);
+        n += 1;
+    }
+    candidate
+}
+
+/// Point a cached server definition at a new server ID
+fn rename_definition(definition: &str, server_id: &str) -> String {
+    match serde_json::from_str::<serde_json::Value>(definition) {
+        Ok(mut value) => {
+            if let Some(object) = value.as_object_mut() {
+                object.insert("id".to_string(), server_id.into());
+            }
+            value.to_string()
+        }
+        Err(_) => definition.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unused_server_id() {
+        let taken: HashSet<String> = ["github", "github-imported"]
+            .into_iter()
+            .map(String::from)
+            .collect();
+        assert_eq!(unused_server_id("memory", &taken), "memory-imported");
+        assert_eq!(unused_server_id("github", &taken), "github-imported-2");
+    }
+
+    #[test]
+    fn test_rename_definition() {
+        let renamed = rename_definition(r#"{"id":"github","name":"GitHub"}"#, "github-imported");
+        let value: serde_json::Value = serde_json::from_str(&renamed).unwrap();
+        assert_eq!(value["id"], "github-imported");
+        assert_eq!(value["name"], "GitHub");
+    }
+}
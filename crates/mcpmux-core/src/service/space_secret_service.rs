@@ -0,0 +1,133 @@
+//! Space secret service
+//!
+//! Manages the named secrets of a space and finds the servers whose input
+//! values reference them, so callers can reconnect those servers when a
+//! secret is rotated.
+
+use anyhow::{anyhow, bail, Result};
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use std::collections::HashMap;
+use std::sync::Arc;
+use tracing::info;
+use uuid::Uuid;
+
+use crate::domain::{InstalledServer, SpaceSecret};
+use crate::repository::{InstalledServerRepository, SpaceSecretRepository};
+
+/// A secret without its value, and the servers using it
+#[derive(Debug, Clone, Serialize)]
+pub struct SpaceSecretInfo {
+    pub name: String,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    /// Server IDs whose input values reference the secret
+    pub used_by: Vec<String>,
+}
+
+/// Service for managing space secrets
+pub struct SpaceSecretService {
+    secrets: Arc<dyn SpaceSecretRepository>,
+    servers: Arc<dyn InstalledServerRepository>,
+}
+
+impl SpaceSecretService {
+    pub fn new(
+        secrets: Arc<dyn SpaceSecretRepository>,
+        servers: Arc<dyn InstalledServerRepository>,
+    ) -> Self {
+        Self { secrets, servers }
+    }
+
+    /// List a space's secrets, without values
+    pub async fn list(&self, space_id: &Uuid) -> Result<Vec<SpaceSecretInfo>> {
+        let servers = self.servers.list_for_space(&space_id.to_string()).await?;
+        Ok(self
+            .secrets
+            .list_for_space(space_id)
+            .await?
+            .into_iter()
+            .map(|secret| SpaceSecretInfo {
+                used_by: servers
+                    .iter()
+                    .filter(|s| s.references_secret(&secret.name))
+                    .map(|s| s.server_id.clone())
+                    .collect(),
+                name: secret.name,
+                created_at: secret.created_at,
+                updated_at: secret.updated_at,
+            })
+            .collect())
+    }
+
+    /// Secret values of a space by name, for resolving `${secret:name}`
+    pub async fn values(&self, space_id: &Uuid) -> Result<HashMap<String, String>> {
+        Ok(self
+            .secrets
+            .list_for_space(space_id)
+            .await?
+            .into_iter()
+            .map(|secret| (secret.name, secret.value))
+            .collect())
+    }
+
+    /// Installed servers whose input values reference the secret `name`
+    pub async fn dependents(&self, space_id: &Uuid, name: &str) -> Result<Vec<InstalledServer>> {
+        Ok(self
+            .servers
+            .list_for_space(&space_id.to_string())
+            .await?
+            .into_iter()
+            .filter(|s| s.references_secret(name))
+            .collect())
+    }
+
+    /// Create or rotate a secret
+    ///
+    /// Returns the servers referencing it, which need reconnecting to pick up
+    /// the new value.
+    pub async fn set(
+        &self,
+        space_id: &Uuid,
+        name: &str,
+        value: &str,
+    ) -> Result<Vec<InstalledServer>> {
+        SpaceSecret::validate_name(name)?;
+        if value.is_empty() {
+            bail!("Secret value is empty");
+        }
+
+        let secret = match self.secrets.get(space_id, name).await? {
+            Some(mut existing) => {
+                existing.value = value.to_string();
+                existing.updated_at = Utc::now();
+                existing
+            }
+            None => SpaceSecret::new(*space_id, name, value),
+        };
+        self.secrets.save(&secret).await?;
+
+        let dependents = self.dependents(space_id, name).await?;
+        info!(
+            space_id = %space_id,
+            secret = name,
+            dependents = dependents.len(),
+            "Saved space secret"
+        );
+        Ok(dependents)
+    }
+
+    /// Delete a secret no server references
+    pub async fn delete(&self, space_id: &Uuid, name: &str) -> Result<()> {
+        let dependents = self.dependents(space_id, name).await?;
+        if !dependents.is_empty() {
+            let ids: Vec<&str> = dependents.iter().map(|s| s.server_id.as_str()).collect();
+            bail!("Secret '{}' is used by {}", name, ids.join(", "));
+        }
+        if !self.secrets.delete(space_id, name).await? {
+            return Err(anyhow!("Secret not found: {}", name));
+        }
+        info!(space_id = %space_id, secret = name, "Deleted space secret");
+        Ok(())
+    }
+}
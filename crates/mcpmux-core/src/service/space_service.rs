@@ -1,16 +1,57 @@
 //! Space service - business logic for managing spaces
 
+use anyhow::{anyhow, Context};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
-use tracing::info;
+use tracing::{info, warn};
 use uuid::Uuid;
 
+use super::{
+    BundleImportOptions, BundledSpace, ImportedSpace, SpaceBundle, SpaceBundleService,
+    SpaceTemplate, SPACE_BUNDLE_VERSION,
+};
 use crate::domain::Space;
-use crate::repository::{FeatureSetRepository, SpaceRepository};
+use crate::repository::{
+    in_transaction, FeatureSetRepository, InboundMcpClientRepository, SpaceRepository,
+};
+
+/// Options for cloning a space
+#[derive(Debug, Clone, Deserialize)]
+pub struct SpaceCloneOptions {
+    /// Name of the new space
+    pub name: String,
+    /// Copy secret input values and space secrets. OAuth tokens are never
+    /// copied; OAuth servers in the new space need authorizing again
+    #[serde(default)]
+    pub include_secrets: bool,
+    /// Give clients the same grants in the new space
+    #[serde(default)]
+    pub include_grants: bool,
+}
+
+/// Outcome of cloning or instantiating a space
+#[derive(Debug, Clone, Serialize)]
+pub struct ClonedSpace {
+    pub space: Space,
+    /// Server IDs installed
+    pub servers: Vec<String>,
+    /// Number of custom feature sets created
+    pub feature_sets: usize,
+    /// Number of client grants copied
+    pub grants: usize,
+    /// Space secrets the servers reference that still need setting
+    pub missing_secrets: Vec<String>,
+}
 
 /// Service for managing Spaces
 pub struct SpaceService {
     repository: Arc<dyn SpaceRepository>,
     feature_set_repository: Option<Arc<dyn FeatureSetRepository>>,
+    bundles: Option<Arc<SpaceBundleService>>,
+    client_repository: Option<Arc<dyn InboundMcpClientRepository>>,
+    templates_dir: Option<PathBuf>,
 }
 
 impl SpaceService {
@@ -19,6 +60,9 @@ impl SpaceService {
         Self {
             repository,
             feature_set_repository: None,
+            bundles: None,
+            client_repository: None,
+            templates_dir: None,
         }
     }
 
@@ -30,9 +74,26 @@ impl SpaceService {
         Self {
             repository,
             feature_set_repository: Some(feature_set_repository),
+            bundles: None,
+            client_repository: None,
+            templates_dir: None,
         }
     }
 
+    /// Enable cloning spaces and creating them from templates stored in
+    /// `templates_dir`
+    pub fn with_cloning(
+        mut self,
+        bundles: Arc<SpaceBundleService>,
+        client_repository: Arc<dyn InboundMcpClientRepository>,
+        templates_dir: impl Into<PathBuf>,
+    ) -> Self {
+        self.bundles = Some(bundles);
+        self.client_repository = Some(client_repository);
+        self.templates_dir = Some(templates_dir.into());
+        self
+    }
+
     /// List all spaces
     pub async fn list(&self) -> anyhow::Result<Vec<Space>> {
         self.repository.list().await
@@ -100,4 +161,252 @@ impl SpaceService {
     pub async fn set_active(&self, id: &Uuid) -> anyhow::Result<()> {
         self.repository.set_default(id).await
     }
+
+    /// Turn read-only mode on or off for a space
+    pub async fn set_read_only(&self, id: &Uuid, read_only: bool) -> anyhow::Result<Space> {
+        let mut space = self
+            .repository
+            .get(id)
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("Space not found: {}", id))?;
+
+        space.read_only = read_only;
+        space.updated_at = chrono::Utc::now();
+        self.repository.update(&space).await?;
+
+        info!(space_id = %id, read_only, "Updated space read-only mode");
+        Ok(space)
+    }
+
+    /// Deep-copy a space: installed servers, discovered features, feature sets
+    /// (with member IDs remapped) and, optionally, secrets and client grants.
+    ///
+    /// Servers defined in the space's JSON config file aren't copied; they
+    /// come from copying the file.
+    pub async fn clone_space(
+        &self,
+        id: &Uuid,
+        options: &SpaceCloneOptions,
+    ) -> anyhow::Result<ClonedSpace> {
+        // Refresh tokens aren't copied: two spaces rotating one token would
+        // lock each other out, so OAuth servers need authorizing again
+        let mut bundle = self.bundles()?.export(&[*id], false).await?;
+        let mut space = bundle
+            .spaces
+            .pop()
+            .ok_or_else(|| anyhow!("Space not found: {}", id))?;
+        if !options.include_secrets {
+            space.strip_secrets();
+        }
+        space.servers.retain(|s| !s.is_from_user_config());
+        space.space.name = options.name.clone();
+
+        let grants_from = options.include_grants.then_some(id);
+        let (imported, grants) = self.import(space, grants_from).await?;
+
+        info!(
+            space_id = %imported.space_id,
+            source_space_id = %id,
+            servers = imported.servers.len(),
+            grants,
+            "Cloned space"
+        );
+        self.cloned(imported, grants).await
+    }
+
+    /// Save a space as a template (without its secrets)
+    pub async fn save_template(
+        &self,
+        id: &Uuid,
+        name: String,
+        description: Option<String>,
+    ) -> anyhow::Result<SpaceTemplate> {
+        let mut bundle = self.bundles()?.export(&[*id], false).await?;
+        let mut space = bundle
+            .spaces
+            .pop()
+            .ok_or_else(|| anyhow!("Space not found: {}", id))?;
+        space.servers.retain(|s| !s.is_from_user_config());
+
+        let template = SpaceTemplate::new(name, description, space);
+        let dir = self.templates_dir()?;
+        tokio::fs::create_dir_all(dir).await?;
+        let path = template_path(dir, &template.id);
+        tokio::fs::write(&path, serde_json::to_string_pretty(&template)?)
+            .await
+            .with_context(|| format!("Failed to write {}", path.display()))?;
+
+        info!(template_id = %template.id, space_id = %id, "Saved space template");
+        Ok(template)
+    }
+
+    /// List saved templates, sorted by name
+    pub async fn list_templates(&self) -> anyhow::Result<Vec<SpaceTemplate>> {
+        let dir = self.templates_dir()?;
+        let mut templates = Vec::new();
+        let mut entries = match tokio::fs::read_dir(dir).await {
+            Ok(entries) => entries,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(templates),
+            Err(e) => return Err(e.into()),
+        };
+        while let Some(entry) = entries.next_entry().await? {
+            let path = entry.path();
+            if path.extension().and_then(|ext| ext.to_str()) != Some("json") {
+                continue;
+            }
+            let parsed = tokio::fs::read_to_string(&path)
+                .await
+                .map_err(anyhow::Error::from)
+                .and_then(|content| SpaceTemplate::parse(&content));
+            match parsed {
+                Ok(template) => templates.push(template),
+                Err(e) => warn!(path = %path.display(), error = %e, "Skipping space template"),
+            }
+        }
+        templates.sort_by(|a, b| a.name.cmp(&b.name));
+        Ok(templates)
+    }
+
+    /// Get a saved template
+    pub async fn get_template(&self, id: &Uuid) -> anyhow::Result<SpaceTemplate> {
+        let path = template_path(self.templates_dir()?, id);
+        let content = tokio::fs::read_to_string(&path)
+            .await
+            .map_err(|_| anyhow!("Template not found: {}", id))?;
+        SpaceTemplate::parse(&content)
+    }
+
+    /// Delete a saved template
+    pub async fn delete_template(&self, id: &Uuid) -> anyhow::Result<()> {
+        let path = template_path(self.templates_dir()?, id);
+        tokio::fs::remove_file(&path)
+            .await
+            .map_err(|_| anyhow!("Template not found: {}", id))
+    }
+
+    /// Create a space from a template, filling in its missing inputs from
+    /// `inputs` (server ID -> input ID -> value)
+    pub async fn create_from_template(
+        &self,
+        template_id: &Uuid,
+        name: &str,
+        icon: Option<String>,
+        inputs: &HashMap<String, HashMap<String, String>>,
+    ) -> anyhow::Result<ClonedSpace> {
+        let template = self.get_template(template_id).await?;
+        let (imported, _) = self
+            .import(template.instantiate(name, icon, inputs), None)
+            .await?;
+
+        info!(
+            space_id = %imported.space_id,
+            template_id = %template_id,
+            servers = imported.servers.len(),
+            "Created space from template"
+        );
+        self.cloned(imported, 0).await
+    }
+
+    fn bundles(&self) -> anyhow::Result<&SpaceBundleService> {
+        self.bundles
+            .as_deref()
+            .ok_or_else(|| anyhow!("Space cloning is not configured"))
+    }
+
+    fn client_repository(&self) -> anyhow::Result<&dyn InboundMcpClientRepository> {
+        self.client_repository
+            .as_deref()
+            .ok_or_else(|| anyhow!("Space cloning is not configured"))
+    }
+
+    fn templates_dir(&self) -> anyhow::Result<&Path> {
+        self.templates_dir
+            .as_deref()
+            .ok_or_else(|| anyhow!("Space templates are not configured"))
+    }
+
+    /// Import one space as a new space, giving clients the same grants in it
+    /// that they have in `grants_from`.
+    ///
+    /// With transactions configured on the bundle service, the space and its
+    /// grants are created together or not at all.
+    async fn import(
+        &self,
+        space: BundledSpace,
+        grants_from: Option<&Uuid>,
+    ) -> anyhow::Result<(ImportedSpace, usize)> {
+        let bundles = self.bundles()?;
+        match bundles.transactions() {
+            Some(transactions) => {
+                in_transaction(transactions, |repos| async move {
+                    let bundles = bundles.bound_to(&repos);
+                    import_space(&bundles, repos.clients.as_ref(), space, grants_from).await
+                })
+                .await
+            }
+            None => import_space(bundles, self.client_repository()?, space, grants_from).await,
+        }
+    }
+
+    async fn cloned(&self, imported: ImportedSpace, grants: usize) -> anyhow::Result<ClonedSpace> {
+        let space = self
+            .repository
+            .get(&imported.space_id)
+            .await?
+            .ok_or_else(|| anyhow!("Space not found: {}", imported.space_id))?;
+        Ok(ClonedSpace {
+            space,
+            servers: imported.servers,
+            feature_sets: imported.feature_sets,
+            grants,
+            missing_secrets: imported.missing_secrets,
+        })
+    }
+}
+
+async fn import_space(
+    bundles: &SpaceBundleService,
+    clients: &dyn InboundMcpClientRepository,
+    space: BundledSpace,
+    grants_from: Option<&Uuid>,
+) -> anyhow::Result<(ImportedSpace, usize)> {
+    let mut report = bundles
+        .import_bundle(
+            SpaceBundle {
+                version: SPACE_BUNDLE_VERSION,
+                exported_at: chrono::Utc::now(),
+                spaces: vec![space],
+            },
+            &BundleImportOptions::default(),
+        )
+        .await?;
+    let imported = report
+        .spaces
+        .pop()
+        .ok_or_else(|| anyhow!("Nothing was imported"))?;
+
+    let mut grants = 0;
+    if let Some(source_id) = grants_from {
+        let source_id = source_id.to_string();
+        let target_id = imported.space_id.to_string();
+        for client in clients.list().await? {
+            let copied: Vec<String> = clients
+                .get_grants_for_space(&client.id, &source_id)
+                .await?
+                .iter()
+                .filter_map(|fs| imported.feature_set_ids.get(fs).cloned())
+                .collect();
+            if !copied.is_empty() {
+                clients
+                    .set_grants_for_space(&client.id, &target_id, &copied)
+                    .await?;
+                grants += copied.len();
+            }
+        }
+    }
+    Ok((imported, grants))
+}
+
+fn template_path(dir: &Path, id: &Uuid) -> PathBuf {
+    dir.join(format!("{}.json", id))
 }
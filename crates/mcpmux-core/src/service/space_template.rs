@@ -0,0 +1,230 @@
+//! Space templates - reusable starting points for new spaces
+//!
+//! A [`SpaceTemplate`] is a space's installed servers, discovered features and
+//! feature sets, saved as JSON without secrets. Creating a space from a
+//! template only asks for the inputs the template has no value for: secret
+//! inputs, and any that were empty when it was saved. Space secrets are saved
+//! by name only and listed so they can be set in the new space.
+
+use anyhow::{bail, Context, Result};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use uuid::Uuid;
+
+use super::BundledSpace;
+use crate::domain::InputDefinition;
+
+/// Current template format version
+pub const SPACE_TEMPLATE_VERSION: u32 = 1;
+
+/// A saved space to create new spaces from
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SpaceTemplate {
+    pub version: u32,
+    pub id: Uuid,
+    pub name: String,
+    #[serde(default)]
+    pub description: Option<String>,
+    pub created_at: DateTime<Utc>,
+    /// The space the template was saved from, without secrets
+    pub space: BundledSpace,
+}
+
+/// An input to fill in when creating a space from a template
+#[derive(Debug, Clone, Serialize)]
+pub struct TemplateInput {
+    pub server_id: String,
+    pub server_name: String,
+    pub input: InputDefinition,
+}
+
+impl SpaceTemplate {
+    /// Save `space` as a template, dropping its secrets
+    pub fn new(name: impl Into<String>, description: Option<String>, space: BundledSpace) -> Self {
+        let mut space = space;
+        space.strip_secrets();
+        Self {
+            version: SPACE_TEMPLATE_VERSION,
+            id: Uuid::new_v4(),
+            name: name.into(),
+            description,
+            created_at: Utc::now(),
+            space,
+        }
+    }
+
+    /// Parse a template file
+    pub fn parse(content: &str) -> Result<Self> {
+        let template: Self = serde_json::from_str(content).context("Invalid space template")?;
+        if template.version > SPACE_TEMPLATE_VERSION {
+            bail!(
+                "Template version {} is newer than this version of McpMux supports ({})",
+                template.version,
+                SPACE_TEMPLATE_VERSION
+            );
+        }
+        Ok(template)
+    }
+
+    /// Inputs declared by the template's servers that it has no value for
+    pub fn inputs(&self) -> Vec<TemplateInput> {
+        let mut inputs = Vec::new();
+        for server in &self.space.servers {
+            let Some(definition) = server.get_definition() else {
+                continue;
+            };
+            for input in &definition.transport.metadata().inputs {
+                let has_value = server
+                    .input_values
+                    .get(&input.id)
+                    .is_some_and(|v| !v.is_empty());
+                if !has_value {
+                    inputs.push(TemplateInput {
+                        server_id: server.server_id.clone(),
+                        server_name: server.display_name().to_string(),
+                        input: input.clone(),
+                    });
+                }
+            }
+        }
+        inputs
+    }
+
+    /// Space secrets the template's servers need, which have to be set in
+    /// each space created from it
+    pub fn secrets(&self) -> Vec<String> {
+        self.space.missing_secrets()
+    }
+
+    /// The template's space under a new name, with `values` (server ID ->
+    /// input ID -> value) filled in
+    pub fn instantiate(
+        &self,
+        name: &str,
+        icon: Option<String>,
+        values: &HashMap<String, HashMap<String, String>>,
+    ) -> BundledSpace {
+        let mut space = self.space.clone();
+        space.space.name = name.to_string();
+        if icon.is_some() {
+            space.space.icon = icon;
+        }
+        for server in &mut space.servers {
+            if let Some(values) = values.get(&server.server_id) {
+                server.input_values.extend(
+                    values
+                        .iter()
+                        .filter(|(_, v)| !v.is_empty())
+                        .map(|(k, v)| (k.clone(), v.clone())),
+                );
+            }
+        }
+        space
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{InstalledServer, ServerDefinition, Space, UserServerEntry};
+    use crate::service::BundledSecret;
+    use std::path::PathBuf;
+
+    fn github() -> InstalledServer {
+        let definition: ServerDefinition = UserServerEntry {
+            command: Some("npx".to_string()),
+            args: Some(vec!["${input:REPO}".to_string()]),
+            env: Some(HashMap::from([(
+                "GITHUB_TOKEN".to_string(),
+                "${input:GITHUB_TOKEN}".to_string(),
+            )])),
+            cwd: None,
+            env_file: None,
+            url: None,
+            headers: None,
+            name: Some("GitHub".to_string()),
+            description: None,
+            icon: None,
+            alias: None,
+            auth: None,
+            metadata: None,
+        }
+        .to_server_definition("github", "space_1", PathBuf::from("/tmp/space.json"));
+        InstalledServer::new("space_1", "github")
+            .with_definition(&definition)
+            .with_input("GITHUB_TOKEN", "ghp_secret")
+            .with_input("REPO", "")
+    }
+
+    fn bundled() -> BundledSpace {
+        BundledSpace {
+            space: Space::new("Client A"),
+            servers: vec![github()],
+            features: vec![],
+            feature_sets: vec![],
+            oauth: vec![],
+            secrets: vec![],
+        }
+    }
+
+    #[test]
+    fn test_template_drops_secrets_and_prompts_for_missing_inputs() {
+        let template = SpaceTemplate::new("Client project", None, bundled());
+
+        assert!(!template.space.servers[0]
+            .input_values
+            .contains_key("GITHUB_TOKEN"));
+        let mut inputs: Vec<String> = template
+            .inputs()
+            .into_iter()
+            .map(|i| format!("{}/{}", i.server_id, i.input.id))
+            .collect();
+        inputs.sort();
+        assert_eq!(inputs, vec!["github/GITHUB_TOKEN", "github/REPO"]);
+    }
+
+    #[test]
+    fn test_template_lists_space_secrets_without_values() {
+        let mut space = bundled();
+        space.servers[0]
+            .extra_headers
+            .insert("X-Api-Key".to_string(), "${secret:api_key}".to_string());
+        space.secrets.push(BundledSecret {
+            name: "github_token".to_string(),
+            value: "ghp_secret".to_string(),
+        });
+        let template = SpaceTemplate::new("Client project", None, space);
+
+        assert!(template.space.secrets.iter().all(|s| s.value.is_empty()));
+        assert_eq!(template.secrets(), vec!["api_key", "github_token"]);
+    }
+
+    #[test]
+    fn test_instantiate_fills_inputs() {
+        let template = SpaceTemplate::new("Client project", None, bundled());
+        let values = HashMap::from([(
+            "github".to_string(),
+            HashMap::from([("GITHUB_TOKEN".to_string(), "ghp_new".to_string())]),
+        )]);
+
+        let space = template.instantiate("Client B", None, &values);
+        assert_eq!(space.space.name, "Client B");
+        assert_eq!(
+            space.servers[0].input_values.get("GITHUB_TOKEN"),
+            Some(&"ghp_new".to_string())
+        );
+    }
+
+    #[test]
+    fn test_parse_rejects_newer_version() {
+        let mut template = SpaceTemplate::new("Client project", None, bundled());
+        template.version = SPACE_TEMPLATE_VERSION + 1;
+        let json = serde_json::to_string(&template).unwrap();
+
+        assert!(SpaceTemplate::parse(&json).is_err());
+        template.version = SPACE_TEMPLATE_VERSION;
+        let json = serde_json::to_string(&template).unwrap();
+        assert_eq!(SpaceTemplate::parse(&json).unwrap().name, "Client project");
+    }
+}
@@ -0,0 +1,1504 @@
+//! Declarative workspace manifests - configuration as code
+//!
+//! A [`WorkspaceManifest`] declares spaces with their installed servers,
+//! feature sets (including rule members) and client grant templates, plus
+//! app settings, in one version-controlled JSON file (schema:
+//! `schemas/workspace-manifest.schema.json`).
+//!
+//! [`WorkspaceManifestService::plan`] diffs the manifest against the
+//! database and [`WorkspaceManifestService::apply`] converges the database
+//! onto it. Both walk the manifest the same way, so an apply makes exactly
+//! the changes its plan showed.
+//!
+//! Attributes the manifest leaves out are not managed: a server without
+//! `enabled` keeps its current state, and inputs that aren't listed keep
+//! their stored values. With `prune`, servers and custom feature sets that
+//! the manifest doesn't list are removed from the declared spaces. Spaces
+//! the manifest doesn't mention are never touched.
+//!
+//! Secret inputs can't be written inline; they are referenced as
+//! `${env:NAME}` or `${file:path}` and resolved when planning. Env vars and
+//! headers that look like secrets must be placeholders (`${env:NAME}`,
+//! `${secret:name}`) that resolve when the server starts. Manifests never
+//! carry space secrets: a `${secret:name}` the space doesn't have yet is
+//! reported as a warning.
+
+use anyhow::{anyhow, bail, Context, Result};
+use chrono::Utc;
+use lazy_static::lazy_static;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::path::Path;
+use std::sync::Arc;
+use tracing::{info, warn};
+use uuid::Uuid;
+
+use crate::domain::{
+    referenced_secrets, Client, DomainEvent, FeatureRule, FeatureSet, FeatureSetMember,
+    FeatureSetType, FeatureType, InstallationSource, InstalledServer, MemberMode, MemberType,
+    ServerDefinition, Space, UserServerEntry,
+};
+use crate::repository::{
+    in_transaction, AppSettingsRepository, CredentialRepository, FeatureSetRepository,
+    InboundMcpClientRepository, InstalledServerRepository, ServerFeatureRepository,
+    SpaceRepository, SpaceSecretRepository, TransactionManager, TransactionRepositories,
+};
+use crate::service::config_import::is_secret;
+
+/// Current manifest format version
+pub const WORKSPACE_MANIFEST_VERSION: u32 = 1;
+
+lazy_static! {
+    /// A whole value that references a secret kept outside the manifest
+    static ref REFERENCE_REGEX: Regex = Regex::new(r"^\$\{(env|file):([^}]+)\}$").unwrap();
+}
+
+/// Declared state of spaces, servers, feature sets, grants and settings
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct WorkspaceManifest {
+    #[serde(rename = "$schema", default, skip_serializing_if = "Option::is_none")]
+    pub schema: Option<String>,
+    pub version: u32,
+    /// Remove servers and custom feature sets the manifest doesn't list from
+    /// the declared spaces
+    #[serde(default)]
+    pub prune: bool,
+    /// App settings by key; `null` removes a setting
+    #[serde(default)]
+    pub settings: BTreeMap<String, Value>,
+    #[serde(default)]
+    pub spaces: Vec<ManifestSpace>,
+}
+
+/// A declared space, matched to existing spaces by name
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ManifestSpace {
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub icon: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Make this the default space
+    #[serde(default)]
+    pub default: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub read_only: Option<bool>,
+    /// Installed servers by server ID
+    #[serde(default)]
+    pub servers: BTreeMap<String, ManifestServer>,
+    #[serde(default)]
+    pub feature_sets: Vec<ManifestFeatureSet>,
+    #[serde(default)]
+    pub client_grants: Vec<ManifestClientGrant>,
+}
+
+/// A declared server installation
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ManifestServer {
+    /// Definition of a server that isn't in the registry, in user space
+    /// config format (`command`/`args`/`env` or `url`/`headers`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub definition: Option<UserServerEntry>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
+    /// Input values; secret inputs must be `${env:NAME}` or `${file:path}`
+    /// references
+    #[serde(default)]
+    pub inputs: BTreeMap<String, String>,
+    /// Environment variable overrides; secret-looking values must be
+    /// placeholders
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub env: Option<HashMap<String, String>>,
+    /// Extra arguments appended to the command
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub args: Option<Vec<String>>,
+    /// Extra HTTP headers; secret-looking values must be placeholders
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub headers: Option<HashMap<String, String>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub read_only: Option<bool>,
+}
+
+/// A declared feature set, matched by name. The builtin "Default" and
+/// "All Features" sets can be declared to manage their members.
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ManifestFeatureSet {
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub icon: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub read_only: Option<bool>,
+    /// Complete membership of the set
+    #[serde(default)]
+    pub members: Vec<ManifestMember>,
+}
+
+/// A feature set member
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ManifestMember {
+    #[serde(flatten)]
+    pub target: MemberTarget,
+    #[serde(default)]
+    pub mode: MemberMode,
+}
+
+/// What a feature set member refers to
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MemberTarget {
+    /// A discovered feature
+    Feature(FeatureRef),
+    /// A rule expression (see [`FeatureRule`])
+    Rule(String),
+    /// Another feature set of the space, by name
+    FeatureSet(String),
+    /// Everything a server offers
+    Server(String),
+}
+
+/// A feature by server ID and name
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct FeatureRef {
+    pub server: String,
+    pub name: String,
+    #[serde(default, rename = "type")]
+    pub feature_type: FeatureType,
+}
+
+/// Feature sets granted in a space to every client matching a pattern
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ManifestClientGrant {
+    /// Glob matched case-insensitively against client names and types
+    /// (e.g. `"Cursor"`, `"claude*"`)
+    pub client: String,
+    /// Names of the feature sets to grant
+    pub feature_sets: Vec<String>,
+}
+
+impl WorkspaceManifest {
+    /// Parse and validate a manifest
+    pub fn parse(content: &str) -> Result<Self> {
+        let manifest: Self = serde_json::from_str(content).context("Invalid workspace manifest")?;
+        manifest.validate()?;
+        Ok(manifest)
+    }
+
+    /// Check what can be checked without the database
+    pub fn validate(&self) -> Result<()> {
+        if self.version != WORKSPACE_MANIFEST_VERSION {
+            bail!(
+                "Unsupported manifest version {} (expected {})",
+                self.version,
+                WORKSPACE_MANIFEST_VERSION
+            );
+        }
+        if self.spaces.iter().filter(|s| s.default).count() > 1 {
+            bail!("Only one space can be the default");
+        }
+
+        let mut space_names = HashSet::new();
+        for space in &self.spaces {
+            if space.name.trim().is_empty() {
+                bail!("Space names can't be empty");
+            }
+            if !space_names.insert(space.name.as_str()) {
+                bail!("Space '{}' is declared twice", space.name);
+            }
+
+            let mut set_names = HashSet::new();
+            for feature_set in &space.feature_sets {
+                if !set_names.insert(feature_set.name.as_str()) {
+                    bail!(
+                        "Feature set '{}' is declared twice in space '{}'",
+                        feature_set.name,
+                        space.name
+                    );
+                }
+                for member in &feature_set.members {
+                    if let MemberTarget::Rule(expression) = &member.target {
+                        FeatureRule::parse(expression).with_context(|| {
+                            format!(
+                                "Invalid rule in feature set '{}' of space '{}'",
+                                feature_set.name, space.name
+                            )
+                        })?;
+                    }
+                }
+            }
+
+            for (key, server) in &space.servers {
+                let definition = server.definition.as_ref();
+                for (what, values) in [
+                    ("env", server.env.as_ref()),
+                    ("header", server.headers.as_ref()),
+                    ("env", definition.and_then(|d| d.env.as_ref())),
+                    ("header", definition.and_then(|d| d.headers.as_ref())),
+                ] {
+                    check_literal_secrets(values, what, key, &space.name)?;
+                }
+            }
+
+            for grant in &space.client_grants {
+                glob::Pattern::new(&grant.client).map_err(|e| {
+                    anyhow!(
+                        "Invalid client pattern '{}' in space '{}': {}",
+                        grant.client,
+                        space.name,
+                        e
+                    )
+                })?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Refuse secret-looking env vars or headers written inline
+fn check_literal_secrets(
+    values: Option<&HashMap<String, String>>,
+    what: &str,
+    server_id: &str,
+    space: &str,
+) -> Result<()> {
+    for (key, value) in values.into_iter().flatten() {
+        if !value.contains("${") && is_secret(key, value) {
+            bail!(
+                "The {} {} of server '{}' in space '{}' looks like a secret: reference it as \
+                 ${{env:NAME}} or ${{secret:name}} instead of writing it in the manifest",
+                what,
+                key,
+                server_id,
+                space
+            );
+        }
+    }
+    Ok(())
+}
+
+/// Kind of change in a plan
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ChangeAction {
+    Create,
+    Update,
+    Delete,
+}
+
+impl ChangeAction {
+    fn symbol(&self) -> char {
+        match self {
+            Self::Create => '+',
+            Self::Update => '~',
+            Self::Delete => '-',
+        }
+    }
+}
+
+/// Kind of resource a change applies to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ManifestResource {
+    Setting,
+    Space,
+    Server,
+    FeatureSet,
+    ClientGrant,
+}
+
+impl ManifestResource {
+    fn label(&self) -> &'static str {
+        match self {
+            Self::Setting => "setting",
+            Self::Space => "space",
+            Self::Server => "server",
+            Self::FeatureSet => "feature set",
+            Self::ClientGrant => "grant",
+        }
+    }
+}
+
+/// One change needed to converge on the manifest
+#[derive(Debug, Clone, Serialize)]
+pub struct PlannedChange {
+    pub action: ChangeAction,
+    pub resource: ManifestResource,
+    /// Space the resource belongs to
+    pub space: Option<String>,
+    pub name: String,
+    /// What changes (attribute names, never secret values)
+    pub details: Vec<String>,
+}
+
+/// Changes needed to converge on a manifest, or made by applying it
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct ManifestPlan {
+    pub changes: Vec<PlannedChange>,
+    /// Declarations that can't take effect yet (e.g. features of servers
+    /// that haven't connected)
+    pub warnings: Vec<String>,
+    /// Events for the changes made (empty for a plan)
+    #[serde(skip)]
+    pub events: Vec<DomainEvent>,
+}
+
+impl ManifestPlan {
+    /// Whether anything differs from the manifest
+    pub fn has_changes(&self) -> bool {
+        !self.changes.is_empty()
+    }
+
+    /// One line per change, e.g. `~ server github (Work): enabled, input GITHUB_TOKEN`
+    pub fn render(&self) -> String {
+        let mut out = String::new();
+        for change in &self.changes {
+            out.push(change.action.symbol());
+            out.push(' ');
+            out.push_str(change.resource.label());
+            out.push(' ');
+            out.push_str(&change.name);
+            if let Some(space) = &change.space {
+                out.push_str(&format!(" ({})", space));
+            }
+            if !change.details.is_empty() {
+                out.push_str(": ");
+                out.push_str(&change.details.join(", "));
+            }
+            out.push('\n');
+        }
+        out
+    }
+}
+
+/// Plans and applies workspace manifests
+pub struct WorkspaceManifestService {
+    space_repo: Arc<dyn SpaceRepository>,
+    installed_server_repo: Arc<dyn InstalledServerRepository>,
+    server_feature_repo: Arc<dyn ServerFeatureRepository>,
+    feature_set_repo: Arc<dyn FeatureSetRepository>,
+    client_repo: Arc<dyn InboundMcpClientRepository>,
+    credential_repo: Arc<dyn CredentialRepository>,
+    space_secret_repo: Arc<dyn SpaceSecretRepository>,
+    settings_repo: Arc<dyn AppSettingsRepository>,
+    transactions: Option<Arc<dyn TransactionManager>>,
+}
+
+impl WorkspaceManifestService {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        space_repo: Arc<dyn SpaceRepository>,
+        installed_server_repo: Arc<dyn InstalledServerRepository>,
+        server_feature_repo: Arc<dyn ServerFeatureRepository>,
+        feature_set_repo: Arc<dyn FeatureSetRepository>,
+        client_repo: Arc<dyn InboundMcpClientRepository>,
+        credential_repo: Arc<dyn CredentialRepository>,
+        space_secret_repo: Arc<dyn SpaceSecretRepository>,
+        settings_repo: Arc<dyn AppSettingsRepository>,
+    ) -> Self {
+        Self {
+            space_repo,
+            installed_server_repo,
+            server_feature_repo,
+            feature_set_repo,
+            client_repo,
+            credential_repo,
+            space_secret_repo,
+            settings_repo,
+            transactions: None,
+        }
+    }
+
+    /// Apply manifests inside a transaction so a failure changes nothing
+    pub fn with_transactions(mut self, transactions: Arc<dyn TransactionManager>) -> Self {
+        self.transactions = Some(transactions);
+        self
+    }
+
+    /// A copy of this service that reads and writes through a transaction
+    fn bound_to(&self, repos: &TransactionRepositories) -> Self {
+        Self {
+            space_repo: repos.spaces.clone(),
+            installed_server_repo: repos.installed_servers.clone(),
+            server_feature_repo: repos.server_features.clone(),
+            feature_set_repo: repos.feature_sets.clone(),
+            client_repo: repos.clients.clone(),
+            credential_repo: repos.credentials.clone(),
+            space_secret_repo: repos.space_secrets.clone(),
+            settings_repo: repos.settings.clone(),
+            transactions: None,
+        }
+    }
+
+    /// Diff a manifest against the database without changing anything.
+    ///
+    /// `source` is the manifest's path: `${file:...}` references resolve
+    /// relative to it. `registry` supplies definitions for servers declared
+    /// without one.
+    pub async fn plan(
+        &self,
+        manifest: &WorkspaceManifest,
+        source: &Path,
+        registry: &[ServerDefinition],
+    ) -> Result<ManifestPlan> {
+        self.converge(manifest, source, registry, false).await
+    }
+
+    /// Converge the database onto a manifest.
+    ///
+    /// Returns the changes made, with the domain events to publish for them.
+    /// With a transaction manager either every change is made or none is.
+    pub async fn apply(
+        &self,
+        manifest: &WorkspaceManifest,
+        source: &Path,
+        registry: &[ServerDefinition],
+    ) -> Result<ManifestPlan> {
+        let plan = match &self.transactions {
+            Some(transactions) => {
+                in_transaction(transactions.as_ref(), |repos| async move {
+                    self.bound_to(&repos)
+                        .converge(manifest, source, registry, true)
+                        .await
+                })
+                .await?
+            }
+            None => self.converge(manifest, source, registry, true).await?,
+        };
+        info!(
+            "[WorkspaceManifest] Applied {} changes from {}",
+            plan.changes.len(),
+            source.display()
+        );
+        Ok(plan)
+    }
+
+    async fn converge(
+        &self,
+        manifest: &WorkspaceManifest,
+        source: &Path,
+        registry: &[ServerDefinition],
+        apply: bool,
+    ) -> Result<ManifestPlan> {
+        manifest.validate()?;
+        let mut run = Convergence {
+            service: self,
+            apply,
+            source,
+            base_dir: source.parent().unwrap_or(Path::new(".")),
+            registry,
+            plan: ManifestPlan::default(),
+        };
+
+        run.settings(&manifest.settings).await?;
+        let clients = self.client_repo.list().await?;
+        for space in &manifest.spaces {
+            run.space(space, manifest.prune, &clients).await?;
+        }
+        Ok(run.plan)
+    }
+}
+
+/// Feature set names mapped to IDs; sets created by the run get their IDs
+/// up front so members can refer to them
+type FeatureSetIds = HashMap<String, String>;
+
+/// One walk over a manifest, recording changes and making them when `apply`
+struct Convergence<'a> {
+    service: &'a WorkspaceManifestService,
+    apply: bool,
+    source: &'a Path,
+    base_dir: &'a Path,
+    registry: &'a [ServerDefinition],
+    plan: ManifestPlan,
+}
+
+impl Convergence<'_> {
+    fn change(
+        &mut self,
+        action: ChangeAction,
+        resource: ManifestResource,
+        space: Option<&str>,
+        name: &str,
+        details: Vec<String>,
+    ) {
+        self.plan.changes.push(PlannedChange {
+            action,
+            resource,
+            space: space.map(str::to_string),
+            name: name.to_string(),
+            details,
+        });
+    }
+
+    async fn settings(&mut self, settings: &BTreeMap<String, Value>) -> Result<()> {
+        let repo = &self.service.settings_repo;
+        for (key, value) in settings {
+            let desired = match value {
+                Value::Null => None,
+                Value::String(s) => Some(s.clone()),
+                other => Some(other.to_string()),
+            };
+            let current = repo.get(key).await?;
+            let action = match (&current, &desired) {
+                (a, b) if a == b => continue,
+                (None, _) => ChangeAction::Create,
+                (_, None) => ChangeAction::Delete,
+                _ => ChangeAction::Update,
+            };
+            self.change(action, ManifestResource::Setting, None, key, vec![]);
+            if self.apply {
+                match desired {
+                    Some(value) => repo.set(key, &value).await?,
+                    None => repo.delete(key).await?,
+                }
+            }
+        }
+        Ok(())
+    }
+
+    async fn space(
+        &mut self,
+        declared: &ManifestSpace,
+        prune: bool,
+        clients: &[Client],
+    ) -> Result<()> {
+        let repo = self.service.space_repo.clone();
+        let existing = repo
+            .list()
+            .await?
+            .into_iter()
+            .find(|s| s.name == declared.name);
+
+        // `None` only while planning a space that doesn't exist yet
+        let space = match existing {
+            Some(mut space) => {
+                let mut details = Vec::new();
+                if declared.icon.is_some() && declared.icon != space.icon {
+                    details.push("icon".to_string());
+                    space.icon = declared.icon.clone();
+                }
+                if declared.description.is_some() && declared.description != space.description {
+                    details.push("description".to_string());
+                    space.description = declared.description.clone();
+                }
+                let read_only_changed = declared.read_only.is_some_and(|r| r != space.read_only);
+                if read_only_changed {
+                    details.push("read_only".to_string());
+                    space.read_only = !space.read_only;
+                }
+                let make_default = declared.default && !space.is_default;
+                if make_default {
+                    details.push("default".to_string());
+                }
+
+                if !details.is_empty() {
+                    self.change(
+                        ChangeAction::Update,
+                        ManifestResource::Space,
+                        None,
+                        &space.name,
+                        details,
+                    );
+                    if self.apply {
+                        space.updated_at = Utc::now();
+                        repo.update(&space).await?;
+                        if make_default {
+                            repo.set_default(&space.id).await?;
+                        }
+                        self.plan.events.push(DomainEvent::SpaceUpdated {
+                            space_id: space.id,
+                            name: space.name.clone(),
+                        });
+                        if read_only_changed {
+                            self.plan.events.push(DomainEvent::SpaceReadOnlyChanged {
+                                space_id: space.id,
+                                read_only: space.read_only,
+                            });
+                        }
+                    }
+                }
+                Some(space)
+            }
+            None => {
+                self.change(
+                    ChangeAction::Create,
+                    ManifestResource::Space,
+                    None,
+                    &declared.name,
+                    vec![],
+                );
+                if self.apply {
+                    let mut space = Space::new(&declared.name);
+                    space.icon = declared.icon.clone();
+                    space.description = declared.description.clone();
+                    space.read_only = declared.read_only.unwrap_or(false);
+                    repo.create(&space).await?;
+                    self.service
+                        .feature_set_repo
+                        .ensure_builtin_for_space(&space.id.to_string())
+                        .await?;
+                    if declared.default {
+                        repo.set_default(&space.id).await?;
+                    }
+                    self.plan.events.push(DomainEvent::SpaceCreated {
+                        space_id: space.id,
+                        name: space.name.clone(),
+                        icon: space.icon.clone(),
+                    });
+                    Some(space)
+                } else {
+                    None
+                }
+            }
+        };
+
+        self.servers(declared, space.as_ref(), prune).await?;
+        self.secret_references(declared, space.as_ref()).await?;
+        let feature_sets = self.feature_sets(declared, space.as_ref(), prune).await?;
+        self.client_grants(declared, space.as_ref(), &feature_sets, clients)
+            .await
+    }
+
+    /// Warn about `${secret:name}` references the space has no secret for
+    async fn secret_references(
+        &mut self,
+        declared: &ManifestSpace,
+        space: Option<&Space>,
+    ) -> Result<()> {
+        let present: HashSet<String> = match space {
+            Some(space) => self
+                .service
+                .space_secret_repo
+                .list_for_space(&space.id)
+                .await?
+                .into_iter()
+                .map(|s| s.name)
+                .collect(),
+            None => HashSet::new(),
+        };
+
+        for (key, server) in &declared.servers {
+            let definition = server.definition.as_ref();
+            let values = server
+                .inputs
+                .values()
+                .chain(server.env.iter().flat_map(|env| env.values()))
+                .chain(server.headers.iter().flat_map(|headers| headers.values()))
+                .chain(
+                    definition
+                        .and_then(|d| d.env.as_ref())
+                        .into_iter()
+                        .flat_map(|env| env.values()),
+                )
+                .chain(
+                    definition
+                        .and_then(|d| d.headers.as_ref())
+                        .into_iter()
+                        .flat_map(|headers| headers.values()),
+                );
+            let mut names = BTreeSet::new();
+            for value in values {
+                names.extend(referenced_secrets(value));
+            }
+            for name in names.into_iter().filter(|name| !present.contains(*name)) {
+                self.plan.warnings.push(format!(
+                    "Server '{}' in space '{}' references secret '{}', which isn't set in \
+                     the space yet",
+                    key, declared.name, name
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    async fn servers(
+        &mut self,
+        declared: &ManifestSpace,
+        space: Option<&Space>,
+        prune: bool,
+    ) -> Result<()> {
+        let repo = self.service.installed_server_repo.clone();
+        let space_id = space.map(|s| s.id.to_string()).unwrap_or_default();
+        let existing = match space {
+            Some(_) => repo.list_for_space(&space_id).await?,
+            None => Vec::new(),
+        };
+
+        let mut declared_ids = HashSet::new();
+        for (key, server) in &declared.servers {
+            let current = existing.iter().find(|s| s.server_id == *key);
+            let definition = self.definition(&space_id, key, server, current)?;
+            let server_id = definition.id.clone();
+            declared_ids.insert(server_id.clone());
+            let current = existing.iter().find(|s| s.server_id == server_id);
+            let inputs = self.inputs(&declared.name, &server_id, server, &definition)?;
+
+            match current {
+                None => {
+                    self.change(
+                        ChangeAction::Create,
+                        ManifestResource::Server,
+                        Some(&declared.name),
+                        &server_id,
+                        vec![],
+                    );
+                    let Some(space) = space.filter(|_| self.apply) else {
+                        continue;
+                    };
+                    let source = match server.definition {
+                        Some(_) => InstallationSource::ManualEntry,
+                        None => InstallationSource::Registry,
+                    };
+                    let mut installed = InstalledServer::new(&space_id, &server_id)
+                        .with_definition(&definition)
+                        .with_inputs(inputs.into_iter().collect())
+                        .with_enabled(server.enabled.unwrap_or(false))
+                        .with_source(source);
+                    installed.env_overrides = server.env.clone().unwrap_or_default();
+                    installed.args_append = server.args.clone().unwrap_or_default();
+                    installed.extra_headers = server.headers.clone().unwrap_or_default();
+                    installed.read_only = server.read_only.unwrap_or(false);
+                    repo.install(&installed).await?;
+                    self.service
+                        .feature_set_repo
+                        .ensure_server_all(&space_id, &server_id, &definition.name)
+                        .await?;
+
+                    self.plan.events.push(DomainEvent::ServerInstalled {
+                        space_id: space.id,
+                        server_id: server_id.clone(),
+                        server_name: definition.name.clone(),
+                    });
+                    if installed.enabled {
+                        self.plan.events.push(DomainEvent::ServerEnabled {
+                            space_id: space.id,
+                            server_id,
+                        });
+                    }
+                }
+                Some(current) => {
+                    let mut updated = current.clone();
+                    let mut details = Vec::new();
+                    if server.definition.is_some() {
+                        let cached: Option<Value> = current
+                            .cached_definition
+                            .as_deref()
+                            .and_then(|d| serde_json::from_str(d).ok());
+                        if cached != serde_json::to_value(&definition).ok() {
+                            details.push("definition".to_string());
+                            updated = updated.with_definition(&definition);
+                        }
+                    }
+                    for (key, value) in &inputs {
+                        if current.input_values.get(key) != Some(value) {
+                            details.push(format!("input {}", key));
+                            updated.input_values.insert(key.clone(), value.clone());
+                        }
+                    }
+                    if let Some(env) = server.env.as_ref().filter(|e| **e != current.env_overrides)
+                    {
+                        details.push("env".to_string());
+                        updated.env_overrides = env.clone();
+                    }
+                    if let Some(args) = server.args.as_ref().filter(|a| **a != current.args_append)
+                    {
+                        details.push("args".to_string());
+                        updated.args_append = args.clone();
+                    }
+                    if let Some(headers) = server
+                        .headers
+                        .as_ref()
+                        .filter(|h| **h != current.extra_headers)
+                    {
+                        details.push("headers".to_string());
+                        updated.extra_headers = headers.clone();
+                    }
+                    if let Some(read_only) = server.read_only.filter(|r| *r != current.read_only) {
+                        details.push("read_only".to_string());
+                        updated.read_only = read_only;
+                    }
+                    let config_changed = !details.is_empty();
+                    let enabled = server.enabled.filter(|e| *e != current.enabled);
+                    if let Some(enabled) = enabled {
+                        details.push(if enabled { "enabled" } else { "disabled" }.to_string());
+                        updated.enabled = enabled;
+                    }
+                    if details.is_empty() {
+                        continue;
+                    }
+
+                    self.change(
+                        ChangeAction::Update,
+                        ManifestResource::Server,
+                        Some(&declared.name),
+                        &server_id,
+                        details,
+                    );
+                    let Some(space) = space.filter(|_| self.apply) else {
+                        continue;
+                    };
+                    updated.updated_at = Utc::now();
+                    repo.update(&updated).await?;
+                    if config_changed {
+                        self.plan.events.push(DomainEvent::ServerConfigUpdated {
+                            space_id: space.id,
+                            server_id: server_id.clone(),
+                        });
+                    }
+                    match enabled {
+                        Some(true) => self.plan.events.push(DomainEvent::ServerEnabled {
+                            space_id: space.id,
+                            server_id,
+                        }),
+                        Some(false) => self.plan.events.push(DomainEvent::ServerDisabled {
+                            space_id: space.id,
+                            server_id,
+                        }),
+                        None => {}
+                    }
+                }
+            }
+        }
+
+        if !prune {
+            return Ok(());
+        }
+        // Servers from the space's JSON file are owned by that file
+        for current in existing
+            .iter()
+            .filter(|s| !declared_ids.contains(&s.server_id) && !s.is_from_user_config())
+        {
+            self.change(
+                ChangeAction::Delete,
+                ManifestResource::Server,
+                Some(&declared.name),
+                &current.server_id,
+                vec![],
+            );
+            let Some(space) = space.filter(|_| self.apply) else {
+                continue;
+            };
+            self.uninstall(space, current).await?;
+        }
+        Ok(())
+    }
+
+    async fn uninstall(&mut self, space: &Space, server: &InstalledServer) -> Result<()> {
+        let space_id = space.id.to_string();
+        let service = self.service;
+        if let Err(e) = service
+            .feature_set_repo
+            .delete_server_all(&space_id, &server.server_id)
+            .await
+        {
+            warn!(
+                server_id = %server.server_id,
+                error = %e,
+                "[WorkspaceManifest] Failed to delete server-all feature set"
+            );
+        }
+        if let Err(e) = service
+            .server_feature_repo
+            .delete_for_server(&space_id, &server.server_id)
+            .await
+        {
+            warn!(
+                server_id = %server.server_id,
+                error = %e,
+                "[WorkspaceManifest] Failed to delete server features"
+            );
+        }
+        if let Err(e) = service
+            .credential_repo
+            .delete_all(&space.id, &server.server_id)
+            .await
+        {
+            warn!(
+                server_id = %server.server_id,
+                error = %e,
+                "[WorkspaceManifest] Failed to delete server credentials"
+            );
+        }
+        service.installed_server_repo.uninstall(&server.id).await?;
+
+        self.plan.events.push(DomainEvent::ServerUninstalled {
+            space_id: space.id,
+            server_id: server.server_id.clone(),
+        });
+        Ok(())
+    }
+
+    /// Definition for a declared server: inline, from the registry, or the
+    /// one cached when it was installed
+    fn definition(
+        &self,
+        space_id: &str,
+        key: &str,
+        server: &ManifestServer,
+        current: Option<&InstalledServer>,
+    ) -> Result<ServerDefinition> {
+        if let Some(entry) = &server.definition {
+            return Ok(entry.to_server_definition(key, space_id, self.source.to_path_buf()));
+        }
+        self.registry
+            .iter()
+            .find(|d| d.id == key)
+            .cloned()
+            .or_else(|| current.and_then(InstalledServer::get_definition))
+            .ok_or_else(|| {
+                anyhow!(
+                    "Server '{}' is not in the registry; give it a `definition`",
+                    key
+                )
+            })
+    }
+
+    /// Resolve a server's declared inputs, refusing inline secrets
+    fn inputs(
+        &self,
+        space: &str,
+        server_id: &str,
+        server: &ManifestServer,
+        definition: &ServerDefinition,
+    ) -> Result<BTreeMap<String, String>> {
+        let secrets: HashSet<&str> = definition
+            .transport
+            .metadata()
+            .inputs
+            .iter()
+            .filter(|i| i.secret)
+            .map(|i| i.id.as_str())
+            .collect();
+
+        server
+            .inputs
+            .iter()
+            .map(|(key, value)| {
+                if secrets.contains(key.as_str()) && !REFERENCE_REGEX.is_match(value) {
+                    bail!(
+                        "Input {} of server '{}' in space '{}' is a secret: reference it as \
+                         ${{env:NAME}} or ${{file:path}} instead of writing it in the manifest",
+                        key,
+                        server_id,
+                        space
+                    );
+                }
+                let resolved = resolve_reference(value, self.base_dir).with_context(|| {
+                    format!(
+                        "Input {} of server '{}' in space '{}'",
+                        key, server_id, space
+                    )
+                })?;
+                Ok((key.clone(), resolved))
+            })
+            .collect()
+    }
+
+    async fn feature_sets(
+        &mut self,
+        declared: &ManifestSpace,
+        space: Option<&Space>,
+        prune: bool,
+    ) -> Result<FeatureSetIds> {
+        let repo = self.service.feature_set_repo.clone();
+        let space_id = space.map(|s| s.id.to_string()).unwrap_or_default();
+        let mut existing = match space {
+            Some(_) => repo.list_by_space(&space_id).await?,
+            None => vec![FeatureSet::new_all(""), FeatureSet::new_default("")],
+        };
+        existing.retain(|s| s.feature_set_type != FeatureSetType::ServerAll && !s.is_deleted);
+
+        let mut ids: FeatureSetIds = existing
+            .iter()
+            .map(|s| (s.name.clone(), s.id.clone()))
+            .collect();
+        for feature_set in &declared.feature_sets {
+            ids.entry(feature_set.name.clone())
+                .or_insert_with(|| Uuid::new_v4().to_string());
+        }
+
+        for feature_set in &declared.feature_sets {
+            let current = match existing.iter().find(|s| s.name == feature_set.name) {
+                Some(current) if space.is_some() => repo.get_with_members(&current.id).await?,
+                Some(current) => Some(current.clone()),
+                None => None,
+            };
+            let members = self.members(declared, space, feature_set, &ids).await?;
+
+            match current {
+                None => {
+                    self.change(
+                        ChangeAction::Create,
+                        ManifestResource::FeatureSet,
+                        Some(&declared.name),
+                        &feature_set.name,
+                        members
+                            .iter()
+                            .map(|(_, label)| format!("+{}", label))
+                            .collect(),
+                    );
+                    let Some(space) = space.filter(|_| self.apply) else {
+                        continue;
+                    };
+                    let id = ids[&feature_set.name].clone();
+                    let mut created = FeatureSet::new_custom(&feature_set.name, &space_id);
+                    created.id = id.clone();
+                    created.description = feature_set.description.clone();
+                    created.icon = feature_set.icon.clone();
+                    created.read_only = feature_set.read_only.unwrap_or(false);
+                    created.members = members
+                        .into_iter()
+                        .map(|(member, _)| FeatureSetMember {
+                            feature_set_id: id.clone(),
+                            ..member
+                        })
+                        .collect();
+                    repo.create(&created).await?;
+                    self.plan.events.push(DomainEvent::FeatureSetCreated {
+                        space_id: space.id,
+                        feature_set_id: id,
+                        name: created.name,
+                        feature_set_type: Some(FeatureSetType::Custom.as_str().to_string()),
+                    });
+                }
+                Some(mut current) => {
+                    let mut details = Vec::new();
+                    if !current.is_builtin {
+                        if feature_set.description.is_some()
+                            && feature_set.description != current.description
+                        {
+                            details.push("description".to_string());
+                            current.description = feature_set.description.clone();
+                        }
+                        if feature_set.icon.is_some() && feature_set.icon != current.icon {
+                            details.push("icon".to_string());
+                            current.icon = feature_set.icon.clone();
+                        }
+                        if let Some(read_only) =
+                            feature_set.read_only.filter(|r| *r != current.read_only)
+                        {
+                            details.push("read_only".to_string());
+                            current.read_only = read_only;
+                        }
+                    }
+                    let attributes_changed = !details.is_empty();
+
+                    let key = |m: &FeatureSetMember| {
+                        (m.member_type.as_str(), m.member_id.clone(), m.mode.as_str())
+                    };
+                    let wanted: BTreeSet<_> = members.iter().map(|(m, _)| key(m)).collect();
+                    let have: BTreeSet<_> = current.members.iter().map(key).collect();
+                    let added: Vec<_> = members
+                        .iter()
+                        .filter(|(m, _)| !have.contains(&key(m)))
+                        .collect();
+                    let removed: Vec<_> = current
+                        .members
+                        .iter()
+                        .filter(|m| !wanted.contains(&key(m)))
+                        .collect();
+                    details.extend(added.iter().map(|(_, label)| format!("+{}", label)));
+                    details.extend(
+                        removed
+                            .iter()
+                            .map(|m| format!("-{} {}", m.member_type.as_str(), m.member_id)),
+                    );
+                    if details.is_empty() {
+                        continue;
+                    }
+
+                    self.change(
+                        ChangeAction::Update,
+                        ManifestResource::FeatureSet,
+                        Some(&declared.name),
+                        &feature_set.name,
+                        details,
+                    );
+                    let Some(space) = space.filter(|_| self.apply) else {
+                        continue;
+                    };
+                    let (added_count, removed_count) = (added.len(), removed.len());
+                    current.members = members
+                        .into_iter()
+                        .map(|(member, _)| FeatureSetMember {
+                            feature_set_id: current.id.clone(),
+                            ..member
+                        })
+                        .collect();
+                    current.updated_at = Utc::now();
+                    repo.update(&current).await?;
+                    if attributes_changed {
+                        self.plan.events.push(DomainEvent::FeatureSetUpdated {
+                            space_id: space.id,
+                            feature_set_id: current.id.clone(),
+                            name: current.name.clone(),
+                        });
+                    }
+                    if added_count + removed_count > 0 {
+                        self.plan
+                            .events
+                            .push(DomainEvent::FeatureSetMembersChanged {
+                                space_id: space.id,
+                                feature_set_id: current.id,
+                                added_count,
+                                removed_count,
+                            });
+                    }
+                }
+            }
+        }
+
+        if prune {
+            let declared_names: HashSet<&str> = declared
+                .feature_sets
+                .iter()
+                .map(|s| s.name.as_str())
+                .collect();
+            for current in existing.iter().filter(|s| {
+                s.feature_set_type == FeatureSetType::Custom
+                    && !declared_names.contains(s.name.as_str())
+            }) {
+                self.change(
+                    ChangeAction::Delete,
+                    ManifestResource::FeatureSet,
+                    Some(&declared.name),
+                    &current.name,
+                    vec![],
+                );
+                ids.remove(&current.name);
+                let Some(space) = space.filter(|_| self.apply) else {
+                    continue;
+                };
+                repo.delete(&current.id).await?;
+                self.plan.events.push(DomainEvent::FeatureSetDeleted {
+                    space_id: space.id,
+                    feature_set_id: current.id.clone(),
+                });
+            }
+        }
+        Ok(ids)
+    }
+
+    /// Resolve declared members to stored members, each with a label for the plan
+    async fn members(
+        &mut self,
+        declared: &ManifestSpace,
+        space: Option<&Space>,
+        feature_set: &ManifestFeatureSet,
+        ids: &FeatureSetIds,
+    ) -> Result<Vec<(FeatureSetMember, String)>> {
+        let space_id = space.map(|s| s.id.to_string()).unwrap_or_default();
+        let mut members = Vec::new();
+        for member in &feature_set.members {
+            let (member_type, member_id, label) = match &member.target {
+                MemberTarget::Rule(expression) => (
+                    MemberType::Rule,
+                    expression.trim().to_string(),
+                    format!("rule {}", expression.trim()),
+                ),
+                MemberTarget::FeatureSet(name) => {
+                    let id = ids.get(name).ok_or_else(|| {
+                        anyhow!(
+                            "Feature set '{}' in space '{}' includes unknown feature set '{}'",
+                            feature_set.name,
+                            declared.name,
+                            name
+                        )
+                    })?;
+                    (
+                        MemberType::FeatureSet,
+                        id.clone(),
+                        format!("feature_set {}", name),
+                    )
+                }
+                MemberTarget::Server(server_id) => {
+                    let server_all = match space {
+                        Some(_) => {
+                            self.service
+                                .feature_set_repo
+                                .get_server_all(&space_id, server_id)
+                                .await?
+                        }
+                        None => None,
+                    };
+                    let id = match server_all {
+                        Some(set) => set.id,
+                        // Created along with the server by this run
+                        None if declared.servers.contains_key(server_id) => {
+                            format!("server-all:{}", server_id)
+                        }
+                        None => bail!(
+                            "Feature set '{}' in space '{}' includes server '{}', which isn't installed",
+                            feature_set.name,
+                            declared.name,
+                            server_id
+                        ),
+                    };
+                    (MemberType::FeatureSet, id, format!("server {}", server_id))
+                }
+                MemberTarget::Feature(feature) => {
+                    let found = match space {
+                        Some(_) => self
+                            .service
+                            .server_feature_repo
+                            .list_for_server(&space_id, &feature.server)
+                            .await?
+                            .into_iter()
+                            .find(|f| {
+                                f.feature_name == feature.name
+                                    && f.feature_type == feature.feature_type
+                            }),
+                        None => None,
+                    };
+                    let label = format!(
+                        "{} {}/{}",
+                        feature.feature_type.as_str(),
+                        feature.server,
+                        feature.name
+                    );
+                    let Some(found) = found else {
+                        self.plan.warnings.push(format!(
+                            "{} in feature set '{}' of space '{}' hasn't been discovered yet; \
+                             connect the server and apply again",
+                            label, feature_set.name, declared.name
+                        ));
+                        continue;
+                    };
+                    (MemberType::Feature, found.id.to_string(), label)
+                }
+            };
+            members.push((
+                FeatureSetMember {
+                    id: Uuid::new_v4().to_string(),
+                    feature_set_id: String::new(),
+                    member_type,
+                    member_id,
+                    mode: member.mode,
+                },
+                label,
+            ));
+        }
+        Ok(members)
+    }
+
+    async fn client_grants(
+        &mut self,
+        declared: &ManifestSpace,
+        space: Option<&Space>,
+        feature_sets: &FeatureSetIds,
+        clients: &[Client],
+    ) -> Result<()> {
+        let names: HashMap<&str, &str> = feature_sets
+            .iter()
+            .map(|(name, id)| (id.as_str(), name.as_str()))
+            .collect();
+
+        // Desired grants per matching client
+        let mut desired: BTreeMap<Uuid, (String, BTreeSet<String>)> = BTreeMap::new();
+        for grant in &declared.client_grants {
+            let pattern = glob::Pattern::new(&grant.client.to_lowercase())?;
+            let ids = grant
+                .feature_sets
+                .iter()
+                .map(|name| {
+                    feature_sets.get(name).cloned().ok_or_else(|| {
+                        anyhow!(
+                            "Client grant '{}' in space '{}' names unknown feature set '{}'",
+                            grant.client,
+                            declared.name,
+                            name
+                        )
+                    })
+                })
+                .collect::<Result<Vec<_>>>()?;
+
+            let matched: Vec<&Client> = clients
+                .iter()
+                .filter(|c| {
+                    pattern.matches(&c.name.to_lowercase())
+                        || pattern.matches(&c.client_type.to_lowercase())
+                })
+                .collect();
+            if matched.is_empty() {
+                self.plan.warnings.push(format!(
+                    "No registered client matches '{}' (space '{}'); apply again after it connects",
+                    grant.client, declared.name
+                ));
+            }
+            for client in matched {
+                desired
+                    .entry(client.id)
+                    .or_insert_with(|| (client.name.clone(), BTreeSet::new()))
+                    .1
+                    .extend(ids.iter().cloned());
+            }
+        }
+
+        for (client_id, (client_name, wanted)) in desired {
+            let have: BTreeSet<String> = match space {
+                Some(space) => self
+                    .service
+                    .client_repo
+                    .get_grants_for_space(&client_id, &space.id.to_string())
+                    .await?
+                    .into_iter()
+                    .collect(),
+                None => BTreeSet::new(),
+            };
+            if have == wanted {
+                continue;
+            }
+
+            let label = |id: &String| names.get(id.as_str()).unwrap_or(&id.as_str()).to_string();
+            let mut details: Vec<String> = wanted
+                .difference(&have)
+                .map(|id| format!("+{}", label(id)))
+                .collect();
+            details.extend(have.difference(&wanted).map(|id| format!("-{}", label(id))));
+            let action = if have.is_empty() {
+                ChangeAction::Create
+            } else {
+                ChangeAction::Update
+            };
+            self.change(
+                action,
+                ManifestResource::ClientGrant,
+                Some(&declared.name),
+                &client_name,
+                details,
+            );
+
+            let Some(space) = space.filter(|_| self.apply) else {
+                continue;
+            };
+            let feature_set_ids: Vec<String> = wanted.into_iter().collect();
+            self.service
+                .client_repo
+                .set_grants_for_space(&client_id, &space.id.to_string(), &feature_set_ids)
+                .await?;
+            self.plan.events.push(DomainEvent::ClientGrantsUpdated {
+                client_id: client_id.to_string(),
+                space_id: space.id,
+                feature_set_ids,
+            });
+        }
+        Ok(())
+    }
+}
+
+/// Resolve a `${env:NAME}` or `${file:path}` reference; other values are
+/// taken literally. Relative file paths resolve against `base_dir`.
+fn resolve_reference(value: &str, base_dir: &Path) -> Result<String> {
+    let Some(captures) = REFERENCE_REGEX.captures(value) else {
+        return Ok(value.to_string());
+    };
+    let target = &captures[2];
+    if &captures[1] == "env" {
+        return std::env::var(target)
+            .map_err(|_| anyhow!("Environment variable {} is not set", target));
+    }
+
+    let path = match target.strip_prefix("~/") {
+        Some(rest) => dirs::home_dir()
+            .ok_or_else(|| anyhow!("No home directory for {}", target))?
+            .join(rest),
+        None => base_dir.join(target),
+    };
+    let content = std::fs::read_to_string(&path)
+        .with_context(|| format!("Failed to read {}", path.display()))?;
+    Ok(content.trim_end_matches(['\r', '\n']).to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_members() {
+        let manifest = WorkspaceManifest::parse(
+            r#"{
+                "version": 1,
+                "spaces": [{
+                    "name": "Work",
+                    "feature_sets": [{
+                        "name": "Issues",
+                        "members": [
+                            { "feature": { "server": "github", "name": "create_issue" } },
+                            { "rule": "server:github name:list_*" },
+                            { "server": "github", "mode": "exclude" }
+                        ]
+                    }]
+                }]
+            }"#,
+        )
+        .unwrap();
+
+        let members = &manifest.spaces[0].feature_sets[0].members;
+        assert_eq!(
+            members[0].target,
+            MemberTarget::Feature(FeatureRef {
+                server: "github".to_string(),
+                name: "create_issue".to_string(),
+                feature_type: FeatureType::Tool,
+            })
+        );
+        assert_eq!(members[0].mode, MemberMode::Include);
+        assert_eq!(
+            members[1].target,
+            MemberTarget::Rule("server:github name:list_*".to_string())
+        );
+        assert_eq!(members[2].mode, MemberMode::Exclude);
+    }
+
+    #[test]
+    fn test_validate_rejects_bad_rules_and_duplicates() {
+        let bad_rule = r#"{"version": 1, "spaces": [{"name": "Work", "feature_sets": [
+            {"name": "X", "members": [{"rule": "colour:red"}]}]}]}"#;
+        assert!(WorkspaceManifest::parse(bad_rule).is_err());
+
+        let duplicate = r#"{"version": 1, "spaces": [{"name": "Work"}, {"name": "Work"}]}"#;
+        assert!(WorkspaceManifest::parse(duplicate).is_err());
+
+        let version = r#"{"version": 2}"#;
+        assert!(WorkspaceManifest::parse(version).is_err());
+    }
+
+    #[test]
+    fn test_validate_rejects_literal_secrets() {
+        let inline_env = r#"{"version": 1, "spaces": [{"name": "Work", "servers": {
+            "github": {"env": {"GITHUB_TOKEN": "ghp_abc"}}}}]}"#;
+        assert!(WorkspaceManifest::parse(inline_env).is_err());
+
+        let definition_header = r#"{"version": 1, "spaces": [{"name": "Work", "servers": {
+            "api": {"definition": {"url": "https://api.example.com/mcp",
+                "headers": {"Authorization": "Bearer abc"}}}}}]}"#;
+        assert!(WorkspaceManifest::parse(definition_header).is_err());
+
+        let placeholders = r#"{"version": 1, "spaces": [{"name": "Work", "servers": {
+            "github": {"env": {"GITHUB_TOKEN": "${env:GITHUB_TOKEN}", "LOG_LEVEL": "debug"},
+                "headers": {"Authorization": "Bearer ${secret:api_key}"}}}}]}"#;
+        assert!(WorkspaceManifest::parse(placeholders).is_ok());
+    }
+
+    #[test]
+    fn test_resolve_reference() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("token"), "s3cret\n").unwrap();
+
+        assert_eq!(
+            resolve_reference("${file:token}", dir.path()).unwrap(),
+            "s3cret"
+        );
+        assert_eq!(resolve_reference("plain", dir.path()).unwrap(), "plain");
+        assert!(resolve_reference("${env:MCPMUX_TEST_UNSET_VAR}", dir.path()).is_err());
+    }
+}
@@ -0,0 +1,124 @@
+//! Stdio bridge
+//!
+//! Lets stdio-only MCP clients use the gateway: JSON-RPC messages read from
+//! the client (newline-delimited, as for any stdio server) are forwarded to
+//! the gateway's Streamable HTTP endpoint over TCP or the Unix domain socket,
+//! and everything the gateway sends back, including server-initiated
+//! notifications, is written to the client.
+
+use anyhow::{Context, Result};
+use futures::future::{BoxFuture, FutureExt};
+use rmcp::{
+    transport::{
+        async_rw::AsyncRwTransport, streamable_http_client::StreamableHttpClientTransportConfig,
+        StreamableHttpClientTransport, Transport,
+    },
+    RoleServer,
+};
+use std::path::PathBuf;
+use tracing::{debug, warn};
+
+/// Where the bridge reaches the gateway
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BridgeTarget {
+    /// Streamable HTTP endpoint URL (e.g. `http://localhost:45818/mcp`)
+    Url(String),
+    /// Gateway Unix domain socket
+    UnixSocket(PathBuf),
+}
+
+impl BridgeTarget {
+    fn client(&self) -> Result<(reqwest::Client, String)> {
+        match self {
+            BridgeTarget::Url(url) => Ok((reqwest::Client::new(), url.clone())),
+            #[cfg(unix)]
+            BridgeTarget::UnixSocket(path) => {
+                let client = reqwest::Client::builder()
+                    .unix_socket(path.as_path())
+                    .build()
+                    .context("Failed to build socket client")?;
+                // The host is ignored when connecting over the socket
+                Ok((client, "http://localhost/mcp".to_string()))
+            }
+            #[cfg(not(unix))]
+            BridgeTarget::UnixSocket(_) => {
+                anyhow::bail!("Unix domain sockets are not supported on this platform")
+            }
+        }
+    }
+}
+
+/// Bridge this process's stdin/stdout to the gateway until either side closes.
+///
+/// `access_token` is sent as the Bearer token on every request.
+pub async fn run_stdio_bridge(target: &BridgeTarget, access_token: Option<String>) -> Result<()> {
+    let (stdin, stdout) = rmcp::transport::stdio();
+    run_bridge(
+        target,
+        access_token,
+        AsyncRwTransport::new_server(stdin, stdout),
+    )
+    .await
+}
+
+/// Bridge an MCP client transport to the gateway until either side closes
+pub async fn run_bridge<T>(
+    target: &BridgeTarget,
+    access_token: Option<String>,
+    mut client: T,
+) -> Result<()>
+where
+    T: Transport<RoleServer>,
+{
+    let (http_client, uri) = target.client()?;
+    let mut config = StreamableHttpClientTransportConfig::with_uri(uri);
+    if let Some(token) = access_token {
+        config = config.auth_header(token);
+    }
+    let mut gateway = StreamableHttpClientTransport::with_client(http_client, config);
+
+    // Sends are applied in order by a single task, so a slow POST never
+    // stops responses and notifications from flowing back to the client
+    let (send_tx, mut send_rx) = tokio::sync::mpsc::unbounded_channel::<BoxFuture<'static, ()>>();
+    let sender = tokio::spawn(async move {
+        while let Some(send) = send_rx.recv().await {
+            send.await;
+        }
+    });
+
+    loop {
+        tokio::select! {
+            message = client.receive() => {
+                let Some(message) = message else {
+                    debug!("[Bridge] Client closed");
+                    break;
+                };
+                let send = gateway.send(message);
+                let send = async move {
+                    if let Err(e) = send.await {
+                        warn!("[Bridge] Failed to forward message to gateway: {}", e);
+                    }
+                };
+                if send_tx.send(send.boxed()).is_err() {
+                    break;
+                }
+            }
+            message = gateway.receive() => {
+                let Some(message) = message else {
+                    debug!("[Bridge] Gateway closed");
+                    break;
+                };
+                client
+                    .send(message)
+                    .await
+                    .context("Failed to write to client")?;
+            }
+        }
+    }
+
+    drop(send_tx);
+    let _ = sender.await;
+    let _ = gateway.close().await;
+    let _ = client.close().await;
+    Ok(())
+}
@@ -459,6 +459,41 @@ impl MCPNotifier {
                 self.notify_all_list_changed(space_id, true).await;
             }
 
+            DomainEvent::SpaceReadOnlyChanged {
+                space_id,
+                read_only,
+            } => {
+                info!(
+                    space_id = %space_id,
+                    read_only = read_only,
+                    "[MCPNotifier] 📨 SpaceReadOnlyChanged - notifying all clients in space"
+                );
+                self.notify_all_list_changed(space_id, true).await;
+            }
+
+            // ============ Feature Definition Quarantine ============
+            // A changed definition hides the feature; approval brings it back
+            DomainEvent::FeatureDefinitionChanged {
+                space_id,
+                server_id,
+                feature_name,
+                ..
+            }
+            | DomainEvent::FeatureDefinitionApproved {
+                space_id,
+                server_id,
+                feature_name,
+                ..
+            } => {
+                info!(
+                    server_id = %server_id,
+                    space_id = %space_id,
+                    feature = %feature_name,
+                    "[MCPNotifier] 📨 Feature definition quarantine changed - notifying all clients in space"
+                );
+                self.notify_all_list_changed(space_id, true).await;
+            }
+
             // ============ Backend Server Notifications (Pass-through with Throttling) ============
             // IMPORTANT: These events come from backend MCP servers. Some servers are "chatty" and
             // emit list_changed when queried (not just when features actually change). Our throttling
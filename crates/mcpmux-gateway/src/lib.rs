@@ -9,21 +9,22 @@
 //! - Event-driven architecture via DomainEvent consumers
 
 pub mod auth;
+pub mod bridge;
 pub mod consumers;
 pub mod logging;
 pub mod mcp;
 pub mod oauth;
-pub mod permissions;
 pub mod pool;
 pub mod server;
 pub mod services;
 
 pub use auth::AccessKeyAuth;
+pub use bridge::{run_bridge, run_stdio_bridge, BridgeTarget};
 pub use oauth::{OAuthConfig, OAuthManager, OAuthToken};
-pub use permissions::{PermissionFilter, PermissionSet};
 pub use server::{
-    AutoConnectResult, DependenciesBuilder, GatewayConfig, GatewayDependencies, GatewayServer,
-    GatewayState, PendingAuthorization, StartupOrchestrator,
+    AutoConnectResult, ClientAuthMode, DependenciesBuilder, GatewayConfig, GatewayDependencies,
+    GatewayServer, GatewayState, PendingAuthorization, StartupOrchestrator, TlsCertificateSource,
+    TlsConfig, UnixSocketConfig,
 };
 
 // Pool module - SOLID architecture
@@ -57,6 +58,7 @@ pub use pool::{
     PoolServices,
     PoolStats,
     ReconnectResult,
+    RequestQueueStats,
     ResolvedTransport,
     // Routing types
     RoutedPrompt,
@@ -75,7 +77,11 @@ pub use pool::{
 };
 
 // Services module
-pub use services::{EventEmitter, GrantService, PrefixCacheService};
+pub use services::{
+    ApprovalDecision, ApprovalSession, ArgumentPolicyService, ArgumentValidationService,
+    EventEmitter, GrantService, PrefixCacheService, ToolApprovalRequest, ToolApprovalService,
+    ToolRateLimitService,
+};
 
 // MCP module (rmcp-based implementation)
 pub use mcp::McpMuxGatewayHandler;
@@ -4,17 +4,31 @@
 //! and resources from multiple backend MCP servers.
 
 use anyhow::Result;
+use mcpmux_core::ApprovalPolicy;
+use mcpmux_core::ArgumentPolicyViolation;
+use mcpmux_core::RateLimitExceeded;
+use mcpmux_core::ServerFeature;
 use rmcp::{
     model::*,
-    service::{NotificationContext, RequestContext},
+    service::{ElicitationMode, NotificationContext, Peer, RequestContext},
     ErrorData as McpError, RoleServer, ServerHandler,
 };
+use std::collections::HashMap;
 use std::sync::Arc;
 use tracing::{debug, info, warn};
 
-use super::context::{extract_oauth_context, OAuthContext};
+use super::context::{extract_oauth_context, extract_session_id, OAuthContext};
+use super::meta_tools;
 use crate::consumers::MCPNotifier;
 use crate::server::ServiceContainer;
+use crate::services::{ApprovalDecision, ApprovalSession, ToolApprovalRequest, APPROVAL_TIMEOUT};
+
+/// Elicitation field for "remember for this session"
+const REMEMBER_FIELD: &str = "remember";
+
+/// JSON-RPC error code for tool calls refused by a rate limit or quota
+/// (`data.retryAfterSeconds` says when to retry)
+const RATE_LIMITED: ErrorCode = ErrorCode(-32029);
 
 /// McpMux Gateway Handler
 ///
@@ -26,16 +40,26 @@ use crate::server::ServiceContainer;
 pub struct McpMuxGatewayHandler {
     pub services: Arc<ServiceContainer>,
     pub notification_bridge: Arc<MCPNotifier>,
+    /// Clears "remember for this session" approvals when the session ends
+    approval_session: Arc<ApprovalSession>,
 }
 
 impl McpMuxGatewayHandler {
     pub fn new(services: Arc<ServiceContainer>, notification_bridge: Arc<MCPNotifier>) -> Self {
+        let approval_session =
+            Arc::new(ApprovalSession::new(services.tool_approval_service.clone()));
         Self {
             services,
             notification_bridge,
+            approval_session,
         }
     }
 
+    /// A handler for a new MCP session (shares services, not session state)
+    pub fn for_session(&self) -> Self {
+        Self::new(self.services.clone(), self.notification_bridge.clone())
+    }
+
     /// Extract OAuth context from request extensions, with session fallback
     ///
     /// Tries to get OAuth context from headers first (injected by middleware).
@@ -81,6 +105,476 @@ impl McpMuxGatewayHandler {
         }
     }
 
+    /// Tools visible to a client, as presented to that client
+    ///
+    /// Tools get qualified names (prefix.tool_name); the client's compatibility
+    /// profile (if any) then rewrites names, schemas and descriptions.
+    async fn client_tools(&self, oauth_ctx: &OAuthContext) -> Result<Vec<Tool>, McpError> {
+        let tools = self.granted_tools(oauth_ctx).await?;
+
+        // Trim to the client's tool budget (pinned and most used tools first)
+        let space_id = oauth_ctx.space_id.to_string();
+        let tools = self
+            .services
+            .tool_budget_service
+            .apply(&oauth_ctx.client_id, &space_id, tools)
+            .await;
+
+        Ok(self
+            .present_tools(oauth_ctx, &tools)
+            .await
+            .into_iter()
+            .map(|(_, tool)| tool)
+            .collect())
+    }
+
+    /// Present tool features to a client, paired with their qualified names
+    ///
+    /// Used for tools/list and for search_tools results, so both show the
+    /// same names and schemas.
+    async fn present_tools(
+        &self,
+        oauth_ctx: &OAuthContext,
+        tools: &[ServerFeature],
+    ) -> Vec<(String, Tool)> {
+        // Convert to MCP Tool types with qualified names (prefix.tool_name)
+        let mcp_tools: Vec<Tool> = tools
+            .iter()
+            .filter_map(|f| {
+                f.raw_json.as_ref().and_then(|json| {
+                    let mut tool: Tool = serde_json::from_value(json.clone()).ok()?;
+                    // Replace name with qualified name (prefix.tool_name)
+                    tool.name = f.qualified_name().into();
+                    Some(tool)
+                })
+            })
+            .collect();
+        let qualified: Vec<String> = mcp_tools.iter().map(|t| t.name.to_string()).collect();
+
+        let profile = self
+            .services
+            .client_compat_service
+            .profile_for_client(&oauth_ctx.client_id)
+            .await
+            .unwrap_or_else(|e| {
+                warn!("Failed to resolve client compatibility profile: {}", e);
+                None
+            });
+
+        let presented = match profile {
+            Some(profile) => {
+                debug!(profile = %profile.name, "Applying client compatibility profile");
+                self.services
+                    .client_compat_service
+                    .apply(&oauth_ctx.client_id, &profile, mcp_tools)
+            }
+            None => mcp_tools,
+        };
+        qualified.into_iter().zip(presented).collect()
+    }
+
+    /// Tool features granted to a client in its space
+    ///
+    /// Input schemas are rewritten by the granted feature sets' argument policies.
+    async fn granted_tools(
+        &self,
+        oauth_ctx: &OAuthContext,
+    ) -> Result<Vec<ServerFeature>, McpError> {
+        // Get client's grants
+        let feature_set_ids = self
+            .services
+            .authorization_service
+            .get_client_grants(&oauth_ctx.client_id, &oauth_ctx.space_id)
+            .await
+            .map_err(|e| McpError::internal_error(format!("Failed to get grants: {}", e), None))?;
+
+        // Get tools via FeatureService
+        let mut tools = self
+            .services
+            .pool_services
+            .feature_service
+            .get_tools_for_grants(&oauth_ctx.space_id.to_string(), &feature_set_ids)
+            .await
+            .map_err(|e| McpError::internal_error(format!("Failed to get tools: {}", e), None))?;
+
+        self.services
+            .pool_services
+            .argument_policy_service
+            .apply_to_tools(&feature_set_ids, &mut tools)
+            .await;
+        Ok(tools)
+    }
+
+    /// Handle the `search_tools` meta-tool over the client's granted tools
+    ///
+    /// Results are presented like tools/list (including compatibility
+    /// profile rewrites), so returned names can be passed to `invoke_tool`.
+    async fn search_tools(
+        &self,
+        oauth_ctx: &OAuthContext,
+        arguments: &serde_json::Value,
+    ) -> Result<CallToolResult, McpError> {
+        let request = meta_tools::parse_search_arguments(arguments)
+            .map_err(|e| McpError::invalid_params(e, None))?;
+
+        let tools = self.granted_tools(oauth_ctx).await?;
+        // Names are rewritten over the whole catalog so they match what
+        // invoke_tool resolves
+        let presented: HashMap<String, Tool> = self
+            .present_tools(oauth_ctx, &tools)
+            .await
+            .into_iter()
+            .collect();
+        let matches: Vec<&Tool> = meta_tools::search_features(&tools, &request)
+            .into_iter()
+            .filter_map(|f| presented.get(&f.qualified_name()))
+            .collect();
+        let results = &matches[..matches.len().min(request.limit)];
+
+        debug!(
+            query = %request.query,
+            matches = matches.len(),
+            returned = results.len(),
+            "search_tools"
+        );
+
+        let body = meta_tools::search_results_json(results, matches.len());
+        Ok(CallToolResult::success(vec![Content::text(
+            body.to_string(),
+        )]))
+    }
+
+    /// Map a tool name as presented to the client back to its qualified name
+    async fn resolve_client_tool_name(
+        &self,
+        oauth_ctx: &OAuthContext,
+        name: &str,
+    ) -> Result<String, McpError> {
+        let compat = &self.services.client_compat_service;
+        if let Some(qualified) = compat.resolve_tool_name(&oauth_ctx.client_id, name) {
+            return Ok(qualified);
+        }
+
+        // No mapping recorded (e.g. gateway restarted since tools/list):
+        // rebuild it if the client's profile rewrites names
+        let rewrites = compat
+            .profile_for_client(&oauth_ctx.client_id)
+            .await
+            .ok()
+            .flatten()
+            .is_some_and(|p| p.rewrites_names());
+        if rewrites {
+            // Meta-tool clients see names rewritten over the whole catalog
+            if compat.meta_tools_enabled(&oauth_ctx.client_id).await {
+                let tools = self.granted_tools(oauth_ctx).await?;
+                self.present_tools(oauth_ctx, &tools).await;
+            } else {
+                self.client_tools(oauth_ctx).await?;
+            }
+            if let Some(qualified) = compat.resolve_tool_name(&oauth_ctx.client_id, name) {
+                return Ok(qualified);
+            }
+        }
+
+        Ok(name.to_string())
+    }
+
+    /// Apply the tool's approval policy, holding `ask` calls until a decision.
+    ///
+    /// Returns the reason when the call must not run. Tools that aren't
+    /// granted pass through; routing rejects them.
+    async fn authorize_tool_call(
+        &self,
+        oauth_ctx: &OAuthContext,
+        context: &RequestContext<RoleServer>,
+        tool_name: &str,
+        arguments: &serde_json::Value,
+    ) -> Result<Option<String>, McpError> {
+        let Some(feature) = self.granted_tools(oauth_ctx).await?.into_iter().find(|f| {
+            f.qualified_name() == tool_name || f.qualified_name_with_server_id() == tool_name
+        }) else {
+            return Ok(None);
+        };
+
+        let approvals = &self.services.tool_approval_service;
+        let policy = approvals
+            .policy_for(&oauth_ctx.space_id.to_string(), &feature)
+            .await
+            .map_err(|e| {
+                McpError::internal_error(format!("Failed to load approval policy: {}", e), None)
+            })?;
+
+        match policy {
+            ApprovalPolicy::Allow => Ok(None),
+            ApprovalPolicy::Deny => {
+                approvals
+                    .record_denied(oauth_ctx.space_id, &oauth_ctx.client_id, &feature)
+                    .await;
+                Ok(Some(format!(
+                    "Tool '{}' is blocked by an approval policy",
+                    tool_name
+                )))
+            }
+            ApprovalPolicy::Ask => {
+                let session_id = extract_session_id(&context.extensions);
+                if approvals.is_remembered(&oauth_ctx.client_id, session_id.as_deref(), feature.id)
+                {
+                    return Ok(None);
+                }
+                if let Some(session_id) = &session_id {
+                    self.approval_session.bind(session_id);
+                }
+
+                let request = ToolApprovalRequest {
+                    request_id: uuid::Uuid::new_v4(),
+                    space_id: oauth_ctx.space_id,
+                    client_id: oauth_ctx.client_id.clone(),
+                    session_id,
+                    server_id: feature.server_id.clone(),
+                    feature_id: feature.id,
+                    tool_name: tool_name.to_string(),
+                    arguments: arguments.clone(),
+                    destructive: feature.is_destructive(),
+                    requested_at: chrono::Utc::now(),
+                };
+
+                // Prefer asking the client's user; fall back to the desktop app
+                let decision = match self.elicit_approval(&context.peer, &request).await {
+                    Some(decision) => decision,
+                    None => approvals.request_approval(request.clone()).await,
+                };
+                approvals.record_decision(&request, &decision).await;
+
+                Ok((!decision.approved).then(|| {
+                    format!(
+                        "Tool call '{}' was not approved ({})",
+                        tool_name, decision.decided_by
+                    )
+                }))
+            }
+        }
+    }
+
+    /// Ask the client's user to approve a tool call via elicitation.
+    ///
+    /// Returns None when the client doesn't support form elicitation or the
+    /// request fails, so the caller can fall back to the desktop app.
+    async fn elicit_approval(
+        &self,
+        peer: &Peer<RoleServer>,
+        request: &ToolApprovalRequest,
+    ) -> Option<ApprovalDecision> {
+        if !peer
+            .supported_elicitation_modes()
+            .contains(&ElicitationMode::Form)
+        {
+            return None;
+        }
+
+        let requested_schema = ElicitationSchema::builder()
+            .optional_bool_with(REMEMBER_FIELD, |b| {
+                b.title("Remember for this session")
+                    .description("Don't ask again for this tool until the session ends")
+                    .with_default(false)
+            })
+            .build()
+            .ok()?;
+        let params = CreateElicitationRequestParams::FormElicitationParams {
+            meta: None,
+            message: format!(
+                "Allow the tool call '{}'{}?\n\nArguments: {}",
+                request.tool_name,
+                if request.destructive {
+                    " (marked destructive)"
+                } else {
+                    ""
+                },
+                request.arguments
+            ),
+            requested_schema,
+        };
+
+        match peer
+            .create_elicitation_with_timeout(params, Some(APPROVAL_TIMEOUT))
+            .await
+        {
+            Ok(result) => {
+                let approved = result.action == ElicitationAction::Accept;
+                let remember = approved
+                    && result
+                        .content
+                        .as_ref()
+                        .and_then(|c| c.get(REMEMBER_FIELD))
+                        .and_then(|r| r.as_bool())
+                        .unwrap_or(false);
+                Some(ApprovalDecision {
+                    approved,
+                    remember,
+                    decided_by: "client".to_string(),
+                })
+            }
+            Err(e) => {
+                warn!(
+                    tool = %request.tool_name,
+                    "Elicitation failed, asking desktop app instead: {}", e
+                );
+                None
+            }
+        }
+    }
+
+    /// Call a backend tool by qualified name (approval, grant checks, routing, usage tracking)
+    async fn dispatch_tool(
+        &self,
+        oauth_ctx: &OAuthContext,
+        context: &RequestContext<RoleServer>,
+        tool_name: &str,
+        arguments: serde_json::Value,
+    ) -> Result<CallToolResult, McpError> {
+        if let Some(reason) = self
+            .authorize_tool_call(oauth_ctx, context, tool_name, &arguments)
+            .await?
+        {
+            info!(tool = %tool_name, "call_tool refused: {}", reason);
+            return Ok(CallToolResult::error(vec![Content::text(reason)]));
+        }
+
+        // Get client's feature set grants for authorization
+        let mut feature_set_ids = self
+            .services
+            .authorization_service
+            .get_client_grants(&oauth_ctx.client_id, &oauth_ctx.space_id)
+            .await
+            .map_err(|e| McpError::internal_error(format!("Failed to get grants: {}", e), None))?;
+
+        let space_id = oauth_ctx.space_id.to_string();
+        let backend_tool = self
+            .services
+            .pool_services
+            .feature_service
+            .find_server_for_qualified_tool(&space_id, tool_name)
+            .await
+            .ok()
+            .flatten();
+
+        // Reserve the call against usage-limited grants before dispatching, so
+        // concurrent calls cannot overrun a grant's limit
+        if let Some((ref server_id, ref backend_name)) = backend_tool {
+            let used_up = self
+                .services
+                .grant_service
+                .reserve_tool_call(
+                    &oauth_ctx.client_id,
+                    &oauth_ctx.space_id,
+                    &feature_set_ids,
+                    server_id,
+                    backend_name,
+                )
+                .await
+                .map_err(|e| {
+                    McpError::internal_error(format!("Failed to count call: {}", e), None)
+                })?;
+            feature_set_ids.retain(|id| !used_up.contains(id));
+        }
+
+        // Call tool via routing service (handles auth and routing)
+        let tool_result = self
+            .services
+            .pool_services
+            .routing_service
+            .call_tool(
+                oauth_ctx.space_id,
+                &oauth_ctx.client_id,
+                &feature_set_ids,
+                tool_name,
+                arguments,
+            )
+            .await
+            .map_err(|e| match e.downcast_ref::<RateLimitExceeded>() {
+                Some(refused) => McpError::new(
+                    RATE_LIMITED,
+                    refused.to_string(),
+                    Some(serde_json::json!({
+                        "target": refused.target,
+                        "retryAfterSeconds": refused.retry_after_secs,
+                    })),
+                ),
+                None if e.is::<ArgumentPolicyViolation>() => {
+                    McpError::invalid_params(e.to_string(), None)
+                }
+                None => McpError::internal_error(format!("Tool call failed: {}", e), None),
+            })?;
+
+        // Record the call for usage-based tool budget prioritization
+        if let Some((server_id, backend_name)) = backend_tool {
+            self.services
+                .tool_budget_service
+                .record_call(&space_id, &server_id, &backend_name)
+                .await;
+        }
+
+        // Convert ToolCallResult to MCP CallToolResult
+        let content: Vec<Content> = tool_result
+            .content
+            .into_iter()
+            .filter_map(|v| serde_json::from_value(v).ok())
+            .collect();
+
+        // Log result summary - show content types and approximate sizes
+        let content_summary: Vec<String> = content
+            .iter()
+            .map(|c| {
+                // Content is Annotated<RawContent>, serialize to inspect type
+                if let Ok(json) = serde_json::to_value(c) {
+                    let content_type = json
+                        .get("type")
+                        .and_then(|t| t.as_str())
+                        .unwrap_or("unknown");
+                    match content_type {
+                        "text" => {
+                            let len = json
+                                .get("text")
+                                .and_then(|t| t.as_str())
+                                .map(|s| s.len())
+                                .unwrap_or(0);
+                            format!("text({}c)", len)
+                        }
+                        "image" => {
+                            let mime = json.get("mimeType").and_then(|m| m.as_str()).unwrap_or("?");
+                            format!("image({})", mime)
+                        }
+                        "resource" => {
+                            let uri = json
+                                .get("resource")
+                                .and_then(|r| r.get("uri"))
+                                .and_then(|u| u.as_str())
+                                .unwrap_or("?");
+                            format!("resource({})", uri)
+                        }
+                        _ => content_type.to_string(),
+                    }
+                } else {
+                    "?".to_string()
+                }
+            })
+            .collect();
+        debug!(
+            tool = %tool_name,
+            is_error = tool_result.is_error,
+            content = ?content_summary,
+            "call_tool result"
+        );
+
+        let result = CallToolResult {
+            content,
+            structured_content: None,
+            is_error: Some(tool_result.is_error),
+            meta: None,
+        };
+
+        Ok(result)
+    }
+
     /// Build InitializeResult with negotiated protocol version
     fn build_initialize_result(&self, protocol_version: ProtocolVersion) -> InitializeResult {
         InitializeResult {
@@ -190,35 +684,17 @@ impl ServerHandler for McpMuxGatewayHandler {
             .get_oauth_context(&context.extensions)
             .map_err(|e| McpError::invalid_params(e.to_string(), None))?;
 
-        // Get client's grants
-        let feature_set_ids = self
+        // Meta-tool mode: the backend catalog is discovered via search_tools
+        let mcp_tools = if self
             .services
-            .authorization_service
-            .get_client_grants(&oauth_ctx.client_id, &oauth_ctx.space_id)
+            .client_compat_service
+            .meta_tools_enabled(&oauth_ctx.client_id)
             .await
-            .map_err(|e| McpError::internal_error(format!("Failed to get grants: {}", e), None))?;
-
-        // Get tools via FeatureService
-        let tools = self
-            .services
-            .pool_services
-            .feature_service
-            .get_tools_for_grants(&oauth_ctx.space_id.to_string(), &feature_set_ids)
-            .await
-            .map_err(|e| McpError::internal_error(format!("Failed to get tools: {}", e), None))?;
-
-        // Convert to MCP Tool types with qualified names (prefix.tool_name)
-        let mcp_tools: Vec<Tool> = tools
-            .iter()
-            .filter_map(|f| {
-                f.raw_json.as_ref().and_then(|json| {
-                    let mut tool: Tool = serde_json::from_value(json.clone()).ok()?;
-                    // Replace name with qualified name (prefix.tool_name)
-                    tool.name = f.qualified_name().into();
-                    Some(tool)
-                })
-            })
-            .collect();
+        {
+            meta_tools::meta_tool_definitions()
+        } else {
+            self.client_tools(&oauth_ctx).await?
+        };
 
         // Log tool names at DEBUG level for visibility
         let tool_names: Vec<String> = mcp_tools.iter().map(|t| t.name.to_string()).collect();
@@ -247,88 +723,39 @@ impl ServerHandler for McpMuxGatewayHandler {
             "call_tool"
         );
 
-        // Get client's feature set grants for authorization
-        let feature_set_ids = self
-            .services
-            .authorization_service
-            .get_client_grants(&oauth_ctx.client_id, &oauth_ctx.space_id)
-            .await
-            .map_err(|e| McpError::internal_error(format!("Failed to get grants: {}", e), None))?;
+        let arguments =
+            serde_json::to_value(params.arguments.unwrap_or_default()).unwrap_or_default();
 
-        // Call tool via routing service (handles auth and routing)
-        let tool_result = self
+        // Meta-tool mode: gateway-owned search_tools / invoke_tool
+        if self
             .services
-            .pool_services
-            .routing_service
-            .call_tool(
-                oauth_ctx.space_id,
-                &feature_set_ids,
-                &params.name,
-                serde_json::to_value(params.arguments.unwrap_or_default()).unwrap_or_default(),
-            )
+            .client_compat_service
+            .meta_tools_enabled(&oauth_ctx.client_id)
             .await
-            .map_err(|e| McpError::internal_error(format!("Tool call failed: {}", e), None))?;
-
-        // Convert ToolCallResult to MCP CallToolResult
-        let content: Vec<Content> = tool_result
-            .content
-            .into_iter()
-            .filter_map(|v| serde_json::from_value(v).ok())
-            .collect();
-
-        // Log result summary - show content types and approximate sizes
-        let content_summary: Vec<String> = content
-            .iter()
-            .map(|c| {
-                // Content is Annotated<RawContent>, serialize to inspect type
-                if let Ok(json) = serde_json::to_value(c) {
-                    let content_type = json
-                        .get("type")
-                        .and_then(|t| t.as_str())
-                        .unwrap_or("unknown");
-                    match content_type {
-                        "text" => {
-                            let len = json
-                                .get("text")
-                                .and_then(|t| t.as_str())
-                                .map(|s| s.len())
-                                .unwrap_or(0);
-                            format!("text({}c)", len)
-                        }
-                        "image" => {
-                            let mime = json.get("mimeType").and_then(|m| m.as_str()).unwrap_or("?");
-                            format!("image({})", mime)
-                        }
-                        "resource" => {
-                            let uri = json
-                                .get("resource")
-                                .and_then(|r| r.get("uri"))
-                                .and_then(|u| u.as_str())
-                                .unwrap_or("?");
-                            format!("resource({})", uri)
-                        }
-                        _ => content_type.to_string(),
-                    }
-                } else {
-                    "?".to_string()
+        {
+            match params.name.as_ref() {
+                meta_tools::SEARCH_TOOLS => return self.search_tools(&oauth_ctx, &arguments).await,
+                meta_tools::INVOKE_TOOL => {
+                    let (tool_name, arguments) = meta_tools::parse_invoke_arguments(&arguments)
+                        .map_err(|e| McpError::invalid_params(e, None))?;
+                    let tool_name = self
+                        .resolve_client_tool_name(&oauth_ctx, &tool_name)
+                        .await?;
+                    return self
+                        .dispatch_tool(&oauth_ctx, &context, &tool_name, arguments)
+                        .await;
                 }
-            })
-            .collect();
-        debug!(
-            tool = %params.name,
-            is_error = tool_result.is_error,
-            content = ?content_summary,
-            "call_tool result"
-        );
+                _ => {}
+            }
+        }
 
-        let result = CallToolResult {
-            content,
-            structured_content: None,
-            is_error: Some(tool_result.is_error),
-            meta: None,
-        };
+        // Map names rewritten by a client compatibility profile back to qualified names
+        let tool_name = self
+            .resolve_client_tool_name(&oauth_ctx, &params.name)
+            .await?;
 
-        Ok(result)
+        self.dispatch_tool(&oauth_ctx, &context, &tool_name, arguments)
+            .await
     }
 
     async fn list_prompts(
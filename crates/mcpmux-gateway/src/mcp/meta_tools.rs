@@ -0,0 +1,329 @@
+//! Gateway-owned meta-tools
+//!
+//! In meta-tool mode a client's `tools/list` contains only `search_tools` and
+//! `invoke_tool`. The model searches the granted tool catalog on demand and
+//! invokes tools by the name search returned, instead of receiving every
+//! schema up front.
+
+use mcpmux_core::ServerFeature;
+use rmcp::model::{JsonObject, Tool};
+use serde_json::{json, Value};
+use std::sync::Arc;
+
+/// Search the granted tool catalog
+pub const SEARCH_TOOLS: &str = "search_tools";
+
+/// Invoke a granted tool by qualified name
+pub const INVOKE_TOOL: &str = "invoke_tool";
+
+/// Default number of search results
+const DEFAULT_SEARCH_LIMIT: usize = 10;
+
+/// Upper bound on search results (keeps responses small)
+const MAX_SEARCH_LIMIT: usize = 50;
+
+/// Definitions of the meta-tools returned by `tools/list`
+pub fn meta_tool_definitions() -> Vec<Tool> {
+    vec![
+        Tool::new(
+            SEARCH_TOOLS,
+            "Search the available tools by keyword. Returns matching tool names, \
+             descriptions and input schemas. Call invoke_tool with a returned name \
+             to run a tool.",
+            schema(json!({
+                "type": "object",
+                "properties": {
+                    "query": {
+                        "type": "string",
+                        "description": "Keywords matched against tool names and descriptions"
+                    },
+                    "limit": {
+                        "type": "integer",
+                        "description": "Maximum number of results (default 10, max 50)",
+                        "minimum": 1
+                    },
+                    "fuzzy": {
+                        "type": "boolean",
+                        "description": "Tolerate typos in keywords (default true)"
+                    }
+                },
+                "required": ["query"]
+            })),
+        ),
+        Tool::new(
+            INVOKE_TOOL,
+            "Invoke a tool found with search_tools.",
+            schema(json!({
+                "type": "object",
+                "properties": {
+                    "name": {
+                        "type": "string",
+                        "description": "Tool name as returned by search_tools"
+                    },
+                    "arguments": {
+                        "type": "object",
+                        "description": "Arguments matching the tool's input schema"
+                    }
+                },
+                "required": ["name"]
+            })),
+        ),
+    ]
+}
+
+fn schema(value: Value) -> Arc<JsonObject> {
+    Arc::new(value.as_object().cloned().unwrap_or_default())
+}
+
+/// Parsed `search_tools` arguments
+#[derive(Debug, Clone, PartialEq)]
+pub struct SearchRequest {
+    pub query: String,
+    pub limit: usize,
+    pub fuzzy: bool,
+}
+
+/// Parse `search_tools` arguments
+pub fn parse_search_arguments(arguments: &Value) -> Result<SearchRequest, String> {
+    let query = arguments
+        .get("query")
+        .and_then(|q| q.as_str())
+        .ok_or_else(|| "search_tools requires a 'query' string".to_string())?
+        .to_string();
+    let limit = arguments
+        .get("limit")
+        .and_then(|l| l.as_u64())
+        .map(|l| (l as usize).clamp(1, MAX_SEARCH_LIMIT))
+        .unwrap_or(DEFAULT_SEARCH_LIMIT);
+    let fuzzy = arguments
+        .get("fuzzy")
+        .and_then(|f| f.as_bool())
+        .unwrap_or(true);
+
+    Ok(SearchRequest {
+        query,
+        limit,
+        fuzzy,
+    })
+}
+
+/// Parse `invoke_tool` arguments into (tool name, tool arguments)
+pub fn parse_invoke_arguments(arguments: &Value) -> Result<(String, Value), String> {
+    let name = arguments
+        .get("name")
+        .and_then(|n| n.as_str())
+        .filter(|n| !n.is_empty())
+        .ok_or_else(|| "invoke_tool requires a 'name' string".to_string())?
+        .to_string();
+    let tool_arguments = match arguments.get("arguments") {
+        None | Some(Value::Null) => json!({}),
+        Some(Value::Object(map)) => Value::Object(map.clone()),
+        Some(_) => return Err("invoke_tool 'arguments' must be an object".to_string()),
+    };
+
+    Ok((name, tool_arguments))
+}
+
+/// Rank tools against a keyword query.
+///
+/// Each query term scores against the qualified name (weighted) and the
+/// description. With `fuzzy`, terms also match name words within a small edit
+/// distance. Tools must match every term. All matches are returned, best
+/// first; callers apply `request.limit`.
+pub fn search_features<'a>(
+    tools: &'a [ServerFeature],
+    request: &SearchRequest,
+) -> Vec<&'a ServerFeature> {
+    let terms: Vec<String> = request
+        .query
+        .split_whitespace()
+        .map(|t| t.to_lowercase())
+        .collect();
+    if terms.is_empty() {
+        return Vec::new();
+    }
+
+    let mut scored: Vec<(u32, &ServerFeature)> = tools
+        .iter()
+        .filter_map(|tool| {
+            let name = tool.qualified_name().to_lowercase();
+            let description = tool.description.as_deref().unwrap_or("").to_lowercase();
+            let words: Vec<&str> = name
+                .split(|c: char| !c.is_alphanumeric())
+                .filter(|w| !w.is_empty())
+                .collect();
+
+            let mut score = 0;
+            for term in &terms {
+                let term_score = if words.iter().any(|w| w == term) {
+                    5
+                } else if name.contains(term.as_str()) {
+                    3
+                } else if description.contains(term.as_str()) {
+                    1
+                } else if request.fuzzy
+                    && words
+                        .iter()
+                        .any(|w| edit_distance(w, term) <= max_typos(term))
+                {
+                    2
+                } else {
+                    return None;
+                };
+                score += term_score;
+            }
+            Some((score, tool))
+        })
+        .collect();
+
+    // Highest score first; name as tie-breaker for stable results
+    scored.sort_by(|(sa, a), (sb, b)| {
+        sb.cmp(sa)
+            .then_with(|| a.qualified_name().cmp(&b.qualified_name()))
+    });
+    scored.into_iter().map(|(_, tool)| tool).collect()
+}
+
+/// Allowed typos for a term (none for very short terms)
+fn max_typos(term: &str) -> usize {
+    match term.chars().count() {
+        0..=3 => 0,
+        4..=7 => 1,
+        _ => 2,
+    }
+}
+
+/// Levenshtein distance between two strings
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut prev: Vec<usize> = (0..=b.len()).collect();
+    let mut curr = vec![0; b.len() + 1];
+
+    for (i, ca) in a.chars().enumerate() {
+        curr[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let cost = usize::from(ca != *cb);
+            curr[j + 1] = (prev[j] + cost).min(prev[j + 1] + 1).min(curr[j] + 1);
+        }
+        std::mem::swap(&mut prev, &mut curr);
+    }
+
+    prev[b.len()]
+}
+
+/// Render search results with full tool definitions, as presented in tools/list
+pub fn search_results_json(results: &[&Tool], total_matches: usize) -> Value {
+    let tools: Vec<Value> = results
+        .iter()
+        .map(|tool| {
+            json!({
+                "name": tool.name,
+                "description": tool.description,
+                "inputSchema": tool.input_schema,
+            })
+        })
+        .collect();
+
+    json!({
+        "tools": tools,
+        "total_matches": total_matches,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tool(server: &str, name: &str, description: &str) -> ServerFeature {
+        let mut feature = ServerFeature::tool("space", server, name);
+        feature.description = Some(description.to_string());
+        feature
+    }
+
+    fn catalog() -> Vec<ServerFeature> {
+        vec![
+            tool(
+                "github",
+                "create_issue",
+                "Create a new issue in a repository",
+            ),
+            tool("github", "list_issues", "List issues in a repository"),
+            tool("slack", "post_message", "Post a message to a channel"),
+        ]
+    }
+
+    fn request(query: &str, fuzzy: bool) -> SearchRequest {
+        SearchRequest {
+            query: query.to_string(),
+            limit: 10,
+            fuzzy,
+        }
+    }
+
+    #[test]
+    fn test_search_ranks_name_matches_first() {
+        let tools = catalog();
+        let results = search_features(&tools, &request("issue", false));
+
+        let names: Vec<_> = results.iter().map(|t| t.feature_name.as_str()).collect();
+        assert_eq!(names, vec!["create_issue", "list_issues"]);
+    }
+
+    #[test]
+    fn test_search_requires_all_terms() {
+        let tools = catalog();
+        let results = search_features(&tools, &request("create issue", false));
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].feature_name, "create_issue");
+
+        assert!(search_features(&tools, &request("", false)).is_empty());
+    }
+
+    #[test]
+    fn test_search_fuzzy_tolerates_typos() {
+        let tools = catalog();
+        assert!(search_features(&tools, &request("mesage", false)).is_empty());
+
+        let results = search_features(&tools, &request("mesage", true));
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].feature_name, "post_message");
+    }
+
+    #[test]
+    fn test_parse_invoke_arguments() {
+        let (name, args) = parse_invoke_arguments(
+            &json!({ "name": "github_create_issue", "arguments": { "title": "x" } }),
+        )
+        .unwrap();
+        assert_eq!(name, "github_create_issue");
+        assert_eq!(args, json!({ "title": "x" }));
+
+        let (_, args) = parse_invoke_arguments(&json!({ "name": "a_b" })).unwrap();
+        assert_eq!(args, json!({}));
+
+        assert!(parse_invoke_arguments(&json!({})).is_err());
+        assert!(parse_invoke_arguments(&json!({ "name": "a_b", "arguments": [] })).is_err());
+    }
+
+    #[test]
+    fn test_search_results_use_presented_tools() {
+        let tool = Tool::new(
+            "github_create_issue_1a2b3c4d",
+            "Create an issue",
+            schema(json!({ "type": "object" })),
+        );
+        let body = search_results_json(&[&tool], 3);
+
+        assert_eq!(body["tools"][0]["name"], "github_create_issue_1a2b3c4d");
+        assert_eq!(body["tools"][0]["inputSchema"], json!({ "type": "object" }));
+        assert_eq!(body["total_matches"], 3);
+    }
+
+    #[test]
+    fn test_parse_search_arguments_clamps_limit() {
+        let request = parse_search_arguments(&json!({ "query": "x", "limit": 500 })).unwrap();
+        assert_eq!(request.limit, 50);
+        assert!(request.fuzzy);
+        assert!(parse_search_arguments(&json!({ "limit": 5 })).is_err());
+    }
+}
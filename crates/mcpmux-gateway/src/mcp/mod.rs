@@ -6,11 +6,13 @@
 //! Architecture:
 //! - `handler`: Implements ServerHandler, delegates to existing services
 //! - `context`: Utilities for extracting OAuth context from requests
+//! - `meta_tools`: search_tools/invoke_tool definitions and catalog search
 //!
 //! Note: MCPNotifier (notification bridge) is now in `consumers/` module.
 
 pub mod context;
 pub mod handler;
+pub mod meta_tools;
 pub mod oauth_middleware;
 
 pub use handler::McpMuxGatewayHandler;
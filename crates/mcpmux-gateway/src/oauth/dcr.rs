@@ -138,6 +138,8 @@ fn build_inbound_client_from_request(
         // MCP client settings
         connection_mode,
         locked_space_id,
+        // Only set for new clients; save_client keeps an existing setting
+        meta_tools: false,
         last_seen,
         created_at,
         updated_at,
@@ -180,6 +182,19 @@ impl DcrError {
 /// - https:// URLs (except for confidential clients with proper secrets)
 /// - http:// URLs to non-loopback addresses
 pub fn validate_redirect_uris(uris: &[String]) -> Result<(), DcrError> {
+    validate_redirect_uris_for_gateway(uris, None)
+}
+
+/// Validate redirect URIs, additionally allowing https:// URIs on the
+/// gateway's own public origin (e.g. "https://mux.example.lan:45818") when
+/// the gateway is exposed beyond loopback with a configured public URL.
+///
+/// The gateway's own OAuth, MCP and metadata endpoints are never accepted
+/// as redirect targets, so codes cannot be bounced back into the gateway.
+pub fn validate_redirect_uris_for_gateway(
+    uris: &[String],
+    gateway_origin: Option<&str>,
+) -> Result<(), DcrError> {
     if uris.is_empty() {
         return Err(DcrError::invalid_redirect_uri(
             "At least one redirect_uri is required",
@@ -195,7 +210,13 @@ pub fn validate_redirect_uris(uris: &[String]) -> Result<(), DcrError> {
         // They don't start with http:// or https://
         let is_custom_scheme = !uri.starts_with("http://") && !uri.starts_with("https://");
 
-        if !is_loopback && !is_custom_scheme {
+        // Same-origin https redirect on the gateway's public hostname
+        let is_gateway_origin = gateway_origin.is_some_and(|origin| {
+            uri.strip_prefix(origin)
+                .is_some_and(|rest| rest.starts_with('/') && !is_gateway_endpoint(rest))
+        });
+
+        if !is_loopback && !is_custom_scheme && !is_gateway_origin {
             warn!(
                 "[DCR] Rejected redirect_uri: {} (must be loopback or custom scheme)",
                 uri
@@ -215,12 +236,38 @@ pub fn validate_redirect_uris(uris: &[String]) -> Result<(), DcrError> {
     Ok(())
 }
 
+/// Paths served by the gateway itself
+fn is_gateway_endpoint(path: &str) -> bool {
+    const GATEWAY_PATHS: [&str; 5] = ["/oauth", "/authorize", "/mcp", "/.well-known", "/health"];
+    let path = path.split(['?', '#']).next().unwrap_or_default();
+    GATEWAY_PATHS.iter().any(|prefix| {
+        path.strip_prefix(prefix)
+            .is_some_and(|rest| rest.is_empty() || rest.starts_with('/'))
+    })
+}
+
+/// The gateway's origin if it is served over https on a non-loopback host
+fn public_origin(base_url: &str) -> Option<String> {
+    let url = url::Url::parse(base_url).ok()?;
+    if url.scheme() != "https" {
+        return None;
+    }
+    match url.host()? {
+        url::Host::Domain(domain) if domain != "localhost" => {}
+        url::Host::Ipv4(ip) if !ip.is_loopback() => {}
+        url::Host::Ipv6(ip) if !ip.is_loopback() => {}
+        _ => return None,
+    }
+    Some(url.origin().ascii_serialization())
+}
+
 /// Process a DCR request and return a registered client or error
 ///
 /// Uses the database as the single source of truth (no in-memory registry)
 pub async fn process_dcr_request(
     repo: &mcpmux_storage::InboundClientRepository,
     request: DcrRequest,
+    gateway_base_url: &str,
 ) -> Result<DcrResponse, DcrError> {
     info!(
         "[DCR] Processing registration for: {} (redirect_uris: {:?})",
@@ -228,7 +275,8 @@ pub async fn process_dcr_request(
     );
 
     // Validate redirect URIs
-    validate_redirect_uris(&request.redirect_uris)?;
+    let gateway_origin = public_origin(gateway_base_url);
+    validate_redirect_uris_for_gateway(&request.redirect_uris, gateway_origin.as_deref())?;
 
     // Check for existing client with same name (idempotent registration by client_name)
     let existing = repo
@@ -418,6 +466,38 @@ mod tests {
         assert!(validate_redirect_uris(&["claude://auth/callback".to_string()]).is_ok());
     }
 
+    #[test]
+    fn test_validate_gateway_origin_uris() {
+        let origin = public_origin("https://mux.example.lan:45818");
+        assert_eq!(origin.as_deref(), Some("https://mux.example.lan:45818"));
+
+        let uris = ["https://mux.example.lan:45818/app/callback".to_string()];
+        assert!(validate_redirect_uris_for_gateway(&uris, origin.as_deref()).is_ok());
+        assert!(validate_redirect_uris(&uris).is_err());
+
+        // Prefix tricks and other hosts are still rejected
+        let evil = ["https://mux.example.lan:45818.evil.com/cb".to_string()];
+        assert!(validate_redirect_uris_for_gateway(&evil, origin.as_deref()).is_err());
+
+        // The gateway's own endpoints are never redirect targets
+        for path in [
+            "/oauth/token",
+            "/authorize?x=1",
+            "/mcp",
+            "/.well-known/oauth",
+        ] {
+            let uris = [format!("https://mux.example.lan:45818{}", path)];
+            assert!(validate_redirect_uris_for_gateway(&uris, origin.as_deref()).is_err());
+        }
+        let uris = ["https://mux.example.lan:45818/mcpapp/cb".to_string()];
+        assert!(validate_redirect_uris_for_gateway(&uris, origin.as_deref()).is_ok());
+
+        // Loopback / plain-http gateways never widen the allowlist
+        assert_eq!(public_origin("http://localhost:45818"), None);
+        assert_eq!(public_origin("https://localhost:45818"), None);
+        assert_eq!(public_origin("https://127.0.0.1:45818"), None);
+    }
+
     #[test]
     fn test_reject_invalid_uris() {
         // Invalid URIs (non-loopback http)
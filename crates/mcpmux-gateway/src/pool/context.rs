@@ -3,6 +3,7 @@
 //! This module provides a context object that bundles per-connection parameters,
 //! reducing function signature complexity throughout the connection pipeline.
 
+use mcpmux_core::ConcurrencyLimit;
 use uuid::Uuid;
 
 use super::transport::ResolvedTransport;
@@ -36,6 +37,9 @@ pub struct ConnectionContext {
     /// - `true`: Don't start OAuth flow or open browser (background reconnection)
     /// - `false`: Full OAuth flow with browser if needed (user clicked Connect)
     pub auto_reconnect: bool,
+
+    /// Limit on in-flight requests to the server (`None` = unlimited)
+    pub concurrency_limit: Option<ConcurrencyLimit>,
 }
 
 impl ConnectionContext {
@@ -46,6 +50,7 @@ impl ConnectionContext {
             server_id: server_id.into(),
             transport,
             auto_reconnect: false,
+            concurrency_limit: None,
         }
     }
 
@@ -55,6 +60,12 @@ impl ConnectionContext {
         self
     }
 
+    /// Set the server's concurrency limit (builder pattern).
+    pub fn with_concurrency_limit(mut self, concurrency_limit: Option<ConcurrencyLimit>) -> Self {
+        self.concurrency_limit = concurrency_limit;
+        self
+    }
+
     /// Convenience: create context for manual user-initiated connection.
     pub fn manual(
         space_id: Uuid,
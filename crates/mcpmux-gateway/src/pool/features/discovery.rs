@@ -1,17 +1,24 @@
 //! Feature Discovery Service - SRP: Discovery & caching
 
 use anyhow::Result;
+use std::collections::HashMap;
 use std::sync::Arc;
+use tokio::sync::broadcast;
 use tracing::{debug, info, warn};
+use uuid::Uuid;
 
 use super::{convert_to_feature, resource_to_feature, CachedFeatures};
 use crate::pool::instance::McpClient;
-use mcpmux_core::{FeatureSetRepository, ServerFeatureRepository};
+use mcpmux_core::{
+    definition_hash, DomainEvent, FeatureSetRepository, FeatureType, ServerFeature,
+    ServerFeatureRepository,
+};
 
 /// Handles feature discovery and caching from MCP clients
 pub struct FeatureDiscoveryService {
     feature_repo: Arc<dyn ServerFeatureRepository>,
     feature_set_repo: Arc<dyn FeatureSetRepository>,
+    event_tx: Option<broadcast::Sender<DomainEvent>>,
 }
 
 impl FeatureDiscoveryService {
@@ -22,6 +29,16 @@ impl FeatureDiscoveryService {
         Self {
             feature_repo,
             feature_set_repo,
+            event_tx: None,
+        }
+    }
+
+    /// Copy of this service that emits definition change events
+    pub fn with_event_tx(&self, event_tx: broadcast::Sender<DomainEvent>) -> Self {
+        Self {
+            feature_repo: self.feature_repo.clone(),
+            feature_set_repo: self.feature_set_repo.clone(),
+            event_tx: Some(event_tx),
         }
     }
 
@@ -84,6 +101,11 @@ impl FeatureDiscoveryService {
             Err(e) => warn!("[FeatureDiscovery] Failed to list resources: {}", e),
         }
 
+        // Quarantine features whose definition changed since it was approved
+        let changes = self
+            .track_definitions(space_id, server_id, &mut discovered)
+            .await;
+
         // Cache all features in database
         let all_features = discovered.all_features();
         if !all_features.is_empty() {
@@ -111,9 +133,124 @@ impl FeatureDiscoveryService {
             );
         }
 
+        for event in changes {
+            if let Some(ref tx) = self.event_tx {
+                let _ = tx.send(event);
+            }
+        }
+
         Ok(discovered)
     }
 
+    /// Compare discovered definitions with the approved ones.
+    ///
+    /// New features are trusted on first discovery. A feature whose definition
+    /// hash differs from its approved hash keeps the approved definition and
+    /// carries the new one as `pending_definition` (quarantined) until the user
+    /// approves it. Returns events for newly detected changes.
+    async fn track_definitions(
+        &self,
+        space_id: &str,
+        server_id: &str,
+        discovered: &mut CachedFeatures,
+    ) -> Vec<DomainEvent> {
+        let existing: HashMap<(FeatureType, String), ServerFeature> =
+            match self.feature_repo.list_for_server(space_id, server_id).await {
+                Ok(features) => features
+                    .into_iter()
+                    .map(|f| ((f.feature_type.clone(), f.feature_name.clone()), f))
+                    .collect(),
+                Err(e) => {
+                    warn!("[FeatureDiscovery] Failed to load cached features: {}", e);
+                    HashMap::new()
+                }
+            };
+
+        let mut events = Vec::new();
+        let features = discovered
+            .tools
+            .iter_mut()
+            .chain(discovered.prompts.iter_mut())
+            .chain(discovered.resources.iter_mut());
+
+        for feature in features {
+            let hash = definition_hash(feature.raw_json.as_ref());
+            let key = (feature.feature_type.clone(), feature.feature_name.clone());
+
+            let Some(previous) = existing.get(&key) else {
+                feature.definition_hash = Some(hash);
+                continue;
+            };
+            feature.id = previous.id;
+
+            match previous.definition_hash {
+                // Unchanged, or cached before definitions were tracked
+                Some(ref approved) if *approved != hash => {}
+                _ => {
+                    feature.definition_hash = Some(hash);
+                    continue;
+                }
+            }
+
+            let new_definition = feature.raw_json.take();
+            let already_pending = previous
+                .pending_definition
+                .as_ref()
+                .is_some_and(|p| definition_hash(Some(p)) == hash);
+
+            // Keep serving nothing but the approved definition
+            feature.raw_json = previous.raw_json.clone();
+            feature.description = previous.description.clone();
+            feature.display_name = previous.display_name.clone();
+            feature.definition_hash = previous.definition_hash.clone();
+            feature.pending_definition = new_definition.clone();
+
+            if !already_pending {
+                warn!(
+                    "[FeatureDiscovery] Definition of {} '{}' on {}/{} changed; quarantined until approved",
+                    feature.feature_type.as_str(),
+                    feature.feature_name,
+                    space_id,
+                    server_id
+                );
+                events.push(DomainEvent::FeatureDefinitionChanged {
+                    space_id: Uuid::parse_str(space_id).unwrap_or_default(),
+                    server_id: server_id.to_string(),
+                    feature_id: feature.id,
+                    feature_type: feature.feature_type.clone(),
+                    feature_name: feature.feature_name.clone(),
+                    old_definition: previous.raw_json.clone(),
+                    new_definition,
+                });
+            }
+        }
+
+        events
+    }
+
+    /// Approve a quarantined feature's pending definition
+    pub async fn approve_pending_definition(
+        &self,
+        feature_id: &Uuid,
+    ) -> Result<Option<ServerFeature>> {
+        let approved = self
+            .feature_repo
+            .approve_pending_definition(feature_id)
+            .await?;
+
+        if let (Some(ref feature), Some(ref tx)) = (&approved, &self.event_tx) {
+            let _ = tx.send(DomainEvent::FeatureDefinitionApproved {
+                space_id: Uuid::parse_str(&feature.space_id).unwrap_or_default(),
+                server_id: feature.server_id.clone(),
+                feature_id: feature.id,
+                feature_type: feature.feature_type.clone(),
+                feature_name: feature.feature_name.clone(),
+            });
+        }
+
+        Ok(approved)
+    }
+
     /// Mark all features for a server as unavailable (on disconnect)
     pub async fn mark_unavailable(&self, space_id: &str, server_id: &str) -> Result<()> {
         self.feature_repo
@@ -2,10 +2,16 @@
 
 use anyhow::Result;
 use std::sync::Arc;
+use tokio::sync::broadcast;
+use uuid::Uuid;
 
 use crate::pool::instance::McpClient;
 use crate::services::PrefixCacheService;
-use mcpmux_core::{FeatureSetRepository, FeatureType, ServerFeature, ServerFeatureRepository};
+use mcpmux_core::{
+    DomainEvent, FeatureSetMember, FeatureSetRepository, FeatureType, ServerFeature,
+    ServerFeatureRepository, SpaceRepository,
+};
+use mcpmux_storage::FeatureOverrideRepository;
 
 use super::{
     CachedFeatures, FeatureDiscoveryService, FeatureResolutionService, FeatureRoutingService,
@@ -47,6 +53,26 @@ impl FeatureService {
         }
     }
 
+    /// Honour the space-level read-only switch when resolving grants
+    pub fn with_space_repo(mut self, space_repo: Arc<dyn SpaceRepository>) -> Self {
+        self.resolution = Arc::new(self.resolution.with_space_repo(space_repo));
+        self
+    }
+
+    /// Expose granted features under their overridden names, titles and
+    /// descriptions, and route calls made with overridden names
+    pub fn with_override_repo(mut self, override_repo: Arc<FeatureOverrideRepository>) -> Self {
+        self.resolution = Arc::new(self.resolution.with_override_repo(override_repo.clone()));
+        self.routing = Arc::new(self.routing.with_override_repo(override_repo));
+        self
+    }
+
+    /// Emit feature definition change events on the given channel
+    pub fn with_event_tx(mut self, event_tx: broadcast::Sender<DomainEvent>) -> Self {
+        self.discovery = Arc::new(self.discovery.with_event_tx(event_tx));
+        self
+    }
+
     // Delegate to FeatureDiscoveryService
     pub async fn discover_and_cache(
         &self,
@@ -59,6 +85,13 @@ impl FeatureService {
             .await
     }
 
+    pub async fn approve_pending_definition(
+        &self,
+        feature_id: &Uuid,
+    ) -> Result<Option<ServerFeature>> {
+        self.discovery.approve_pending_definition(feature_id).await
+    }
+
     pub async fn mark_unavailable(&self, space_id: &str, server_id: &str) -> Result<()> {
         self.discovery.mark_unavailable(space_id, server_id).await
     }
@@ -120,6 +153,16 @@ impl FeatureService {
             .await
     }
 
+    /// Rule members reachable from granted feature sets (for previews)
+    pub async fn get_rules_for_grants(
+        &self,
+        feature_set_ids: &[String],
+    ) -> Result<Vec<FeatureSetMember>> {
+        self.resolution
+            .rules_for_feature_sets(feature_set_ids)
+            .await
+    }
+
     // Delegate to FeatureRoutingService (with type-specific helpers)
     pub async fn find_server_for_qualified_tool(
         &self,
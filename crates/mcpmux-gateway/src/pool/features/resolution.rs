@@ -3,24 +3,37 @@
 use anyhow::Result;
 use std::collections::HashSet;
 use std::sync::Arc;
-use tracing::debug;
+use tracing::{debug, warn};
+use uuid::Uuid;
 
 use crate::services::PrefixCacheService;
 use mcpmux_core::{
-    FeatureSet, FeatureSetRepository, FeatureSetType, FeatureType, MemberMode, MemberType,
-    ServerFeature, ServerFeatureRepository,
+    apply_feature_overrides, FeatureRule, FeatureSet, FeatureSetMember, FeatureSetRepository,
+    FeatureSetType, FeatureType, MemberMode, MemberType, ServerFeature, ServerFeatureRepository,
+    SpaceRepository,
 };
+use mcpmux_storage::FeatureOverrideRepository;
 
-/// Helper to apply include/exclude mode (DRY)
+/// Feature IDs admitted by a read-only feature set or space
+type ReadOnlyIds = HashSet<String>;
+
+/// Helper to apply include/exclude mode (DRY).
+///
+/// In read-only mode, includes are limited to `read_only_ids`; excludes
+/// always apply.
 fn apply_mode_to_set(
     mode: MemberMode,
     feature_ids: impl Iterator<Item = String>,
+    read_only: Option<&ReadOnlyIds>,
     allowed: &mut HashSet<String>,
     excluded: &mut HashSet<String>,
 ) {
-    match mode {
-        MemberMode::Include => allowed.extend(feature_ids),
-        MemberMode::Exclude => excluded.extend(feature_ids),
+    match (mode, read_only) {
+        (MemberMode::Include, Some(read_only_ids)) => {
+            allowed.extend(feature_ids.filter(|id| read_only_ids.contains(id)))
+        }
+        (MemberMode::Include, None) => allowed.extend(feature_ids),
+        (MemberMode::Exclude, _) => excluded.extend(feature_ids),
     }
 }
 
@@ -29,6 +42,10 @@ pub struct FeatureResolutionService {
     feature_repo: Arc<dyn ServerFeatureRepository>,
     feature_set_repo: Arc<dyn FeatureSetRepository>,
     prefix_cache: Arc<PrefixCacheService>,
+    /// Used to honour the space-level read-only switch
+    space_repo: Option<Arc<dyn SpaceRepository>>,
+    /// Exposed names, titles and descriptions applied to granted features
+    override_repo: Option<Arc<FeatureOverrideRepository>>,
 }
 
 impl FeatureResolutionService {
@@ -41,6 +58,47 @@ impl FeatureResolutionService {
             feature_repo,
             feature_set_repo,
             prefix_cache,
+            space_repo: None,
+            override_repo: None,
+        }
+    }
+
+    /// Honour the space-level read-only switch
+    pub fn with_space_repo(&self, space_repo: Arc<dyn SpaceRepository>) -> Self {
+        Self {
+            feature_repo: self.feature_repo.clone(),
+            feature_set_repo: self.feature_set_repo.clone(),
+            prefix_cache: self.prefix_cache.clone(),
+            space_repo: Some(space_repo),
+            override_repo: self.override_repo.clone(),
+        }
+    }
+
+    /// Apply the space's feature overrides to granted features
+    pub fn with_override_repo(&self, override_repo: Arc<FeatureOverrideRepository>) -> Self {
+        Self {
+            feature_repo: self.feature_repo.clone(),
+            feature_set_repo: self.feature_set_repo.clone(),
+            prefix_cache: self.prefix_cache.clone(),
+            space_repo: self.space_repo.clone(),
+            override_repo: Some(override_repo),
+        }
+    }
+
+    /// Whether the space only exposes read-only features
+    async fn is_space_read_only(&self, space_id: &str) -> bool {
+        let (Some(repo), Ok(id)) = (&self.space_repo, Uuid::parse_str(space_id)) else {
+            return false;
+        };
+        match repo.get(&id).await {
+            Ok(space) => space.is_some_and(|s| s.read_only),
+            Err(e) => {
+                warn!(
+                    "[FeatureResolution] Failed to load space {}: {}",
+                    space_id, e
+                );
+                false
+            }
         }
     }
 
@@ -54,7 +112,7 @@ impl FeatureResolutionService {
 
         let mut result: Vec<ServerFeature> = all_features
             .into_iter()
-            .filter(|f| f.is_available)
+            .filter(|f| f.is_available && !f.is_quarantined())
             .collect();
 
         if let Some(feature_type) = filter_type {
@@ -85,6 +143,11 @@ impl FeatureResolutionService {
         let mut has_all_grant = false;
 
         let all_features = self.feature_repo.list_for_space(space_id).await?;
+        let read_only_ids: ReadOnlyIds = all_features
+            .iter()
+            .filter(|f| f.is_read_only())
+            .map(|f| f.id.to_string())
+            .collect();
 
         debug!(
             "[FeatureResolution] Resolving {} feature sets for space {}",
@@ -107,18 +170,24 @@ impl FeatureResolutionService {
                 }
             };
 
+            let read_only = feature_set.read_only.then_some(&read_only_ids);
+
             match feature_set.feature_set_type {
-                FeatureSetType::All => {
-                    has_all_grant = true;
-                }
+                FeatureSetType::All => match read_only {
+                    Some(ids) => allowed_feature_ids.extend(ids.iter().cloned()),
+                    None => has_all_grant = true,
+                },
                 FeatureSetType::Default => {
                     // Default feature set uses explicit members only
                     // Empty default = no features (secure by default)
                     self.resolve_members(
                         &feature_set,
                         &all_features,
+                        read_only,
+                        &read_only_ids,
                         &mut allowed_feature_ids,
                         &mut excluded_feature_ids,
+                        &mut HashSet::new(),
                     )
                     .await?;
                 }
@@ -138,6 +207,9 @@ impl FeatureResolutionService {
                             server_id
                         );
                         for f in &server_features {
+                            if read_only.is_some() && !f.is_read_only() {
+                                continue;
+                            }
                             debug!(
                                 "[FeatureResolution] ServerAll: adding feature id={}, name={}, available={}",
                                 f.id, f.feature_name, f.is_available
@@ -152,8 +224,11 @@ impl FeatureResolutionService {
                     self.resolve_members(
                         &feature_set,
                         &all_features,
+                        read_only,
+                        &read_only_ids,
                         &mut allowed_feature_ids,
                         &mut excluded_feature_ids,
+                        &mut HashSet::new(),
                     )
                     .await?;
                 }
@@ -167,9 +242,14 @@ impl FeatureResolutionService {
         );
 
         let mut result: Vec<ServerFeature> = if has_all_grant {
+            // Exclusions (e.g. a global "never allow" set) still apply to All
             all_features
                 .into_iter()
-                .filter(|f| f.is_available)
+                .filter(|f| {
+                    f.is_available
+                        && !f.is_quarantined()
+                        && !excluded_feature_ids.contains(&f.id.to_string())
+                })
                 .collect()
         } else {
             all_features
@@ -177,7 +257,8 @@ impl FeatureResolutionService {
                 .filter(|f| {
                     let in_allowed = allowed_feature_ids.contains(&f.id.to_string());
                     let in_excluded = excluded_feature_ids.contains(&f.id.to_string());
-                    let passes = f.is_available && in_allowed && !in_excluded;
+                    let passes =
+                        f.is_available && !f.is_quarantined() && in_allowed && !in_excluded;
                     if !passes && in_allowed {
                         debug!(
                             "[FeatureResolution] Feature {} (server={}) filtered out: is_available={}, in_allowed={}, in_excluded={}",
@@ -194,6 +275,15 @@ impl FeatureResolutionService {
             result.len()
         );
 
+        if self.is_space_read_only(space_id).await {
+            result.retain(|f| f.is_read_only());
+            debug!(
+                "[FeatureResolution] Space {} is read-only: {} features remain",
+                space_id,
+                result.len()
+            );
+        }
+
         // Apply type filter if specified (OCP)
         if let Some(feature_type) = filter_type {
             result.retain(|f| f.feature_type == feature_type);
@@ -208,39 +298,92 @@ impl FeatureResolutionService {
             feature.server_alias = Some(prefix);
         }
 
+        // Apply exposed names, titles and descriptions (after rule matching)
+        if let Some(override_repo) = &self.override_repo {
+            match override_repo.list_for_space(space_id).await {
+                Ok(overrides) => apply_feature_overrides(&overrides, feature_set_ids, &mut result),
+                Err(e) => warn!(
+                    "[FeatureResolution] Failed to load feature overrides: {}",
+                    e
+                ),
+            }
+        }
+
         Ok(result)
     }
 
+    /// Resolve a feature set's members. `read_only` is set when this set or
+    /// any set containing it is read-only. `visiting` holds the sets being
+    /// resolved above this one, so a cycle of nested sets ends.
+    #[allow(clippy::too_many_arguments)]
     async fn resolve_members(
         &self,
         feature_set: &FeatureSet,
         all_features: &[ServerFeature],
+        read_only: Option<&ReadOnlyIds>,
+        read_only_ids: &ReadOnlyIds,
         allowed: &mut HashSet<String>,
         excluded: &mut HashSet<String>,
+        visiting: &mut HashSet<String>,
     ) -> Result<()> {
+        if !visiting.insert(feature_set.id.clone()) {
+            warn!(
+                "[FeatureResolution] Feature set {} contains itself; skipping the cycle",
+                feature_set.id
+            );
+            return Ok(());
+        }
         for member in &feature_set.members {
             match member.member_type {
+                MemberType::Feature if feature_set.is_global() => {
+                    // Global sets name features by server and feature name,
+                    // since feature IDs differ per space
+                    let ids = all_features
+                        .iter()
+                        .filter(|f| f.portable_key() == member.member_id)
+                        .map(|f| f.id.to_string());
+                    apply_mode_to_set(member.mode, ids, read_only, allowed, excluded);
+                }
                 MemberType::Feature => {
                     apply_mode_to_set(
                         member.mode,
                         std::iter::once(member.member_id.clone()),
+                        read_only,
                         allowed,
                         excluded,
                     );
                 }
+                MemberType::Rule => {
+                    let rule = match FeatureRule::parse(&member.member_id) {
+                        Ok(rule) => rule,
+                        Err(e) => {
+                            warn!(
+                                "[FeatureResolution] Skipping invalid rule '{}' in {}: {}",
+                                member.member_id, feature_set.id, e
+                            );
+                            continue;
+                        }
+                    };
+                    let ids = all_features
+                        .iter()
+                        .filter(|f| f.is_available && rule.matches(f))
+                        .map(|f| f.id.to_string());
+                    apply_mode_to_set(member.mode, ids, read_only, allowed, excluded);
+                }
                 MemberType::FeatureSet => {
                     if let Some(nested_fs) = self
                         .feature_set_repo
                         .get_with_members(&member.member_id)
                         .await?
                     {
+                        let read_only = read_only.or(nested_fs.read_only.then_some(read_only_ids));
                         match nested_fs.feature_set_type {
                             FeatureSetType::All => {
                                 let ids = all_features
                                     .iter()
                                     .filter(|f| f.is_available)
                                     .map(|f| f.id.to_string());
-                                apply_mode_to_set(member.mode, ids, allowed, excluded);
+                                apply_mode_to_set(member.mode, ids, read_only, allowed, excluded);
                             }
                             FeatureSetType::ServerAll => {
                                 if let Some(ref server_id) = nested_fs.server_id {
@@ -248,15 +391,24 @@ impl FeatureResolutionService {
                                         .iter()
                                         .filter(|f| f.server_id == *server_id && f.is_available)
                                         .map(|f| f.id.to_string());
-                                    apply_mode_to_set(member.mode, ids, allowed, excluded);
+                                    apply_mode_to_set(
+                                        member.mode,
+                                        ids,
+                                        read_only,
+                                        allowed,
+                                        excluded,
+                                    );
                                 }
                             }
                             _ => {
                                 Box::pin(self.resolve_members(
                                     &nested_fs,
                                     all_features,
+                                    read_only,
+                                    read_only_ids,
                                     allowed,
                                     excluded,
+                                    visiting,
                                 ))
                                 .await?;
                             }
@@ -265,6 +417,35 @@ impl FeatureResolutionService {
                 }
             }
         }
+        visiting.remove(&feature_set.id);
         Ok(())
     }
+
+    /// Rule members reachable from the given feature sets, including nested sets
+    pub async fn rules_for_feature_sets(
+        &self,
+        feature_set_ids: &[String],
+    ) -> Result<Vec<FeatureSetMember>> {
+        let mut rules = Vec::new();
+        let mut visited = HashSet::new();
+        let mut pending: Vec<String> = feature_set_ids.to_vec();
+        while let Some(fs_id) = pending.pop() {
+            if !visited.insert(fs_id.clone()) {
+                continue;
+            }
+            let Some(feature_set) = self.feature_set_repo.get_with_members(&fs_id).await? else {
+                continue;
+            };
+            for member in feature_set.members {
+                match member.member_type {
+                    MemberType::Rule => rules.push(member),
+                    MemberType::FeatureSet if member.mode == MemberMode::Include => {
+                        pending.push(member.member_id)
+                    }
+                    _ => {}
+                }
+            }
+        }
+        Ok(rules)
+    }
 }
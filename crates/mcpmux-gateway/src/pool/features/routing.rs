@@ -6,11 +6,14 @@ use tracing::warn;
 
 use crate::services::PrefixCacheService;
 use mcpmux_core::{FeatureType, ServerFeatureRepository};
+use mcpmux_storage::FeatureOverrideRepository;
 
 /// Handles qualified name resolution and routing to servers
 pub struct FeatureRoutingService {
     feature_repo: Arc<dyn ServerFeatureRepository>,
     prefix_cache: Arc<PrefixCacheService>,
+    /// Maps overridden names back to their features
+    override_repo: Option<Arc<FeatureOverrideRepository>>,
 }
 
 impl FeatureRoutingService {
@@ -21,13 +24,64 @@ impl FeatureRoutingService {
         Self {
             feature_repo,
             prefix_cache,
+            override_repo: None,
         }
     }
 
+    /// Resolve names exposed by feature overrides
+    pub fn with_override_repo(&self, override_repo: Arc<FeatureOverrideRepository>) -> Self {
+        Self {
+            feature_repo: self.feature_repo.clone(),
+            prefix_cache: self.prefix_cache.clone(),
+            override_repo: Some(override_repo),
+        }
+    }
+
+    /// Find the feature a feature override exposes under `name`
+    ///
+    /// Override names are unique within a space, so the caller's grants are
+    /// not needed here; grant checks happen on the resolved feature.
+    async fn resolve_override_name(
+        &self,
+        space_id: &str,
+        name: &str,
+        feature_type: FeatureType,
+    ) -> Option<(String, String)> {
+        let repo = self.override_repo.as_ref()?;
+        match repo.list_for_space(space_id).await {
+            Ok(overrides) => overrides
+                .into_iter()
+                .find(|o| o.feature_type == feature_type && o.name.as_deref() == Some(name))
+                .map(|o| (o.server_id, o.feature_name)),
+            Err(e) => {
+                warn!("[FeatureRouting] Failed to load feature overrides: {}", e);
+                None
+            }
+        }
+    }
+
+    /// Resolve an exposed name: an override's name, else `prefix_feature_name`
+    async fn resolve_name(
+        &self,
+        space_id: &str,
+        name: &str,
+        feature_type: FeatureType,
+    ) -> Option<(String, String)> {
+        if let Some(resolved) = self
+            .resolve_override_name(space_id, name, feature_type)
+            .await
+        {
+            return Some(resolved);
+        }
+        self.prefix_cache
+            .resolve_qualified_name(space_id, name)
+            .await
+    }
+
     /// Find which server provides a qualified tool or prompt
     ///
     /// Format: `prefix_feature_name` (underscore separator for Cursor compatibility)
-    /// Supports: alias_name or server_id_name
+    /// Supports: alias_name or server_id_name, or a name set by a feature override
     ///
     /// Note: This method does NOT work for resources - use find_server_for_resource_uri instead
     pub async fn find_server_for_qualified_feature(
@@ -36,11 +90,10 @@ impl FeatureRoutingService {
         qualified_name: &str,
         feature_type: FeatureType,
     ) -> Result<Option<(String, String)>> {
-        // Use shared resolution logic from PrefixCacheService
+        // Overridden names first, then the shared logic from PrefixCacheService
         // Format: prefix_feature_name (underscore separator)
         let (server_id, feature_name) = match self
-            .prefix_cache
-            .resolve_qualified_name(space_id, qualified_name)
+            .resolve_name(space_id, qualified_name, feature_type.clone())
             .await
         {
             Some(res) => res,
@@ -81,8 +134,7 @@ impl FeatureRoutingService {
         space_id: &str,
         qualified_name: &str,
     ) -> Result<(String, String)> {
-        self.prefix_cache
-            .resolve_qualified_name(space_id, qualified_name)
+        self.resolve_name(space_id, qualified_name, FeatureType::Tool)
             .await
             .ok_or_else(|| {
                 anyhow::anyhow!("Tool name must be qualified with prefix: prefix_tool_name")
@@ -98,8 +150,7 @@ impl FeatureRoutingService {
         space_id: &str,
         qualified_name: &str,
     ) -> Result<(String, String)> {
-        self.prefix_cache
-            .resolve_qualified_name(space_id, qualified_name)
+        self.resolve_name(space_id, qualified_name, FeatureType::Prompt)
             .await
             .ok_or_else(|| {
                 anyhow::anyhow!("Prompt name must be qualified with prefix: prefix_prompt_name")
@@ -17,6 +17,7 @@ mod features;
 mod instance;
 mod oauth;
 mod oauth_utils;
+mod request_queue;
 mod routing;
 mod server_manager;
 mod service;
@@ -39,11 +40,16 @@ pub use oauth::{
     OAuthCallback, OAuthCompleteEvent, OAuthInitResult, OAuthTokenInfo, OutboundOAuthManager,
 };
 
+// Request queueing
+pub use request_queue::RequestQueue;
+
 // SOLID Services
 pub use connection::{ConnectionResult, ConnectionService};
 pub use features::{CachedFeatures, FeatureService};
 pub use routing::{RoutedPrompt, RoutedResource, RoutedTool, RoutingService};
-pub use service::{InstalledServerInfo, PoolService, PoolStats, ReconnectResult};
+pub use service::{
+    InstalledServerInfo, PoolService, PoolStats, ReconnectResult, RequestQueueStats,
+};
 pub use token::TokenService;
 pub use transport::{ResolvedTransport, Transport, TransportConnectResult, TransportFactory};
 
@@ -0,0 +1,135 @@
+//! Per-server request queue
+//!
+//! Caps the requests in flight to one server instance. Requests beyond the
+//! limit wait in FIFO order (tokio's semaphore is fair) up to the queue size
+//! and queue timeout; anything more is refused straight away.
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use anyhow::{anyhow, Result};
+use mcpmux_core::ConcurrencyLimit;
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
+
+/// Bounded FIFO queue in front of a server
+pub struct RequestQueue {
+    limit: ConcurrencyLimit,
+    slots: Arc<Semaphore>,
+    queued: AtomicUsize,
+}
+
+/// Decrements the queued count however the wait ends (including cancellation)
+struct QueuedGuard<'a>(&'a AtomicUsize);
+
+impl Drop for QueuedGuard<'_> {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+impl RequestQueue {
+    pub fn new(limit: ConcurrencyLimit) -> Self {
+        Self {
+            slots: Arc::new(Semaphore::new(limit.max_concurrent_requests as usize)),
+            queued: AtomicUsize::new(0),
+            limit,
+        }
+    }
+
+    pub fn limit(&self) -> &ConcurrencyLimit {
+        &self.limit
+    }
+
+    /// Requests currently sent to the server
+    pub fn active(&self) -> usize {
+        (self.limit.max_concurrent_requests as usize).saturating_sub(self.slots.available_permits())
+    }
+
+    /// Requests waiting for a slot
+    pub fn queued(&self) -> usize {
+        self.queued.load(Ordering::SeqCst)
+    }
+
+    /// Wait for a slot; the request may be sent while the permit is held
+    pub async fn acquire(&self, server_id: &str) -> Result<OwnedSemaphorePermit> {
+        // Only succeeds when nobody is waiting, so FIFO order is kept
+        if let Ok(permit) = self.slots.clone().try_acquire_owned() {
+            return Ok(permit);
+        }
+
+        if self.queued.fetch_add(1, Ordering::SeqCst) >= self.limit.max_queued_requests as usize {
+            self.queued.fetch_sub(1, Ordering::SeqCst);
+            return Err(anyhow!(
+                "Server '{}' is busy: {} requests in flight and {} queued",
+                server_id,
+                self.limit.max_concurrent_requests,
+                self.limit.max_queued_requests
+            ));
+        }
+        let _guard = QueuedGuard(&self.queued);
+
+        let timeout = Duration::from_secs(self.limit.queue_timeout_secs);
+        match tokio::time::timeout(timeout, self.slots.clone().acquire_owned()).await {
+            Ok(Ok(permit)) => Ok(permit),
+            Ok(Err(_)) => Err(anyhow!("Request queue for '{}' was closed", server_id)),
+            Err(_) => Err(anyhow!(
+                "Server '{}' is busy: timed out after {}s waiting in the request queue",
+                server_id,
+                self.limit.queue_timeout_secs
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn queue(max_concurrent: u32, max_queued: u32, timeout_secs: u64) -> Arc<RequestQueue> {
+        Arc::new(RequestQueue::new(ConcurrencyLimit {
+            max_concurrent_requests: max_concurrent,
+            max_queued_requests: max_queued,
+            queue_timeout_secs: timeout_secs,
+        }))
+    }
+
+    #[tokio::test]
+    async fn test_waits_for_a_free_slot() {
+        let queue = queue(1, 4, 30);
+        let first = queue.acquire("sqlite").await.unwrap();
+        assert_eq!(queue.active(), 1);
+
+        let waiter = {
+            let queue = queue.clone();
+            tokio::spawn(async move { queue.acquire("sqlite").await.map(|_| ()) })
+        };
+        tokio::task::yield_now().await;
+        assert_eq!(queue.queued(), 1);
+
+        drop(first);
+        waiter.await.unwrap().unwrap();
+        assert_eq!(queue.queued(), 0);
+        assert_eq!(queue.active(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_full_queue_is_refused() {
+        let queue = queue(1, 0, 30);
+        let _held = queue.acquire("sqlite").await.unwrap();
+
+        let err = queue.acquire("sqlite").await.unwrap_err();
+        assert!(err.to_string().contains("busy"));
+        assert_eq!(queue.queued(), 0);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_queue_timeout() {
+        let queue = queue(1, 4, 5);
+        let _held = queue.acquire("sqlite").await.unwrap();
+
+        let err = queue.acquire("sqlite").await.unwrap_err();
+        assert!(err.to_string().contains("timed out after 5s"));
+        assert_eq!(queue.queued(), 0);
+    }
+}
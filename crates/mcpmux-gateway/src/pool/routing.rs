@@ -20,6 +20,7 @@ use uuid::Uuid;
 use super::connection::ConnectionResult;
 use super::features::FeatureService;
 use super::service::PoolService;
+use crate::services::{ArgumentPolicyService, ArgumentValidationService, ToolRateLimitService};
 
 /// A tool as returned by the routing service
 #[derive(Debug, Clone)]
@@ -62,6 +63,9 @@ pub struct RoutingService {
     feature_service: Arc<FeatureService>,
     pool_service: Arc<PoolService>,
     log_manager: Arc<ServerLogManager>,
+    rate_limits: Option<Arc<ToolRateLimitService>>,
+    argument_policies: Option<Arc<ArgumentPolicyService>>,
+    argument_validation: Option<Arc<ArgumentValidationService>>,
 }
 
 impl RoutingService {
@@ -74,9 +78,33 @@ impl RoutingService {
             feature_service,
             pool_service,
             log_manager,
+            rate_limits: None,
+            argument_policies: None,
+            argument_validation: None,
         }
     }
 
+    /// Enforce per-client rate limits and quotas on tool calls
+    pub fn with_rate_limits(mut self, rate_limits: Arc<ToolRateLimitService>) -> Self {
+        self.rate_limits = Some(rate_limits);
+        self
+    }
+
+    /// Enforce the argument policies of the granted feature sets on tool calls
+    pub fn with_argument_policies(mut self, argument_policies: Arc<ArgumentPolicyService>) -> Self {
+        self.argument_policies = Some(argument_policies);
+        self
+    }
+
+    /// Check tool call arguments against the tool's input schema before dispatch
+    pub fn with_argument_validation(
+        mut self,
+        argument_validation: Arc<ArgumentValidationService>,
+    ) -> Self {
+        self.argument_validation = Some(argument_validation);
+        self
+    }
+
     /// List tools available to a client based on their grants
     ///
     /// Returns tools from all connected servers, filtered by the client's feature set grants.
@@ -183,9 +211,10 @@ impl RoutingService {
     pub async fn call_tool(
         &self,
         space_id: Uuid,
+        client_id: &str,
         feature_set_ids: &[String],
         tool_name: &str,
-        arguments: Value,
+        mut arguments: Value,
     ) -> Result<ToolCallResult> {
         let space_id_str = space_id.to_string();
 
@@ -223,14 +252,14 @@ impl RoutingService {
             .collect();
         info!("[RoutingService] Allowed tools: {:?}", tool_features);
 
-        let is_allowed = allowed_features.iter().any(|f| {
+        let allowed_feature = allowed_features.iter().find(|f| {
             f.feature_type == FeatureType::Tool
                 && f.server_id == server_id
                 && f.feature_name == actual_tool_name
                 && f.is_available
         });
 
-        if !is_allowed {
+        let Some(allowed_feature) = allowed_feature else {
             warn!(
                 "[RoutingService] Tool '{}' NOT allowed. Looking for server_id='{}', feature_name='{}', is_available=true",
                 tool_name, server_id, actual_tool_name
@@ -239,10 +268,98 @@ impl RoutingService {
                 "Tool '{}' is not allowed by the current grants",
                 tool_name
             ));
-        }
+        };
 
         info!("[RoutingService] Tool '{}' is ALLOWED", tool_name);
 
+        // 3. Enforce argument policies (refusals are `ArgumentPolicyViolation`)
+        if let Some(argument_policies) = &self.argument_policies {
+            if let Err(e) = argument_policies
+                .apply_to_arguments(
+                    feature_set_ids,
+                    &allowed_feature.id.to_string(),
+                    tool_name,
+                    &mut arguments,
+                )
+                .await
+            {
+                warn!("[RoutingService] {}", e);
+                self.log(
+                    &space_id,
+                    &server_id,
+                    LogLevel::Warn,
+                    e.to_string(),
+                    Some(serde_json::json!({
+                        "client_id": client_id,
+                        "tool": actual_tool_name
+                    })),
+                )
+                .await;
+                return Err(e);
+            }
+        }
+
+        // 4. Validate arguments against the input schema; violations go back to
+        // the model as a tool error so it can correct the call
+        if let Some(argument_validation) = &self.argument_validation {
+            let violations = argument_validation
+                .validate(allowed_feature, &arguments)
+                .await;
+            if !violations.is_empty() {
+                warn!(
+                    "[RoutingService] Rejected call to '{}': {} schema violation(s)",
+                    tool_name,
+                    violations.len()
+                );
+                self.log(
+                    &space_id,
+                    &server_id,
+                    LogLevel::Warn,
+                    format!("Invalid arguments for tool: {}", actual_tool_name),
+                    Some(serde_json::json!({
+                        "client_id": client_id,
+                        "tool": actual_tool_name,
+                        "violations": violations
+                    })),
+                )
+                .await;
+                return Ok(ToolCallResult {
+                    content: vec![serde_json::json!({
+                        "type": "text",
+                        "text": format!(
+                            "Invalid arguments for tool '{}':\n- {}",
+                            tool_name,
+                            violations.join("\n- ")
+                        )
+                    })],
+                    is_error: true,
+                });
+            }
+        }
+
+        // 5. Apply the client's rate limits and quotas (refusals are `RateLimitExceeded`)
+        if let Some(rate_limits) = &self.rate_limits {
+            if let Err(refused) = rate_limits
+                .acquire(client_id, &server_id, &actual_tool_name)
+                .await
+            {
+                warn!("[RoutingService] {}", refused);
+                self.log(
+                    &space_id,
+                    &server_id,
+                    LogLevel::Warn,
+                    refused.to_string(),
+                    Some(serde_json::json!({
+                        "client_id": client_id,
+                        "tool": actual_tool_name,
+                        "retry_after_secs": refused.retry_after_secs
+                    })),
+                )
+                .await;
+                return Err(refused.into());
+            }
+        }
+
         info!(
             "[RoutingService] Calling tool {} on server {}",
             actual_tool_name, server_id
@@ -280,6 +397,8 @@ impl RoutingService {
             // We can assume RunningService is not cloneable but its peer() returns a Service handle which is.
             // Let's use with_client to get the handle out.
             let client_handle = instance.with_client(|client| client.peer().clone());
+            // Wait our turn on servers with a concurrency limit (not counted in the call timeout)
+            let _slot = pool.acquire_request_slot(space_id, &server_id).await?;
 
             match client_handle {
                 Some(client) => {
@@ -311,7 +430,7 @@ impl RoutingService {
             }
         }
 
-        // 3. Dispatch the call with retry logic
+        // 6. Dispatch the call with retry logic
         // NOTE: Preemptive token refresh is no longer needed here.
         // RMCP's AuthClient with DatabaseCredentialStore handles token refresh
         // automatically on every HTTP request when needed.
@@ -9,12 +9,15 @@
 //! - Coordinating connect/disconnect operations
 //! - Bulk connect on startup (reconnect_all_enabled)
 //! - Providing access to server instances for routing
+//! - Queueing requests to servers with a concurrency limit
 
 use std::sync::Arc;
 
 use anyhow::Result;
 use dashmap::DashMap;
+use mcpmux_core::ConcurrencyLimit;
 use serde_json::Value;
+use tokio::sync::OwnedSemaphorePermit;
 use tracing::{debug, info, warn};
 use uuid::Uuid;
 
@@ -23,6 +26,7 @@ use super::context::ConnectionContext;
 use super::features::{CachedFeatures, FeatureService};
 use super::instance::{InstanceKey, InstanceState, ServerInstance};
 use super::oauth::OutboundOAuthManager;
+use super::request_queue::RequestQueue;
 use super::token::TokenService;
 use super::transport::{ResolvedTransport, TransportType};
 
@@ -62,6 +66,16 @@ impl ReconnectResult {
     }
 }
 
+/// Request queue statistics for a server with a concurrency limit
+#[derive(Debug, Clone)]
+pub struct RequestQueueStats {
+    pub space_id: Uuid,
+    pub server_id: String,
+    pub max_concurrent_requests: u32,
+    pub active_requests: usize,
+    pub queued_requests: usize,
+}
+
 /// Pool statistics
 #[derive(Debug, Clone, Default)]
 pub struct PoolStats {
@@ -70,6 +84,10 @@ pub struct PoolStats {
     pub connecting_instances: usize,
     pub failed_instances: usize,
     pub oauth_pending_instances: usize,
+    /// Requests waiting for a slot across all request queues
+    pub queued_requests: usize,
+    /// Per-server request queues
+    pub request_queues: Vec<RequestQueueStats>,
 }
 
 /// Pool Service - main orchestrator for server connections
@@ -82,6 +100,8 @@ pub struct PoolService {
     feature_service: Arc<FeatureService>,
     /// Token service (exposed for routing)
     token_service: Arc<TokenService>,
+    /// Request queues for servers with a concurrency limit, keyed by (space_id, server_id)
+    queues: DashMap<(Uuid, String), Arc<RequestQueue>>,
 }
 
 impl PoolService {
@@ -95,6 +115,7 @@ impl PoolService {
             connection_service,
             feature_service,
             token_service,
+            queues: DashMap::new(),
         }
     }
 
@@ -113,6 +134,63 @@ impl PoolService {
         self.connection_service.oauth_manager()
     }
 
+    /// Wait for a free request slot on a server.
+    ///
+    /// Hold the permit while the request is in flight. Returns `None` when
+    /// the server has no concurrency limit.
+    pub async fn acquire_request_slot(
+        &self,
+        space_id: Uuid,
+        server_id: &str,
+    ) -> Result<Option<OwnedSemaphorePermit>> {
+        let queue = self
+            .queues
+            .get(&(space_id, server_id.to_string()))
+            .map(|q| q.clone());
+        match queue {
+            Some(queue) => queue.acquire(server_id).await.map(Some),
+            None => Ok(None),
+        }
+    }
+
+    /// Apply a server's concurrency limit.
+    ///
+    /// An unchanged limit keeps the existing queue; requests already holding
+    /// a slot on a replaced queue finish normally.
+    fn apply_concurrency_limit(
+        &self,
+        space_id: Uuid,
+        server_id: &str,
+        limit: Option<&ConcurrencyLimit>,
+    ) {
+        let key = (space_id, server_id.to_string());
+        match limit {
+            Some(limit) => {
+                if self.queues.get(&key).is_some_and(|q| q.limit() == limit) {
+                    return;
+                }
+                info!(
+                    "[PoolService] Limiting {}/{} to {} concurrent requests ({} queued, {}s timeout)",
+                    space_id,
+                    server_id,
+                    limit.max_concurrent_requests,
+                    limit.max_queued_requests,
+                    limit.queue_timeout_secs
+                );
+                self.queues
+                    .insert(key, Arc::new(RequestQueue::new(limit.clone())));
+            }
+            None => {
+                if self.queues.remove(&key).is_some() {
+                    info!(
+                        "[PoolService] Removed concurrency limit for {}/{}",
+                        space_id, server_id
+                    );
+                }
+            }
+        }
+    }
+
     /// Read a resource from a backend server
     ///
     /// On auth errors, automatically reconnects the server and retries once.
@@ -159,6 +237,7 @@ impl PoolService {
             .ok_or_else(|| anyhow::anyhow!("Server not connected: {}", server_id))?;
 
         let client_handle = instance.with_client(|client| client.peer().clone());
+        let _slot = self.acquire_request_slot(space_id, server_id).await?;
 
         match client_handle {
             Some(client) => {
@@ -238,6 +317,7 @@ impl PoolService {
             .ok_or_else(|| anyhow::anyhow!("Server not connected: {}", server_id))?;
 
         let client_handle = instance.with_client(|client| client.peer().clone());
+        let _slot = self.acquire_request_slot(space_id, server_id).await?;
 
         match client_handle {
             Some(client) => {
@@ -266,6 +346,8 @@ impl PoolService {
     pub async fn connect_server(&self, ctx: &ConnectionContext) -> ConnectionResult {
         let key = (ctx.space_id, ctx.server_id.to_string());
 
+        self.apply_concurrency_limit(ctx.space_id, &ctx.server_id, ctx.concurrency_limit.as_ref());
+
         // Check for existing instance
         if let Some(instance) = self.instances.get(&key) {
             if instance.is_healthy() {
@@ -296,9 +378,9 @@ impl PoolService {
 
         // Use proper InstanceKey constructors that include the URL
         let instance_key = match &ctx.transport {
-            ResolvedTransport::Stdio { command, args, env } => {
-                InstanceKey::stdio(ctx.space_id, command, args, env)
-            }
+            ResolvedTransport::Stdio {
+                command, args, env, ..
+            } => InstanceKey::stdio(ctx.space_id, command, args, env),
             ResolvedTransport::Http { url, headers, .. } => {
                 InstanceKey::http(ctx.space_id, url, headers)
             }
@@ -331,6 +413,7 @@ impl PoolService {
     pub fn remove_instance(&self, space_id: Uuid, server_id: &str) {
         let key = (space_id, server_id.to_string());
 
+        self.queues.remove(&key);
         if let Some((_, _instance)) = self.instances.remove(&key) {
             info!(
                 "[PoolService] Removed instance for {}/{} (tokens preserved)",
@@ -393,6 +476,18 @@ impl PoolService {
             }
         }
 
+        for entry in self.queues.iter() {
+            let queue = entry.value();
+            stats.queued_requests += queue.queued();
+            stats.request_queues.push(RequestQueueStats {
+                space_id: entry.key().0,
+                server_id: entry.key().1.clone(),
+                max_concurrent_requests: queue.limit().max_concurrent_requests,
+                active_requests: queue.active(),
+                queued_requests: queue.queued(),
+            });
+        }
+
         stats
     }
 
@@ -497,7 +592,8 @@ impl PoolService {
 
             // Attempt connection (auto-reconnect mode - no browser opening)
             let ctx = ConnectionContext::new(server.space_id, server.server_id.clone(), config)
-                .with_auto_reconnect(true);
+                .with_auto_reconnect(true)
+                .with_concurrency_limit(server.concurrency_limit.clone());
             match self.connect_server(&ctx).await {
                 ConnectionResult::Connected { reused, .. } => {
                     if reused {
@@ -543,4 +639,5 @@ pub struct InstalledServerInfo {
     pub server_id: String,
     pub requires_oauth: bool,
     pub has_credentials: bool,
+    pub concurrency_limit: Option<ConcurrencyLimit>,
 }
@@ -6,6 +6,7 @@
 use std::sync::Arc;
 
 use crate::server::GatewayDependencies;
+use crate::services::{ArgumentPolicyService, ArgumentValidationService, ToolRateLimitService};
 use mcpmux_core::DomainEvent;
 
 use super::{
@@ -23,6 +24,7 @@ pub struct PoolServices {
     pub oauth_manager: Arc<OutboundOAuthManager>,
     pub routing_service: Arc<RoutingService>,
     pub server_manager: Arc<ServerManager>,
+    pub argument_policy_service: Arc<ArgumentPolicyService>,
 }
 
 /// Factory for creating pool services
@@ -77,11 +79,18 @@ impl ServiceFactory {
         );
 
         // FeatureService - discovers and caches MCP features
-        let feature_service = Arc::new(FeatureService::new(
-            deps.feature_repo.clone(),
-            deps.feature_set_repo.clone(),
-            prefix_cache.clone(), // Clone here since we use it again below
-        ));
+        let feature_service = Arc::new(
+            FeatureService::new(
+                deps.feature_repo.clone(),
+                deps.feature_set_repo.clone(),
+                prefix_cache.clone(), // Clone here since we use it again below
+            )
+            .with_space_repo(deps.space_repo.clone())
+            .with_override_repo(Arc::new(mcpmux_storage::FeatureOverrideRepository::new(
+                deps.database.clone(),
+            )))
+            .with_event_tx(event_tx.clone()),
+        );
 
         // ServerManager - event-driven orchestrator for server state
         // No longer has circular dependency with PoolService
@@ -102,11 +111,24 @@ impl ServiceFactory {
 
         // RoutingService - handles request dispatch
         // NOTE: No longer needs token_service - RMCP's AuthClient handles token refresh per-request
-        let routing_service = Arc::new(RoutingService::new(
-            feature_service.clone(),
-            pool_service.clone(),
-            deps.log_manager.clone(),
-        ));
+        // Per-client rate limits, feature set argument policies and input schemas are enforced on every tool call
+        let argument_policy_service = Arc::new(ArgumentPolicyService::new(Arc::new(
+            mcpmux_storage::ArgumentPolicyRepository::new(deps.database.clone()),
+        )));
+        let routing_service = Arc::new(
+            RoutingService::new(
+                feature_service.clone(),
+                pool_service.clone(),
+                deps.log_manager.clone(),
+            )
+            .with_rate_limits(Arc::new(ToolRateLimitService::new(Arc::new(
+                mcpmux_storage::RateLimitRepository::new(deps.database.clone()),
+            ))))
+            .with_argument_policies(argument_policy_service.clone())
+            .with_argument_validation(Arc::new(ArgumentValidationService::new(
+                deps.installed_server_repo.clone(),
+            ))),
+        );
 
         PoolServices {
             pool_service,
@@ -116,6 +138,7 @@ impl ServiceFactory {
             oauth_manager,
             routing_service,
             server_manager,
+            argument_policy_service,
         }
     }
 }
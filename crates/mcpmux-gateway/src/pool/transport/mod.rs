@@ -10,6 +10,7 @@ pub mod shell_env;
 mod stdio;
 
 use std::collections::HashMap;
+use std::path::PathBuf;
 use std::sync::Arc;
 
 use async_trait::async_trait;
@@ -17,7 +18,7 @@ use mcpmux_core::{CredentialRepository, OutboundOAuthRepository, ServerLogManage
 use uuid::Uuid;
 
 pub use http::HttpTransport;
-pub use stdio::{configure_child_process_platform, StdioTransport};
+pub use stdio::{configure_child_process_platform, load_env_file, StdioTransport};
 
 // Re-export TransportType from mcpmux-core as the single source of truth
 pub use mcpmux_core::TransportType;
@@ -61,6 +62,10 @@ pub enum ResolvedTransport {
         command: String,
         args: Vec<String>,
         env: HashMap<String, String>,
+        /// Directory to launch the process from
+        cwd: Option<PathBuf>,
+        /// `.env` file loaded under `env` at connect time
+        env_file: Option<PathBuf>,
     },
     Http {
         url: String,
@@ -92,10 +97,23 @@ impl ResolvedTransport {
 
         let mut hasher = DefaultHasher::new();
         match self {
-            ResolvedTransport::Stdio { command, args, env } => {
+            ResolvedTransport::Stdio {
+                command,
+                args,
+                env,
+                cwd,
+                env_file,
+            } => {
                 "stdio".hash(&mut hasher);
                 command.hash(&mut hasher);
                 args.hash(&mut hasher);
+                cwd.hash(&mut hasher);
+                env_file.hash(&mut hasher);
+                // Editing the .env file changes what the process gets
+                env_file
+                    .as_ref()
+                    .and_then(|path| std::fs::read(path).ok())
+                    .hash(&mut hasher);
                 let mut env_pairs: Vec<_> = env.iter().collect();
                 env_pairs.sort_by_key(|(k, _)| *k);
                 for (k, v) in env_pairs {
@@ -141,16 +159,26 @@ impl TransportFactory {
         event_tx: Option<tokio::sync::broadcast::Sender<mcpmux_core::DomainEvent>>,
     ) -> Box<dyn Transport> {
         match config {
-            ResolvedTransport::Stdio { command, args, env } => Box::new(StdioTransport::new(
-                command.clone(),
-                args.clone(),
-                env.clone(),
-                space_id,
-                server_id,
-                log_manager,
-                connect_timeout,
-                event_tx,
-            )),
+            ResolvedTransport::Stdio {
+                command,
+                args,
+                env,
+                cwd,
+                env_file,
+            } => Box::new(
+                StdioTransport::new(
+                    command.clone(),
+                    args.clone(),
+                    env.clone(),
+                    space_id,
+                    server_id,
+                    log_manager,
+                    connect_timeout,
+                    event_tx,
+                )
+                .with_cwd(cwd.clone())
+                .with_env_file(env_file.clone()),
+            ),
             ResolvedTransport::Http { url, headers } => Box::new(HttpTransport::new(
                 url.clone(),
                 headers.clone(),
@@ -175,3 +203,26 @@ pub fn create_client_handler(
 ) -> McpClientHandler {
     McpClientHandler::new(server_id, space_id, event_tx, log_manager)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_config_hash_tracks_env_file_contents() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(".env");
+        std::fs::write(&path, "API_KEY=one\n").unwrap();
+        let transport = ResolvedTransport::Stdio {
+            command: "node".to_string(),
+            args: vec![],
+            env: HashMap::new(),
+            cwd: None,
+            env_file: Some(path.clone()),
+        };
+
+        let before = transport.config_hash();
+        std::fs::write(&path, "API_KEY=two\n").unwrap();
+        assert_ne!(transport.config_hash(), before);
+    }
+}
@@ -4,9 +4,13 @@
 //! the static registry definition and user-specific installation settings.
 
 use super::ResolvedTransport;
-use mcpmux_core::{InstalledServer, TransportConfig as RegistryConfig};
+use anyhow::{anyhow, bail, Result};
+use mcpmux_core::{
+    get_spaces_dir, interpolate_secrets, Capabilities, InstallationSource, InstalledServer,
+    TransportConfig as RegistryConfig, VariableContext,
+};
 use std::collections::HashMap;
-use std::path::Path;
+use std::path::{Component, Path, PathBuf};
 
 const MCP_STATE_DIR_ENV: &str = "MCP_STATE_DIR";
 
@@ -33,12 +37,53 @@ fn merge_input_defaults(
     merged
 }
 
+/// Read-only launch settings, if the installation asks for read-only mode and
+/// its cached definition declares one.
+fn read_only_capabilities(installed: &InstalledServer) -> Option<Capabilities> {
+    if !installed.read_only {
+        return None;
+    }
+    let capabilities = installed
+        .get_definition()
+        .and_then(|d| d.capabilities)
+        .filter(|c| c.read_only_mode);
+    if capabilities.is_none() {
+        tracing::warn!(
+            "[TransportResolution] {}/{} is set to read-only but its definition declares no read-only mode",
+            installed.space_id,
+            installed.server_id
+        );
+    }
+    capabilities
+}
+
+/// Variables available to a server defined in a space's JSON file.
+///
+/// Registry definitions don't get interpolation: they must not be able to
+/// read the user's environment or files.
+fn variable_context(installed: &InstalledServer) -> Option<VariableContext> {
+    match &installed.source {
+        InstallationSource::UserConfig { file_path } => {
+            Some(VariableContext::new(&installed.space_id).with_config_file(file_path))
+        }
+        _ => None,
+    }
+}
+
 /// Build transport config from registry transport and installed server
+///
+/// `secrets` are the space's secrets by name, for input values that
+/// reference them as `${secret:name}`. `base_state_dir` is the app data
+/// directory.
+///
+/// Fails when a secret, or a variable of a user-config server, can't be
+/// resolved.
 pub fn build_transport_config(
     registry_transport: &RegistryConfig,
     installed: &InstalledServer,
+    secrets: &HashMap<String, String>,
     base_state_dir: Option<&Path>,
-) -> ResolvedTransport {
+) -> Result<ResolvedTransport> {
     tracing::debug!(
         "[TransportResolution] Building config for {}/{} with {} input values",
         installed.space_id,
@@ -46,20 +91,59 @@ pub fn build_transport_config(
         installed.input_values.len()
     );
 
-    // Merge user-provided values with defaults from input definitions
-    let effective_values = merge_input_defaults(registry_transport, &installed.input_values);
+    // Merge user-provided values with defaults from input definitions, then
+    // fill in the space secrets they reference
+    let mut effective_values = merge_input_defaults(registry_transport, &installed.input_values);
+    for (id, value) in effective_values.iter_mut() {
+        *value = interpolate_secrets(value, secrets).map_err(|e| {
+            anyhow!(
+                "Unresolved secret in input {} of server '{}': {}",
+                id,
+                installed.server_id,
+                e
+            )
+        })?;
+    }
+    let read_only = read_only_capabilities(installed);
+    let variables = variable_context(installed);
+    let resolve = |field: &str, template: &str| -> Result<String> {
+        let template = match &variables {
+            Some(ctx) => ctx.interpolate(template).map_err(|e| {
+                anyhow!(
+                    "Unresolved variable in {} of server '{}': {}",
+                    field,
+                    installed.server_id,
+                    e
+                )
+            })?,
+            None => template.to_string(),
+        };
+        Ok(resolve_placeholders(&template, &effective_values))
+    };
 
     match registry_transport {
         RegistryConfig::Stdio {
-            command, args, env, ..
+            command,
+            args,
+            env,
+            cwd,
+            env_file,
+            ..
         } => {
-            let resolved_command = resolve_placeholders(command, &effective_values);
+            let resolved_command = resolve("command", command)?;
             let mut resolved_args: Vec<String> = args
                 .iter()
-                .map(|arg| resolve_placeholders(arg, &effective_values))
-                .collect();
-
-            // Append user's extra args
+                .map(|arg| resolve("args", arg))
+                .collect::<Result<_>>()?;
+
+            // Append the server's read-only args, then the user's extra args
+            if let Some(ref caps) = read_only {
+                resolved_args.extend(
+                    caps.read_only_args
+                        .iter()
+                        .map(|arg| resolve_placeholders(arg, &effective_values)),
+                );
+            }
             resolved_args.extend(installed.args_append.clone());
 
             // Build env from registry + input values + env_overrides
@@ -67,13 +151,8 @@ pub fn build_transport_config(
 
             // 1. Start with registry env
             for (k, v) in env {
-                let resolved_value = resolve_placeholders(v, &effective_values);
-                tracing::debug!(
-                    "[TransportResolution] Registry env: {}={} → {}",
-                    k,
-                    v,
-                    resolved_value
-                );
+                let resolved_value = resolve(&format!("env {}", k), v)?;
+                tracing::debug!("[TransportResolution] Registry env: {}={}", k, v);
                 resolved_env.insert(k.clone(), resolved_value);
             }
 
@@ -84,10 +163,19 @@ pub fn build_transport_config(
             );
             resolved_env.extend(effective_values.clone());
 
-            // 3. Apply user's env overrides
-            resolved_env.extend(installed.env_overrides.clone());
+            // 3. Apply the server's read-only env
+            if let Some(ref caps) = read_only {
+                for (k, v) in &caps.read_only_env {
+                    resolved_env.insert(k.clone(), resolve_placeholders(v, &effective_values));
+                }
+            }
 
-            // 4. Inject MCP_STATE_DIR if not already set
+            // 4. Apply user's env overrides (imported secrets are input references)
+            for (k, v) in &installed.env_overrides {
+                resolved_env.insert(k.clone(), resolve_placeholders(v, &effective_values));
+            }
+
+            // 5. Inject MCP_STATE_DIR if not already set
             apply_state_dir_env(&mut resolved_env, base_state_dir, installed);
 
             tracing::debug!(
@@ -95,28 +183,71 @@ pub fn build_transport_config(
                 resolved_env.len()
             );
 
-            ResolvedTransport::Stdio {
+            // The installation's working directory and .env file replace the
+            // definition's. Relative paths resolve against the config file's
+            // folder, or the spaces folder for servers without one.
+            let resolve_path = |field: &str, path: &str| -> Result<PathBuf> {
+                let path = resolve(field, path)?;
+                Ok(match (&variables, base_state_dir) {
+                    (Some(ctx), _) => ctx.resolve_path(&path),
+                    (None, Some(base)) => get_spaces_dir(base).join(path),
+                    (None, None) => PathBuf::from(path),
+                })
+            };
+            // Only the user's own files may point anywhere: a definition that
+            // didn't come from a config file stays inside the spaces folder
+            let definition_path = |field: &str, path: &str| -> Result<PathBuf> {
+                if variables.is_none() {
+                    let resolved = resolve_placeholders(path, &effective_values);
+                    if !is_contained(&resolved) {
+                        bail!(
+                            "The {} of server '{}' must be a relative path inside the spaces \
+                             folder: {}",
+                            field,
+                            installed.server_id,
+                            resolved
+                        );
+                    }
+                }
+                resolve_path(field, path)
+            };
+            let cwd = match (&installed.cwd_override, cwd) {
+                (Some(path), _) => Some(resolve_path("cwd", path)?),
+                (None, Some(path)) => Some(definition_path("cwd", path)?),
+                (None, None) => None,
+            };
+            let env_file = match (&installed.env_file_override, env_file) {
+                (Some(path), _) => Some(resolve_path("env_file", path)?),
+                (None, Some(path)) => Some(definition_path("env_file", path)?),
+                (None, None) => None,
+            };
+
+            Ok(ResolvedTransport::Stdio {
                 command: resolved_command,
                 args: resolved_args,
                 env: resolved_env,
-            }
+                cwd,
+                env_file,
+            })
         }
         RegistryConfig::Http { url, headers, .. } => {
-            let resolved_url = resolve_placeholders(url, &effective_values);
+            let resolved_url = resolve("url", url)?;
 
             // Resolve headers from registry
             let mut resolved_headers: HashMap<String, String> = headers
                 .iter()
-                .map(|(k, v)| (k.clone(), resolve_placeholders(v, &effective_values)))
-                .collect();
+                .map(|(k, v)| Ok((k.clone(), resolve(&format!("header {}", k), v)?)))
+                .collect::<Result<_>>()?;
 
-            // Add user's extra headers
-            resolved_headers.extend(installed.extra_headers.clone());
+            // Add user's extra headers (imported secrets are input references)
+            for (k, v) in &installed.extra_headers {
+                resolved_headers.insert(k.clone(), resolve_placeholders(v, &effective_values));
+            }
 
-            ResolvedTransport::Http {
+            Ok(ResolvedTransport::Http {
                 url: resolved_url,
                 headers: resolved_headers,
-            }
+            })
         }
     }
 }
@@ -145,6 +276,13 @@ fn apply_state_dir_env(
     );
 }
 
+/// Whether `path` is relative and never climbs out of its base directory
+fn is_contained(path: &str) -> bool {
+    Path::new(path)
+        .components()
+        .all(|c| matches!(c, Component::Normal(_) | Component::CurDir))
+}
+
 /// Resolve placeholders like ${input:INPUT_NAME} in a string
 fn resolve_placeholders(template: &str, input_values: &HashMap<String, String>) -> String {
     let mut result = template.to_string();
@@ -184,6 +322,8 @@ mod tests {
             command: "node".to_string(),
             args: vec!["server.js".to_string()],
             env: HashMap::from([("LOG_LEVEL".to_string(), "${input:LOG_LEVEL}".to_string())]),
+            cwd: None,
+            env_file: None,
             metadata: TransportMetadata {
                 inputs: vec![make_input("LOG_LEVEL", Some("info"))],
             },
@@ -191,7 +331,8 @@ mod tests {
 
         let installed = make_installed(HashMap::new()); // No user values
 
-        let resolved = build_transport_config(&transport, &installed, None);
+        let resolved =
+            build_transport_config(&transport, &installed, &HashMap::new(), None).unwrap();
 
         match resolved {
             ResolvedTransport::Stdio { env, .. } => {
@@ -208,6 +349,8 @@ mod tests {
             command: "node".to_string(),
             args: vec![],
             env: HashMap::from([("LOG_LEVEL".to_string(), "${input:LOG_LEVEL}".to_string())]),
+            cwd: None,
+            env_file: None,
             metadata: TransportMetadata {
                 inputs: vec![make_input("LOG_LEVEL", Some("info"))],
             },
@@ -218,7 +361,8 @@ mod tests {
             "debug".to_string(),
         )]));
 
-        let resolved = build_transport_config(&transport, &installed, None);
+        let resolved =
+            build_transport_config(&transport, &installed, &HashMap::new(), None).unwrap();
 
         match resolved {
             ResolvedTransport::Stdio { env, .. } => {
@@ -235,6 +379,8 @@ mod tests {
             command: "node".to_string(),
             args: vec!["--port".to_string(), "${input:PORT}".to_string()],
             env: HashMap::new(),
+            cwd: None,
+            env_file: None,
             metadata: TransportMetadata {
                 inputs: vec![make_input("PORT", Some("8080"))],
             },
@@ -242,7 +388,8 @@ mod tests {
 
         let installed = make_installed(HashMap::new());
 
-        let resolved = build_transport_config(&transport, &installed, None);
+        let resolved =
+            build_transport_config(&transport, &installed, &HashMap::new(), None).unwrap();
 
         match resolved {
             ResolvedTransport::Stdio { args, .. } => {
@@ -259,6 +406,8 @@ mod tests {
             command: "${input:BINARY_PATH}".to_string(),
             args: vec![],
             env: HashMap::new(),
+            cwd: None,
+            env_file: None,
             metadata: TransportMetadata {
                 inputs: vec![make_input("BINARY_PATH", Some("/usr/local/bin/mcp"))],
             },
@@ -266,7 +415,8 @@ mod tests {
 
         let installed = make_installed(HashMap::new());
 
-        let resolved = build_transport_config(&transport, &installed, None);
+        let resolved =
+            build_transport_config(&transport, &installed, &HashMap::new(), None).unwrap();
 
         match resolved {
             ResolvedTransport::Stdio { command, .. } => {
@@ -288,7 +438,8 @@ mod tests {
 
         let installed = make_installed(HashMap::new());
 
-        let resolved = build_transport_config(&transport, &installed, None);
+        let resolved =
+            build_transport_config(&transport, &installed, &HashMap::new(), None).unwrap();
 
         match resolved {
             ResolvedTransport::Http { url, .. } => {
@@ -310,7 +461,8 @@ mod tests {
 
         let installed = make_installed(HashMap::new());
 
-        let resolved = build_transport_config(&transport, &installed, None);
+        let resolved =
+            build_transport_config(&transport, &installed, &HashMap::new(), None).unwrap();
 
         match resolved {
             ResolvedTransport::Http { headers, .. } => {
@@ -330,6 +482,8 @@ mod tests {
                 ("PORT".to_string(), "${input:PORT}".to_string()),
                 ("API_KEY".to_string(), "${input:API_KEY}".to_string()),
             ]),
+            cwd: None,
+            env_file: None,
             metadata: TransportMetadata {
                 inputs: vec![
                     make_input("LOG_LEVEL", Some("info")),
@@ -345,7 +499,8 @@ mod tests {
             ("API_KEY".to_string(), "secret123".to_string()),
         ]));
 
-        let resolved = build_transport_config(&transport, &installed, None);
+        let resolved =
+            build_transport_config(&transport, &installed, &HashMap::new(), None).unwrap();
 
         match resolved {
             ResolvedTransport::Stdio { env, .. } => {
@@ -366,6 +521,8 @@ mod tests {
             command: "node".to_string(),
             args: vec![],
             env: HashMap::from([("API_KEY".to_string(), "${input:API_KEY}".to_string())]),
+            cwd: None,
+            env_file: None,
             metadata: TransportMetadata {
                 inputs: vec![make_input("API_KEY", None)],
             },
@@ -373,7 +530,8 @@ mod tests {
 
         let installed = make_installed(HashMap::new());
 
-        let resolved = build_transport_config(&transport, &installed, None);
+        let resolved =
+            build_transport_config(&transport, &installed, &HashMap::new(), None).unwrap();
 
         match resolved {
             ResolvedTransport::Stdio { env, .. } => {
@@ -390,6 +548,8 @@ mod tests {
             command: "node".to_string(),
             args: vec![],
             env: HashMap::new(),
+            cwd: None,
+            env_file: None,
             metadata: TransportMetadata {
                 inputs: vec![
                     make_input("A", Some("default_a")),
@@ -405,4 +565,313 @@ mod tests {
         assert_eq!(merged.get("A"), Some(&"user_a".to_string()));
         assert_eq!(merged.get("B"), Some(&"default_b".to_string()));
     }
+
+    #[test]
+    fn test_read_only_launch_applies_declared_args_and_env() {
+        let transport = RegistryConfig::Stdio {
+            command: "db-mcp".to_string(),
+            args: vec!["serve".to_string()],
+            env: HashMap::new(),
+            cwd: None,
+            env_file: None,
+            metadata: TransportMetadata::default(),
+        };
+        let mut definition: mcpmux_core::ServerDefinition =
+            serde_json::from_value(serde_json::json!({
+                "id": "test-server",
+                "name": "Test",
+                "transport": { "type": "stdio", "command": "db-mcp" },
+                "capabilities": {
+                    "read_only_mode": true,
+                    "read_only_args": ["--read-only"],
+                    "read_only_env": { "DB_READ_ONLY": "1" }
+                }
+            }))
+            .unwrap();
+
+        let mut installed = make_installed(HashMap::new()).with_definition(&definition);
+        installed.args_append = vec!["--verbose".to_string()];
+
+        // Not enabled: nothing added
+        match build_transport_config(&transport, &installed, &HashMap::new(), None).unwrap() {
+            ResolvedTransport::Stdio { args, env, .. } => {
+                assert_eq!(args, vec!["serve", "--verbose"]);
+                assert!(!env.contains_key("DB_READ_ONLY"));
+            }
+            _ => panic!("Expected Stdio transport"),
+        }
+
+        installed.read_only = true;
+        match build_transport_config(&transport, &installed, &HashMap::new(), None).unwrap() {
+            ResolvedTransport::Stdio { args, env, .. } => {
+                assert_eq!(args, vec!["serve", "--read-only", "--verbose"]);
+                assert_eq!(env.get("DB_READ_ONLY"), Some(&"1".to_string()));
+            }
+            _ => panic!("Expected Stdio transport"),
+        }
+
+        // A definition without read-only mode ignores the switch
+        definition.capabilities.as_mut().unwrap().read_only_mode = false;
+        let installed = installed.with_definition(&definition);
+        match build_transport_config(&transport, &installed, &HashMap::new(), None).unwrap() {
+            ResolvedTransport::Stdio { args, .. } => {
+                assert_eq!(args, vec!["serve", "--verbose"]);
+            }
+            _ => panic!("Expected Stdio transport"),
+        }
+    }
+
+    #[test]
+    fn test_user_config_variables_are_interpolated() {
+        std::env::set_var("MCPMUX_RESOLUTION_TEST_TOKEN", "tok");
+        let transport = RegistryConfig::Stdio {
+            command: "node".to_string(),
+            args: vec!["${workspaceFolder}/server.js".to_string()],
+            env: HashMap::from([
+                (
+                    "TOKEN".to_string(),
+                    "${env:MCPMUX_RESOLUTION_TEST_TOKEN}".to_string(),
+                ),
+                (
+                    "LEVEL".to_string(),
+                    "${env:MCPMUX_RESOLUTION_TEST_UNSET:-info}".to_string(),
+                ),
+            ]),
+            cwd: None,
+            env_file: None,
+            metadata: TransportMetadata::default(),
+        };
+        let installed =
+            make_installed(HashMap::new()).with_source(InstallationSource::UserConfig {
+                file_path: "/home/me/spaces/work.json".into(),
+            });
+
+        match build_transport_config(&transport, &installed, &HashMap::new(), None).unwrap() {
+            ResolvedTransport::Stdio { args, env, .. } => {
+                assert_eq!(args, vec!["/home/me/spaces/server.js"]);
+                assert_eq!(env.get("TOKEN"), Some(&"tok".to_string()));
+                assert_eq!(env.get("LEVEL"), Some(&"info".to_string()));
+            }
+            _ => panic!("Expected Stdio transport"),
+        }
+    }
+
+    #[test]
+    fn test_unresolved_user_config_variable_fails() {
+        let transport = RegistryConfig::Http {
+            url: "https://api.example.com/mcp".to_string(),
+            headers: HashMap::from([(
+                "Authorization".to_string(),
+                "Bearer ${env:MCPMUX_RESOLUTION_TEST_UNSET}".to_string(),
+            )]),
+            metadata: TransportMetadata::default(),
+        };
+        let installed =
+            make_installed(HashMap::new()).with_source(InstallationSource::UserConfig {
+                file_path: "/home/me/spaces/work.json".into(),
+            });
+
+        let err =
+            build_transport_config(&transport, &installed, &HashMap::new(), None).unwrap_err();
+        assert!(err.to_string().contains("header Authorization"));
+        assert!(err
+            .to_string()
+            .contains("MCPMUX_RESOLUTION_TEST_UNSET is not set"));
+    }
+
+    #[test]
+    fn test_registry_definitions_are_not_interpolated() {
+        let transport = RegistryConfig::Stdio {
+            command: "node".to_string(),
+            args: vec!["${env:HOME}".to_string()],
+            env: HashMap::new(),
+            cwd: None,
+            env_file: None,
+            metadata: TransportMetadata::default(),
+        };
+
+        match build_transport_config(
+            &transport,
+            &make_installed(HashMap::new()),
+            &HashMap::new(),
+            None,
+        )
+        .unwrap()
+        {
+            ResolvedTransport::Stdio { args, .. } => assert_eq!(args, vec!["${env:HOME}"]),
+            _ => panic!("Expected Stdio transport"),
+        }
+    }
+
+    #[test]
+    fn test_user_config_paths_resolve_against_config_folder() {
+        let transport = RegistryConfig::Stdio {
+            command: "node".to_string(),
+            args: vec![],
+            env: HashMap::new(),
+            cwd: Some("project".to_string()),
+            env_file: Some("${workspaceFolder}/project/.env".to_string()),
+            metadata: TransportMetadata::default(),
+        };
+        let installed =
+            make_installed(HashMap::new()).with_source(InstallationSource::UserConfig {
+                file_path: "/home/me/spaces/work.json".into(),
+            });
+
+        match build_transport_config(&transport, &installed, &HashMap::new(), None).unwrap() {
+            ResolvedTransport::Stdio { cwd, env_file, .. } => {
+                assert_eq!(cwd, Some(PathBuf::from("/home/me/spaces/project")));
+                assert_eq!(
+                    env_file,
+                    Some(PathBuf::from("/home/me/spaces/project/.env"))
+                );
+            }
+            _ => panic!("Expected Stdio transport"),
+        }
+    }
+
+    #[test]
+    fn test_registry_paths_resolve_against_spaces_folder() {
+        let transport = RegistryConfig::Stdio {
+            command: "node".to_string(),
+            args: vec![],
+            env: HashMap::new(),
+            cwd: Some("project".to_string()),
+            env_file: Some("project/.env".to_string()),
+            metadata: TransportMetadata::default(),
+        };
+        let installed = make_installed(HashMap::new());
+
+        match build_transport_config(
+            &transport,
+            &installed,
+            &HashMap::new(),
+            Some(Path::new("/home/me/.mcpmux")),
+        )
+        .unwrap()
+        {
+            ResolvedTransport::Stdio { cwd, env_file, .. } => {
+                assert_eq!(cwd, Some(PathBuf::from("/home/me/.mcpmux/spaces/project")));
+                assert_eq!(
+                    env_file,
+                    Some(PathBuf::from("/home/me/.mcpmux/spaces/project/.env"))
+                );
+            }
+            _ => panic!("Expected Stdio transport"),
+        }
+    }
+
+    #[test]
+    fn test_registry_paths_cannot_leave_spaces_folder() {
+        let base = Some(Path::new("/home/me/.mcpmux"));
+        let installed = make_installed(HashMap::from([(
+            "DIR".to_string(),
+            "../../.ssh".to_string(),
+        )]));
+        for (cwd, env_file) in [
+            (Some("/etc"), None),
+            (None, Some("/home/me/.aws/credentials")),
+            (Some("../.."), None),
+            (None, Some("project/../../../.env")),
+            (Some("${input:DIR}"), None),
+        ] {
+            let transport = RegistryConfig::Stdio {
+                command: "node".to_string(),
+                args: vec![],
+                env: HashMap::new(),
+                cwd: cwd.map(str::to_string),
+                env_file: env_file.map(str::to_string),
+                metadata: TransportMetadata::default(),
+            };
+            assert!(
+                build_transport_config(&transport, &installed, &HashMap::new(), base).is_err(),
+                "{:?} {:?}",
+                cwd,
+                env_file
+            );
+        }
+    }
+
+    #[test]
+    fn test_installed_launch_overrides_win() {
+        let transport = RegistryConfig::Stdio {
+            command: "node".to_string(),
+            args: vec![],
+            env: HashMap::new(),
+            cwd: Some("/opt/server".to_string()),
+            env_file: None,
+            metadata: TransportMetadata::default(),
+        };
+        let mut installed = make_installed(HashMap::new());
+        installed.cwd_override = Some("/srv/checkout".to_string());
+        installed.env_file_override = Some("/srv/checkout/.env".to_string());
+
+        match build_transport_config(&transport, &installed, &HashMap::new(), None).unwrap() {
+            ResolvedTransport::Stdio { cwd, env_file, .. } => {
+                assert_eq!(cwd, Some(PathBuf::from("/srv/checkout")));
+                assert_eq!(env_file, Some(PathBuf::from("/srv/checkout/.env")));
+            }
+            _ => panic!("Expected Stdio transport"),
+        }
+    }
+
+    #[test]
+    fn test_extra_headers_resolve_imported_inputs() {
+        let transport = RegistryConfig::Http {
+            url: "https://mcp.example.com/mcp".to_string(),
+            headers: HashMap::new(),
+            metadata: TransportMetadata::default(),
+        };
+        let mut installed = make_installed(HashMap::from([(
+            "AUTHORIZATION".to_string(),
+            "lin_api_123".to_string(),
+        )]));
+        installed.extra_headers = HashMap::from([(
+            "Authorization".to_string(),
+            "Bearer ${input:AUTHORIZATION}".to_string(),
+        )]);
+
+        match build_transport_config(&transport, &installed, &HashMap::new(), None).unwrap() {
+            ResolvedTransport::Http { headers, .. } => {
+                assert_eq!(
+                    headers.get("Authorization"),
+                    Some(&"Bearer lin_api_123".to_string())
+                );
+            }
+            _ => panic!("Expected Http transport"),
+        }
+    }
+
+    #[test]
+    fn test_space_secrets_resolve_in_inputs() {
+        let transport = RegistryConfig::Http {
+            url: "https://api.githubcopilot.com/mcp".to_string(),
+            headers: HashMap::from([(
+                "Authorization".to_string(),
+                "Bearer ${input:GITHUB_TOKEN}".to_string(),
+            )]),
+            metadata: TransportMetadata::default(),
+        };
+        let installed = make_installed(HashMap::from([(
+            "GITHUB_TOKEN".to_string(),
+            "${secret:github_token}".to_string(),
+        )]));
+        let secrets = HashMap::from([("github_token".to_string(), "ghp_shared".to_string())]);
+
+        match build_transport_config(&transport, &installed, &secrets, None).unwrap() {
+            ResolvedTransport::Http { headers, .. } => {
+                assert_eq!(
+                    headers.get("Authorization"),
+                    Some(&"Bearer ghp_shared".to_string())
+                );
+            }
+            _ => panic!("Expected Http transport"),
+        }
+
+        let err = build_transport_config(&transport, &installed, &HashMap::new(), None)
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("input GITHUB_TOKEN"));
+        assert!(err.contains("secret 'github_token' is not set"));
+    }
 }
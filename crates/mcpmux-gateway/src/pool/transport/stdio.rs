@@ -10,6 +10,7 @@
 //! externally via the HTTP gateway.
 
 use std::collections::HashMap;
+use std::path::{Path, PathBuf};
 use std::process::Stdio;
 use std::sync::Arc;
 use std::time::Duration;
@@ -125,6 +126,8 @@ pub struct StdioTransport {
     command: String,
     args: Vec<String>,
     env: HashMap<String, String>,
+    cwd: Option<PathBuf>,
+    env_file: Option<PathBuf>,
     space_id: Uuid,
     server_id: String,
     log_manager: Option<Arc<ServerLogManager>>,
@@ -148,6 +151,8 @@ impl StdioTransport {
             command,
             args,
             env,
+            cwd: None,
+            env_file: None,
             space_id,
             server_id,
             log_manager,
@@ -156,6 +161,18 @@ impl StdioTransport {
         }
     }
 
+    /// Launch the process from this directory (checked at connect time)
+    pub fn with_cwd(mut self, cwd: Option<PathBuf>) -> Self {
+        self.cwd = cwd;
+        self
+    }
+
+    /// Load this `.env` file at connect time; configured env wins on conflicts
+    pub fn with_env_file(mut self, env_file: Option<PathBuf>) -> Self {
+        self.env_file = env_file;
+        self
+    }
+
     /// Log a message to the server log manager.
     async fn log(&self, level: LogLevel, source: LogSource, message: String) {
         if let Some(log_manager) = &self.log_manager {
@@ -192,8 +209,17 @@ impl Transport for StdioTransport {
         // Homebrew, nvm, Volta, fnm, or /usr/local/bin — this fixes that.
         let shell_path = shell_env::get_shell_path();
 
+        if let Some(cwd) = &self.cwd {
+            if let Err(err) = validate_cwd(cwd) {
+                error!(server_id = %self.server_id, "{}", err);
+                self.log(LogLevel::Error, LogSource::Connection, err.clone())
+                    .await;
+                return TransportConnectResult::Failed(err);
+            }
+        }
+
         // Validate command exists, using the shell-resolved PATH when available
-        let command_path = match resolve_command(&self.command, shell_path) {
+        let command_path = match resolve_command(&self.command, shell_path, self.cwd.as_deref()) {
             Ok(path) => path,
             Err(_) => {
                 let hint = command_hint(&self.command);
@@ -215,16 +241,33 @@ impl Transport for StdioTransport {
         );
 
         // Build the child process environment:
-        // - Start with user-configured env vars (from resolution.rs)
+        // - Start with the .env file, if any
+        // - Layer user-configured env vars (from resolution.rs) on top
         // - Inject the shell-resolved PATH so child processes can find
         //   their own dependencies (e.g., npx needs to find node)
         let args = self.args.clone();
-        let mut env = self.env.clone();
+        let mut env = match &self.env_file {
+            Some(path) => match load_env_file(path) {
+                Ok(vars) => vars,
+                Err(err) => {
+                    error!(server_id = %self.server_id, "{}", err);
+                    self.log(LogLevel::Error, LogSource::Connection, err.clone())
+                        .await;
+                    return TransportConnectResult::Failed(err);
+                }
+            },
+            None => HashMap::new(),
+        };
+        env.extend(self.env.clone());
         inject_shell_path(&mut env, shell_path);
+        let cwd = self.cwd.clone();
 
         let (transport, child_stderr) =
             match TokioChildProcess::builder(Command::new(&command_path).configure(move |cmd| {
                 cmd.args(&args).envs(&env).kill_on_drop(true);
+                if let Some(cwd) = &cwd {
+                    cmd.current_dir(cwd);
+                }
                 configure_child_process_platform(cmd);
             }))
             .stderr(Stdio::piped())
@@ -317,7 +360,14 @@ impl Transport for StdioTransport {
 fn resolve_command(
     command: &str,
     shell_path: Option<&std::ffi::OsString>,
-) -> Result<std::path::PathBuf, which::Error> {
+    cwd: Option<&Path>,
+) -> Result<PathBuf, which::Error> {
+    if let Some(cwd) = cwd {
+        // Relative commands like `./server.sh` resolve against the working directory
+        let path = shell_path.cloned().or_else(|| std::env::var_os("PATH"));
+        return which::which_in(command, path.as_ref(), cwd)
+            .or_else(|_| which::which_in(format!("{}.exe", command), path, cwd));
+    }
     if let Some(path) = shell_path {
         which::which_in(command, Some(path), ".")
             .or_else(|_| which::which_in(format!("{}.exe", command), Some(path), "."))
@@ -326,6 +376,30 @@ fn resolve_command(
     }
 }
 
+/// Check the working directory exists before spawning, so a missing folder
+/// isn't reported as a missing command.
+fn validate_cwd(cwd: &Path) -> Result<(), String> {
+    match std::fs::metadata(cwd) {
+        Ok(meta) if meta.is_dir() => Ok(()),
+        Ok(_) => Err(format!(
+            "Working directory {} is not a directory",
+            cwd.display()
+        )),
+        Err(_) => Err(format!(
+            "Working directory {} does not exist",
+            cwd.display()
+        )),
+    }
+}
+
+/// Read variables from a `.env` file.
+pub fn load_env_file(path: &Path) -> Result<HashMap<String, String>, String> {
+    let iter = dotenvy::from_path_iter(path)
+        .map_err(|e| format!("Failed to read env file {}: {}", path.display(), e))?;
+    iter.map(|item| item.map_err(|e| format!("Failed to parse env file {}: {}", path.display(), e)))
+        .collect()
+}
+
 /// Inject the shell-resolved PATH into the child process environment.
 ///
 /// This ensures child processes (e.g., npx spawning node) can find their
@@ -357,7 +431,7 @@ mod tests {
         #[cfg(unix)]
         {
             let path = OsString::from("/bin:/usr/bin");
-            let result = resolve_command("sh", Some(&path));
+            let result = resolve_command("sh", Some(&path), None);
             assert!(result.is_ok(), "Should find 'sh' in /bin:/usr/bin");
         }
     }
@@ -367,7 +441,7 @@ mod tests {
         // Without shell_path, falls back to which::which (uses process PATH)
         #[cfg(unix)]
         {
-            let result = resolve_command("sh", None);
+            let result = resolve_command("sh", None, None);
             assert!(result.is_ok(), "Should find 'sh' via process PATH");
         }
     }
@@ -375,7 +449,11 @@ mod tests {
     #[test]
     fn test_resolve_command_returns_error_for_nonexistent() {
         let fake_path = OsString::from("/nonexistent/path");
-        let result = resolve_command("this_command_surely_does_not_exist_xyz", Some(&fake_path));
+        let result = resolve_command(
+            "this_command_surely_does_not_exist_xyz",
+            Some(&fake_path),
+            None,
+        );
         assert!(result.is_err(), "Should fail for nonexistent command");
     }
 
@@ -383,7 +461,7 @@ mod tests {
     fn test_resolve_command_not_found_in_restricted_path() {
         // Even if 'sh' exists, it shouldn't be found if PATH points elsewhere
         let path = OsString::from("/tmp/empty_dir_that_does_not_exist");
-        let result = resolve_command("sh", Some(&path));
+        let result = resolve_command("sh", Some(&path), None);
         assert!(
             result.is_err(),
             "Should not find 'sh' in a path that doesn't contain it"
@@ -395,7 +473,7 @@ mod tests {
     fn test_resolve_command_with_full_shell_path() {
         // Use the actual shell-resolved PATH to find a real command
         if let Some(shell_path) = shell_env::get_shell_path() {
-            let result = resolve_command("sh", Some(shell_path));
+            let result = resolve_command("sh", Some(shell_path), None);
             assert!(result.is_ok(), "Should find 'sh' using resolved shell PATH");
         }
     }
@@ -463,6 +541,37 @@ mod tests {
 
     // ── command_hint tests ─────────────────────────────────────────
 
+    #[test]
+    fn test_validate_cwd() {
+        let dir = tempfile::tempdir().unwrap();
+        let file = dir.path().join("file.txt");
+        std::fs::write(&file, "").unwrap();
+
+        assert!(validate_cwd(dir.path()).is_ok());
+        assert!(validate_cwd(&file)
+            .unwrap_err()
+            .contains("is not a directory"));
+        assert!(validate_cwd(&dir.path().join("missing"))
+            .unwrap_err()
+            .contains("does not exist"));
+    }
+
+    #[test]
+    fn test_load_env_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(".env");
+        std::fs::write(
+            &path,
+            "# comment\nAPI_URL=http://localhost\nQUOTED=\"a b\"\n",
+        )
+        .unwrap();
+
+        let env = load_env_file(&path).unwrap();
+        assert_eq!(env.get("API_URL"), Some(&"http://localhost".to_string()));
+        assert_eq!(env.get("QUOTED"), Some(&"a b".to_string()));
+        assert_eq!(env.len(), 2);
+    }
+
     #[test]
     fn test_command_hint_docker() {
         assert!(command_hint("docker").contains("Docker Desktop"));
@@ -10,7 +10,7 @@ use crate::services::ClientMetadataService;
 use mcpmux_core::{
     AppSettingsRepository, CimdMetadataFetcher, CredentialRepository, FeatureSetRepository,
     InstalledServerRepository, OutboundOAuthRepository, ServerDiscoveryService,
-    ServerFeatureRepository, ServerLogManager, SpaceRepository,
+    ServerFeatureRepository, ServerLogManager, SpaceRepository, SpaceSecretRepository,
 };
 use mcpmux_storage::{Database, InboundClientRepository};
 use tokio::sync::Mutex;
@@ -45,6 +45,8 @@ pub struct GatewayDependencies {
     pub state_dir: Option<PathBuf>,
     /// App settings repository (for OAuth port persistence)
     pub settings_repo: Option<Arc<dyn AppSettingsRepository>>,
+    /// Space secrets referenced by input values (`${secret:name}`)
+    pub space_secret_repo: Option<Arc<dyn SpaceSecretRepository>>,
 }
 
 impl GatewayDependencies {
@@ -81,7 +83,8 @@ impl GatewayDependencies {
             database,
             jwt_secret,
             state_dir,
-            settings_repo: None, // Use builder for this
+            settings_repo: None,     // Use builder for this
+            space_secret_repo: None, // Use builder for this
         }
     }
 }
@@ -103,6 +106,7 @@ pub struct DependenciesBuilder {
     jwt_secret: Option<zeroize::Zeroizing<[u8; mcpmux_storage::JWT_SECRET_SIZE]>>,
     state_dir: Option<PathBuf>,
     settings_repo: Option<Arc<dyn AppSettingsRepository>>,
+    space_secret_repo: Option<Arc<dyn SpaceSecretRepository>>,
 }
 
 impl DependenciesBuilder {
@@ -123,6 +127,7 @@ impl DependenciesBuilder {
             jwt_secret: None,
             state_dir: None,
             settings_repo: None,
+            space_secret_repo: None,
         }
     }
 
@@ -184,6 +189,11 @@ impl DependenciesBuilder {
         self
     }
 
+    pub fn with_space_secret_repo(mut self, repo: Arc<dyn SpaceSecretRepository>) -> Self {
+        self.space_secret_repo = Some(repo);
+        self
+    }
+
     pub fn build(self) -> Result<GatewayDependencies, String> {
         let database = self.database.ok_or("database is required")?;
 
@@ -238,6 +248,7 @@ impl DependenciesBuilder {
             jwt_secret: self.jwt_secret,
             state_dir: self.state_dir,
             settings_repo: self.settings_repo,
+            space_secret_repo: self.space_secret_repo,
         })
     }
 }
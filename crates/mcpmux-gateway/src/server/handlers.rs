@@ -1009,6 +1009,7 @@ pub struct UpdateClientRequest {
     pub client_alias: Option<String>,
     pub connection_mode: Option<String>,
     pub locked_space_id: Option<String>,
+    pub meta_tools: Option<bool>,
 }
 
 /// Update client settings (connection mode, alias, etc.)
@@ -1209,6 +1210,7 @@ pub async fn oauth_update_client(
             req.client_alias,
             req.connection_mode,
             locked_space_id,
+            req.meta_tools,
         )
         .await
     {
@@ -1318,7 +1320,7 @@ pub async fn oauth_register(
     })?;
 
     // Process DCR request (saves to database)
-    match process_dcr_request(repo, request).await {
+    match process_dcr_request(repo, request, &gateway_state.base_url).await {
         Ok(response) => {
             info!(
                 "[DCR] Successfully registered client: {} ({})",
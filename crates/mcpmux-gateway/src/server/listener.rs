@@ -0,0 +1,175 @@
+//! Gateway listeners
+//!
+//! Binds the sockets the gateway router is served on. TCP is the default;
+//! a Unix domain socket can be served alongside it (or instead of it) so that
+//! access is controlled by filesystem permissions rather than by whoever can
+//! reach a loopback port.
+
+use mcpmux_core::AppSettingsService;
+use std::path::PathBuf;
+
+/// Unix domain socket listener configuration
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnixSocketConfig {
+    /// Filesystem path of the socket
+    pub path: PathBuf,
+    /// Permission bits of the socket file
+    pub mode: u32,
+}
+
+impl UnixSocketConfig {
+    /// Create a socket config with the default (owner-only) permissions
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self {
+            path: path.into(),
+            mode: AppSettingsService::DEFAULT_UNIX_SOCKET_MODE,
+        }
+    }
+
+    /// Override the permission bits applied to the socket file
+    pub fn with_mode(mut self, mode: u32) -> Self {
+        self.mode = mode;
+        self
+    }
+}
+
+/// Bind a Unix domain socket for the gateway.
+///
+/// - Creates the parent directory (owner-only) if it does not exist
+/// - Removes a stale socket left behind by a previous run, but refuses to
+///   remove anything at that path which is not a socket
+/// - Binds inside a fresh owner-only directory and applies `config.mode`
+///   before moving the socket into place, so nobody else can connect in the
+///   window between `bind` and `chmod`
+#[cfg(unix)]
+pub fn bind_unix_socket(config: &UnixSocketConfig) -> anyhow::Result<tokio::net::UnixListener> {
+    use std::os::unix::fs::{DirBuilderExt, FileTypeExt, PermissionsExt};
+
+    let path = &config.path;
+
+    if let Some(parent) = path.parent() {
+        if !parent.as_os_str().is_empty() && !parent.exists() {
+            std::fs::DirBuilder::new()
+                .recursive(true)
+                .mode(0o700)
+                .create(parent)?;
+        }
+    }
+
+    match std::fs::symlink_metadata(path) {
+        Ok(meta) if meta.file_type().is_socket() => {
+            tracing::debug!("[Gateway] Removing stale socket at {}", path.display());
+            std::fs::remove_file(path)?;
+        }
+        Ok(_) => {
+            anyhow::bail!(
+                "Refusing to bind gateway socket: {} exists and is not a socket",
+                path.display()
+            );
+        }
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => {}
+        Err(e) => return Err(e.into()),
+    }
+
+    let parent = match path.parent() {
+        Some(parent) if !parent.as_os_str().is_empty() => parent,
+        _ => std::path::Path::new("."),
+    };
+    // Kept short: socket paths are limited to ~100 bytes
+    let staging = parent.join(format!(
+        ".{}",
+        &uuid::Uuid::new_v4().simple().to_string()[..8]
+    ));
+    std::fs::DirBuilder::new().mode(0o700).create(&staging)?;
+
+    let staged_path = staging.join("s");
+    let bound = tokio::net::UnixListener::bind(&staged_path)
+        .map_err(anyhow::Error::from)
+        .and_then(|listener| {
+            std::fs::set_permissions(&staged_path, std::fs::Permissions::from_mode(config.mode))?;
+            std::fs::rename(&staged_path, path)?;
+            Ok(listener)
+        });
+    if let Err(e) = std::fs::remove_dir_all(&staging) {
+        tracing::warn!("[Gateway] Failed to remove {}: {}", staging.display(), e);
+    }
+
+    bound
+}
+
+/// Remove the socket file on shutdown (best effort)
+#[cfg(unix)]
+pub fn remove_unix_socket(config: &UnixSocketConfig) {
+    if let Err(e) = std::fs::remove_file(&config.path) {
+        if e.kind() != std::io::ErrorKind::NotFound {
+            tracing::warn!(
+                "[Gateway] Failed to remove socket {}: {}",
+                config.path.display(),
+                e
+            );
+        }
+    }
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+    use std::os::unix::fs::PermissionsExt;
+
+    fn temp_socket_path() -> PathBuf {
+        std::env::temp_dir()
+            .join(format!("mcpmux-test-{}", uuid::Uuid::new_v4()))
+            .join("gateway.sock")
+    }
+
+    #[tokio::test]
+    async fn test_bind_applies_mode() {
+        let config = UnixSocketConfig::new(temp_socket_path()).with_mode(0o660);
+        let _listener = bind_unix_socket(&config).unwrap();
+
+        let mode = std::fs::metadata(&config.path)
+            .unwrap()
+            .permissions()
+            .mode();
+        assert_eq!(mode & 0o777, 0o660);
+
+        remove_unix_socket(&config);
+        assert!(!config.path.exists());
+    }
+
+    #[tokio::test]
+    async fn test_bind_leaves_no_staging_directory() {
+        let config = UnixSocketConfig::new(temp_socket_path());
+        let _listener = bind_unix_socket(&config).unwrap();
+
+        let entries: Vec<_> = std::fs::read_dir(config.path.parent().unwrap())
+            .unwrap()
+            .map(|e| e.unwrap().file_name())
+            .collect();
+        assert_eq!(entries, vec![std::ffi::OsString::from("gateway.sock")]);
+
+        remove_unix_socket(&config);
+    }
+
+    #[tokio::test]
+    async fn test_bind_replaces_stale_socket() {
+        let config = UnixSocketConfig::new(temp_socket_path());
+        drop(bind_unix_socket(&config).unwrap());
+        assert!(config.path.exists());
+
+        // Rebinding over the leftover socket file succeeds
+        let _listener = bind_unix_socket(&config).unwrap();
+        remove_unix_socket(&config);
+    }
+
+    #[tokio::test]
+    async fn test_bind_refuses_regular_file() {
+        let config = UnixSocketConfig::new(temp_socket_path());
+        std::fs::create_dir_all(config.path.parent().unwrap()).unwrap();
+        std::fs::write(&config.path, b"not a socket").unwrap();
+
+        assert!(bind_unix_socket(&config).is_err());
+        assert!(config.path.exists());
+        std::fs::remove_file(&config.path).unwrap();
+    }
+}
@@ -6,19 +6,23 @@
 
 mod dependencies;
 mod handlers;
+pub mod listener;
 pub mod logging_middleware;
 pub mod rate_limit;
 mod service_container;
 mod startup;
 mod state;
+pub mod tls;
 
 use handlers::AppState; // Import AppState
 
 pub use dependencies::{DependenciesBuilder, GatewayDependencies};
 pub use handlers::PendingAuthorization;
+pub use listener::UnixSocketConfig;
 pub use service_container::ServiceContainer;
 pub use startup::{AutoConnectResult, StartupOrchestrator, TokenRefreshResult};
 pub use state::{ClientSession, GatewayState};
+pub use tls::{self_signed_hostnames, ClientAuthMode, TlsCertificateSource, TlsConfig};
 
 use axum::{
     middleware,
@@ -46,6 +50,15 @@ pub struct GatewayConfig {
     pub host: String,
     /// Port to listen on
     pub port: u16,
+    /// Serve on the TCP `host`/`port` (can be disabled when a Unix socket is configured)
+    pub tcp_enabled: bool,
+    /// Optional Unix domain socket to serve on (in addition to or instead of TCP)
+    pub unix_socket: Option<UnixSocketConfig>,
+    /// Terminate TLS on the TCP listener (required for non-loopback exposure)
+    pub tls: Option<TlsConfig>,
+    /// Externally reachable URL (e.g. "https://mux.example.lan:45818").
+    /// Overrides the derived localhost URL in OAuth metadata.
+    pub public_url: Option<String>,
     /// Enable CORS for browser access
     pub enable_cors: bool,
 }
@@ -55,6 +68,10 @@ impl Default for GatewayConfig {
         Self {
             host: "127.0.0.1".to_string(),
             port: mcpmux_core::branding::DEFAULT_GATEWAY_PORT,
+            tcp_enabled: true,
+            unix_socket: None,
+            tls: None,
+            public_url: None,
             enable_cors: true,
         }
     }
@@ -69,9 +86,41 @@ impl GatewayConfig {
     }
 
     /// Get the base URL for this gateway
-    /// Uses localhost for consistency with client configurations
+    ///
+    /// Uses the configured public URL when set; otherwise localhost (for
+    /// consistency with client configurations) with the scheme matching TLS.
     pub fn base_url(&self) -> String {
-        format!("http://localhost:{}", self.port)
+        if let Some(ref public_url) = self.public_url {
+            return public_url.trim_end_matches('/').to_string();
+        }
+        let scheme = if self.tls.is_some() { "https" } else { "http" };
+        format!("{}://localhost:{}", scheme, self.port)
+    }
+
+    /// Whether the TCP listener binds a loopback address only
+    pub fn is_loopback(&self) -> bool {
+        self.host
+            .parse::<std::net::IpAddr>()
+            .map(|ip| ip.is_loopback())
+            .unwrap_or(false)
+    }
+
+    /// Check that the listener configuration is usable and safe to serve
+    pub fn validate(&self) -> anyhow::Result<()> {
+        if !self.tcp_enabled && self.unix_socket.is_none() {
+            anyhow::bail!("Gateway has no listener configured (TCP disabled and no Unix socket)");
+        }
+        #[cfg(not(unix))]
+        if self.unix_socket.is_some() {
+            anyhow::bail!("Unix domain sockets are not supported on this platform");
+        }
+        if self.tcp_enabled && !self.is_loopback() && self.tls.is_none() {
+            anyhow::bail!(
+                "Refusing to listen on non-loopback address {} without TLS",
+                self.host
+            );
+        }
+        Ok(())
     }
 }
 
@@ -169,6 +218,11 @@ impl GatewayServer {
         self.services.grant_service.clone()
     }
 
+    /// Get the tool approval service (approval policies and held tool calls)
+    pub fn tool_approval_service(&self) -> Arc<crate::services::ToolApprovalService> {
+        self.services.tool_approval_service.clone()
+    }
+
     /// Get the OAuth manager
     pub fn oauth_manager(&self) -> Arc<crate::pool::OutboundOAuthManager> {
         self.services.pool_services.oauth_manager.clone()
@@ -199,8 +253,12 @@ impl GatewayServer {
         }
     }
 
-    /// Build the Axum router
-    fn build_router(&self) -> Router {
+    /// Build the Axum routers
+    ///
+    /// Returns `(public, local)`: `local` adds the desktop's client
+    /// management endpoints and is only served on loopback and Unix socket
+    /// listeners, since those endpoints take no authentication.
+    fn build_router(&self) -> (Router, Router) {
         let state = self.state.clone();
 
         // Create app state with services
@@ -250,7 +308,7 @@ impl GatewayServer {
         let mcp_service = StreamableHttpService::new(
             move || {
                 debug!("[Gateway] Creating handler instance for MCP session");
-                Ok(handler.clone())
+                Ok(handler.for_session())
             },
             LocalSessionManager::default().into(),
             StreamableHttpServerConfig {
@@ -270,10 +328,19 @@ impl GatewayServer {
                     mcp_oauth_middleware,
                 ));
 
-        // Client features endpoint (needs services, public)
+        // Client management (for desktop app, local listeners only)
         // Supports both DCR (simple IDs) and CIMD (URL-encoded IDs)
         // Clients should URL-encode client_ids that contain special characters
-        let client_features_routes = Router::new()
+        let client_management_routes = Router::new()
+            .route("/oauth/clients", get(handlers::oauth_list_clients))
+            .route(
+                "/oauth/clients/{client_id}",
+                put(handlers::oauth_update_client),
+            )
+            .route(
+                "/oauth/clients/{client_id}",
+                delete(handlers::oauth_delete_client),
+            )
             .route(
                 "/oauth/clients/{client_id}/features",
                 get(handlers::oauth_get_client_features),
@@ -307,18 +374,7 @@ impl GatewayServer {
             // (approve_oauth_consent), which can only be invoked by the desktop
             // app's own WebView—not by external HTTP clients, scripts, or bots.
             // Client registration (DCR - public)
-            .route("/oauth/register", post(handlers::oauth_register))
-            // Client management (for desktop app)
-            .route("/oauth/clients", get(handlers::oauth_list_clients))
-            // Client CRUD - expects URL-encoded client_id for CIMD clients
-            .route(
-                "/oauth/clients/{client_id}",
-                put(handlers::oauth_update_client),
-            )
-            .route(
-                "/oauth/clients/{client_id}",
-                delete(handlers::oauth_delete_client),
-            );
+            .route("/oauth/register", post(handlers::oauth_register));
 
         // E2E test mode: re-enable HTTP consent endpoint (guarded by env var).
         // In production this endpoint does NOT exist—consent is Tauri-IPC-only.
@@ -333,13 +389,22 @@ impl GatewayServer {
         // Rate limiter for OAuth endpoints (prevents abuse / consent flooding)
         let rate_limiter = rate_limit::default_oauth_rate_limiter();
 
-        let mut router = router
+        let public = router
             // Protected MCP routes (using rmcp's StreamableHttpService)
             .merge(mcp_routes)
-            // Client features (needs services)
-            .merge(client_features_routes)
             // Global state for all routes
-            .with_state(app_state.clone())
+            .with_state(app_state.clone());
+        let local = public.clone().merge(client_management_routes);
+
+        (
+            self.with_layers(public, rate_limiter.clone()),
+            self.with_layers(local, rate_limiter),
+        )
+    }
+
+    /// Apply the middleware shared by every listener's router
+    fn with_layers(&self, router: Router, rate_limiter: rate_limit::RateLimiter) -> Router {
+        let mut router = router
             .layer(TraceLayer::new_for_http())
             // Request/Response logging with body (DEBUG level)
             .layer(middleware::from_fn(
@@ -367,9 +432,33 @@ impl GatewayServer {
     /// 1. Starts auto-connect in background
     /// 2. Starts the HTTP server
     pub async fn run(self) -> anyhow::Result<()> {
-        let addr = self.config.addr();
-
-        info!("[Gateway] Starting on {}", addr);
+        self.config.validate()?;
+
+        if self.config.tcp_enabled {
+            info!(
+                "[Gateway] Starting on {} ({})",
+                self.config.addr(),
+                if self.config.tls.is_some() {
+                    "TLS"
+                } else {
+                    "plain HTTP"
+                }
+            );
+            if !self.config.is_loopback() {
+                info!(
+                    "[Gateway] Client management is not served on {}",
+                    self.config.host
+                );
+            }
+        }
+        info!("[Gateway] Base URL: {}", self.config.base_url());
+        if let Some(ref socket) = self.config.unix_socket {
+            info!(
+                "[Gateway] Starting on unix:{} (mode {:o})",
+                socket.path.display(),
+                socket.mode
+            );
+        }
         info!(
             "[Gateway] CORS: {}",
             if self.config.enable_cors {
@@ -392,6 +481,9 @@ impl GatewayServer {
         // MCPNotifier is started in build_router()
         info!("[Gateway] MCPNotifier started (listening to DomainEvents)");
 
+        // Expire time-bound and usage-limited grants in the background
+        self.services.grant_service.clone().start_expiry_task();
+
         // Auto-connect enabled servers in background (non-blocking for fast startup)
         // MCP clients will receive list_changed notifications when backends connect
         let self_arc = Arc::new(self);
@@ -443,12 +535,46 @@ impl GatewayServer {
         });
 
         // Build router and start server immediately
-        let router = self_arc.build_router();
-        let listener = tokio::net::TcpListener::bind(addr).await?;
+        let (public_router, local_router) = self_arc.build_router();
+        let mut listeners = tokio::task::JoinSet::new();
+
+        if self_arc.config.tcp_enabled {
+            let router = if self_arc.config.is_loopback() {
+                local_router.clone()
+            } else {
+                public_router
+            };
+            match self_arc.config.tls {
+                Some(ref tls) => {
+                    let server_config = tls.build_server_config()?;
+                    let listener =
+                        tls::TlsListener::bind(self_arc.config.addr(), server_config).await?;
+                    listeners.spawn(async move { axum::serve(listener, router).await });
+                }
+                None => {
+                    let listener = tokio::net::TcpListener::bind(self_arc.config.addr()).await?;
+                    listeners.spawn(async move { axum::serve(listener, router).await });
+                }
+            }
+        }
+
+        #[cfg(unix)]
+        let _socket_guard = match self_arc.config.unix_socket.clone() {
+            Some(socket) => {
+                let listener = listener::bind_unix_socket(&socket)?;
+                let router = local_router.clone();
+                listeners.spawn(async move { axum::serve(listener, router).await });
+                Some(UnixSocketGuard(socket))
+            }
+            None => None,
+        };
 
         info!("[Gateway] Ready to accept connections (servers connecting in background)");
 
-        axum::serve(listener, router).await?;
+        // Run until any listener stops; an error on one listener stops the gateway
+        if let Some(result) = listeners.join_next().await {
+            result??;
+        }
 
         Ok(())
     }
@@ -460,3 +586,39 @@ impl GatewayServer {
         tokio::spawn(async move { self.run().await })
     }
 }
+
+/// Removes the gateway socket file when the server future is dropped
+/// (including when the spawned gateway task is aborted)
+#[cfg(unix)]
+struct UnixSocketGuard(UnixSocketConfig);
+
+#[cfg(unix)]
+impl Drop for UnixSocketGuard {
+    fn drop(&mut self) {
+        listener::remove_unix_socket(&self.0);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_refuses_non_loopback_without_tls() {
+        let config = GatewayConfig {
+            host: "0.0.0.0".to_string(),
+            ..Default::default()
+        };
+        assert!(config.validate().is_err());
+
+        let config = GatewayConfig {
+            host: "0.0.0.0".to_string(),
+            tcp_enabled: false,
+            unix_socket: Some(UnixSocketConfig::new("/tmp/mcpmux-test.sock")),
+            ..Default::default()
+        };
+        assert_eq!(config.validate().is_ok(), cfg!(unix));
+
+        assert!(GatewayConfig::default().validate().is_ok());
+    }
+}
@@ -7,10 +7,11 @@ use std::sync::Arc;
 
 use crate::pool::{PoolServices, ServerManager, ServiceFactory};
 use crate::services::{
-    AuthorizationService, ClientMetadataService, GrantService, PrefixCacheService,
-    SpaceResolverService,
+    AuthorizationService, ClientCompatService, ClientMetadataService, GrantService,
+    PrefixCacheService, SpaceResolverService, ToolApprovalService, ToolBudgetService,
 };
 use mcpmux_core::DomainEvent;
+use mcpmux_storage::{ToolApprovalRepository, ToolUsageRepository};
 
 use super::{dependencies::GatewayDependencies, GatewayState, StartupOrchestrator};
 
@@ -42,6 +43,15 @@ pub struct ServiceContainer {
     /// Client metadata service for OAuth client information
     pub client_metadata_service: Arc<ClientMetadataService>,
 
+    /// Client compatibility profiles for tool listings (SRP)
+    pub client_compat_service: Arc<ClientCompatService>,
+
+    /// Tool budget service for capping tools/list per client (SRP)
+    pub tool_budget_service: Arc<ToolBudgetService>,
+
+    /// Tool approval service for approval policies and held tool calls (SRP)
+    pub tool_approval_service: Arc<ToolApprovalService>,
+
     /// Grant service for centralized grant management with auto-notifications (SRP + DRY)
     pub grant_service: Arc<GrantService>,
 
@@ -100,13 +110,37 @@ impl ServiceContainer {
         // Create client metadata service
         let client_metadata_service = deps.client_metadata_service.clone();
 
+        // Create client compatibility service (per-client tool presentation)
+        let client_compat_service = Arc::new(ClientCompatService::new(
+            deps.inbound_client_repo.clone(),
+            deps.settings_repo.clone(),
+        ));
+
+        // Create tool budget service (per-client tools/list cap, usage history)
+        let tool_budget_service = Arc::new(ToolBudgetService::new(
+            deps.inbound_client_repo.clone(),
+            Arc::new(ToolUsageRepository::new(deps.database.clone())),
+        ));
+
+        // Create tool approval service (approval policies, held tool calls)
+        let tool_approval_service = Arc::new(ToolApprovalService::new(
+            Arc::new(ToolApprovalRepository::new(deps.database.clone())),
+            deps.settings_repo.clone(),
+            pool_services.feature_service.clone(),
+            deps.log_manager.clone(),
+            domain_event_tx.clone(),
+        ));
+
         // Create grant service (centralized grant management with domain events)
         // Emits domain events (what happened) instead of implementation-specific events (what to do)
-        let grant_service = Arc::new(GrantService::new(
-            deps.inbound_client_repo.clone(), // Concrete type (pragmatic)
-            deps.feature_set_repo.clone(),    // Trait (DIP)
-            domain_event_tx.clone(),          // Direct event bus (decoupled)
-        ));
+        let grant_service = Arc::new(
+            GrantService::new(
+                deps.inbound_client_repo.clone(), // Concrete type (pragmatic)
+                deps.feature_set_repo.clone(),    // Trait (DIP)
+                domain_event_tx.clone(),          // Direct event bus (decoupled)
+            )
+            .with_feature_service(pool_services.feature_service.clone()),
+        );
 
         Self {
             pool_services,
@@ -116,6 +150,9 @@ impl ServiceContainer {
             space_resolver_service,
             prefix_cache_service,
             client_metadata_service,
+            client_compat_service,
+            tool_budget_service,
+            tool_approval_service,
             grant_service,
             gateway_state,
             dependencies: deps.clone(),
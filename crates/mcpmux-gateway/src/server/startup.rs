@@ -3,6 +3,7 @@
 //! Follows Single Responsibility Principle - only concerned with startup logic.
 //! Keeps GatewayServer focused on serving requests, not initialization.
 
+use std::collections::HashMap;
 use std::sync::Arc;
 
 use anyhow::Result;
@@ -249,23 +250,43 @@ impl StartupOrchestrator {
             return Ok(ConnectOutcome::NeedsOAuth);
         }
 
+        let key = crate::pool::ServerKey::new(space_id, server.server_id.clone());
+
+        // Space secrets the server's input values may reference
+        let secrets: HashMap<String, String> = match &self.dependencies.space_secret_repo {
+            Some(repo) => repo
+                .list_for_space(&space_id)
+                .await?
+                .into_iter()
+                .map(|secret| (secret.name, secret.value))
+                .collect(),
+            None => HashMap::new(),
+        };
+
         // Build transport config using cached definition
-        let transport_config = crate::pool::transport::resolution::build_transport_config(
+        let transport_config = match crate::pool::transport::resolution::build_transport_config(
             &definition.transport,
             server,
+            &secrets,
             self.dependencies.state_dir.as_deref(),
-        );
+        ) {
+            Ok(config) => config,
+            Err(e) => {
+                self.server_manager.set_error(&key, e.to_string()).await;
+                return Err(e);
+            }
+        };
 
         // Explicitly set state to connecting in ServerManager BEFORE starting connection
         // This ensures the UI reflects the "Connecting" state during startup
-        let key = crate::pool::ServerKey::new(space_id, server.server_id.clone());
         let _ = self.server_manager.set_connecting(&key).await;
 
         // Attempt connection through pool service (auto-connect mode: don't start OAuth flow)
         // For auto-connect, we pass auto_reconnect=true so OAuth-required servers just return
         // OAuthRequired without starting the callback server or opening browser
         let ctx = ConnectionContext::new(space_id, server.server_id.clone(), transport_config)
-            .with_auto_reconnect(true);
+            .with_auto_reconnect(true)
+            .with_concurrency_limit(server.concurrency_limit.clone());
         let connection_result = self.pool_service.connect_server(&ctx).await;
 
         match connection_result {
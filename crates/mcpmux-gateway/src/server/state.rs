@@ -16,7 +16,7 @@ use zeroize::Zeroizing;
 use super::handlers::PendingAuthorization;
 use crate::services::ClientMetadataService;
 use mcpmux_core::DomainEvent;
-use mcpmux_storage::{Database, InboundClientRepository, JWT_SECRET_SIZE};
+use mcpmux_storage::{Database, InboundClientRepository, ToolUsageRepository, JWT_SECRET_SIZE};
 use tokio::sync::broadcast;
 
 /// Client session in the gateway
@@ -59,6 +59,8 @@ pub struct GatewayState {
     db: Option<Arc<Mutex<Database>>>,
     /// Inbound client repository (OAuth + MCP client unified storage)
     inbound_client_repository: Option<InboundClientRepository>,
+    /// Tool usage repository (call history for tool budgets)
+    tool_usage_repository: Option<ToolUsageRepository>,
     /// Client metadata service (CIMD + DCR resolution)
     client_metadata_service: Option<Arc<ClientMetadataService>>,
     /// Unified event broadcaster (UI subscribes to receive all domain events)
@@ -78,6 +80,7 @@ impl GatewayState {
             jwt_signing_secret: None,
             db: None,
             inbound_client_repository: None,
+            tool_usage_repository: None,
             client_metadata_service: None,
             domain_event_tx,
         }
@@ -110,6 +113,7 @@ impl GatewayState {
     pub fn set_database(&mut self, db: Arc<Mutex<Database>>) {
         info!("[State] Database connection configured for OAuth persistence");
         self.inbound_client_repository = Some(InboundClientRepository::new(db.clone()));
+        self.tool_usage_repository = Some(ToolUsageRepository::new(db.clone()));
         self.db = Some(db);
     }
 
@@ -118,6 +122,11 @@ impl GatewayState {
         self.inbound_client_repository.as_ref()
     }
 
+    /// Get the tool usage repository (call history)
+    pub fn tool_usage_repository(&self) -> Option<&ToolUsageRepository> {
+        self.tool_usage_repository.as_ref()
+    }
+
     /// Set the client metadata service
     pub fn set_client_metadata_service(&mut self, service: Arc<ClientMetadataService>) {
         info!("[State] Client metadata service configured (CIMD + DCR resolution)");
@@ -0,0 +1,479 @@
+//! TLS termination for the gateway
+//!
+//! Lets the gateway be exposed beyond loopback (e.g. to trusted machines on a
+//! LAN). Certificates are either user-provided PEM files or a self-signed
+//! certificate generated once and persisted in the gateway state directory.
+//! Client certificates can optionally be verified against a CA bundle
+//! (mutual TLS).
+
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, UdpSocket};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::Duration;
+
+use anyhow::Context;
+use rustls::pki_types::pem::PemObject;
+use rustls::pki_types::{CertificateDer, PrivateKeyDer};
+use rustls::server::WebPkiClientVerifier;
+use rustls::{RootCertStore, ServerConfig};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::sync::mpsc;
+use tokio_rustls::server::TlsStream;
+use tokio_rustls::TlsAcceptor;
+use tracing::{debug, info, warn};
+
+/// Maximum time a client may take to complete the TLS handshake
+const HANDSHAKE_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// File names used for the generated self-signed certificate
+const SELF_SIGNED_CERT_FILE: &str = "gateway-cert.pem";
+const SELF_SIGNED_KEY_FILE: &str = "gateway-key.pem";
+
+/// Where the gateway's server certificate comes from
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TlsCertificateSource {
+    /// User-provided PEM certificate chain and private key
+    Files {
+        cert_path: PathBuf,
+        key_path: PathBuf,
+    },
+    /// Self-signed certificate generated on first use and reused afterwards
+    SelfSigned {
+        /// Directory the generated certificate and key are stored in
+        dir: PathBuf,
+        /// Subject alternative names (hostnames / IPs) for the certificate
+        hostnames: Vec<String>,
+    },
+}
+
+/// Client certificate (mutual TLS) verification mode
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ClientAuthMode {
+    /// Client certificates are not requested
+    #[default]
+    Off,
+    /// Client certificates are verified if presented, but not required
+    Optional,
+    /// Every connection must present a certificate signed by the client CA
+    Required,
+}
+
+impl ClientAuthMode {
+    /// Parse from a settings string ("off", "optional", "required")
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "off" => Some(Self::Off),
+            "optional" => Some(Self::Optional),
+            "required" => Some(Self::Required),
+            _ => None,
+        }
+    }
+
+    /// Settings string for this mode
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Off => "off",
+            Self::Optional => "optional",
+            Self::Required => "required",
+        }
+    }
+}
+
+/// TLS configuration for the gateway's TCP listener
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TlsConfig {
+    /// Server certificate source
+    pub certificate: TlsCertificateSource,
+    /// Client certificate verification mode
+    pub client_auth: ClientAuthMode,
+    /// PEM bundle of CAs trusted to sign client certificates
+    /// (required unless `client_auth` is `Off`)
+    pub client_ca_path: Option<PathBuf>,
+}
+
+impl TlsConfig {
+    /// TLS with user-provided certificate files and no client auth
+    pub fn from_files(cert_path: impl Into<PathBuf>, key_path: impl Into<PathBuf>) -> Self {
+        Self {
+            certificate: TlsCertificateSource::Files {
+                cert_path: cert_path.into(),
+                key_path: key_path.into(),
+            },
+            client_auth: ClientAuthMode::Off,
+            client_ca_path: None,
+        }
+    }
+
+    /// TLS with a self-signed certificate persisted in `dir`
+    pub fn self_signed(dir: impl Into<PathBuf>, hostnames: Vec<String>) -> Self {
+        Self {
+            certificate: TlsCertificateSource::SelfSigned {
+                dir: dir.into(),
+                hostnames,
+            },
+            client_auth: ClientAuthMode::Off,
+            client_ca_path: None,
+        }
+    }
+
+    /// Enable client certificate verification against a CA bundle
+    pub fn with_client_auth(mut self, mode: ClientAuthMode, ca_path: Option<PathBuf>) -> Self {
+        self.client_auth = mode;
+        self.client_ca_path = ca_path;
+        self
+    }
+
+    /// Build the rustls server config
+    pub fn build_server_config(&self) -> anyhow::Result<ServerConfig> {
+        let (cert_path, key_path) = match &self.certificate {
+            TlsCertificateSource::Files {
+                cert_path,
+                key_path,
+            } => (cert_path.clone(), key_path.clone()),
+            TlsCertificateSource::SelfSigned { dir, hostnames } => {
+                ensure_self_signed_certificate(dir, hostnames)?
+            }
+        };
+
+        let certs = load_certs(&cert_path)?;
+        let key = PrivateKeyDer::from_pem_file(&key_path)
+            .with_context(|| format!("Failed to read private key {}", key_path.display()))?;
+
+        let provider = Arc::new(rustls::crypto::ring::default_provider());
+        let builder = ServerConfig::builder_with_provider(provider.clone())
+            .with_safe_default_protocol_versions()?;
+
+        let builder = match self.client_auth {
+            ClientAuthMode::Off => builder.with_no_client_auth(),
+            mode => {
+                let ca_path = self
+                    .client_ca_path
+                    .as_ref()
+                    .context("Client certificate verification requires a client CA bundle")?;
+                let mut roots = RootCertStore::empty();
+                for cert in load_certs(ca_path)? {
+                    roots.add(cert)?;
+                }
+                let verifier =
+                    WebPkiClientVerifier::builder_with_provider(Arc::new(roots), provider);
+                let verifier = if mode == ClientAuthMode::Optional {
+                    verifier.allow_unauthenticated().build()?
+                } else {
+                    verifier.build()?
+                };
+                builder.with_client_cert_verifier(verifier)
+            }
+        };
+
+        let mut config = builder.with_single_cert(certs, key)?;
+        // The listener serves HTTP/1.1 only, so don't offer h2
+        config.alpn_protocols = vec![b"http/1.1".to_vec()];
+        Ok(config)
+    }
+}
+
+/// Load a PEM certificate chain
+fn load_certs(path: &Path) -> anyhow::Result<Vec<CertificateDer<'static>>> {
+    let certs = CertificateDer::pem_file_iter(path)
+        .with_context(|| format!("Failed to read certificates {}", path.display()))?
+        .collect::<Result<Vec<_>, _>>()
+        .with_context(|| format!("Invalid PEM in {}", path.display()))?;
+    if certs.is_empty() {
+        anyhow::bail!("No certificates found in {}", path.display());
+    }
+    Ok(certs)
+}
+
+/// Names a self-signed certificate should cover for a gateway bound to
+/// `bind_host` and reachable at `public_url`.
+///
+/// A wildcard bind address (`0.0.0.0`, `::`) is not a name clients connect
+/// to; the machine's primary address for that family is used instead.
+pub fn self_signed_hostnames(bind_host: &str, public_url: Option<&str>) -> Vec<String> {
+    let mut names = Vec::new();
+    match bind_host.parse::<IpAddr>() {
+        Ok(ip) if ip.is_unspecified() => names.extend(primary_address(ip).map(|a| a.to_string())),
+        _ => names.push(bind_host.to_string()),
+    }
+    if let Some(host) = public_url
+        .and_then(|u| url::Url::parse(u).ok())
+        .and_then(|u| u.host_str().map(|h| h.trim_matches(['[', ']']).to_string()))
+    {
+        if !names.contains(&host) {
+            names.push(host);
+        }
+    }
+    names
+}
+
+/// Source address the OS would use to reach the network for `family`'s
+/// address family. Connecting a UDP socket sends no packets.
+fn primary_address(family: IpAddr) -> Option<IpAddr> {
+    let (bind, probe): (SocketAddr, SocketAddr) = match family {
+        IpAddr::V4(_) => (
+            (Ipv4Addr::UNSPECIFIED, 0).into(),
+            (Ipv4Addr::new(192, 0, 2, 1), 9).into(),
+        ),
+        IpAddr::V6(_) => (
+            (Ipv6Addr::UNSPECIFIED, 0).into(),
+            (Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 1), 9).into(),
+        ),
+    };
+    let socket = UdpSocket::bind(bind).ok()?;
+    socket.connect(probe).ok()?;
+    let ip = socket.local_addr().ok()?.ip();
+    (!ip.is_unspecified() && !ip.is_loopback()).then_some(ip)
+}
+
+/// Return the paths of the self-signed certificate in `dir`, generating it if needed.
+///
+/// The certificate is regenerated when the requested hostnames change so that
+/// a newly configured public hostname is covered.
+pub fn ensure_self_signed_certificate(
+    dir: &Path,
+    hostnames: &[String],
+) -> anyhow::Result<(PathBuf, PathBuf)> {
+    let cert_path = dir.join(SELF_SIGNED_CERT_FILE);
+    let key_path = dir.join(SELF_SIGNED_KEY_FILE);
+    let names_path = dir.join("gateway-cert.names");
+
+    // Wildcard bind addresses are never valid names to connect to
+    let mut names: Vec<String> = hostnames
+        .iter()
+        .filter(|h| !h.parse::<IpAddr>().is_ok_and(|ip| ip.is_unspecified()))
+        .cloned()
+        .collect();
+    for default in ["localhost", "127.0.0.1"] {
+        if !names.iter().any(|n| n == default) {
+            names.push(default.to_string());
+        }
+    }
+    let names_line = names.join(",");
+
+    if cert_path.exists()
+        && key_path.exists()
+        && std::fs::read_to_string(&names_path).ok().as_deref() == Some(names_line.as_str())
+    {
+        return Ok((cert_path, key_path));
+    }
+
+    info!(
+        "[Gateway] Generating self-signed TLS certificate for {}",
+        names_line
+    );
+    std::fs::create_dir_all(dir)?;
+
+    let certified = rcgen::generate_simple_self_signed(names)?;
+    std::fs::write(&cert_path, certified.cert.pem())?;
+    write_private(&key_path, certified.key_pair.serialize_pem().as_bytes())?;
+    std::fs::write(&names_path, names_line)?;
+
+    Ok((cert_path, key_path))
+}
+
+/// Write a file readable only by the owner (private key material)
+fn write_private(path: &Path, contents: &[u8]) -> std::io::Result<()> {
+    #[cfg(unix)]
+    {
+        use std::io::Write;
+        use std::os::unix::fs::OpenOptionsExt;
+        let mut file = std::fs::OpenOptions::new()
+            .write(true)
+            .create(true)
+            .truncate(true)
+            .mode(0o600)
+            .open(path)?;
+        file.write_all(contents)
+    }
+    #[cfg(not(unix))]
+    {
+        std::fs::write(path, contents)
+    }
+}
+
+/// TCP listener that terminates TLS before handing connections to axum.
+///
+/// Handshakes run in their own tasks so a slow or stalled client can't block
+/// other connections from being accepted.
+pub struct TlsListener {
+    local_addr: SocketAddr,
+    rx: mpsc::Receiver<(TlsStream<TcpStream>, SocketAddr)>,
+    accept_task: tokio::task::JoinHandle<()>,
+}
+
+impl TlsListener {
+    /// Bind `addr` and start accepting TLS connections
+    pub async fn bind(addr: SocketAddr, config: ServerConfig) -> anyhow::Result<Self> {
+        let listener = TcpListener::bind(addr).await?;
+        let local_addr = listener.local_addr()?;
+        let acceptor = TlsAcceptor::from(Arc::new(config));
+        let (tx, rx) = mpsc::channel(64);
+
+        let accept_task = tokio::spawn(async move {
+            loop {
+                let (stream, peer) = match listener.accept().await {
+                    Ok(conn) => conn,
+                    Err(e) => {
+                        warn!("[Gateway] TLS accept error: {}", e);
+                        tokio::time::sleep(Duration::from_millis(50)).await;
+                        continue;
+                    }
+                };
+
+                let acceptor = acceptor.clone();
+                let tx = tx.clone();
+                tokio::spawn(async move {
+                    match tokio::time::timeout(HANDSHAKE_TIMEOUT, acceptor.accept(stream)).await {
+                        Ok(Ok(tls)) => {
+                            let _ = tx.send((tls, peer)).await;
+                        }
+                        Ok(Err(e)) => debug!("[Gateway] TLS handshake with {} failed: {}", peer, e),
+                        Err(_) => debug!("[Gateway] TLS handshake with {} timed out", peer),
+                    }
+                });
+            }
+        });
+
+        Ok(Self {
+            local_addr,
+            rx,
+            accept_task,
+        })
+    }
+}
+
+impl Drop for TlsListener {
+    fn drop(&mut self) {
+        self.accept_task.abort();
+    }
+}
+
+impl axum::serve::Listener for TlsListener {
+    type Io = TlsStream<TcpStream>;
+    type Addr = SocketAddr;
+
+    async fn accept(&mut self) -> (Self::Io, Self::Addr) {
+        match self.rx.recv().await {
+            Some(conn) => conn,
+            // The accept loop only stops when the listener is dropped
+            None => std::future::pending().await,
+        }
+    }
+
+    fn local_addr(&self) -> std::io::Result<Self::Addr> {
+        Ok(self.local_addr)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_dir() -> PathBuf {
+        std::env::temp_dir().join(format!("mcpmux-tls-test-{}", uuid::Uuid::new_v4()))
+    }
+
+    #[test]
+    fn test_client_auth_mode_roundtrip() {
+        for mode in [
+            ClientAuthMode::Off,
+            ClientAuthMode::Optional,
+            ClientAuthMode::Required,
+        ] {
+            assert_eq!(ClientAuthMode::parse(mode.as_str()), Some(mode));
+        }
+        assert_eq!(ClientAuthMode::parse("yes"), None);
+    }
+
+    #[test]
+    fn test_self_signed_certificate_is_reused() {
+        let dir = temp_dir();
+        let hostnames = vec!["mux.lan".to_string()];
+
+        let (cert, key) = ensure_self_signed_certificate(&dir, &hostnames).unwrap();
+        let first = std::fs::read(&cert).unwrap();
+
+        // Same hostnames: reused
+        ensure_self_signed_certificate(&dir, &hostnames).unwrap();
+        assert_eq!(std::fs::read(&cert).unwrap(), first);
+
+        // New hostname: regenerated
+        ensure_self_signed_certificate(&dir, &["other.lan".to_string()]).unwrap();
+        assert_ne!(std::fs::read(&cert).unwrap(), first);
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            let mode = std::fs::metadata(&key).unwrap().permissions().mode();
+            assert_eq!(mode & 0o777, 0o600);
+        }
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_self_signed_hostnames() {
+        assert_eq!(
+            self_signed_hostnames("192.168.1.20", Some("https://mux.lan:45818")),
+            vec!["192.168.1.20".to_string(), "mux.lan".to_string()]
+        );
+        assert_eq!(
+            self_signed_hostnames("127.0.0.1", None),
+            vec!["127.0.0.1".to_string()]
+        );
+
+        // Wildcard binds resolve to a concrete address (if any), never themselves
+        for wildcard in ["0.0.0.0", "::"] {
+            let names = self_signed_hostnames(wildcard, Some("https://[fd00::5]:45818"));
+            assert!(!names.iter().any(|n| n == wildcard));
+            assert_eq!(names.last().map(String::as_str), Some("fd00::5"));
+        }
+    }
+
+    #[test]
+    fn test_wildcard_is_not_a_certificate_name() {
+        let dir = temp_dir();
+        ensure_self_signed_certificate(&dir, &["0.0.0.0".to_string()]).unwrap();
+        let names = std::fs::read_to_string(dir.join("gateway-cert.names")).unwrap();
+        assert_eq!(names, "localhost,127.0.0.1");
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_build_server_config_offers_http1_only() {
+        let dir = temp_dir();
+        let config = TlsConfig::self_signed(&dir, vec!["mux.lan".to_string()])
+            .build_server_config()
+            .unwrap();
+        assert_eq!(config.alpn_protocols, vec![b"http/1.1".to_vec()]);
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_build_server_config_self_signed() {
+        let dir = temp_dir();
+        let config = TlsConfig::self_signed(&dir, vec!["mux.lan".to_string()]);
+        assert!(config.build_server_config().is_ok());
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_client_auth_requires_ca() {
+        let dir = temp_dir();
+        let config =
+            TlsConfig::self_signed(&dir, vec![]).with_client_auth(ClientAuthMode::Required, None);
+        assert!(config.build_server_config().is_err());
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_client_auth_with_ca_bundle() {
+        let dir = temp_dir();
+        // Any valid certificate works as a CA bundle for config construction
+        let (ca_cert, _) = ensure_self_signed_certificate(&dir.join("ca"), &[]).unwrap();
+        let config = TlsConfig::self_signed(&dir, vec![])
+            .with_client_auth(ClientAuthMode::Required, Some(ca_cert));
+        assert!(config.build_server_config().is_ok());
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
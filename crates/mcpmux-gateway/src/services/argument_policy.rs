@@ -0,0 +1,87 @@
+//! Argument Policy Service
+//!
+//! Applies the argument policies of a client's granted feature sets: tool
+//! schemas are rewritten before they are listed, and call arguments are
+//! checked and completed before dispatch. When several granted sets have a
+//! policy for the same tool, all of them apply.
+//! Follows SRP: only handles tool argument policies.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use anyhow::Result;
+use mcpmux_core::{ArgumentPolicy, ServerFeature};
+use mcpmux_storage::ArgumentPolicyRepository;
+use serde_json::Value;
+use tracing::warn;
+
+/// Service for tool argument policies
+pub struct ArgumentPolicyService {
+    repo: Arc<ArgumentPolicyRepository>,
+}
+
+impl ArgumentPolicyService {
+    pub fn new(repo: Arc<ArgumentPolicyRepository>) -> Self {
+        Self { repo }
+    }
+
+    /// Merged policies of the granted feature sets, keyed by feature ID
+    pub async fn policies_for_grants(
+        &self,
+        feature_set_ids: &[String],
+    ) -> Result<HashMap<String, ArgumentPolicy>> {
+        let mut merged: HashMap<String, ArgumentPolicy> = HashMap::new();
+        for rule in self.repo.list_for_feature_sets(feature_set_ids).await? {
+            merged
+                .entry(rule.feature_id)
+                .and_modify(|policy| policy.merge(&rule.policy))
+                .or_insert(rule.policy);
+        }
+        Ok(merged)
+    }
+
+    /// Rewrite the `inputSchema` in each tool's raw JSON as the client should see it.
+    ///
+    /// Storage errors are logged and the tools are left unchanged.
+    pub async fn apply_to_tools(&self, feature_set_ids: &[String], tools: &mut [ServerFeature]) {
+        let policies = match self.policies_for_grants(feature_set_ids).await {
+            Ok(policies) if !policies.is_empty() => policies,
+            Ok(_) => return,
+            Err(e) => {
+                warn!("[ArgumentPolicy] Failed to load policies: {}", e);
+                return;
+            }
+        };
+
+        for tool in tools.iter_mut() {
+            let Some(policy) = policies.get(&tool.id.to_string()) else {
+                continue;
+            };
+            if let Some(schema) = tool
+                .raw_json
+                .as_mut()
+                .and_then(|json| json.get_mut("inputSchema"))
+            {
+                policy.apply_to_schema(schema);
+            }
+        }
+    }
+
+    /// Check a call's arguments against the granted policies and inject
+    /// fixed values and defaults.
+    ///
+    /// Refusals are [`mcpmux_core::ArgumentPolicyViolation`]s.
+    pub async fn apply_to_arguments(
+        &self,
+        feature_set_ids: &[String],
+        feature_id: &str,
+        tool_name: &str,
+        arguments: &mut Value,
+    ) -> Result<()> {
+        let policies = self.policies_for_grants(feature_set_ids).await?;
+        if let Some(policy) = policies.get(feature_id) {
+            policy.apply_to_arguments(tool_name, arguments)?;
+        }
+        Ok(())
+    }
+}
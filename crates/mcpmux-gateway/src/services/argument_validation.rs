@@ -0,0 +1,154 @@
+//! Argument Validation Service
+//!
+//! Checks tool call arguments against the tool's `inputSchema` before
+//! dispatch, so malformed calls come back to the model as a tool error that
+//! lists each violation instead of reaching (and often crashing) the server.
+//! Compiled validators are cached by feature definition hash.
+//! Follows SRP: only handles argument validation.
+
+use std::sync::Arc;
+
+use dashmap::DashMap;
+use jsonschema::Validator;
+use mcpmux_core::{definition_hash, InstalledServerRepository, ServerFeature};
+use serde_json::{Map, Value};
+use tracing::{debug, warn};
+
+/// Service for validating tool arguments against input schemas
+pub struct ArgumentValidationService {
+    installed_server_repo: Arc<dyn InstalledServerRepository>,
+    /// Compiled validators by definition hash (`None` = schema does not compile)
+    validators: DashMap<String, Option<Arc<Validator>>>,
+}
+
+impl ArgumentValidationService {
+    pub fn new(installed_server_repo: Arc<dyn InstalledServerRepository>) -> Self {
+        Self {
+            installed_server_repo,
+            validators: DashMap::new(),
+        }
+    }
+
+    /// Violations of the tool's input schema, one message per violation.
+    ///
+    /// Empty when the arguments are valid, the tool has no usable schema, or
+    /// the server has argument validation turned off.
+    pub async fn validate(&self, tool: &ServerFeature, arguments: &Value) -> Vec<String> {
+        if self.is_skipped(tool).await {
+            return Vec::new();
+        }
+        let Some(validator) = self.validator_for(tool) else {
+            return Vec::new();
+        };
+        violations(&validator, arguments)
+    }
+
+    async fn is_skipped(&self, tool: &ServerFeature) -> bool {
+        match self
+            .installed_server_repo
+            .get_by_server_id(&tool.space_id, &tool.server_id)
+            .await
+        {
+            Ok(server) => server.is_some_and(|s| s.skip_argument_validation),
+            Err(e) => {
+                warn!(
+                    "[ArgumentValidation] Failed to load server {}: {}",
+                    tool.server_id, e
+                );
+                false
+            }
+        }
+    }
+
+    fn validator_for(&self, tool: &ServerFeature) -> Option<Arc<Validator>> {
+        let hash = tool
+            .definition_hash
+            .clone()
+            .unwrap_or_else(|| definition_hash(tool.raw_json.as_ref()));
+        if let Some(cached) = self.validators.get(&hash) {
+            return cached.clone();
+        }
+
+        let schema = tool.raw_json.as_ref()?.get("inputSchema")?;
+        let validator = match jsonschema::validator_for(schema) {
+            Ok(validator) => {
+                debug!(
+                    "[ArgumentValidation] Compiled schema for {}/{} ({})",
+                    tool.server_id, tool.feature_name, hash
+                );
+                Some(Arc::new(validator))
+            }
+            Err(e) => {
+                warn!(
+                    "[ArgumentValidation] Not validating {}/{}: invalid inputSchema: {}",
+                    tool.server_id, tool.feature_name, e
+                );
+                None
+            }
+        };
+        self.validators.insert(hash, validator.clone());
+        validator
+    }
+}
+
+/// Schema violations of a call's arguments (missing arguments count as `{}`)
+fn violations(validator: &Validator, arguments: &Value) -> Vec<String> {
+    let empty = Value::Object(Map::new());
+    let arguments = if arguments.is_null() {
+        &empty
+    } else {
+        arguments
+    };
+
+    validator
+        .iter_errors(arguments)
+        .map(|error| {
+            let path = error.instance_path.to_string();
+            if path.is_empty() {
+                error.to_string()
+            } else {
+                format!("{}: {}", path, error)
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn validator() -> Validator {
+        jsonschema::validator_for(&json!({
+            "type": "object",
+            "properties": {
+                "owner": { "type": "string" },
+                "limit": { "type": "integer", "minimum": 1 }
+            },
+            "required": ["owner"]
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_valid_arguments() {
+        let validator = validator();
+        assert!(violations(&validator, &json!({ "owner": "acme", "limit": 5 })).is_empty());
+    }
+
+    #[test]
+    fn test_violations_are_listed() {
+        let validator = validator();
+
+        let found = violations(&validator, &json!({ "limit": 0 }));
+        assert_eq!(found.len(), 2);
+        assert!(found
+            .iter()
+            .any(|v| v.contains("\"owner\" is a required property")));
+        assert!(found.iter().any(|v| v.starts_with("/limit: ")));
+
+        // Omitted arguments are validated as an empty object
+        let found = violations(&validator, &Value::Null);
+        assert_eq!(found.len(), 1);
+    }
+}
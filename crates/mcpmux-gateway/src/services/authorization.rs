@@ -33,7 +33,8 @@ impl AuthorizationService {
     /// Get effective feature set grants for a client in a specific space.
     ///
     /// Resolution strategy (least-privilege by default):
-    /// 1. Return explicit per-client grants from DB if any exist.
+    /// 1. Return explicit per-client grants from DB if any exist, skipping
+    ///    grants that have expired or used up their calls.
     /// 2. Always include the Default feature set as a baseline.
     ///
     /// Clients with no explicit grants only receive the Default feature set,
@@ -0,0 +1,551 @@
+//! Client Compatibility Service
+//!
+//! Applies per-client compatibility profiles to `tools/list` results and maps
+//! rewritten tool names back to qualified names for `tools/call`.
+//! Follows SRP: only handles tool presentation for picky clients.
+
+use anyhow::Result;
+use dashmap::DashMap;
+use mcpmux_core::{AppSettingsRepository, AppSettingsService, ClientCompatProfile};
+use mcpmux_storage::InboundClientRepository;
+use rmcp::model::{JsonObject, Tool};
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+use tracing::{debug, warn};
+
+/// Length of the hash suffix appended to shortened names ("_" + 8 hex chars)
+const HASH_SUFFIX_LEN: usize = 9;
+
+/// Maximum nesting when inlining `$ref`s
+const MAX_REF_DEPTH: usize = 8;
+
+/// Maximum `$ref`s inlined per schema. Definitions that reference each other
+/// more than once would otherwise grow the schema exponentially.
+const MAX_INLINED_REFS: usize = 64;
+
+/// Keywords whose value is a single subschema
+const SCHEMA_KEYWORDS: &[&str] = &[
+    "items",
+    "additionalItems",
+    "additionalProperties",
+    "unevaluatedItems",
+    "unevaluatedProperties",
+    "propertyNames",
+    "contains",
+    "not",
+    "if",
+    "then",
+    "else",
+];
+
+/// Keywords whose value is an array of subschemas
+const SCHEMA_ARRAY_KEYWORDS: &[&str] = &["allOf", "anyOf", "oneOf", "prefixItems"];
+
+/// Keywords whose value is a map of subschemas
+const SCHEMA_MAP_KEYWORDS: &[&str] = &["properties", "patternProperties", "$defs", "definitions"];
+
+/// Service for applying client compatibility profiles
+pub struct ClientCompatService {
+    client_repo: Arc<InboundClientRepository>,
+    settings_repo: Option<Arc<dyn AppSettingsRepository>>,
+    /// client_id -> (exposed tool name -> qualified tool name)
+    name_maps: DashMap<String, HashMap<String, String>>,
+}
+
+impl ClientCompatService {
+    pub fn new(
+        client_repo: Arc<InboundClientRepository>,
+        settings_repo: Option<Arc<dyn AppSettingsRepository>>,
+    ) -> Self {
+        Self {
+            client_repo,
+            settings_repo,
+            name_maps: DashMap::new(),
+        }
+    }
+
+    /// Resolve the compatibility profile for a client (None = pass through)
+    pub async fn profile_for_client(&self, client_id: &str) -> Result<Option<ClientCompatProfile>> {
+        let Some(client) = self.client_repo.get_client(client_id).await? else {
+            return Ok(None);
+        };
+
+        let user_profiles = match &self.settings_repo {
+            Some(repo) => {
+                AppSettingsService::new(repo.clone())
+                    .get_client_compat_profiles()
+                    .await
+            }
+            None => Vec::new(),
+        };
+
+        Ok(ClientCompatProfile::resolve(
+            &user_profiles,
+            client.software_id.as_deref(),
+            &client.client_name,
+        ))
+    }
+
+    /// Whether the client is in meta-tool mode (search_tools/invoke_tool only)
+    pub async fn meta_tools_enabled(&self, client_id: &str) -> bool {
+        match self.client_repo.get_client(client_id).await {
+            Ok(client) => client.is_some_and(|c| c.meta_tools),
+            Err(e) => {
+                warn!("[Compat] Failed to load client {}: {}", client_id, e);
+                false
+            }
+        }
+    }
+
+    /// Apply a profile to tools carrying qualified names.
+    ///
+    /// Records the name mapping for the client so `tools/call` can be routed.
+    pub fn apply(
+        &self,
+        client_id: &str,
+        profile: &ClientCompatProfile,
+        tools: Vec<Tool>,
+    ) -> Vec<Tool> {
+        let qualified: Vec<String> = tools.iter().map(|t| t.name.to_string()).collect();
+        let exposed = rewrite_tool_names(profile, &qualified);
+
+        if profile.rewrites_names() {
+            let reverse = exposed
+                .iter()
+                .map(|(q, e)| (e.clone(), q.clone()))
+                .collect();
+            self.name_maps.insert(client_id.to_string(), reverse);
+        }
+
+        tools
+            .into_iter()
+            .map(|mut tool| {
+                if let Some(name) = exposed.get(tool.name.as_ref()) {
+                    tool.name = name.clone().into();
+                }
+                if let Some(max) = profile.max_description_length {
+                    if let Some(description) = &tool.description {
+                        tool.description = Some(truncate_chars(description, max).into());
+                    }
+                }
+                if profile.inline_schema_refs || !profile.strip_schema_keywords.is_empty() {
+                    let mut schema = (*tool.input_schema).clone();
+                    normalize_schema(profile, &mut schema);
+                    tool.input_schema = Arc::new(schema);
+
+                    if let Some(output) = &tool.output_schema {
+                        let mut schema = (**output).clone();
+                        normalize_schema(profile, &mut schema);
+                        tool.output_schema = Some(Arc::new(schema));
+                    }
+                }
+                tool
+            })
+            .collect()
+    }
+
+    /// Map a tool name exposed to a client back to its qualified name.
+    ///
+    /// Returns `None` if no mapping is recorded for this name.
+    pub fn resolve_tool_name(&self, client_id: &str, name: &str) -> Option<String> {
+        let qualified = self.name_maps.get(client_id)?.get(name).cloned();
+        if let Some(ref qualified) = qualified {
+            debug!("[Compat] Mapped tool {} -> {}", name, qualified);
+        }
+        qualified
+    }
+}
+
+/// Compute the exposed name for each qualified tool name.
+///
+/// Names are sanitized and shortened per the profile. Shortened names, and
+/// every name in a group that would collide, get a hash suffix derived from
+/// the qualified name (which carries the server prefix), so the mapping
+/// doesn't depend on listing order and is stable across listings.
+pub fn rewrite_tool_names(
+    profile: &ClientCompatProfile,
+    qualified: &[String],
+) -> HashMap<String, String> {
+    let mut result = HashMap::with_capacity(qualified.len());
+    if !profile.rewrites_names() {
+        for name in qualified {
+            result.insert(name.clone(), name.clone());
+        }
+        return result;
+    }
+
+    let max = profile.max_tool_name_length.unwrap_or(usize::MAX);
+
+    let mut names: Vec<&String> = qualified.iter().collect();
+    names.sort();
+    names.dedup();
+
+    let sanitized: Vec<String> = names
+        .iter()
+        .map(|name| {
+            if profile.restrict_tool_name_charset {
+                name.chars()
+                    .map(|c| {
+                        if c.is_ascii_alphanumeric() || c == '_' || c == '-' {
+                            c
+                        } else {
+                            '_'
+                        }
+                    })
+                    .collect()
+            } else {
+                name.to_string()
+            }
+        })
+        .collect();
+    let candidates: Vec<String> = names
+        .iter()
+        .zip(&sanitized)
+        .map(|(name, sanitized)| {
+            if sanitized.chars().count() > max {
+                with_hash_suffix(sanitized, name, max)
+            } else {
+                sanitized.clone()
+            }
+        })
+        .collect();
+
+    let mut counts: HashMap<&str, usize> = HashMap::new();
+    for candidate in &candidates {
+        *counts.entry(candidate.as_str()).or_default() += 1;
+    }
+
+    let mut used: HashSet<String> = HashSet::new();
+    for ((name, sanitized), candidate) in names.iter().zip(&sanitized).zip(&candidates) {
+        let mut exposed = if counts[candidate.as_str()] > 1 {
+            with_hash_suffix(sanitized, name, max)
+        } else {
+            candidate.clone()
+        };
+        // Hash suffixes colliding too is vanishingly rare; names are visited
+        // in sorted order so even this fallback is deterministic
+        let mut attempt = 1;
+        while used.contains(&exposed) {
+            exposed = with_hash_suffix(sanitized, &format!("{}#{}", name, attempt), max);
+            attempt += 1;
+        }
+
+        used.insert(exposed.clone());
+        result.insert(name.to_string(), exposed);
+    }
+
+    result
+}
+
+/// Truncate `base` to fit `max` with an 8-hex-char hash of `original` appended
+fn with_hash_suffix(base: &str, original: &str, max: usize) -> String {
+    let digest = Sha256::digest(original.as_bytes());
+    let hash: String = digest
+        .iter()
+        .take(4)
+        .map(|b| format!("{:02x}", b))
+        .collect();
+
+    if max <= HASH_SUFFIX_LEN {
+        return hash.chars().take(max.max(1)).collect();
+    }
+
+    let keep = base.chars().count().min(max - HASH_SUFFIX_LEN);
+    let prefix: String = base.chars().take(keep).collect();
+    format!("{}_{}", prefix, hash)
+}
+
+/// Truncate to `max` characters, marking the cut with "..."
+fn truncate_chars(text: &str, max: usize) -> String {
+    if text.chars().count() <= max {
+        return text.to_string();
+    }
+    if max <= 3 {
+        return text.chars().take(max).collect();
+    }
+    let mut truncated: String = text.chars().take(max - 3).collect();
+    truncated.push_str("...");
+    truncated
+}
+
+/// Normalize a JSON Schema per the profile (inline refs, strip keywords)
+pub fn normalize_schema(profile: &ClientCompatProfile, schema: &mut JsonObject) {
+    if profile.inline_schema_refs {
+        let mut defs = JsonObject::new();
+        for key in ["$defs", "definitions"] {
+            if let Some(Value::Object(map)) = schema.remove(key) {
+                defs.extend(map);
+            }
+        }
+        let mut budget = MAX_INLINED_REFS;
+        for value in schema.values_mut() {
+            inline_refs(value, &defs, &mut Vec::new(), &mut budget);
+        }
+    }
+
+    if !profile.strip_schema_keywords.is_empty() {
+        strip_keywords(schema, &profile.strip_schema_keywords);
+    }
+}
+
+/// Replace local `$ref`s ("#/$defs/X", "#/definitions/X") with their targets.
+///
+/// `expanding` holds the definitions being inlined above `value`, and
+/// `budget` the refs left to inline in this schema.
+fn inline_refs(
+    value: &mut Value,
+    defs: &JsonObject,
+    expanding: &mut Vec<String>,
+    budget: &mut usize,
+) {
+    match value {
+        Value::Object(map) => {
+            let target = map.get("$ref").and_then(|r| r.as_str()).and_then(|r| {
+                r.strip_prefix("#/$defs/")
+                    .or_else(|| r.strip_prefix("#/definitions/"))
+            });
+
+            if let Some(target) = target.map(str::to_string) {
+                // Recursive, over-deep or over-budget references become
+                // permissive schemas
+                let inline =
+                    *budget > 0 && expanding.len() < MAX_REF_DEPTH && !expanding.contains(&target);
+                let mut resolved = match defs.get(&target) {
+                    Some(Value::Object(def)) if inline => {
+                        *budget -= 1;
+                        def.clone()
+                    }
+                    _ => JsonObject::new(),
+                };
+                map.remove("$ref");
+                for (k, v) in std::mem::take(map) {
+                    resolved.entry(k).or_insert(v);
+                }
+                *map = resolved;
+                expanding.push(target);
+                for v in map.values_mut() {
+                    inline_refs(v, defs, expanding, budget);
+                }
+                expanding.pop();
+            } else {
+                for v in map.values_mut() {
+                    inline_refs(v, defs, expanding, budget);
+                }
+            }
+        }
+        Value::Array(items) => {
+            for v in items {
+                inline_refs(v, defs, expanding, budget);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Remove keywords from a schema and all its subschemas.
+///
+/// Walks only subschema positions, so properties that happen to be named
+/// like a keyword (e.g. a "format" argument) are kept.
+fn strip_keywords(schema: &mut JsonObject, keywords: &[String]) {
+    for keyword in keywords {
+        schema.remove(keyword);
+    }
+
+    for (key, value) in schema.iter_mut() {
+        let key = key.as_str();
+        if SCHEMA_KEYWORDS.contains(&key) {
+            match value {
+                Value::Object(sub) => strip_keywords(sub, keywords),
+                // Tuple-style "items"
+                Value::Array(subs) => strip_keyword_list(subs, keywords),
+                _ => {}
+            }
+        } else if SCHEMA_ARRAY_KEYWORDS.contains(&key) {
+            if let Value::Array(subs) = value {
+                strip_keyword_list(subs, keywords);
+            }
+        } else if SCHEMA_MAP_KEYWORDS.contains(&key) {
+            if let Value::Object(map) = value {
+                for sub in map.values_mut() {
+                    if let Value::Object(sub) = sub {
+                        strip_keywords(sub, keywords);
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn strip_keyword_list(subs: &mut [Value], keywords: &[String]) {
+    for sub in subs {
+        if let Value::Object(sub) = sub {
+            strip_keywords(sub, keywords);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn strict_profile() -> ClientCompatProfile {
+        ClientCompatProfile {
+            name: "strict".to_string(),
+            max_tool_name_length: Some(20),
+            restrict_tool_name_charset: true,
+            ..Default::default()
+        }
+    }
+
+    fn as_object(value: Value) -> JsonObject {
+        value.as_object().cloned().unwrap()
+    }
+
+    #[test]
+    fn test_rewrite_tool_names_sanitizes_and_shortens() {
+        let qualified = vec![
+            "github.create_issue".to_string(),
+            "filesystem.read_multiple_files_at_once".to_string(),
+        ];
+        let names = rewrite_tool_names(&strict_profile(), &qualified);
+
+        assert_eq!(names["github.create_issue"], "github_create_issue");
+        let long = &names["filesystem.read_multiple_files_at_once"];
+        assert_eq!(long.len(), 20);
+        assert!(long.starts_with("filesystem_"));
+
+        // Stable across listings
+        assert_eq!(rewrite_tool_names(&strict_profile(), &qualified), names);
+    }
+
+    #[test]
+    fn test_rewrite_tool_names_resolves_collisions() {
+        let qualified = vec!["a.b".to_string(), "a_b".to_string(), "c.d".to_string()];
+        let names = rewrite_tool_names(&strict_profile(), &qualified);
+
+        assert!(names["a.b"].starts_with("a_b_"));
+        assert!(names["a_b"].starts_with("a_b_"));
+        assert_ne!(names["a.b"], names["a_b"]);
+        assert_eq!(names["c.d"], "c_d");
+
+        // Listing order doesn't decide who gets which name
+        let reversed: Vec<String> = qualified.iter().rev().cloned().collect();
+        assert_eq!(rewrite_tool_names(&strict_profile(), &reversed), names);
+
+        // Without a collision the plain name is kept
+        let alone = rewrite_tool_names(&strict_profile(), &["a_b".to_string()]);
+        assert_eq!(alone["a_b"], "a_b");
+    }
+
+    #[test]
+    fn test_rewrite_tool_names_passthrough_without_rules() {
+        let profile = ClientCompatProfile::default();
+        let names = rewrite_tool_names(&profile, &["x.y".to_string()]);
+        assert_eq!(names["x.y"], "x.y");
+    }
+
+    #[test]
+    fn test_inline_refs() {
+        let profile = ClientCompatProfile {
+            inline_schema_refs: true,
+            ..Default::default()
+        };
+        let mut schema = as_object(json!({
+            "type": "object",
+            "properties": {
+                "owner": { "$ref": "#/$defs/User", "description": "Owner" },
+                "node": { "$ref": "#/definitions/Node" }
+            },
+            "$defs": { "User": { "type": "string" } },
+            "definitions": {
+                "Node": { "type": "object", "properties": { "next": { "$ref": "#/definitions/Node" } } }
+            }
+        }));
+
+        normalize_schema(&profile, &mut schema);
+
+        assert!(!schema.contains_key("$defs"));
+        assert!(!schema.contains_key("definitions"));
+        assert_eq!(
+            schema["properties"]["owner"],
+            json!({ "type": "string", "description": "Owner" })
+        );
+        // Recursive references terminate
+        assert!(!serde_json::to_string(&schema).unwrap().contains("$ref"));
+    }
+
+    #[test]
+    fn test_inline_refs_bounds_shared_definitions() {
+        let profile = ClientCompatProfile {
+            inline_schema_refs: true,
+            ..Default::default()
+        };
+        // Each definition references the next twice: fully inlined, the
+        // schema would hold 2^20 copies of the last one
+        let mut defs = serde_json::Map::new();
+        for i in 0..20 {
+            let next = json!({ "$ref": format!("#/$defs/D{}", i + 1) });
+            defs.insert(
+                format!("D{i}"),
+                json!({ "type": "object", "properties": { "a": next, "b": next } }),
+            );
+        }
+        defs.insert("D20".to_string(), json!({ "type": "string" }));
+        let mut schema = as_object(json!({
+            "type": "object",
+            "properties": { "root": { "$ref": "#/$defs/D0" } },
+            "$defs": defs
+        }));
+
+        normalize_schema(&profile, &mut schema);
+
+        let output = serde_json::to_string(&schema).unwrap();
+        assert!(!output.contains("$ref"));
+        assert!(
+            output.len() < 16 * 1024,
+            "schema grew to {} bytes",
+            output.len()
+        );
+        assert_eq!(schema["properties"]["root"]["type"], "object");
+    }
+
+    #[test]
+    fn test_strip_keywords_keeps_property_names() {
+        let profile = ClientCompatProfile {
+            strip_schema_keywords: vec!["format".to_string(), "oneOf".to_string()],
+            ..Default::default()
+        };
+        let mut schema = as_object(json!({
+            "type": "object",
+            "properties": {
+                "format": { "type": "string", "format": "uri" },
+                "value": { "oneOf": [{ "type": "string" }, { "type": "number" }] },
+                "list": { "type": "array", "items": { "type": "string", "format": "date" } }
+            }
+        }));
+
+        normalize_schema(&profile, &mut schema);
+
+        assert_eq!(
+            Value::Object(schema),
+            json!({
+                "type": "object",
+                "properties": {
+                    "format": { "type": "string" },
+                    "value": {},
+                    "list": { "type": "array", "items": { "type": "string" } }
+                }
+            })
+        );
+    }
+
+    #[test]
+    fn test_truncate_chars() {
+        assert_eq!(truncate_chars("short", 10), "short");
+        assert_eq!(truncate_chars("a long description", 10), "a long ...");
+        assert_eq!(truncate_chars("héllo wörld", 8).chars().count(), 8);
+    }
+}
@@ -136,6 +136,7 @@ impl ClientMetadataService {
             metadata_cache_ttl: Some(3600), // 1 hour default
             connection_mode: "follow_active".to_string(),
             locked_space_id: None,
+            meta_tools: false,
             last_seen: Some(now.clone()),
             created_at: now.clone(),
             updated_at: now,
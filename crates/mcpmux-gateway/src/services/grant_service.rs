@@ -4,6 +4,7 @@
 //!
 //! **Responsibility (SRP):**
 //! - Grant/revoke feature sets to clients
+//! - Expire time-bound and usage-limited grants
 //! - Emit list_changed notifications automatically for ALL grant changes
 //! - Ensure DRY - single place for grant logic + notifications
 //!
@@ -13,13 +14,21 @@
 //! - Notifications work for: default grants, custom grants, individual features, batch updates
 
 use anyhow::Result;
+use chrono::Utc;
 use mcpmux_core::{DomainEvent, FeatureSetRepository};
-use mcpmux_storage::InboundClientRepository;
+use mcpmux_storage::{ClientGrant, GrantLimits, InboundClientRepository};
 use std::sync::Arc;
+use std::time::Duration;
 use tokio::sync::broadcast;
-use tracing::{info, warn};
+use tokio::task::JoinHandle;
+use tracing::{debug, info, warn};
 use uuid::Uuid;
 
+use crate::pool::FeatureService;
+
+/// How often lapsed grants are removed
+const GRANT_EXPIRY_INTERVAL: Duration = Duration::from_secs(30);
+
 /// Centralized service for grant management with automatic event emission
 ///
 /// **SOLID & Domain-Driven Design:**
@@ -38,6 +47,8 @@ pub struct GrantService {
     feature_set_repo: Arc<dyn FeatureSetRepository>,
     /// Domain event broadcaster (decoupled from consumers)
     event_tx: broadcast::Sender<DomainEvent>,
+    /// Resolves which call-limited grants admit a tool
+    feature_service: Option<Arc<FeatureService>>,
 }
 
 impl GrantService {
@@ -50,9 +61,16 @@ impl GrantService {
             client_repo,
             feature_set_repo,
             event_tx,
+            feature_service: None,
         }
     }
 
+    /// Count tool calls against call-limited grants
+    pub fn with_feature_service(mut self, feature_service: Arc<FeatureService>) -> Self {
+        self.feature_service = Some(feature_service);
+        self
+    }
+
     /// Grant a feature set to a client in a space
     ///
     /// Emits FeatureSetGranted domain event for consumers to handle.
@@ -88,6 +106,162 @@ impl GrantService {
         Ok(())
     }
 
+    /// Grant a feature set that lapses at `limits.expires_at` or after
+    /// `limits.max_calls` tool calls, whichever comes first.
+    ///
+    /// Re-granting replaces the limits and resets the call count.
+    pub async fn grant_feature_set_with_limits(
+        &self,
+        client_id: &str,
+        space_id: &str,
+        feature_set_id: &str,
+        limits: &GrantLimits,
+    ) -> Result<()> {
+        let space_uuid = Uuid::parse_str(space_id)?;
+        if limits.max_calls == Some(0) {
+            anyhow::bail!("max_calls must be at least 1");
+        }
+        if limits.expires_at.is_some_and(|at| at <= Utc::now()) {
+            anyhow::bail!("expires_at must be in the future");
+        }
+
+        info!(
+            client_id = %client_id,
+            space_id = %space_id,
+            feature_set_id = %feature_set_id,
+            expires_at = ?limits.expires_at,
+            max_calls = ?limits.max_calls,
+            "[GrantService] Granting feature set with limits"
+        );
+
+        self.client_repo
+            .grant_feature_set_with_limits(client_id, space_id, feature_set_id, limits)
+            .await?;
+
+        let _ = self.event_tx.send(DomainEvent::GrantIssued {
+            client_id: client_id.to_string(),
+            space_id: space_uuid,
+            feature_set_id: feature_set_id.to_string(),
+        });
+
+        Ok(())
+    }
+
+    /// Reserve a tool call against the client's call-limited grants that
+    /// admit the tool, before the call is dispatched. Grants that reach their
+    /// limit are revoked.
+    ///
+    /// Returns the grants among `feature_set_ids` that have no calls left;
+    /// the call must not be admitted through them.
+    pub async fn reserve_tool_call(
+        &self,
+        client_id: &str,
+        space_id: &Uuid,
+        feature_set_ids: &[String],
+        server_id: &str,
+        tool_name: &str,
+    ) -> Result<Vec<String>> {
+        let Some(ref feature_service) = self.feature_service else {
+            return Ok(Vec::new());
+        };
+        let space_id_str = space_id.to_string();
+        let limited: Vec<ClientGrant> = self
+            .client_repo
+            .list_grants_for_space(client_id, &space_id_str)
+            .await?
+            .into_iter()
+            .filter(|g| g.max_calls.is_some() && feature_set_ids.contains(&g.feature_set_id))
+            .collect();
+        if limited.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let mut admitting = Vec::new();
+        for grant in limited {
+            let tools = feature_service
+                .get_tools_for_grants(&space_id_str, std::slice::from_ref(&grant.feature_set_id))
+                .await?;
+            if tools
+                .iter()
+                .any(|f| f.server_id == server_id && f.feature_name == tool_name)
+            {
+                admitting.push(grant.feature_set_id);
+            }
+        }
+        if admitting.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        debug!(
+            client_id = %client_id,
+            tool = %tool_name,
+            grants = ?admitting,
+            "[GrantService] Reserving call against limited grants"
+        );
+        let reserved = self
+            .client_repo
+            .reserve_grant_calls(client_id, &space_id_str, &admitting)
+            .await?;
+
+        let now = Utc::now();
+        for grant in reserved.iter().filter(|g| g.is_lapsed(now)) {
+            info!(
+                client_id = %grant.client_id,
+                feature_set_id = %grant.feature_set_id,
+                "[GrantService] Grant used up its calls"
+            );
+            self.revoke_feature_set(&grant.client_id, &grant.space_id, &grant.feature_set_id)
+                .await?;
+        }
+
+        Ok(admitting
+            .into_iter()
+            .filter(|id| !reserved.iter().any(|g| &g.feature_set_id == id))
+            .collect())
+    }
+
+    /// Remove grants that have expired or used up their calls.
+    ///
+    /// Emits GrantRevoked for each removed grant; returns how many were removed.
+    pub async fn expire_lapsed_grants(&self) -> Result<usize> {
+        let lapsed = self.client_repo.delete_lapsed_grants().await?;
+        for grant in &lapsed {
+            info!(
+                client_id = %grant.client_id,
+                space_id = %grant.space_id,
+                feature_set_id = %grant.feature_set_id,
+                "[GrantService] Grant lapsed"
+            );
+            match Uuid::parse_str(&grant.space_id) {
+                Ok(space_id) => {
+                    let _ = self.event_tx.send(DomainEvent::GrantRevoked {
+                        client_id: grant.client_id.clone(),
+                        space_id,
+                        feature_set_id: grant.feature_set_id.clone(),
+                    });
+                }
+                Err(e) => warn!(
+                    "[GrantService] Lapsed grant has invalid space id {}: {}",
+                    grant.space_id, e
+                ),
+            }
+        }
+        Ok(lapsed.len())
+    }
+
+    /// Start the background loop that expires lapsed grants (call once at startup)
+    pub fn start_expiry_task(self: Arc<Self>) -> JoinHandle<()> {
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(GRANT_EXPIRY_INTERVAL);
+            loop {
+                interval.tick().await;
+                if let Err(e) = self.expire_lapsed_grants().await {
+                    warn!("[GrantService] Failed to expire grants: {}", e);
+                }
+            }
+        })
+    }
+
     /// Revoke a feature set from a client in a space
     ///
     /// Emits FeatureSetRevoked domain event for consumers to handle.
@@ -143,8 +317,9 @@ impl GrantService {
         // Verify feature set exists
         match self.feature_set_repo.get(feature_set_id).await? {
             Some(feature_set) => {
-                // Ensure the feature set belongs to the specified space
-                if feature_set.space_id.as_deref() != Some(space_id) {
+                // Ensure the feature set belongs to the specified space;
+                // global sets apply in every space
+                if !feature_set.is_global() && feature_set.space_id.as_deref() != Some(space_id) {
                     warn!(
                         "[GrantService] Feature set {} belongs to space {:?}, not {}",
                         feature_set_id, feature_set.space_id, space_id
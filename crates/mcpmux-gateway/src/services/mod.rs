@@ -5,18 +5,32 @@
 //! - Services depend on abstractions (DIP)
 //! - Open for extension, closed for modification (OCP)
 
+mod argument_policy;
+mod argument_validation;
 mod authorization;
+mod client_compat;
 mod client_metadata_service;
 mod event_emitter;
 mod grant_service;
 mod notification_emitter;
 mod prefix_cache;
 mod space_resolver;
+mod tool_approval;
+mod tool_budget;
+mod tool_rate_limit;
 
+pub use argument_policy::ArgumentPolicyService;
+pub use argument_validation::ArgumentValidationService;
 pub use authorization::AuthorizationService;
+pub use client_compat::ClientCompatService;
 pub use client_metadata_service::ClientMetadataService;
 pub use event_emitter::EventEmitter;
 pub use grant_service::GrantService;
 pub use notification_emitter::NotificationEmitter;
 pub use prefix_cache::PrefixCacheService;
 pub use space_resolver::SpaceResolverService;
+pub use tool_approval::{
+    ApprovalDecision, ApprovalSession, ToolApprovalRequest, ToolApprovalService, APPROVAL_TIMEOUT,
+};
+pub use tool_budget::{estimate_tool_tokens, select_tools, ToolBudgetService};
+pub use tool_rate_limit::ToolRateLimitService;
@@ -0,0 +1,359 @@
+//! Tool Approval Service
+//!
+//! Decides whether a tool call runs, is refused, or waits for a human.
+//! Held calls are published as `ToolApprovalRequested` events for the desktop
+//! app and released by [`ToolApprovalService::resolve`]; the MCP handler can
+//! instead ask the client via elicitation. Decisions are written to the
+//! server log. "Remember for this session" approvals only exist for calls
+//! with an MCP session and are dropped when the session ends.
+//! Follows SRP: only handles approval policy and pending approvals.
+
+use anyhow::{anyhow, Result};
+use chrono::{DateTime, Utc};
+use dashmap::{DashMap, DashSet};
+use mcpmux_core::{
+    AppSettingsRepository, AppSettingsService, ApprovalPolicy, ApprovalTarget, DomainEvent,
+    LogLevel, LogSource, ServerFeature, ServerLog, ServerLogManager,
+};
+use mcpmux_storage::ToolApprovalRepository;
+use serde::Serialize;
+use serde_json::{json, Value};
+use std::sync::{Arc, OnceLock};
+use std::time::Duration;
+use tokio::sync::{broadcast, oneshot};
+use tracing::{debug, info, warn};
+use uuid::Uuid;
+
+use crate::pool::FeatureService;
+
+/// How long a held tool call waits for a decision before it is rejected
+pub const APPROVAL_TIMEOUT: Duration = Duration::from_secs(120);
+
+/// A tool call waiting for approval
+#[derive(Debug, Clone, Serialize)]
+pub struct ToolApprovalRequest {
+    pub request_id: Uuid,
+    pub space_id: Uuid,
+    pub client_id: String,
+    /// MCP session the call came from (scopes "remember for this session")
+    pub session_id: Option<String>,
+    pub server_id: String,
+    pub feature_id: Uuid,
+    /// Qualified tool name as called by the client
+    pub tool_name: String,
+    pub arguments: Value,
+    /// Whether the server annotates the tool as destructive
+    pub destructive: bool,
+    pub requested_at: DateTime<Utc>,
+}
+
+/// The outcome of an approval request
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ApprovalDecision {
+    pub approved: bool,
+    /// Skip approval for this tool for the rest of the session
+    pub remember: bool,
+    /// Who decided ("desktop", "client", "timeout")
+    pub decided_by: String,
+}
+
+impl ApprovalDecision {
+    fn rejected(decided_by: &str) -> Self {
+        Self {
+            approved: false,
+            remember: false,
+            decided_by: decided_by.to_string(),
+        }
+    }
+}
+
+struct PendingApproval {
+    request: ToolApprovalRequest,
+    sender: oneshot::Sender<ApprovalDecision>,
+}
+
+/// Service for tool call approval policies and pending approvals
+pub struct ToolApprovalService {
+    repo: Arc<ToolApprovalRepository>,
+    settings_repo: Option<Arc<dyn AppSettingsRepository>>,
+    feature_service: Arc<FeatureService>,
+    log_manager: Arc<ServerLogManager>,
+    event_tx: broadcast::Sender<DomainEvent>,
+    pending: DashMap<Uuid, PendingApproval>,
+    /// (client_id, session_id, feature_id) approved for the rest of the session
+    remembered: DashSet<(String, String, Uuid)>,
+}
+
+impl ToolApprovalService {
+    pub fn new(
+        repo: Arc<ToolApprovalRepository>,
+        settings_repo: Option<Arc<dyn AppSettingsRepository>>,
+        feature_service: Arc<FeatureService>,
+        log_manager: Arc<ServerLogManager>,
+        event_tx: broadcast::Sender<DomainEvent>,
+    ) -> Self {
+        Self {
+            repo,
+            settings_repo,
+            feature_service,
+            log_manager,
+            event_tx,
+            pending: DashMap::new(),
+            remembered: DashSet::new(),
+        }
+    }
+
+    /// Effective approval policy for a tool in a space
+    pub async fn policy_for(
+        &self,
+        space_id: &str,
+        feature: &ServerFeature,
+    ) -> Result<ApprovalPolicy> {
+        let rules = self.repo.list_for_space(space_id).await?;
+        let feature_id = feature.id.to_string();
+
+        let tool_rule = rules
+            .iter()
+            .find(|r| r.target_type == ApprovalTarget::Tool && r.target == feature_id)
+            .map(|r| r.policy);
+
+        let mut feature_set_rules = Vec::new();
+        if tool_rule.is_none() {
+            for rule in rules
+                .iter()
+                .filter(|r| r.target_type == ApprovalTarget::FeatureSet)
+            {
+                let members = self
+                    .feature_service
+                    .resolve_feature_sets(space_id, std::slice::from_ref(&rule.target))
+                    .await?;
+                if members.iter().any(|m| m.id == feature.id) {
+                    feature_set_rules.push(rule.policy);
+                }
+            }
+        }
+
+        let destructive_default = match &self.settings_repo {
+            Some(repo) => {
+                AppSettingsService::new(repo.clone())
+                    .get_destructive_tool_policy()
+                    .await
+            }
+            None => ApprovalPolicy::Allow,
+        };
+
+        Ok(ApprovalPolicy::resolve(
+            tool_rule,
+            feature_set_rules,
+            feature.is_destructive(),
+            destructive_default,
+        ))
+    }
+
+    /// Whether the tool was approved with "remember for this session".
+    ///
+    /// Calls without a session are never remembered.
+    pub fn is_remembered(
+        &self,
+        client_id: &str,
+        session_id: Option<&str>,
+        feature_id: Uuid,
+    ) -> bool {
+        let Some(session_id) = session_id else {
+            return false;
+        };
+        self.remembered
+            .contains(&(client_id.to_string(), session_id.to_string(), feature_id))
+    }
+
+    /// Forget the remembered approvals of an ended session
+    pub fn end_session(&self, session_id: &str) {
+        self.remembered
+            .retain(|(_, session, _)| session != session_id);
+        debug!(
+            "[ToolApproval] Cleared approvals for session {}",
+            session_id
+        );
+    }
+
+    /// Hold a tool call until the desktop app decides (or the timeout passes)
+    pub async fn request_approval(&self, request: ToolApprovalRequest) -> ApprovalDecision {
+        let request_id = request.request_id;
+        let (sender, receiver) = oneshot::channel();
+
+        info!(
+            "[ToolApproval] Holding {} for client {} (request {})",
+            request.tool_name, request.client_id, request_id
+        );
+        // Register before notifying so listeners see the request as pending
+        let event = DomainEvent::ToolApprovalRequested {
+            request_id,
+            space_id: request.space_id,
+            client_id: request.client_id.clone(),
+            server_id: request.server_id.clone(),
+            tool_name: request.tool_name.clone(),
+            arguments: request.arguments.clone(),
+        };
+        self.pending
+            .insert(request_id, PendingApproval { request, sender });
+        let _ = self.event_tx.send(event);
+
+        match tokio::time::timeout(APPROVAL_TIMEOUT, receiver).await {
+            Ok(Ok(decision)) => decision,
+            Ok(Err(_)) => ApprovalDecision::rejected("cancelled"),
+            Err(_) => {
+                self.pending.remove(&request_id);
+                ApprovalDecision::rejected("timeout")
+            }
+        }
+    }
+
+    /// Approve or reject a held tool call from the desktop app
+    pub fn resolve(&self, request_id: Uuid, approved: bool, remember: bool) -> Result<()> {
+        let (_, pending) = self
+            .pending
+            .remove(&request_id)
+            .ok_or_else(|| anyhow!("No pending approval with id {}", request_id))?;
+
+        debug!(
+            "[ToolApproval] Request {} {} from desktop",
+            request_id,
+            if approved { "approved" } else { "rejected" }
+        );
+        pending
+            .sender
+            .send(ApprovalDecision {
+                approved,
+                remember: approved && remember,
+                decided_by: "desktop".to_string(),
+            })
+            .map_err(|_| anyhow!("Tool call {} is no longer waiting", request_id))
+    }
+
+    /// Tool calls currently waiting for a decision, oldest first
+    pub fn list_pending(&self) -> Vec<ToolApprovalRequest> {
+        let mut requests: Vec<_> = self.pending.iter().map(|p| p.request.clone()).collect();
+        requests.sort_by_key(|r| r.requested_at);
+        requests
+    }
+
+    /// Record a decision: remember it if asked, audit it, and notify the UI
+    pub async fn record_decision(
+        &self,
+        request: &ToolApprovalRequest,
+        decision: &ApprovalDecision,
+    ) {
+        if decision.approved && decision.remember {
+            match &request.session_id {
+                Some(session_id) => {
+                    self.remembered.insert((
+                        request.client_id.clone(),
+                        session_id.clone(),
+                        request.feature_id,
+                    ));
+                }
+                None => debug!(
+                    "[ToolApproval] No session for {}; approval not remembered",
+                    request.tool_name
+                ),
+            }
+        }
+
+        let verb = if decision.approved {
+            "approved"
+        } else {
+            "rejected"
+        };
+        let log = ServerLog::new(
+            if decision.approved {
+                LogLevel::Info
+            } else {
+                LogLevel::Warn
+            },
+            LogSource::App,
+            format!(
+                "Tool call {} {} by {}",
+                request.tool_name, verb, decision.decided_by
+            ),
+        )
+        .with_metadata(json!({
+            "request_id": request.request_id,
+            "client_id": request.client_id,
+            "session_id": request.session_id,
+            "tool": request.tool_name,
+            "approved": decision.approved,
+            "approved_by": decision.decided_by,
+            "remember_for_session": decision.remember,
+        }));
+        if let Err(e) = self
+            .log_manager
+            .append(&request.space_id.to_string(), &request.server_id, log)
+            .await
+        {
+            warn!("[ToolApproval] Failed to log decision: {}", e);
+        }
+
+        let _ = self.event_tx.send(DomainEvent::ToolApprovalResolved {
+            request_id: request.request_id,
+            space_id: request.space_id,
+            client_id: request.client_id.clone(),
+            server_id: request.server_id.clone(),
+            tool_name: request.tool_name.clone(),
+            approved: decision.approved,
+            decided_by: decision.decided_by.clone(),
+        });
+    }
+
+    /// Record a call refused by a `deny` policy
+    pub async fn record_denied(&self, space_id: Uuid, client_id: &str, feature: &ServerFeature) {
+        let log = ServerLog::new(
+            LogLevel::Warn,
+            LogSource::App,
+            format!("Tool call {} denied by policy", feature.qualified_name()),
+        )
+        .with_metadata(json!({
+            "client_id": client_id,
+            "tool": feature.qualified_name(),
+            "approved": false,
+            "approved_by": "policy",
+        }));
+        if let Err(e) = self
+            .log_manager
+            .append(&space_id.to_string(), &feature.server_id, log)
+            .await
+        {
+            warn!("[ToolApproval] Failed to log denial: {}", e);
+        }
+    }
+}
+
+/// Ties remembered approvals to the lifetime of an MCP session.
+///
+/// Each session's handler holds one; when the session ends and the handler
+/// is dropped, the session's remembered approvals are cleared.
+pub struct ApprovalSession {
+    service: Arc<ToolApprovalService>,
+    session_id: OnceLock<String>,
+}
+
+impl ApprovalSession {
+    pub fn new(service: Arc<ToolApprovalService>) -> Self {
+        Self {
+            service,
+            session_id: OnceLock::new(),
+        }
+    }
+
+    /// Record the session this guard belongs to (first ID wins)
+    pub fn bind(&self, session_id: &str) {
+        let _ = self.session_id.set(session_id.to_string());
+    }
+}
+
+impl Drop for ApprovalSession {
+    fn drop(&mut self) {
+        if let Some(session_id) = self.session_id.get() {
+            self.service.end_session(session_id);
+        }
+    }
+}
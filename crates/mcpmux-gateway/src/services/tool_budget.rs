@@ -0,0 +1,262 @@
+//! Tool Budget Service
+//!
+//! Caps the number of tools a client sees in tools/list. When a client has a
+//! budget, tools are prioritized (pinned first, then by server priority, then
+//! by call frequency) and only the top N are returned.
+//! Follows SRP: only decides which tools fit in a client's budget.
+
+use mcpmux_core::ServerFeature;
+use mcpmux_storage::{ClientToolBudget, InboundClientRepository, ToolUsage, ToolUsageRepository};
+use std::collections::HashMap;
+use std::sync::Arc;
+use tracing::{debug, warn};
+
+/// Rough bytes-per-token ratio for estimating schema cost in a client's context
+const BYTES_PER_TOKEN: usize = 4;
+
+/// Service for applying per-client tool budgets
+pub struct ToolBudgetService {
+    client_repo: Arc<InboundClientRepository>,
+    usage_repo: Arc<ToolUsageRepository>,
+}
+
+impl ToolBudgetService {
+    pub fn new(
+        client_repo: Arc<InboundClientRepository>,
+        usage_repo: Arc<ToolUsageRepository>,
+    ) -> Self {
+        Self {
+            client_repo,
+            usage_repo,
+        }
+    }
+
+    /// Trim tools to the client's budget (no-op without a budget)
+    ///
+    /// Storage errors are logged and the full list is returned.
+    pub async fn apply(
+        &self,
+        client_id: &str,
+        space_id: &str,
+        tools: Vec<ServerFeature>,
+    ) -> Vec<ServerFeature> {
+        let budget = match self.client_repo.get_tool_budget(client_id).await {
+            Ok(Some(budget)) => budget,
+            Ok(None) => return tools,
+            Err(e) => {
+                warn!(
+                    "[ToolBudget] Failed to load budget for {}: {}",
+                    client_id, e
+                );
+                return tools;
+            }
+        };
+        match budget.max_tools {
+            Some(max_tools) if tools.len() > max_tools as usize => {}
+            _ => return tools,
+        }
+
+        let usage = self
+            .usage_repo
+            .get_usage_for_space(space_id)
+            .await
+            .unwrap_or_else(|e| {
+                warn!("[ToolBudget] Failed to load tool usage: {}", e);
+                HashMap::new()
+            });
+        let total = tools.len();
+        let kept = select_tools(tools, &budget, &usage);
+
+        debug!(
+            "[ToolBudget] Client {} limited to {} of {} tools",
+            client_id,
+            kept.len(),
+            total
+        );
+        kept
+    }
+
+    /// Record a tool call (feeds usage-based prioritization)
+    pub async fn record_call(&self, space_id: &str, server_id: &str, tool_name: &str) {
+        if let Err(e) = self
+            .usage_repo
+            .record_call(space_id, server_id, tool_name)
+            .await
+        {
+            warn!("[ToolBudget] Failed to record tool usage: {}", e);
+        }
+    }
+}
+
+/// Select the tools that fit in a budget.
+///
+/// Priority: pinned tools (in pin order), then tools of prioritized servers
+/// (in server order), then call count, then most recently called. Pins are
+/// portable feature keys, so they survive alias changes and name overrides.
+/// Kept tools are returned in their original order so listings stay stable as
+/// usage changes.
+pub fn select_tools(
+    tools: Vec<ServerFeature>,
+    budget: &ClientToolBudget,
+    usage: &HashMap<(String, String), ToolUsage>,
+) -> Vec<ServerFeature> {
+    let Some(max_tools) = budget.max_tools.map(|m| m as usize) else {
+        return tools;
+    };
+
+    let pin_rank = |f: &ServerFeature| {
+        let key = f.portable_key();
+        budget.pinned_tools.iter().position(|p| *p == key)
+    };
+    let server_rank = |f: &ServerFeature| {
+        budget
+            .server_priority
+            .iter()
+            .position(|s| *s == f.server_id)
+            .unwrap_or(usize::MAX)
+    };
+    let usage_of = |f: &ServerFeature| usage.get(&(f.server_id.clone(), f.feature_name.clone()));
+
+    let mut ranked: Vec<usize> = (0..tools.len()).collect();
+    ranked.sort_by(|&a, &b| {
+        let (a, b) = (&tools[a], &tools[b]);
+        match (pin_rank(a), pin_rank(b)) {
+            (Some(x), Some(y)) => return x.cmp(&y),
+            (Some(_), None) => return std::cmp::Ordering::Less,
+            (None, Some(_)) => return std::cmp::Ordering::Greater,
+            (None, None) => {}
+        }
+        match server_rank(a).cmp(&server_rank(b)) {
+            std::cmp::Ordering::Equal => {}
+            order => return order,
+        }
+        let (ua, ub) = (usage_of(a), usage_of(b));
+        let count = |u: Option<&ToolUsage>| u.map(|u| u.call_count).unwrap_or(0);
+        let last = |u: Option<&ToolUsage>| u.map(|u| u.last_called_at.clone());
+        count(ub)
+            .cmp(&count(ua))
+            .then_with(|| last(ub).cmp(&last(ua)))
+    });
+
+    let mut keep = vec![false; tools.len()];
+    for index in ranked.into_iter().take(max_tools) {
+        keep[index] = true;
+    }
+
+    tools
+        .into_iter()
+        .zip(keep)
+        .filter_map(|(tool, keep)| keep.then_some(tool))
+        .collect()
+}
+
+/// Estimate the context tokens a tool definition costs a client
+pub fn estimate_tool_tokens(feature: &ServerFeature) -> usize {
+    let bytes = feature
+        .raw_json
+        .as_ref()
+        .and_then(|json| serde_json::to_string(json).ok())
+        .map(|s| s.len())
+        .unwrap_or_else(|| {
+            feature.feature_name.len() + feature.description.as_ref().map_or(0, |d| d.len())
+        });
+    bytes.div_ceil(BYTES_PER_TOKEN)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tool(server: &str, name: &str) -> ServerFeature {
+        ServerFeature::tool("space", server, name)
+    }
+
+    fn usage(server: &str, name: &str, count: u64) -> ((String, String), ToolUsage) {
+        (
+            (server.to_string(), name.to_string()),
+            ToolUsage {
+                server_id: server.to_string(),
+                tool_name: name.to_string(),
+                call_count: count,
+                last_called_at: "2026-01-01T00:00:00Z".to_string(),
+            },
+        )
+    }
+
+    fn names(tools: &[ServerFeature]) -> Vec<String> {
+        tools.iter().map(|t| t.feature_name.clone()).collect()
+    }
+
+    #[test]
+    fn test_select_tools_without_limit() {
+        let tools = vec![tool("a", "one"), tool("a", "two")];
+        let kept = select_tools(tools, &ClientToolBudget::default(), &HashMap::new());
+        assert_eq!(kept.len(), 2);
+    }
+
+    #[test]
+    fn test_select_tools_prefers_pinned_then_usage() {
+        let tools = vec![
+            tool("a", "one"),
+            tool("a", "two"),
+            tool("b", "three"),
+            tool("b", "four"),
+        ];
+        let budget = ClientToolBudget {
+            max_tools: Some(2),
+            pinned_tools: vec![tools[3].portable_key()],
+            ..Default::default()
+        };
+        let usage = HashMap::from([usage("a", "two", 5), usage("b", "three", 2)]);
+
+        let kept = select_tools(tools, &budget, &usage);
+
+        // Pinned "four" plus most-used "two", in original order
+        assert_eq!(names(&kept), vec!["two", "four"]);
+    }
+
+    #[test]
+    fn test_select_tools_pins_survive_renames() {
+        let mut tools = vec![tool("a", "one"), tool("a", "two")];
+        let budget = ClientToolBudget {
+            max_tools: Some(1),
+            pinned_tools: vec![tools[1].portable_key()],
+            ..Default::default()
+        };
+        tools[1].server_alias = Some("renamed".to_string());
+        tools[1].exposed_name = Some("custom".to_string());
+
+        let kept = select_tools(tools, &budget, &HashMap::new());
+        assert_eq!(names(&kept), vec!["two"]);
+    }
+
+    #[test]
+    fn test_select_tools_prefers_prioritized_servers_over_usage() {
+        let tools = vec![
+            tool("a", "one"),
+            tool("b", "two"),
+            tool("c", "three"),
+            tool("c", "four"),
+        ];
+        let budget = ClientToolBudget {
+            max_tools: Some(3),
+            pinned_tools: vec![tools[2].portable_key()],
+            server_priority: vec!["b".to_string()],
+        };
+        let usage = HashMap::from([usage("a", "one", 1), usage("c", "four", 9)]);
+
+        let kept = select_tools(tools, &budget, &usage);
+
+        // Pinned "three", prioritized server "b", then most-used "four"
+        assert_eq!(names(&kept), vec!["two", "three", "four"]);
+    }
+
+    #[test]
+    fn test_estimate_tool_tokens() {
+        let mut feature = tool("a", "one");
+        feature.raw_json =
+            Some(serde_json::json!({ "name": "one", "description": "x".repeat(100) }));
+        let tokens = estimate_tool_tokens(&feature);
+        assert!(tokens > 25 && tokens < 40);
+    }
+}
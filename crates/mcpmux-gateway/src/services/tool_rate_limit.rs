@@ -0,0 +1,87 @@
+//! Tool Rate Limit Service
+//!
+//! Applies per-client rate limits and daily quotas to tool calls. A call
+//! must fit every limit that covers it (the server's and the tool's); it is
+//! only counted when all of them allow it.
+//! Follows SRP: only decides whether a client may make another call.
+
+use chrono::Utc;
+use dashmap::DashMap;
+use mcpmux_core::RateLimitExceeded;
+use mcpmux_storage::RateLimitRepository;
+use std::sync::Arc;
+use tokio::sync::Mutex;
+use tracing::{debug, warn};
+
+/// Service for enforcing tool call rate limits
+pub struct ToolRateLimitService {
+    repo: Arc<RateLimitRepository>,
+    /// Per-client locks serializing check-and-count so concurrent calls
+    /// can't overdraw a bucket
+    locks: DashMap<String, Arc<Mutex<()>>>,
+}
+
+impl ToolRateLimitService {
+    pub fn new(repo: Arc<RateLimitRepository>) -> Self {
+        Self {
+            repo,
+            locks: DashMap::new(),
+        }
+    }
+
+    /// Count a call to `tool_name` on `server_id`, or refuse it.
+    ///
+    /// Storage errors are logged and the call is allowed.
+    pub async fn acquire(
+        &self,
+        client_id: &str,
+        server_id: &str,
+        tool_name: &str,
+    ) -> Result<(), RateLimitExceeded> {
+        // Every limit that can cover this call belongs to the client
+        let lock = self.locks.entry(client_id.to_string()).or_default().clone();
+        let _guard = lock.lock().await;
+
+        let limits = match self.repo.list_for_client(client_id).await {
+            Ok(limits) => limits,
+            Err(e) => {
+                warn!("[RateLimit] Failed to load limits for {}: {}", client_id, e);
+                return Ok(());
+            }
+        };
+
+        let now = Utc::now();
+        let mut updates = Vec::new();
+        let mut refused: Option<RateLimitExceeded> = None;
+        for limit in limits.iter().filter(|l| l.applies_to(server_id, tool_name)) {
+            match limit.try_acquire(now) {
+                Ok(usage) => updates.push((limit, usage)),
+                // Report the limit that takes longest to clear
+                Err(e) => {
+                    if refused
+                        .as_ref()
+                        .is_none_or(|r| e.retry_after_secs > r.retry_after_secs)
+                    {
+                        refused = Some(e);
+                    }
+                }
+            }
+        }
+
+        if let Some(refused) = refused {
+            debug!("[RateLimit] Client {} refused: {}", client_id, refused);
+            return Err(refused);
+        }
+
+        for (limit, usage) in updates {
+            if let Err(e) = self.repo.save_usage(limit, &usage).await {
+                warn!(
+                    "[RateLimit] Failed to save usage for {}: {}",
+                    limit.target(),
+                    e
+                );
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,191 @@
+//! Passphrase encryption for space bundles.
+//!
+//! Bundles leave the machine, so they can't be encrypted with the local
+//! master key. Instead a key is derived from a user passphrase with Argon2id
+//! and the bundle JSON is sealed with [`FieldEncryptor`] (AES-256-GCM). The
+//! KDF parameters and salt are stored alongside the ciphertext.
+
+use anyhow::{anyhow, bail, Context, Result};
+use argon2::{Algorithm, Argon2, Params, Version};
+use mcpmux_core::SpaceBundle;
+use ring::rand::{SecureRandom, SystemRandom};
+use serde::{Deserialize, Serialize};
+use zeroize::Zeroizing;
+
+use crate::crypto::{FieldEncryptor, KEY_SIZE};
+
+/// Identifies a sealed bundle file.
+pub const BUNDLE_FORMAT: &str = "mcpmux-space-bundle";
+
+/// Size of the Argon2 salt.
+const SALT_SIZE: usize = 16;
+
+/// Shortest passphrase accepted when sealing.
+const MIN_PASSPHRASE_LEN: usize = 8;
+
+/// Upper bounds for KDF parameters read from a bundle file.
+///
+/// The parameters come from an untrusted file, so without a cap a crafted
+/// bundle could make opening it allocate gigabytes or spin for minutes.
+const MAX_MEMORY_KIB: u32 = 256 * 1024;
+const MAX_ITERATIONS: u32 = 16;
+const MAX_PARALLELISM: u32 = 16;
+
+/// On-disk form of a sealed bundle.
+#[derive(Serialize, Deserialize)]
+struct SealedBundle {
+    format: String,
+    kdf: KdfParams,
+    /// hex(nonce + ciphertext + tag) of the bundle JSON
+    ciphertext: String,
+}
+
+/// Argon2id parameters used to derive the bundle key.
+#[derive(Serialize, Deserialize)]
+struct KdfParams {
+    algorithm: String,
+    /// Hex-encoded salt
+    salt: String,
+    memory_kib: u32,
+    iterations: u32,
+    parallelism: u32,
+}
+
+fn derive_key(passphrase: &str, kdf: &KdfParams) -> Result<Zeroizing<[u8; KEY_SIZE]>> {
+    if kdf.algorithm != "argon2id" {
+        bail!("Unsupported key derivation: {}", kdf.algorithm);
+    }
+    let salt = hex::decode(&kdf.salt).context("Invalid salt encoding")?;
+    let params = Params::new(
+        kdf.memory_kib,
+        kdf.iterations,
+        kdf.parallelism,
+        Some(KEY_SIZE),
+    )
+    .map_err(|e| anyhow!("Invalid key derivation parameters: {}", e))?;
+
+    let mut key = Zeroizing::new([0u8; KEY_SIZE]);
+    Argon2::new(Algorithm::Argon2id, Version::V0x13, params)
+        .hash_password_into(passphrase.as_bytes(), &salt, key.as_mut())
+        .map_err(|e| anyhow!("Key derivation failed: {}", e))?;
+    Ok(key)
+}
+
+/// Encrypt a bundle with a passphrase.
+///
+/// Returns the file content to write.
+pub fn seal_space_bundle(bundle: &SpaceBundle, passphrase: &str) -> Result<String> {
+    if passphrase.chars().count() < MIN_PASSPHRASE_LEN {
+        bail!(
+            "Passphrase must be at least {} characters",
+            MIN_PASSPHRASE_LEN
+        );
+    }
+
+    let mut salt = [0u8; SALT_SIZE];
+    SystemRandom::new()
+        .fill(&mut salt)
+        .map_err(|_| anyhow!("Failed to generate salt"))?;
+    let defaults = Params::default();
+    let kdf = KdfParams {
+        algorithm: "argon2id".to_string(),
+        salt: hex::encode(salt),
+        memory_kib: defaults.m_cost(),
+        iterations: defaults.t_cost(),
+        parallelism: defaults.p_cost(),
+    };
+
+    let key = derive_key(passphrase, &kdf)?;
+    let plaintext = Zeroizing::new(serde_json::to_string(bundle)?);
+    let ciphertext = FieldEncryptor::new(&key)?.encrypt(&plaintext)?;
+
+    Ok(serde_json::to_string_pretty(&SealedBundle {
+        format: BUNDLE_FORMAT.to_string(),
+        kdf,
+        ciphertext,
+    })?)
+}
+
+/// Reject KDF parameters outside the range this build would ever write.
+fn check_kdf_bounds(kdf: &KdfParams) -> Result<()> {
+    if kdf.memory_kib > MAX_MEMORY_KIB
+        || kdf.iterations > MAX_ITERATIONS
+        || kdf.parallelism > MAX_PARALLELISM
+    {
+        bail!("Bundle key derivation parameters are out of range");
+    }
+    if kdf.salt.len() != SALT_SIZE * 2 {
+        bail!("Invalid salt length");
+    }
+    Ok(())
+}
+
+/// Decrypt a bundle sealed with [`seal_space_bundle`].
+pub fn open_space_bundle(sealed: &str, passphrase: &str) -> Result<SpaceBundle> {
+    let sealed: SealedBundle = serde_json::from_str(sealed).context("Not a McpMux space bundle")?;
+    if sealed.format != BUNDLE_FORMAT {
+        bail!("Not a McpMux space bundle");
+    }
+    check_kdf_bounds(&sealed.kdf)?;
+
+    let key = derive_key(passphrase, &sealed.kdf)?;
+    let plaintext = Zeroizing::new(
+        FieldEncryptor::new(&key)?
+            .decrypt(&sealed.ciphertext)
+            .map_err(|_| anyhow!("Wrong passphrase or corrupted bundle"))?,
+    );
+    serde_json::from_str(&plaintext).context("Invalid bundle contents")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+    use mcpmux_core::{BundledSpace, Space};
+
+    fn test_bundle() -> SpaceBundle {
+        SpaceBundle {
+            version: mcpmux_core::SPACE_BUNDLE_VERSION,
+            exported_at: Utc::now(),
+            spaces: vec![BundledSpace {
+                space: Space::new("Work"),
+                servers: vec![],
+                features: vec![],
+                feature_sets: vec![],
+                oauth: vec![],
+                secrets: vec![],
+            }],
+        }
+    }
+
+    #[test]
+    fn test_seal_open_roundtrip() {
+        let sealed = seal_space_bundle(&test_bundle(), "correct horse battery").unwrap();
+        assert!(!sealed.contains("Work"));
+
+        let opened = open_space_bundle(&sealed, "correct horse battery").unwrap();
+        assert_eq!(opened.spaces[0].space.name, "Work");
+    }
+
+    #[test]
+    fn test_wrong_passphrase_fails() {
+        let sealed = seal_space_bundle(&test_bundle(), "correct horse battery").unwrap();
+        let err = open_space_bundle(&sealed, "wrong horse battery").unwrap_err();
+        assert!(err.to_string().contains("Wrong passphrase"));
+    }
+
+    #[test]
+    fn test_oversized_kdf_params_rejected() {
+        let sealed = seal_space_bundle(&test_bundle(), "correct horse battery").unwrap();
+        let mut value: serde_json::Value = serde_json::from_str(&sealed).unwrap();
+        value["kdf"]["memory_kib"] = serde_json::json!(u32::MAX);
+
+        let err = open_space_bundle(&value.to_string(), "correct horse battery").unwrap_err();
+        assert!(err.to_string().contains("out of range"));
+    }
+
+    #[test]
+    fn test_short_passphrase_rejected() {
+        assert!(seal_space_bundle(&test_bundle(), "short").is_err());
+    }
+}
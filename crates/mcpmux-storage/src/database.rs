@@ -18,8 +18,12 @@
 use anyhow::{Context, Result};
 use rusqlite::Connection;
 use std::path::Path;
+use std::time::Duration;
 use tracing::{debug, info};
 
+/// How long a write waits for another connection's transaction to finish.
+const BUSY_TIMEOUT: Duration = Duration::from_secs(5);
+
 /// A database migration with version number and SQL content.
 struct Migration {
     version: i64,
@@ -29,14 +33,81 @@ struct Migration {
 
 /// All migrations in order. Add new migrations here.
 ///
-/// Note: Migrations have been consolidated into a single clean initial migration.
-/// The schema includes cached_definition for offline operation and excludes
-/// runtime fields (connection_status, last_connected_at, last_error).
-const MIGRATIONS: &[Migration] = &[Migration {
-    version: 1,
-    name: "initial",
-    sql: include_str!("migrations/001_initial.sql"),
-}];
+/// Migration 1 is the consolidated initial schema (with cached_definition for
+/// offline operation and without runtime fields such as connection_status);
+/// each later migration adds one feature's tables and columns.
+const MIGRATIONS: &[Migration] = &[
+    Migration {
+        version: 1,
+        name: "initial",
+        sql: include_str!("migrations/001_initial.sql"),
+    },
+    Migration {
+        version: 2,
+        name: "tool_budget",
+        sql: include_str!("migrations/002_tool_budget.sql"),
+    },
+    Migration {
+        version: 3,
+        name: "meta_tools",
+        sql: include_str!("migrations/003_meta_tools.sql"),
+    },
+    Migration {
+        version: 4,
+        name: "feature_definitions",
+        sql: include_str!("migrations/004_feature_definitions.sql"),
+    },
+    Migration {
+        version: 5,
+        name: "tool_approval",
+        sql: include_str!("migrations/005_tool_approval.sql"),
+    },
+    Migration {
+        version: 6,
+        name: "read_only",
+        sql: include_str!("migrations/006_read_only.sql"),
+    },
+    Migration {
+        version: 7,
+        name: "grant_limits",
+        sql: include_str!("migrations/007_grant_limits.sql"),
+    },
+    Migration {
+        version: 8,
+        name: "rate_limits",
+        sql: include_str!("migrations/008_rate_limits.sql"),
+    },
+    Migration {
+        version: 9,
+        name: "concurrency_limits",
+        sql: include_str!("migrations/009_concurrency_limits.sql"),
+    },
+    Migration {
+        version: 10,
+        name: "argument_policies",
+        sql: include_str!("migrations/010_argument_policies.sql"),
+    },
+    Migration {
+        version: 11,
+        name: "argument_validation",
+        sql: include_str!("migrations/011_argument_validation.sql"),
+    },
+    Migration {
+        version: 12,
+        name: "feature_overrides",
+        sql: include_str!("migrations/012_feature_overrides.sql"),
+    },
+    Migration {
+        version: 13,
+        name: "stdio_working_directory",
+        sql: include_str!("migrations/013_stdio_working_directory.sql"),
+    },
+    Migration {
+        version: 14,
+        name: "space_secrets",
+        sql: include_str!("migrations/014_space_secrets.sql"),
+    },
+];
 
 /// SQLite database wrapper.
 pub struct Database {
@@ -64,6 +135,10 @@ impl Database {
         // Set journal mode to WAL for better concurrency
         conn.pragma_update(None, "journal_mode", "WAL")?;
 
+        // Transactions run on their own connections; wait for them to finish
+        // instead of failing with SQLITE_BUSY
+        conn.busy_timeout(BUSY_TIMEOUT)?;
+
         debug!("Opened database at {:?}", path);
 
         let db = Self { conn };
@@ -51,6 +51,7 @@
 //! let credential_repo = SqliteCredentialRepository::new(db.clone(), encryptor);
 //! ```
 
+mod bundle;
 pub mod crypto;
 mod database;
 pub mod keychain;
@@ -60,6 +61,7 @@ pub mod keychain_dpapi;
 pub mod keychain_file;
 mod repositories;
 
+pub use bundle::{open_space_bundle, seal_space_bundle, BUNDLE_FORMAT};
 pub use crypto::{generate_master_key, FieldEncryptor, KEY_SIZE};
 pub use database::Database;
 pub use keychain::{
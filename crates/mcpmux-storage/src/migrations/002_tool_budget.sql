@@ -0,0 +1,36 @@
+-- Tool budgets and call history
+--
+-- Some clients degrade badly beyond ~40-100 tools. A client tool budget caps
+-- how many tools tools/list returns; tool_usage records calls so the most
+-- used tools are kept when the cap applies.
+--
+-- Pins are portable feature keys (server_id:tool:feature_name), which don't
+-- change with a server alias or a feature override. server_priority orders
+-- whole servers after pinned tools when a budget applies.
+
+-- ============================================================================
+-- CLIENT TOOL BUDGETS (per INBOUND client)
+-- ============================================================================
+
+CREATE TABLE IF NOT EXISTS client_tool_budgets (
+    client_id TEXT PRIMARY KEY,        -- References inbound_clients.client_id
+    max_tools INTEGER,                 -- NULL = unlimited
+    pinned_tools TEXT NOT NULL DEFAULT '[]',  -- JSON array of feature keys (always kept)
+    server_priority TEXT NOT NULL DEFAULT '[]',  -- JSON array of server IDs, highest first
+    updated_at TEXT NOT NULL,
+    FOREIGN KEY (client_id) REFERENCES inbound_clients(client_id) ON DELETE CASCADE
+);
+
+-- ============================================================================
+-- TOOL USAGE (call history aggregated per space + server + tool)
+-- ============================================================================
+
+CREATE TABLE IF NOT EXISTS tool_usage (
+    space_id TEXT NOT NULL,
+    server_id TEXT NOT NULL,
+    tool_name TEXT NOT NULL,           -- Backend tool name (unprefixed)
+    call_count INTEGER NOT NULL DEFAULT 0,
+    last_called_at TEXT NOT NULL,
+    PRIMARY KEY (space_id, server_id, tool_name),
+    FOREIGN KEY (space_id) REFERENCES spaces(id) ON DELETE CASCADE
+);
@@ -0,0 +1,8 @@
+-- Meta-tool mode
+--
+-- Clients with meta_tools enabled see only the gateway's search_tools and
+-- invoke_tool in tools/list and discover backend tools on demand. It is a
+-- client setting: it decides what tools/list returns, not how many tools a
+-- budget keeps.
+
+ALTER TABLE inbound_clients ADD COLUMN meta_tools INTEGER NOT NULL DEFAULT 0;
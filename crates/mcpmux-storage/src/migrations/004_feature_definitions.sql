@@ -0,0 +1,25 @@
+-- Feature definition tracking ("rug pull" detection)
+--
+-- Servers can change a tool's description or schema at any time, e.g. to
+-- inject instructions. Each feature stores the hash of its approved
+-- definition; a changed definition is held in pending_raw_json (the feature
+-- is quarantined) until the user approves it. Every definition seen is kept
+-- in server_feature_versions.
+
+ALTER TABLE server_features ADD COLUMN definition_hash TEXT;      -- Hash of the approved raw_json
+ALTER TABLE server_features ADD COLUMN pending_raw_json TEXT;     -- Changed definition awaiting approval
+
+-- ============================================================================
+-- SERVER FEATURE VERSIONS (definition history)
+-- ============================================================================
+
+CREATE TABLE IF NOT EXISTS server_feature_versions (
+    feature_id TEXT NOT NULL,          -- References server_features.id
+    definition_hash TEXT NOT NULL,
+    raw_json TEXT,
+    approved INTEGER NOT NULL DEFAULT 0,
+    recorded_at TEXT NOT NULL,
+    approved_at TEXT,
+    PRIMARY KEY (feature_id, definition_hash),
+    FOREIGN KEY (feature_id) REFERENCES server_features(id) ON DELETE CASCADE
+);
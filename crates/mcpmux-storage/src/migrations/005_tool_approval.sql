@@ -0,0 +1,16 @@
+-- Tool call approval policies
+--
+-- A policy holds tool calls for human approval ("ask") or refuses them
+-- ("deny"). Rules target a single tool (server feature ID) or every tool in
+-- a feature set. Tools without a rule fall back to the destructive-tool
+-- default in app settings.
+
+CREATE TABLE IF NOT EXISTS tool_approval_policies (
+    space_id TEXT NOT NULL,
+    target_type TEXT NOT NULL,         -- 'tool' | 'feature_set'
+    target TEXT NOT NULL,              -- server_features.id or feature_sets.id
+    policy TEXT NOT NULL,              -- 'allow' | 'ask' | 'deny'
+    updated_at TEXT NOT NULL,
+    PRIMARY KEY (space_id, target_type, target),
+    FOREIGN KEY (space_id) REFERENCES spaces(id) ON DELETE CASCADE
+);
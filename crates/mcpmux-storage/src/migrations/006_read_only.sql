@@ -0,0 +1,10 @@
+-- Read-only mode
+--
+-- A read-only feature set or space only admits tools the server marks with
+-- readOnlyHint (prompts and resources are always admitted). Installed
+-- servers whose definition declares read_only_mode can be launched with the
+-- definition's read-only arguments and environment.
+
+ALTER TABLE spaces ADD COLUMN read_only INTEGER NOT NULL DEFAULT 0;
+ALTER TABLE feature_sets ADD COLUMN read_only INTEGER NOT NULL DEFAULT 0;
+ALTER TABLE installed_servers ADD COLUMN read_only INTEGER NOT NULL DEFAULT 0;
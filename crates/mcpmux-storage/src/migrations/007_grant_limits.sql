@@ -0,0 +1,9 @@
+-- Time-bound and usage-limited grants
+--
+-- A grant may expire at a point in time (expires_at, RFC 3339 UTC) or after
+-- a number of tool calls (max_calls). Lapsed grants are ignored when
+-- resolving a client's grants and removed by the gateway's expiry task.
+
+ALTER TABLE client_grants ADD COLUMN expires_at TEXT;
+ALTER TABLE client_grants ADD COLUMN max_calls INTEGER;
+ALTER TABLE client_grants ADD COLUMN call_count INTEGER NOT NULL DEFAULT 0;
@@ -0,0 +1,21 @@
+-- Tool call rate limits and daily quotas
+--
+-- Limits a client's calls to a server (tool_name = '') or to one tool with a
+-- token bucket (per_minute refill, burst size) and/or a per-UTC-day quota.
+-- Usage counters live on the same row so limits survive restarts.
+
+CREATE TABLE IF NOT EXISTS client_rate_limits (
+    client_id TEXT NOT NULL,           -- References inbound_clients.client_id
+    server_id TEXT NOT NULL,
+    tool_name TEXT NOT NULL DEFAULT '', -- Backend tool name, '' = every tool on the server
+    per_minute INTEGER,                -- Token bucket refill rate (NULL = no rate limit)
+    burst INTEGER,                     -- Bucket size (NULL = per_minute)
+    daily_quota INTEGER,               -- Calls per UTC day (NULL = unlimited)
+    tokens REAL,                       -- Bucket level at usage_updated_at (NULL = not used yet)
+    usage_updated_at TEXT,
+    quota_day TEXT,                    -- UTC date quota_used counts towards
+    quota_used INTEGER NOT NULL DEFAULT 0,
+    updated_at TEXT NOT NULL,
+    PRIMARY KEY (client_id, server_id, tool_name),
+    FOREIGN KEY (client_id) REFERENCES inbound_clients(client_id) ON DELETE CASCADE
+);
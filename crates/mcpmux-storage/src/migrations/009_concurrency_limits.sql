@@ -0,0 +1,6 @@
+-- Per-server concurrency limits
+--
+-- JSON ConcurrencyLimit (max_concurrent_requests, max_queued_requests,
+-- queue_timeout_secs); NULL sends every request to the server right away.
+
+ALTER TABLE installed_servers ADD COLUMN concurrency_limit TEXT;
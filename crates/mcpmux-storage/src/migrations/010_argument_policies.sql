@@ -0,0 +1,14 @@
+-- Tool argument policies
+--
+-- A feature set can pin, default, restrict or forbid the arguments of a tool
+-- it grants. The policy is a JSON ArgumentPolicy (fixed, defaults,
+-- allowed_values, forbidden).
+
+CREATE TABLE IF NOT EXISTS tool_argument_policies (
+    feature_set_id TEXT NOT NULL,      -- References feature_sets.id
+    feature_id TEXT NOT NULL,          -- References server_features.id
+    policy TEXT NOT NULL,              -- JSON ArgumentPolicy
+    updated_at TEXT NOT NULL,
+    PRIMARY KEY (feature_set_id, feature_id),
+    FOREIGN KEY (feature_set_id) REFERENCES feature_sets(id) ON DELETE CASCADE
+);
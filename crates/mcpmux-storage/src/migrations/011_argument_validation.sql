@@ -0,0 +1,6 @@
+-- Tool argument validation
+--
+-- Tool call arguments are checked against the tool's inputSchema before
+-- dispatch; servers whose schemas are known to be wrong can opt out.
+
+ALTER TABLE installed_servers ADD COLUMN skip_argument_validation INTEGER NOT NULL DEFAULT 0;
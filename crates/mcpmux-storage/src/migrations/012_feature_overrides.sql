@@ -0,0 +1,29 @@
+-- Feature overrides
+--
+-- Exposed name, title and description for a tool or prompt, for a whole
+-- space or for clients granted one feature set. Keyed by server and feature
+-- name (not server_features.id) so overrides survive rediscovery;
+-- definition_hash is the feature's hash when the override was saved. An
+-- override's name routes calls back to one feature, so it is unique in the
+-- space.
+
+CREATE TABLE IF NOT EXISTS feature_overrides (
+    space_id TEXT NOT NULL,
+    feature_set_id TEXT,               -- NULL = whole space
+    server_id TEXT NOT NULL,
+    feature_type TEXT NOT NULL,        -- 'tool' or 'prompt'
+    feature_name TEXT NOT NULL,
+    name TEXT,
+    title TEXT,
+    description TEXT,
+    definition_hash TEXT,
+    updated_at TEXT NOT NULL,
+    FOREIGN KEY (space_id) REFERENCES spaces(id) ON DELETE CASCADE,
+    FOREIGN KEY (feature_set_id) REFERENCES feature_sets(id) ON DELETE CASCADE
+);
+
+CREATE UNIQUE INDEX IF NOT EXISTS idx_feature_overrides_target
+    ON feature_overrides(space_id, COALESCE(feature_set_id, ''), server_id, feature_type, feature_name);
+
+CREATE UNIQUE INDEX IF NOT EXISTS idx_feature_overrides_name
+    ON feature_overrides(space_id, name) WHERE name IS NOT NULL;
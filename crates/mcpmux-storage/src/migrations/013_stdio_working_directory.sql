@@ -0,0 +1,7 @@
+-- Stdio working directory and .env file
+--
+-- Per-installation overrides for the directory a stdio server is launched
+-- from and a dotenv file loaded into its environment.
+
+ALTER TABLE installed_servers ADD COLUMN cwd_override TEXT;
+ALTER TABLE installed_servers ADD COLUMN env_file_override TEXT;
@@ -0,0 +1,14 @@
+-- Space secrets
+--
+-- Named secrets shared by the servers of a space. Input values reference
+-- them as ${secret:name}; only the value is encrypted (AES-256-GCM).
+
+CREATE TABLE IF NOT EXISTS space_secrets (
+    space_id TEXT NOT NULL,
+    name TEXT NOT NULL,
+    secret_value TEXT NOT NULL,
+    created_at TEXT NOT NULL,
+    updated_at TEXT NOT NULL,
+    PRIMARY KEY (space_id, name),
+    FOREIGN KEY (space_id) REFERENCES spaces(id) ON DELETE CASCADE
+);
@@ -0,0 +1,109 @@
+//! Tool argument policy repository
+//!
+//! Per-feature-set policies that pin, default, restrict or forbid tool
+//! arguments. Used by the gateway when listing and calling tools.
+
+use std::sync::Arc;
+
+use anyhow::Result;
+use chrono::Utc;
+use mcpmux_core::{ArgumentPolicy, ToolArgumentPolicy};
+use rusqlite::{params, params_from_iter};
+use tokio::sync::Mutex;
+use tracing::warn;
+
+use crate::Database;
+
+/// Tool argument policy repository with database persistence
+pub struct ArgumentPolicyRepository {
+    db: Arc<Mutex<Database>>,
+}
+
+impl ArgumentPolicyRepository {
+    /// Create a new argument policy repository with a database
+    pub fn new(db: Arc<Mutex<Database>>) -> Self {
+        Self { db }
+    }
+
+    /// List the policies of the given feature sets, ordered by feature set ID
+    pub async fn list_for_feature_sets(
+        &self,
+        feature_set_ids: &[String],
+    ) -> Result<Vec<ToolArgumentPolicy>> {
+        if feature_set_ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let db = self.db.lock().await;
+        let conn = db.connection();
+
+        let placeholders = vec!["?"; feature_set_ids.len()].join(", ");
+        let mut stmt = conn.prepare(&format!(
+            "SELECT feature_set_id, feature_id, policy
+             FROM tool_argument_policies WHERE feature_set_id IN ({})
+             ORDER BY feature_set_id, feature_id",
+            placeholders
+        ))?;
+
+        let rows = stmt.query_map(params_from_iter(feature_set_ids), |row| {
+            Ok((
+                row.get::<_, String>(0)?,
+                row.get::<_, String>(1)?,
+                row.get::<_, String>(2)?,
+            ))
+        })?;
+
+        let mut policies = Vec::new();
+        for row in rows {
+            let (feature_set_id, feature_id, policy) = row?;
+            match serde_json::from_str::<ArgumentPolicy>(&policy) {
+                Ok(policy) => policies.push(ToolArgumentPolicy {
+                    feature_set_id,
+                    feature_id,
+                    policy,
+                }),
+                Err(e) => warn!(
+                    "[ArgumentPolicyRepository] Skipping invalid policy for {}/{}: {}",
+                    feature_set_id, feature_id, e
+                ),
+            }
+        }
+
+        Ok(policies)
+    }
+
+    /// Create or replace a policy
+    pub async fn set(&self, policy: &ToolArgumentPolicy) -> Result<()> {
+        let db = self.db.lock().await;
+        let conn = db.connection();
+
+        conn.execute(
+            "INSERT INTO tool_argument_policies (feature_set_id, feature_id, policy, updated_at)
+             VALUES (?1, ?2, ?3, ?4)
+             ON CONFLICT(feature_set_id, feature_id) DO UPDATE SET
+                policy = excluded.policy,
+                updated_at = excluded.updated_at",
+            params![
+                policy.feature_set_id,
+                policy.feature_id,
+                serde_json::to_string(&policy.policy)?,
+                Utc::now().to_rfc3339()
+            ],
+        )?;
+
+        Ok(())
+    }
+
+    /// Remove a policy
+    pub async fn delete(&self, feature_set_id: &str, feature_id: &str) -> Result<()> {
+        let db = self.db.lock().await;
+        let conn = db.connection();
+
+        conn.execute(
+            "DELETE FROM tool_argument_policies WHERE feature_set_id = ?1 AND feature_id = ?2",
+            params![feature_set_id, feature_id],
+        )?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,139 @@
+//! Feature override repository
+//!
+//! Exposed names, titles and descriptions for tools and prompts, per space or
+//! per feature set. Used by the gateway when listing and routing features.
+
+use std::sync::Arc;
+
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use mcpmux_core::{FeatureOverride, FeatureType};
+use rusqlite::params;
+use tokio::sync::Mutex;
+use tracing::warn;
+
+use crate::Database;
+
+/// Feature override repository with database persistence
+pub struct FeatureOverrideRepository {
+    db: Arc<Mutex<Database>>,
+}
+
+impl FeatureOverrideRepository {
+    /// Create a new feature override repository with a database
+    pub fn new(db: Arc<Mutex<Database>>) -> Self {
+        Self { db }
+    }
+
+    /// List a space's overrides (space-wide and per feature set)
+    pub async fn list_for_space(&self, space_id: &str) -> Result<Vec<FeatureOverride>> {
+        let db = self.db.lock().await;
+        let conn = db.connection();
+
+        let mut stmt = conn.prepare(
+            "SELECT space_id, feature_set_id, server_id, feature_type, feature_name,
+                    name, title, description, definition_hash, updated_at
+             FROM feature_overrides WHERE space_id = ?1
+             ORDER BY server_id, feature_name, feature_set_id",
+        )?;
+
+        let rows = stmt.query_map(params![space_id], |row| {
+            Ok((
+                FeatureOverride {
+                    space_id: row.get(0)?,
+                    feature_set_id: row.get(1)?,
+                    server_id: row.get(2)?,
+                    feature_type: FeatureType::Tool,
+                    feature_name: row.get(4)?,
+                    name: row.get(5)?,
+                    title: row.get(6)?,
+                    description: row.get(7)?,
+                    definition_hash: row.get(8)?,
+                    updated_at: DateTime::parse_from_rfc3339(&row.get::<_, String>(9)?)
+                        .map(|dt| dt.with_timezone(&Utc))
+                        .unwrap_or_else(|_| Utc::now()),
+                },
+                row.get::<_, String>(3)?,
+            ))
+        })?;
+
+        let mut overrides = Vec::new();
+        for row in rows {
+            let (mut feature_override, feature_type) = row?;
+            match FeatureType::parse(&feature_type) {
+                Some(feature_type) => {
+                    feature_override.feature_type = feature_type;
+                    overrides.push(feature_override);
+                }
+                None => warn!(
+                    "[FeatureOverrideRepository] Skipping override with unknown type '{}'",
+                    feature_type
+                ),
+            }
+        }
+
+        Ok(overrides)
+    }
+
+    /// Create or replace the override for a feature (and feature set, if any)
+    pub async fn set(&self, feature_override: &FeatureOverride) -> Result<()> {
+        let db = self.db.lock().await;
+        let conn = db.connection();
+
+        conn.execute(
+            "INSERT INTO feature_overrides
+             (space_id, feature_set_id, server_id, feature_type, feature_name,
+              name, title, description, definition_hash, updated_at)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10)
+             ON CONFLICT(space_id, COALESCE(feature_set_id, ''), server_id, feature_type, feature_name)
+             DO UPDATE SET
+                name = excluded.name,
+                title = excluded.title,
+                description = excluded.description,
+                definition_hash = excluded.definition_hash,
+                updated_at = excluded.updated_at",
+            params![
+                feature_override.space_id,
+                feature_override.feature_set_id,
+                feature_override.server_id,
+                feature_override.feature_type.as_str(),
+                feature_override.feature_name,
+                feature_override.name,
+                feature_override.title,
+                feature_override.description,
+                feature_override.definition_hash,
+                feature_override.updated_at.to_rfc3339()
+            ],
+        )?;
+
+        Ok(())
+    }
+
+    /// Remove the override for a feature (and feature set, if any)
+    pub async fn delete(
+        &self,
+        space_id: &str,
+        feature_set_id: Option<&str>,
+        server_id: &str,
+        feature_type: &FeatureType,
+        feature_name: &str,
+    ) -> Result<()> {
+        let db = self.db.lock().await;
+        let conn = db.connection();
+
+        conn.execute(
+            "DELETE FROM feature_overrides
+             WHERE space_id = ?1 AND feature_set_id IS ?2
+               AND server_id = ?3 AND feature_type = ?4 AND feature_name = ?5",
+            params![
+                space_id,
+                feature_set_id,
+                server_id,
+                feature_type.as_str(),
+                feature_name
+            ],
+        )?;
+
+        Ok(())
+    }
+}
@@ -54,6 +54,7 @@ impl SqliteFeatureSetRepository {
             is_deleted: row.get::<_, i32>(8)? == 1,
             created_at: Self::parse_datetime(&row.get::<_, String>(9)?),
             updated_at: Self::parse_datetime(&row.get::<_, String>(10)?),
+            read_only: row.get::<_, i32>(11)? == 1,
             members: vec![], // Members loaded separately
         })
     }
@@ -117,7 +118,7 @@ impl FeatureSetRepository for SqliteFeatureSetRepository {
 
         let mut stmt = conn.prepare(
             "SELECT id, name, description, icon, space_id, feature_set_type, 
-                    server_id, is_builtin, is_deleted, created_at, updated_at 
+                    server_id, is_builtin, is_deleted, created_at, updated_at, read_only
              FROM feature_sets 
              WHERE is_deleted = 0
              ORDER BY is_builtin DESC, name ASC",
@@ -136,7 +137,7 @@ impl FeatureSetRepository for SqliteFeatureSetRepository {
 
         let mut stmt = conn.prepare(
             "SELECT id, name, description, icon, space_id, feature_set_type, 
-                    server_id, is_builtin, is_deleted, created_at, updated_at 
+                    server_id, is_builtin, is_deleted, created_at, updated_at, read_only
              FROM feature_sets 
              WHERE space_id = ? AND is_deleted = 0
              ORDER BY is_builtin DESC, feature_set_type, name ASC",
@@ -154,6 +155,29 @@ impl FeatureSetRepository for SqliteFeatureSetRepository {
         Ok(feature_sets)
     }
 
+    async fn list_global(&self) -> Result<Vec<FeatureSet>> {
+        let db = self.db.lock().await;
+        let conn = db.connection();
+
+        let mut stmt = conn.prepare(
+            "SELECT id, name, description, icon, space_id, feature_set_type, 
+                    server_id, is_builtin, is_deleted, created_at, updated_at, read_only
+             FROM feature_sets 
+             WHERE space_id IS NULL AND is_deleted = 0
+             ORDER BY name ASC",
+        )?;
+
+        let mut feature_sets = stmt
+            .query_map([], Self::row_to_feature_set)?
+            .collect::<Result<Vec<_>, _>>()?;
+
+        for fs in &mut feature_sets {
+            fs.members = Self::get_members_sync(conn, &fs.id)?;
+        }
+
+        Ok(feature_sets)
+    }
+
     async fn get(&self, id: &str) -> Result<Option<FeatureSet>> {
         let db = self.db.lock().await;
         let conn = db.connection();
@@ -161,7 +185,7 @@ impl FeatureSetRepository for SqliteFeatureSetRepository {
         let result = conn
             .query_row(
                 "SELECT id, name, description, icon, space_id, feature_set_type, 
-                        server_id, is_builtin, is_deleted, created_at, updated_at 
+                        server_id, is_builtin, is_deleted, created_at, updated_at, read_only
                  FROM feature_sets 
                  WHERE id = ? AND is_deleted = 0",
                 params![id],
@@ -189,8 +213,8 @@ impl FeatureSetRepository for SqliteFeatureSetRepository {
         conn.execute(
             "INSERT INTO feature_sets 
                 (id, name, description, icon, space_id, feature_set_type, 
-                 server_id, is_builtin, is_deleted, created_at, updated_at)
-             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11)",
+                 server_id, is_builtin, is_deleted, created_at, updated_at, read_only)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12)",
             params![
                 feature_set.id,
                 feature_set.name,
@@ -203,6 +227,7 @@ impl FeatureSetRepository for SqliteFeatureSetRepository {
                 if feature_set.is_deleted { 1 } else { 0 },
                 feature_set.created_at.to_rfc3339(),
                 feature_set.updated_at.to_rfc3339(),
+                if feature_set.read_only { 1 } else { 0 },
             ],
         )?;
 
@@ -232,7 +257,7 @@ impl FeatureSetRepository for SqliteFeatureSetRepository {
 
         let rows_affected = conn.execute(
             "UPDATE feature_sets 
-             SET name = ?2, description = ?3, icon = ?4, updated_at = ?5
+             SET name = ?2, description = ?3, icon = ?4, updated_at = ?5, read_only = ?6
              WHERE id = ?1 AND is_deleted = 0",
             params![
                 feature_set.id,
@@ -240,6 +265,7 @@ impl FeatureSetRepository for SqliteFeatureSetRepository {
                 feature_set.description,
                 feature_set.icon,
                 feature_set.updated_at.to_rfc3339(),
+                if feature_set.read_only { 1 } else { 0 },
             ],
         )?;
 
@@ -304,7 +330,7 @@ impl FeatureSetRepository for SqliteFeatureSetRepository {
 
         let mut stmt = conn.prepare(
             "SELECT id, name, description, icon, space_id, feature_set_type, 
-                    server_id, is_builtin, is_deleted, created_at, updated_at 
+                    server_id, is_builtin, is_deleted, created_at, updated_at, read_only
              FROM feature_sets 
              WHERE space_id = ? AND is_builtin = 1 AND is_deleted = 0
              ORDER BY feature_set_type, name ASC",
@@ -324,7 +350,7 @@ impl FeatureSetRepository for SqliteFeatureSetRepository {
         let result = conn
             .query_row(
                 "SELECT id, name, description, icon, space_id, feature_set_type, 
-                        server_id, is_builtin, is_deleted, created_at, updated_at 
+                        server_id, is_builtin, is_deleted, created_at, updated_at, read_only
                  FROM feature_sets 
                  WHERE space_id = ? AND server_id = ? AND feature_set_type = 'server-all' AND is_deleted = 0",
                 params![space_id, server_id],
@@ -359,7 +385,7 @@ impl FeatureSetRepository for SqliteFeatureSetRepository {
         let result = conn
             .query_row(
                 "SELECT id, name, description, icon, space_id, feature_set_type, 
-                        server_id, is_builtin, is_deleted, created_at, updated_at 
+                        server_id, is_builtin, is_deleted, created_at, updated_at, read_only
                  FROM feature_sets 
                  WHERE space_id = ? AND feature_set_type = 'default' AND is_deleted = 0",
                 params![space_id],
@@ -377,7 +403,7 @@ impl FeatureSetRepository for SqliteFeatureSetRepository {
         let result = conn
             .query_row(
                 "SELECT id, name, description, icon, space_id, feature_set_type, 
-                        server_id, is_builtin, is_deleted, created_at, updated_at 
+                        server_id, is_builtin, is_deleted, created_at, updated_at, read_only
                  FROM feature_sets 
                  WHERE space_id = ? AND feature_set_type = 'all' AND is_deleted = 0",
                 params![space_id],
@@ -558,4 +584,27 @@ mod tests {
             .unwrap();
         assert_eq!(fs.id, fs2.id);
     }
+
+    #[tokio::test]
+    async fn test_global_feature_sets() {
+        let db = Arc::new(Mutex::new(Database::open_in_memory().unwrap()));
+        let repo = SqliteFeatureSetRepository::new(db);
+
+        let mut global = FeatureSet::new_global("No deletes");
+        global.members = vec![FeatureSetMember::rule(
+            &global.id,
+            "name:delete_*",
+            MemberMode::Exclude,
+        )];
+        repo.create(&global).await.unwrap();
+
+        let listed = repo.list_global().await.unwrap();
+        assert_eq!(listed.len(), 1);
+        assert!(listed[0].is_global());
+        assert_eq!(listed[0].members.len(), 1);
+
+        // Global sets are not listed as part of any space
+        let space_sets = repo.list_by_space(DEFAULT_SPACE_ID).await.unwrap();
+        assert!(space_sets.iter().all(|fs| fs.id != global.id));
+    }
 }
@@ -11,7 +11,8 @@
 //! 3. Pre-registration - server pre-configures client_id
 
 use anyhow::Result;
-use rusqlite::params;
+use chrono::{DateTime, SecondsFormat, Utc};
+use rusqlite::{named_params, params, OptionalExtension};
 use serde::{Deserialize, Serialize};
 use sha2::{Digest, Sha256};
 use std::sync::Arc;
@@ -90,11 +91,89 @@ pub struct InboundClient {
     // MCP client preferences
     pub connection_mode: String, // 'follow_active', 'locked', 'ask_on_change'
     pub locked_space_id: Option<String>,
+    /// List only the gateway's search_tools/invoke_tool meta-tools.
+    /// Only changed through `update_client_settings`.
+    #[serde(default)]
+    pub meta_tools: bool,
     pub last_seen: Option<String>,
     pub created_at: String,
     pub updated_at: String,
 }
 
+/// Per-client cap on the number of tools returned by tools/list
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ClientToolBudget {
+    /// Maximum number of tools (None = unlimited)
+    pub max_tools: Option<u32>,
+    /// Portable keys of tools that are always kept (in priority order)
+    pub pinned_tools: Vec<String>,
+    /// Server IDs whose tools are kept next (in priority order)
+    #[serde(default)]
+    pub server_priority: Vec<String>,
+}
+
+/// Optional limits on a client grant
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GrantLimits {
+    /// When the grant lapses (None = never)
+    pub expires_at: Option<DateTime<Utc>>,
+    /// Tool calls after which the grant lapses (None = unlimited)
+    pub max_calls: Option<u32>,
+}
+
+impl GrantLimits {
+    /// Whether any limit is set
+    pub fn is_limited(&self) -> bool {
+        self.expires_at.is_some() || self.max_calls.is_some()
+    }
+}
+
+/// A feature set granted to a client in a space, with its limits
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ClientGrant {
+    pub client_id: String,
+    pub space_id: String,
+    pub feature_set_id: String,
+    pub expires_at: Option<DateTime<Utc>>,
+    pub max_calls: Option<u32>,
+    /// Tool calls counted against `max_calls`
+    pub call_count: u32,
+}
+
+impl ClientGrant {
+    /// Whether the grant has expired or used up its calls
+    pub fn is_lapsed(&self, now: DateTime<Utc>) -> bool {
+        self.expires_at.is_some_and(|at| at <= now)
+            || self.max_calls.is_some_and(|max| self.call_count >= max)
+    }
+}
+
+/// Grant expiry timestamps are stored with a fixed format so they compare as text
+pub(super) fn format_grant_time(at: DateTime<Utc>) -> String {
+    at.to_rfc3339_opts(SecondsFormat::Secs, true)
+}
+
+/// SQL condition selecting grants that are still in effect (`:now` is the current time)
+pub(super) const ACTIVE_GRANT: &str = "(expires_at IS NULL OR expires_at > :now)
+             AND (max_calls IS NULL OR call_count < max_calls)";
+
+const GRANT_COLUMNS: &str =
+    "client_id, space_id, feature_set_id, expires_at, max_calls, call_count";
+
+fn grant_from_row(row: &rusqlite::Row<'_>) -> rusqlite::Result<ClientGrant> {
+    let expires_at: Option<String> = row.get(3)?;
+    Ok(ClientGrant {
+        client_id: row.get(0)?,
+        space_id: row.get(1)?,
+        feature_set_id: row.get(2)?,
+        expires_at: expires_at
+            .and_then(|s| DateTime::parse_from_rfc3339(&s).ok())
+            .map(|dt| dt.with_timezone(&Utc)),
+        max_calls: row.get(4)?,
+        call_count: row.get(5)?,
+    })
+}
+
 /// Authorization code (pending exchange)
 #[derive(Debug, Clone)]
 pub struct AuthorizationCode {
@@ -168,13 +247,15 @@ impl InboundClientRepository {
     /// 4: logo_uri, 5: client_uri, 6: software_id, 7: software_version,
     /// 8: redirect_uris, 9: grant_types, 10: response_types, 11: token_endpoint_auth_method, 12: scope,
     /// 13: metadata_url, 14: metadata_cached_at, 15: metadata_cache_ttl,
-    /// 16: connection_mode, 17: locked_space_id, 18: last_seen, 19: created_at, 20: updated_at, 21: approved
+    /// 16: connection_mode, 17: locked_space_id, 18: last_seen, 19: created_at, 20: updated_at, 21: approved,
+    /// 22: meta_tools
     fn map_row_to_client(row: &rusqlite::Row) -> rusqlite::Result<InboundClient> {
         let registration_type_str: String = row.get(1)?;
         let redirect_uris_json: Option<String> = row.get(8)?;
         let grant_types_json: Option<String> = row.get(9)?;
         let response_types_json: Option<String> = row.get(10)?;
         let approved_int: i32 = row.get::<_, Option<i32>>(21)?.unwrap_or(0);
+        let meta_tools_int: i32 = row.get(22)?;
 
         Ok(InboundClient {
             client_id: row.get(0)?,
@@ -206,6 +287,7 @@ impl InboundClientRepository {
                 .get::<_, Option<String>>(16)?
                 .unwrap_or_else(|| "follow_active".to_string()),
             locked_space_id: row.get(17)?,
+            meta_tools: meta_tools_int != 0,
             last_seen: row.get(18)?,
             created_at: row.get(19)?,
             updated_at: row.get(20)?,
@@ -218,13 +300,16 @@ impl InboundClientRepository {
          logo_uri, client_uri, software_id, software_version,
          redirect_uris, grant_types, response_types, token_endpoint_auth_method, scope,
          metadata_url, metadata_cached_at, metadata_cache_ttl,
-         connection_mode, locked_space_id, last_seen, created_at, updated_at, approved";
+         connection_mode, locked_space_id, last_seen, created_at, updated_at, approved, meta_tools";
 
     // =========================================================================
     // Client Operations (unified inbound_clients table)
     // =========================================================================
 
     /// Register or update an inbound client (supports CIMD, DCR, pre-registered)
+    ///
+    /// `meta_tools` is only written for new clients, so re-registration and
+    /// metadata refreshes keep the user's setting.
     pub async fn save_client(&self, client: &InboundClient) -> Result<()> {
         let db = self.db.lock().await;
         let conn = db.connection();
@@ -235,9 +320,9 @@ impl InboundClientRepository {
                 redirect_uris, grant_types, response_types, token_endpoint_auth_method, scope,
                 metadata_url, metadata_cached_at, metadata_cache_ttl,
                 connection_mode, locked_space_id,
-                last_seen, created_at, updated_at, approved
+                last_seen, created_at, updated_at, approved, meta_tools
              )
-             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16, ?17, ?18, ?19, ?20, ?21, ?22)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16, ?17, ?18, ?19, ?20, ?21, ?22, ?23)
              ON CONFLICT(client_id) DO UPDATE SET
                 registration_type = ?2, client_name = ?3, client_alias = ?4,
                 logo_uri = ?5, client_uri = ?6, software_id = ?7, software_version = ?8,
@@ -269,6 +354,7 @@ impl InboundClientRepository {
                 client.created_at,
                 client.updated_at,
                 client.approved as i32,
+                client.meta_tools as i32,
             ],
         )?;
         debug!(
@@ -427,6 +513,7 @@ impl InboundClientRepository {
         client_alias: Option<String>,
         connection_mode: Option<String>,
         locked_space_id: Option<Option<String>>, // None = don't change, Some(None) = clear, Some(Some(x)) = set
+        meta_tools: Option<bool>,
     ) -> Result<Option<InboundClient>> {
         let now = chrono::Utc::now().format("%Y-%m-%dT%H:%M:%SZ").to_string();
 
@@ -470,6 +557,16 @@ impl InboundClientRepository {
             )?;
         }
 
+        // Update meta-tool mode if provided
+        if let Some(meta_tools) = meta_tools {
+            let db = self.db.lock().await;
+            let conn = db.connection();
+            conn.execute(
+                "UPDATE inbound_clients SET meta_tools = ?1 WHERE client_id = ?2",
+                params![meta_tools as i32, client_id],
+            )?;
+        }
+
         debug!("[OAuth] Updated settings for client: {}", client_id);
 
         // Return updated client
@@ -753,6 +850,38 @@ impl InboundClientRepository {
         Ok(())
     }
 
+    /// Grant a feature set with an expiry and/or call limit.
+    ///
+    /// Replaces the limits of an existing grant and resets its call count.
+    pub async fn grant_feature_set_with_limits(
+        &self,
+        client_id: &str,
+        space_id: &str,
+        feature_set_id: &str,
+        limits: &GrantLimits,
+    ) -> Result<()> {
+        let db = self.db.lock().await;
+        let conn = db.connection();
+
+        conn.execute(
+            "INSERT INTO client_grants (client_id, space_id, feature_set_id, expires_at, max_calls, call_count)
+             VALUES (?1, ?2, ?3, ?4, ?5, 0)
+             ON CONFLICT(client_id, space_id, feature_set_id) DO UPDATE SET
+                expires_at = excluded.expires_at,
+                max_calls = excluded.max_calls,
+                call_count = 0",
+            params![
+                client_id,
+                space_id,
+                feature_set_id,
+                limits.expires_at.map(format_grant_time),
+                limits.max_calls
+            ],
+        )?;
+
+        Ok(())
+    }
+
     /// Revoke a feature set from a client in a specific space
     pub async fn revoke_feature_set(
         &self,
@@ -781,18 +910,105 @@ impl InboundClientRepository {
         let db = self.db.lock().await;
         let conn = db.connection();
 
-        let mut stmt = conn.prepare(
+        let mut stmt = conn.prepare(&format!(
             "SELECT feature_set_id FROM client_grants 
-             WHERE client_id = ?1 AND space_id = ?2",
-        )?;
+             WHERE client_id = :client AND space_id = :space AND {ACTIVE_GRANT}"
+        ))?;
+
+        let grants = stmt
+            .query_map(
+                named_params! {
+                    ":client": client_id,
+                    ":space": space_id,
+                    ":now": format_grant_time(Utc::now()),
+                },
+                |row| row.get::<_, String>(0),
+            )?
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+
+        Ok(grants)
+    }
+
+    /// Get a client's grants in a space with their limits (including lapsed ones)
+    pub async fn list_grants_for_space(
+        &self,
+        client_id: &str,
+        space_id: &str,
+    ) -> Result<Vec<ClientGrant>> {
+        let db = self.db.lock().await;
+        let conn = db.connection();
 
+        let mut stmt = conn.prepare(&format!(
+            "SELECT {GRANT_COLUMNS} FROM client_grants WHERE client_id = ?1 AND space_id = ?2"
+        ))?;
         let grants = stmt
-            .query_map(params![client_id, space_id], |row| row.get::<_, String>(0))?
+            .query_map(params![client_id, space_id], grant_from_row)?
             .collect::<std::result::Result<Vec<_>, _>>()?;
 
         Ok(grants)
     }
 
+    /// Reserve one tool call against each of the given call-limited grants.
+    ///
+    /// The count is taken atomically, so a grant never admits more than
+    /// `max_calls` calls however many run at once. Returns the grants that
+    /// admitted the call; a grant that is missing had no calls left.
+    pub async fn reserve_grant_calls(
+        &self,
+        client_id: &str,
+        space_id: &str,
+        feature_set_ids: &[String],
+    ) -> Result<Vec<ClientGrant>> {
+        let db = self.db.lock().await;
+        let conn = db.connection();
+
+        let mut reserved = Vec::new();
+        for feature_set_id in feature_set_ids {
+            let grant = conn
+                .query_row(
+                    &format!(
+                        "UPDATE client_grants SET call_count = call_count + 1
+                         WHERE client_id = ?1 AND space_id = ?2 AND feature_set_id = ?3
+                           AND max_calls IS NOT NULL AND call_count < max_calls
+                           AND (expires_at IS NULL OR expires_at > ?4)
+                         RETURNING {GRANT_COLUMNS}"
+                    ),
+                    params![
+                        client_id,
+                        space_id,
+                        feature_set_id,
+                        format_grant_time(Utc::now())
+                    ],
+                    grant_from_row,
+                )
+                .optional()?;
+            reserved.extend(grant);
+        }
+
+        Ok(reserved)
+    }
+
+    /// Delete grants that have expired or used up their calls, returning them
+    pub async fn delete_lapsed_grants(&self) -> Result<Vec<ClientGrant>> {
+        let db = self.db.lock().await;
+        let conn = db.connection();
+
+        let mut stmt = conn.prepare(&format!(
+            "DELETE FROM client_grants
+             WHERE (expires_at IS NOT NULL AND expires_at <= ?1)
+                OR (max_calls IS NOT NULL AND call_count >= max_calls)
+             RETURNING {GRANT_COLUMNS}"
+        ))?;
+        let lapsed = stmt
+            .query_map(params![format_grant_time(Utc::now())], grant_from_row)?
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+
+        if !lapsed.is_empty() {
+            info!("[OAuth] Removed {} lapsed grants", lapsed.len());
+        }
+        Ok(lapsed)
+    }
+
     /// Get all grants for a client across all spaces
     pub async fn get_all_grants(
         &self,
@@ -801,18 +1017,19 @@ impl InboundClientRepository {
         let db = self.db.lock().await;
         let conn = db.connection();
 
-        let mut stmt = conn.prepare(
+        let mut stmt = conn.prepare(&format!(
             "SELECT space_id, feature_set_id FROM client_grants 
-             WHERE client_id = ?1
-             ORDER BY space_id",
-        )?;
+             WHERE client_id = :client AND {ACTIVE_GRANT}
+             ORDER BY space_id"
+        ))?;
 
         let mut grants: std::collections::HashMap<String, Vec<String>> =
             std::collections::HashMap::new();
 
-        let rows = stmt.query_map(params![client_id], |row| {
-            Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?))
-        })?;
+        let rows = stmt.query_map(
+            named_params! { ":client": client_id, ":now": format_grant_time(Utc::now()) },
+            |row| Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?)),
+        )?;
 
         for row in rows {
             let (space_id, feature_set_id) = row?;
@@ -823,6 +1040,68 @@ impl InboundClientRepository {
     }
 
     // =========================================================================
+    // Client Tool Budgets
+    // =========================================================================
+
+    /// Get a client's tool budget (None = no budget configured)
+    pub async fn get_tool_budget(&self, client_id: &str) -> Result<Option<ClientToolBudget>> {
+        let db = self.db.lock().await;
+        let conn = db.connection();
+
+        let mut stmt = conn.prepare(
+            "SELECT max_tools, pinned_tools, server_priority
+             FROM client_tool_budgets WHERE client_id = ?1",
+        )?;
+
+        let mut rows = stmt.query(params![client_id])?;
+        let Some(row) = rows.next()? else {
+            return Ok(None);
+        };
+
+        let max_tools: Option<u32> = row.get(0)?;
+        let pinned_json: String = row.get(1)?;
+        let priority_json: String = row.get(2)?;
+
+        Ok(Some(ClientToolBudget {
+            max_tools,
+            pinned_tools: serde_json::from_str(&pinned_json).unwrap_or_default(),
+            server_priority: serde_json::from_str(&priority_json).unwrap_or_default(),
+        }))
+    }
+
+    /// Set a client's tool budget (replaces any existing budget)
+    pub async fn set_tool_budget(&self, client_id: &str, budget: &ClientToolBudget) -> Result<()> {
+        let db = self.db.lock().await;
+        let conn = db.connection();
+        let now = chrono::Utc::now().to_rfc3339();
+
+        conn.execute(
+            "INSERT INTO client_tool_budgets
+                (client_id, max_tools, pinned_tools, server_priority, updated_at)
+             VALUES (?1, ?2, ?3, ?4, ?5)
+             ON CONFLICT(client_id) DO UPDATE SET
+                max_tools = excluded.max_tools,
+                pinned_tools = excluded.pinned_tools,
+                server_priority = excluded.server_priority,
+                updated_at = excluded.updated_at",
+            params![
+                client_id,
+                budget.max_tools,
+                serde_json::to_string(&budget.pinned_tools)?,
+                serde_json::to_string(&budget.server_priority)?,
+                now
+            ],
+        )?;
+
+        debug!(
+            "[OAuth] Tool budget for {}: max={:?}, pinned={}, servers={}",
+            client_id,
+            budget.max_tools,
+            budget.pinned_tools.len(),
+            budget.server_priority.len()
+        );
+        Ok(())
+    }
 }
 
 #[cfg(test)]
@@ -10,10 +10,11 @@ use anyhow::Result;
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
 use mcpmux_core::{Client, ConnectionMode, InboundMcpClientRepository};
-use rusqlite::{params, OptionalExtension};
+use rusqlite::{named_params, params, OptionalExtension};
 use tokio::sync::Mutex;
 use uuid::Uuid;
 
+use super::inbound_client_repository::{format_grant_time, ACTIVE_GRANT};
 use crate::Database;
 
 /// SQLite-backed implementation of InboundMcpClientRepository.
@@ -320,15 +321,20 @@ impl InboundMcpClientRepository for SqliteInboundMcpClientRepository {
         let db = self.db.lock().await;
         let conn = db.connection();
 
-        let mut stmt = conn.prepare(
+        let mut stmt = conn.prepare(&format!(
             "SELECT feature_set_id FROM client_grants 
-             WHERE client_id = ?1 AND space_id = ?2",
-        )?;
+             WHERE client_id = :client AND space_id = :space AND {ACTIVE_GRANT}"
+        ))?;
 
         let grants = stmt
-            .query_map(params![client_id.to_string(), space_id], |row| {
-                row.get::<_, String>(0)
-            })?
+            .query_map(
+                named_params! {
+                    ":client": client_id.to_string(),
+                    ":space": space_id,
+                    ":now": format_grant_time(Utc::now()),
+                },
+                |row| row.get::<_, String>(0),
+            )?
             .collect::<Result<Vec<_>, _>>()?;
 
         Ok(grants)
@@ -341,18 +347,22 @@ impl InboundMcpClientRepository for SqliteInboundMcpClientRepository {
         let db = self.db.lock().await;
         let conn = db.connection();
 
-        let mut stmt = conn.prepare(
+        let mut stmt = conn.prepare(&format!(
             "SELECT space_id, feature_set_id FROM client_grants 
-             WHERE client_id = ?1
-             ORDER BY space_id",
-        )?;
+             WHERE client_id = :client AND {ACTIVE_GRANT}
+             ORDER BY space_id"
+        ))?;
 
         let mut grants: std::collections::HashMap<String, Vec<String>> =
             std::collections::HashMap::new();
 
-        let rows = stmt.query_map(params![client_id.to_string()], |row| {
-            Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?))
-        })?;
+        let rows = stmt.query_map(
+            named_params! {
+                ":client": client_id.to_string(),
+                ":now": format_grant_time(Utc::now()),
+            },
+            |row| Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?)),
+        )?;
 
         for row in rows {
             let (space_id, feature_set_id) = row?;
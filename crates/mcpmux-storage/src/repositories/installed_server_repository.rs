@@ -7,7 +7,9 @@ use std::sync::Arc;
 use anyhow::Result;
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
-use mcpmux_core::{InstallationSource, InstalledServer, InstalledServerRepository};
+use mcpmux_core::{
+    ConcurrencyLimit, InstallationSource, InstalledServer, InstalledServerRepository,
+};
 use rusqlite::{params, OptionalExtension};
 use tokio::sync::Mutex;
 use uuid::Uuid;
@@ -30,6 +32,11 @@ struct RawServerRow {
     created_at: String,
     updated_at: String,
     source: Option<String>,
+    read_only: bool,
+    concurrency_limit: Option<String>,
+    skip_argument_validation: bool,
+    cwd_override: Option<String>,
+    env_file_override: Option<String>,
 }
 
 /// SQLite-backed implementation of InstalledServerRepository.
@@ -102,7 +109,7 @@ impl SqliteInstalledServerRepository {
     }
 
     /// Serialize InstallationSource to database string format.
-    /// Format: "registry" | "user_config:/path/to/file.json" | "manual_entry"
+    /// Format: "registry" | "user_config:/path/to/file.json" | "manual_entry" | "imported:cursor"
     fn serialize_source(source: &InstallationSource) -> String {
         match source {
             InstallationSource::Registry => "registry".to_string(),
@@ -110,6 +117,7 @@ impl SqliteInstalledServerRepository {
                 format!("user_config:{}", file_path.display())
             }
             InstallationSource::ManualEntry => "manual_entry".to_string(),
+            InstallationSource::Imported { client } => format!("imported:{}", client),
         }
     }
 
@@ -124,14 +132,28 @@ impl SqliteInstalledServerRepository {
                     file_path: PathBuf::from(path),
                 }
             }
+            Some(s) if s.starts_with("imported:") => InstallationSource::Imported {
+                client: s.strip_prefix("imported:").unwrap_or("").to_string(),
+            },
             _ => InstallationSource::Registry,
         }
     }
 
+    /// Serialize an optional ConcurrencyLimit to JSON.
+    fn serialize_concurrency_limit(limit: Option<&ConcurrencyLimit>) -> Option<String> {
+        limit.and_then(|l| serde_json::to_string(l).ok())
+    }
+
+    /// Parse an optional ConcurrencyLimit from JSON.
+    fn parse_concurrency_limit(s: Option<String>) -> Option<ConcurrencyLimit> {
+        s.and_then(|json| serde_json::from_str(&json).ok())
+    }
+
     /// Standard column list for SELECT queries
     const SELECT_COLUMNS: &'static str =
         "id, space_id, server_id, server_name, cached_definition, input_values, enabled, env_overrides,
-         args_append, extra_headers, oauth_connected, created_at, updated_at, source";
+         args_append, extra_headers, oauth_connected, created_at, updated_at, source, read_only,
+         concurrency_limit, skip_argument_validation, cwd_override, env_file_override";
 
     /// Extract raw row data (used in the closure passed to rusqlite).
     fn extract_row(row: &rusqlite::Row) -> rusqlite::Result<RawServerRow> {
@@ -150,6 +172,11 @@ impl SqliteInstalledServerRepository {
             created_at: row.get(11)?,
             updated_at: row.get(12)?,
             source: row.get(13)?,
+            read_only: row.get(14)?,
+            concurrency_limit: row.get(15)?,
+            skip_argument_validation: row.get(16)?,
+            cwd_override: row.get(17)?,
+            env_file_override: row.get(18)?,
         })
     }
 
@@ -166,6 +193,11 @@ impl SqliteInstalledServerRepository {
             env_overrides: Self::parse_json_map(row.env_overrides),
             args_append: Self::parse_json_vec(row.args_append),
             extra_headers: Self::parse_json_map(row.extra_headers),
+            read_only: row.read_only,
+            concurrency_limit: Self::parse_concurrency_limit(row.concurrency_limit),
+            skip_argument_validation: row.skip_argument_validation,
+            cwd_override: row.cwd_override,
+            env_file_override: row.env_file_override,
             oauth_connected: row.oauth_connected,
             source: Self::parse_source(row.source),
             created_at: Self::parse_datetime(&row.created_at),
@@ -275,8 +307,10 @@ impl InstalledServerRepository for SqliteInstalledServerRepository {
         conn.execute(
             "INSERT INTO installed_servers
              (id, space_id, server_id, server_name, cached_definition, input_values, enabled, env_overrides,
-              args_append, extra_headers, oauth_connected, created_at, updated_at, source)
-             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14)",
+              args_append, extra_headers, oauth_connected, created_at, updated_at, source, read_only,
+              concurrency_limit, skip_argument_validation, cwd_override, env_file_override)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16, ?17,
+                     ?18, ?19)",
             params![
                 server.id.to_string(),
                 server.space_id,
@@ -292,6 +326,11 @@ impl InstalledServerRepository for SqliteInstalledServerRepository {
                 server.created_at.to_rfc3339(),
                 server.updated_at.to_rfc3339(),
                 Self::serialize_source(&server.source),
+                server.read_only,
+                Self::serialize_concurrency_limit(server.concurrency_limit.as_ref()),
+                server.skip_argument_validation,
+                server.cwd_override,
+                server.env_file_override,
             ],
         )?;
         Ok(())
@@ -307,7 +346,8 @@ impl InstalledServerRepository for SqliteInstalledServerRepository {
             "UPDATE installed_servers
              SET server_name = ?2, cached_definition = ?3, input_values = ?4, enabled = ?5,
                  env_overrides = ?6, args_append = ?7, extra_headers = ?8, oauth_connected = ?9,
-                 updated_at = ?10, source = ?11
+                 updated_at = ?10, source = ?11, read_only = ?12, concurrency_limit = ?13,
+                 skip_argument_validation = ?14, cwd_override = ?15, env_file_override = ?16
              WHERE id = ?1",
             params![
                 server.id.to_string(),
@@ -321,6 +361,11 @@ impl InstalledServerRepository for SqliteInstalledServerRepository {
                 server.oauth_connected,
                 Utc::now().to_rfc3339(),
                 Self::serialize_source(&server.source),
+                server.read_only,
+                Self::serialize_concurrency_limit(server.concurrency_limit.as_ref()),
+                server.skip_argument_validation,
+                server.cwd_override,
+                server.env_file_override,
             ],
         )?;
         Ok(())
@@ -1,26 +1,40 @@
 //! Repository implementations using SQLite.
 
 mod app_settings_repository;
+mod argument_policy_repository;
 mod credential_repository;
+mod feature_override_repository;
 mod feature_set_repository;
 mod inbound_client_repository;
 mod inbound_mcp_client_repository;
 mod installed_server_repository;
 mod outbound_oauth_client_repository;
+mod rate_limit_repository;
 mod server_feature_repository;
 mod space_repository;
+mod space_secret_repository;
+mod tool_approval_repository;
+mod tool_usage_repository;
+mod transaction_manager;
 
 pub use app_settings_repository::SqliteAppSettingsRepository;
+pub use argument_policy_repository::ArgumentPolicyRepository;
 pub use credential_repository::SqliteCredentialRepository;
+pub use feature_override_repository::FeatureOverrideRepository;
 pub use feature_set_repository::SqliteFeatureSetRepository;
 pub use inbound_client_repository::{
-    AuthorizationCode, InboundClient, InboundClientRepository, RegistrationType, TokenRecord,
-    TokenType,
+    AuthorizationCode, ClientGrant, ClientToolBudget, GrantLimits, InboundClient,
+    InboundClientRepository, RegistrationType, TokenRecord, TokenType,
 };
 pub use inbound_mcp_client_repository::SqliteInboundMcpClientRepository;
 pub use installed_server_repository::SqliteInstalledServerRepository;
 pub use outbound_oauth_client_repository::SqliteOutboundOAuthRepository;
+pub use rate_limit_repository::RateLimitRepository;
 pub use server_feature_repository::{
     FeatureType, ServerFeature, ServerFeatureRepository, SqliteServerFeatureRepository,
 };
 pub use space_repository::SqliteSpaceRepository;
+pub use space_secret_repository::SqliteSpaceSecretRepository;
+pub use tool_approval_repository::ToolApprovalRepository;
+pub use tool_usage_repository::{ToolUsage, ToolUsageRepository};
+pub use transaction_manager::SqliteTransactionManager;
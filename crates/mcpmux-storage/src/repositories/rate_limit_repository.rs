@@ -0,0 +1,148 @@
+//! Rate limit repository
+//!
+//! Per-client rate limits and daily quotas on tool calls, together with
+//! their usage counters. Used by the gateway to throttle tool calls.
+
+use std::sync::Arc;
+
+use anyhow::Result;
+use chrono::{DateTime, NaiveDate, Utc};
+use mcpmux_core::{ClientRateLimit, RateLimitUsage};
+use rusqlite::params;
+use tokio::sync::Mutex;
+
+use crate::Database;
+
+/// Rate limit repository with database persistence
+pub struct RateLimitRepository {
+    db: Arc<Mutex<Database>>,
+}
+
+impl RateLimitRepository {
+    /// Create a new rate limit repository with a database
+    pub fn new(db: Arc<Mutex<Database>>) -> Self {
+        Self { db }
+    }
+
+    /// List a client's limits with their usage counters
+    pub async fn list_for_client(&self, client_id: &str) -> Result<Vec<ClientRateLimit>> {
+        let db = self.db.lock().await;
+        let conn = db.connection();
+
+        let mut stmt = conn.prepare(
+            "SELECT client_id, server_id, tool_name, per_minute, burst, daily_quota,
+                    tokens, usage_updated_at, quota_day, quota_used
+             FROM client_rate_limits WHERE client_id = ?1
+             ORDER BY server_id, tool_name",
+        )?;
+
+        let rows = stmt.query_map(params![client_id], |row| {
+            let tool_name: String = row.get(2)?;
+            let tokens: Option<f64> = row.get(6)?;
+            let usage_updated_at: Option<String> = row.get(7)?;
+            let quota_day: Option<String> = row.get(8)?;
+            let quota_used: u32 = row.get(9)?;
+
+            let usage = match (tokens, usage_updated_at, quota_day) {
+                (Some(tokens), Some(updated_at), Some(quota_day)) => {
+                    match (
+                        DateTime::parse_from_rfc3339(&updated_at),
+                        NaiveDate::parse_from_str(&quota_day, "%Y-%m-%d"),
+                    ) {
+                        (Ok(updated_at), Ok(quota_day)) => Some(RateLimitUsage {
+                            tokens,
+                            updated_at: updated_at.with_timezone(&Utc),
+                            quota_day,
+                            quota_used,
+                        }),
+                        _ => None,
+                    }
+                }
+                _ => None,
+            };
+
+            Ok(ClientRateLimit {
+                client_id: row.get(0)?,
+                server_id: row.get(1)?,
+                tool_name: (!tool_name.is_empty()).then_some(tool_name),
+                per_minute: row.get(3)?,
+                burst: row.get(4)?,
+                daily_quota: row.get(5)?,
+                usage,
+            })
+        })?;
+
+        Ok(rows.collect::<std::result::Result<Vec<_>, _>>()?)
+    }
+
+    /// Create or update a limit (usage counters are kept)
+    pub async fn set(&self, limit: &ClientRateLimit) -> Result<()> {
+        let db = self.db.lock().await;
+        let conn = db.connection();
+        let now = Utc::now().to_rfc3339();
+
+        conn.execute(
+            "INSERT INTO client_rate_limits
+                (client_id, server_id, tool_name, per_minute, burst, daily_quota, updated_at)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)
+             ON CONFLICT(client_id, server_id, tool_name) DO UPDATE SET
+                per_minute = excluded.per_minute,
+                burst = excluded.burst,
+                daily_quota = excluded.daily_quota,
+                updated_at = excluded.updated_at",
+            params![
+                limit.client_id,
+                limit.server_id,
+                limit.tool_name.as_deref().unwrap_or(""),
+                limit.per_minute,
+                limit.burst,
+                limit.daily_quota,
+                now
+            ],
+        )?;
+
+        Ok(())
+    }
+
+    /// Persist a limit's usage counters
+    pub async fn save_usage(&self, limit: &ClientRateLimit, usage: &RateLimitUsage) -> Result<()> {
+        let db = self.db.lock().await;
+        let conn = db.connection();
+
+        conn.execute(
+            "UPDATE client_rate_limits
+             SET tokens = ?4, usage_updated_at = ?5, quota_day = ?6, quota_used = ?7
+             WHERE client_id = ?1 AND server_id = ?2 AND tool_name = ?3",
+            params![
+                limit.client_id,
+                limit.server_id,
+                limit.tool_name.as_deref().unwrap_or(""),
+                usage.tokens,
+                usage.updated_at.to_rfc3339(),
+                usage.quota_day.format("%Y-%m-%d").to_string(),
+                usage.quota_used
+            ],
+        )?;
+
+        Ok(())
+    }
+
+    /// Remove a limit
+    pub async fn delete(
+        &self,
+        client_id: &str,
+        server_id: &str,
+        tool_name: Option<&str>,
+    ) -> Result<()> {
+        let db = self.db.lock().await;
+        let conn = db.connection();
+
+        conn.execute(
+            "DELETE FROM client_rate_limits
+             WHERE client_id = ?1 AND server_id = ?2 AND tool_name = ?3",
+            params![client_id, server_id, tool_name.unwrap_or("")],
+        )?;
+
+        Ok(())
+    }
+}
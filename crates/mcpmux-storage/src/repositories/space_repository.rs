@@ -50,7 +50,7 @@ impl SpaceRepository for SqliteSpaceRepository {
         tracing::debug!("[SpaceRepository::list] Querying spaces...");
 
         let mut stmt = conn.prepare(
-            "SELECT id, name, icon, description, is_default, sort_order, created_at, updated_at 
+            "SELECT id, name, icon, description, is_default, sort_order, created_at, updated_at, read_only
              FROM spaces 
              ORDER BY sort_order ASC, name ASC",
         )?;
@@ -75,6 +75,7 @@ impl SpaceRepository for SqliteSpaceRepository {
                     description: row.get(3)?,
                     is_default: row.get::<_, i32>(4)? == 1,
                     sort_order: row.get(5)?,
+                    read_only: row.get::<_, i32>(8)? == 1,
                     created_at: Self::parse_datetime(&row.get::<_, String>(6)?),
                     updated_at: Self::parse_datetime(&row.get::<_, String>(7)?),
                 })
@@ -91,7 +92,7 @@ impl SpaceRepository for SqliteSpaceRepository {
         let conn = db.connection();
 
         let mut stmt = conn.prepare(
-            "SELECT id, name, icon, description, is_default, sort_order, created_at, updated_at 
+            "SELECT id, name, icon, description, is_default, sort_order, created_at, updated_at, read_only
              FROM spaces 
              WHERE id = ?",
         )?;
@@ -108,6 +109,7 @@ impl SpaceRepository for SqliteSpaceRepository {
                     description: row.get(3)?,
                     is_default: row.get::<_, i32>(4)? == 1,
                     sort_order: row.get(5)?,
+                    read_only: row.get::<_, i32>(8)? == 1,
                     created_at: Self::parse_datetime(&row.get::<_, String>(6)?),
                     updated_at: Self::parse_datetime(&row.get::<_, String>(7)?),
                 })
@@ -124,8 +126,8 @@ impl SpaceRepository for SqliteSpaceRepository {
         let now = chrono::Utc::now().format("%Y-%m-%dT%H:%M:%SZ").to_string();
 
         conn.execute(
-            "INSERT INTO spaces (id, name, icon, description, is_default, sort_order, created_at, updated_at)
-             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)",
+            "INSERT INTO spaces (id, name, icon, description, is_default, sort_order, created_at, updated_at, read_only)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)",
             params![
                 space_id,
                 space.name,
@@ -135,6 +137,7 @@ impl SpaceRepository for SqliteSpaceRepository {
                 space.sort_order,
                 space.created_at.to_rfc3339(),
                 space.updated_at.to_rfc3339(),
+                if space.read_only { 1 } else { 0 },
             ],
         )?;
 
@@ -170,7 +173,8 @@ impl SpaceRepository for SqliteSpaceRepository {
 
         let rows_affected = conn.execute(
             "UPDATE spaces 
-             SET name = ?2, icon = ?3, description = ?4, is_default = ?5, sort_order = ?6, updated_at = ?7
+             SET name = ?2, icon = ?3, description = ?4, is_default = ?5, sort_order = ?6, updated_at = ?7,
+                 read_only = ?8
              WHERE id = ?1",
             params![
                 space.id.to_string(),
@@ -180,6 +184,7 @@ impl SpaceRepository for SqliteSpaceRepository {
                 if space.is_default { 1 } else { 0 },
                 space.sort_order,
                 space.updated_at.to_rfc3339(),
+                if space.read_only { 1 } else { 0 },
             ],
         )?;
 
@@ -204,7 +209,7 @@ impl SpaceRepository for SqliteSpaceRepository {
         let conn = db.connection();
 
         let mut stmt = conn.prepare(
-            "SELECT id, name, icon, description, is_default, sort_order, created_at, updated_at
+            "SELECT id, name, icon, description, is_default, sort_order, created_at, updated_at, read_only
              FROM spaces
              WHERE is_default = 1
              LIMIT 1",
@@ -222,6 +227,7 @@ impl SpaceRepository for SqliteSpaceRepository {
                     description: row.get(3)?,
                     is_default: true,
                     sort_order: row.get(5)?,
+                    read_only: row.get::<_, i32>(8)? == 1,
                     created_at: Self::parse_datetime(&row.get::<_, String>(6)?),
                     updated_at: Self::parse_datetime(&row.get::<_, String>(7)?),
                 })
@@ -235,14 +241,19 @@ impl SpaceRepository for SqliteSpaceRepository {
         let db = self.db.lock().await;
         let conn = db.connection();
 
-        // Use a transaction to ensure atomicity
-        let tx = conn.unchecked_transaction()?;
+        // Use a transaction to ensure atomicity, unless the caller already
+        // opened one (SQLite transactions don't nest)
+        let tx = if conn.is_autocommit() {
+            Some(conn.unchecked_transaction()?)
+        } else {
+            None
+        };
 
         // Clear all defaults
-        tx.execute("UPDATE spaces SET is_default = 0", [])?;
+        conn.execute("UPDATE spaces SET is_default = 0", [])?;
 
         // Set the new default
-        let rows_affected = tx.execute(
+        let rows_affected = conn.execute(
             "UPDATE spaces SET is_default = 1 WHERE id = ?",
             params![id.to_string()],
         )?;
@@ -251,7 +262,9 @@ impl SpaceRepository for SqliteSpaceRepository {
             anyhow::bail!("Space not found: {}", id);
         }
 
-        tx.commit()?;
+        if let Some(tx) = tx {
+            tx.commit()?;
+        }
 
         Ok(())
     }
@@ -0,0 +1,188 @@
+//! SQLite implementation of SpaceSecretRepository with encryption.
+//!
+//! Each secret is stored as a row per (space, name). Only the value is
+//! encrypted — the name and timestamps are plaintext.
+
+use std::sync::Arc;
+
+use anyhow::Result;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use mcpmux_core::{SpaceSecret, SpaceSecretRepository};
+use rusqlite::{params, OptionalExtension};
+use tokio::sync::Mutex;
+use uuid::Uuid;
+
+use crate::crypto::FieldEncryptor;
+use crate::Database;
+
+/// Raw row data extracted from SQLite before decryption.
+struct RawSecretRow {
+    space_id: String,
+    name: String,
+    secret_value: String, // Encrypted
+    created_at: String,
+    updated_at: String,
+}
+
+/// SQLite-backed space secret repository with field-level encryption.
+pub struct SqliteSpaceSecretRepository {
+    db: Arc<Mutex<Database>>,
+    encryptor: Arc<FieldEncryptor>,
+}
+
+impl SqliteSpaceSecretRepository {
+    /// Create a new space secret repository.
+    pub fn new(db: Arc<Mutex<Database>>, encryptor: Arc<FieldEncryptor>) -> Self {
+        Self { db, encryptor }
+    }
+
+    /// Standard column list for SELECT queries.
+    const SELECT_COLUMNS: &'static str = "space_id, name, secret_value, created_at, updated_at";
+
+    /// Extract raw row data from a rusqlite Row.
+    fn extract_row(row: &rusqlite::Row) -> rusqlite::Result<RawSecretRow> {
+        Ok(RawSecretRow {
+            space_id: row.get(0)?,
+            name: row.get(1)?,
+            secret_value: row.get(2)?,
+            created_at: row.get(3)?,
+            updated_at: row.get(4)?,
+        })
+    }
+
+    /// Parse an RFC3339 datetime string.
+    fn parse_datetime(s: &str) -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339(s)
+            .map(|dt| dt.with_timezone(&Utc))
+            .unwrap_or_else(|_| Utc::now())
+    }
+
+    /// Build a SpaceSecret from extracted row data (needs &self for decryption).
+    fn build_secret(&self, row: RawSecretRow) -> Result<SpaceSecret> {
+        let value = self
+            .encryptor
+            .decrypt(&row.secret_value)
+            .map_err(|e| anyhow::anyhow!("Failed to decrypt secret '{}': {}", row.name, e))?;
+
+        Ok(SpaceSecret {
+            space_id: row.space_id.parse().unwrap_or_else(|_| Uuid::new_v4()),
+            name: row.name,
+            value,
+            created_at: Self::parse_datetime(&row.created_at),
+            updated_at: Self::parse_datetime(&row.updated_at),
+        })
+    }
+}
+
+#[async_trait]
+impl SpaceSecretRepository for SqliteSpaceSecretRepository {
+    async fn list_for_space(&self, space_id: &Uuid) -> Result<Vec<SpaceSecret>> {
+        let db = self.db.lock().await;
+        let conn = db.connection();
+
+        let mut stmt = conn.prepare(&format!(
+            "SELECT {} FROM space_secrets WHERE space_id = ?1 ORDER BY name",
+            Self::SELECT_COLUMNS
+        ))?;
+
+        let rows: Vec<_> = stmt
+            .query_map(params![space_id.to_string()], Self::extract_row)?
+            .collect::<Result<Vec<_>, _>>()?;
+
+        rows.into_iter().map(|r| self.build_secret(r)).collect()
+    }
+
+    async fn get(&self, space_id: &Uuid, name: &str) -> Result<Option<SpaceSecret>> {
+        let db = self.db.lock().await;
+        let conn = db.connection();
+
+        let mut stmt = conn.prepare(&format!(
+            "SELECT {} FROM space_secrets WHERE space_id = ?1 AND name = ?2",
+            Self::SELECT_COLUMNS
+        ))?;
+
+        let row = stmt
+            .query_row(params![space_id.to_string(), name], Self::extract_row)
+            .optional()?;
+
+        row.map(|r| self.build_secret(r)).transpose()
+    }
+
+    async fn save(&self, secret: &SpaceSecret) -> Result<()> {
+        let db = self.db.lock().await;
+        let conn = db.connection();
+
+        let encrypted_value = self
+            .encryptor
+            .encrypt(&secret.value)
+            .map_err(|e| anyhow::anyhow!("Failed to encrypt secret '{}': {}", secret.name, e))?;
+
+        conn.execute(
+            "INSERT INTO space_secrets (space_id, name, secret_value, created_at, updated_at)
+             VALUES (?1, ?2, ?3, ?4, ?5)
+             ON CONFLICT(space_id, name) DO UPDATE SET
+                secret_value = excluded.secret_value,
+                updated_at = excluded.updated_at",
+            params![
+                secret.space_id.to_string(),
+                secret.name,
+                encrypted_value,
+                secret.created_at.to_rfc3339(),
+                secret.updated_at.to_rfc3339(),
+            ],
+        )?;
+
+        Ok(())
+    }
+
+    async fn delete(&self, space_id: &Uuid, name: &str) -> Result<bool> {
+        let db = self.db.lock().await;
+        let conn = db.connection();
+
+        let deleted = conn.execute(
+            "DELETE FROM space_secrets WHERE space_id = ?1 AND name = ?2",
+            params![space_id.to_string(), name],
+        )?;
+
+        Ok(deleted > 0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_secret_is_encrypted_at_rest() {
+        let db = Arc::new(Mutex::new(Database::open_in_memory().unwrap()));
+        let key = crate::crypto::generate_master_key().unwrap();
+        let encryptor = Arc::new(FieldEncryptor::new(&key).unwrap());
+        let repo = SqliteSpaceSecretRepository::new(db.clone(), encryptor);
+
+        let space_id = Uuid::new_v4();
+        db.lock().await.connection().execute(
+            "INSERT INTO spaces (id, name, created_at, updated_at) VALUES (?, 'Test', datetime('now'), datetime('now'))",
+            params![space_id.to_string()],
+        ).unwrap();
+
+        repo.save(&SpaceSecret::new(space_id, "github_token", "ghp_plain"))
+            .await
+            .unwrap();
+
+        let stored: String = db
+            .lock()
+            .await
+            .connection()
+            .query_row(
+                "SELECT secret_value FROM space_secrets WHERE name = 'github_token'",
+                [],
+                |row| row.get(0),
+            )
+            .unwrap();
+        assert!(!stored.contains("ghp_plain"));
+
+        let found = repo.get(&space_id, "github_token").await.unwrap().unwrap();
+        assert_eq!(found.value, "ghp_plain");
+    }
+}
@@ -0,0 +1,112 @@
+//! Tool approval policy repository
+//!
+//! Per-space approval rules for tools and feature sets. Used by the gateway
+//! to decide whether a tool call runs, waits for a human, or is refused.
+
+use std::sync::Arc;
+
+use anyhow::Result;
+use mcpmux_core::{ApprovalPolicy, ApprovalTarget, ToolApprovalRule};
+use rusqlite::params;
+use tokio::sync::Mutex;
+use tracing::warn;
+
+use crate::Database;
+
+/// Tool approval policy repository with database persistence
+pub struct ToolApprovalRepository {
+    db: Arc<Mutex<Database>>,
+}
+
+impl ToolApprovalRepository {
+    /// Create a new tool approval repository with a database
+    pub fn new(db: Arc<Mutex<Database>>) -> Self {
+        Self { db }
+    }
+
+    /// List all rules in a space
+    pub async fn list_for_space(&self, space_id: &str) -> Result<Vec<ToolApprovalRule>> {
+        let db = self.db.lock().await;
+        let conn = db.connection();
+
+        let mut stmt = conn.prepare(
+            "SELECT space_id, target_type, target, policy
+             FROM tool_approval_policies WHERE space_id = ?1
+             ORDER BY target_type, target",
+        )?;
+
+        let rows = stmt.query_map(params![space_id], |row| {
+            Ok((
+                row.get::<_, String>(0)?,
+                row.get::<_, String>(1)?,
+                row.get::<_, String>(2)?,
+                row.get::<_, String>(3)?,
+            ))
+        })?;
+
+        let mut rules = Vec::new();
+        for row in rows {
+            let (space_id, target_type, target, policy) = row?;
+            match (
+                ApprovalTarget::parse(&target_type),
+                ApprovalPolicy::parse(&policy),
+            ) {
+                (Some(target_type), Some(policy)) => rules.push(ToolApprovalRule {
+                    space_id,
+                    target_type,
+                    target,
+                    policy,
+                }),
+                _ => warn!(
+                    "[ToolApproval] Skipping invalid rule {}:{} = {}",
+                    target_type, target, policy
+                ),
+            }
+        }
+
+        Ok(rules)
+    }
+
+    /// Create or replace a rule
+    pub async fn set(&self, rule: &ToolApprovalRule) -> Result<()> {
+        let db = self.db.lock().await;
+        let conn = db.connection();
+        let now = chrono::Utc::now().to_rfc3339();
+
+        conn.execute(
+            "INSERT INTO tool_approval_policies (space_id, target_type, target, policy, updated_at)
+             VALUES (?1, ?2, ?3, ?4, ?5)
+             ON CONFLICT(space_id, target_type, target) DO UPDATE SET
+                policy = excluded.policy,
+                updated_at = excluded.updated_at",
+            params![
+                rule.space_id,
+                rule.target_type.as_str(),
+                rule.target,
+                rule.policy.as_str(),
+                now
+            ],
+        )?;
+
+        Ok(())
+    }
+
+    /// Remove a rule (the tool falls back to feature set rules or the default)
+    pub async fn delete(
+        &self,
+        space_id: &str,
+        target_type: ApprovalTarget,
+        target: &str,
+    ) -> Result<()> {
+        let db = self.db.lock().await;
+        let conn = db.connection();
+
+        conn.execute(
+            "DELETE FROM tool_approval_policies
+             WHERE space_id = ?1 AND target_type = ?2 AND target = ?3",
+            params![space_id, target_type.as_str(), target],
+        )?;
+
+        Ok(())
+    }
+}
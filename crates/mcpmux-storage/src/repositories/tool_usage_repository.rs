@@ -0,0 +1,88 @@
+//! Tool usage repository
+//!
+//! Aggregated tool call history per (space, server, tool). Used to keep the
+//! most used tools when a client's tool budget trims tools/list.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use anyhow::Result;
+use rusqlite::params;
+use tokio::sync::Mutex;
+
+use crate::Database;
+
+/// Call statistics for one tool in a space
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ToolUsage {
+    pub server_id: String,
+    pub tool_name: String,
+    pub call_count: u64,
+    pub last_called_at: String,
+}
+
+/// Tool usage repository with database persistence
+pub struct ToolUsageRepository {
+    db: Arc<Mutex<Database>>,
+}
+
+impl ToolUsageRepository {
+    /// Create a new tool usage repository with a database
+    pub fn new(db: Arc<Mutex<Database>>) -> Self {
+        Self { db }
+    }
+
+    /// Record a tool call
+    pub async fn record_call(
+        &self,
+        space_id: &str,
+        server_id: &str,
+        tool_name: &str,
+    ) -> Result<()> {
+        let db = self.db.lock().await;
+        let conn = db.connection();
+        let now = chrono::Utc::now().to_rfc3339();
+
+        conn.execute(
+            "INSERT INTO tool_usage (space_id, server_id, tool_name, call_count, last_called_at)
+             VALUES (?1, ?2, ?3, 1, ?4)
+             ON CONFLICT(space_id, server_id, tool_name) DO UPDATE SET
+                call_count = call_count + 1,
+                last_called_at = excluded.last_called_at",
+            params![space_id, server_id, tool_name, now],
+        )?;
+
+        Ok(())
+    }
+
+    /// Get usage for all tools called in a space, keyed by (server_id, tool_name)
+    pub async fn get_usage_for_space(
+        &self,
+        space_id: &str,
+    ) -> Result<HashMap<(String, String), ToolUsage>> {
+        let db = self.db.lock().await;
+        let conn = db.connection();
+
+        let mut stmt = conn.prepare(
+            "SELECT server_id, tool_name, call_count, last_called_at
+             FROM tool_usage WHERE space_id = ?1",
+        )?;
+
+        let rows = stmt.query_map(params![space_id], |row| {
+            Ok(ToolUsage {
+                server_id: row.get(0)?,
+                tool_name: row.get(1)?,
+                call_count: row.get::<_, i64>(2)?.max(0) as u64,
+                last_called_at: row.get(3)?,
+            })
+        })?;
+
+        let mut usage = HashMap::new();
+        for row in rows {
+            let row = row?;
+            usage.insert((row.server_id.clone(), row.tool_name.clone()), row);
+        }
+
+        Ok(usage)
+    }
+}
@@ -0,0 +1,182 @@
+//! SQLite implementation of TransactionManager.
+//!
+//! Each transaction opens its own connection to the database file and builds
+//! repositories over it, so the shared connection used by the rest of the app
+//! is never inside the transaction. Writers on the shared connection wait on
+//! SQLite's lock (see `Database::open`) until the transaction finishes. Units
+//! of work are serialized with an async lock held until commit or rollback.
+
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use anyhow::Result;
+use async_trait::async_trait;
+use mcpmux_core::{Transaction, TransactionManager, TransactionRepositories};
+use tokio::sync::{Mutex, OwnedMutexGuard};
+
+use crate::{
+    Database, FieldEncryptor, SqliteAppSettingsRepository, SqliteCredentialRepository,
+    SqliteFeatureSetRepository, SqliteInboundMcpClientRepository, SqliteInstalledServerRepository,
+    SqliteOutboundOAuthRepository, SqliteServerFeatureRepository, SqliteSpaceRepository,
+    SqliteSpaceSecretRepository,
+};
+
+/// SQLite-backed transaction manager.
+pub struct SqliteTransactionManager {
+    path: PathBuf,
+    encryptor: Arc<FieldEncryptor>,
+    unit: Arc<Mutex<()>>,
+}
+
+impl SqliteTransactionManager {
+    /// Create a transaction manager for the database file at `path`.
+    pub fn new(path: impl Into<PathBuf>, encryptor: Arc<FieldEncryptor>) -> Self {
+        Self {
+            path: path.into(),
+            encryptor,
+            unit: Arc::new(Mutex::new(())),
+        }
+    }
+}
+
+#[async_trait]
+impl TransactionManager for SqliteTransactionManager {
+    async fn begin(&self) -> Result<Box<dyn Transaction>> {
+        let unit = self.unit.clone().lock_owned().await;
+        let database = Database::open(&self.path)?;
+        database.connection().execute_batch("BEGIN IMMEDIATE")?;
+
+        let db = Arc::new(Mutex::new(database));
+        let encryptor = self.encryptor.clone();
+        let repositories = TransactionRepositories {
+            spaces: Arc::new(SqliteSpaceRepository::new(db.clone())),
+            installed_servers: Arc::new(SqliteInstalledServerRepository::new(
+                db.clone(),
+                encryptor.clone(),
+            )),
+            server_features: Arc::new(SqliteServerFeatureRepository::new(db.clone())),
+            feature_sets: Arc::new(SqliteFeatureSetRepository::new(db.clone())),
+            clients: Arc::new(SqliteInboundMcpClientRepository::new(db.clone())),
+            credentials: Arc::new(SqliteCredentialRepository::new(
+                db.clone(),
+                encryptor.clone(),
+            )),
+            space_secrets: Arc::new(SqliteSpaceSecretRepository::new(db.clone(), encryptor)),
+            oauth: Arc::new(SqliteOutboundOAuthRepository::new(db.clone())),
+            settings: Arc::new(SqliteAppSettingsRepository::new(db.clone())),
+        };
+
+        Ok(Box::new(SqliteTransaction {
+            db,
+            repositories,
+            _unit: unit,
+        }))
+    }
+}
+
+/// A transaction on its own connection.
+///
+/// Dropping it without committing closes the connection, which rolls the
+/// transaction back.
+struct SqliteTransaction {
+    db: Arc<Mutex<Database>>,
+    repositories: TransactionRepositories,
+    _unit: OwnedMutexGuard<()>,
+}
+
+impl SqliteTransaction {
+    async fn execute(&self, sql: &str) -> Result<()> {
+        let db = self.db.lock().await;
+        db.connection().execute_batch(sql)?;
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl Transaction for SqliteTransaction {
+    fn repositories(&self) -> &TransactionRepositories {
+        &self.repositories
+    }
+
+    async fn commit(self: Box<Self>) -> Result<()> {
+        let result = self.execute("COMMIT").await;
+        if result.is_err() {
+            // A failed COMMIT can leave the transaction open
+            let _ = self.execute("ROLLBACK").await;
+        }
+        result
+    }
+
+    async fn rollback(self: Box<Self>) -> Result<()> {
+        self.execute("ROLLBACK").await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anyhow::bail;
+    use mcpmux_core::{in_transaction, Space, SpaceRepository};
+    use tempfile::TempDir;
+
+    use crate::generate_master_key;
+
+    fn setup() -> (TempDir, Arc<Mutex<Database>>, SqliteTransactionManager) {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("mcpmux.db");
+        let db = Arc::new(Mutex::new(Database::open(&path).unwrap()));
+        let key = generate_master_key().unwrap();
+        let encryptor = Arc::new(FieldEncryptor::new(&key).unwrap());
+        (dir, db, SqliteTransactionManager::new(path, encryptor))
+    }
+
+    fn has_space(spaces: &[Space], name: &str) -> bool {
+        spaces.iter().any(|s| s.name == name)
+    }
+
+    #[tokio::test]
+    async fn test_rollback_discards_writes() {
+        let (_dir, db, transactions) = setup();
+        let spaces = SqliteSpaceRepository::new(db);
+
+        let result: Result<()> = in_transaction(&transactions, |repos| async move {
+            repos.spaces.create(&Space::new("Doomed")).await?;
+            bail!("boom")
+        })
+        .await;
+        assert!(result.is_err());
+        assert!(!has_space(&spaces.list().await.unwrap(), "Doomed"));
+
+        in_transaction(&transactions, |repos| async move {
+            repos.spaces.create(&Space::new("Kept")).await
+        })
+        .await
+        .unwrap();
+        assert!(has_space(&spaces.list().await.unwrap(), "Kept"));
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_rollback_keeps_writes_made_outside_the_transaction() {
+        let (_dir, db, transactions) = setup();
+        let spaces = Arc::new(SqliteSpaceRepository::new(db));
+
+        let outside = spaces.clone();
+        let (write_tx, write_rx) = tokio::sync::oneshot::channel();
+        let result: Result<()> = in_transaction(&transactions, |repos| async move {
+            repos.spaces.create(&Space::new("Doomed")).await?;
+            // Writes on the shared connection while the transaction is open
+            let _ = write_tx.send(tokio::spawn(async move {
+                outside.create(&Space::new("Other")).await
+            }));
+            std::thread::sleep(std::time::Duration::from_millis(50));
+            bail!("boom")
+        })
+        .await;
+        assert!(result.is_err());
+        write_rx.await.unwrap().await.unwrap().unwrap();
+
+        let listed = spaces.list().await.unwrap();
+        assert!(!has_space(&listed, "Doomed"));
+        assert!(has_space(&listed, "Other"));
+    }
+}
@@ -11,6 +11,10 @@ pub use mcpmux_core::{
 pub mod mocks;
 pub use mocks::MockRepositories;
 
+/// SQLite repository fixtures
+pub mod repos;
+pub use repos::SqliteRepos;
+
 /// Service test helpers
 pub mod services;
 pub use services::ServerManagerTestHarness;
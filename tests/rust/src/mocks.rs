@@ -313,6 +313,25 @@ impl ServerFeatureRepository for MockServerFeatureRepository {
         map.retain(|_, f| !(f.space_id == space_id && f.server_id == server_id));
         Ok(())
     }
+
+    async fn approve_pending_definition(&self, id: &Uuid) -> RepoResult<Option<ServerFeature>> {
+        let mut map = self.features.write().unwrap();
+        let Some(feature) = map.get_mut(id) else {
+            return Ok(None);
+        };
+        if let Some(pending) = feature.pending_definition.take() {
+            feature.definition_hash = Some(mcpmux_core::definition_hash(Some(&pending)));
+            feature.raw_json = Some(pending);
+        }
+        Ok(Some(feature.clone()))
+    }
+
+    async fn list_definition_versions(
+        &self,
+        _id: &Uuid,
+    ) -> RepoResult<Vec<mcpmux_core::FeatureDefinitionVersion>> {
+        Ok(vec![])
+    }
 }
 
 // ============================================================================
@@ -353,6 +372,17 @@ impl FeatureSetRepository for MockFeatureSetRepository {
             .collect())
     }
 
+    async fn list_global(&self) -> RepoResult<Vec<FeatureSet>> {
+        Ok(self
+            .sets
+            .read()
+            .unwrap()
+            .values()
+            .filter(|s| s.space_id.is_none())
+            .cloned()
+            .collect())
+    }
+
     async fn get(&self, id: &str) -> RepoResult<Option<FeatureSet>> {
         Ok(self.sets.read().unwrap().get(id).cloned())
     }
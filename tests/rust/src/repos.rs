@@ -0,0 +1,112 @@
+//! SQLite repository fixtures
+//!
+//! Wires every SQLite repository to one database in a temporary directory
+//! with a fresh master key, as a single McpMux installation would. The
+//! database is a file so transactions can open their own connections to it.
+
+use std::sync::Arc;
+
+use mcpmux_core::repository::{
+    AppSettingsRepository, CredentialRepository, FeatureSetRepository, InboundMcpClientRepository,
+    InstalledServerRepository, OutboundOAuthRepository, ServerFeatureRepository, SpaceRepository,
+    SpaceSecretRepository, TransactionManager,
+};
+use mcpmux_core::{InstalledServer, Space};
+use mcpmux_storage::{
+    generate_master_key, Database, FieldEncryptor, SqliteAppSettingsRepository,
+    SqliteCredentialRepository, SqliteFeatureSetRepository, SqliteInboundMcpClientRepository,
+    SqliteInstalledServerRepository, SqliteOutboundOAuthRepository, SqliteServerFeatureRepository,
+    SqliteSpaceRepository, SqliteSpaceSecretRepository, SqliteTransactionManager,
+};
+use tempfile::TempDir;
+use tokio::sync::Mutex;
+use uuid::Uuid;
+
+use crate::fixtures;
+
+/// All SQLite repositories over one database
+pub struct SqliteRepos {
+    pub db: Arc<Mutex<Database>>,
+    pub encryptor: Arc<FieldEncryptor>,
+    pub spaces: Arc<dyn SpaceRepository>,
+    pub servers: Arc<dyn InstalledServerRepository>,
+    pub features: Arc<dyn ServerFeatureRepository>,
+    pub feature_sets: Arc<dyn FeatureSetRepository>,
+    pub credentials: Arc<dyn CredentialRepository>,
+    pub oauth: Arc<dyn OutboundOAuthRepository>,
+    pub secrets: Arc<dyn SpaceSecretRepository>,
+    pub clients: Arc<dyn InboundMcpClientRepository>,
+    pub settings: Arc<dyn AppSettingsRepository>,
+    pub transactions: Arc<dyn TransactionManager>,
+    _temp_dir: TempDir,
+}
+
+impl SqliteRepos {
+    /// Create repositories over a new database and master key
+    pub fn new() -> Self {
+        let temp_dir = TempDir::new().expect("Failed to create temp dir");
+        let db_path = temp_dir.path().join("mcpmux.db");
+        let db = Arc::new(Mutex::new(
+            Database::open(&db_path).expect("Failed to open test database"),
+        ));
+        let key = generate_master_key().expect("Failed to generate key");
+        let encryptor = Arc::new(FieldEncryptor::new(&key).expect("Failed to create encryptor"));
+        Self {
+            spaces: Arc::new(SqliteSpaceRepository::new(db.clone())),
+            servers: Arc::new(SqliteInstalledServerRepository::new(
+                db.clone(),
+                encryptor.clone(),
+            )),
+            features: Arc::new(SqliteServerFeatureRepository::new(db.clone())),
+            feature_sets: Arc::new(SqliteFeatureSetRepository::new(db.clone())),
+            credentials: Arc::new(SqliteCredentialRepository::new(
+                db.clone(),
+                encryptor.clone(),
+            )),
+            oauth: Arc::new(SqliteOutboundOAuthRepository::new(db.clone())),
+            secrets: Arc::new(SqliteSpaceSecretRepository::new(
+                db.clone(),
+                encryptor.clone(),
+            )),
+            clients: Arc::new(SqliteInboundMcpClientRepository::new(db.clone())),
+            settings: Arc::new(SqliteAppSettingsRepository::new(db.clone())),
+            transactions: Arc::new(SqliteTransactionManager::new(db_path, encryptor.clone())),
+            db,
+            encryptor,
+            _temp_dir: temp_dir,
+        }
+    }
+
+    /// Create a space with its builtin feature sets
+    pub async fn seed_space(&self, name: &str) -> Space {
+        let space = fixtures::test_space(name);
+        self.spaces.create(&space).await.unwrap();
+        self.feature_sets
+            .ensure_builtin_for_space(&space.id.to_string())
+            .await
+            .unwrap();
+        space
+    }
+
+    /// Create a space holding a GitHub server whose `GITHUB_TOKEN` input is
+    /// `token`
+    pub async fn seed_github(&self, space_name: &str, token: &str) -> Uuid {
+        let space = self.seed_space(space_name).await;
+        self.servers
+            .install(&github_server(&space.id.to_string(), token))
+            .await
+            .unwrap();
+        space.id
+    }
+}
+
+impl Default for SqliteRepos {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A GitHub server whose `GITHUB_TOKEN` input is `token`
+pub fn github_server(space_id: &str, token: &str) -> InstalledServer {
+    fixtures::test_installed_server(space_id, "github").with_input("GITHUB_TOKEN", token)
+}
@@ -112,6 +112,46 @@ async fn test_update_feature_set() {
     assert_eq!(loaded.description, Some("New description".to_string()));
 }
 
+#[tokio::test]
+async fn test_read_only_flag_persists() {
+    let test_db = TestDatabase::new();
+    let db = Arc::new(Mutex::new(test_db.db));
+    let feature_repo = SqliteFeatureSetRepository::new(Arc::clone(&db));
+    let space_repo = SqliteSpaceRepository::new(db);
+
+    let mut space = fixtures::test_space("Test Space");
+    SpaceRepository::create(&space_repo, &space).await.unwrap();
+
+    let mut fs = fixtures::test_feature_set("Readers", &space.id.to_string()).with_read_only(true);
+    FeatureSetRepository::create(&feature_repo, &fs)
+        .await
+        .unwrap();
+    let loaded = FeatureSetRepository::get(&feature_repo, &fs.id)
+        .await
+        .unwrap()
+        .unwrap();
+    assert!(loaded.read_only);
+
+    fs.read_only = false;
+    FeatureSetRepository::update(&feature_repo, &fs)
+        .await
+        .unwrap();
+    let loaded = FeatureSetRepository::get(&feature_repo, &fs.id)
+        .await
+        .unwrap()
+        .unwrap();
+    assert!(!loaded.read_only);
+
+    // Spaces carry the same flag
+    space.read_only = true;
+    SpaceRepository::update(&space_repo, &space).await.unwrap();
+    let loaded = SpaceRepository::get(&space_repo, &space.id)
+        .await
+        .unwrap()
+        .unwrap();
+    assert!(loaded.read_only);
+}
+
 #[tokio::test]
 async fn test_delete_feature_set() {
     let test_db = TestDatabase::new();
@@ -4,9 +4,11 @@
 //! These test the INBOUND flow: AI clients (Cursor, Claude) connecting TO McpMux.
 
 use mcpmux_core::repository::SpaceRepository;
+use mcpmux_core::ClientRateLimit;
 use mcpmux_storage::{
-    AuthorizationCode, InboundClient, InboundClientRepository, RegistrationType,
-    SqliteSpaceRepository, TokenRecord, TokenType,
+    AuthorizationCode, ClientToolBudget, GrantLimits, InboundClient, InboundClientRepository,
+    RateLimitRepository, RegistrationType, SqliteSpaceRepository, TokenRecord, TokenType,
+    ToolUsageRepository,
 };
 use std::sync::Arc;
 use tests::{db::TestDatabase, fixtures};
@@ -37,6 +39,7 @@ fn create_test_client(name: &str) -> InboundClient {
         metadata_cache_ttl: None,
         connection_mode: "follow_active".to_string(),
         locked_space_id: None,
+        meta_tools: false,
         last_seen: None,
         created_at: now.clone(),
         updated_at: now,
@@ -778,6 +781,200 @@ async fn test_grants_per_space_isolation() {
     assert_eq!(personal_grants.len(), 1);
 }
 
+#[tokio::test]
+async fn test_expired_grant_is_inactive_and_removed() {
+    let test_db = TestDatabase::new();
+    let db = Arc::new(Mutex::new(test_db.db));
+    let repo = InboundClientRepository::new(Arc::clone(&db));
+    let space_repo = SqliteSpaceRepository::new(db);
+
+    let space = fixtures::test_space("Expiry");
+    SpaceRepository::create(&space_repo, &space).await.unwrap();
+    let client = create_test_client("Expiry Client");
+    repo.save_client(&client).await.unwrap();
+
+    let space_id = space.id.to_string();
+    let all_fs_id = format!("fs_all_{}", space.id);
+    let default_fs_id = format!("fs_default_{}", space.id);
+
+    // One grant already expired, one valid for an hour
+    let expired = GrantLimits {
+        expires_at: Some(chrono::Utc::now() - chrono::Duration::minutes(1)),
+        max_calls: None,
+    };
+    let valid = GrantLimits {
+        expires_at: Some(chrono::Utc::now() + chrono::Duration::hours(1)),
+        max_calls: None,
+    };
+    repo.grant_feature_set_with_limits(&client.client_id, &space_id, &all_fs_id, &expired)
+        .await
+        .unwrap();
+    repo.grant_feature_set_with_limits(&client.client_id, &space_id, &default_fs_id, &valid)
+        .await
+        .unwrap();
+
+    // Only the valid grant is active, but both are listed
+    let grants = repo
+        .get_grants_for_space(&client.client_id, &space_id)
+        .await
+        .unwrap();
+    assert_eq!(grants, vec![default_fs_id.clone()]);
+    let all_grants = repo.get_all_grants(&client.client_id).await.unwrap();
+    assert_eq!(
+        all_grants.get(&space_id).unwrap(),
+        &vec![default_fs_id.clone()]
+    );
+    let details = repo
+        .list_grants_for_space(&client.client_id, &space_id)
+        .await
+        .unwrap();
+    assert_eq!(details.len(), 2);
+
+    // Cleanup removes only the expired grant
+    let lapsed = repo.delete_lapsed_grants().await.unwrap();
+    assert_eq!(lapsed.len(), 1);
+    assert_eq!(lapsed[0].feature_set_id, all_fs_id);
+    let details = repo
+        .list_grants_for_space(&client.client_id, &space_id)
+        .await
+        .unwrap();
+    assert_eq!(details.len(), 1);
+    assert!(details[0].expires_at.is_some());
+}
+
+#[tokio::test]
+async fn test_call_limited_grant_is_exhausted() {
+    let test_db = TestDatabase::new();
+    let db = Arc::new(Mutex::new(test_db.db));
+    let repo = InboundClientRepository::new(Arc::clone(&db));
+    let space_repo = SqliteSpaceRepository::new(db);
+
+    let space = fixtures::test_space("Call Limit");
+    SpaceRepository::create(&space_repo, &space).await.unwrap();
+    let client = create_test_client("Limited Client");
+    repo.save_client(&client).await.unwrap();
+
+    let space_id = space.id.to_string();
+    let all_fs_id = format!("fs_all_{}", space.id);
+    let limits = GrantLimits {
+        expires_at: None,
+        max_calls: Some(2),
+    };
+    repo.grant_feature_set_with_limits(&client.client_id, &space_id, &all_fs_id, &limits)
+        .await
+        .unwrap();
+
+    let fs_ids = vec![all_fs_id.clone()];
+    let reserved = repo
+        .reserve_grant_calls(&client.client_id, &space_id, &fs_ids)
+        .await
+        .unwrap();
+    assert_eq!(reserved.len(), 1);
+    assert!(!reserved[0].is_lapsed(chrono::Utc::now()));
+    assert_eq!(
+        repo.get_grants_for_space(&client.client_id, &space_id)
+            .await
+            .unwrap()
+            .len(),
+        1
+    );
+
+    // Second call uses up the grant
+    let reserved = repo
+        .reserve_grant_calls(&client.client_id, &space_id, &fs_ids)
+        .await
+        .unwrap();
+    assert_eq!(reserved.len(), 1);
+    assert_eq!(reserved[0].call_count, 2);
+    assert!(reserved[0].is_lapsed(chrono::Utc::now()));
+    assert!(repo
+        .get_grants_for_space(&client.client_id, &space_id)
+        .await
+        .unwrap()
+        .is_empty());
+
+    // Further calls are refused
+    assert!(repo
+        .reserve_grant_calls(&client.client_id, &space_id, &fs_ids)
+        .await
+        .unwrap()
+        .is_empty());
+
+    // Re-granting resets the count
+    repo.grant_feature_set_with_limits(&client.client_id, &space_id, &all_fs_id, &limits)
+        .await
+        .unwrap();
+    let details = repo
+        .list_grants_for_space(&client.client_id, &space_id)
+        .await
+        .unwrap();
+    assert_eq!(details[0].call_count, 0);
+    assert_eq!(details[0].max_calls, Some(2));
+
+    // Re-granting without limits lifts them
+    repo.reserve_grant_calls(&client.client_id, &space_id, &fs_ids)
+        .await
+        .unwrap();
+    repo.grant_feature_set_with_limits(
+        &client.client_id,
+        &space_id,
+        &all_fs_id,
+        &GrantLimits::default(),
+    )
+    .await
+    .unwrap();
+    let details = repo
+        .list_grants_for_space(&client.client_id, &space_id)
+        .await
+        .unwrap();
+    assert_eq!(details[0].call_count, 0);
+    assert_eq!(details[0].max_calls, None);
+    assert_eq!(details[0].expires_at, None);
+}
+
+#[tokio::test]
+async fn test_concurrent_calls_never_exceed_grant_limit() {
+    let test_db = TestDatabase::new();
+    let db = Arc::new(Mutex::new(test_db.db));
+    let repo = Arc::new(InboundClientRepository::new(Arc::clone(&db)));
+    let space_repo = SqliteSpaceRepository::new(db);
+
+    let space = fixtures::test_space("Concurrent Limit");
+    SpaceRepository::create(&space_repo, &space).await.unwrap();
+    let client = create_test_client("Busy Client");
+    repo.save_client(&client).await.unwrap();
+
+    let space_id = space.id.to_string();
+    let all_fs_id = format!("fs_all_{}", space.id);
+    let limits = GrantLimits {
+        expires_at: None,
+        max_calls: Some(3),
+    };
+    repo.grant_feature_set_with_limits(&client.client_id, &space_id, &all_fs_id, &limits)
+        .await
+        .unwrap();
+
+    let calls: Vec<_> = (0..10)
+        .map(|_| {
+            let repo = Arc::clone(&repo);
+            let client_id = client.client_id.clone();
+            let space_id = space_id.clone();
+            let fs_ids = vec![all_fs_id.clone()];
+            tokio::spawn(async move {
+                repo.reserve_grant_calls(&client_id, &space_id, &fs_ids)
+                    .await
+                    .unwrap()
+                    .len()
+            })
+        })
+        .collect();
+    let mut admitted = 0;
+    for call in calls {
+        admitted += call.await.unwrap();
+    }
+    assert_eq!(admitted, 3);
+}
+
 // =============================================================================
 // Client Settings Update Tests
 // =============================================================================
@@ -803,6 +1000,7 @@ async fn test_update_client_settings() {
             Some("My Cursor".to_string()),    // alias
             Some("locked".to_string()),       // connection_mode
             Some(Some(space.id.to_string())), // locked_space_id
+            Some(true),                       // meta_tools
         )
         .await
         .expect("Failed to update settings");
@@ -812,6 +1010,12 @@ async fn test_update_client_settings() {
     assert_eq!(updated.client_alias, Some("My Cursor".to_string()));
     assert_eq!(updated.connection_mode, "locked");
     assert_eq!(updated.locked_space_id, Some(space.id.to_string()));
+    assert!(updated.meta_tools);
+
+    // Re-registration keeps the meta-tool setting
+    repo.save_client(&client).await.unwrap();
+    let reloaded = repo.get_client(&client.client_id).await.unwrap().unwrap();
+    assert!(reloaded.meta_tools);
 }
 
 #[tokio::test]
@@ -836,3 +1040,146 @@ async fn test_update_last_seen() {
     let updated = repo.get_client(&client.client_id).await.unwrap().unwrap();
     assert!(updated.last_seen.is_some());
 }
+
+// =============================================================================
+// Tool Budget Tests
+// =============================================================================
+
+#[tokio::test]
+async fn test_tool_budget_roundtrip() {
+    let test_db = TestDatabase::new();
+    let db = Arc::new(Mutex::new(test_db.db));
+    let repo = InboundClientRepository::new(db);
+
+    let client = create_test_client("Budget Client");
+    repo.save_client(&client).await.unwrap();
+
+    // No budget by default
+    assert!(repo
+        .get_tool_budget(&client.client_id)
+        .await
+        .unwrap()
+        .is_none());
+
+    let budget = ClientToolBudget {
+        max_tools: Some(40),
+        pinned_tools: vec!["github:tool:create_issue".to_string()],
+        server_priority: vec!["github".to_string(), "slack".to_string()],
+    };
+    repo.set_tool_budget(&client.client_id, &budget)
+        .await
+        .unwrap();
+    assert_eq!(
+        repo.get_tool_budget(&client.client_id).await.unwrap(),
+        Some(budget)
+    );
+
+    // Updating replaces the budget
+    let unlimited = ClientToolBudget::default();
+    repo.set_tool_budget(&client.client_id, &unlimited)
+        .await
+        .unwrap();
+    assert_eq!(
+        repo.get_tool_budget(&client.client_id).await.unwrap(),
+        Some(unlimited)
+    );
+
+    // Deleting the client removes its budget
+    repo.delete_client(&client.client_id).await.unwrap();
+    assert!(repo
+        .get_tool_budget(&client.client_id)
+        .await
+        .unwrap()
+        .is_none());
+}
+
+#[tokio::test]
+async fn test_tool_usage_counts_calls() {
+    let test_db = TestDatabase::new();
+    let db = Arc::new(Mutex::new(test_db.db));
+    let usage_repo = ToolUsageRepository::new(Arc::clone(&db));
+    let space_repo = SqliteSpaceRepository::new(db);
+
+    let space = fixtures::test_space("Usage Space");
+    SpaceRepository::create(&space_repo, &space).await.unwrap();
+    let space_id = space.id.to_string();
+
+    usage_repo
+        .record_call(&space_id, "github", "create_issue")
+        .await
+        .unwrap();
+    usage_repo
+        .record_call(&space_id, "github", "create_issue")
+        .await
+        .unwrap();
+    usage_repo
+        .record_call(&space_id, "github", "list_issues")
+        .await
+        .unwrap();
+
+    let usage = usage_repo.get_usage_for_space(&space_id).await.unwrap();
+    assert_eq!(usage.len(), 2);
+    assert_eq!(
+        usage[&("github".to_string(), "create_issue".to_string())].call_count,
+        2
+    );
+    assert_eq!(
+        usage[&("github".to_string(), "list_issues".to_string())].call_count,
+        1
+    );
+}
+
+// =============================================================================
+// Rate Limit Tests
+// =============================================================================
+
+#[tokio::test]
+async fn test_rate_limit_usage_persists() {
+    let test_db = TestDatabase::new();
+    let db = Arc::new(Mutex::new(test_db.db));
+    let client_repo = InboundClientRepository::new(Arc::clone(&db));
+    let repo = RateLimitRepository::new(db);
+
+    let client = create_test_client("Limited Client");
+    client_repo.save_client(&client).await.unwrap();
+
+    let limit = ClientRateLimit {
+        client_id: client.client_id.clone(),
+        server_id: "github".to_string(),
+        tool_name: Some("create_issue".to_string()),
+        per_minute: Some(10),
+        burst: None,
+        daily_quota: Some(2),
+        usage: None,
+    };
+    repo.set(&limit).await.unwrap();
+
+    // Counters survive a reload
+    let now = chrono::Utc::now();
+    let usage = limit.try_acquire(now).unwrap();
+    repo.save_usage(&limit, &usage).await.unwrap();
+
+    let limits = repo.list_for_client(&client.client_id).await.unwrap();
+    assert_eq!(limits.len(), 1);
+    assert_eq!(limits[0].tool_name.as_deref(), Some("create_issue"));
+    assert_eq!(limits[0].quota_remaining(now), Some(1));
+
+    // Updating the limit keeps its counters
+    repo.set(&ClientRateLimit {
+        daily_quota: Some(5),
+        ..limit.clone()
+    })
+    .await
+    .unwrap();
+    let limits = repo.list_for_client(&client.client_id).await.unwrap();
+    assert_eq!(limits[0].quota_remaining(now), Some(4));
+
+    repo.delete(&client.client_id, "github", Some("create_issue"))
+        .await
+        .unwrap();
+    assert!(repo
+        .list_for_client(&client.client_id)
+        .await
+        .unwrap()
+        .is_empty());
+}
@@ -1,6 +1,7 @@
 //! InstalledServerRepository integration tests
 
 use mcpmux_core::repository::{InstalledServerRepository, SpaceRepository};
+use mcpmux_core::{ConcurrencyLimit, InstallationSource};
 use mcpmux_storage::{
     generate_master_key, FieldEncryptor, SqliteInstalledServerRepository, SqliteSpaceRepository,
 };
@@ -441,6 +442,156 @@ async fn test_installed_server_env_overrides_persist() {
     assert_eq!(loaded.env_overrides.get("DEBUG"), Some(&"true".to_string()));
 }
 
+#[tokio::test]
+async fn test_installed_server_concurrency_limit_persist() {
+    let test_db = TestDatabase::new();
+    let db = Arc::new(Mutex::new(test_db.db));
+    let server_repo = SqliteInstalledServerRepository::new(Arc::clone(&db), test_encryptor());
+    let space_repo = SqliteSpaceRepository::new(db);
+
+    let space = fixtures::test_space("Test Space");
+    SpaceRepository::create(&space_repo, &space).await.unwrap();
+
+    let mut server = fixtures::test_installed_server(&space.id.to_string(), "sqlite-server");
+    let server_id = server.id;
+    InstalledServerRepository::install(&server_repo, &server)
+        .await
+        .expect("Failed to install server");
+
+    let loaded = InstalledServerRepository::get(&server_repo, &server_id)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(loaded.concurrency_limit, None);
+
+    // Set a limit via update, then clear it again
+    server.concurrency_limit = Some(ConcurrencyLimit {
+        max_concurrent_requests: 1,
+        max_queued_requests: 8,
+        queue_timeout_secs: 10,
+    });
+    InstalledServerRepository::update(&server_repo, &server)
+        .await
+        .unwrap();
+    let loaded = InstalledServerRepository::get(&server_repo, &server_id)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(loaded.concurrency_limit, server.concurrency_limit);
+
+    server.concurrency_limit = None;
+    InstalledServerRepository::update(&server_repo, &server)
+        .await
+        .unwrap();
+    let loaded = InstalledServerRepository::get(&server_repo, &server_id)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(loaded.concurrency_limit, None);
+}
+
+#[tokio::test]
+async fn test_installed_server_skip_argument_validation_persist() {
+    let test_db = TestDatabase::new();
+    let db = Arc::new(Mutex::new(test_db.db));
+    let server_repo = SqliteInstalledServerRepository::new(Arc::clone(&db), test_encryptor());
+    let space_repo = SqliteSpaceRepository::new(db);
+
+    let space = fixtures::test_space("Test Space");
+    SpaceRepository::create(&space_repo, &space).await.unwrap();
+
+    let mut server = fixtures::test_installed_server(&space.id.to_string(), "sqlite-server");
+    let server_id = server.id;
+    InstalledServerRepository::install(&server_repo, &server)
+        .await
+        .expect("Failed to install server");
+
+    let loaded = InstalledServerRepository::get(&server_repo, &server_id)
+        .await
+        .unwrap()
+        .unwrap();
+    assert!(!loaded.skip_argument_validation);
+
+    server.skip_argument_validation = true;
+    InstalledServerRepository::update(&server_repo, &server)
+        .await
+        .unwrap();
+    let loaded = InstalledServerRepository::get(&server_repo, &server_id)
+        .await
+        .unwrap()
+        .unwrap();
+    assert!(loaded.skip_argument_validation);
+}
+
+#[tokio::test]
+async fn test_installed_server_launch_overrides_persist() {
+    let test_db = TestDatabase::new();
+    let db = Arc::new(Mutex::new(test_db.db));
+    let server_repo = SqliteInstalledServerRepository::new(Arc::clone(&db), test_encryptor());
+    let space_repo = SqliteSpaceRepository::new(db);
+
+    let space = fixtures::test_space("Test Space");
+    SpaceRepository::create(&space_repo, &space).await.unwrap();
+
+    let mut server = fixtures::test_installed_server(&space.id.to_string(), "project-server");
+    let server_id = server.id;
+    InstalledServerRepository::install(&server_repo, &server)
+        .await
+        .expect("Failed to install server");
+
+    let loaded = InstalledServerRepository::get(&server_repo, &server_id)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(loaded.cwd_override, None);
+    assert_eq!(loaded.env_file_override, None);
+
+    server.cwd_override = Some("/srv/project".to_string());
+    server.env_file_override = Some("/srv/project/.env".to_string());
+    InstalledServerRepository::update(&server_repo, &server)
+        .await
+        .unwrap();
+    let loaded = InstalledServerRepository::get(&server_repo, &server_id)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(loaded.cwd_override.as_deref(), Some("/srv/project"));
+    assert_eq!(
+        loaded.env_file_override.as_deref(),
+        Some("/srv/project/.env")
+    );
+}
+
+#[tokio::test]
+async fn test_installed_server_imported_source_persist() {
+    let test_db = TestDatabase::new();
+    let db = Arc::new(Mutex::new(test_db.db));
+    let server_repo = SqliteInstalledServerRepository::new(Arc::clone(&db), test_encryptor());
+    let space_repo = SqliteSpaceRepository::new(db);
+
+    let space = fixtures::test_space("Test Space");
+    SpaceRepository::create(&space_repo, &space).await.unwrap();
+
+    let server = fixtures::test_installed_server(&space.id.to_string(), "imported-server")
+        .with_source(InstallationSource::Imported {
+            client: "cursor".to_string(),
+        });
+    InstalledServerRepository::install(&server_repo, &server)
+        .await
+        .expect("Failed to install server");
+
+    let loaded = InstalledServerRepository::get(&server_repo, &server.id)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        loaded.source,
+        InstallationSource::Imported {
+            client: "cursor".to_string()
+        }
+    );
+}
+
 #[tokio::test]
 async fn test_installed_server_args_append_persist() {
     let test_db = TestDatabase::new();
@@ -8,6 +8,10 @@
 //! - InboundClient repository (DCR, OAuth tokens, grants)
 //! - FeatureSet repository (builtin types, members)
 //! - Outbound OAuth repository (server credentials)
+//! - Space bundles (export/import between machines)
+//! - Space cloning and templates
+//! - Space secrets (shared, encrypted)
+//! - Workspace manifests (plan/apply)
 
 mod feature_set;
 mod inbound_client;
@@ -15,3 +19,7 @@ mod installed_server;
 mod migrations;
 mod outbound_oauth;
 mod repositories;
+mod space_bundle;
+mod space_clone;
+mod space_secret;
+mod workspace_manifest;
@@ -1,13 +1,20 @@
 //! Repository integration tests
 
-use mcpmux_storage::SqliteSpaceRepository;
+use mcpmux_storage::{
+    ArgumentPolicyRepository, FeatureOverrideRepository, SqliteFeatureSetRepository,
+    SqliteSpaceRepository, ToolApprovalRepository,
+};
 use pretty_assertions::assert_eq;
 use std::sync::Arc;
 use tests::{db::TestDatabase, fixtures};
 use tokio::sync::Mutex;
 
 // Import the trait to use its methods
-use mcpmux_core::repository::SpaceRepository;
+use mcpmux_core::repository::{FeatureSetRepository, SpaceRepository};
+use mcpmux_core::{
+    ApprovalPolicy, ApprovalTarget, ArgumentPolicy, FeatureOverride, ServerFeature,
+    ToolApprovalRule, ToolArgumentPolicy,
+};
 
 #[tokio::test]
 async fn test_space_repository_create_and_get() {
@@ -241,3 +248,171 @@ async fn test_space_repository_concurrent_writes() {
         .count();
     assert_eq!(concurrent_count, 5);
 }
+
+#[tokio::test]
+async fn test_tool_approval_rules_upsert_and_delete() {
+    let test_db = TestDatabase::new();
+    let db = Arc::new(Mutex::new(test_db.db));
+    let space_repo = SqliteSpaceRepository::new(Arc::clone(&db));
+    let repo = ToolApprovalRepository::new(db);
+
+    let space = fixtures::test_space("Approval Space");
+    SpaceRepository::create(&space_repo, &space).await.unwrap();
+    let space_id = space.id.to_string();
+
+    let mut rule = ToolApprovalRule {
+        space_id: space_id.clone(),
+        target_type: ApprovalTarget::FeatureSet,
+        target: "fs_writes".to_string(),
+        policy: ApprovalPolicy::Ask,
+    };
+    repo.set(&rule).await.unwrap();
+
+    // Setting the same target again replaces the policy
+    rule.policy = ApprovalPolicy::Deny;
+    repo.set(&rule).await.unwrap();
+
+    let rules = repo.list_for_space(&space_id).await.unwrap();
+    assert_eq!(rules, vec![rule]);
+
+    repo.delete(&space_id, ApprovalTarget::FeatureSet, "fs_writes")
+        .await
+        .unwrap();
+    assert!(repo.list_for_space(&space_id).await.unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_argument_policies_upsert_and_delete() {
+    let test_db = TestDatabase::new();
+    let db = Arc::new(Mutex::new(test_db.db));
+    let space_repo = SqliteSpaceRepository::new(Arc::clone(&db));
+    let feature_set_repo = SqliteFeatureSetRepository::new(Arc::clone(&db));
+    let repo = ArgumentPolicyRepository::new(db);
+
+    let space = fixtures::test_space("Policy Space");
+    SpaceRepository::create(&space_repo, &space).await.unwrap();
+    let feature_set = fixtures::test_feature_set("Issues", &space.id.to_string());
+    FeatureSetRepository::create(&feature_set_repo, &feature_set)
+        .await
+        .unwrap();
+
+    let mut policy = ToolArgumentPolicy {
+        feature_set_id: feature_set.id.clone(),
+        feature_id: "feature-create-issue".to_string(),
+        policy: serde_json::from_value(serde_json::json!({
+            "fixed": { "owner": "acme" },
+            "forbidden": ["assignees"]
+        }))
+        .unwrap(),
+    };
+    repo.set(&policy).await.unwrap();
+
+    // Setting the same tool again replaces the policy
+    policy.policy = ArgumentPolicy {
+        forbidden: vec!["labels".to_string()],
+        ..Default::default()
+    };
+    repo.set(&policy).await.unwrap();
+
+    let policies = repo
+        .list_for_feature_sets(std::slice::from_ref(&feature_set.id))
+        .await
+        .unwrap();
+    assert_eq!(policies, vec![policy.clone()]);
+    assert!(repo
+        .list_for_feature_sets(&["other".to_string()])
+        .await
+        .unwrap()
+        .is_empty());
+
+    repo.delete(&feature_set.id, &policy.feature_id)
+        .await
+        .unwrap();
+    assert!(repo
+        .list_for_feature_sets(&[feature_set.id])
+        .await
+        .unwrap()
+        .is_empty());
+}
+
+#[tokio::test]
+async fn test_feature_overrides_per_space_and_feature_set() {
+    let test_db = TestDatabase::new();
+    let db = Arc::new(Mutex::new(test_db.db));
+    let space_repo = SqliteSpaceRepository::new(Arc::clone(&db));
+    let feature_set_repo = SqliteFeatureSetRepository::new(Arc::clone(&db));
+    let repo = FeatureOverrideRepository::new(db);
+
+    let space = fixtures::test_space("Override Space");
+    SpaceRepository::create(&space_repo, &space).await.unwrap();
+    let space_id = space.id.to_string();
+    let feature_set = fixtures::test_feature_set("Research", &space_id);
+    FeatureSetRepository::create(&feature_set_repo, &feature_set)
+        .await
+        .unwrap();
+
+    let tool = ServerFeature::tool(&space_id, "github", "search_repositories");
+    let mut space_wide = FeatureOverride::for_feature(&tool, None);
+    space_wide.name = Some("find_repos".to_string());
+    repo.set(&space_wide).await.unwrap();
+
+    // Setting the same target again replaces it; a feature set override is separate
+    space_wide.description = Some("Find repositories".to_string());
+    repo.set(&space_wide).await.unwrap();
+    let mut for_set = FeatureOverride::for_feature(&tool, Some(feature_set.id.clone()));
+    for_set.title = Some("Repository search".to_string());
+    repo.set(&for_set).await.unwrap();
+
+    let overrides = repo.list_for_space(&space_id).await.unwrap();
+    assert_eq!(overrides.len(), 2);
+    let loaded = overrides
+        .iter()
+        .find(|o| o.feature_set_id.is_none())
+        .unwrap();
+    assert_eq!(loaded.name.as_deref(), Some("find_repos"));
+    assert_eq!(loaded.description.as_deref(), Some("Find repositories"));
+    assert!(loaded.targets(&tool));
+
+    repo.delete(
+        &space_id,
+        None,
+        "github",
+        &tool.feature_type,
+        "search_repositories",
+    )
+    .await
+    .unwrap();
+    let overrides = repo.list_for_space(&space_id).await.unwrap();
+    assert_eq!(overrides.len(), 1);
+    assert_eq!(overrides[0].feature_set_id.as_ref(), Some(&feature_set.id));
+}
+
+#[tokio::test]
+async fn test_feature_override_names_are_unique_per_space() {
+    let test_db = TestDatabase::new();
+    let db = Arc::new(Mutex::new(test_db.db));
+    let space_repo = SqliteSpaceRepository::new(Arc::clone(&db));
+    let repo = FeatureOverrideRepository::new(db);
+
+    let space = fixtures::test_space("Override Names");
+    SpaceRepository::create(&space_repo, &space).await.unwrap();
+    let other = fixtures::test_space("Other Space");
+    SpaceRepository::create(&space_repo, &other).await.unwrap();
+    let space_id = space.id.to_string();
+
+    let search = ServerFeature::tool(&space_id, "github", "search_repositories");
+    let mut first = FeatureOverride::for_feature(&search, None);
+    first.name = Some("find".to_string());
+    repo.set(&first).await.unwrap();
+
+    let list = ServerFeature::tool(&space_id, "github", "list_repositories");
+    let mut second = FeatureOverride::for_feature(&list, None);
+    second.name = Some("find".to_string());
+    assert!(repo.set(&second).await.is_err());
+
+    // Other spaces have their own names
+    let elsewhere = ServerFeature::tool(other.id.to_string(), "github", "list_repositories");
+    let mut third = FeatureOverride::for_feature(&elsewhere, None);
+    third.name = Some("find".to_string());
+    repo.set(&third).await.unwrap();
+}
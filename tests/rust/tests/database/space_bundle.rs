@@ -0,0 +1,273 @@
+//! Space bundle export/import integration tests
+//!
+//! Exports spaces from one database and imports them into another with a
+//! different master key, as when moving to a new machine.
+
+use mcpmux_core::domain::{
+    Credential, CredentialType, MemberMode, OutboundOAuthRegistration, SpaceSecret,
+};
+use mcpmux_core::{BundleImportOptions, FeatureSetMember, ServerConflict, SpaceBundleService};
+use mcpmux_storage::{open_space_bundle, seal_space_bundle};
+use std::collections::HashMap;
+use tests::{features, fixtures, SqliteRepos};
+
+/// Repositories of one McpMux installation, with its own master key
+type Machine = SqliteRepos;
+
+fn bundles(machine: &Machine) -> SpaceBundleService {
+    SpaceBundleService::new(
+        machine.spaces.clone(),
+        machine.servers.clone(),
+        machine.features.clone(),
+        machine.feature_sets.clone(),
+        machine.credentials.clone(),
+        machine.oauth.clone(),
+        machine.secrets.clone(),
+    )
+    .with_transactions(machine.transactions.clone())
+}
+
+/// Create a space with a GitHub server, a tool, a custom feature set and an
+/// OAuth refresh token
+async fn seed(machine: &Machine) -> uuid::Uuid {
+    let id = machine.seed_github("Work", "ghp_secret").await;
+    let space_id = id.to_string();
+
+    let tool = features::test_tool(&space_id, "github", "create_issue");
+    machine.features.upsert(&tool).await.unwrap();
+
+    let mut custom = fixtures::test_feature_set("Issues", &space_id);
+    custom.members = vec![
+        FeatureSetMember::include_feature(&custom.id, &tool.id.to_string()),
+        FeatureSetMember::rule(&custom.id, "github:list_*", MemberMode::Include),
+    ];
+    machine.feature_sets.create(&custom).await.unwrap();
+
+    machine
+        .oauth
+        .save(&OutboundOAuthRegistration::new(
+            id,
+            "github",
+            "https://api.githubcopilot.com/mcp",
+            "dcr_client",
+            "http://127.0.0.1:9876/callback",
+        ))
+        .await
+        .unwrap();
+    machine
+        .credentials
+        .save(&Credential::refresh_token(
+            id,
+            "github",
+            "refresh_secret",
+            None,
+        ))
+        .await
+        .unwrap();
+
+    id
+}
+
+#[tokio::test]
+async fn test_bundle_roundtrip_to_new_machine() {
+    let source = Machine::new();
+    let space_id = seed(&source).await;
+
+    let bundle = bundles(&source).export(&[space_id], true).await.unwrap();
+    let sealed = seal_space_bundle(&bundle, "moving day passphrase").unwrap();
+    assert!(!sealed.contains("ghp_secret"));
+
+    let target = Machine::new();
+    let opened = open_space_bundle(&sealed, "moving day passphrase").unwrap();
+    let report = bundles(&target)
+        .import(opened, &BundleImportOptions::default())
+        .await
+        .unwrap();
+
+    let imported = &report.spaces[0];
+    assert!(imported.created);
+    assert_ne!(imported.space_id, space_id);
+    assert_eq!(imported.servers, vec!["github".to_string()]);
+    assert_eq!(imported.feature_sets, 1);
+    assert_eq!(imported.oauth_tokens, 1);
+
+    let new_space_id = imported.space_id.to_string();
+    let server = target
+        .servers
+        .get_by_server_id(&new_space_id, "github")
+        .await
+        .unwrap()
+        .expect("server imported");
+    assert_eq!(server.input_values["GITHUB_TOKEN"], "ghp_secret");
+    assert!(server.oauth_connected);
+
+    // Custom set members point at the new feature ID
+    let tool = target
+        .features
+        .list_for_server(&new_space_id, "github")
+        .await
+        .unwrap()
+        .remove(0);
+    let sets = target
+        .feature_sets
+        .list_by_space(&new_space_id)
+        .await
+        .unwrap();
+    let custom = sets.iter().find(|s| s.name == "Issues").unwrap();
+    let members = target
+        .feature_sets
+        .get_with_members(&custom.id)
+        .await
+        .unwrap()
+        .unwrap()
+        .members;
+    assert!(members.iter().any(|m| m.member_id == tool.id.to_string()));
+    assert!(members.iter().any(|m| m.member_id == "github:list_*"));
+
+    let token = target
+        .credentials
+        .get(&imported.space_id, "github", &CredentialType::RefreshToken)
+        .await
+        .unwrap()
+        .expect("refresh token imported");
+    assert_eq!(token.value, "refresh_secret");
+}
+
+#[tokio::test]
+async fn test_bundle_carries_space_secrets() {
+    let source = Machine::new();
+    let space_id = seed(&source).await;
+    source
+        .secrets
+        .save(&SpaceSecret::new(space_id, "sentry_token", "sntrys_secret"))
+        .await
+        .unwrap();
+    let mut server = source
+        .servers
+        .get_by_server_id(&space_id.to_string(), "github")
+        .await
+        .unwrap()
+        .unwrap();
+    server.env_overrides = HashMap::from([
+        (
+            "SENTRY_TOKEN".to_string(),
+            "${secret:sentry_token}".to_string(),
+        ),
+        (
+            "LINEAR_TOKEN".to_string(),
+            "${secret:linear_token}".to_string(),
+        ),
+    ]);
+    source.servers.update(&server).await.unwrap();
+
+    let bundle = bundles(&source).export(&[space_id], false).await.unwrap();
+    let target = Machine::new();
+    let report = bundles(&target)
+        .import(bundle, &BundleImportOptions::default())
+        .await
+        .unwrap();
+
+    let imported = &report.spaces[0];
+    assert_eq!(imported.secrets, 1);
+    assert_eq!(imported.missing_secrets, vec!["linear_token".to_string()]);
+    let secret = target
+        .secrets
+        .get(&imported.space_id, "sentry_token")
+        .await
+        .unwrap()
+        .expect("secret imported");
+    assert_eq!(secret.value, "sntrys_secret");
+}
+
+#[tokio::test]
+async fn test_bundle_without_tokens() {
+    let source = Machine::new();
+    let space_id = seed(&source).await;
+
+    let bundle = bundles(&source).export(&[space_id], false).await.unwrap();
+    assert!(bundle.spaces[0].oauth.is_empty());
+}
+
+#[tokio::test]
+async fn test_bundle_import_into_space_renames_conflicts() {
+    let machine = Machine::new();
+    let space_id = seed(&machine).await;
+    let bundle = bundles(&machine).export(&[space_id], false).await.unwrap();
+
+    let options = BundleImportOptions {
+        target_space_id: Some(space_id),
+        on_conflict: ServerConflict::Rename,
+    };
+    let report = bundles(&machine).import(bundle, &options).await.unwrap();
+
+    let imported = &report.spaces[0];
+    assert!(!imported.created);
+    assert_eq!(imported.servers, vec!["github-imported".to_string()]);
+    let servers = machine
+        .servers
+        .list_for_space(&space_id.to_string())
+        .await
+        .unwrap();
+    assert_eq!(servers.len(), 2);
+}
+
+#[tokio::test]
+async fn test_bundle_import_into_space_skips_conflicts() {
+    let machine = Machine::new();
+    let space_id = seed(&machine).await;
+    let bundle = bundles(&machine).export(&[space_id], false).await.unwrap();
+
+    let options = BundleImportOptions {
+        target_space_id: Some(space_id),
+        on_conflict: ServerConflict::Skip,
+    };
+    let report = bundles(&machine).import(bundle, &options).await.unwrap();
+
+    assert_eq!(report.spaces[0].skipped, vec!["github".to_string()]);
+    assert!(report.spaces[0].servers.is_empty());
+}
+
+#[tokio::test]
+async fn test_bundle_replace_drops_old_credentials() {
+    let machine = Machine::new();
+    let space_id = seed(&machine).await;
+    let bundle = bundles(&machine).export(&[space_id], false).await.unwrap();
+
+    let options = BundleImportOptions {
+        target_space_id: Some(space_id),
+        on_conflict: ServerConflict::Replace,
+    };
+    let report = bundles(&machine).import(bundle, &options).await.unwrap();
+    assert_eq!(report.spaces[0].servers, vec!["github".to_string()]);
+
+    let token = machine
+        .credentials
+        .get(&space_id, "github", &CredentialType::RefreshToken)
+        .await
+        .unwrap();
+    assert!(token.is_none());
+    assert!(machine
+        .oauth
+        .get(&space_id, "github")
+        .await
+        .unwrap()
+        .is_none());
+}
+
+#[tokio::test]
+async fn test_failed_bundle_import_leaves_nothing_behind() {
+    let source = Machine::new();
+    let space_id = seed(&source).await;
+    let mut bundle = bundles(&source).export(&[space_id], false).await.unwrap();
+    // A second server with the same ID fails after the space was created
+    let duplicate = bundle.spaces[0].servers[0].clone();
+    bundle.spaces[0].servers.push(duplicate);
+
+    let target = Machine::new();
+    let before = target.spaces.list().await.unwrap().len();
+    assert!(bundles(&target)
+        .import(bundle, &BundleImportOptions::default())
+        .await
+        .is_err());
+    assert_eq!(target.spaces.list().await.unwrap().len(), before);
+}
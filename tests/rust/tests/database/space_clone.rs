@@ -0,0 +1,360 @@
+//! Space cloning and template integration tests
+//!
+//! Clones spaces within one database and saves/instantiates templates from a
+//! temporary templates directory.
+
+use mcpmux_core::domain::{
+    Client, Credential, CredentialType, MemberMode, SpaceSecret, UserServerEntry,
+};
+use mcpmux_core::repository::InboundMcpClientRepository;
+use mcpmux_core::{
+    FeatureSetMember, OutboundOAuthRegistration, SpaceBundleService, SpaceCloneOptions,
+    SpaceService,
+};
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::Arc;
+use tests::repos::github_server;
+use tests::{features, fixtures, SqliteRepos};
+
+struct Fixture {
+    repos: SqliteRepos,
+    clients: Arc<dyn InboundMcpClientRepository>,
+    service: SpaceService,
+    _templates: tempfile::TempDir,
+}
+
+impl Fixture {
+    fn new() -> Self {
+        let repos = SqliteRepos::new();
+        let bundles = Arc::new(
+            SpaceBundleService::new(
+                repos.spaces.clone(),
+                repos.servers.clone(),
+                repos.features.clone(),
+                repos.feature_sets.clone(),
+                repos.credentials.clone(),
+                repos.oauth.clone(),
+                repos.secrets.clone(),
+            )
+            .with_transactions(repos.transactions.clone()),
+        );
+        let clients = repos.clients.clone();
+        let templates = tempfile::tempdir().expect("Failed to create temp dir");
+        let service = SpaceService::with_feature_set_repository(
+            repos.spaces.clone(),
+            repos.feature_sets.clone(),
+        )
+        .with_cloning(bundles, clients.clone(), templates.path());
+
+        Self {
+            repos,
+            clients,
+            service,
+            _templates: templates,
+        }
+    }
+
+    /// Create a space with a GitHub server (with a secret token input, a
+    /// literal secret env override, a space secret header and an OAuth
+    /// connection), a tool and a custom feature set granted to Cursor
+    async fn seed(&self) -> uuid::Uuid {
+        self.clients.create(&Client::cursor()).await.unwrap();
+        let space = self.repos.seed_space("Client A").await;
+        let space_id = space.id.to_string();
+
+        let definition = UserServerEntry {
+            command: Some("npx".to_string()),
+            args: None,
+            env: Some(HashMap::from([(
+                "GITHUB_TOKEN".to_string(),
+                "${input:GITHUB_TOKEN}".to_string(),
+            )])),
+            cwd: None,
+            env_file: None,
+            url: None,
+            headers: None,
+            name: Some("GitHub".to_string()),
+            description: None,
+            icon: None,
+            alias: None,
+            auth: None,
+            metadata: None,
+        }
+        .to_server_definition("github", &space_id, PathBuf::from("/tmp/space.json"));
+        let mut server = github_server(&space_id, "ghp_secret").with_definition(&definition);
+        server.env_overrides = HashMap::from([
+            ("LOG_LEVEL".to_string(), "debug".to_string()),
+            ("SENTRY_AUTH_TOKEN".to_string(), "sntrys_secret".to_string()),
+        ]);
+        server.extra_headers = HashMap::from([(
+            "X-Linear-Key".to_string(),
+            "${secret:linear_token}".to_string(),
+        )]);
+        self.repos
+            .secrets
+            .save(&SpaceSecret::new(space.id, "linear_token", "lin_secret"))
+            .await
+            .unwrap();
+        self.repos.servers.install(&server).await.unwrap();
+
+        let tool = features::test_tool(&space_id, "github", "create_issue");
+        self.repos.features.upsert(&tool).await.unwrap();
+
+        let mut custom = fixtures::test_feature_set("Issues", &space_id);
+        custom.members = vec![
+            FeatureSetMember::include_feature(&custom.id, &tool.id.to_string()),
+            FeatureSetMember::rule(&custom.id, "github:list_*", MemberMode::Include),
+        ];
+        self.repos.feature_sets.create(&custom).await.unwrap();
+
+        let client = self.cursor().await;
+        self.clients
+            .set_grants_for_space(&client.id, &space_id, &[custom.id.clone()])
+            .await
+            .unwrap();
+
+        self.repos
+            .oauth
+            .save(&OutboundOAuthRegistration::new(
+                space.id,
+                "github",
+                "https://api.githubcopilot.com/mcp",
+                "dcr_client",
+                "http://127.0.0.1:9876/callback",
+            ))
+            .await
+            .unwrap();
+        self.repos
+            .credentials
+            .save(&Credential::refresh_token(
+                space.id,
+                "github",
+                "refresh_secret",
+                None,
+            ))
+            .await
+            .unwrap();
+        let server = self
+            .repos
+            .servers
+            .get_by_server_id(&space_id, "github")
+            .await
+            .unwrap()
+            .unwrap();
+        self.repos
+            .servers
+            .set_oauth_connected(&server.id, true)
+            .await
+            .unwrap();
+
+        space.id
+    }
+
+    async fn cursor(&self) -> Client {
+        self.clients.list().await.unwrap().remove(0)
+    }
+}
+
+#[tokio::test]
+async fn test_clone_space_with_secrets_and_grants() {
+    let fixture = Fixture::new();
+    let space_id = fixture.seed().await;
+
+    let options = SpaceCloneOptions {
+        name: "Client B".to_string(),
+        include_secrets: true,
+        include_grants: true,
+    };
+    let cloned = fixture
+        .service
+        .clone_space(&space_id, &options)
+        .await
+        .unwrap();
+
+    assert_ne!(cloned.space.id, space_id);
+    assert_eq!(cloned.space.name, "Client B");
+    assert_eq!(cloned.servers, vec!["github".to_string()]);
+    assert_eq!(cloned.feature_sets, 1);
+    assert_eq!(cloned.grants, 1);
+
+    let new_space_id = cloned.space.id.to_string();
+    let server = fixture
+        .repos
+        .servers
+        .get_by_server_id(&new_space_id, "github")
+        .await
+        .unwrap()
+        .expect("server cloned");
+    assert_eq!(server.input_values["GITHUB_TOKEN"], "ghp_secret");
+    assert_eq!(server.env_overrides["SENTRY_AUTH_TOKEN"], "sntrys_secret");
+    assert!(cloned.missing_secrets.is_empty());
+    let secret = fixture
+        .repos
+        .secrets
+        .get(&cloned.space.id, "linear_token")
+        .await
+        .unwrap()
+        .expect("space secret cloned");
+    assert_eq!(secret.value, "lin_secret");
+
+    // Grants point at the clone's copy of the feature set
+    let sets = fixture
+        .repos
+        .feature_sets
+        .list_by_space(&new_space_id)
+        .await
+        .unwrap();
+    let custom = sets.iter().find(|s| s.name == "Issues").unwrap();
+    let client = fixture.cursor().await;
+    let grants = fixture
+        .clients
+        .get_grants_for_space(&client.id, &new_space_id)
+        .await
+        .unwrap();
+    assert_eq!(grants, vec![custom.id.clone()]);
+
+    // The source space is untouched
+    let source = fixture
+        .repos
+        .servers
+        .list_for_space(&space_id.to_string())
+        .await
+        .unwrap();
+    assert_eq!(source.len(), 1);
+}
+
+#[tokio::test]
+async fn test_clone_space_does_not_copy_refresh_tokens() {
+    let fixture = Fixture::new();
+    let space_id = fixture.seed().await;
+
+    let options = SpaceCloneOptions {
+        name: "Client B".to_string(),
+        include_secrets: true,
+        include_grants: false,
+    };
+    let cloned = fixture
+        .service
+        .clone_space(&space_id, &options)
+        .await
+        .unwrap();
+
+    let server = fixture
+        .repos
+        .servers
+        .get_by_server_id(&cloned.space.id.to_string(), "github")
+        .await
+        .unwrap()
+        .expect("server cloned");
+    assert!(!server.oauth_connected);
+    assert!(fixture
+        .repos
+        .credentials
+        .get(&cloned.space.id, "github", &CredentialType::RefreshToken)
+        .await
+        .unwrap()
+        .is_none());
+
+    // The source keeps its token
+    assert!(fixture
+        .repos
+        .credentials
+        .get(&space_id, "github", &CredentialType::RefreshToken)
+        .await
+        .unwrap()
+        .is_some());
+}
+
+#[tokio::test]
+async fn test_clone_space_without_secrets_or_grants() {
+    let fixture = Fixture::new();
+    let space_id = fixture.seed().await;
+
+    let options = SpaceCloneOptions {
+        name: "Client B".to_string(),
+        include_secrets: false,
+        include_grants: false,
+    };
+    let cloned = fixture
+        .service
+        .clone_space(&space_id, &options)
+        .await
+        .unwrap();
+    assert_eq!(cloned.grants, 0);
+
+    let new_space_id = cloned.space.id.to_string();
+    let server = fixture
+        .repos
+        .servers
+        .get_by_server_id(&new_space_id, "github")
+        .await
+        .unwrap()
+        .expect("server cloned");
+    assert!(!server.input_values.contains_key("GITHUB_TOKEN"));
+    assert!(!server.env_overrides.contains_key("SENTRY_AUTH_TOKEN"));
+    assert_eq!(server.env_overrides["LOG_LEVEL"], "debug");
+    assert_eq!(cloned.missing_secrets, vec!["linear_token".to_string()]);
+    assert!(fixture
+        .repos
+        .secrets
+        .list_for_space(&cloned.space.id)
+        .await
+        .unwrap()
+        .is_empty());
+
+    let client = fixture.cursor().await;
+    assert!(fixture
+        .clients
+        .get_grants_for_space(&client.id, &new_space_id)
+        .await
+        .unwrap()
+        .is_empty());
+}
+
+#[tokio::test]
+async fn test_template_roundtrip() {
+    let fixture = Fixture::new();
+    let space_id = fixture.seed().await;
+
+    let template = fixture
+        .service
+        .save_template(&space_id, "Client project".to_string(), None)
+        .await
+        .unwrap();
+    let templates = fixture.service.list_templates().await.unwrap();
+    assert_eq!(templates.len(), 1);
+    assert_eq!(templates[0].id, template.id);
+    let missing: Vec<String> = templates[0]
+        .inputs()
+        .into_iter()
+        .map(|i| i.input.id)
+        .collect();
+    assert_eq!(missing, vec!["GITHUB_TOKEN".to_string()]);
+    assert_eq!(templates[0].secrets(), vec!["linear_token".to_string()]);
+
+    let inputs = HashMap::from([(
+        "github".to_string(),
+        HashMap::from([("GITHUB_TOKEN".to_string(), "ghp_other".to_string())]),
+    )]);
+    let created = fixture
+        .service
+        .create_from_template(&template.id, "Client C", None, &inputs)
+        .await
+        .unwrap();
+    assert_eq!(created.space.name, "Client C");
+    assert_eq!(created.feature_sets, 1);
+    assert_eq!(created.missing_secrets, vec!["linear_token".to_string()]);
+
+    let server = fixture
+        .repos
+        .servers
+        .get_by_server_id(&created.space.id.to_string(), "github")
+        .await
+        .unwrap()
+        .expect("server created");
+    assert_eq!(server.input_values["GITHUB_TOKEN"], "ghp_other");
+
+    fixture.service.delete_template(&template.id).await.unwrap();
+    assert!(fixture.service.list_templates().await.unwrap().is_empty());
+}
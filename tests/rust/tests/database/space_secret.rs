@@ -0,0 +1,123 @@
+//! Space secret integration tests
+//!
+//! Stores secrets encrypted per space and tracks which installed servers
+//! reference them from their input values.
+
+use mcpmux_core::SpaceSecretService;
+use tests::{fixtures, SqliteRepos};
+use uuid::Uuid;
+
+struct Fixture {
+    repos: SqliteRepos,
+    service: SpaceSecretService,
+}
+
+impl Fixture {
+    fn new() -> Self {
+        let repos = SqliteRepos::new();
+        let service = SpaceSecretService::new(repos.secrets.clone(), repos.servers.clone());
+        Self { repos, service }
+    }
+
+    /// Create a space with a GitHub server whose token input uses a secret
+    async fn seed(&self) -> Uuid {
+        self.repos
+            .seed_github("Work", "${secret:github_token}")
+            .await
+    }
+}
+
+#[tokio::test]
+async fn test_set_and_rotate_secret() {
+    let fixture = Fixture::new();
+    let space_id = fixture.seed().await;
+
+    let dependents = fixture
+        .service
+        .set(&space_id, "github_token", "ghp_one")
+        .await
+        .unwrap();
+    assert_eq!(dependents.len(), 1);
+    assert_eq!(dependents[0].server_id, "github");
+
+    let created = fixture.service.list(&space_id).await.unwrap().remove(0);
+    assert_eq!(created.used_by, vec!["github".to_string()]);
+
+    fixture
+        .service
+        .set(&space_id, "github_token", "ghp_two")
+        .await
+        .unwrap();
+    let rotated = fixture.service.list(&space_id).await.unwrap().remove(0);
+    assert_eq!(rotated.created_at, created.created_at);
+    assert!(rotated.updated_at >= created.updated_at);
+
+    let values = fixture.service.values(&space_id).await.unwrap();
+    assert_eq!(
+        values.get("github_token").map(String::as_str),
+        Some("ghp_two")
+    );
+}
+
+#[tokio::test]
+async fn test_secrets_are_scoped_to_space() {
+    let fixture = Fixture::new();
+    let space_id = fixture.seed().await;
+    let other = fixtures::test_space("Personal");
+    fixture.repos.spaces.create(&other).await.unwrap();
+
+    fixture
+        .service
+        .set(&space_id, "github_token", "ghp_work")
+        .await
+        .unwrap();
+
+    assert!(fixture.service.values(&other.id).await.unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_delete_secret_in_use_fails() {
+    let fixture = Fixture::new();
+    let space_id = fixture.seed().await;
+    fixture
+        .service
+        .set(&space_id, "github_token", "ghp_one")
+        .await
+        .unwrap();
+    fixture
+        .service
+        .set(&space_id, "unused", "value")
+        .await
+        .unwrap();
+
+    let err = fixture
+        .service
+        .delete(&space_id, "github_token")
+        .await
+        .unwrap_err();
+    assert!(err.to_string().contains("github"));
+
+    fixture.service.delete(&space_id, "unused").await.unwrap();
+    let names: Vec<String> = fixture
+        .service
+        .list(&space_id)
+        .await
+        .unwrap()
+        .into_iter()
+        .map(|s| s.name)
+        .collect();
+    assert_eq!(names, vec!["github_token".to_string()]);
+}
+
+#[tokio::test]
+async fn test_invalid_secret_name_rejected() {
+    let fixture = Fixture::new();
+    let space_id = fixture.seed().await;
+
+    assert!(fixture
+        .service
+        .set(&space_id, "bad name", "value")
+        .await
+        .is_err());
+    assert!(fixture.service.set(&space_id, "empty", "").await.is_err());
+}
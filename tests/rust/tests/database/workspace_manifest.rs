@@ -0,0 +1,335 @@
+//! Workspace manifest plan/apply integration tests
+//!
+//! Plans and applies manifests against a real database and checks that a
+//! second plan finds nothing left to change.
+
+use mcpmux_core::domain::{Client, SpaceSecret};
+use mcpmux_core::{
+    ChangeAction, ManifestResource, MemberType, WorkspaceManifest, WorkspaceManifestService,
+};
+use std::path::Path;
+use tests::{features, SqliteRepos};
+
+struct Workspace {
+    repos: SqliteRepos,
+    service: WorkspaceManifestService,
+}
+
+impl Workspace {
+    fn new() -> Self {
+        let repos = SqliteRepos::new();
+        let service = WorkspaceManifestService::new(
+            repos.spaces.clone(),
+            repos.servers.clone(),
+            repos.features.clone(),
+            repos.feature_sets.clone(),
+            repos.clients.clone(),
+            repos.credentials.clone(),
+            repos.secrets.clone(),
+            repos.settings.clone(),
+        )
+        .with_transactions(repos.transactions.clone());
+        Self { repos, service }
+    }
+}
+
+const MANIFEST: &str = r#"{
+    "version": 1,
+    "settings": { "ui.compact_mode": true },
+    "spaces": [{
+        "name": "Work",
+        "icon": "💼",
+        "default": true,
+        "servers": {
+            "echo": {
+                "definition": { "command": "npx", "args": ["-y", "echo-mcp"] },
+                "enabled": true,
+                "env": { "LOG_LEVEL": "debug" }
+            }
+        },
+        "feature_sets": [{
+            "name": "Readers",
+            "members": [
+                { "rule": "server:echo name:read_*" },
+                { "feature": { "server": "echo", "name": "say" } }
+            ]
+        }],
+        "client_grants": [{ "client": "cursor*", "feature_sets": ["Readers"] }]
+    }]
+}"#;
+
+fn source() -> &'static Path {
+    Path::new("/tmp/mcpmux.json")
+}
+
+#[tokio::test]
+async fn test_plan_does_not_change_anything() {
+    let ws = Workspace::new();
+    let manifest = WorkspaceManifest::parse(MANIFEST).unwrap();
+
+    let plan = ws.service.plan(&manifest, source(), &[]).await.unwrap();
+
+    let kinds: Vec<_> = plan
+        .changes
+        .iter()
+        .map(|c| (c.action, c.resource))
+        .collect();
+    assert!(kinds.contains(&(ChangeAction::Create, ManifestResource::Setting)));
+    assert!(kinds.contains(&(ChangeAction::Create, ManifestResource::Space)));
+    assert!(kinds.contains(&(ChangeAction::Create, ManifestResource::Server)));
+    assert!(kinds.contains(&(ChangeAction::Create, ManifestResource::FeatureSet)));
+    assert!(plan.render().contains("+ space Work"));
+    assert!(!ws
+        .repos
+        .spaces
+        .list()
+        .await
+        .unwrap()
+        .iter()
+        .any(|s| s.name == "Work"));
+    assert_eq!(
+        ws.repos.settings.get("ui.compact_mode").await.unwrap(),
+        None
+    );
+}
+
+#[tokio::test]
+async fn test_apply_converges() {
+    let ws = Workspace::new();
+    let cursor = Client::cursor();
+    ws.repos.clients.create(&cursor).await.unwrap();
+    let manifest = WorkspaceManifest::parse(MANIFEST).unwrap();
+
+    let applied = ws.service.apply(&manifest, source(), &[]).await.unwrap();
+    assert!(!applied.events.is_empty());
+    // The tool hasn't been discovered yet
+    assert_eq!(applied.warnings.len(), 1);
+
+    let space = ws
+        .repos
+        .spaces
+        .get_default()
+        .await
+        .unwrap()
+        .expect("default space");
+    assert_eq!(space.name, "Work");
+    let space_id = space.id.to_string();
+
+    let server = ws
+        .repos
+        .servers
+        .get_by_server_id(&space_id, "echo")
+        .await
+        .unwrap()
+        .expect("server installed");
+    assert!(server.enabled);
+    assert_eq!(server.env_overrides["LOG_LEVEL"], "debug");
+    assert_eq!(
+        ws.repos.settings.get("ui.compact_mode").await.unwrap(),
+        Some("true".to_string())
+    );
+
+    let readers = ws
+        .repos
+        .feature_sets
+        .list_by_space(&space_id)
+        .await
+        .unwrap()
+        .into_iter()
+        .find(|s| s.name == "Readers")
+        .expect("feature set created");
+    let grants = ws
+        .repos
+        .clients
+        .get_grants_for_space(&cursor.id, &space_id)
+        .await
+        .unwrap();
+    assert_eq!(grants, vec![readers.id.clone()]);
+
+    // Nothing left to do until the tool shows up
+    let plan = ws.service.plan(&manifest, source(), &[]).await.unwrap();
+    assert!(
+        !plan.has_changes(),
+        "unexpected changes:\n{}",
+        plan.render()
+    );
+
+    ws.repos
+        .features
+        .upsert(&features::test_tool(&space_id, "echo", "say"))
+        .await
+        .unwrap();
+    let applied = ws.service.apply(&manifest, source(), &[]).await.unwrap();
+    assert_eq!(applied.changes.len(), 1);
+    let members = ws
+        .repos
+        .feature_sets
+        .get_with_members(&readers.id)
+        .await
+        .unwrap()
+        .unwrap()
+        .members;
+    assert_eq!(members.len(), 2);
+    assert!(members.iter().any(|m| m.member_type == MemberType::Feature));
+}
+
+#[tokio::test]
+async fn test_prune_removes_undeclared_servers() {
+    let ws = Workspace::new();
+    let manifest = WorkspaceManifest::parse(MANIFEST).unwrap();
+    ws.service.apply(&manifest, source(), &[]).await.unwrap();
+
+    let pruned =
+        WorkspaceManifest::parse(r#"{"version": 1, "prune": true, "spaces": [{"name": "Work"}]}"#)
+            .unwrap();
+    let plan = ws.service.apply(&pruned, source(), &[]).await.unwrap();
+
+    assert!(plan
+        .changes
+        .iter()
+        .all(|c| c.action == ChangeAction::Delete));
+    let space = ws.repos.spaces.get_default().await.unwrap().unwrap();
+    assert!(ws
+        .repos
+        .servers
+        .list_for_space(&space.id.to_string())
+        .await
+        .unwrap()
+        .is_empty());
+}
+
+#[tokio::test]
+async fn test_inline_secret_is_rejected() {
+    let ws = Workspace::new();
+    let manifest = WorkspaceManifest::parse(
+        r#"{"version": 1, "spaces": [{"name": "Work", "servers": {
+            "github": {
+                "definition": {
+                    "command": "npx",
+                    "args": ["-y", "@modelcontextprotocol/server-github"],
+                    "env": { "GITHUB_PERSONAL_ACCESS_TOKEN": "${input:GITHUB_TOKEN}" }
+                },
+                "inputs": { "GITHUB_TOKEN": "ghp_plaintext" }
+            }
+        }}]}"#,
+    )
+    .unwrap();
+
+    let err = ws.service.plan(&manifest, source(), &[]).await.unwrap_err();
+    assert!(err.to_string().contains("is a secret"));
+
+    std::env::set_var("MCPMUX_MANIFEST_TEST_TOKEN", "ghp_from_env");
+    let manifest = WorkspaceManifest::parse(
+        &serde_json::to_string(&manifest)
+            .unwrap()
+            .replace("ghp_plaintext", "${env:MCPMUX_MANIFEST_TEST_TOKEN}"),
+    )
+    .unwrap();
+    let plan = ws.service.apply(&manifest, source(), &[]).await.unwrap();
+    assert!(!plan.render().contains("ghp_from_env"));
+
+    let space_id = ws
+        .repos
+        .spaces
+        .list()
+        .await
+        .unwrap()
+        .into_iter()
+        .find(|s| s.name == "Work")
+        .unwrap()
+        .id;
+    let server = ws
+        .repos
+        .servers
+        .get_by_server_id(&space_id.to_string(), "github")
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(server.input_values["GITHUB_TOKEN"], "ghp_from_env");
+}
+
+#[tokio::test]
+async fn test_failed_apply_changes_nothing() {
+    let ws = Workspace::new();
+    // The space is created before the unknown server fails the run
+    let manifest = WorkspaceManifest::parse(
+        r#"{"version": 1, "spaces": [{"name": "Work", "servers": {"unknown": {}}}]}"#,
+    )
+    .unwrap();
+
+    let err = ws
+        .service
+        .apply(&manifest, source(), &[])
+        .await
+        .unwrap_err();
+    assert!(err.to_string().contains("not in the registry"));
+    assert!(!ws
+        .repos
+        .spaces
+        .list()
+        .await
+        .unwrap()
+        .iter()
+        .any(|s| s.name == "Work"));
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_failed_apply_keeps_writes_made_elsewhere() {
+    let ws = Workspace::new();
+    let manifest = WorkspaceManifest::parse(
+        r#"{"version": 1, "spaces": [{"name": "Work", "servers": {"unknown": {}}}]}"#,
+    )
+    .unwrap();
+
+    // A write through the app's own repositories while the apply runs
+    let settings = ws.repos.settings.clone();
+    let write = tokio::spawn(async move { settings.set("ui.theme", "dark").await });
+    assert!(ws.service.apply(&manifest, source(), &[]).await.is_err());
+    write.await.unwrap().unwrap();
+
+    assert_eq!(
+        ws.repos.settings.get("ui.theme").await.unwrap().as_deref(),
+        Some("dark")
+    );
+    assert!(!ws
+        .repos
+        .spaces
+        .list()
+        .await
+        .unwrap()
+        .iter()
+        .any(|s| s.name == "Work"));
+}
+
+#[tokio::test]
+async fn test_unset_secret_references_are_warned_about() {
+    let ws = Workspace::new();
+    let manifest = WorkspaceManifest::parse(
+        r#"{"version": 1, "spaces": [{"name": "Work", "servers": {"echo": {
+            "definition": { "command": "npx", "args": ["-y", "echo-mcp"] },
+            "env": { "API_KEY": "${secret:api_key}" }
+        }}}]}"#,
+    )
+    .unwrap();
+
+    let plan = ws.service.apply(&manifest, source(), &[]).await.unwrap();
+    assert!(plan.warnings.iter().any(|w| w.contains("secret 'api_key'")));
+
+    // Once the space has the secret there's nothing to warn about
+    let space = ws
+        .repos
+        .spaces
+        .list()
+        .await
+        .unwrap()
+        .into_iter()
+        .find(|s| s.name == "Work")
+        .unwrap();
+    ws.repos
+        .secrets
+        .save(&SpaceSecret::new(space.id, "api_key", "sk_live"))
+        .await
+        .unwrap();
+    let plan = ws.service.plan(&manifest, source(), &[]).await.unwrap();
+    assert!(plan.warnings.iter().all(|w| !w.contains("secret")));
+}
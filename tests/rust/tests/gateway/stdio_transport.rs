@@ -145,6 +145,79 @@ async fn test_stdio_transport_connect_command_not_found() {
     }
 }
 
+/// Verify that connect fails with a clear error when the working directory is missing
+#[tokio::test]
+async fn test_stdio_transport_connect_missing_cwd() {
+    use mcpmux_gateway::pool::transport::StdioTransport;
+    use mcpmux_gateway::pool::{Transport, TransportConnectResult};
+    use std::collections::HashMap;
+    use std::time::Duration;
+    use uuid::Uuid;
+
+    let dir = tempfile::tempdir().unwrap();
+    let missing = dir.path().join("missing");
+    let transport = StdioTransport::new(
+        "node".to_string(),
+        vec![],
+        HashMap::new(),
+        Uuid::new_v4(),
+        "test-server".to_string(),
+        None,
+        Duration::from_secs(5),
+        None,
+    )
+    .with_cwd(Some(missing.clone()));
+
+    match transport.connect().await {
+        TransportConnectResult::Failed(msg) => {
+            assert_eq!(
+                msg,
+                format!("Working directory {} does not exist", missing.display())
+            );
+        }
+        _ => panic!("Expected TransportConnectResult::Failed for missing working directory"),
+    }
+}
+
+/// Verify that connect fails when the .env file can't be read
+#[tokio::test]
+async fn test_stdio_transport_connect_missing_env_file() {
+    use mcpmux_gateway::pool::transport::StdioTransport;
+    use mcpmux_gateway::pool::{Transport, TransportConnectResult};
+    use std::collections::HashMap;
+    use std::time::Duration;
+    use uuid::Uuid;
+
+    #[cfg(windows)]
+    let program = "cmd";
+    #[cfg(unix)]
+    let program = "sh";
+
+    let dir = tempfile::tempdir().unwrap();
+    let transport = StdioTransport::new(
+        program.to_string(),
+        vec![],
+        HashMap::new(),
+        Uuid::new_v4(),
+        "test-server".to_string(),
+        None,
+        Duration::from_secs(5),
+        None,
+    )
+    .with_cwd(Some(dir.path().to_path_buf()))
+    .with_env_file(Some(dir.path().join(".env")));
+
+    match transport.connect().await {
+        TransportConnectResult::Failed(msg) => {
+            assert!(
+                msg.contains("Failed to read env file"),
+                "Expected env file error, got: {msg}"
+            );
+        }
+        _ => panic!("Expected TransportConnectResult::Failed for missing env file"),
+    }
+}
+
 /// Verify that configure_child_process_platform can be called multiple times
 /// without issues (idempotency).
 #[tokio::test]
@@ -4,6 +4,7 @@
 //! - Feature grant resolution (Space → FeatureSet → Features)
 //! - Feature routing (qualified names, prefix resolution)
 //! - MCP request handling (tools, resources, prompts)
+//! - Tool call approval (policies, held calls)
 //!
 //! NOTE: Authorization tests that require InboundClientRepository
 //! are in the database tests since they need the real SQLite implementation.
@@ -11,3 +12,4 @@
 mod feature_grants;
 mod feature_routing;
 mod mcp_flows;
+mod tool_approval;
@@ -0,0 +1,265 @@
+//! Tool approval tests
+//!
+//! Tests approval policy resolution (tool rule, feature set rules, destructive
+//! default) and the hold / resolve flow for tool calls awaiting a human.
+
+use std::sync::Arc;
+use std::time::Duration;
+use uuid::Uuid;
+
+use mcpmux_core::{
+    ApprovalPolicy, ApprovalTarget, DomainEvent, FeatureSet, FeatureSetMember,
+    FeatureSetRepository, LogConfig, MemberMode, MemberType, ServerFeature,
+    ServerFeatureRepository, ServerLogManager, SpaceRepository, ToolApprovalRule,
+};
+use mcpmux_gateway::{
+    ApprovalDecision, ApprovalSession, FeatureService, PrefixCacheService, ToolApprovalRequest,
+    ToolApprovalService,
+};
+use mcpmux_storage::{SqliteSpaceRepository, ToolApprovalRepository};
+use tests::db::TestDatabase;
+use tests::fixtures;
+use tests::mocks::{MockFeatureSetRepository, MockServerFeatureRepository};
+use tokio::sync::{broadcast, Mutex};
+
+struct Harness {
+    space_id: String,
+    service: Arc<ToolApprovalService>,
+    rules: Arc<ToolApprovalRepository>,
+    feature_repo: Arc<MockServerFeatureRepository>,
+    feature_set_repo: Arc<MockFeatureSetRepository>,
+    events: broadcast::Receiver<DomainEvent>,
+    _log_dir: tempfile::TempDir,
+}
+
+async fn harness() -> Harness {
+    let test_db = TestDatabase::in_memory();
+    let db = Arc::new(Mutex::new(test_db.db));
+    let space = fixtures::test_space("Approval Space");
+    SpaceRepository::create(&SqliteSpaceRepository::new(Arc::clone(&db)), &space)
+        .await
+        .unwrap();
+
+    let feature_repo = Arc::new(MockServerFeatureRepository::new());
+    let feature_set_repo = Arc::new(MockFeatureSetRepository::new());
+    let feature_service = Arc::new(FeatureService::new(
+        feature_repo.clone() as Arc<dyn ServerFeatureRepository>,
+        feature_set_repo.clone() as Arc<dyn FeatureSetRepository>,
+        Arc::new(PrefixCacheService::new()),
+    ));
+
+    let log_dir = tempfile::tempdir().expect("Failed to create temp dir");
+    let log_manager = Arc::new(ServerLogManager::new(LogConfig {
+        base_dir: log_dir.path().to_path_buf(),
+        ..Default::default()
+    }));
+
+    let rules = Arc::new(ToolApprovalRepository::new(db));
+    let (event_tx, events) = broadcast::channel(16);
+    let service = Arc::new(ToolApprovalService::new(
+        rules.clone(),
+        None,
+        feature_service,
+        log_manager,
+        event_tx,
+    ));
+
+    Harness {
+        space_id: space.id.to_string(),
+        service,
+        rules,
+        feature_repo,
+        feature_set_repo,
+        events,
+        _log_dir: log_dir,
+    }
+}
+
+fn rule(
+    space_id: &str,
+    target_type: ApprovalTarget,
+    target: &str,
+    policy: ApprovalPolicy,
+) -> ToolApprovalRule {
+    ToolApprovalRule {
+        space_id: space_id.to_string(),
+        target_type,
+        target: target.to_string(),
+        policy,
+    }
+}
+
+fn approval_request(space_id: &str, feature: &ServerFeature) -> ToolApprovalRequest {
+    ToolApprovalRequest {
+        request_id: Uuid::new_v4(),
+        space_id: Uuid::parse_str(space_id).unwrap(),
+        client_id: "client-1".to_string(),
+        session_id: Some("session-1".to_string()),
+        server_id: feature.server_id.clone(),
+        feature_id: feature.id,
+        tool_name: feature.qualified_name(),
+        arguments: serde_json::json!({ "path": "/tmp/x" }),
+        destructive: feature.is_destructive(),
+        requested_at: chrono::Utc::now(),
+    }
+}
+
+#[tokio::test]
+async fn test_policy_precedence() {
+    let h = harness().await;
+
+    let delete = ServerFeature::tool(&h.space_id, "fs", "delete_file").with_raw_json(
+        serde_json::json!({ "name": "delete_file", "annotations": { "destructiveHint": true } }),
+    );
+    let read = ServerFeature::tool(&h.space_id, "fs", "read_file");
+    h.feature_repo.upsert(&delete).await.unwrap();
+    h.feature_repo.upsert(&read).await.unwrap();
+
+    // No rules and no settings: everything is allowed
+    assert_eq!(
+        h.service.policy_for(&h.space_id, &delete).await.unwrap(),
+        ApprovalPolicy::Allow
+    );
+
+    // A feature set rule applies to its members only
+    let mut writes = FeatureSet::new_custom("Writes", &h.space_id);
+    writes.members.push(FeatureSetMember {
+        id: Uuid::new_v4().to_string(),
+        feature_set_id: writes.id.clone(),
+        member_id: delete.id.to_string(),
+        member_type: MemberType::Feature,
+        mode: MemberMode::Include,
+    });
+    h.feature_set_repo.create(&writes).await.unwrap();
+    h.rules
+        .set(&rule(
+            &h.space_id,
+            ApprovalTarget::FeatureSet,
+            &writes.id,
+            ApprovalPolicy::Ask,
+        ))
+        .await
+        .unwrap();
+
+    assert_eq!(
+        h.service.policy_for(&h.space_id, &delete).await.unwrap(),
+        ApprovalPolicy::Ask
+    );
+    assert_eq!(
+        h.service.policy_for(&h.space_id, &read).await.unwrap(),
+        ApprovalPolicy::Allow
+    );
+
+    // A tool rule overrides feature set rules
+    h.rules
+        .set(&rule(
+            &h.space_id,
+            ApprovalTarget::Tool,
+            &delete.id.to_string(),
+            ApprovalPolicy::Deny,
+        ))
+        .await
+        .unwrap();
+    assert_eq!(
+        h.service.policy_for(&h.space_id, &delete).await.unwrap(),
+        ApprovalPolicy::Deny
+    );
+}
+
+#[tokio::test]
+async fn test_held_call_released_by_desktop_decision() {
+    let mut h = harness().await;
+    let feature = ServerFeature::tool(&h.space_id, "fs", "delete_file");
+    let request = approval_request(&h.space_id, &feature);
+
+    let service = h.service.clone();
+    let held = tokio::spawn({
+        let request = request.clone();
+        async move { service.request_approval(request).await }
+    });
+
+    // The desktop app is told about the held call
+    let event = tokio::time::timeout(Duration::from_secs(5), h.events.recv())
+        .await
+        .expect("approval request event")
+        .unwrap();
+    assert!(matches!(
+        event,
+        DomainEvent::ToolApprovalRequested { request_id, .. } if request_id == request.request_id
+    ));
+    assert_eq!(h.service.list_pending().len(), 1);
+
+    h.service
+        .resolve(request.request_id, true, true)
+        .expect("pending request");
+    let decision = held.await.unwrap();
+    assert!(decision.approved);
+    assert!(decision.remember);
+    assert_eq!(decision.decided_by, "desktop");
+    assert!(h.service.list_pending().is_empty());
+
+    // Recording a remembered approval skips asking again in the same session only
+    h.service.record_decision(&request, &decision).await;
+    assert!(h
+        .service
+        .is_remembered("client-1", Some("session-1"), feature.id));
+    assert!(!h
+        .service
+        .is_remembered("client-1", Some("session-2"), feature.id));
+
+    // Resolving twice fails
+    assert!(h.service.resolve(request.request_id, true, false).is_err());
+}
+
+fn remembered_approval() -> ApprovalDecision {
+    ApprovalDecision {
+        approved: true,
+        remember: true,
+        decided_by: "desktop".to_string(),
+    }
+}
+
+#[tokio::test]
+async fn test_remembered_approvals_need_a_session() {
+    let h = harness().await;
+    let feature = ServerFeature::tool(&h.space_id, "fs", "delete_file");
+    let mut request = approval_request(&h.space_id, &feature);
+    request.session_id = None;
+
+    h.service
+        .record_decision(&request, &remembered_approval())
+        .await;
+
+    assert!(!h.service.is_remembered("client-1", None, feature.id));
+    assert!(!h.service.is_remembered("client-1", Some(""), feature.id));
+}
+
+#[tokio::test]
+async fn test_remembered_approvals_end_with_the_session() {
+    let h = harness().await;
+    let feature = ServerFeature::tool(&h.space_id, "fs", "delete_file");
+    let request = approval_request(&h.space_id, &feature);
+    let mut other = approval_request(&h.space_id, &feature);
+    other.session_id = Some("session-2".to_string());
+
+    let session = ApprovalSession::new(h.service.clone());
+    session.bind("session-1");
+    h.service
+        .record_decision(&request, &remembered_approval())
+        .await;
+    h.service
+        .record_decision(&other, &remembered_approval())
+        .await;
+    assert!(h
+        .service
+        .is_remembered("client-1", Some("session-1"), feature.id));
+
+    // Dropping the session's guard forgets its approvals only
+    drop(session);
+    assert!(!h
+        .service
+        .is_remembered("client-1", Some("session-1"), feature.id));
+    assert!(h
+        .service
+        .is_remembered("client-1", Some("session-2"), feature.id));
+}
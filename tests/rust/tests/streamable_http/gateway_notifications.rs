@@ -109,6 +109,7 @@ impl TestGateway {
             description: None,
             is_default: true,
             sort_order: 0,
+            read_only: false,
             created_at: chrono::Utc::now(),
             updated_at: chrono::Utc::now(),
         };
@@ -142,6 +143,7 @@ impl TestGateway {
             metadata_cache_ttl: None,
             connection_mode: "follow_active".to_string(),
             locked_space_id: None,
+            meta_tools: false,
             last_seen: None,
             created_at: now.clone(),
             updated_at: now,
@@ -211,7 +213,7 @@ impl TestGateway {
 
         // Build MCP service
         let mcp_service = StreamableHttpService::new(
-            move || Ok(handler.clone()),
+            move || Ok(handler.for_session()),
             Arc::new(LocalSessionManager::default()),
             StreamableHttpServerConfig {
                 stateful_mode: true,
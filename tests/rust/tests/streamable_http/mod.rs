@@ -7,3 +7,4 @@
 
 mod gateway_notifications;
 mod notifications;
+mod stdio_bridge;
@@ -0,0 +1,219 @@
+//! Test: stdio bridge to a Streamable HTTP gateway
+//!
+//! Validates that:
+//! 1. A client speaking newline-delimited JSON-RPC reaches the gateway through the bridge
+//! 2. The bridge works over TCP and over a Unix domain socket
+//! 3. The access token is sent as a Bearer token on every request
+
+use axum::{body::Body, http::Request, middleware, middleware::Next, response::Response, Router};
+use mcpmux_gateway::{run_bridge, BridgeTarget};
+use rmcp::{
+    model::*,
+    service::RequestContext,
+    transport::{
+        async_rw::AsyncRwTransport,
+        streamable_http_server::{
+            session::local::LocalSessionManager, StreamableHttpServerConfig, StreamableHttpService,
+        },
+    },
+    ErrorData as McpError, RoleServer, ServerHandler, ServiceExt,
+};
+use std::sync::Arc;
+use tokio_util::sync::CancellationToken;
+
+const TOKEN: &str = "bridge-test-token";
+
+#[derive(Clone)]
+struct EchoHandler;
+
+impl ServerHandler for EchoHandler {
+    fn get_info(&self) -> ServerInfo {
+        ServerInfo {
+            protocol_version: Default::default(),
+            capabilities: ServerCapabilities::builder().enable_tools().build(),
+            server_info: Implementation {
+                name: "bridge-test-server".to_string(),
+                version: "1.0.0".to_string(),
+                ..Default::default()
+            },
+            instructions: None,
+        }
+    }
+
+    async fn list_tools(
+        &self,
+        _params: Option<PaginatedRequestParams>,
+        _context: RequestContext<RoleServer>,
+    ) -> Result<ListToolsResult, McpError> {
+        let schema: Arc<serde_json::Map<String, serde_json::Value>> = Arc::new(
+            serde_json::from_value(serde_json::json!({"type": "object", "properties": {}}))
+                .unwrap(),
+        );
+        Ok(ListToolsResult::with_all_items(vec![Tool::new(
+            "echo",
+            "Echo the tool name",
+            schema,
+        )]))
+    }
+
+    async fn call_tool(
+        &self,
+        params: CallToolRequestParams,
+        _context: RequestContext<RoleServer>,
+    ) -> Result<CallToolResult, McpError> {
+        Ok(CallToolResult::success(vec![Content::text(format!(
+            "Called: {}",
+            params.name
+        ))]))
+    }
+}
+
+/// Reject requests without the expected Bearer token
+async fn require_token(request: Request<Body>, next: Next) -> Response {
+    let authorized = request
+        .headers()
+        .get("authorization")
+        .and_then(|v| v.to_str().ok())
+        == Some(&format!("Bearer {}", TOKEN));
+    if !authorized {
+        return Response::builder()
+            .status(401)
+            .body(Body::from("Missing token"))
+            .unwrap();
+    }
+    next.run(request).await
+}
+
+fn router(ct: &CancellationToken) -> Router {
+    let service = StreamableHttpService::new(
+        || Ok(EchoHandler),
+        Arc::new(LocalSessionManager::default()),
+        StreamableHttpServerConfig {
+            stateful_mode: true,
+            sse_keep_alive: Some(std::time::Duration::from_secs(15)),
+            sse_retry: Some(std::time::Duration::from_secs(3)),
+            cancellation_token: ct.child_token(),
+        },
+    );
+    Router::new()
+        .nest_service("/mcp", service)
+        .layer(middleware::from_fn(require_token))
+}
+
+/// Connect an MCP client through the bridge and exercise a request round trip
+async fn assert_bridge_round_trip(target: BridgeTarget, token: Option<&str>) -> bool {
+    let (client_io, bridge_io) = tokio::io::duplex(64 * 1024);
+
+    let (bridge_read, bridge_write) = tokio::io::split(bridge_io);
+    let token = token.map(String::from);
+    let bridge = tokio::spawn(async move {
+        run_bridge(
+            &target,
+            token,
+            AsyncRwTransport::new_server(bridge_read, bridge_write),
+        )
+        .await
+    });
+
+    let (client_read, client_write) = tokio::io::split(client_io);
+    let client = tokio::time::timeout(
+        std::time::Duration::from_secs(5),
+        ().serve((client_read, client_write)),
+    )
+    .await
+    .expect("handshake should not hang");
+    let Ok(client) = client else {
+        bridge.abort();
+        return false;
+    };
+
+    let info = client.peer_info().expect("server info");
+    assert_eq!(info.server_info.name, "bridge-test-server");
+
+    let tools = client.list_tools(Default::default()).await.unwrap();
+    assert_eq!(tools.tools.len(), 1);
+    assert_eq!(tools.tools[0].name, "echo");
+
+    let result = client
+        .call_tool(CallToolRequestParams {
+            meta: None,
+            name: "echo".into(),
+            arguments: None,
+            task: None,
+        })
+        .await
+        .unwrap();
+    assert_eq!(
+        result.content[0].as_text().map(|t| t.text.as_str()),
+        Some("Called: echo")
+    );
+
+    client.cancel().await.ok();
+    tokio::time::timeout(std::time::Duration::from_secs(5), bridge)
+        .await
+        .expect("bridge exits when the client closes")
+        .unwrap()
+        .unwrap();
+    true
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_bridge_over_tcp() {
+    let ct = CancellationToken::new();
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("http://{}/mcp", listener.local_addr().unwrap());
+    let router = router(&ct);
+    let shutdown = ct.clone();
+    tokio::spawn(async move {
+        axum::serve(listener, router)
+            .with_graceful_shutdown(async move { shutdown.cancelled().await })
+            .await
+            .unwrap();
+    });
+
+    assert!(assert_bridge_round_trip(BridgeTarget::Url(url), Some(TOKEN)).await);
+    ct.cancel();
+}
+
+#[cfg(unix)]
+#[tokio::test(flavor = "multi_thread")]
+async fn test_bridge_over_unix_socket() {
+    use mcpmux_gateway::{server::listener::bind_unix_socket, UnixSocketConfig};
+
+    let dir = tempfile::tempdir().unwrap();
+    let config = UnixSocketConfig::new(dir.path().join("gateway.sock"));
+    let listener = bind_unix_socket(&config).unwrap();
+
+    let ct = CancellationToken::new();
+    let router = router(&ct);
+    let shutdown = ct.clone();
+    tokio::spawn(async move {
+        axum::serve(listener, router)
+            .with_graceful_shutdown(async move { shutdown.cancelled().await })
+            .await
+            .unwrap();
+    });
+
+    assert!(
+        assert_bridge_round_trip(BridgeTarget::UnixSocket(config.path.clone()), Some(TOKEN)).await
+    );
+    ct.cancel();
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_bridge_without_token_is_rejected() {
+    let ct = CancellationToken::new();
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("http://{}/mcp", listener.local_addr().unwrap());
+    let router = router(&ct);
+    let shutdown = ct.clone();
+    tokio::spawn(async move {
+        axum::serve(listener, router)
+            .with_graceful_shutdown(async move { shutdown.cancelled().await })
+            .await
+            .unwrap();
+    });
+
+    assert!(!assert_bridge_round_trip(BridgeTarget::Url(url), None).await);
+    ct.cancel();
+}
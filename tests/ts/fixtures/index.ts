@@ -9,6 +9,7 @@ export interface Space {
   description: string | null;
   is_default: boolean;
   sort_order: number;
+  read_only: boolean;
   created_at: string;
   updated_at: string;
 }
@@ -34,6 +35,7 @@ export function createTestSpace(overrides: Partial<Space> = {}): Space {
     description: 'A test space',
     is_default: false,
     sort_order: 0,
+    read_only: false,
     created_at: now,
     updated_at: now,
     ...overrides,